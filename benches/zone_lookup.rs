@@ -0,0 +1,105 @@
+//! Measures how long it takes to look up the offsets of a large batch of
+//! instants in several time zones, comparing the library’s binary search
+//! with the linear scan through the transitions that it replaced.
+//!
+//! Run it with `cargo bench --bench zone_lookup`. Zones are loaded from the
+//! system’s zoneinfo database, falling back to the test fixtures. The
+//! instants run past the end of each zone’s transition table, so lookups
+//! that have to use the zone’s POSIX rule are measured too.
+
+extern crate datetime;
+use datetime::zone::{TimeZone, TimeZoneSource, FixedTimespan};
+use datetime::zone::posix::PosixTimeZone;
+use datetime::{Instant, LocalDateTime};
+
+use std::path::Path;
+use std::time;
+
+
+const ZONES: &[&str] = &[
+    "Europe/London",
+    "America/New_York",
+    "America/Chicago",
+    "Australia/Sydney",
+    "Asia/Tokyo",
+];
+
+const BATCH: i64 = 1_000_000;
+
+/// From the start of 1900 to the start of 2100.
+const START: i64 = -2_208_988_800;
+const END: i64 = 4_102_444_800;
+
+
+fn main() {
+    let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/zoneinfo");
+    let step = (END - START) / BATCH;
+
+    for name in ZONES {
+        let zone = match TimeZone::named(name).or_else(|_| TimeZone::named_in(&fixtures, name)) {
+            Ok(zone)  => zone,
+            Err(e)    => { println!("{:<20} skipped: {}", name, e); continue; },
+        };
+
+        let (first, rest, extension) = table(&zone);
+        let table_end = rest.last().map_or(START, |t| t.0);
+        let datetimes: Vec<LocalDateTime> = (0 .. BATCH)
+            .map(|i| LocalDateTime::from_instant(Instant::at(START + i * step)))
+            .collect();
+
+        for &datetime in &datetimes {
+            assert_eq!(zone.offset(datetime), linear_offset(first, rest, extension, datetime),
+                       "{} disagrees at {:?}", name, datetime);
+        }
+
+        // Past the last transition in the table, both ways of looking up
+        // the offset have to generate the POSIX rule’s transitions, so
+        // those lookups are timed separately.
+        let (within, past): (Vec<LocalDateTime>, Vec<LocalDateTime>) = datetimes.iter()
+            .partition(|dt| dt.to_instant().seconds() <= table_end);
+
+        let (binary, binary_total) = time_lookups(&within, |dt| zone.offset(dt));
+        let (linear, linear_total) = time_lookups(&within, |dt| linear_offset(first, rest, extension, dt));
+        assert_eq!(binary_total, linear_total);
+        let (generated, generated_total) = time_lookups(&past, |dt| zone.offset(dt));
+
+        println!("{:<20} {:>4} transitions: {:>6.1} ns per lookup, {:>6.1} ns with a linear scan ({:.1}× speed-up, checksum {}); {:>6.1} ns past them (checksum {})",
+                 name, rest.len(), binary, linear, linear / binary, binary_total, generated, generated_total);
+    }
+}
+
+/// Returns the first timespan, the transitions, and the POSIX rule of a
+/// zone.
+fn table<'a>(zone: &'a TimeZone) -> (&'a FixedTimespan<'a>, &'a [ (i64, FixedTimespan<'a>) ], Option<&'a PosixTimeZone<'a>>) {
+    match zone.0 {
+        TimeZoneSource::Static(tz)        => (&tz.fixed_timespans.first, tz.fixed_timespans.rest, tz.fixed_timespans.extension.as_ref()),
+        TimeZoneSource::Runtime(ref arc)  => (&arc.fixed_timespans.first, &arc.fixed_timespans.rest, arc.fixed_timespans.extension.as_ref()),
+    }
+}
+
+/// Looks up the offset at a datetime the way it was done before the binary
+/// search: by scanning the transitions from the start, then generating
+/// the POSIX rule’s transitions for instants past the end of them.
+fn linear_offset(first: &FixedTimespan, rest: &[ (i64, FixedTimespan) ], extension: Option<&PosixTimeZone>, datetime: LocalDateTime) -> i64 {
+    let time = datetime.to_instant().seconds();
+    match (rest.last(), extension) {
+        (Some(last), Some(extension)) if time > last.0  => return extension.timespan_at(time).offset,
+        (None, Some(extension))                         => return extension.timespan_at(time).offset,
+        _                                               => {},
+    }
+
+    match rest.iter().take_while(|t| t.0 < time).last() {
+        Some(t)  => t.1.offset,
+        None     => first.offset,
+    }
+}
+
+/// Runs a lookup on every datetime, returning the average time taken in
+/// nanoseconds and the sum of the offsets, so the work can’t be skipped.
+fn time_lookups<F: Fn(LocalDateTime) -> i64>(datetimes: &[LocalDateTime], lookup: F) -> (f64, i64) {
+    let started = time::Instant::now();
+    let total: i64 = datetimes.iter().map(|&dt| lookup(dt)).sum();
+    let elapsed = started.elapsed();
+
+    (elapsed.as_secs_f64() * 1e9 / datetimes.len().max(1) as f64, total)
+}
@@ -0,0 +1,178 @@
+//! Generates the tables used by the `embedded-tzdata` feature.
+//!
+//! Run it with the path to a `tzdata.zi` file from the IANA time zone
+//! database (or any set of tzdata source files), and write its output to
+//! `src/cal/zone/embedded/data.rs`:
+//!
+//! ```sh
+//! cargo run --example generate_embedded -- /usr/share/zoneinfo/tzdata.zi > src/cal/zone/embedded/data.rs
+//! ```
+
+extern crate datetime;
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::process::exit;
+
+use datetime::zone::FixedTimespan;
+use datetime::zone::posix::{PosixTimeZone, RuleDay, TransitionRule};
+use datetime::zone::tzdata::Database;
+
+
+fn main() {
+    let paths: Vec<String> = env::args().skip(1).collect();
+    if paths.is_empty() {
+        eprintln!("Usage: generate_embedded FILE...");
+        exit(2);
+    }
+
+    let mut database = Database::new();
+    let mut version = None;
+
+    for path in &paths {
+        let source = match fs::read_to_string(path) {
+            Ok(source)  => source,
+            Err(e)      => { eprintln!("{}: {}", path, e); exit(1); },
+        };
+
+        if let Some(line) = source.lines().find(|line| line.starts_with("# version ")) {
+            version = Some(line["# version ".len() ..].trim().to_owned());
+        }
+
+        if let Err(e) = database.parse(&source) {
+            eprintln!("{}: {}", path, e);
+            exit(1);
+        }
+    }
+
+    let mut generator = Generator::default();
+    for name in database.zone_names() {
+        let zone = match database.compile(name) {
+            Ok(zone)  => zone,
+            Err(e)    => { eprintln!("{}: {}", name, e); exit(1); },
+        };
+
+        let set = zone.fixed_timespans;
+        let first = generator.timespan(&set.first);
+        let rest = set.rest.iter().map(|&(time, ref timespan)| (time, generator.timespan(timespan))).collect();
+        let extension = set.extension.as_ref().map(|extension| generator.extension(extension));
+        let _ = generator.zones.insert(name.to_owned(), Zone { first, rest, extension });
+    }
+
+    let mut names: Vec<(&str, &str)> = database.zone_names().into_iter().map(|name| (name, name)).collect();
+    for (link, mut target) in database.links() {
+        while !generator.zones.contains_key(target) {
+            target = database.links().into_iter().find(|l| l.0 == target).expect("dangling link").1;
+        }
+
+        names.push((link, target));
+    }
+    names.sort();
+
+    print!("{}", generator.output(version.as_ref().map_or("unknown", |v| &**v), &names));
+}
+
+
+struct Zone {
+    first: usize,
+    rest: Vec<(i64, usize)>,
+    extension: Option<String>,
+}
+
+#[derive(Default)]
+struct Generator {
+    timespans: BTreeMap<(i64, bool, String), usize>,
+    zones: BTreeMap<String, Zone>,
+}
+
+impl Generator {
+
+    /// Returns the index of the constant for the given timespan, adding
+    /// it if this is the first time it has been seen.
+    fn timespan(&mut self, timespan: &FixedTimespan) -> usize {
+        let key = (timespan.offset, timespan.is_dst, timespan.name.to_string());
+        let next = self.timespans.len();
+        *self.timespans.entry(key).or_insert(next)
+    }
+
+    fn extension(&mut self, extension: &PosixTimeZone) -> String {
+        let standard = self.timespan(&extension.standard);
+        match extension.daylight {
+            None => format!("PosixTimeZone {{ standard: T{}, daylight: None }}", standard),
+            Some(ref daylight) => {
+                let timespan = self.timespan(&daylight.timespan);
+                format!("PosixTimeZone {{ standard: T{}, daylight: Some(DaylightRule {{ timespan: T{}, start: {}, end: {} }}) }}",
+                        standard, timespan, rule(&daylight.start), rule(&daylight.end))
+            },
+        }
+    }
+
+    fn output(&self, version: &str, names: &[(&str, &str)]) -> String {
+        let mut out = String::new();
+        writeln!(out, "// This file is generated by examples/generate_embedded.rs from version").unwrap();
+        writeln!(out, "// {} of the IANA time zone database. Do not edit it by hand.", version).unwrap();
+        writeln!(out).unwrap();
+        writeln!(out, "use std::borrow::Cow;").unwrap();
+        writeln!(out).unwrap();
+        writeln!(out, "use cal::datetime::{{Month, Weekday}};").unwrap();
+        writeln!(out, "use cal::zone::{{FixedTimespan, FixedTimespanSet, StaticTimeZone}};").unwrap();
+        writeln!(out, "use cal::zone::posix::{{DaylightRule, PosixTimeZone, RuleDay, TransitionRule}};").unwrap();
+        writeln!(out).unwrap();
+        writeln!(out, "pub const VERSION: &str = {:?};", version).unwrap();
+
+        let mut timespans: Vec<_> = self.timespans.iter().collect();
+        timespans.sort_by_key(|&(_, index)| *index);
+
+        writeln!(out).unwrap();
+        for ((offset, is_dst, name), index) in timespans {
+            writeln!(out, "const T{}: FixedTimespan<'static> = FixedTimespan {{ offset: {}, is_dst: {}, name: Cow::Borrowed({:?}) }};",
+                     index, offset, is_dst, name).unwrap();
+        }
+
+        for (name, zone) in &self.zones {
+            writeln!(out).unwrap();
+            writeln!(out, "static {}: &[(i64, FixedTimespan<'static>)] = &[", identifier(name)).unwrap();
+            for &(time, index) in &zone.rest {
+                writeln!(out, "    ({}, T{}),", time, index).unwrap();
+            }
+            writeln!(out, "];").unwrap();
+        }
+
+        writeln!(out).unwrap();
+        writeln!(out, "/// Every zone and link in the database, sorted by name.").unwrap();
+        writeln!(out, "pub static ZONES: &[StaticTimeZone<'static>] = &[").unwrap();
+        for &(name, target) in names {
+            let zone = &self.zones[target];
+            let extension = zone.extension.as_ref().map_or("None".to_owned(), |e| format!("Some({})", e));
+            writeln!(out, "    StaticTimeZone {{ name: {:?}, fixed_timespans: FixedTimespanSet {{ first: T{}, rest: {}, extension: {} }} }},",
+                     name, zone.first, identifier(target), extension).unwrap();
+        }
+        writeln!(out, "];").unwrap();
+
+        out
+    }
+}
+
+fn rule(rule: &TransitionRule) -> String {
+    let day = match rule.day {
+        RuleDay::JulianIgnoringLeap(day)  => format!("RuleDay::JulianIgnoringLeap({})", day),
+        RuleDay::JulianZero(day)          => format!("RuleDay::JulianZero({})", day),
+        RuleDay::MonthWeekday { month, week, weekday } => {
+            format!("RuleDay::MonthWeekday {{ month: Month::{:?}, week: {}, weekday: Weekday::{:?} }}", month, week, weekday)
+        },
+    };
+
+    format!("TransitionRule {{ day: {}, time: {} }}", day, rule.time)
+}
+
+/// Turns a zone name, such as “America/Port-au-Prince” or “Etc/GMT+5”,
+/// into the name of a static.
+fn identifier(name: &str) -> String {
+    name.replace('+', "_PLUS_")
+        .replace('-', "_MINUS_")
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_uppercase() } else { '_' })
+        .collect()
+}
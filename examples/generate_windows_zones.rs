@@ -0,0 +1,96 @@
+//! Generates the table used by the `windows` zone module.
+//!
+//! Run it with the path to the `windowsZones.xml` file from the Unicode
+//! CLDR supplemental data, and write its output to
+//! `src/cal/zone/windows/data.rs`:
+//!
+//! ```sh
+//! cargo run --example generate_windows_zones -- common/supplemental/windowsZones.xml > src/cal/zone/windows/data.rs
+//! ```
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::process::exit;
+
+
+fn main() {
+    let path = match env::args().nth(1) {
+        Some(path)  => path,
+        None        => { eprintln!("Usage: generate_windows_zones FILE"); exit(2); },
+    };
+
+    let source = match fs::read_to_string(&path) {
+        Ok(source)  => source,
+        Err(e)      => { eprintln!("{}: {}", path, e); exit(1); },
+    };
+
+    let mut mappings = BTreeMap::new();
+    let mut zones = BTreeMap::new();
+
+    for (number, line) in source.lines().enumerate() {
+        let line = line.trim();
+        if !line.starts_with("<mapZone ") {
+            continue;
+        }
+
+        let (windows, territory, types) = match (attribute(line, "other"), attribute(line, "territory"), attribute(line, "type")) {
+            (Some(w), Some(t), Some(z))  => (w, t, z),
+            _                            => { eprintln!("{}:{}: missing attribute", path, number + 1); exit(1); },
+        };
+
+        let types: Vec<String> = types.split_whitespace().map(String::from).collect();
+        for zone in &types {
+            // The same zone is often listed for both a territory and the
+            // whole world, but should only ever map to one Windows zone.
+            if let Some(existing) = zones.insert(zone.clone(), windows.clone()) {
+                if existing != windows {
+                    eprintln!("{}:{}: {} is in both {:?} and {:?}", path, number + 1, zone, existing, windows);
+                    exit(1);
+                }
+            }
+        }
+
+        let _ = mappings.insert((windows, territory), types);
+    }
+
+    print!("{}", output(&mappings, &zones));
+}
+
+/// Returns the value of the given attribute in an XML tag, unescaped.
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let start = tag.find(&format!(" {}=\"", name))? + name.len() + 3;
+    let length = tag[start ..].find('"')?;
+
+    Some(tag[start .. start + length]
+         .replace("&lt;", "<").replace("&gt;", ">").replace("&quot;", "\"")
+         .replace("&apos;", "'").replace("&amp;", "&"))
+}
+
+fn output(mappings: &BTreeMap<(String, String), Vec<String>>, zones: &BTreeMap<String, String>) -> String {
+    let mut out = String::new();
+    writeln!(out, "// This file is generated by examples/generate_windows_zones.rs from the").unwrap();
+    writeln!(out, "// CLDR windowsZones.xml supplemental data. Do not edit it by hand.").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "/// Every Windows zone ID and territory, with the zones used there, sorted").unwrap();
+    writeln!(out, "/// by Windows zone ID and then territory. The territory “001” gives the").unwrap();
+    writeln!(out, "/// zone to use when the territory isn’t known.").unwrap();
+    writeln!(out, "pub static MAPPINGS: &[(&str, &str, &[&str])] = &[").unwrap();
+    for ((windows, territory), types) in mappings {
+        let types: Vec<String> = types.iter().map(|t| format!("{:?}", t)).collect();
+        writeln!(out, "    ({:?}, {:?}, &[ {} ]),", windows, territory, types.join(", ")).unwrap();
+    }
+    writeln!(out, "];").unwrap();
+
+    writeln!(out).unwrap();
+    writeln!(out, "/// Every zone name in the mappings, with its Windows zone ID, sorted by").unwrap();
+    writeln!(out, "/// zone name.").unwrap();
+    writeln!(out, "pub static ZONES: &[(&str, &str)] = &[").unwrap();
+    for (zone, windows) in zones {
+        writeln!(out, "    ({:?}, {:?}),", zone, windows).unwrap();
+    }
+    writeln!(out, "];").unwrap();
+
+    out
+}
@@ -329,6 +329,12 @@ const DAYS_IN_400Y: i64 = 365 * 400 + 97;
 /// are simply ignored.
 const SECONDS_IN_DAY: i64 = 86400;
 
+/// The earliest and latest years that dates can be created in. These keep
+/// the number of seconds between any datetime and the Unix epoch, and
+/// every calculation along the way, well within the range of an `i64`.
+const MIN_YEAR: i64 = -999_999_999;
+const MAX_YEAR: i64 =  999_999_999;
+
 
 /// Number of days between  **1st January, 1970** and **1st March, 2000**.
 ///
@@ -394,7 +400,7 @@ pub struct LocalTime {
     hour:   i8,
     minute: i8,
     second: i8,
-    millisecond: i16,
+    nanosecond: i32,
 }
 
 /// A **local date-time** is an exact instant on the timeline, *without a
@@ -408,6 +414,22 @@ pub struct LocalDateTime {
 
 impl LocalDate {
 
+    /// The earliest date that can be created: the 1st of January in the
+    /// year -999,999,999.
+    pub const MIN: Self = Self {
+        ymd:      YMD { year: MIN_YEAR, month: January, day: 1 },
+        yearday:  1,
+        weekday:  Monday,
+    };
+
+    /// The latest date that can be created: the 31st of December in the
+    /// year 999,999,999.
+    pub const MAX: Self = Self {
+        ymd:      YMD { year: MAX_YEAR, month: December, day: 31 },
+        yearday:  365,
+        weekday:  Friday,
+    };
+
     /// Creates a new local date instance from the given year, month, and day
     /// fields.
     ///
@@ -430,6 +452,7 @@ impl LocalDate {
     /// assert!(LocalDate::ymd(2100, Month::February, 29).is_err());
     /// ```
     pub fn ymd(year: i64, month: Month, day: i8) -> Result<Self, Error> {
+        check_year(year)?;
         YMD { year, month, day }
             .to_days_since_epoch()
             .map(|days| Self::from_days_since_epoch(days - EPOCH_DIFFERENCE))
@@ -455,6 +478,7 @@ impl LocalDate {
     /// assert_eq!(date.day(), 13);
     /// ```
     pub fn yd(year: i64, yearday: i64) -> Result<Self, Error> {
+        check_year(year)?;
         if yearday.is_within(0..367) {
             let jan_1 = YMD { year, month: January, day: 1 };
             let days = jan_1.to_days_since_epoch()?;
@@ -505,10 +529,14 @@ impl LocalDate {
     /// assert_eq!(date.weekday(), Weekday::Sunday);
     /// ```
     pub fn ywd(year: i64, week: i64, weekday: Weekday) -> Result<Self, Error> {
+        check_year(year)?;
+
         let jan_4 = YMD { year, month: January, day: 4 };
         let correction = days_to_weekday(jan_4.to_days_since_epoch().unwrap() - EPOCH_DIFFERENCE).days_from_monday_as_one() as i64 + 3;
 
-        let yearday = 7 * week + weekday.days_from_monday_as_one() as i64 - correction;
+        let yearday = week.checked_mul(7)
+                          .and_then(|days| days.checked_add(weekday.days_from_monday_as_one() as i64 - correction))
+                          .ok_or(Error::OutOfRange)?;
 
         if yearday <= 0 {
             let days_in_year = if Year(year - 1).is_leap_year() { 366 } else { 365 };
@@ -640,6 +668,49 @@ impl LocalDate {
         }
     }
 
+    /// Returns the date the given number of days after this one, or `None`
+    /// if it would be outside the range from `MIN` to `MAX`.
+    pub fn checked_add_days(&self, days: i64) -> Option<Self> {
+        let days = self.days_since_epoch().checked_add(days)?;
+        if days.is_within(Self::MIN.days_since_epoch() .. Self::MAX.days_since_epoch() + 1) {
+            Some(Self::from_days_since_epoch(days))
+        }
+        else {
+            None
+        }
+    }
+
+    /// Returns the date the given number of days before this one, or
+    /// `None` if it would be outside the range from `MIN` to `MAX`.
+    pub fn checked_sub_days(&self, days: i64) -> Option<Self> {
+        self.checked_add_days(days.checked_neg()?)
+    }
+
+    /// Returns the date the given number of days after this one, or `MIN`
+    /// or `MAX` if it would be outside the range between them.
+    pub fn saturating_add_days(&self, days: i64) -> Self {
+        match self.checked_add_days(days) {
+            Some(date)         => date,
+            None if days < 0   => Self::MIN,
+            None               => Self::MAX,
+        }
+    }
+
+    /// Returns the date the given number of days before this one, or `MIN`
+    /// or `MAX` if it would be outside the range between them.
+    pub fn saturating_sub_days(&self, days: i64) -> Self {
+        match self.checked_sub_days(days) {
+            Some(date)         => date,
+            None if days < 0   => Self::MAX,
+            None               => Self::MIN,
+        }
+    }
+
+    /// Returns the number of days between the EPOCH and this date.
+    fn days_since_epoch(&self) -> i64 {
+        self.ymd.to_days_since_epoch().unwrap() - EPOCH_DIFFERENCE
+    }
+
     /// Creates a new datestamp instance with the given year, month, day,
     /// weekday, and yearday fields.
     ///
@@ -697,6 +768,12 @@ impl Ord for LocalDate {
 
 impl LocalTime {
 
+    /// The earliest time of day, midnight.
+    pub const MIN: Self = Self { hour: 0, minute: 0, second: 0, nanosecond: 0 };
+
+    /// The latest time of day, the last nanosecond before midnight.
+    pub const MAX: Self = Self { hour: 23, minute: 59, second: 59, nanosecond: 999_999_999 };
+
     /// Computes the number of hours, minutes, and seconds, based on the
     /// number of seconds that have elapsed since midnight.
     pub fn from_seconds_since_midnight(seconds: i64) -> Self {
@@ -706,28 +783,35 @@ impl LocalTime {
     /// Computes the number of hours, minutes, and seconds, based on the
     /// number of seconds that have elapsed since midnight.
     pub fn from_seconds_and_milliseconds_since_midnight(seconds: i64, millisecond_of_second: i16) -> Self {
+        Self::from_seconds_and_nanoseconds_since_midnight(seconds, i32::from(millisecond_of_second) * 1_000_000)
+    }
+
+    /// Computes the number of hours, minutes, and seconds, based on the
+    /// number of seconds that have elapsed since midnight, keeping the
+    /// given nanosecond of the second.
+    pub fn from_seconds_and_nanoseconds_since_midnight(seconds: i64, nanosecond_of_second: i32) -> Self {
         Self {
             hour:   (seconds / 60 / 60) as i8,
             minute: (seconds / 60 % 60) as i8,
             second: (seconds % 60) as i8,
-            millisecond: millisecond_of_second,
+            nanosecond: nanosecond_of_second,
         }
     }
 
     /// Returns the time at midnight, with all fields initialised to 0.
     pub fn midnight() -> Self {
-        Self { hour: 0, minute: 0, second: 0, millisecond: 0 }
+        Self { hour: 0, minute: 0, second: 0, nanosecond: 0 }
     }
 
     /// Creates a new timestamp instance with the given hour and minute
-    /// fields. The second and millisecond fields are set to 0.
+    /// fields. The second and nanosecond fields are set to 0.
     ///
     /// The values are checked for validity before instantiation, and
     /// passing in values out of range will return an `Err`.
     pub fn hm(hour: i8, minute: i8) -> Result<Self, Error> {
         if (hour.is_within(0..24) && minute.is_within(0..60))
         || (hour == 24 && minute == 00) {
-            Ok(Self { hour, minute, second: 0, millisecond: 0 })
+            Ok(Self { hour, minute, second: 0, nanosecond: 0 })
         }
         else {
             Err(Error::OutOfRange)
@@ -735,14 +819,14 @@ impl LocalTime {
     }
 
     /// Creates a new timestamp instance with the given hour, minute, and
-    /// second fields. The millisecond field is set to 0.
+    /// second fields. The nanosecond field is set to 0.
     ///
     /// The values are checked for validity before instantiation, and
     /// passing in values out of range will return an `Err`.
     pub fn hms(hour: i8, minute: i8, second: i8) -> Result<Self, Error> {
         if (hour.is_within(0..24) && minute.is_within(0..60) && second.is_within(0..60))
         || (hour == 24 && minute == 00 && second == 00) {
-            Ok(Self { hour, minute, second, millisecond: 0 })
+            Ok(Self { hour, minute, second, nanosecond: 0 })
         }
         else {
             Err(Error::OutOfRange)
@@ -755,10 +839,24 @@ impl LocalTime {
     /// The values are checked for validity before instantiation, and
     /// passing in values out of range will return an `Err`.
     pub fn hms_ms(hour: i8, minute: i8, second: i8, millisecond: i16) -> Result<Self, Error> {
+        if millisecond.is_within(0..1000) {
+            Self::hms_ns(hour, minute, second, i32::from(millisecond) * 1_000_000)
+        }
+        else {
+            Err(Error::OutOfRange)
+        }
+    }
+
+    /// Creates a new timestamp instance with the given hour, minute,
+    /// second, and nanosecond fields.
+    ///
+    /// The values are checked for validity before instantiation, and
+    /// passing in values out of range will return an `Err`.
+    pub fn hms_ns(hour: i8, minute: i8, second: i8, nanosecond: i32) -> Result<Self, Error> {
         if hour.is_within(0..24)   && minute.is_within(0..60)
-        && second.is_within(0..60) && millisecond.is_within(0..1000)
+        && second.is_within(0..60) && nanosecond.is_within(0..1_000_000_000)
         {
-            Ok(Self { hour, minute, second, millisecond })
+            Ok(Self { hour, minute, second, nanosecond })
         }
         else {
             Err(Error::OutOfRange)
@@ -766,7 +864,7 @@ impl LocalTime {
     }
 
     /// Calculate the number of seconds since midnight this time is at,
-    /// ignoring milliseconds.
+    /// ignoring fractions of a second.
     pub fn to_seconds(&self) -> i64 {
         self.hour as i64 * 3600
             + self.minute as i64 * 60
@@ -778,7 +876,8 @@ impl TimePiece for LocalTime {
     fn hour(&self) -> i8 { self.hour }
     fn minute(&self) -> i8 { self.minute }
     fn second(&self) -> i8 { self.second }
-    fn millisecond(&self) -> i16 { self.millisecond }
+    fn millisecond(&self) -> i16 { (self.nanosecond / 1_000_000) as i16 }
+    fn nanosecond(&self) -> i32 { self.nanosecond }
 }
 
 impl fmt::Debug for LocalTime {
@@ -787,18 +886,46 @@ impl fmt::Debug for LocalTime {
     }
 }
 
+/// Subtracting one time of day from another gives the length of time
+/// between them on the same day, which is negative if the other time is
+/// later.
+impl Sub<LocalTime> for LocalTime {
+    type Output = Duration;
+
+    fn sub(self, other: Self) -> Duration {
+        Duration::of_ns(self.to_seconds() - other.to_seconds(), self.nanosecond - other.nanosecond)
+    }
+}
+
 
 impl LocalDateTime {
 
+    /// The earliest date-time that can be created: midnight on the 1st of
+    /// January, -999,999,999.
+    pub const MIN: Self = Self { date: LocalDate::MIN, time: LocalTime::MIN };
+
+    /// The latest date-time that can be created: the last nanosecond of
+    /// the 31st of December, 999,999,999.
+    pub const MAX: Self = Self { date: LocalDate::MAX, time: LocalTime::MAX };
+
     /// Computes a complete date-time based on the values in the given
     /// Instant parameter.
     pub fn from_instant(instant: Instant) -> Self {
-        Self::at_ms(instant.seconds(), instant.milliseconds())
+        // Split the input value into days and seconds before moving it to
+        // the EPOCH, so that it can’t overflow, and let LocalDate and
+        // LocalTime do all the hard work.
+        let (days, secs) = split_cycles(instant.seconds(), SECONDS_IN_DAY);
+
+        Self {
+            date: LocalDate::from_days_since_epoch(days - EPOCH_DIFFERENCE),
+            time: LocalTime::from_seconds_and_nanoseconds_since_midnight(secs, instant.nanoseconds()),
+        }
     }
 
     /// Computes a complete date-time based on the number of seconds that
     /// have elapsed since **midnight, 1st January, 1970**, setting the
-    /// number of milliseconds to 0.
+    /// number of milliseconds to 0. Seconds past `MIN` or `MAX` are
+    /// clamped to them.
     pub fn at(seconds_since_1970_epoch: i64) -> Self {
         Self::at_ms(seconds_since_1970_epoch, 0)
     }
@@ -806,16 +933,14 @@ impl LocalDateTime {
     /// Computes a complete date-time based on the number of seconds that
     /// have elapsed since **midnight, 1st January, 1970**,
     pub fn at_ms(seconds_since_1970_epoch: i64, millisecond_of_second: i16) -> Self {
-        let seconds = seconds_since_1970_epoch - EPOCH_DIFFERENCE * SECONDS_IN_DAY;
-
-        // Just split the input value into days and seconds, and let
-        // LocalDate and LocalTime do all the hard work.
-        let (days, secs) = split_cycles(seconds, SECONDS_IN_DAY);
+        Self::from_instant(Instant::at_ms(seconds_since_1970_epoch, millisecond_of_second))
+    }
 
-        Self {
-            date: LocalDate::from_days_since_epoch(days),
-            time: LocalTime::from_seconds_and_milliseconds_since_midnight(secs, millisecond_of_second),
-        }
+    /// Computes a complete date-time based on the number of seconds that
+    /// have elapsed since **midnight, 1st January, 1970**, along with the
+    /// nanosecond of that second.
+    pub fn at_ns(seconds_since_1970_epoch: i64, nanosecond_of_second: i32) -> Self {
+        Self::from_instant(Instant::at_ns(seconds_since_1970_epoch, nanosecond_of_second))
     }
 
     /// Creates a new local date time from a local date and a local time.
@@ -839,18 +964,46 @@ impl LocalDateTime {
     /// Creates a new date-time stamp set to the current time.
     #[cfg_attr(target_os = "redox", allow(unused_unsafe))]
     pub fn now() -> Self {
-        let (s, ms) = unsafe { sys_time() };
-        Self::at_ms(s, ms)
+        let (s, ns) = unsafe { sys_time() };
+        Self::at_ns(s, ns)
     }
 
     pub fn to_instant(&self) -> Instant {
-        let seconds = self.date.ymd.to_days_since_epoch().unwrap() * SECONDS_IN_DAY + self.time.to_seconds();
-        Instant::at_ms(seconds, self.time.millisecond)
+        // Any datetime made from an instant converts back to it, but the
+        // start of its day can be before the earliest `i64` number of
+        // seconds, so the sum is done with more room.
+        let days = self.date.ymd.to_days_since_epoch().unwrap();
+        let seconds = i128::from(days) * i128::from(SECONDS_IN_DAY) + i128::from(self.time.to_seconds());
+        Instant::at_ns(seconds as i64, self.time.nanosecond)
     }
 
     pub fn add_seconds(&self, seconds: i64) -> Self {
         Self::from_instant(self.to_instant() + Duration::of(seconds))
     }
+
+    /// Adds a duration to this date-time, returning `None` if the result
+    /// would be outside the range from `MIN` to `MAX`.
+    pub fn checked_add(&self, duration: Duration) -> Option<Self> {
+        self.to_instant().checked_add(duration).map(Self::from_instant)
+    }
+
+    /// Subtracts a duration from this date-time, returning `None` if the
+    /// result would be outside the range from `MIN` to `MAX`.
+    pub fn checked_sub(&self, duration: Duration) -> Option<Self> {
+        self.to_instant().checked_sub(duration).map(Self::from_instant)
+    }
+
+    /// Adds a duration to this date-time, returning `MIN` or `MAX` if the
+    /// result would be outside the range between them.
+    pub fn saturating_add(&self, duration: Duration) -> Self {
+        Self::from_instant(self.to_instant().saturating_add(duration))
+    }
+
+    /// Subtracts a duration from this date-time, returning `MIN` or `MAX`
+    /// if the result would be outside the range between them.
+    pub fn saturating_sub(&self, duration: Duration) -> Self {
+        Self::from_instant(self.to_instant().saturating_sub(duration))
+    }
 }
 
 impl DatePiece for LocalDateTime {
@@ -865,7 +1018,8 @@ impl TimePiece for LocalDateTime {
     fn hour(&self) -> i8 { self.time.hour }
     fn minute(&self) -> i8 { self.time.minute }
     fn second(&self) -> i8 { self.time.second }
-    fn millisecond(&self) -> i16 { self.time.millisecond }
+    fn millisecond(&self) -> i16 { self.time.millisecond() }
+    fn nanosecond(&self) -> i32 { self.time.nanosecond }
 }
 
 impl fmt::Debug for LocalDateTime {
@@ -874,19 +1028,33 @@ impl fmt::Debug for LocalDateTime {
     }
 }
 
+/// Adding a duration panics if the result would be past `MIN` or `MAX`;
+/// `checked_add` and `saturating_add` don’t.
 impl Add<Duration> for LocalDateTime {
     type Output = Self;
 
     fn add(self, duration: Duration) -> Self {
-        Self::from_instant(self.to_instant() + duration)
+        self.checked_add(duration).expect("overflow when adding duration to datetime")
     }
 }
 
+/// Subtracting a duration panics if the result would be past `MIN` or
+/// `MAX`; `checked_sub` and `saturating_sub` don’t.
 impl Sub<Duration> for LocalDateTime {
     type Output = Self;
 
     fn sub(self, duration: Duration) -> Self {
-        Self::from_instant(self.to_instant() - duration)
+        self.checked_sub(duration).expect("overflow when subtracting duration from datetime")
+    }
+}
+
+/// Subtracting one date-time from another gives the length of time between
+/// them, which is negative if the other date-time is later.
+impl Sub<LocalDateTime> for LocalDateTime {
+    type Output = Duration;
+
+    fn sub(self, other: Self) -> Duration {
+        self.to_instant() - other.to_instant()
     }
 }
 
@@ -962,6 +1130,12 @@ impl YMD {
     }
 }
 
+/// Returns an error if dates can’t be created in the given year.
+fn check_year(year: i64) -> Result<(), Error> {
+    if year.is_within(MIN_YEAR .. MAX_YEAR + 1) { Ok(()) }
+                                          else { Err(Error::OutOfRange) }
+}
+
 /// Computes the weekday, given the number of days that have passed
 /// since the EPOCH.
 fn days_to_weekday(days: i64) -> Weekday {
@@ -1245,4 +1419,68 @@ mod test {
             assert_eq!(debugged, "LocalDateTime(2009-02-13T23:31:30.000)");
         }
     }
+
+    mod bounds {
+        use super::*;
+        use super::super::{MIN_YEAR, MAX_YEAR};
+        use cal::TimePiece;
+        use duration::Duration;
+        use instant::Instant;
+
+        #[test]
+        fn constants() {
+            let min = LocalDate::ymd(MIN_YEAR, Month::January, 1).unwrap();
+            let max = LocalDate::ymd(MAX_YEAR, Month::December, 31).unwrap();
+            assert_eq!((min.weekday(), min.yearday()), (LocalDate::MIN.weekday(), LocalDate::MIN.yearday()));
+            assert_eq!((max.weekday(), max.yearday()), (LocalDate::MAX.weekday(), LocalDate::MAX.yearday()));
+        }
+
+        #[test]
+        fn instants() {
+            assert_eq!(LocalDateTime::MIN.to_instant(), Instant::MIN);
+            assert_eq!(LocalDateTime::MAX.to_instant(), Instant::MAX);
+            assert_eq!(LocalDateTime::from_instant(Instant::MIN), LocalDateTime::MIN);
+            assert_eq!(LocalDateTime::from_instant(Instant::MAX), LocalDateTime::MAX);
+            assert_eq!(LocalTime::MAX.nanosecond(), 999_999_999);
+        }
+
+        #[test]
+        fn out_of_range_years() {
+            assert!(LocalDate::ymd(MAX_YEAR + 1, Month::January, 1).is_err());
+            assert!(LocalDate::ymd(i64::MIN, Month::January, 1).is_err());
+            assert!(LocalDate::yd(i64::MAX, 1).is_err());
+            assert!(LocalDate::ywd(i64::MIN, 1, Weekday::Monday).is_err());
+            assert!(LocalDate::ywd(2015, i64::MAX, Weekday::Monday).is_err());
+        }
+
+        #[test]
+        fn extreme_seconds_are_clamped() {
+            assert_eq!(LocalDateTime::at(i64::MIN), LocalDateTime::MIN);
+            assert_eq!(LocalDateTime::at(i64::MAX), LocalDateTime::MAX);
+            assert_eq!(LocalDateTime::at(i64::MAX) + Duration::zero(), LocalDateTime::MAX);
+        }
+
+        #[test]
+        fn days() {
+            let date = LocalDate::ymd(2024, Month::February, 28).unwrap();
+            assert_eq!(date.checked_add_days(2), LocalDate::ymd(2024, Month::March, 1).ok());
+            assert_eq!(date.checked_sub_days(59), LocalDate::ymd(2023, Month::December, 31).ok());
+            assert_eq!(LocalDate::MAX.checked_add_days(1), None);
+            assert_eq!(LocalDate::MIN.checked_sub_days(1), None);
+            assert_eq!(date.checked_sub_days(i64::MIN), None);
+            assert_eq!(date.saturating_add_days(i64::MAX), LocalDate::MAX);
+            assert_eq!(date.saturating_sub_days(i64::MAX), LocalDate::MIN);
+            assert_eq!(date.saturating_sub_days(i64::MIN), LocalDate::MAX);
+        }
+
+        #[test]
+        fn datetimes() {
+            let then = LocalDateTime::at(0);
+            assert_eq!(then.checked_add(Duration::of(1)), Some(LocalDateTime::at(1)));
+            assert_eq!(LocalDateTime::MAX.checked_add(Duration::of_ns(0, 1)), None);
+            assert_eq!(LocalDateTime::MIN.checked_sub(Duration::of_ns(0, 1)), None);
+            assert_eq!(then.saturating_add(Duration::MAX), LocalDateTime::MAX);
+            assert_eq!(then.saturating_sub(Duration::MAX), LocalDateTime::MIN);
+        }
+    }
 }
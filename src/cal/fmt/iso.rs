@@ -1,6 +1,7 @@
 use std::fmt;
 use cal::{LocalDate, LocalTime, LocalDateTime, DatePiece, TimePiece};
 use cal::{Offset, OffsetDateTime};
+use cal::zone::{ZonedDateTime, round_offset_to_minutes};
 use util::RangeExt;
 
 
@@ -34,9 +35,22 @@ impl ISO for LocalDate {
     }
 }
 
+/// Times are written with as many fractional digits as they need, in
+/// groups of three: milliseconds, microseconds, or nanoseconds.
 impl ISO for LocalTime {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{:02}:{:02}:{:02}.{:03}", self.hour(), self.minute(), self.second(), self.millisecond())
+        write!(f, "{:02}:{:02}:{:02}", self.hour(), self.minute(), self.second())?;
+
+        let nanosecond = self.nanosecond();
+        if nanosecond % 1_000_000 == 0 {
+            write!(f, ".{:03}", nanosecond / 1_000_000)
+        }
+        else if nanosecond % 1_000 == 0 {
+            write!(f, ".{:06}", nanosecond / 1_000)
+        }
+        else {
+            write!(f, ".{:09}", nanosecond)
+        }
     }
 }
 
@@ -70,3 +84,26 @@ impl ISO for OffsetDateTime {
         write!(f, "{}{}", self.local.iso(), self.offset.iso())
     }
 }
+
+/// Zoned datetimes are written in the format of RFC 9557: the local time,
+/// the offset in effect, and the zone’s name in brackets, such as
+/// `2026-03-29T01:30:00.000+01:00[Europe/London]`. Zones without a name
+/// only get the offset.
+///
+/// Offsets with seconds, such as London’s local mean time of −0:01:15,
+/// are rounded to the nearest minute, as the format has no room for them.
+/// Parsing accepts the rounded offset for such a time.
+impl<'a> ISO for ZonedDateTime<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let offset = round_offset_to_minutes(self.offset());
+        let sign = if offset < 0 { "-" } else { "+" };
+        let (hours, minutes) = (offset.abs() / 3600, offset.abs() / 60 % 60);
+
+        write!(f, "{}{}{:02}:{:02}", self.local_datetime().iso(), sign, hours, minutes)?;
+
+        match self.zone_name() {
+            Some(name)  => write!(f, "[{}]", name),
+            None        => Ok(()),
+        }
+    }
+}
@@ -67,4 +67,15 @@ pub trait TimePiece {
 
     /// The millisecond of the second.
     fn millisecond(&self) -> i16;
+
+    /// The microsecond of the second.
+    fn microsecond(&self) -> i32 {
+        self.nanosecond() / 1000
+    }
+
+    /// The nanosecond of the second. Values that only store milliseconds
+    /// can leave this out, and have it worked out from `millisecond`.
+    fn nanosecond(&self) -> i32 {
+        i32::from(self.millisecond()) * 1_000_000
+    }
 }
@@ -2,8 +2,10 @@
 
 use std::error::Error as ErrorTrait;
 use std::fmt;
+use std::ops::Sub;
 
 use duration::Duration;
+use instant::Instant;
 use cal::{DatePiece, TimePiece};
 use cal::datetime::{LocalDateTime, Month, Weekday, Error as DateTimeError};
 use cal::fmt::ISO;
@@ -133,6 +135,26 @@ pub struct OffsetDateTime {
     pub offset: Offset,
 }
 
+impl OffsetDateTime {
+
+    /// Returns the instant this datetime refers to: its local time, moved
+    /// back by the offset.
+    pub fn to_instant(&self) -> Instant {
+        let offset = self.offset.offset_seconds.unwrap_or(0);
+        self.local.to_instant() - Duration::of(i64::from(offset))
+    }
+}
+
+/// Subtracting one offset datetime from another gives the length of time
+/// between the instants they refer to, so their offsets needn’t match.
+impl Sub<OffsetDateTime> for OffsetDateTime {
+    type Output = Duration;
+
+    fn sub(self, other: Self) -> Duration {
+        self.to_instant() - other.to_instant()
+    }
+}
+
 impl DatePiece for OffsetDateTime {
     fn year(&self) -> i64 {
         self.offset.adjust(self.local).year()
@@ -171,6 +193,10 @@ impl TimePiece for OffsetDateTime {
     fn millisecond(&self) -> i16 {
         self.offset.adjust(self.local).millisecond()
     }
+
+    fn nanosecond(&self) -> i32 {
+        self.offset.adjust(self.local).nanosecond()
+    }
 }
 
 impl fmt::Debug for OffsetDateTime {
@@ -245,4 +271,14 @@ mod test {
         let debugged = format!("{:?}", offset.transform_date(then));
         assert_eq!(debugged, "OffsetDateTime(2009-02-13T23:31:30.000+00:25:21)");
     }
+
+    #[test]
+    fn offset_date_time_instant() {
+        use cal::LocalDateTime;
+        use instant::Instant;
+
+        let offset = Offset::of_hours_and_minutes(1, 0).unwrap();
+        assert_eq!(offset.transform_date(LocalDateTime::at(3600)).to_instant(), Instant::at(0));
+        assert_eq!(Offset::utc().transform_date(LocalDateTime::at(3600)).to_instant(), Instant::at(3600));
+    }
 }
@@ -2,10 +2,17 @@ use std::error::Error as ErrorTrait;
 use std::fmt;
 use std::str::FromStr;
 
+use std::borrow::Cow;
+use std::sync::Arc;
+
 use iso8601;
 
+use duration::Duration;
 use cal::datetime::{LocalDate, LocalTime, LocalDateTime, Month, Weekday, Error as DateTimeError};
 use cal::offset::{Offset, OffsetDateTime, Error as OffsetError};
+use cal::zone::{TimeZone, TimeZoneSource, ZonedDateTime, FixedTimespan, LocalTimes, Disambiguation, OffsetMismatch, ParseError as ZonedError};
+use cal::zone::round_offset_to_minutes;
+use cal::zone::runtime::{OwnedTimeZone, OwnedFixedTimespanSet};
 
 
 impl FromStr for LocalDate {
@@ -24,7 +31,7 @@ impl FromStr for LocalTime {
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         match iso8601::time(input) {
-            Ok(fields)  => fields_to_time(fields).map_err(Error::Date),
+            Ok(fields)  => fields_to_time(fields, input).map_err(Error::Date),
             Err(e)      => Err(Error::Parse(e)),
         }
     }
@@ -40,7 +47,7 @@ impl FromStr for LocalDateTime {
         };
 
         let date = fields_to_date(fields.date).map_err(Error::Date)?;
-        let time = fields_to_time(fields.time).map_err(Error::Date)?;
+        let time = fields_to_time(fields.time, input).map_err(Error::Date)?;
         Ok(Self::new(date, time))
     }
 }
@@ -55,12 +62,178 @@ impl FromStr for OffsetDateTime {
         };
 
         let date   = fields_to_date(fields.date).map_err(|e| Error::Date(OffsetError::Date(e)))?;
-        let time   = fields_to_time(fields.time).map_err(|e| Error::Date(OffsetError::Date(e)))?;
+        let time   = fields_to_time(fields.time, input).map_err(|e| Error::Date(OffsetError::Date(e)))?;
         let offset = Offset::of_hours_and_minutes(fields.time.tz_offset_hours as i8, fields.time.tz_offset_minutes as i8).map_err(Error::Date)?;
         Ok(offset.transform_date(LocalDateTime::new(date, time)))
     }
 }
 
+impl FromStr for ZonedDateTime<'static> {
+    type Err = Error<ZonedError>;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        zoned_datetime(input, OffsetMismatch::Reject)
+    }
+}
+
+
+/// Parses a zoned datetime in the format of RFC 9557, such as
+/// `2026-03-29T01:30:00+01:00[Europe/London]`.
+///
+/// The offset can be left out, in which case the local time is resolved
+/// in the zone, or be `Z`, in which case the instant is known but the
+/// local offset isn’t, so it never mismatches. Annotations after the zone,
+/// such as `[u-ca=gregory]`, are ignored unless they are marked critical.
+pub fn zoned_datetime(input: &str, mismatch: OffsetMismatch) -> Result<ZonedDateTime<'static>, Error<ZonedError>> {
+    let bracket = input.find('[').ok_or(Error::Date(ZonedError::MissingZone))?;
+    let (datetime, annotations) = input.split_at(bracket);
+    let zone = annotations_to_zone(annotations).map_err(Error::Date)?;
+
+    let (datetime, offset) = split_offset(datetime).map_err(Error::Date)?;
+    let local = match datetime.parse::<LocalDateTime>() {
+        Ok(local)                => local,
+        Err(Error::Date(e))      => return Err(Error::Date(ZonedError::Date(e))),
+        Err(Error::Parse(e))     => return Err(Error::Parse(e)),
+    };
+
+    let offset = match offset {
+        ParsedOffset::Utc         => return Ok(zone.at(local.to_instant())),
+        ParsedOffset::Missing     => return Ok(resolve_compatible(&zone, local)),
+        ParsedOffset::Seconds(s)  => s,
+    };
+
+    let candidates = match zone.convert_local(local) {
+        LocalTimes::Precise(zoned)                => vec![ zoned ],
+        LocalTimes::Ambiguous { earlier, later }  => vec![ earlier, later ],
+        LocalTimes::Impossible                    => Vec::new(),
+    };
+
+    // Offsets with seconds get written rounded to the minute, so a whole
+    // minute offset also matches one that rounds to it.
+    let matches = |zoned: &ZonedDateTime| zoned.offset() == offset
+        || (offset % 60 == 0 && round_offset_to_minutes(zoned.offset()) == offset);
+
+    if let Some(zoned) = candidates.into_iter().find(matches) {
+        return Ok(zoned);
+    }
+
+    match mismatch {
+        OffsetMismatch::Reject        => Err(Error::Date(ZonedError::OffsetMismatch { local, offset })),
+        OffsetMismatch::PreferOffset  => Ok(zone.at((local - Duration::of(offset)).to_instant())),
+        OffsetMismatch::PreferZone    => Ok(resolve_compatible(&zone, local)),
+    }
+}
+
+fn resolve_compatible(zone: &TimeZone, local: LocalDateTime) -> ZonedDateTime<'static> {
+    zone.resolve_local(local, Disambiguation::Compatible)
+        .expect("compatible disambiguation never fails")
+}
+
+/// The offset at the end of a datetime, if any.
+enum ParsedOffset {
+    Missing,
+    Utc,
+    Seconds(i64),
+}
+
+/// Splits the offset off the end of a datetime.
+fn split_offset(datetime: &str) -> Result<(&str, ParsedOffset), ZonedError> {
+    let time_start = match datetime.find(&['T', 't'][..]) {
+        Some(index)  => index,
+        None         => return Ok((datetime, ParsedOffset::Missing)),
+    };
+
+    if datetime.ends_with('Z') || datetime.ends_with('z') {
+        return Ok((&datetime[.. datetime.len() - 1], ParsedOffset::Utc));
+    }
+
+    match datetime[time_start ..].rfind(&['+', '-'][..]) {
+        Some(index) => {
+            let (datetime, offset) = datetime.split_at(time_start + index);
+            Ok((datetime, ParsedOffset::Seconds(parse_offset(offset)?)))
+        },
+        None => Ok((datetime, ParsedOffset::Missing)),
+    }
+}
+
+/// Parses an offset such as `+01:00`, `-0430`, or `+00:01:15` into a
+/// number of seconds.
+fn parse_offset(input: &str) -> Result<i64, ZonedError> {
+    let invalid = || ZonedError::InvalidOffset(input.to_owned());
+
+    let (sign, rest) = match input.as_bytes().first() {
+        Some(b'+')  => (1, &input[1..]),
+        Some(b'-')  => (-1, &input[1..]),
+        _           => return Err(invalid()),
+    };
+
+    let digits: String = rest.chars().filter(|&c| c != ':').collect();
+    if !digits.bytes().all(|b| b.is_ascii_digit()) || ![2, 4, 6].contains(&digits.len()) {
+        return Err(invalid());
+    }
+
+    // Colons either separate every field or none of them.
+    let colons = rest.len() - digits.len();
+    if colons != 0 && colons != digits.len() / 2 - 1 {
+        return Err(invalid());
+    }
+
+    let field = |i: usize| digits.get(i .. i + 2).map_or(0, |d| d.parse::<i64>().unwrap());
+    let (hours, minutes, seconds) = (field(0), field(2), field(4));
+    if hours > 23 || minutes > 59 || seconds > 59 {
+        return Err(invalid());
+    }
+
+    Ok(sign * (hours * 3600 + minutes * 60 + seconds))
+}
+
+/// Reads the bracketed annotations after a datetime, returning the time
+/// zone named by the first one.
+fn annotations_to_zone(mut annotations: &str) -> Result<TimeZone, ZonedError> {
+    let mut zone = None;
+
+    while !annotations.is_empty() {
+        let end = match (annotations.starts_with('['), annotations.find(']')) {
+            (true, Some(end))  => end,
+            _                  => return Err(ZonedError::InvalidAnnotation(annotations.to_owned())),
+        };
+
+        let annotation = &annotations[1 .. end];
+        annotations = &annotations[end + 1 ..];
+
+        let (critical, content) = match annotation.strip_prefix('!') {
+            Some(content)  => (true, content),
+            None           => (false, annotation),
+        };
+
+        if content.contains('=') {
+            if critical {
+                return Err(ZonedError::InvalidAnnotation(annotation.to_owned()));
+            }
+        }
+        else if zone.is_some() || content.is_empty() {
+            return Err(ZonedError::InvalidAnnotation(annotation.to_owned()));
+        }
+        else if content.starts_with('+') || content.starts_with('-') {
+            zone = Some(fixed_zone(content, parse_offset(content)?)?);
+        }
+        else {
+            zone = Some(TimeZone::named(content).map_err(ZonedError::Zone)?);
+        }
+    }
+
+    zone.ok_or(ZonedError::MissingZone)
+}
+
+/// Creates a time zone that is always at the given offset, for offsets
+/// used in place of a zone name, such as `[+01:00]`.
+fn fixed_zone(name: &str, offset: i64) -> Result<TimeZone, ZonedError> {
+    let timespan = FixedTimespan { offset, is_dst: false, name: Cow::Owned(name.to_owned()) };
+    let set = OwnedFixedTimespanSet { first: timespan, rest: Vec::new(), extension: None };
+    let zone = OwnedTimeZone::new(Some(name.to_owned()), set).map_err(|_| ZonedError::InvalidOffset(name.to_owned()))?;
+    Ok(TimeZone(TimeZoneSource::Runtime(Arc::new(zone))))
+}
+
 
 fn fields_to_date(fields: iso8601::Date) -> Result<LocalDate, DateTimeError> {
     if let iso8601::Date::YMD { year, month, day } = fields {
@@ -79,13 +252,37 @@ fn fields_to_date(fields: iso8601::Date) -> Result<LocalDate, DateTimeError> {
     }
 }
 
-fn fields_to_time(fields: iso8601::Time) -> Result<LocalTime, DateTimeError> {
+fn fields_to_time(fields: iso8601::Time, input: &str) -> Result<LocalTime, DateTimeError> {
     let h  = fields.hour as i8;
     let m  = fields.minute as i8;
     let s  = fields.second as i8;
-    let ms = fields.millisecond as i16;
+    let ns = fraction_to_nanoseconds(input);
+
+    LocalTime::hms_ns(h, m, s, ns)
+}
+
+/// Reads the fraction of a second from a time or datetime that has
+/// already been parsed, as the parser only keeps its milliseconds.
+/// Digits past the ninth are truncated.
+///
+/// The fraction has to come straight after the seconds of the time
+/// component: the parser ignores anything after the time and its offset,
+/// so a separator found further along isn’t part of the time.
+fn fraction_to_nanoseconds(input: &str) -> i32 {
+    let time = input.find('T').map_or(input, |index| &input[index + 1 ..]);
+    let after_seconds = time.trim_start_matches(|c: char| c.is_ascii_digit() || c == ':');
+    let fraction = match after_seconds.strip_prefix(&['.', ','][..]) {
+        Some(fraction)  => fraction,
+        None            => return 0,
+    };
+
+    let digits: Vec<i32> = fraction.bytes()
+                                   .take_while(u8::is_ascii_digit)
+                                   .take(9)
+                                   .map(|b| i32::from(b - b'0'))
+                                   .collect();
 
-    LocalTime::hms_ms(h, m, s, ms)
+    (0 .. 9).fold(0, |ns, i| ns * 10 + digits.get(i).cloned().unwrap_or(0))
 }
 
 
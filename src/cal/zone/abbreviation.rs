@@ -0,0 +1,286 @@
+//! Resolving time zone abbreviations, such as “PST” or “CEST”, to offsets.
+//!
+//! Abbreviations are not unique: “IST” is used in India, Ireland, and
+//! Israel, each with a different offset, and “CST” means both Central
+//! Standard Time and China Standard Time. So rather than using a fixed
+//! list, an `AbbreviationMap` is built from the abbreviations of a set of
+//! loaded zones, and reports when an abbreviation has more than one
+//! possible offset. A region, such as “Europe” or “Asia/Kolkata”, can be
+//! given to prefer the offsets used by zones in that region.
+
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::error::Error as ErrorTrait;
+use std::fmt;
+
+use cal::datetime::LocalDateTime;
+use cal::offset::{Offset, OffsetDateTime};
+use cal::zone::TimeZone;
+use instant::Instant;
+
+
+/// A map from abbreviations to the offsets and zones that use them.
+#[derive(PartialEq, Debug, Clone)]
+pub struct AbbreviationMap {
+    abbreviations: BTreeMap<String, Vec<Candidate>>,
+    since: i64,
+}
+
+/// One of the offsets that an abbreviation could mean.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Candidate {
+
+    /// The total offset from UTC, in seconds.
+    pub offset: i64,
+
+    /// The names of the zones that use the abbreviation with this offset,
+    /// in the order they were added.
+    pub zones: Vec<String>,
+}
+
+impl Candidate {
+
+    /// Returns whether any of this candidate’s zones are in the given
+    /// region, which can be an area such as “America”, or a whole zone
+    /// name such as “America/Los_Angeles”.
+    pub fn is_in_region(&self, region: &str) -> bool {
+        let region = region.trim_end_matches('/');
+        self.zones.iter().any(|zone| {
+            zone == region || (zone.starts_with(region) && zone[region.len() ..].starts_with('/'))
+        })
+    }
+}
+
+impl Default for AbbreviationMap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AbbreviationMap {
+
+    /// Creates a new, empty map that takes the abbreviations zones have
+    /// used since the Unix epoch.
+    ///
+    /// Zones used many abbreviations in the past that mean something else
+    /// today, such as the “LMT” used by every zone before it adopted
+    /// standard time, so older abbreviations are left out.
+    pub fn new() -> Self {
+        Self::since(Instant::at_epoch())
+    }
+
+    /// Creates a new, empty map that takes the abbreviations zones have
+    /// used since the given instant.
+    pub fn since(instant: Instant) -> Self {
+        Self { abbreviations: BTreeMap::new(), since: instant.seconds() }
+    }
+
+    /// Creates a map from the abbreviations used by the given zones.
+    pub fn from_zones<'z, I: IntoIterator<Item=&'z TimeZone>>(zones: I) -> Self {
+        let mut map = Self::new();
+        for zone in zones {
+            map.add_zone(zone);
+        }
+        map
+    }
+
+    /// Adds every abbreviation that the zone has used since this map’s
+    /// starting instant, including those produced by its POSIX rule.
+    ///
+    /// Numeric abbreviations such as “+0530”, which the zoneinfo database
+    /// uses for zones without an abbreviation in common use, are skipped.
+    pub fn add_zone(&mut self, zone: &TimeZone) {
+        let since = self.since;
+        let timespans = zone.0.with_timespans(|set| {
+            let current = set.find(since);
+            let mut timespans = vec![ (current.name.to_string(), current.offset) ];
+
+            for &(time, ref timespan) in set.rest {
+                if time > since {
+                    timespans.push((timespan.name.to_string(), timespan.offset));
+                }
+            }
+
+            if let Some(ref extension) = set.extension {
+                timespans.push((extension.standard.name.to_string(), extension.standard.offset));
+                if let Some(ref daylight) = extension.daylight {
+                    timespans.push((daylight.timespan.name.to_string(), daylight.timespan.offset));
+                }
+            }
+
+            timespans
+        });
+
+        for (abbreviation, offset) in timespans {
+            if abbreviation.is_empty() || abbreviation.starts_with(&['+', '-'][..]) {
+                continue;
+            }
+
+            self.add(abbreviation, offset, zone.zone_name());
+        }
+    }
+
+    fn add(&mut self, abbreviation: String, offset: i64, zone: Option<&str>) {
+        let candidates = self.abbreviations.entry(abbreviation).or_default();
+
+        let index = match candidates.iter().position(|c| c.offset == offset) {
+            Some(index)  => index,
+            None         => {
+                candidates.push(Candidate { offset, zones: Vec::new() });
+                candidates.len() - 1
+            },
+        };
+
+        if let Some(zone) = zone {
+            let zones = &mut candidates[index].zones;
+            if !zones.iter().any(|z| z == zone) {
+                zones.push(zone.to_owned());
+            }
+        }
+    }
+
+    /// Returns every abbreviation in this map, in alphabetical order.
+    pub fn abbreviations(&self) -> Vec<&str> {
+        self.abbreviations.keys().map(|a| &**a).collect()
+    }
+
+    /// Returns every offset that the abbreviation could mean, in the order
+    /// they were first seen, or an empty slice if it isn’t in this map.
+    pub fn candidates(&self, abbreviation: &str) -> &[Candidate] {
+        self.abbreviations.get(abbreviation).map_or(&[], |c| &**c)
+    }
+
+    /// Returns the offset that the abbreviation means.
+    ///
+    /// If it could mean more than one offset, the region is used to narrow
+    /// it down: when exactly one of the candidates has a zone in the region,
+    /// that candidate is returned. Otherwise the abbreviation is ambiguous.
+    pub fn resolve(&self, abbreviation: &str, region: Option<&str>) -> Result<&Candidate, Error> {
+        let candidates = self.candidates(abbreviation);
+        match candidates.len() {
+            0 => return Err(Error::Unknown(abbreviation.to_owned())),
+            1 => return Ok(&candidates[0]),
+            _ => {},
+        }
+
+        if let Some(region) = region {
+            let mut in_region = candidates.iter().filter(|c| c.is_in_region(region));
+            if let (Some(candidate), None) = (in_region.next(), in_region.next()) {
+                return Ok(candidate);
+            }
+        }
+
+        Err(Error::Ambiguous {
+            abbreviation: abbreviation.to_owned(),
+            offsets: candidates.iter().map(|c| c.offset).collect(),
+        })
+    }
+
+    /// Returns the offset that the abbreviation means, as an `Offset`.
+    pub fn offset(&self, abbreviation: &str, region: Option<&str>) -> Result<Offset, Error> {
+        let candidate = self.resolve(abbreviation, region)?;
+        i32::try_from(candidate.offset).ok()
+            .and_then(|seconds| Offset::of_seconds(seconds).ok())
+            .ok_or(Error::OutOfRange(candidate.offset))
+    }
+
+    /// Attaches the offset that the abbreviation means to a local datetime,
+    /// turning a time such as “10:00 PST” into an `OffsetDateTime`.
+    pub fn to_offset_datetime(&self, local: LocalDateTime, abbreviation: &str, region: Option<&str>) -> Result<OffsetDateTime, Error> {
+        Ok(self.offset(abbreviation, region)?.transform_date(local))
+    }
+}
+
+
+/// An error that can occur when resolving an abbreviation.
+#[derive(PartialEq, Debug, Clone)]
+pub enum Error {
+
+    /// No zone in the map uses this abbreviation.
+    Unknown(String),
+
+    /// The abbreviation could mean any of these offsets, and the region,
+    /// if one was given, didn’t narrow it down to one.
+    Ambiguous { abbreviation: String, offsets: Vec<i64> },
+
+    /// The abbreviation’s offset can’t be used as an `Offset`.
+    OutOfRange(i64),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Unknown(ref a)  => write!(f, "unknown time zone abbreviation {:?}", a),
+            Error::Ambiguous { ref abbreviation, ref offsets } => {
+                write!(f, "time zone abbreviation {:?} is ambiguous between {} offsets", abbreviation, offsets.len())
+            },
+            Error::OutOfRange(o)   => write!(f, "offset {} is out of range", o),
+        }
+    }
+}
+
+impl ErrorTrait for Error {
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use cal::zone::tzdata::Database;
+
+    fn map(source: &str) -> AbbreviationMap {
+        let mut database = Database::new();
+        database.parse(source).unwrap();
+
+        let mut map = AbbreviationMap::new();
+        for name in database.zone_names() {
+            map.add_zone(&database.time_zone(name).unwrap());
+        }
+        map
+    }
+
+    #[test]
+    fn regions() {
+        let candidate = Candidate { offset: 0, zones: vec![ "America/Argentina/Salta".into() ] };
+        assert!(candidate.is_in_region("America"));
+        assert!(candidate.is_in_region("America/"));
+        assert!(candidate.is_in_region("America/Argentina"));
+        assert!(candidate.is_in_region("America/Argentina/Salta"));
+        assert!(!candidate.is_in_region("America/Arg"));
+        assert!(!candidate.is_in_region("Asia"));
+    }
+
+    #[test]
+    fn shared_offsets_are_grouped() {
+        let map = map("Z America/New_York -5 - EST\nZ America/Toronto -5 - EST\n");
+        assert_eq!(map.candidates("EST"), &[ Candidate {
+            offset: -5 * 3600,
+            zones: vec![ "America/New_York".into(), "America/Toronto".into() ],
+        } ]);
+    }
+
+    #[test]
+    fn old_abbreviations_are_skipped() {
+        let map = map("Z Asia/Kolkata 5:53:28 - LMT 1854 Jun 28\n5:30 - IST\n");
+        assert_eq!(map.abbreviations(), vec![ "IST" ]);
+    }
+
+    #[test]
+    fn numeric_abbreviations_are_skipped() {
+        let map = map("Z Asia/Dubai 4 - +04\n");
+        assert!(map.abbreviations().is_empty());
+    }
+
+    #[test]
+    fn unknown() {
+        let map = map("Z Asia/Dubai 4 - GST\n");
+        assert_eq!(map.resolve("XYZ", None), Err(Error::Unknown("XYZ".into())));
+    }
+
+    #[test]
+    fn offset_too_large_for_32_bits() {
+        let mut map = AbbreviationMap::new();
+        map.add("BIG".into(), 1 << 32, None);
+        assert_eq!(map.offset("BIG", None), Err(Error::OutOfRange(1 << 32)));
+    }
+}
@@ -0,0 +1,411 @@
+//! Listing the names of the zones in a time zone database.
+//!
+//! A `Catalogue` holds the names of a database’s zones and links, along
+//! with the countries and locations that each zone covers. The names come
+//! from the tzdata source files, such as the `tzdata.zi` file installed
+//! alongside most compiled zoneinfo databases, and the countries from the
+//! `zone1970.tab` and `iso3166.tab` files.
+//!
+//! Names can be *canonical*, such as “America/Los_Angeles”, or *aliases*,
+//! such as “US/Pacific”, which are links to a canonical zone.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::error::Error as ErrorTrait;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use cal::zone::tzdata::{self, Database};
+use system::zoneinfo_directories;
+
+
+/// The zone names, aliases, and countries of a time zone database.
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct Catalogue {
+    zones: BTreeSet<String>,
+    aliases: BTreeMap<String, String>,
+    countries: BTreeMap<String, String>,
+    locations: Vec<Location>,
+}
+
+/// A line of `zone1970.tab`: a zone, and the area that it covers.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Location {
+
+    /// The ISO 3166 codes of the countries that the zone covers, such as
+    /// `GB` or `US`. Most zones cover only one country.
+    pub countries: Vec<String>,
+
+    /// The latitude of the zone’s principal location, in degrees, where
+    /// north is positive.
+    pub latitude: f64,
+
+    /// The longitude of the zone’s principal location, in degrees, where
+    /// east is positive.
+    pub longitude: f64,
+
+    /// The canonical name of the zone, such as “America/New_York”.
+    pub zone: String,
+
+    /// A description of the area the zone covers, for countries with more
+    /// than one zone, such as “Eastern (most areas)”.
+    pub comment: Option<String>,
+}
+
+
+impl Catalogue {
+
+    /// Creates a new, empty catalogue.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a catalogue of the zones and links in a set of parsed
+    /// tzdata source files.
+    pub fn from_database(database: &Database) -> Self {
+        let mut catalogue = Self::new();
+        catalogue.add_database(database);
+        catalogue
+    }
+
+    /// Loads a catalogue from the compiled zoneinfo database in the given
+    /// directory.
+    ///
+    /// Zone and link names are read from the `tzdata.zi` file if there is
+    /// one. Otherwise, every TZif file in the directory is listed as a
+    /// canonical zone, as there’s no way to tell which are links. The
+    /// `zone1970.tab` and `iso3166.tab` files are read if they exist.
+    pub fn load(directory: &Path) -> Result<Self, Error> {
+        let mut catalogue = Self::new();
+
+        match fs::read_to_string(directory.join("tzdata.zi")) {
+            Ok(source) => {
+                let mut database = Database::new();
+                database.parse(&source).map_err(Error::Tzdata)?;
+                catalogue.add_database(&database);
+            },
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => {
+                catalogue.add_directory(directory, "")?;
+            },
+            Err(e) => return Err(Error::Io(e)),
+        }
+
+        if let Some(source) = read_if_exists(&directory.join("zone1970.tab"))? {
+            catalogue.parse_zone1970(&source)?;
+        }
+
+        if let Some(source) = read_if_exists(&directory.join("iso3166.tab"))? {
+            catalogue.parse_iso3166(&source)?;
+        }
+
+        Ok(catalogue)
+    }
+
+    /// Loads a catalogue from the first of the system’s zoneinfo
+    /// directories that exists, searched in the same order as by
+    /// `TimeZone::named`.
+    pub fn system() -> Result<Self, Error> {
+        match zoneinfo_directories().into_iter().find(|d| d.is_dir()) {
+            Some(directory)  => Self::load(&directory),
+            None             => Err(Error::Io(io::Error::new(io::ErrorKind::NotFound, "no zoneinfo directory"))),
+        }
+    }
+
+    fn add_database(&mut self, database: &Database) {
+        self.zones.extend(database.zone_names().into_iter().map(String::from));
+
+        let links: BTreeMap<&str, &str> = database.links().into_iter().collect();
+        for (&alias, &target) in &links {
+            // Links should point straight to a zone, but can form chains.
+            let mut canonical = target;
+            for _ in 0 .. 16 {
+                match links.get(canonical) {
+                    Some(next)  => canonical = next,
+                    None        => break,
+                }
+            }
+
+            if self.zones.contains(canonical) {
+                let _ = self.aliases.insert(alias.to_owned(), canonical.to_owned());
+            }
+        }
+    }
+
+    /// Adds every TZif file under the directory as a zone, skipping the
+    /// `posix` and `right` copies of the database.
+    fn add_directory(&mut self, directory: &Path, prefix: &str) -> Result<(), Error> {
+        for entry in fs::read_dir(directory).map_err(Error::Io)? {
+            let entry = entry.map_err(Error::Io)?;
+            let name = match entry.file_name().into_string() {
+                Ok(name)  => format!("{}{}", prefix, name),
+                Err(_)    => continue,
+            };
+
+            let path = entry.path();
+            if path.is_dir() {
+                if name != "posix" && name != "right" {
+                    self.add_directory(&path, &format!("{}/", name))?;
+                }
+            }
+            else if name != "localtime" && name != "posixrules" && is_tzif(&path) {
+                let _ = self.zones.insert(name);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reads the contents of a `zone1970.tab` file, which lists each
+    /// zone along with the countries it covers.
+    pub fn parse_zone1970(&mut self, source: &str) -> Result<(), Error> {
+        for (number, line) in source.lines().enumerate() {
+            if line.starts_with('#') || line.trim().is_empty() {
+                continue;
+            }
+
+            let invalid = || Error::InvalidLine { line: number + 1 };
+            let fields: Vec<&str> = line.split('\t').collect();
+            if fields.len() < 3 || fields.len() > 4 {
+                return Err(invalid());
+            }
+
+            let (latitude, longitude) = parse_coordinates(fields[1]).ok_or_else(invalid)?;
+            self.locations.push(Location {
+                countries:  fields[0].split(',').map(String::from).collect(),
+                latitude,
+                longitude,
+                zone:       fields[2].to_owned(),
+                comment:    fields.get(3).map(|c| (*c).to_owned()),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Reads the contents of an `iso3166.tab` file, which gives the name
+    /// of each country code.
+    pub fn parse_iso3166(&mut self, source: &str) -> Result<(), Error> {
+        for (number, line) in source.lines().enumerate() {
+            if line.starts_with('#') || line.trim().is_empty() {
+                continue;
+            }
+
+            match line.split_once('\t') {
+                Some((code, name))  => { let _ = self.countries.insert(code.to_owned(), name.to_owned()); },
+                None                => return Err(Error::InvalidLine { line: number + 1 }),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the canonical zone names, in alphabetical order.
+    pub fn zone_names(&self) -> Vec<&str> {
+        self.zones.iter().map(|z| &**z).collect()
+    }
+
+    /// Returns every name, canonical or alias, in alphabetical order.
+    pub fn all_names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.zones.iter().chain(self.aliases.keys()).map(|z| &**z).collect();
+        names.sort_unstable();
+        names
+    }
+
+    /// Returns whether the name is in this catalogue, either as a zone or
+    /// as an alias.
+    pub fn contains(&self, name: &str) -> bool {
+        self.zones.contains(name) || self.aliases.contains_key(name)
+    }
+
+    /// Returns whether the name is the canonical name of a zone, rather
+    /// than an alias.
+    pub fn is_canonical(&self, name: &str) -> bool {
+        self.zones.contains(name)
+    }
+
+    /// Returns the canonical name for the given name, following an alias
+    /// such as “US/Pacific” to “America/Los_Angeles”, or `None` if the
+    /// name isn’t in this catalogue.
+    pub fn canonical_name<'a>(&'a self, name: &str) -> Option<&'a str> {
+        if let Some(zone) = self.zones.get(name) {
+            return Some(zone);
+        }
+
+        self.aliases.get(name).map(|z| &**z)
+    }
+
+    /// Returns the aliases of the zone with the given canonical name, in
+    /// alphabetical order.
+    pub fn aliases(&self, canonical: &str) -> Vec<&str> {
+        self.aliases.iter()
+            .filter(|&(_, target)| target == canonical)
+            .map(|(alias, _)| &**alias)
+            .collect()
+    }
+
+    /// Returns the locations from `zone1970.tab`, in the file’s order.
+    pub fn locations(&self) -> &[Location] {
+        &self.locations
+    }
+
+    /// Returns the locations of the zones that cover the country with the
+    /// given ISO 3166 code, such as `GB`.
+    pub fn zones_for_country(&self, code: &str) -> Vec<&Location> {
+        self.locations.iter()
+            .filter(|l| l.countries.iter().any(|c| c == code))
+            .collect()
+    }
+
+    /// Returns the name of the country with the given ISO 3166 code, such
+    /// as “Britain (UK)” for `GB`, as given in `iso3166.tab`.
+    pub fn country_name(&self, code: &str) -> Option<&str> {
+        self.countries.get(code).map(|n| &**n)
+    }
+}
+
+
+fn read_if_exists(path: &Path) -> Result<Option<String>, Error> {
+    match fs::read_to_string(path) {
+        Ok(source)                                         => Ok(Some(source)),
+        Err(ref e) if e.kind() == io::ErrorKind::NotFound  => Ok(None),
+        Err(e)                                             => Err(Error::Io(e)),
+    }
+}
+
+fn is_tzif(path: &Path) -> bool {
+    use std::io::Read;
+
+    let mut magic = [0; 4];
+    fs::File::open(path).and_then(|mut f| f.read_exact(&mut magic)).is_ok() && &magic == b"TZif"
+}
+
+/// Parses ISO 6709 coordinates in the form used by `zone1970.tab`, which
+/// is `±DDMM±DDDMM` or `±DDMMSS±DDDMMSS`, into decimal degrees.
+fn parse_coordinates(input: &str) -> Option<(f64, f64)> {
+    let split = input.get(1..)?.find(&['+', '-'][..])? + 1;
+    let (latitude, longitude) = input.split_at(split);
+    Some((parse_angle(latitude, 2)?, parse_angle(longitude, 3)?))
+}
+
+fn parse_angle(input: &str, degree_digits: usize) -> Option<f64> {
+    let sign = match input.as_bytes().first()? {
+        b'+'  => 1.0,
+        b'-'  => -1.0,
+        _     => return None,
+    };
+
+    let digits = &input[1..];
+    if !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let seconds = match digits.len().checked_sub(degree_digits)? {
+        2  => 0.0,
+        4  => digits[degree_digits + 2 ..].parse::<f64>().ok()?,
+        _  => return None,
+    };
+
+    let degrees = digits[.. degree_digits].parse::<f64>().ok()?;
+    let minutes = digits[degree_digits .. degree_digits + 2].parse::<f64>().ok()?;
+    Some(sign * (degrees + minutes / 60.0 + seconds / 3600.0))
+}
+
+
+/// An error that can occur when loading a catalogue.
+#[derive(Debug)]
+pub enum Error {
+
+    /// A file could not be read.
+    Io(io::Error),
+
+    /// The `tzdata.zi` file could not be parsed.
+    Tzdata(tzdata::Error),
+
+    /// A line of a `.tab` file has the wrong number of fields or invalid
+    /// coordinates.
+    InvalidLine { line: usize },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Io(ref e)              => write!(f, "error reading zone catalogue: {}", e),
+            Error::Tzdata(ref e)          => write!(f, "error parsing zone catalogue: {}", e),
+            Error::InvalidLine { line }   => write!(f, "line {}: invalid zone table line", line),
+        }
+    }
+}
+
+impl ErrorTrait for Error {
+    fn source(&self) -> Option<&(dyn ErrorTrait + 'static)> {
+        match *self {
+            Error::Io(ref e)      => Some(e),
+            Error::Tzdata(ref e)  => Some(e),
+            _                     => None,
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn coordinates() {
+        assert_eq!(parse_coordinates("+4230+00131"), Some((42.5, 1.0 + 31.0 / 60.0)));
+        assert_eq!(parse_coordinates("-3352+15113"), Some((-(33.0 + 52.0 / 60.0), 151.0 + 13.0 / 60.0)));
+
+        let (latitude, longitude) = parse_coordinates("+513030-0000731").unwrap();
+        assert_eq!(latitude, 51.0 + 30.0 / 60.0 + 30.0 / 3600.0);
+        assert_eq!(longitude, -(7.0 / 60.0 + 31.0 / 3600.0));
+    }
+
+    #[test]
+    fn bad_coordinates() {
+        assert_eq!(parse_coordinates("+42+001"), None);
+        assert_eq!(parse_coordinates("4230+00131"), None);
+        assert_eq!(parse_coordinates("+4230"), None);
+        assert_eq!(parse_coordinates(""), None);
+        assert_eq!(parse_coordinates("+1+2"), None);
+        assert_eq!(parse_coordinates("é+4230+00131"), None);
+    }
+
+    #[test]
+    fn zone1970_line() {
+        let mut catalogue = Catalogue::new();
+        catalogue.parse_zone1970("# comment\nAE,OM\t+2518+05518\tAsia/Dubai\tCrozet\n").unwrap();
+        assert_eq!(catalogue.locations(), &[ Location {
+            countries:  vec![ "AE".into(), "OM".into() ],
+            latitude:   25.3,
+            longitude:  55.3,
+            zone:       "Asia/Dubai".into(),
+            comment:    Some("Crozet".into()),
+        } ]);
+    }
+
+    #[test]
+    fn zone1970_bad_line() {
+        let mut catalogue = Catalogue::new();
+        assert_eq!(catalogue.parse_zone1970("AD\n").unwrap_err().to_string(), "line 1: invalid zone table line");
+    }
+
+    #[test]
+    fn zone1970_bad_coordinates() {
+        let mut catalogue = Catalogue::new();
+        assert!(matches!(catalogue.parse_zone1970("GB\t\tEurope/London\n"), Err(Error::InvalidLine { line: 1 })));
+        assert!(matches!(catalogue.parse_zone1970("GB\t+1+2\tEurope/London\n"), Err(Error::InvalidLine { line: 1 })));
+    }
+
+    #[test]
+    fn alias_chains() {
+        let mut database = Database::new();
+        database.parse("Z Europe/London 0 - GMT\nL Europe/London GB\nL GB GB-Eire\nL Nowhere Dangling\n").unwrap();
+
+        let catalogue = Catalogue::from_database(&database);
+        assert_eq!(catalogue.canonical_name("GB-Eire"), Some("Europe/London"));
+        assert_eq!(catalogue.aliases("Europe/London"), vec![ "GB", "GB-Eire" ]);
+        assert!(!catalogue.contains("Dangling"));
+    }
+}
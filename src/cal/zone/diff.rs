@@ -0,0 +1,170 @@
+//! Comparing two versions of a time zone.
+//!
+//! When the zoneinfo database is updated, a zone’s future transitions can
+//! move, appear, or disappear, changing what a stored local time means.
+//! Comparing the old and new versions of a zone over a range of instants
+//! gives the intervals where they disagree, so only the datetimes that
+//! fall in one of them need checking again.
+
+use std::borrow::Cow;
+
+use cal::zone::{TimeZone, FixedTimespan};
+use instant::Instant;
+
+
+/// An interval in which two versions of a time zone have a different
+/// offset or abbreviation.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Difference {
+
+    /// The instant at which the interval starts.
+    pub from: Instant,
+
+    /// The instant at which the interval ends, which is not part of it.
+    pub to: Instant,
+
+    /// The timespan in effect in the old version of the zone.
+    pub old: FixedTimespan<'static>,
+
+    /// The timespan in effect in the new version of the zone.
+    pub new: FixedTimespan<'static>,
+}
+
+impl Difference {
+
+    /// Returns whether the given instant is in this interval.
+    pub fn contains(&self, instant: Instant) -> bool {
+        self.from <= instant && instant < self.to
+    }
+
+    /// Returns whether the offset changed, rather than only the
+    /// abbreviation.
+    pub fn offset_changed(&self) -> bool {
+        self.old.offset != self.new.offset
+    }
+}
+
+
+/// Returns every interval between `from` and `to` where the old and new
+/// versions of a zone have a different offset or abbreviation, in order.
+/// Adjacent intervals with the same old and new timespans are merged.
+pub fn compare(old: &TimeZone, new: &TimeZone, from: Instant, to: Instant) -> Vec<Difference> {
+    if from >= to {
+        return Vec::new();
+    }
+
+    let (old, new) = (Timeline::new(old, from, to), Timeline::new(new, from, to));
+
+    let mut boundaries = vec![ from ];
+    boundaries.extend(old.changes.iter().map(|c| c.0));
+    boundaries.extend(new.changes.iter().map(|c| c.0));
+    boundaries.sort();
+    boundaries.dedup();
+
+    let mut differences: Vec<Difference> = Vec::new();
+    for (index, &start) in boundaries.iter().enumerate() {
+        let end = boundaries.get(index + 1).cloned().unwrap_or(to);
+        let (old, new) = (old.at(start), new.at(start));
+
+        if old.offset == new.offset && old.name == new.name {
+            continue;
+        }
+
+        if let Some(last) = differences.last_mut() {
+            if last.to == start && last.old == *old && last.new == *new {
+                last.to = end;
+                continue;
+            }
+        }
+
+        differences.push(Difference { from: start, to: end, old: old.clone(), new: new.clone() });
+    }
+
+    differences
+}
+
+/// The timespans a zone goes through in a range of instants.
+struct Timeline {
+    initial: FixedTimespan<'static>,
+    changes: Vec<(Instant, FixedTimespan<'static>)>,
+}
+
+impl Timeline {
+    fn new(zone: &TimeZone, from: Instant, to: Instant) -> Self {
+        let zoned = zone.at(from);
+        let initial = FixedTimespan {
+            offset:  zoned.offset(),
+            is_dst:  zoned.is_dst(),
+            name:    Cow::Owned(zoned.abbreviation()),
+        };
+
+        // The timespans are taken from the transitions themselves, rather
+        // than looked up at each instant, as a lookup at the exact instant
+        // of a transition gives the timespan before it.
+        let changes = zone.transitions(from, to).map(|t| (t.instant, owned(t.after))).collect();
+        Self { initial, changes }
+    }
+
+    fn at(&self, instant: Instant) -> &FixedTimespan<'static> {
+        match self.changes.partition_point(|c| c.0 <= instant) {
+            0  => &self.initial,
+            n  => &self.changes[n - 1].1,
+        }
+    }
+}
+
+fn owned(timespan: FixedTimespan) -> FixedTimespan<'static> {
+    FixedTimespan {
+        offset:  timespan.offset,
+        is_dst:  timespan.is_dst,
+        name:    Cow::Owned(timespan.name.into_owned()),
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn zone(tz: &str) -> TimeZone {
+        TimeZone::from_posix(tz).unwrap()
+    }
+
+    #[test]
+    fn same_zone() {
+        let london = zone("GMT0BST,M3.5.0/1,M10.5.0");
+        assert!(compare(&london, &london, Instant::at(0), Instant::at(1_000_000_000)).is_empty());
+    }
+
+    #[test]
+    fn empty_range() {
+        let (old, new) = (zone("EST5"), zone("CST6"));
+        assert!(compare(&old, &new, Instant::at(100), Instant::at(100)).is_empty());
+    }
+
+    #[test]
+    fn fixed_offset_change() {
+        let (old, new) = (zone("EST5"), zone("CST6"));
+        assert_eq!(compare(&old, &new, Instant::at(0), Instant::at(100)), vec![ Difference {
+            from:  Instant::at(0),
+            to:    Instant::at(100),
+            old:   FixedTimespan { offset: -5 * 3600, is_dst: false, name: Cow::Borrowed("EST") },
+            new:   FixedTimespan { offset: -6 * 3600, is_dst: false, name: Cow::Borrowed("CST") },
+        } ]);
+    }
+
+    #[test]
+    fn abbreviation_change() {
+        let differences = compare(&zone("XYZ-3"), &zone("ABC-3"), Instant::at(0), Instant::at(100));
+        assert_eq!(differences.len(), 1);
+        assert!(!differences[0].offset_changed());
+    }
+
+    #[test]
+    fn contains() {
+        let differences = compare(&zone("EST5"), &zone("CST6"), Instant::at(0), Instant::at(100));
+        assert!(differences[0].contains(Instant::at(0)));
+        assert!(differences[0].contains(Instant::at_ms(99, 999)));
+        assert!(!differences[0].contains(Instant::at(100)));
+    }
+}
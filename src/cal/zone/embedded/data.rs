@@ -0,0 +1,21700 @@
+// This file is generated by examples/generate_embedded.rs from version
+// 2025b of the IANA time zone database. Do not edit it by hand.
+
+use std::borrow::Cow;
+
+use cal::datetime::{Month, Weekday};
+use cal::zone::{FixedTimespan, FixedTimespanSet, StaticTimeZone};
+use cal::zone::posix::{DaylightRule, PosixTimeZone, RuleDay, TransitionRule};
+
+pub const VERSION: &str = "2025b";
+
+const T0: FixedTimespan<'static> = FixedTimespan { offset: -968, is_dst: false, name: Cow::Borrowed("LMT") };
+const T1: FixedTimespan<'static> = FixedTimespan { offset: 0, is_dst: false, name: Cow::Borrowed("GMT") };
+const T2: FixedTimespan<'static> = FixedTimespan { offset: -52, is_dst: false, name: Cow::Borrowed("LMT") };
+const T3: FixedTimespan<'static> = FixedTimespan { offset: 1200, is_dst: true, name: Cow::Borrowed("+0020") };
+const T4: FixedTimespan<'static> = FixedTimespan { offset: 1800, is_dst: false, name: Cow::Borrowed("+0030") };
+const T5: FixedTimespan<'static> = FixedTimespan { offset: 1800, is_dst: true, name: Cow::Borrowed("+0030") };
+const T6: FixedTimespan<'static> = FixedTimespan { offset: 9288, is_dst: false, name: Cow::Borrowed("LMT") };
+const T7: FixedTimespan<'static> = FixedTimespan { offset: 9320, is_dst: false, name: Cow::Borrowed("ADMT") };
+const T8: FixedTimespan<'static> = FixedTimespan { offset: 10800, is_dst: false, name: Cow::Borrowed("EAT") };
+const T9: FixedTimespan<'static> = FixedTimespan { offset: 732, is_dst: false, name: Cow::Borrowed("LMT") };
+const T10: FixedTimespan<'static> = FixedTimespan { offset: 561, is_dst: false, name: Cow::Borrowed("PMT") };
+const T11: FixedTimespan<'static> = FixedTimespan { offset: 0, is_dst: false, name: Cow::Borrowed("WET") };
+const T12: FixedTimespan<'static> = FixedTimespan { offset: 3600, is_dst: true, name: Cow::Borrowed("WEST") };
+const T13: FixedTimespan<'static> = FixedTimespan { offset: 3600, is_dst: false, name: Cow::Borrowed("CET") };
+const T14: FixedTimespan<'static> = FixedTimespan { offset: 7200, is_dst: true, name: Cow::Borrowed("CEST") };
+const T15: FixedTimespan<'static> = FixedTimespan { offset: 9332, is_dst: false, name: Cow::Borrowed("LMT") };
+const T16: FixedTimespan<'static> = FixedTimespan { offset: -1920, is_dst: false, name: Cow::Borrowed("LMT") };
+const T17: FixedTimespan<'static> = FixedTimespan { offset: -3600, is_dst: false, name: Cow::Borrowed("-01") };
+const T18: FixedTimespan<'static> = FixedTimespan { offset: 4460, is_dst: false, name: Cow::Borrowed("LMT") };
+const T19: FixedTimespan<'static> = FixedTimespan { offset: 3600, is_dst: false, name: Cow::Borrowed("WAT") };
+const T20: FixedTimespan<'static> = FixedTimespan { offset: -3996, is_dst: false, name: Cow::Borrowed("LMT") };
+const T21: FixedTimespan<'static> = FixedTimespan { offset: -3740, is_dst: false, name: Cow::Borrowed("LMT") };
+const T22: FixedTimespan<'static> = FixedTimespan { offset: 8400, is_dst: false, name: Cow::Borrowed("LMT") };
+const T23: FixedTimespan<'static> = FixedTimespan { offset: 8470, is_dst: false, name: Cow::Borrowed("ZMT") };
+const T24: FixedTimespan<'static> = FixedTimespan { offset: 8460, is_dst: false, name: Cow::Borrowed("ZMT") };
+const T25: FixedTimespan<'static> = FixedTimespan { offset: 7200, is_dst: false, name: Cow::Borrowed("CAT") };
+const T26: FixedTimespan<'static> = FixedTimespan { offset: 3668, is_dst: false, name: Cow::Borrowed("LMT") };
+const T27: FixedTimespan<'static> = FixedTimespan { offset: 7048, is_dst: false, name: Cow::Borrowed("LMT") };
+const T28: FixedTimespan<'static> = FixedTimespan { offset: 7509, is_dst: false, name: Cow::Borrowed("LMT") };
+const T29: FixedTimespan<'static> = FixedTimespan { offset: 7200, is_dst: false, name: Cow::Borrowed("EET") };
+const T30: FixedTimespan<'static> = FixedTimespan { offset: 10800, is_dst: true, name: Cow::Borrowed("EEST") };
+const T31: FixedTimespan<'static> = FixedTimespan { offset: -1820, is_dst: false, name: Cow::Borrowed("LMT") };
+const T32: FixedTimespan<'static> = FixedTimespan { offset: 0, is_dst: false, name: Cow::Borrowed("+00") };
+const T33: FixedTimespan<'static> = FixedTimespan { offset: 3600, is_dst: true, name: Cow::Borrowed("+01") };
+const T34: FixedTimespan<'static> = FixedTimespan { offset: 3600, is_dst: false, name: Cow::Borrowed("+01") };
+const T35: FixedTimespan<'static> = FixedTimespan { offset: 0, is_dst: true, name: Cow::Borrowed("+00") };
+const T36: FixedTimespan<'static> = FixedTimespan { offset: -1276, is_dst: false, name: Cow::Borrowed("LMT") };
+const T37: FixedTimespan<'static> = FixedTimespan { offset: -3292, is_dst: false, name: Cow::Borrowed("LMT") };
+const T38: FixedTimespan<'static> = FixedTimespan { offset: -4184, is_dst: false, name: Cow::Borrowed("LMT") };
+const T39: FixedTimespan<'static> = FixedTimespan { offset: 9428, is_dst: false, name: Cow::Borrowed("LMT") };
+const T40: FixedTimespan<'static> = FixedTimespan { offset: 9900, is_dst: false, name: Cow::Borrowed("+0245") };
+const T41: FixedTimespan<'static> = FixedTimespan { offset: 10356, is_dst: false, name: Cow::Borrowed("LMT") };
+const T42: FixedTimespan<'static> = FixedTimespan { offset: 2328, is_dst: false, name: Cow::Borrowed("LMT") };
+const T43: FixedTimespan<'static> = FixedTimespan { offset: -3168, is_dst: false, name: Cow::Borrowed("LMT") };
+const T44: FixedTimespan<'static> = FixedTimespan { offset: -3180, is_dst: false, name: Cow::Borrowed("LMT") };
+const T45: FixedTimespan<'static> = FixedTimespan { offset: -2400, is_dst: true, name: Cow::Borrowed("-0040") };
+const T46: FixedTimespan<'static> = FixedTimespan { offset: 6220, is_dst: false, name: Cow::Borrowed("LMT") };
+const T47: FixedTimespan<'static> = FixedTimespan { offset: 5400, is_dst: false, name: Cow::Borrowed("SAST") };
+const T48: FixedTimespan<'static> = FixedTimespan { offset: 10800, is_dst: true, name: Cow::Borrowed("CAST") };
+const T49: FixedTimespan<'static> = FixedTimespan { offset: 7452, is_dst: false, name: Cow::Borrowed("LMT") };
+const T50: FixedTimespan<'static> = FixedTimespan { offset: 6720, is_dst: false, name: Cow::Borrowed("LMT") };
+const T51: FixedTimespan<'static> = FixedTimespan { offset: 7200, is_dst: false, name: Cow::Borrowed("SAST") };
+const T52: FixedTimespan<'static> = FixedTimespan { offset: 10800, is_dst: true, name: Cow::Borrowed("SAST") };
+const T53: FixedTimespan<'static> = FixedTimespan { offset: 7588, is_dst: false, name: Cow::Borrowed("LMT") };
+const T54: FixedTimespan<'static> = FixedTimespan { offset: 7780, is_dst: false, name: Cow::Borrowed("LMT") };
+const T55: FixedTimespan<'static> = FixedTimespan { offset: 9000, is_dst: false, name: Cow::Borrowed("+0230") };
+const T56: FixedTimespan<'static> = FixedTimespan { offset: 7808, is_dst: false, name: Cow::Borrowed("LMT") };
+const T57: FixedTimespan<'static> = FixedTimespan { offset: 7216, is_dst: false, name: Cow::Borrowed("LMT") };
+const T58: FixedTimespan<'static> = FixedTimespan { offset: 3672, is_dst: false, name: Cow::Borrowed("LMT") };
+const T59: FixedTimespan<'static> = FixedTimespan { offset: 815, is_dst: false, name: Cow::Borrowed("LMT") };
+const T60: FixedTimespan<'static> = FixedTimespan { offset: 2268, is_dst: false, name: Cow::Borrowed("LMT") };
+const T61: FixedTimespan<'static> = FixedTimespan { offset: 292, is_dst: false, name: Cow::Borrowed("LMT") };
+const T62: FixedTimespan<'static> = FixedTimespan { offset: 3176, is_dst: false, name: Cow::Borrowed("LMT") };
+const T63: FixedTimespan<'static> = FixedTimespan { offset: 3124, is_dst: false, name: Cow::Borrowed("LMT") };
+const T64: FixedTimespan<'static> = FixedTimespan { offset: 6592, is_dst: false, name: Cow::Borrowed("LMT") };
+const T65: FixedTimespan<'static> = FixedTimespan { offset: 6788, is_dst: false, name: Cow::Borrowed("LMT") };
+const T66: FixedTimespan<'static> = FixedTimespan { offset: 2108, is_dst: false, name: Cow::Borrowed("LMT") };
+const T67: FixedTimespan<'static> = FixedTimespan { offset: 7818, is_dst: false, name: Cow::Borrowed("LMT") };
+const T68: FixedTimespan<'static> = FixedTimespan { offset: 6600, is_dst: false, name: Cow::Borrowed("LMT") };
+const T69: FixedTimespan<'static> = FixedTimespan { offset: 7464, is_dst: false, name: Cow::Borrowed("LMT") };
+const T70: FixedTimespan<'static> = FixedTimespan { offset: 10888, is_dst: false, name: Cow::Borrowed("LMT") };
+const T71: FixedTimespan<'static> = FixedTimespan { offset: -2588, is_dst: false, name: Cow::Borrowed("LMT") };
+const T72: FixedTimespan<'static> = FixedTimespan { offset: -2670, is_dst: false, name: Cow::Borrowed("MMT") };
+const T73: FixedTimespan<'static> = FixedTimespan { offset: 8836, is_dst: false, name: Cow::Borrowed("LMT") };
+const T74: FixedTimespan<'static> = FixedTimespan { offset: 3612, is_dst: false, name: Cow::Borrowed("LMT") };
+const T75: FixedTimespan<'static> = FixedTimespan { offset: 7200, is_dst: true, name: Cow::Borrowed("WAST") };
+const T76: FixedTimespan<'static> = FixedTimespan { offset: 508, is_dst: false, name: Cow::Borrowed("LMT") };
+const T77: FixedTimespan<'static> = FixedTimespan { offset: -3828, is_dst: false, name: Cow::Borrowed("LMT") };
+const T78: FixedTimespan<'static> = FixedTimespan { offset: -364, is_dst: false, name: Cow::Borrowed("LMT") };
+const T79: FixedTimespan<'static> = FixedTimespan { offset: 628, is_dst: false, name: Cow::Borrowed("LMT") };
+const T80: FixedTimespan<'static> = FixedTimespan { offset: 1616, is_dst: false, name: Cow::Borrowed("LMT") };
+const T81: FixedTimespan<'static> = FixedTimespan { offset: -2205, is_dst: false, name: Cow::Borrowed("LMT") };
+const T82: FixedTimespan<'static> = FixedTimespan { offset: 3164, is_dst: false, name: Cow::Borrowed("LMT") };
+const T83: FixedTimespan<'static> = FixedTimespan { offset: 2444, is_dst: false, name: Cow::Borrowed("LMT") };
+const T84: FixedTimespan<'static> = FixedTimespan { offset: 4104, is_dst: false, name: Cow::Borrowed("LMT") };
+const T85: FixedTimespan<'static> = FixedTimespan { offset: 5400, is_dst: false, name: Cow::Borrowed("+0130") };
+const T86: FixedTimespan<'static> = FixedTimespan { offset: 3600, is_dst: true, name: Cow::Borrowed("WAT") };
+const T87: FixedTimespan<'static> = FixedTimespan { offset: 44002, is_dst: false, name: Cow::Borrowed("LMT") };
+const T88: FixedTimespan<'static> = FixedTimespan { offset: -42398, is_dst: false, name: Cow::Borrowed("LMT") };
+const T89: FixedTimespan<'static> = FixedTimespan { offset: -39600, is_dst: false, name: Cow::Borrowed("NST") };
+const T90: FixedTimespan<'static> = FixedTimespan { offset: -36000, is_dst: true, name: Cow::Borrowed("NWT") };
+const T91: FixedTimespan<'static> = FixedTimespan { offset: -36000, is_dst: true, name: Cow::Borrowed("BDT") };
+const T92: FixedTimespan<'static> = FixedTimespan { offset: -39600, is_dst: false, name: Cow::Borrowed("BST") };
+const T93: FixedTimespan<'static> = FixedTimespan { offset: -32400, is_dst: true, name: Cow::Borrowed("HDT") };
+const T94: FixedTimespan<'static> = FixedTimespan { offset: -36000, is_dst: false, name: Cow::Borrowed("HST") };
+const T95: FixedTimespan<'static> = FixedTimespan { offset: 50424, is_dst: false, name: Cow::Borrowed("LMT") };
+const T96: FixedTimespan<'static> = FixedTimespan { offset: -35976, is_dst: false, name: Cow::Borrowed("LMT") };
+const T97: FixedTimespan<'static> = FixedTimespan { offset: -36000, is_dst: false, name: Cow::Borrowed("AST") };
+const T98: FixedTimespan<'static> = FixedTimespan { offset: -32400, is_dst: true, name: Cow::Borrowed("AWT") };
+const T99: FixedTimespan<'static> = FixedTimespan { offset: -32400, is_dst: true, name: Cow::Borrowed("AHDT") };
+const T100: FixedTimespan<'static> = FixedTimespan { offset: -36000, is_dst: false, name: Cow::Borrowed("AHST") };
+const T101: FixedTimespan<'static> = FixedTimespan { offset: -28800, is_dst: true, name: Cow::Borrowed("AKDT") };
+const T102: FixedTimespan<'static> = FixedTimespan { offset: -32400, is_dst: false, name: Cow::Borrowed("AKST") };
+const T103: FixedTimespan<'static> = FixedTimespan { offset: -15136, is_dst: false, name: Cow::Borrowed("LMT") };
+const T104: FixedTimespan<'static> = FixedTimespan { offset: -14400, is_dst: false, name: Cow::Borrowed("AST") };
+const T105: FixedTimespan<'static> = FixedTimespan { offset: -14832, is_dst: false, name: Cow::Borrowed("LMT") };
+const T106: FixedTimespan<'static> = FixedTimespan { offset: -18000, is_dst: false, name: Cow::Borrowed("EST") };
+const T107: FixedTimespan<'static> = FixedTimespan { offset: -11568, is_dst: false, name: Cow::Borrowed("LMT") };
+const T108: FixedTimespan<'static> = FixedTimespan { offset: -10800, is_dst: false, name: Cow::Borrowed("-03") };
+const T109: FixedTimespan<'static> = FixedTimespan { offset: -7200, is_dst: true, name: Cow::Borrowed("-02") };
+const T110: FixedTimespan<'static> = FixedTimespan { offset: -14028, is_dst: false, name: Cow::Borrowed("LMT") };
+const T111: FixedTimespan<'static> = FixedTimespan { offset: -15408, is_dst: false, name: Cow::Borrowed("CMT") };
+const T112: FixedTimespan<'static> = FixedTimespan { offset: -14400, is_dst: false, name: Cow::Borrowed("-04") };
+const T113: FixedTimespan<'static> = FixedTimespan { offset: -10800, is_dst: true, name: Cow::Borrowed("-03") };
+const T114: FixedTimespan<'static> = FixedTimespan { offset: -15788, is_dst: false, name: Cow::Borrowed("LMT") };
+const T115: FixedTimespan<'static> = FixedTimespan { offset: -15408, is_dst: false, name: Cow::Borrowed("LMT") };
+const T116: FixedTimespan<'static> = FixedTimespan { offset: -15672, is_dst: false, name: Cow::Borrowed("LMT") };
+const T117: FixedTimespan<'static> = FixedTimespan { offset: -16044, is_dst: false, name: Cow::Borrowed("LMT") };
+const T118: FixedTimespan<'static> = FixedTimespan { offset: -16516, is_dst: false, name: Cow::Borrowed("LMT") };
+const T119: FixedTimespan<'static> = FixedTimespan { offset: -16612, is_dst: false, name: Cow::Borrowed("LMT") };
+const T120: FixedTimespan<'static> = FixedTimespan { offset: -15700, is_dst: false, name: Cow::Borrowed("LMT") };
+const T121: FixedTimespan<'static> = FixedTimespan { offset: -16444, is_dst: false, name: Cow::Borrowed("LMT") };
+const T122: FixedTimespan<'static> = FixedTimespan { offset: -15924, is_dst: false, name: Cow::Borrowed("LMT") };
+const T123: FixedTimespan<'static> = FixedTimespan { offset: -15652, is_dst: false, name: Cow::Borrowed("LMT") };
+const T124: FixedTimespan<'static> = FixedTimespan { offset: -16392, is_dst: false, name: Cow::Borrowed("LMT") };
+const T125: FixedTimespan<'static> = FixedTimespan { offset: -16824, is_dst: false, name: Cow::Borrowed("LMT") };
+const T126: FixedTimespan<'static> = FixedTimespan { offset: -16200, is_dst: false, name: Cow::Borrowed("-0430") };
+const T127: FixedTimespan<'static> = FixedTimespan { offset: -13840, is_dst: false, name: Cow::Borrowed("LMT") };
+const T128: FixedTimespan<'static> = FixedTimespan { offset: -21988, is_dst: false, name: Cow::Borrowed("LMT") };
+const T129: FixedTimespan<'static> = FixedTimespan { offset: -21600, is_dst: false, name: Cow::Borrowed("CST") };
+const T130: FixedTimespan<'static> = FixedTimespan { offset: -18000, is_dst: true, name: Cow::Borrowed("CDT") };
+const T131: FixedTimespan<'static> = FixedTimespan { offset: -9244, is_dst: false, name: Cow::Borrowed("LMT") };
+const T132: FixedTimespan<'static> = FixedTimespan { offset: -25260, is_dst: false, name: Cow::Borrowed("LMT") };
+const T133: FixedTimespan<'static> = FixedTimespan { offset: -25200, is_dst: false, name: Cow::Borrowed("MST") };
+const T134: FixedTimespan<'static> = FixedTimespan { offset: -21600, is_dst: true, name: Cow::Borrowed("MDT") };
+const T135: FixedTimespan<'static> = FixedTimespan { offset: -14309, is_dst: false, name: Cow::Borrowed("LMT") };
+const T136: FixedTimespan<'static> = FixedTimespan { offset: -10800, is_dst: true, name: Cow::Borrowed("ADT") };
+const T137: FixedTimespan<'static> = FixedTimespan { offset: -12600, is_dst: true, name: Cow::Borrowed("-0330") };
+const T138: FixedTimespan<'static> = FixedTimespan { offset: -11636, is_dst: false, name: Cow::Borrowed("LMT") };
+const T139: FixedTimespan<'static> = FixedTimespan { offset: -21168, is_dst: false, name: Cow::Borrowed("LMT") };
+const T140: FixedTimespan<'static> = FixedTimespan { offset: -19800, is_dst: true, name: Cow::Borrowed("-0530") };
+const T141: FixedTimespan<'static> = FixedTimespan { offset: -18000, is_dst: true, name: Cow::Borrowed("CWT") };
+const T142: FixedTimespan<'static> = FixedTimespan { offset: -13708, is_dst: false, name: Cow::Borrowed("LMT") };
+const T143: FixedTimespan<'static> = FixedTimespan { offset: -10800, is_dst: true, name: Cow::Borrowed("AWT") };
+const T144: FixedTimespan<'static> = FixedTimespan { offset: -14560, is_dst: false, name: Cow::Borrowed("LMT") };
+const T145: FixedTimespan<'static> = FixedTimespan { offset: -17776, is_dst: false, name: Cow::Borrowed("LMT") };
+const T146: FixedTimespan<'static> = FixedTimespan { offset: -18000, is_dst: false, name: Cow::Borrowed("-05") };
+const T147: FixedTimespan<'static> = FixedTimespan { offset: -14400, is_dst: true, name: Cow::Borrowed("-04") };
+const T148: FixedTimespan<'static> = FixedTimespan { offset: -27889, is_dst: false, name: Cow::Borrowed("LMT") };
+const T149: FixedTimespan<'static> = FixedTimespan { offset: -28800, is_dst: false, name: Cow::Borrowed("PST") };
+const T150: FixedTimespan<'static> = FixedTimespan { offset: -25200, is_dst: true, name: Cow::Borrowed("PDT") };
+const T151: FixedTimespan<'static> = FixedTimespan { offset: -21600, is_dst: true, name: Cow::Borrowed("MWT") };
+const T152: FixedTimespan<'static> = FixedTimespan { offset: 0, is_dst: false, name: Cow::Borrowed("-00") };
+const T153: FixedTimespan<'static> = FixedTimespan { offset: -13108, is_dst: false, name: Cow::Borrowed("LMT") };
+const T154: FixedTimespan<'static> = FixedTimespan { offset: -20824, is_dst: false, name: Cow::Borrowed("LMT") };
+const T155: FixedTimespan<'static> = FixedTimespan { offset: -14400, is_dst: true, name: Cow::Borrowed("EDT") };
+const T156: FixedTimespan<'static> = FixedTimespan { offset: -16064, is_dst: false, name: Cow::Borrowed("LMT") };
+const T157: FixedTimespan<'static> = FixedTimespan { offset: -16060, is_dst: false, name: Cow::Borrowed("CMT") };
+const T158: FixedTimespan<'static> = FixedTimespan { offset: -12560, is_dst: false, name: Cow::Borrowed("LMT") };
+const T159: FixedTimespan<'static> = FixedTimespan { offset: -19532, is_dst: false, name: Cow::Borrowed("LMT") };
+const T160: FixedTimespan<'static> = FixedTimespan { offset: -18430, is_dst: false, name: Cow::Borrowed("KMT") };
+const T161: FixedTimespan<'static> = FixedTimespan { offset: -21036, is_dst: false, name: Cow::Borrowed("LMT") };
+const T162: FixedTimespan<'static> = FixedTimespan { offset: -25460, is_dst: false, name: Cow::Borrowed("LMT") };
+const T163: FixedTimespan<'static> = FixedTimespan { offset: -25556, is_dst: false, name: Cow::Borrowed("LMT") };
+const T164: FixedTimespan<'static> = FixedTimespan { offset: -20173, is_dst: false, name: Cow::Borrowed("LMT") };
+const T165: FixedTimespan<'static> = FixedTimespan { offset: -17296, is_dst: false, name: Cow::Borrowed("LMT") };
+const T166: FixedTimespan<'static> = FixedTimespan { offset: -16965, is_dst: false, name: Cow::Borrowed("SMT") };
+const T167: FixedTimespan<'static> = FixedTimespan { offset: -27964, is_dst: false, name: Cow::Borrowed("LMT") };
+const T168: FixedTimespan<'static> = FixedTimespan { offset: -13460, is_dst: false, name: Cow::Borrowed("LMT") };
+const T169: FixedTimespan<'static> = FixedTimespan { offset: -16547, is_dst: false, name: Cow::Borrowed("LMT") };
+const T170: FixedTimespan<'static> = FixedTimespan { offset: -4480, is_dst: false, name: Cow::Borrowed("LMT") };
+const T171: FixedTimespan<'static> = FixedTimespan { offset: -33460, is_dst: false, name: Cow::Borrowed("LMT") };
+const T172: FixedTimespan<'static> = FixedTimespan { offset: -32400, is_dst: false, name: Cow::Borrowed("YST") };
+const T173: FixedTimespan<'static> = FixedTimespan { offset: -28800, is_dst: true, name: Cow::Borrowed("YDT") };
+const T174: FixedTimespan<'static> = FixedTimespan { offset: -28800, is_dst: true, name: Cow::Borrowed("YWT") };
+const T175: FixedTimespan<'static> = FixedTimespan { offset: -25200, is_dst: true, name: Cow::Borrowed("YDDT") };
+const T176: FixedTimespan<'static> = FixedTimespan { offset: -28856, is_dst: false, name: Cow::Borrowed("LMT") };
+const T177: FixedTimespan<'static> = FixedTimespan { offset: -25200, is_dst: true, name: Cow::Borrowed("PWT") };
+const T178: FixedTimespan<'static> = FixedTimespan { offset: -25196, is_dst: false, name: Cow::Borrowed("LMT") };
+const T179: FixedTimespan<'static> = FixedTimespan { offset: -19931, is_dst: false, name: Cow::Borrowed("LMT") };
+const T180: FixedTimespan<'static> = FixedTimespan { offset: -14400, is_dst: true, name: Cow::Borrowed("EWT") };
+const T181: FixedTimespan<'static> = FixedTimespan { offset: -14736, is_dst: false, name: Cow::Borrowed("LMT") };
+const T182: FixedTimespan<'static> = FixedTimespan { offset: -27232, is_dst: false, name: Cow::Borrowed("LMT") };
+const T183: FixedTimespan<'static> = FixedTimespan { offset: -16768, is_dst: false, name: Cow::Borrowed("LMT") };
+const T184: FixedTimespan<'static> = FixedTimespan { offset: -21408, is_dst: false, name: Cow::Borrowed("LMT") };
+const T185: FixedTimespan<'static> = FixedTimespan { offset: -29447, is_dst: false, name: Cow::Borrowed("LMT") };
+const T186: FixedTimespan<'static> = FixedTimespan { offset: -9240, is_dst: false, name: Cow::Borrowed("LMT") };
+const T187: FixedTimespan<'static> = FixedTimespan { offset: -14388, is_dst: false, name: Cow::Borrowed("LMT") };
+const T188: FixedTimespan<'static> = FixedTimespan { offset: -14500, is_dst: false, name: Cow::Borrowed("LMT") };
+const T189: FixedTimespan<'static> = FixedTimespan { offset: -12652, is_dst: false, name: Cow::Borrowed("NST") };
+const T190: FixedTimespan<'static> = FixedTimespan { offset: -9052, is_dst: true, name: Cow::Borrowed("NDT") };
+const T191: FixedTimespan<'static> = FixedTimespan { offset: -12600, is_dst: false, name: Cow::Borrowed("NST") };
+const T192: FixedTimespan<'static> = FixedTimespan { offset: -9000, is_dst: true, name: Cow::Borrowed("NDT") };
+const T193: FixedTimespan<'static> = FixedTimespan { offset: -9000, is_dst: true, name: Cow::Borrowed("NWT") };
+const T194: FixedTimespan<'static> = FixedTimespan { offset: -7200, is_dst: true, name: Cow::Borrowed("ADDT") };
+const T195: FixedTimespan<'static> = FixedTimespan { offset: -17072, is_dst: false, name: Cow::Borrowed("LMT") };
+const T196: FixedTimespan<'static> = FixedTimespan { offset: -14820, is_dst: false, name: Cow::Borrowed("LMT") };
+const T197: FixedTimespan<'static> = FixedTimespan { offset: -14768, is_dst: false, name: Cow::Borrowed("LMT") };
+const T198: FixedTimespan<'static> = FixedTimespan { offset: -21724, is_dst: false, name: Cow::Borrowed("LMT") };
+const T199: FixedTimespan<'static> = FixedTimespan { offset: -19160, is_dst: false, name: Cow::Borrowed("LMT") };
+const T200: FixedTimespan<'static> = FixedTimespan { offset: -18840, is_dst: false, name: Cow::Borrowed("QMT") };
+const T201: FixedTimespan<'static> = FixedTimespan { offset: -13959, is_dst: false, name: Cow::Borrowed("LMT") };
+const T202: FixedTimespan<'static> = FixedTimespan { offset: -13500, is_dst: false, name: Cow::Borrowed("-0345") };
+const T203: FixedTimespan<'static> = FixedTimespan { offset: -15264, is_dst: false, name: Cow::Borrowed("LMT") };
+const T204: FixedTimespan<'static> = FixedTimespan { offset: -19768, is_dst: false, name: Cow::Borrowed("LMT") };
+const T205: FixedTimespan<'static> = FixedTimespan { offset: -19776, is_dst: false, name: Cow::Borrowed("HMT") };
+const T206: FixedTimespan<'static> = FixedTimespan { offset: -18000, is_dst: false, name: Cow::Borrowed("CST") };
+const T207: FixedTimespan<'static> = FixedTimespan { offset: -14400, is_dst: true, name: Cow::Borrowed("CDT") };
+const T208: FixedTimespan<'static> = FixedTimespan { offset: -26632, is_dst: false, name: Cow::Borrowed("LMT") };
+const T209: FixedTimespan<'static> = FixedTimespan { offset: -20678, is_dst: false, name: Cow::Borrowed("LMT") };
+const T210: FixedTimespan<'static> = FixedTimespan { offset: -20790, is_dst: false, name: Cow::Borrowed("LMT") };
+const T211: FixedTimespan<'static> = FixedTimespan { offset: -20723, is_dst: false, name: Cow::Borrowed("LMT") };
+const T212: FixedTimespan<'static> = FixedTimespan { offset: -20947, is_dst: false, name: Cow::Borrowed("LMT") };
+const T213: FixedTimespan<'static> = FixedTimespan { offset: -20823, is_dst: false, name: Cow::Borrowed("LMT") };
+const T214: FixedTimespan<'static> = FixedTimespan { offset: -20416, is_dst: false, name: Cow::Borrowed("LMT") };
+const T215: FixedTimespan<'static> = FixedTimespan { offset: -21007, is_dst: false, name: Cow::Borrowed("LMT") };
+const T216: FixedTimespan<'static> = FixedTimespan { offset: -20785, is_dst: false, name: Cow::Borrowed("LMT") };
+const T217: FixedTimespan<'static> = FixedTimespan { offset: -18430, is_dst: false, name: Cow::Borrowed("LMT") };
+const T218: FixedTimespan<'static> = FixedTimespan { offset: 54139, is_dst: false, name: Cow::Borrowed("LMT") };
+const T219: FixedTimespan<'static> = FixedTimespan { offset: -32261, is_dst: false, name: Cow::Borrowed("LMT") };
+const T220: FixedTimespan<'static> = FixedTimespan { offset: -20582, is_dst: false, name: Cow::Borrowed("LMT") };
+const T221: FixedTimespan<'static> = FixedTimespan { offset: -20364, is_dst: false, name: Cow::Borrowed("LMT") };
+const T222: FixedTimespan<'static> = FixedTimespan { offset: -16356, is_dst: false, name: Cow::Borrowed("LMT") };
+const T223: FixedTimespan<'static> = FixedTimespan { offset: -12756, is_dst: true, name: Cow::Borrowed("BST") };
+const T224: FixedTimespan<'static> = FixedTimespan { offset: -18492, is_dst: false, name: Cow::Borrowed("LMT") };
+const T225: FixedTimespan<'static> = FixedTimespan { offset: -18516, is_dst: false, name: Cow::Borrowed("LMT") };
+const T226: FixedTimespan<'static> = FixedTimespan { offset: -28378, is_dst: false, name: Cow::Borrowed("LMT") };
+const T227: FixedTimespan<'static> = FixedTimespan { offset: -8572, is_dst: false, name: Cow::Borrowed("LMT") };
+const T228: FixedTimespan<'static> = FixedTimespan { offset: -20708, is_dst: false, name: Cow::Borrowed("LMT") };
+const T229: FixedTimespan<'static> = FixedTimespan { offset: -20712, is_dst: false, name: Cow::Borrowed("MMT") };
+const T230: FixedTimespan<'static> = FixedTimespan { offset: -14404, is_dst: false, name: Cow::Borrowed("LMT") };
+const T231: FixedTimespan<'static> = FixedTimespan { offset: -14660, is_dst: false, name: Cow::Borrowed("LMT") };
+const T232: FixedTimespan<'static> = FixedTimespan { offset: -23400, is_dst: false, name: Cow::Borrowed("LMT") };
+const T233: FixedTimespan<'static> = FixedTimespan { offset: -25540, is_dst: false, name: Cow::Borrowed("LMT") };
+const T234: FixedTimespan<'static> = FixedTimespan { offset: -21027, is_dst: false, name: Cow::Borrowed("LMT") };
+const T235: FixedTimespan<'static> = FixedTimespan { offset: -21508, is_dst: false, name: Cow::Borrowed("LMT") };
+const T236: FixedTimespan<'static> = FixedTimespan { offset: 54822, is_dst: false, name: Cow::Borrowed("LMT") };
+const T237: FixedTimespan<'static> = FixedTimespan { offset: -31578, is_dst: false, name: Cow::Borrowed("LMT") };
+const T238: FixedTimespan<'static> = FixedTimespan { offset: -23796, is_dst: false, name: Cow::Borrowed("LMT") };
+const T239: FixedTimespan<'static> = FixedTimespan { offset: -13480, is_dst: false, name: Cow::Borrowed("LMT") };
+const T240: FixedTimespan<'static> = FixedTimespan { offset: -15548, is_dst: false, name: Cow::Borrowed("LMT") };
+const T241: FixedTimespan<'static> = FixedTimespan { offset: -24076, is_dst: false, name: Cow::Borrowed("LMT") };
+const T242: FixedTimespan<'static> = FixedTimespan { offset: -13491, is_dst: false, name: Cow::Borrowed("LMT") };
+const T243: FixedTimespan<'static> = FixedTimespan { offset: -12600, is_dst: false, name: Cow::Borrowed("-0330") };
+const T244: FixedTimespan<'static> = FixedTimespan { offset: -9000, is_dst: true, name: Cow::Borrowed("-0230") };
+const T245: FixedTimespan<'static> = FixedTimespan { offset: -5400, is_dst: true, name: Cow::Borrowed("-0130") };
+const T246: FixedTimespan<'static> = FixedTimespan { offset: -14932, is_dst: false, name: Cow::Borrowed("LMT") };
+const T247: FixedTimespan<'static> = FixedTimespan { offset: -18570, is_dst: false, name: Cow::Borrowed("LMT") };
+const T248: FixedTimespan<'static> = FixedTimespan { offset: -17762, is_dst: false, name: Cow::Borrowed("LMT") };
+const T249: FixedTimespan<'static> = FixedTimespan { offset: 46702, is_dst: false, name: Cow::Borrowed("LMT") };
+const T250: FixedTimespan<'static> = FixedTimespan { offset: -39698, is_dst: false, name: Cow::Borrowed("LMT") };
+const T251: FixedTimespan<'static> = FixedTimespan { offset: -7780, is_dst: false, name: Cow::Borrowed("LMT") };
+const T252: FixedTimespan<'static> = FixedTimespan { offset: -7200, is_dst: false, name: Cow::Borrowed("-02") };
+const T253: FixedTimespan<'static> = FixedTimespan { offset: -3600, is_dst: true, name: Cow::Borrowed("-01") };
+const T254: FixedTimespan<'static> = FixedTimespan { offset: -24427, is_dst: false, name: Cow::Borrowed("LMT") };
+const T255: FixedTimespan<'static> = FixedTimespan { offset: -24312, is_dst: false, name: Cow::Borrowed("LMT") };
+const T256: FixedTimespan<'static> = FixedTimespan { offset: -24339, is_dst: false, name: Cow::Borrowed("LMT") };
+const T257: FixedTimespan<'static> = FixedTimespan { offset: -12416, is_dst: false, name: Cow::Borrowed("LMT") };
+const T258: FixedTimespan<'static> = FixedTimespan { offset: -25060, is_dst: false, name: Cow::Borrowed("LMT") };
+const T259: FixedTimespan<'static> = FixedTimespan { offset: -19088, is_dst: false, name: Cow::Borrowed("LMT") };
+const T260: FixedTimespan<'static> = FixedTimespan { offset: -19176, is_dst: false, name: Cow::Borrowed("CMT") };
+const T261: FixedTimespan<'static> = FixedTimespan { offset: -13240, is_dst: false, name: Cow::Borrowed("LMT") };
+const T262: FixedTimespan<'static> = FixedTimespan { offset: -13252, is_dst: false, name: Cow::Borrowed("PMT") };
+const T263: FixedTimespan<'static> = FixedTimespan { offset: -13236, is_dst: false, name: Cow::Borrowed("PMT") };
+const T264: FixedTimespan<'static> = FixedTimespan { offset: -26898, is_dst: false, name: Cow::Borrowed("LMT") };
+const T265: FixedTimespan<'static> = FixedTimespan { offset: -17360, is_dst: false, name: Cow::Borrowed("LMT") };
+const T266: FixedTimespan<'static> = FixedTimespan { offset: -17340, is_dst: false, name: Cow::Borrowed("PPMT") };
+const T267: FixedTimespan<'static> = FixedTimespan { offset: -14764, is_dst: false, name: Cow::Borrowed("LMT") };
+const T268: FixedTimespan<'static> = FixedTimespan { offset: -15336, is_dst: false, name: Cow::Borrowed("LMT") };
+const T269: FixedTimespan<'static> = FixedTimespan { offset: -15865, is_dst: false, name: Cow::Borrowed("LMT") };
+const T270: FixedTimespan<'static> = FixedTimespan { offset: -17020, is_dst: false, name: Cow::Borrowed("LMT") };
+const T271: FixedTimespan<'static> = FixedTimespan { offset: -8376, is_dst: false, name: Cow::Borrowed("LMT") };
+const T272: FixedTimespan<'static> = FixedTimespan { offset: -25116, is_dst: false, name: Cow::Borrowed("LMT") };
+const T273: FixedTimespan<'static> = FixedTimespan { offset: -16272, is_dst: false, name: Cow::Borrowed("LMT") };
+const T274: FixedTimespan<'static> = FixedTimespan { offset: -13128, is_dst: false, name: Cow::Borrowed("LMT") };
+const T275: FixedTimespan<'static> = FixedTimespan { offset: -16965, is_dst: false, name: Cow::Borrowed("LMT") };
+const T276: FixedTimespan<'static> = FixedTimespan { offset: -16776, is_dst: false, name: Cow::Borrowed("LMT") };
+const T277: FixedTimespan<'static> = FixedTimespan { offset: -16800, is_dst: false, name: Cow::Borrowed("SDMT") };
+const T278: FixedTimespan<'static> = FixedTimespan { offset: -16200, is_dst: true, name: Cow::Borrowed("-0430") };
+const T279: FixedTimespan<'static> = FixedTimespan { offset: -11188, is_dst: false, name: Cow::Borrowed("LMT") };
+const T280: FixedTimespan<'static> = FixedTimespan { offset: -5272, is_dst: false, name: Cow::Borrowed("LMT") };
+const T281: FixedTimespan<'static> = FixedTimespan { offset: 53927, is_dst: false, name: Cow::Borrowed("LMT") };
+const T282: FixedTimespan<'static> = FixedTimespan { offset: -32473, is_dst: false, name: Cow::Borrowed("LMT") };
+const T283: FixedTimespan<'static> = FixedTimespan { offset: -12652, is_dst: false, name: Cow::Borrowed("LMT") };
+const T284: FixedTimespan<'static> = FixedTimespan { offset: -5400, is_dst: true, name: Cow::Borrowed("NDDT") };
+const T285: FixedTimespan<'static> = FixedTimespan { offset: -15052, is_dst: false, name: Cow::Borrowed("LMT") };
+const T286: FixedTimespan<'static> = FixedTimespan { offset: -14640, is_dst: false, name: Cow::Borrowed("LMT") };
+const T287: FixedTimespan<'static> = FixedTimespan { offset: -15584, is_dst: false, name: Cow::Borrowed("LMT") };
+const T288: FixedTimespan<'static> = FixedTimespan { offset: -14696, is_dst: false, name: Cow::Borrowed("LMT") };
+const T289: FixedTimespan<'static> = FixedTimespan { offset: -25880, is_dst: false, name: Cow::Borrowed("LMT") };
+const T290: FixedTimespan<'static> = FixedTimespan { offset: -20932, is_dst: false, name: Cow::Borrowed("LMT") };
+const T291: FixedTimespan<'static> = FixedTimespan { offset: -16508, is_dst: false, name: Cow::Borrowed("LMT") };
+const T292: FixedTimespan<'static> = FixedTimespan { offset: -28084, is_dst: false, name: Cow::Borrowed("LMT") };
+const T293: FixedTimespan<'static> = FixedTimespan { offset: -19052, is_dst: false, name: Cow::Borrowed("LMT") };
+const T294: FixedTimespan<'static> = FixedTimespan { offset: -15508, is_dst: false, name: Cow::Borrowed("LMT") };
+const T295: FixedTimespan<'static> = FixedTimespan { offset: -29548, is_dst: false, name: Cow::Borrowed("LMT") };
+const T296: FixedTimespan<'static> = FixedTimespan { offset: -32412, is_dst: false, name: Cow::Borrowed("LMT") };
+const T297: FixedTimespan<'static> = FixedTimespan { offset: -23316, is_dst: false, name: Cow::Borrowed("LMT") };
+const T298: FixedTimespan<'static> = FixedTimespan { offset: 52865, is_dst: false, name: Cow::Borrowed("LMT") };
+const T299: FixedTimespan<'static> = FixedTimespan { offset: -33535, is_dst: false, name: Cow::Borrowed("LMT") };
+const T300: FixedTimespan<'static> = FixedTimespan { offset: 28800, is_dst: false, name: Cow::Borrowed("+08") };
+const T301: FixedTimespan<'static> = FixedTimespan { offset: 39600, is_dst: false, name: Cow::Borrowed("+11") };
+const T302: FixedTimespan<'static> = FixedTimespan { offset: 25200, is_dst: false, name: Cow::Borrowed("+07") };
+const T303: FixedTimespan<'static> = FixedTimespan { offset: 18000, is_dst: false, name: Cow::Borrowed("+05") };
+const T304: FixedTimespan<'static> = FixedTimespan { offset: 36000, is_dst: false, name: Cow::Borrowed("+10") };
+const T305: FixedTimespan<'static> = FixedTimespan { offset: 36000, is_dst: false, name: Cow::Borrowed("AEST") };
+const T306: FixedTimespan<'static> = FixedTimespan { offset: 39600, is_dst: true, name: Cow::Borrowed("AEDT") };
+const T307: FixedTimespan<'static> = FixedTimespan { offset: 21600, is_dst: false, name: Cow::Borrowed("+06") };
+const T308: FixedTimespan<'static> = FixedTimespan { offset: 43200, is_dst: false, name: Cow::Borrowed("NZST") };
+const T309: FixedTimespan<'static> = FixedTimespan { offset: 46800, is_dst: true, name: Cow::Borrowed("NZDT") };
+const T310: FixedTimespan<'static> = FixedTimespan { offset: 10800, is_dst: false, name: Cow::Borrowed("+03") };
+const T311: FixedTimespan<'static> = FixedTimespan { offset: 7200, is_dst: true, name: Cow::Borrowed("+02") };
+const T312: FixedTimespan<'static> = FixedTimespan { offset: 10794, is_dst: false, name: Cow::Borrowed("LMT") };
+const T313: FixedTimespan<'static> = FixedTimespan { offset: 18468, is_dst: false, name: Cow::Borrowed("LMT") };
+const T314: FixedTimespan<'static> = FixedTimespan { offset: 25200, is_dst: true, name: Cow::Borrowed("+07") };
+const T315: FixedTimespan<'static> = FixedTimespan { offset: 21600, is_dst: true, name: Cow::Borrowed("+06") };
+const T316: FixedTimespan<'static> = FixedTimespan { offset: 8624, is_dst: false, name: Cow::Borrowed("LMT") };
+const T317: FixedTimespan<'static> = FixedTimespan { offset: 42596, is_dst: false, name: Cow::Borrowed("LMT") };
+const T318: FixedTimespan<'static> = FixedTimespan { offset: 43200, is_dst: false, name: Cow::Borrowed("+12") };
+const T319: FixedTimespan<'static> = FixedTimespan { offset: 46800, is_dst: false, name: Cow::Borrowed("+13") };
+const T320: FixedTimespan<'static> = FixedTimespan { offset: 50400, is_dst: true, name: Cow::Borrowed("+14") };
+const T321: FixedTimespan<'static> = FixedTimespan { offset: 46800, is_dst: true, name: Cow::Borrowed("+13") };
+const T322: FixedTimespan<'static> = FixedTimespan { offset: 43200, is_dst: true, name: Cow::Borrowed("+12") };
+const T323: FixedTimespan<'static> = FixedTimespan { offset: 12064, is_dst: false, name: Cow::Borrowed("LMT") };
+const T324: FixedTimespan<'static> = FixedTimespan { offset: 14400, is_dst: false, name: Cow::Borrowed("+04") };
+const T325: FixedTimespan<'static> = FixedTimespan { offset: 18000, is_dst: true, name: Cow::Borrowed("+05") };
+const T326: FixedTimespan<'static> = FixedTimespan { offset: 13720, is_dst: false, name: Cow::Borrowed("LMT") };
+const T327: FixedTimespan<'static> = FixedTimespan { offset: 14012, is_dst: false, name: Cow::Borrowed("LMT") };
+const T328: FixedTimespan<'static> = FixedTimespan { offset: 12464, is_dst: false, name: Cow::Borrowed("LMT") };
+const T329: FixedTimespan<'static> = FixedTimespan { offset: 10660, is_dst: false, name: Cow::Borrowed("LMT") };
+const T330: FixedTimespan<'static> = FixedTimespan { offset: 10656, is_dst: false, name: Cow::Borrowed("BMT") };
+const T331: FixedTimespan<'static> = FixedTimespan { offset: 14400, is_dst: true, name: Cow::Borrowed("+04") };
+const T332: FixedTimespan<'static> = FixedTimespan { offset: 12140, is_dst: false, name: Cow::Borrowed("LMT") };
+const T333: FixedTimespan<'static> = FixedTimespan { offset: 12600, is_dst: false, name: Cow::Borrowed("+0330") };
+const T334: FixedTimespan<'static> = FixedTimespan { offset: 11964, is_dst: false, name: Cow::Borrowed("LMT") };
+const T335: FixedTimespan<'static> = FixedTimespan { offset: 24124, is_dst: false, name: Cow::Borrowed("LMT") };
+const T336: FixedTimespan<'static> = FixedTimespan { offset: 20100, is_dst: false, name: Cow::Borrowed("LMT") };
+const T337: FixedTimespan<'static> = FixedTimespan { offset: 28800, is_dst: true, name: Cow::Borrowed("+08") };
+const T338: FixedTimespan<'static> = FixedTimespan { offset: 8520, is_dst: false, name: Cow::Borrowed("LMT") };
+const T339: FixedTimespan<'static> = FixedTimespan { offset: 17904, is_dst: false, name: Cow::Borrowed("LMT") };
+const T340: FixedTimespan<'static> = FixedTimespan { offset: 27580, is_dst: false, name: Cow::Borrowed("LMT") };
+const T341: FixedTimespan<'static> = FixedTimespan { offset: 27000, is_dst: false, name: Cow::Borrowed("+0730") };
+const T342: FixedTimespan<'static> = FixedTimespan { offset: 27232, is_dst: false, name: Cow::Borrowed("LMT") };
+const T343: FixedTimespan<'static> = FixedTimespan { offset: 32400, is_dst: false, name: Cow::Borrowed("+09") };
+const T344: FixedTimespan<'static> = FixedTimespan { offset: 36000, is_dst: true, name: Cow::Borrowed("+10") };
+const T345: FixedTimespan<'static> = FixedTimespan { offset: 32400, is_dst: true, name: Cow::Borrowed("+09") };
+const T346: FixedTimespan<'static> = FixedTimespan { offset: 19164, is_dst: false, name: Cow::Borrowed("LMT") };
+const T347: FixedTimespan<'static> = FixedTimespan { offset: 19172, is_dst: false, name: Cow::Borrowed("MMT") };
+const T348: FixedTimespan<'static> = FixedTimespan { offset: 19800, is_dst: false, name: Cow::Borrowed("+0530") };
+const T349: FixedTimespan<'static> = FixedTimespan { offset: 23400, is_dst: true, name: Cow::Borrowed("+0630") };
+const T350: FixedTimespan<'static> = FixedTimespan { offset: 23400, is_dst: false, name: Cow::Borrowed("+0630") };
+const T351: FixedTimespan<'static> = FixedTimespan { offset: 8712, is_dst: false, name: Cow::Borrowed("LMT") };
+const T352: FixedTimespan<'static> = FixedTimespan { offset: 21700, is_dst: false, name: Cow::Borrowed("LMT") };
+const T353: FixedTimespan<'static> = FixedTimespan { offset: 21200, is_dst: false, name: Cow::Borrowed("HMT") };
+const T354: FixedTimespan<'static> = FixedTimespan { offset: 30140, is_dst: false, name: Cow::Borrowed("LMT") };
+const T355: FixedTimespan<'static> = FixedTimespan { offset: 13272, is_dst: false, name: Cow::Borrowed("LMT") };
+const T356: FixedTimespan<'static> = FixedTimespan { offset: 16512, is_dst: false, name: Cow::Borrowed("LMT") };
+const T357: FixedTimespan<'static> = FixedTimespan { offset: 8148, is_dst: false, name: Cow::Borrowed("LMT") };
+const T358: FixedTimespan<'static> = FixedTimespan { offset: 8272, is_dst: false, name: Cow::Borrowed("LMT") };
+const T359: FixedTimespan<'static> = FixedTimespan { offset: 7200, is_dst: false, name: Cow::Borrowed("IST") };
+const T360: FixedTimespan<'static> = FixedTimespan { offset: 10800, is_dst: true, name: Cow::Borrowed("IDT") };
+const T361: FixedTimespan<'static> = FixedTimespan { offset: 8423, is_dst: false, name: Cow::Borrowed("LMT") };
+const T362: FixedTimespan<'static> = FixedTimespan { offset: 25590, is_dst: false, name: Cow::Borrowed("LMT") };
+const T363: FixedTimespan<'static> = FixedTimespan { offset: 27402, is_dst: false, name: Cow::Borrowed("LMT") };
+const T364: FixedTimespan<'static> = FixedTimespan { offset: 28800, is_dst: false, name: Cow::Borrowed("HKT") };
+const T365: FixedTimespan<'static> = FixedTimespan { offset: 32400, is_dst: true, name: Cow::Borrowed("HKST") };
+const T366: FixedTimespan<'static> = FixedTimespan { offset: 30600, is_dst: true, name: Cow::Borrowed("HKWT") };
+const T367: FixedTimespan<'static> = FixedTimespan { offset: 32400, is_dst: false, name: Cow::Borrowed("JST") };
+const T368: FixedTimespan<'static> = FixedTimespan { offset: 21996, is_dst: false, name: Cow::Borrowed("LMT") };
+const T369: FixedTimespan<'static> = FixedTimespan { offset: 25025, is_dst: false, name: Cow::Borrowed("LMT") };
+const T370: FixedTimespan<'static> = FixedTimespan { offset: 25632, is_dst: false, name: Cow::Borrowed("LMT") };
+const T371: FixedTimespan<'static> = FixedTimespan { offset: 26400, is_dst: false, name: Cow::Borrowed("+0720") };
+const T372: FixedTimespan<'static> = FixedTimespan { offset: 25200, is_dst: false, name: Cow::Borrowed("WIB") };
+const T373: FixedTimespan<'static> = FixedTimespan { offset: 33768, is_dst: false, name: Cow::Borrowed("LMT") };
+const T374: FixedTimespan<'static> = FixedTimespan { offset: 34200, is_dst: false, name: Cow::Borrowed("+0930") };
+const T375: FixedTimespan<'static> = FixedTimespan { offset: 32400, is_dst: false, name: Cow::Borrowed("WIT") };
+const T376: FixedTimespan<'static> = FixedTimespan { offset: 8454, is_dst: false, name: Cow::Borrowed("LMT") };
+const T377: FixedTimespan<'static> = FixedTimespan { offset: 8440, is_dst: false, name: Cow::Borrowed("JMT") };
+const T378: FixedTimespan<'static> = FixedTimespan { offset: 14400, is_dst: true, name: Cow::Borrowed("IDDT") };
+const T379: FixedTimespan<'static> = FixedTimespan { offset: 16608, is_dst: false, name: Cow::Borrowed("LMT") };
+const T380: FixedTimespan<'static> = FixedTimespan { offset: 16200, is_dst: false, name: Cow::Borrowed("+0430") };
+const T381: FixedTimespan<'static> = FixedTimespan { offset: 38076, is_dst: false, name: Cow::Borrowed("LMT") };
+const T382: FixedTimespan<'static> = FixedTimespan { offset: 16092, is_dst: false, name: Cow::Borrowed("LMT") };
+const T383: FixedTimespan<'static> = FixedTimespan { offset: 21600, is_dst: true, name: Cow::Borrowed("PKST") };
+const T384: FixedTimespan<'static> = FixedTimespan { offset: 18000, is_dst: false, name: Cow::Borrowed("PKT") };
+const T385: FixedTimespan<'static> = FixedTimespan { offset: 20476, is_dst: false, name: Cow::Borrowed("LMT") };
+const T386: FixedTimespan<'static> = FixedTimespan { offset: 20700, is_dst: false, name: Cow::Borrowed("+0545") };
+const T387: FixedTimespan<'static> = FixedTimespan { offset: 32533, is_dst: false, name: Cow::Borrowed("LMT") };
+const T388: FixedTimespan<'static> = FixedTimespan { offset: 39600, is_dst: true, name: Cow::Borrowed("+11") };
+const T389: FixedTimespan<'static> = FixedTimespan { offset: 21208, is_dst: false, name: Cow::Borrowed("LMT") };
+const T390: FixedTimespan<'static> = FixedTimespan { offset: 19270, is_dst: false, name: Cow::Borrowed("MMT") };
+const T391: FixedTimespan<'static> = FixedTimespan { offset: 19800, is_dst: false, name: Cow::Borrowed("IST") };
+const T392: FixedTimespan<'static> = FixedTimespan { offset: 22286, is_dst: false, name: Cow::Borrowed("LMT") };
+const T393: FixedTimespan<'static> = FixedTimespan { offset: 24406, is_dst: false, name: Cow::Borrowed("LMT") };
+const T394: FixedTimespan<'static> = FixedTimespan { offset: 24925, is_dst: false, name: Cow::Borrowed("SMT") };
+const T395: FixedTimespan<'static> = FixedTimespan { offset: 26400, is_dst: true, name: Cow::Borrowed("+0720") };
+const T396: FixedTimespan<'static> = FixedTimespan { offset: 26480, is_dst: false, name: Cow::Borrowed("LMT") };
+const T397: FixedTimespan<'static> = FixedTimespan { offset: 30000, is_dst: true, name: Cow::Borrowed("+0820") };
+const T398: FixedTimespan<'static> = FixedTimespan { offset: 11516, is_dst: false, name: Cow::Borrowed("LMT") };
+const T399: FixedTimespan<'static> = FixedTimespan { offset: 27250, is_dst: false, name: Cow::Borrowed("LMT") };
+const T400: FixedTimespan<'static> = FixedTimespan { offset: 28800, is_dst: false, name: Cow::Borrowed("CST") };
+const T401: FixedTimespan<'static> = FixedTimespan { offset: 32400, is_dst: true, name: Cow::Borrowed("CDT") };
+const T402: FixedTimespan<'static> = FixedTimespan { offset: 36192, is_dst: false, name: Cow::Borrowed("LMT") };
+const T403: FixedTimespan<'static> = FixedTimespan { offset: 28656, is_dst: false, name: Cow::Borrowed("LMT") };
+const T404: FixedTimespan<'static> = FixedTimespan { offset: 28800, is_dst: false, name: Cow::Borrowed("WITA") };
+const T405: FixedTimespan<'static> = FixedTimespan { offset: -57368, is_dst: false, name: Cow::Borrowed("LMT") };
+const T406: FixedTimespan<'static> = FixedTimespan { offset: 29032, is_dst: false, name: Cow::Borrowed("LMT") };
+const T407: FixedTimespan<'static> = FixedTimespan { offset: 28800, is_dst: false, name: Cow::Borrowed("PST") };
+const T408: FixedTimespan<'static> = FixedTimespan { offset: 32400, is_dst: true, name: Cow::Borrowed("PDT") };
+const T409: FixedTimespan<'static> = FixedTimespan { offset: 14064, is_dst: false, name: Cow::Borrowed("LMT") };
+const T410: FixedTimespan<'static> = FixedTimespan { offset: 8008, is_dst: false, name: Cow::Borrowed("LMT") };
+const T411: FixedTimespan<'static> = FixedTimespan { offset: 20928, is_dst: false, name: Cow::Borrowed("LMT") };
+const T412: FixedTimespan<'static> = FixedTimespan { offset: 19900, is_dst: false, name: Cow::Borrowed("LMT") };
+const T413: FixedTimespan<'static> = FixedTimespan { offset: 17610, is_dst: false, name: Cow::Borrowed("LMT") };
+const T414: FixedTimespan<'static> = FixedTimespan { offset: 12324, is_dst: false, name: Cow::Borrowed("LMT") };
+const T415: FixedTimespan<'static> = FixedTimespan { offset: 25180, is_dst: false, name: Cow::Borrowed("LMT") };
+const T416: FixedTimespan<'static> = FixedTimespan { offset: 25590, is_dst: false, name: Cow::Borrowed("PLMT") };
+const T417: FixedTimespan<'static> = FixedTimespan { offset: 26240, is_dst: false, name: Cow::Borrowed("LMT") };
+const T418: FixedTimespan<'static> = FixedTimespan { offset: 30180, is_dst: false, name: Cow::Borrowed("LMT") };
+const T419: FixedTimespan<'static> = FixedTimespan { offset: 30600, is_dst: false, name: Cow::Borrowed("KST") };
+const T420: FixedTimespan<'static> = FixedTimespan { offset: 32400, is_dst: false, name: Cow::Borrowed("KST") };
+const T421: FixedTimespan<'static> = FixedTimespan { offset: 12368, is_dst: false, name: Cow::Borrowed("LMT") };
+const T422: FixedTimespan<'static> = FixedTimespan { offset: 15268, is_dst: false, name: Cow::Borrowed("LMT") };
+const T423: FixedTimespan<'static> = FixedTimespan { offset: 15712, is_dst: false, name: Cow::Borrowed("LMT") };
+const T424: FixedTimespan<'static> = FixedTimespan { offset: 11212, is_dst: false, name: Cow::Borrowed("LMT") };
+const T425: FixedTimespan<'static> = FixedTimespan { offset: 34248, is_dst: false, name: Cow::Borrowed("LMT") };
+const T426: FixedTimespan<'static> = FixedTimespan { offset: 16073, is_dst: false, name: Cow::Borrowed("LMT") };
+const T427: FixedTimespan<'static> = FixedTimespan { offset: 30472, is_dst: false, name: Cow::Borrowed("LMT") };
+const T428: FixedTimespan<'static> = FixedTimespan { offset: 36000, is_dst: true, name: Cow::Borrowed("KDT") };
+const T429: FixedTimespan<'static> = FixedTimespan { offset: 34200, is_dst: true, name: Cow::Borrowed("KDT") };
+const T430: FixedTimespan<'static> = FixedTimespan { offset: 29143, is_dst: false, name: Cow::Borrowed("LMT") };
+const T431: FixedTimespan<'static> = FixedTimespan { offset: 24925, is_dst: false, name: Cow::Borrowed("LMT") };
+const T432: FixedTimespan<'static> = FixedTimespan { offset: 36892, is_dst: false, name: Cow::Borrowed("LMT") };
+const T433: FixedTimespan<'static> = FixedTimespan { offset: 29160, is_dst: false, name: Cow::Borrowed("LMT") };
+const T434: FixedTimespan<'static> = FixedTimespan { offset: 16631, is_dst: false, name: Cow::Borrowed("LMT") };
+const T435: FixedTimespan<'static> = FixedTimespan { offset: 10751, is_dst: false, name: Cow::Borrowed("LMT") };
+const T436: FixedTimespan<'static> = FixedTimespan { offset: 12344, is_dst: false, name: Cow::Borrowed("LMT") };
+const T437: FixedTimespan<'static> = FixedTimespan { offset: 16200, is_dst: true, name: Cow::Borrowed("+0430") };
+const T438: FixedTimespan<'static> = FixedTimespan { offset: 21516, is_dst: false, name: Cow::Borrowed("LMT") };
+const T439: FixedTimespan<'static> = FixedTimespan { offset: 33539, is_dst: false, name: Cow::Borrowed("LMT") };
+const T440: FixedTimespan<'static> = FixedTimespan { offset: 36000, is_dst: true, name: Cow::Borrowed("JDT") };
+const T441: FixedTimespan<'static> = FixedTimespan { offset: 20391, is_dst: false, name: Cow::Borrowed("LMT") };
+const T442: FixedTimespan<'static> = FixedTimespan { offset: 25652, is_dst: false, name: Cow::Borrowed("LMT") };
+const T443: FixedTimespan<'static> = FixedTimespan { offset: 21020, is_dst: false, name: Cow::Borrowed("LMT") };
+const T444: FixedTimespan<'static> = FixedTimespan { offset: 34374, is_dst: false, name: Cow::Borrowed("LMT") };
+const T445: FixedTimespan<'static> = FixedTimespan { offset: 24624, is_dst: false, name: Cow::Borrowed("LMT") };
+const T446: FixedTimespan<'static> = FixedTimespan { offset: 31651, is_dst: false, name: Cow::Borrowed("LMT") };
+const T447: FixedTimespan<'static> = FixedTimespan { offset: 31138, is_dst: false, name: Cow::Borrowed("LMT") };
+const T448: FixedTimespan<'static> = FixedTimespan { offset: 23087, is_dst: false, name: Cow::Borrowed("LMT") };
+const T449: FixedTimespan<'static> = FixedTimespan { offset: 14553, is_dst: false, name: Cow::Borrowed("LMT") };
+const T450: FixedTimespan<'static> = FixedTimespan { offset: 13505, is_dst: false, name: Cow::Borrowed("PMT") };
+const T451: FixedTimespan<'static> = FixedTimespan { offset: 10680, is_dst: false, name: Cow::Borrowed("LMT") };
+const T452: FixedTimespan<'static> = FixedTimespan { offset: -6160, is_dst: false, name: Cow::Borrowed("LMT") };
+const T453: FixedTimespan<'static> = FixedTimespan { offset: -6872, is_dst: false, name: Cow::Borrowed("HMT") };
+const T454: FixedTimespan<'static> = FixedTimespan { offset: -15558, is_dst: false, name: Cow::Borrowed("LMT") };
+const T455: FixedTimespan<'static> = FixedTimespan { offset: -11958, is_dst: true, name: Cow::Borrowed("BST") };
+const T456: FixedTimespan<'static> = FixedTimespan { offset: -15558, is_dst: false, name: Cow::Borrowed("BMT") };
+const T457: FixedTimespan<'static> = FixedTimespan { offset: -3696, is_dst: false, name: Cow::Borrowed("LMT") };
+const T458: FixedTimespan<'static> = FixedTimespan { offset: -5644, is_dst: false, name: Cow::Borrowed("LMT") };
+const T459: FixedTimespan<'static> = FixedTimespan { offset: -1624, is_dst: false, name: Cow::Borrowed("LMT") };
+const T460: FixedTimespan<'static> = FixedTimespan { offset: -4056, is_dst: false, name: Cow::Borrowed("LMT") };
+const T461: FixedTimespan<'static> = FixedTimespan { offset: -5280, is_dst: false, name: Cow::Borrowed("LMT") };
+const T462: FixedTimespan<'static> = FixedTimespan { offset: -8768, is_dst: false, name: Cow::Borrowed("LMT") };
+const T463: FixedTimespan<'static> = FixedTimespan { offset: -1368, is_dst: false, name: Cow::Borrowed("LMT") };
+const T464: FixedTimespan<'static> = FixedTimespan { offset: -13884, is_dst: false, name: Cow::Borrowed("LMT") };
+const T465: FixedTimespan<'static> = FixedTimespan { offset: 33260, is_dst: false, name: Cow::Borrowed("LMT") };
+const T466: FixedTimespan<'static> = FixedTimespan { offset: 32400, is_dst: false, name: Cow::Borrowed("ACST") };
+const T467: FixedTimespan<'static> = FixedTimespan { offset: 34200, is_dst: false, name: Cow::Borrowed("ACST") };
+const T468: FixedTimespan<'static> = FixedTimespan { offset: 37800, is_dst: true, name: Cow::Borrowed("ACDT") };
+const T469: FixedTimespan<'static> = FixedTimespan { offset: 36728, is_dst: false, name: Cow::Borrowed("LMT") };
+const T470: FixedTimespan<'static> = FixedTimespan { offset: 33948, is_dst: false, name: Cow::Borrowed("LMT") };
+const T471: FixedTimespan<'static> = FixedTimespan { offset: 31400, is_dst: false, name: Cow::Borrowed("LMT") };
+const T472: FixedTimespan<'static> = FixedTimespan { offset: 30928, is_dst: false, name: Cow::Borrowed("LMT") };
+const T473: FixedTimespan<'static> = FixedTimespan { offset: 31500, is_dst: false, name: Cow::Borrowed("+0845") };
+const T474: FixedTimespan<'static> = FixedTimespan { offset: 35100, is_dst: true, name: Cow::Borrowed("+0945") };
+const T475: FixedTimespan<'static> = FixedTimespan { offset: 35356, is_dst: false, name: Cow::Borrowed("LMT") };
+const T476: FixedTimespan<'static> = FixedTimespan { offset: 35756, is_dst: false, name: Cow::Borrowed("LMT") };
+const T477: FixedTimespan<'static> = FixedTimespan { offset: 38180, is_dst: false, name: Cow::Borrowed("LMT") };
+const T478: FixedTimespan<'static> = FixedTimespan { offset: 37800, is_dst: false, name: Cow::Borrowed("+1030") };
+const T479: FixedTimespan<'static> = FixedTimespan { offset: 41400, is_dst: true, name: Cow::Borrowed("+1130") };
+const T480: FixedTimespan<'static> = FixedTimespan { offset: 34792, is_dst: false, name: Cow::Borrowed("LMT") };
+const T481: FixedTimespan<'static> = FixedTimespan { offset: 27804, is_dst: false, name: Cow::Borrowed("LMT") };
+const T482: FixedTimespan<'static> = FixedTimespan { offset: 28800, is_dst: false, name: Cow::Borrowed("AWST") };
+const T483: FixedTimespan<'static> = FixedTimespan { offset: 32400, is_dst: true, name: Cow::Borrowed("AWDT") };
+const T484: FixedTimespan<'static> = FixedTimespan { offset: 36292, is_dst: false, name: Cow::Borrowed("LMT") };
+const T485: FixedTimespan<'static> = FixedTimespan { offset: -36000, is_dst: false, name: Cow::Borrowed("-10") };
+const T486: FixedTimespan<'static> = FixedTimespan { offset: -39600, is_dst: false, name: Cow::Borrowed("-11") };
+const T487: FixedTimespan<'static> = FixedTimespan { offset: -43200, is_dst: false, name: Cow::Borrowed("-12") };
+const T488: FixedTimespan<'static> = FixedTimespan { offset: -21600, is_dst: false, name: Cow::Borrowed("-06") };
+const T489: FixedTimespan<'static> = FixedTimespan { offset: -25200, is_dst: false, name: Cow::Borrowed("-07") };
+const T490: FixedTimespan<'static> = FixedTimespan { offset: -28800, is_dst: false, name: Cow::Borrowed("-08") };
+const T491: FixedTimespan<'static> = FixedTimespan { offset: -32400, is_dst: false, name: Cow::Borrowed("-09") };
+const T492: FixedTimespan<'static> = FixedTimespan { offset: 50400, is_dst: false, name: Cow::Borrowed("+14") };
+const T493: FixedTimespan<'static> = FixedTimespan { offset: 7200, is_dst: false, name: Cow::Borrowed("+02") };
+const T494: FixedTimespan<'static> = FixedTimespan { offset: 0, is_dst: false, name: Cow::Borrowed("UTC") };
+const T495: FixedTimespan<'static> = FixedTimespan { offset: 1172, is_dst: false, name: Cow::Borrowed("LMT") };
+const T496: FixedTimespan<'static> = FixedTimespan { offset: 4772, is_dst: true, name: Cow::Borrowed("NST") };
+const T497: FixedTimespan<'static> = FixedTimespan { offset: 1172, is_dst: false, name: Cow::Borrowed("AMT") };
+const T498: FixedTimespan<'static> = FixedTimespan { offset: 4800, is_dst: true, name: Cow::Borrowed("+0120") };
+const T499: FixedTimespan<'static> = FixedTimespan { offset: 1200, is_dst: false, name: Cow::Borrowed("+0020") };
+const T500: FixedTimespan<'static> = FixedTimespan { offset: 364, is_dst: false, name: Cow::Borrowed("LMT") };
+const T501: FixedTimespan<'static> = FixedTimespan { offset: 11532, is_dst: false, name: Cow::Borrowed("LMT") };
+const T502: FixedTimespan<'static> = FixedTimespan { offset: 5692, is_dst: false, name: Cow::Borrowed("LMT") };
+const T503: FixedTimespan<'static> = FixedTimespan { offset: 4920, is_dst: false, name: Cow::Borrowed("LMT") };
+const T504: FixedTimespan<'static> = FixedTimespan { offset: 3208, is_dst: false, name: Cow::Borrowed("LMT") };
+const T505: FixedTimespan<'static> = FixedTimespan { offset: 10800, is_dst: true, name: Cow::Borrowed("CEMT") };
+const T506: FixedTimespan<'static> = FixedTimespan { offset: 1050, is_dst: false, name: Cow::Borrowed("LMT") };
+const T507: FixedTimespan<'static> = FixedTimespan { offset: 6264, is_dst: false, name: Cow::Borrowed("LMT") };
+const T508: FixedTimespan<'static> = FixedTimespan { offset: 4580, is_dst: false, name: Cow::Borrowed("LMT") };
+const T509: FixedTimespan<'static> = FixedTimespan { offset: 6920, is_dst: false, name: Cow::Borrowed("LMT") };
+const T510: FixedTimespan<'static> = FixedTimespan { offset: 6900, is_dst: false, name: Cow::Borrowed("CMT") };
+const T511: FixedTimespan<'static> = FixedTimespan { offset: 6264, is_dst: false, name: Cow::Borrowed("BMT") };
+const T512: FixedTimespan<'static> = FixedTimespan { offset: 10800, is_dst: false, name: Cow::Borrowed("MSK") };
+const T513: FixedTimespan<'static> = FixedTimespan { offset: 14400, is_dst: true, name: Cow::Borrowed("MSD") };
+const T514: FixedTimespan<'static> = FixedTimespan { offset: 3020, is_dst: false, name: Cow::Borrowed("LMT") };
+const T515: FixedTimespan<'static> = FixedTimespan { offset: -1521, is_dst: false, name: Cow::Borrowed("LMT") };
+const T516: FixedTimespan<'static> = FixedTimespan { offset: 2079, is_dst: true, name: Cow::Borrowed("IST") };
+const T517: FixedTimespan<'static> = FixedTimespan { offset: 3600, is_dst: true, name: Cow::Borrowed("BST") };
+const T518: FixedTimespan<'static> = FixedTimespan { offset: 3600, is_dst: true, name: Cow::Borrowed("IST") };
+const T519: FixedTimespan<'static> = FixedTimespan { offset: 0, is_dst: true, name: Cow::Borrowed("GMT") };
+const T520: FixedTimespan<'static> = FixedTimespan { offset: 3600, is_dst: false, name: Cow::Borrowed("IST") };
+const T521: FixedTimespan<'static> = FixedTimespan { offset: -1284, is_dst: false, name: Cow::Borrowed("LMT") };
+const T522: FixedTimespan<'static> = FixedTimespan { offset: 7200, is_dst: true, name: Cow::Borrowed("BDST") };
+const T523: FixedTimespan<'static> = FixedTimespan { offset: -609, is_dst: false, name: Cow::Borrowed("LMT") };
+const T524: FixedTimespan<'static> = FixedTimespan { offset: 5989, is_dst: false, name: Cow::Borrowed("LMT") };
+const T525: FixedTimespan<'static> = FixedTimespan { offset: -1075, is_dst: false, name: Cow::Borrowed("LMT") };
+const T526: FixedTimespan<'static> = FixedTimespan { offset: 6952, is_dst: false, name: Cow::Borrowed("LMT") };
+const T527: FixedTimespan<'static> = FixedTimespan { offset: 7016, is_dst: false, name: Cow::Borrowed("IMT") };
+const T528: FixedTimespan<'static> = FixedTimespan { offset: -506, is_dst: false, name: Cow::Borrowed("LMT") };
+const T529: FixedTimespan<'static> = FixedTimespan { offset: 11928, is_dst: false, name: Cow::Borrowed("LMT") };
+const T530: FixedTimespan<'static> = FixedTimespan { offset: 14400, is_dst: false, name: Cow::Borrowed("MSK") };
+const T531: FixedTimespan<'static> = FixedTimespan { offset: 7324, is_dst: false, name: Cow::Borrowed("LMT") };
+const T532: FixedTimespan<'static> = FixedTimespan { offset: 7200, is_dst: true, name: Cow::Borrowed("WEMT") };
+const T533: FixedTimespan<'static> = FixedTimespan { offset: 3484, is_dst: false, name: Cow::Borrowed("LMT") };
+const T534: FixedTimespan<'static> = FixedTimespan { offset: -75, is_dst: false, name: Cow::Borrowed("LMT") };
+const T535: FixedTimespan<'static> = FixedTimespan { offset: 1476, is_dst: false, name: Cow::Borrowed("LMT") };
+const T536: FixedTimespan<'static> = FixedTimespan { offset: 7200, is_dst: true, name: Cow::Borrowed("WEST") };
+const T537: FixedTimespan<'static> = FixedTimespan { offset: 3600, is_dst: false, name: Cow::Borrowed("WET") };
+const T538: FixedTimespan<'static> = FixedTimespan { offset: -884, is_dst: false, name: Cow::Borrowed("LMT") };
+const T539: FixedTimespan<'static> = FixedTimespan { offset: 6616, is_dst: false, name: Cow::Borrowed("LMT") };
+const T540: FixedTimespan<'static> = FixedTimespan { offset: 6600, is_dst: false, name: Cow::Borrowed("MMT") };
+const T541: FixedTimespan<'static> = FixedTimespan { offset: 1772, is_dst: false, name: Cow::Borrowed("LMT") };
+const T542: FixedTimespan<'static> = FixedTimespan { offset: 9017, is_dst: false, name: Cow::Borrowed("LMT") };
+const T543: FixedTimespan<'static> = FixedTimespan { offset: 9079, is_dst: false, name: Cow::Borrowed("MMT") };
+const T544: FixedTimespan<'static> = FixedTimespan { offset: 12679, is_dst: true, name: Cow::Borrowed("MST") };
+const T545: FixedTimespan<'static> = FixedTimespan { offset: 16279, is_dst: true, name: Cow::Borrowed("MDST") };
+const T546: FixedTimespan<'static> = FixedTimespan { offset: 2580, is_dst: false, name: Cow::Borrowed("LMT") };
+const T547: FixedTimespan<'static> = FixedTimespan { offset: 561, is_dst: false, name: Cow::Borrowed("LMT") };
+const T548: FixedTimespan<'static> = FixedTimespan { offset: 3464, is_dst: false, name: Cow::Borrowed("LMT") };
+const T549: FixedTimespan<'static> = FixedTimespan { offset: 5794, is_dst: false, name: Cow::Borrowed("LMT") };
+const T550: FixedTimespan<'static> = FixedTimespan { offset: 9394, is_dst: true, name: Cow::Borrowed("LST") };
+const T551: FixedTimespan<'static> = FixedTimespan { offset: 5794, is_dst: false, name: Cow::Borrowed("RMT") };
+const T552: FixedTimespan<'static> = FixedTimespan { offset: 2996, is_dst: false, name: Cow::Borrowed("LMT") };
+const T553: FixedTimespan<'static> = FixedTimespan { offset: 12020, is_dst: false, name: Cow::Borrowed("LMT") };
+const T554: FixedTimespan<'static> = FixedTimespan { offset: 10800, is_dst: true, name: Cow::Borrowed("+03") };
+const T555: FixedTimespan<'static> = FixedTimespan { offset: 4420, is_dst: false, name: Cow::Borrowed("LMT") };
+const T556: FixedTimespan<'static> = FixedTimespan { offset: 11058, is_dst: false, name: Cow::Borrowed("LMT") };
+const T557: FixedTimespan<'static> = FixedTimespan { offset: 8184, is_dst: false, name: Cow::Borrowed("LMT") };
+const T558: FixedTimespan<'static> = FixedTimespan { offset: 8160, is_dst: false, name: Cow::Borrowed("SMT") };
+const T559: FixedTimespan<'static> = FixedTimespan { offset: 5144, is_dst: false, name: Cow::Borrowed("LMT") };
+const T560: FixedTimespan<'static> = FixedTimespan { offset: 5596, is_dst: false, name: Cow::Borrowed("LMT") };
+const T561: FixedTimespan<'static> = FixedTimespan { offset: 4332, is_dst: false, name: Cow::Borrowed("LMT") };
+const T562: FixedTimespan<'static> = FixedTimespan { offset: 3614, is_dst: false, name: Cow::Borrowed("SET") };
+const T563: FixedTimespan<'static> = FixedTimespan { offset: 5940, is_dst: false, name: Cow::Borrowed("LMT") };
+const T564: FixedTimespan<'static> = FixedTimespan { offset: 5940, is_dst: false, name: Cow::Borrowed("TMT") };
+const T565: FixedTimespan<'static> = FixedTimespan { offset: 4760, is_dst: false, name: Cow::Borrowed("LMT") };
+const T566: FixedTimespan<'static> = FixedTimespan { offset: 11616, is_dst: false, name: Cow::Borrowed("LMT") };
+const T567: FixedTimespan<'static> = FixedTimespan { offset: 2284, is_dst: false, name: Cow::Borrowed("LMT") };
+const T568: FixedTimespan<'static> = FixedTimespan { offset: 3921, is_dst: false, name: Cow::Borrowed("LMT") };
+const T569: FixedTimespan<'static> = FixedTimespan { offset: 6076, is_dst: false, name: Cow::Borrowed("LMT") };
+const T570: FixedTimespan<'static> = FixedTimespan { offset: 5040, is_dst: false, name: Cow::Borrowed("WMT") };
+const T571: FixedTimespan<'static> = FixedTimespan { offset: 5736, is_dst: false, name: Cow::Borrowed("KMT") };
+const T572: FixedTimespan<'static> = FixedTimespan { offset: 5040, is_dst: false, name: Cow::Borrowed("LMT") };
+const T573: FixedTimespan<'static> = FixedTimespan { offset: 3832, is_dst: false, name: Cow::Borrowed("LMT") };
+const T574: FixedTimespan<'static> = FixedTimespan { offset: 2048, is_dst: false, name: Cow::Borrowed("LMT") };
+const T575: FixedTimespan<'static> = FixedTimespan { offset: 1786, is_dst: false, name: Cow::Borrowed("BMT") };
+const T576: FixedTimespan<'static> = FixedTimespan { offset: 11404, is_dst: false, name: Cow::Borrowed("LMT") };
+const T577: FixedTimespan<'static> = FixedTimespan { offset: 14400, is_dst: true, name: Cow::Borrowed("EAST") };
+const T578: FixedTimespan<'static> = FixedTimespan { offset: 17380, is_dst: false, name: Cow::Borrowed("LMT") };
+const T579: FixedTimespan<'static> = FixedTimespan { offset: 25372, is_dst: false, name: Cow::Borrowed("LMT") };
+const T580: FixedTimespan<'static> = FixedTimespan { offset: 23260, is_dst: false, name: Cow::Borrowed("LMT") };
+const T581: FixedTimespan<'static> = FixedTimespan { offset: 10384, is_dst: false, name: Cow::Borrowed("LMT") };
+const T582: FixedTimespan<'static> = FixedTimespan { offset: 13308, is_dst: false, name: Cow::Borrowed("LMT") };
+const T583: FixedTimespan<'static> = FixedTimespan { offset: 17640, is_dst: false, name: Cow::Borrowed("LMT") };
+const T584: FixedTimespan<'static> = FixedTimespan { offset: 13800, is_dst: false, name: Cow::Borrowed("LMT") };
+const T585: FixedTimespan<'static> = FixedTimespan { offset: 10856, is_dst: false, name: Cow::Borrowed("LMT") };
+const T586: FixedTimespan<'static> = FixedTimespan { offset: 13312, is_dst: false, name: Cow::Borrowed("LMT") };
+const T587: FixedTimespan<'static> = FixedTimespan { offset: 3600, is_dst: false, name: Cow::Borrowed("MET") };
+const T588: FixedTimespan<'static> = FixedTimespan { offset: 7200, is_dst: true, name: Cow::Borrowed("MEST") };
+const T589: FixedTimespan<'static> = FixedTimespan { offset: 45184, is_dst: false, name: Cow::Borrowed("LMT") };
+const T590: FixedTimespan<'static> = FixedTimespan { offset: -41216, is_dst: false, name: Cow::Borrowed("LMT") };
+const T591: FixedTimespan<'static> = FixedTimespan { offset: -41400, is_dst: false, name: Cow::Borrowed("-1130") };
+const T592: FixedTimespan<'static> = FixedTimespan { offset: -36000, is_dst: true, name: Cow::Borrowed("-10") };
+const T593: FixedTimespan<'static> = FixedTimespan { offset: 41944, is_dst: false, name: Cow::Borrowed("LMT") };
+const T594: FixedTimespan<'static> = FixedTimespan { offset: 41400, is_dst: false, name: Cow::Borrowed("NZMT") };
+const T595: FixedTimespan<'static> = FixedTimespan { offset: 45000, is_dst: true, name: Cow::Borrowed("NZST") };
+const T596: FixedTimespan<'static> = FixedTimespan { offset: 43200, is_dst: true, name: Cow::Borrowed("NZST") };
+const T597: FixedTimespan<'static> = FixedTimespan { offset: 37336, is_dst: false, name: Cow::Borrowed("LMT") };
+const T598: FixedTimespan<'static> = FixedTimespan { offset: 35312, is_dst: false, name: Cow::Borrowed("PMMT") };
+const T599: FixedTimespan<'static> = FixedTimespan { offset: 44028, is_dst: false, name: Cow::Borrowed("LMT") };
+const T600: FixedTimespan<'static> = FixedTimespan { offset: 44100, is_dst: false, name: Cow::Borrowed("+1215") };
+const T601: FixedTimespan<'static> = FixedTimespan { offset: 45900, is_dst: false, name: Cow::Borrowed("+1245") };
+const T602: FixedTimespan<'static> = FixedTimespan { offset: 49500, is_dst: true, name: Cow::Borrowed("+1345") };
+const T603: FixedTimespan<'static> = FixedTimespan { offset: -49972, is_dst: false, name: Cow::Borrowed("LMT") };
+const T604: FixedTimespan<'static> = FixedTimespan { offset: 36428, is_dst: false, name: Cow::Borrowed("LMT") };
+const T605: FixedTimespan<'static> = FixedTimespan { offset: -26248, is_dst: false, name: Cow::Borrowed("LMT") };
+const T606: FixedTimespan<'static> = FixedTimespan { offset: -21600, is_dst: true, name: Cow::Borrowed("-06") };
+const T607: FixedTimespan<'static> = FixedTimespan { offset: -18000, is_dst: true, name: Cow::Borrowed("-05") };
+const T608: FixedTimespan<'static> = FixedTimespan { offset: 40396, is_dst: false, name: Cow::Borrowed("LMT") };
+const T609: FixedTimespan<'static> = FixedTimespan { offset: -41096, is_dst: false, name: Cow::Borrowed("LMT") };
+const T610: FixedTimespan<'static> = FixedTimespan { offset: 42944, is_dst: false, name: Cow::Borrowed("LMT") };
+const T611: FixedTimespan<'static> = FixedTimespan { offset: 43012, is_dst: false, name: Cow::Borrowed("LMT") };
+const T612: FixedTimespan<'static> = FixedTimespan { offset: -21504, is_dst: false, name: Cow::Borrowed("LMT") };
+const T613: FixedTimespan<'static> = FixedTimespan { offset: -32388, is_dst: false, name: Cow::Borrowed("LMT") };
+const T614: FixedTimespan<'static> = FixedTimespan { offset: 38388, is_dst: false, name: Cow::Borrowed("LMT") };
+const T615: FixedTimespan<'static> = FixedTimespan { offset: -51660, is_dst: false, name: Cow::Borrowed("LMT") };
+const T616: FixedTimespan<'static> = FixedTimespan { offset: 34740, is_dst: false, name: Cow::Borrowed("LMT") };
+const T617: FixedTimespan<'static> = FixedTimespan { offset: 36000, is_dst: false, name: Cow::Borrowed("GST") };
+const T618: FixedTimespan<'static> = FixedTimespan { offset: 39600, is_dst: true, name: Cow::Borrowed("GDT") };
+const T619: FixedTimespan<'static> = FixedTimespan { offset: 36000, is_dst: false, name: Cow::Borrowed("ChST") };
+const T620: FixedTimespan<'static> = FixedTimespan { offset: -37886, is_dst: false, name: Cow::Borrowed("LMT") };
+const T621: FixedTimespan<'static> = FixedTimespan { offset: -37800, is_dst: false, name: Cow::Borrowed("HST") };
+const T622: FixedTimespan<'static> = FixedTimespan { offset: -34200, is_dst: true, name: Cow::Borrowed("HDT") };
+const T623: FixedTimespan<'static> = FixedTimespan { offset: -34200, is_dst: true, name: Cow::Borrowed("HWT") };
+const T624: FixedTimespan<'static> = FixedTimespan { offset: -37760, is_dst: false, name: Cow::Borrowed("LMT") };
+const T625: FixedTimespan<'static> = FixedTimespan { offset: -38400, is_dst: false, name: Cow::Borrowed("-1040") };
+const T626: FixedTimespan<'static> = FixedTimespan { offset: -47284, is_dst: false, name: Cow::Borrowed("LMT") };
+const T627: FixedTimespan<'static> = FixedTimespan { offset: 39116, is_dst: false, name: Cow::Borrowed("LMT") };
+const T628: FixedTimespan<'static> = FixedTimespan { offset: 40160, is_dst: false, name: Cow::Borrowed("LMT") };
+const T629: FixedTimespan<'static> = FixedTimespan { offset: 41088, is_dst: false, name: Cow::Borrowed("LMT") };
+const T630: FixedTimespan<'static> = FixedTimespan { offset: -33480, is_dst: false, name: Cow::Borrowed("LMT") };
+const T631: FixedTimespan<'static> = FixedTimespan { offset: -34200, is_dst: false, name: Cow::Borrowed("-0930") };
+const T632: FixedTimespan<'static> = FixedTimespan { offset: -42568, is_dst: false, name: Cow::Borrowed("LMT") };
+const T633: FixedTimespan<'static> = FixedTimespan { offset: -39600, is_dst: false, name: Cow::Borrowed("SST") };
+const T634: FixedTimespan<'static> = FixedTimespan { offset: 40060, is_dst: false, name: Cow::Borrowed("LMT") };
+const T635: FixedTimespan<'static> = FixedTimespan { offset: 41400, is_dst: false, name: Cow::Borrowed("+1130") };
+const T636: FixedTimespan<'static> = FixedTimespan { offset: -40780, is_dst: false, name: Cow::Borrowed("LMT") };
+const T637: FixedTimespan<'static> = FixedTimespan { offset: -40800, is_dst: false, name: Cow::Borrowed("-1120") };
+const T638: FixedTimespan<'static> = FixedTimespan { offset: 40312, is_dst: false, name: Cow::Borrowed("LMT") };
+const T639: FixedTimespan<'static> = FixedTimespan { offset: 40320, is_dst: false, name: Cow::Borrowed("+1112") };
+const T640: FixedTimespan<'static> = FixedTimespan { offset: 45000, is_dst: true, name: Cow::Borrowed("+1230") };
+const T641: FixedTimespan<'static> = FixedTimespan { offset: 39948, is_dst: false, name: Cow::Borrowed("LMT") };
+const T642: FixedTimespan<'static> = FixedTimespan { offset: 45432, is_dst: false, name: Cow::Borrowed("LMT") };
+const T643: FixedTimespan<'static> = FixedTimespan { offset: -40968, is_dst: false, name: Cow::Borrowed("LMT") };
+const T644: FixedTimespan<'static> = FixedTimespan { offset: -54124, is_dst: false, name: Cow::Borrowed("LMT") };
+const T645: FixedTimespan<'static> = FixedTimespan { offset: 32276, is_dst: false, name: Cow::Borrowed("LMT") };
+const T646: FixedTimespan<'static> = FixedTimespan { offset: -31220, is_dst: false, name: Cow::Borrowed("LMT") };
+const T647: FixedTimespan<'static> = FixedTimespan { offset: -30600, is_dst: false, name: Cow::Borrowed("-0830") };
+const T648: FixedTimespan<'static> = FixedTimespan { offset: -48428, is_dst: false, name: Cow::Borrowed("LMT") };
+const T649: FixedTimespan<'static> = FixedTimespan { offset: 37972, is_dst: false, name: Cow::Borrowed("LMT") };
+const T650: FixedTimespan<'static> = FixedTimespan { offset: 35320, is_dst: false, name: Cow::Borrowed("LMT") };
+const T651: FixedTimespan<'static> = FixedTimespan { offset: 48056, is_dst: false, name: Cow::Borrowed("LMT") };
+const T652: FixedTimespan<'static> = FixedTimespan { offset: -38344, is_dst: false, name: Cow::Borrowed("LMT") };
+const T653: FixedTimespan<'static> = FixedTimespan { offset: -37800, is_dst: false, name: Cow::Borrowed("-1030") };
+const T654: FixedTimespan<'static> = FixedTimespan { offset: -34200, is_dst: true, name: Cow::Borrowed("-0930") };
+const T655: FixedTimespan<'static> = FixedTimespan { offset: -51420, is_dst: false, name: Cow::Borrowed("LMT") };
+const T656: FixedTimespan<'static> = FixedTimespan { offset: 34980, is_dst: false, name: Cow::Borrowed("LMT") };
+const T657: FixedTimespan<'static> = FixedTimespan { offset: -35896, is_dst: false, name: Cow::Borrowed("LMT") };
+const T658: FixedTimespan<'static> = FixedTimespan { offset: 41524, is_dst: false, name: Cow::Borrowed("LMT") };
+const T659: FixedTimespan<'static> = FixedTimespan { offset: 44352, is_dst: false, name: Cow::Borrowed("LMT") };
+const T660: FixedTimespan<'static> = FixedTimespan { offset: 44400, is_dst: false, name: Cow::Borrowed("+1220") };
+const T661: FixedTimespan<'static> = FixedTimespan { offset: 39988, is_dst: false, name: Cow::Borrowed("LMT") };
+const T662: FixedTimespan<'static> = FixedTimespan { offset: 44120, is_dst: false, name: Cow::Borrowed("LMT") };
+
+static AFRICA_ABIDJAN: &[(i64, FixedTimespan<'static>)] = &[
+    (-1830383032, T1),
+];
+
+static AFRICA_ACCRA: &[(i64, FixedTimespan<'static>)] = &[
+    (-1709337548, T1),
+    (-1581206400, T3),
+    (-1577917200, T1),
+    (-1556834400, T3),
+    (-1546294800, T1),
+    (-1525298400, T3),
+    (-1514758800, T1),
+    (-1493762400, T3),
+    (-1483222800, T1),
+    (-1462226400, T3),
+    (-1451686800, T1),
+    (-1430604000, T3),
+    (-1420064400, T1),
+    (-1399068000, T3),
+    (-1388528400, T1),
+    (-1367532000, T3),
+    (-1356992400, T1),
+    (-1335996000, T3),
+    (-1325456400, T1),
+    (-1304373600, T3),
+    (-1293834000, T1),
+    (-1272837600, T3),
+    (-1262298000, T1),
+    (-1241301600, T3),
+    (-1230762000, T1),
+    (-1209765600, T3),
+    (-1199226000, T1),
+    (-1178143200, T3),
+    (-1167603600, T1),
+    (-1146607200, T3),
+    (-1136067600, T1),
+    (-1115071200, T3),
+    (-1104531600, T1),
+    (-1083535200, T3),
+    (-1072995600, T1),
+    (-1051912800, T3),
+    (-1041373200, T1),
+    (-1020376800, T3),
+    (-1009837200, T1),
+    (-988840800, T3),
+    (-978301200, T1),
+    (-957304800, T3),
+    (-946765200, T1),
+    (-936309600, T3),
+    (-915142800, T1),
+    (-904773600, T3),
+    (-883606800, T1),
+    (-880329600, T4),
+    (-756952200, T1),
+    (-610149600, T5),
+    (-599610600, T1),
+    (-578613600, T5),
+    (-568074600, T1),
+    (-546991200, T5),
+    (-536452200, T1),
+    (-515455200, T5),
+    (-504916200, T1),
+    (-483919200, T5),
+    (-473380200, T1),
+    (-452383200, T5),
+    (-441844200, T1),
+];
+
+static AFRICA_ADDIS_ABABA: &[(i64, FixedTimespan<'static>)] = &[
+    (-3155682888, T7),
+    (-1062210920, T8),
+];
+
+static AFRICA_ALGIERS: &[(i64, FixedTimespan<'static>)] = &[
+    (-2486592732, T10),
+    (-1855958961, T11),
+    (-1689814800, T12),
+    (-1680397200, T11),
+    (-1665363600, T12),
+    (-1648342800, T11),
+    (-1635123600, T12),
+    (-1616893200, T11),
+    (-1604278800, T12),
+    (-1585443600, T11),
+    (-1574038800, T12),
+    (-1552266000, T11),
+    (-1539997200, T12),
+    (-1531443600, T11),
+    (-956365200, T12),
+    (-950486400, T11),
+    (-942012000, T13),
+    (-812502000, T14),
+    (-796262400, T13),
+    (-781052400, T14),
+    (-766630800, T13),
+    (-733280400, T11),
+    (-439430400, T13),
+    (-212029200, T11),
+    (41468400, T12),
+    (54774000, T11),
+    (231724800, T12),
+    (259545600, T14),
+    (275274000, T13),
+    (309740400, T11),
+    (325468800, T12),
+    (341802000, T11),
+    (357523200, T13),
+];
+
+static AFRICA_ASMARA: &[(i64, FixedTimespan<'static>)] = &[
+    (-2524530932, T7),
+    (-1062210920, T8),
+];
+
+static AFRICA_BAMAKO: &[(i64, FixedTimespan<'static>)] = &[
+    (-1830382080, T1),
+    (-1131235200, T17),
+    (-300841200, T1),
+];
+
+static AFRICA_BANGUI: &[(i64, FixedTimespan<'static>)] = &[
+    (-1830388460, T19),
+];
+
+static AFRICA_BANJUL: &[(i64, FixedTimespan<'static>)] = &[
+    (-1159829604, T17),
+    (-880930800, T1),
+];
+
+static AFRICA_BISSAU: &[(i64, FixedTimespan<'static>)] = &[
+    (-1830380400, T17),
+    (157770000, T1),
+];
+
+static AFRICA_BLANTYRE: &[(i64, FixedTimespan<'static>)] = &[
+    (-1844302800, T23),
+    (-1751682070, T24),
+    (-1404440460, T25),
+];
+
+static AFRICA_BRAZZAVILLE: &[(i64, FixedTimespan<'static>)] = &[
+    (-1830387668, T19),
+];
+
+static AFRICA_BUJUMBURA: &[(i64, FixedTimespan<'static>)] = &[
+    (-2524528648, T25),
+];
+
+static AFRICA_CAIRO: &[(i64, FixedTimespan<'static>)] = &[
+    (-2185409109, T29),
+    (-929844000, T30),
+    (-923108400, T29),
+    (-906170400, T30),
+    (-892868400, T29),
+    (-875844000, T30),
+    (-857790000, T29),
+    (-844308000, T30),
+    (-825822000, T29),
+    (-812685600, T30),
+    (-794199600, T29),
+    (-779853600, T30),
+    (-762663600, T29),
+    (-399088800, T30),
+    (-386650800, T29),
+    (-368330400, T30),
+    (-355114800, T29),
+    (-336790800, T30),
+    (-323654400, T29),
+    (-305168400, T30),
+    (-292032000, T29),
+    (-273632400, T30),
+    (-260496000, T29),
+    (-242096400, T30),
+    (-228960000, T29),
+    (-210560400, T30),
+    (-197424000, T29),
+    (-178938000, T30),
+    (-165801600, T29),
+    (-147402000, T30),
+    (-134265600, T29),
+    (-115866000, T30),
+    (-102643200, T29),
+    (-84330000, T30),
+    (-71107200, T29),
+    (-52707600, T30),
+    (-39484800, T29),
+    (-21171600, T30),
+    (-7948800, T29),
+    (10364400, T30),
+    (23587200, T29),
+    (41900400, T30),
+    (55123200, T29),
+    (73522800, T30),
+    (86745600, T29),
+    (105058800, T30),
+    (118281600, T29),
+    (136594800, T30),
+    (149817600, T29),
+    (168130800, T30),
+    (181353600, T29),
+    (199753200, T30),
+    (212976000, T29),
+    (231289200, T30),
+    (244512000, T29),
+    (262825200, T30),
+    (276048000, T29),
+    (294361200, T30),
+    (307584000, T29),
+    (325983600, T30),
+    (339206400, T29),
+    (357519600, T30),
+    (370742400, T29),
+    (396399600, T30),
+    (402278400, T29),
+    (426812400, T30),
+    (433814400, T29),
+    (452214000, T30),
+    (465436800, T29),
+    (483750000, T30),
+    (496972800, T29),
+    (515286000, T30),
+    (528508800, T29),
+    (546822000, T30),
+    (560044800, T29),
+    (578444400, T30),
+    (591667200, T29),
+    (610412400, T30),
+    (623203200, T29),
+    (641516400, T30),
+    (654739200, T29),
+    (673052400, T30),
+    (686275200, T29),
+    (704674800, T30),
+    (717897600, T29),
+    (736210800, T30),
+    (749433600, T29),
+    (767746800, T30),
+    (780969600, T29),
+    (799020000, T30),
+    (812322000, T29),
+    (830469600, T30),
+    (843771600, T29),
+    (861919200, T30),
+    (875221200, T29),
+    (893368800, T30),
+    (906670800, T29),
+    (925423200, T30),
+    (938725200, T29),
+    (956872800, T30),
+    (970174800, T29),
+    (988322400, T30),
+    (1001624400, T29),
+    (1019772000, T30),
+    (1033074000, T29),
+    (1051221600, T30),
+    (1064523600, T29),
+    (1083276000, T30),
+    (1096578000, T29),
+    (1114725600, T30),
+    (1128027600, T29),
+    (1146175200, T30),
+    (1158872400, T29),
+    (1177624800, T30),
+    (1189112400, T29),
+    (1209074400, T30),
+    (1219957200, T29),
+    (1240524000, T30),
+    (1250802000, T29),
+    (1272578400, T30),
+    (1281474000, T29),
+    (1284069600, T30),
+    (1285880400, T29),
+    (1400191200, T30),
+    (1403816400, T29),
+    (1406844000, T30),
+    (1411678800, T29),
+    (1682632800, T30),
+    (1698354000, T29),
+    (1714082400, T30),
+    (1730408400, T29),
+];
+
+static AFRICA_CASABLANCA: &[(i64, FixedTimespan<'static>)] = &[
+    (-1773012580, T32),
+    (-956361600, T33),
+    (-950490000, T32),
+    (-942019200, T33),
+    (-761187600, T32),
+    (-617241600, T33),
+    (-605149200, T32),
+    (-81432000, T33),
+    (-71110800, T32),
+    (141264000, T33),
+    (147222000, T32),
+    (199756800, T33),
+    (207702000, T32),
+    (231292800, T33),
+    (244249200, T32),
+    (265507200, T33),
+    (271033200, T32),
+    (448243200, T34),
+    (504918000, T32),
+    (1212278400, T33),
+    (1220223600, T32),
+    (1243814400, T33),
+    (1250809200, T32),
+    (1272758400, T33),
+    (1281222000, T32),
+    (1301788800, T33),
+    (1312066800, T32),
+    (1335664800, T33),
+    (1342749600, T32),
+    (1345428000, T33),
+    (1348970400, T32),
+    (1367114400, T33),
+    (1373162400, T32),
+    (1376100000, T33),
+    (1382839200, T32),
+    (1396144800, T33),
+    (1403920800, T32),
+    (1406944800, T33),
+    (1414288800, T32),
+    (1427594400, T33),
+    (1434247200, T32),
+    (1437271200, T33),
+    (1445738400, T32),
+    (1459044000, T33),
+    (1465092000, T32),
+    (1468116000, T33),
+    (1477792800, T32),
+    (1490493600, T33),
+    (1495332000, T32),
+    (1498960800, T33),
+    (1509242400, T32),
+    (1521943200, T33),
+    (1526176800, T32),
+    (1529200800, T33),
+    (1557021600, T35),
+    (1560045600, T34),
+    (1587261600, T35),
+    (1590890400, T34),
+    (1618106400, T35),
+    (1621130400, T34),
+    (1648346400, T35),
+    (1651975200, T34),
+    (1679191200, T35),
+    (1682215200, T34),
+    (1710036000, T35),
+    (1713060000, T34),
+    (1740276000, T35),
+    (1743904800, T34),
+    (1771120800, T35),
+    (1774144800, T34),
+    (1801965600, T35),
+    (1804989600, T34),
+    (1832205600, T35),
+    (1835834400, T34),
+    (1863050400, T35),
+    (1866074400, T34),
+    (1893290400, T35),
+    (1896919200, T34),
+    (1924135200, T35),
+    (1927159200, T34),
+    (1954980000, T35),
+    (1958004000, T34),
+    (1985220000, T35),
+    (1988848800, T34),
+    (2016064800, T35),
+    (2019088800, T34),
+    (2046304800, T35),
+    (2049933600, T34),
+    (2077149600, T35),
+    (2080778400, T34),
+    (2107994400, T35),
+    (2111018400, T34),
+    (2138234400, T35),
+    (2141863200, T34),
+    (2169079200, T35),
+    (2172103200, T34),
+    (2199924000, T35),
+    (2202948000, T34),
+    (2230164000, T35),
+    (2233792800, T34),
+    (2261008800, T35),
+    (2264032800, T34),
+    (2291248800, T35),
+    (2294877600, T34),
+    (2322093600, T35),
+    (2325722400, T34),
+    (2352938400, T35),
+    (2355962400, T34),
+    (2383178400, T35),
+    (2386807200, T34),
+    (2414023200, T35),
+    (2417047200, T34),
+    (2444868000, T35),
+    (2447892000, T34),
+    (2475108000, T35),
+    (2478736800, T34),
+    (2505952800, T35),
+    (2508976800, T34),
+    (2536192800, T35),
+    (2539821600, T34),
+    (2567037600, T35),
+    (2570666400, T34),
+    (2597882400, T35),
+    (2600906400, T34),
+    (2628122400, T35),
+    (2631751200, T34),
+    (2658967200, T35),
+    (2661991200, T34),
+    (2689812000, T35),
+    (2692836000, T34),
+    (2720052000, T35),
+    (2723680800, T34),
+    (2750896800, T35),
+    (2753920800, T34),
+    (2781136800, T35),
+    (2784765600, T34),
+    (2811981600, T35),
+    (2815610400, T34),
+    (2842826400, T35),
+    (2845850400, T34),
+    (2873066400, T35),
+    (2876695200, T34),
+    (2903911200, T35),
+    (2906935200, T34),
+    (2934756000, T35),
+    (2937780000, T34),
+    (2964996000, T35),
+    (2968624800, T34),
+    (2995840800, T35),
+    (2998864800, T34),
+    (3026080800, T35),
+    (3029709600, T34),
+    (3056925600, T35),
+    (3060554400, T34),
+    (3087770400, T35),
+    (3090794400, T34),
+    (3118010400, T35),
+    (3121639200, T34),
+    (3148855200, T35),
+    (3151879200, T34),
+    (3179700000, T35),
+    (3182724000, T34),
+    (3209940000, T35),
+    (3213568800, T34),
+    (3240784800, T35),
+    (3243808800, T34),
+    (3271024800, T35),
+    (3274653600, T34),
+    (3301869600, T35),
+    (3305498400, T34),
+    (3332714400, T35),
+    (3335738400, T34),
+    (3362954400, T35),
+    (3366583200, T34),
+    (3393799200, T35),
+    (3396823200, T34),
+    (3424644000, T35),
+    (3427668000, T34),
+    (3454884000, T35),
+    (3458512800, T34),
+    (3485728800, T35),
+    (3488752800, T34),
+    (3515968800, T35),
+    (3519597600, T34),
+    (3546813600, T35),
+    (3549837600, T34),
+    (3577658400, T35),
+    (3580682400, T34),
+    (3607898400, T35),
+    (3611527200, T34),
+    (3638743200, T35),
+    (3641767200, T34),
+    (3669588000, T35),
+    (3672612000, T34),
+    (3699828000, T35),
+    (3703456800, T34),
+];
+
+static AFRICA_CEUTA: &[(i64, FixedTimespan<'static>)] = &[
+    (-2177452800, T11),
+    (-1630112400, T12),
+    (-1616810400, T11),
+    (-1442451600, T12),
+    (-1427673600, T11),
+    (-1379293200, T12),
+    (-1364774400, T11),
+    (-1348448400, T12),
+    (-1333324800, T11),
+    (-1316390400, T12),
+    (-1301270400, T11),
+    (-81432000, T12),
+    (-71110800, T11),
+    (141264000, T12),
+    (147222000, T11),
+    (199756800, T12),
+    (207702000, T11),
+    (231292800, T12),
+    (244249200, T11),
+    (265507200, T12),
+    (271033200, T11),
+    (448243200, T13),
+    (512528400, T14),
+    (528253200, T13),
+    (543978000, T14),
+    (559702800, T13),
+    (575427600, T14),
+    (591152400, T13),
+    (606877200, T14),
+    (622602000, T13),
+    (638326800, T14),
+    (654656400, T13),
+    (670381200, T14),
+    (686106000, T13),
+    (701830800, T14),
+    (717555600, T13),
+    (733280400, T14),
+    (749005200, T13),
+    (764730000, T14),
+    (780454800, T13),
+    (796179600, T14),
+    (811904400, T13),
+    (828234000, T14),
+    (846378000, T13),
+    (859683600, T14),
+    (877827600, T13),
+];
+
+static AFRICA_CONAKRY: &[(i64, FixedTimespan<'static>)] = &[
+    (-1830380708, T1),
+    (-1131235200, T17),
+    (-315615600, T1),
+];
+
+static AFRICA_DAKAR: &[(i64, FixedTimespan<'static>)] = &[
+    (-1830379816, T17),
+    (-902098800, T1),
+];
+
+static AFRICA_DAR_ES_SALAAM: &[(i64, FixedTimespan<'static>)] = &[
+    (-1230777428, T8),
+    (-694321200, T40),
+    (-284006700, T8),
+];
+
+static AFRICA_DJIBOUTI: &[(i64, FixedTimespan<'static>)] = &[
+    (-1846291956, T8),
+];
+
+static AFRICA_DOUALA: &[(i64, FixedTimespan<'static>)] = &[
+    (-1830386328, T19),
+];
+
+static AFRICA_EL_AAIUN: &[(i64, FixedTimespan<'static>)] = &[
+    (-1136070432, T17),
+    (198291600, T32),
+    (199756800, T33),
+    (207702000, T32),
+    (231292800, T33),
+    (244249200, T32),
+    (265507200, T33),
+    (271033200, T32),
+    (1212278400, T33),
+    (1220223600, T32),
+    (1243814400, T33),
+    (1250809200, T32),
+    (1272758400, T33),
+    (1281222000, T32),
+    (1301788800, T33),
+    (1312066800, T32),
+    (1335664800, T33),
+    (1342749600, T32),
+    (1345428000, T33),
+    (1348970400, T32),
+    (1367114400, T33),
+    (1373162400, T32),
+    (1376100000, T33),
+    (1382839200, T32),
+    (1396144800, T33),
+    (1403920800, T32),
+    (1406944800, T33),
+    (1414288800, T32),
+    (1427594400, T33),
+    (1434247200, T32),
+    (1437271200, T33),
+    (1445738400, T32),
+    (1459044000, T33),
+    (1465092000, T32),
+    (1468116000, T33),
+    (1477792800, T32),
+    (1490493600, T33),
+    (1495332000, T32),
+    (1498960800, T33),
+    (1509242400, T32),
+    (1521943200, T33),
+    (1526176800, T32),
+    (1529200800, T33),
+    (1557021600, T35),
+    (1560045600, T34),
+    (1587261600, T35),
+    (1590890400, T34),
+    (1618106400, T35),
+    (1621130400, T34),
+    (1648346400, T35),
+    (1651975200, T34),
+    (1679191200, T35),
+    (1682215200, T34),
+    (1710036000, T35),
+    (1713060000, T34),
+    (1740276000, T35),
+    (1743904800, T34),
+    (1771120800, T35),
+    (1774144800, T34),
+    (1801965600, T35),
+    (1804989600, T34),
+    (1832205600, T35),
+    (1835834400, T34),
+    (1863050400, T35),
+    (1866074400, T34),
+    (1893290400, T35),
+    (1896919200, T34),
+    (1924135200, T35),
+    (1927159200, T34),
+    (1954980000, T35),
+    (1958004000, T34),
+    (1985220000, T35),
+    (1988848800, T34),
+    (2016064800, T35),
+    (2019088800, T34),
+    (2046304800, T35),
+    (2049933600, T34),
+    (2077149600, T35),
+    (2080778400, T34),
+    (2107994400, T35),
+    (2111018400, T34),
+    (2138234400, T35),
+    (2141863200, T34),
+    (2169079200, T35),
+    (2172103200, T34),
+    (2199924000, T35),
+    (2202948000, T34),
+    (2230164000, T35),
+    (2233792800, T34),
+    (2261008800, T35),
+    (2264032800, T34),
+    (2291248800, T35),
+    (2294877600, T34),
+    (2322093600, T35),
+    (2325722400, T34),
+    (2352938400, T35),
+    (2355962400, T34),
+    (2383178400, T35),
+    (2386807200, T34),
+    (2414023200, T35),
+    (2417047200, T34),
+    (2444868000, T35),
+    (2447892000, T34),
+    (2475108000, T35),
+    (2478736800, T34),
+    (2505952800, T35),
+    (2508976800, T34),
+    (2536192800, T35),
+    (2539821600, T34),
+    (2567037600, T35),
+    (2570666400, T34),
+    (2597882400, T35),
+    (2600906400, T34),
+    (2628122400, T35),
+    (2631751200, T34),
+    (2658967200, T35),
+    (2661991200, T34),
+    (2689812000, T35),
+    (2692836000, T34),
+    (2720052000, T35),
+    (2723680800, T34),
+    (2750896800, T35),
+    (2753920800, T34),
+    (2781136800, T35),
+    (2784765600, T34),
+    (2811981600, T35),
+    (2815610400, T34),
+    (2842826400, T35),
+    (2845850400, T34),
+    (2873066400, T35),
+    (2876695200, T34),
+    (2903911200, T35),
+    (2906935200, T34),
+    (2934756000, T35),
+    (2937780000, T34),
+    (2964996000, T35),
+    (2968624800, T34),
+    (2995840800, T35),
+    (2998864800, T34),
+    (3026080800, T35),
+    (3029709600, T34),
+    (3056925600, T35),
+    (3060554400, T34),
+    (3087770400, T35),
+    (3090794400, T34),
+    (3118010400, T35),
+    (3121639200, T34),
+    (3148855200, T35),
+    (3151879200, T34),
+    (3179700000, T35),
+    (3182724000, T34),
+    (3209940000, T35),
+    (3213568800, T34),
+    (3240784800, T35),
+    (3243808800, T34),
+    (3271024800, T35),
+    (3274653600, T34),
+    (3301869600, T35),
+    (3305498400, T34),
+    (3332714400, T35),
+    (3335738400, T34),
+    (3362954400, T35),
+    (3366583200, T34),
+    (3393799200, T35),
+    (3396823200, T34),
+    (3424644000, T35),
+    (3427668000, T34),
+    (3454884000, T35),
+    (3458512800, T34),
+    (3485728800, T35),
+    (3488752800, T34),
+    (3515968800, T35),
+    (3519597600, T34),
+    (3546813600, T35),
+    (3549837600, T34),
+    (3577658400, T35),
+    (3580682400, T34),
+    (3607898400, T35),
+    (3611527200, T34),
+    (3638743200, T35),
+    (3641767200, T34),
+    (3669588000, T35),
+    (3672612000, T34),
+    (3699828000, T35),
+    (3703456800, T34),
+];
+
+static AFRICA_FREETOWN: &[(i64, FixedTimespan<'static>)] = &[
+    (-1783120020, T17),
+    (-1170284400, T45),
+    (-1159831200, T17),
+    (-1146610800, T45),
+    (-1128295200, T17),
+    (-1115074800, T45),
+    (-1096759200, T17),
+    (-1083538800, T45),
+    (-1065136800, T17),
+    (-1051916400, T45),
+    (-1033600800, T17),
+    (-1020380400, T45),
+    (-1002064800, T17),
+    (-988844400, T45),
+    (-965258400, T17),
+    (-957308400, T45),
+    (-956964000, T17),
+    (-885769200, T1),
+];
+
+static AFRICA_GABORONE: &[(i64, FixedTimespan<'static>)] = &[
+    (-2682294220, T47),
+    (-2109288600, T25),
+    (-829526400, T48),
+    (-813805200, T25),
+];
+
+static AFRICA_HARARE: &[(i64, FixedTimespan<'static>)] = &[
+    (-2109290652, T25),
+];
+
+static AFRICA_JOHANNESBURG: &[(i64, FixedTimespan<'static>)] = &[
+    (-2458173120, T47),
+    (-2109288600, T51),
+    (-860976000, T52),
+    (-845254800, T51),
+    (-829526400, T52),
+    (-813805200, T51),
+];
+
+static AFRICA_JUBA: &[(i64, FixedTimespan<'static>)] = &[
+    (-1230775588, T25),
+    (10360800, T48),
+    (24786000, T25),
+    (41810400, T48),
+    (56322000, T25),
+    (73432800, T48),
+    (87944400, T25),
+    (104882400, T48),
+    (119480400, T25),
+    (136332000, T48),
+    (151016400, T25),
+    (167781600, T48),
+    (182552400, T25),
+    (199231200, T48),
+    (214174800, T25),
+    (230680800, T48),
+    (245710800, T25),
+    (262735200, T48),
+    (277246800, T25),
+    (294184800, T48),
+    (308782800, T25),
+    (325634400, T48),
+    (340405200, T25),
+    (357084000, T48),
+    (371941200, T25),
+    (388533600, T48),
+    (403477200, T25),
+    (419983200, T48),
+    (435013200, T25),
+    (452037600, T48),
+    (466635600, T25),
+    (483487200, T48),
+    (498171600, T25),
+    (947930400, T8),
+    (1612126800, T25),
+];
+
+static AFRICA_KAMPALA: &[(i64, FixedTimespan<'static>)] = &[
+    (-1309745380, T8),
+    (-1262314800, T55),
+    (-694319400, T40),
+    (-410237100, T8),
+];
+
+static AFRICA_KHARTOUM: &[(i64, FixedTimespan<'static>)] = &[
+    (-1230775808, T25),
+    (10360800, T48),
+    (24786000, T25),
+    (41810400, T48),
+    (56322000, T25),
+    (73432800, T48),
+    (87944400, T25),
+    (104882400, T48),
+    (119480400, T25),
+    (136332000, T48),
+    (151016400, T25),
+    (167781600, T48),
+    (182552400, T25),
+    (199231200, T48),
+    (214174800, T25),
+    (230680800, T48),
+    (245710800, T25),
+    (262735200, T48),
+    (277246800, T25),
+    (294184800, T48),
+    (308782800, T25),
+    (325634400, T48),
+    (340405200, T25),
+    (357084000, T48),
+    (371941200, T25),
+    (388533600, T48),
+    (403477200, T25),
+    (419983200, T48),
+    (435013200, T25),
+    (452037600, T48),
+    (466635600, T25),
+    (483487200, T48),
+    (498171600, T25),
+    (947930400, T8),
+    (1509483600, T25),
+];
+
+static AFRICA_KIGALI: &[(i64, FixedTimespan<'static>)] = &[
+    (-1091498416, T25),
+];
+
+static AFRICA_KINSHASA: &[(i64, FixedTimespan<'static>)] = &[
+    (-2276643672, T19),
+];
+
+static AFRICA_LAGOS: &[(i64, FixedTimespan<'static>)] = &[
+    (-2035584815, T1),
+    (-1940889600, T59),
+    (-1767226415, T4),
+    (-1588465800, T19),
+];
+
+static AFRICA_LIBREVILLE: &[(i64, FixedTimespan<'static>)] = &[
+    (-1830386268, T19),
+];
+
+static AFRICA_LOME: &[(i64, FixedTimespan<'static>)] = &[
+    (-2429827492, T1),
+];
+
+static AFRICA_LUANDA: &[(i64, FixedTimespan<'static>)] = &[
+    (-2461452776, T63),
+    (-1830387600, T19),
+];
+
+static AFRICA_LUBUMBASHI: &[(i64, FixedTimespan<'static>)] = &[
+    (-2276646592, T19),
+    (-1567990800, T25),
+];
+
+static AFRICA_LUSAKA: &[(i64, FixedTimespan<'static>)] = &[
+    (-2109289988, T25),
+];
+
+static AFRICA_MALABO: &[(i64, FixedTimespan<'static>)] = &[
+    (-1830386108, T1),
+    (-190857600, T19),
+];
+
+static AFRICA_MAPUTO: &[(i64, FixedTimespan<'static>)] = &[
+    (-1924999818, T25),
+];
+
+static AFRICA_MASERU: &[(i64, FixedTimespan<'static>)] = &[
+    (-2109289800, T51),
+    (-829526400, T52),
+    (-813805200, T51),
+];
+
+static AFRICA_MBABANE: &[(i64, FixedTimespan<'static>)] = &[
+    (-2109290664, T51),
+];
+
+static AFRICA_MOGADISHU: &[(i64, FixedTimespan<'static>)] = &[
+    (-2403572488, T8),
+    (-1230778800, T55),
+    (-410236200, T8),
+];
+
+static AFRICA_MONROVIA: &[(i64, FixedTimespan<'static>)] = &[
+    (-1604359012, T72),
+    (63593070, T1),
+];
+
+static AFRICA_NAIROBI: &[(i64, FixedTimespan<'static>)] = &[
+    (-1946168836, T55),
+    (-1309746600, T8),
+    (-1261969200, T55),
+    (-1041388200, T40),
+    (-865305900, T8),
+];
+
+static AFRICA_NDJAMENA: &[(i64, FixedTimespan<'static>)] = &[
+    (-1830387612, T19),
+    (308703600, T75),
+    (321314400, T19),
+];
+
+static AFRICA_NIAMEY: &[(i64, FixedTimespan<'static>)] = &[
+    (-1830384508, T17),
+    (-1131231600, T1),
+    (-315619200, T19),
+];
+
+static AFRICA_NOUAKCHOTT: &[(i64, FixedTimespan<'static>)] = &[
+    (-1830380172, T1),
+    (-1131235200, T17),
+    (-286930800, T1),
+];
+
+static AFRICA_OUAGADOUGOU: &[(i64, FixedTimespan<'static>)] = &[
+    (-1830383636, T1),
+];
+
+static AFRICA_PORTO_MINUS_NOVO: &[(i64, FixedTimespan<'static>)] = &[
+    (-1830384628, T1),
+    (-1131235200, T19),
+];
+
+static AFRICA_SAO_TOME: &[(i64, FixedTimespan<'static>)] = &[
+    (-2713912016, T81),
+    (-1830384000, T1),
+    (1514768400, T19),
+    (1546304400, T1),
+];
+
+static AFRICA_TRIPOLI: &[(i64, FixedTimespan<'static>)] = &[
+    (-1577926364, T13),
+    (-574902000, T14),
+    (-568087200, T13),
+    (-512175600, T14),
+    (-504928800, T13),
+    (-449888400, T14),
+    (-441856800, T13),
+    (-347158800, T29),
+    (378684000, T13),
+    (386463600, T14),
+    (402271200, T13),
+    (417999600, T14),
+    (433807200, T13),
+    (449622000, T14),
+    (465429600, T13),
+    (481590000, T14),
+    (496965600, T13),
+    (512953200, T14),
+    (528674400, T13),
+    (544230000, T14),
+    (560037600, T13),
+    (575852400, T14),
+    (591660000, T13),
+    (607388400, T14),
+    (623196000, T13),
+    (641775600, T29),
+    (844034400, T13),
+    (860108400, T14),
+    (1352505600, T13),
+    (1364515200, T14),
+];
+
+static AFRICA_TUNIS: &[(i64, FixedTimespan<'static>)] = &[
+    (-2797202444, T10),
+    (-1855958961, T13),
+    (-969242400, T14),
+    (-950493600, T13),
+    (-941940000, T14),
+    (-891136800, T13),
+    (-877827600, T14),
+    (-857257200, T13),
+    (-844556400, T14),
+    (-842918400, T13),
+    (-842223600, T14),
+    (-828230400, T13),
+    (-812502000, T14),
+    (-796269600, T13),
+    (-781052400, T14),
+    (-766634400, T13),
+    (231202800, T14),
+    (243903600, T13),
+    (262825200, T14),
+    (276044400, T13),
+    (581122800, T14),
+    (591145200, T13),
+    (606870000, T14),
+    (622594800, T13),
+    (641516400, T14),
+    (654649200, T13),
+    (1114902000, T14),
+    (1128038400, T13),
+    (1143334800, T14),
+    (1162083600, T13),
+    (1174784400, T14),
+    (1193533200, T13),
+    (1206838800, T14),
+    (1224982800, T13),
+];
+
+static AFRICA_WINDHOEK: &[(i64, FixedTimespan<'static>)] = &[
+    (-2458170504, T85),
+    (-2109288600, T51),
+    (-860976000, T52),
+    (-845254800, T51),
+    (764200800, T86),
+    (778640400, T25),
+    (796780800, T86),
+    (810090000, T25),
+    (828835200, T86),
+    (841539600, T25),
+    (860284800, T86),
+    (873594000, T25),
+    (891734400, T86),
+    (905043600, T25),
+    (923184000, T86),
+    (936493200, T25),
+    (954633600, T86),
+    (967942800, T25),
+    (986083200, T86),
+    (999392400, T25),
+    (1018137600, T86),
+    (1030842000, T25),
+    (1049587200, T86),
+    (1062896400, T25),
+    (1081036800, T86),
+    (1094346000, T25),
+    (1112486400, T86),
+    (1125795600, T25),
+    (1143936000, T86),
+    (1157245200, T25),
+    (1175385600, T86),
+    (1188694800, T25),
+    (1207440000, T86),
+    (1220749200, T25),
+    (1238889600, T86),
+    (1252198800, T25),
+    (1270339200, T86),
+    (1283648400, T25),
+    (1301788800, T86),
+    (1315098000, T25),
+    (1333238400, T86),
+    (1346547600, T25),
+    (1365292800, T86),
+    (1377997200, T25),
+    (1396742400, T86),
+    (1410051600, T25),
+    (1428192000, T86),
+    (1441501200, T25),
+    (1459641600, T86),
+    (1472950800, T25),
+    (1491091200, T86),
+    (1504400400, T25),
+];
+
+static AMERICA_ADAK: &[(i64, FixedTimespan<'static>)] = &[
+    (-3225223727, T88),
+    (-2188944802, T89),
+    (-880196400, T90),
+    (-765374400, T89),
+    (-21466800, T91),
+    (-5745600, T92),
+    (9982800, T91),
+    (25704000, T92),
+    (41432400, T91),
+    (57758400, T92),
+    (73486800, T91),
+    (89208000, T92),
+    (104936400, T91),
+    (120657600, T92),
+    (126709200, T91),
+    (152107200, T92),
+    (162392400, T91),
+    (183556800, T92),
+    (199285200, T91),
+    (215611200, T92),
+    (230734800, T91),
+    (247060800, T92),
+    (262789200, T91),
+    (278510400, T92),
+    (294238800, T91),
+    (309960000, T92),
+    (325688400, T91),
+    (341409600, T92),
+    (357138000, T91),
+    (372859200, T92),
+    (388587600, T91),
+    (404913600, T92),
+    (420037200, T91),
+    (452088000, T93),
+    (467809200, T94),
+    (483537600, T93),
+    (499258800, T94),
+    (514987200, T93),
+    (530708400, T94),
+    (544622400, T93),
+    (562158000, T94),
+    (576072000, T93),
+    (594212400, T94),
+    (607521600, T93),
+    (625662000, T94),
+    (638971200, T93),
+    (657111600, T94),
+    (671025600, T93),
+    (688561200, T94),
+    (702475200, T93),
+    (720010800, T94),
+    (733924800, T93),
+    (752065200, T94),
+    (765374400, T93),
+    (783514800, T94),
+    (796824000, T93),
+    (814964400, T94),
+    (828878400, T93),
+    (846414000, T94),
+    (860328000, T93),
+    (877863600, T94),
+    (891777600, T93),
+    (909313200, T94),
+    (923227200, T93),
+    (941367600, T94),
+    (954676800, T93),
+    (972817200, T94),
+    (986126400, T93),
+    (1004266800, T94),
+    (1018180800, T93),
+    (1035716400, T94),
+    (1049630400, T93),
+    (1067166000, T94),
+    (1081080000, T93),
+    (1099220400, T94),
+    (1112529600, T93),
+    (1130670000, T94),
+    (1143979200, T93),
+    (1162119600, T94),
+    (1173614400, T93),
+    (1194174000, T94),
+    (1205064000, T93),
+    (1225623600, T94),
+];
+
+static AMERICA_ANCHORAGE: &[(i64, FixedTimespan<'static>)] = &[
+    (-3225223727, T96),
+    (-2188951224, T97),
+    (-880200000, T98),
+    (-765378000, T97),
+    (-21470400, T99),
+    (-5749200, T100),
+    (9979200, T99),
+    (25700400, T100),
+    (41428800, T99),
+    (57754800, T100),
+    (73483200, T99),
+    (89204400, T100),
+    (104932800, T99),
+    (120654000, T100),
+    (126705600, T99),
+    (152103600, T100),
+    (162388800, T99),
+    (183553200, T100),
+    (199281600, T99),
+    (215607600, T100),
+    (230731200, T99),
+    (247057200, T100),
+    (262785600, T99),
+    (278506800, T100),
+    (294235200, T99),
+    (309956400, T100),
+    (325684800, T99),
+    (341406000, T100),
+    (357134400, T99),
+    (372855600, T100),
+    (388584000, T99),
+    (404910000, T100),
+    (420033600, T99),
+    (452084400, T101),
+    (467805600, T102),
+    (483534000, T101),
+    (499255200, T102),
+    (514983600, T101),
+    (530704800, T102),
+    (544618800, T101),
+    (562154400, T102),
+    (576068400, T101),
+    (594208800, T102),
+    (607518000, T101),
+    (625658400, T102),
+    (638967600, T101),
+    (657108000, T102),
+    (671022000, T101),
+    (688557600, T102),
+    (702471600, T101),
+    (720007200, T102),
+    (733921200, T101),
+    (752061600, T102),
+    (765370800, T101),
+    (783511200, T102),
+    (796820400, T101),
+    (814960800, T102),
+    (828874800, T101),
+    (846410400, T102),
+    (860324400, T101),
+    (877860000, T102),
+    (891774000, T101),
+    (909309600, T102),
+    (923223600, T101),
+    (941364000, T102),
+    (954673200, T101),
+    (972813600, T102),
+    (986122800, T101),
+    (1004263200, T102),
+    (1018177200, T101),
+    (1035712800, T102),
+    (1049626800, T101),
+    (1067162400, T102),
+    (1081076400, T101),
+    (1099216800, T102),
+    (1112526000, T101),
+    (1130666400, T102),
+    (1143975600, T101),
+    (1162116000, T102),
+    (1173610800, T101),
+    (1194170400, T102),
+    (1205060400, T101),
+    (1225620000, T102),
+];
+
+static AMERICA_ANGUILLA: &[(i64, FixedTimespan<'static>)] = &[
+    (-1825098464, T104),
+];
+
+static AMERICA_ANTIGUA: &[(i64, FixedTimespan<'static>)] = &[
+    (-1825098768, T106),
+    (-599598000, T104),
+];
+
+static AMERICA_ARAGUAINA: &[(i64, FixedTimespan<'static>)] = &[
+    (-1767214032, T108),
+    (-1206957600, T109),
+    (-1191362400, T108),
+    (-1175374800, T109),
+    (-1159826400, T108),
+    (-633819600, T109),
+    (-622069200, T108),
+    (-602283600, T109),
+    (-591832800, T108),
+    (-570747600, T109),
+    (-560210400, T108),
+    (-539125200, T109),
+    (-531352800, T108),
+    (-191365200, T109),
+    (-184197600, T108),
+    (-155163600, T109),
+    (-150069600, T108),
+    (-128898000, T109),
+    (-121125600, T108),
+    (-99954000, T109),
+    (-89589600, T108),
+    (-68418000, T109),
+    (-57967200, T108),
+    (499748400, T109),
+    (511236000, T108),
+    (530593200, T109),
+    (540266400, T108),
+    (562129200, T109),
+    (571197600, T108),
+    (592974000, T109),
+    (602042400, T108),
+    (624423600, T109),
+    (634701600, T108),
+    (813726000, T109),
+    (824004000, T108),
+    (844570800, T109),
+    (856058400, T108),
+    (876106800, T109),
+    (888717600, T108),
+    (908074800, T109),
+    (919562400, T108),
+    (938919600, T109),
+    (951616800, T108),
+    (970974000, T109),
+    (982461600, T108),
+    (1003028400, T109),
+    (1013911200, T108),
+    (1036292400, T109),
+    (1045360800, T108),
+    (1350788400, T109),
+    (1361066400, T108),
+];
+
+static AMERICA_ARGENTINA_BUENOS_AIRES: &[(i64, FixedTimespan<'static>)] = &[
+    (-2372097972, T111),
+    (-1567453392, T112),
+    (-1233432000, T113),
+    (-1222981200, T112),
+    (-1205956800, T113),
+    (-1194037200, T112),
+    (-1172865600, T113),
+    (-1162501200, T112),
+    (-1141329600, T113),
+    (-1130965200, T112),
+    (-1109793600, T113),
+    (-1099429200, T112),
+    (-1078257600, T113),
+    (-1067806800, T112),
+    (-1046635200, T113),
+    (-1036270800, T112),
+    (-1015099200, T113),
+    (-1004734800, T112),
+    (-983563200, T113),
+    (-973198800, T112),
+    (-952027200, T113),
+    (-941576400, T112),
+    (-931032000, T113),
+    (-900882000, T112),
+    (-890337600, T113),
+    (-833749200, T112),
+    (-827265600, T113),
+    (-752274000, T112),
+    (-733780800, T113),
+    (-197326800, T112),
+    (-190843200, T113),
+    (-184194000, T112),
+    (-164491200, T113),
+    (-152658000, T112),
+    (-132955200, T113),
+    (-121122000, T112),
+    (-101419200, T113),
+    (-86821200, T112),
+    (-71092800, T113),
+    (-54766800, T112),
+    (-39038400, T113),
+    (-23317200, T112),
+    (-7588800, T108),
+    (128142000, T109),
+    (136605600, T108),
+    (596948400, T109),
+    (605066400, T108),
+    (624423600, T109),
+    (636516000, T108),
+    (656478000, T109),
+    (667965600, T108),
+    (687927600, T109),
+    (699415200, T108),
+    (719377200, T109),
+    (731469600, T108),
+    (938919600, T112),
+    (938923200, T113),
+    (1198983600, T109),
+    (1205632800, T108),
+    (1224385200, T109),
+    (1237082400, T108),
+];
+
+static AMERICA_ARGENTINA_CATAMARCA: &[(i64, FixedTimespan<'static>)] = &[
+    (-2372096212, T111),
+    (-1567453392, T112),
+    (-1233432000, T113),
+    (-1222981200, T112),
+    (-1205956800, T113),
+    (-1194037200, T112),
+    (-1172865600, T113),
+    (-1162501200, T112),
+    (-1141329600, T113),
+    (-1130965200, T112),
+    (-1109793600, T113),
+    (-1099429200, T112),
+    (-1078257600, T113),
+    (-1067806800, T112),
+    (-1046635200, T113),
+    (-1036270800, T112),
+    (-1015099200, T113),
+    (-1004734800, T112),
+    (-983563200, T113),
+    (-973198800, T112),
+    (-952027200, T113),
+    (-941576400, T112),
+    (-931032000, T113),
+    (-900882000, T112),
+    (-890337600, T113),
+    (-833749200, T112),
+    (-827265600, T113),
+    (-752274000, T112),
+    (-733780800, T113),
+    (-197326800, T112),
+    (-190843200, T113),
+    (-184194000, T112),
+    (-164491200, T113),
+    (-152658000, T112),
+    (-132955200, T113),
+    (-121122000, T112),
+    (-101419200, T113),
+    (-86821200, T112),
+    (-71092800, T113),
+    (-54766800, T112),
+    (-39038400, T113),
+    (-23317200, T112),
+    (-7588800, T108),
+    (128142000, T109),
+    (136605600, T108),
+    (596948400, T109),
+    (605066400, T108),
+    (624423600, T109),
+    (636516000, T108),
+    (656478000, T109),
+    (667965600, T112),
+    (687931200, T109),
+    (699415200, T108),
+    (719377200, T109),
+    (731469600, T108),
+    (938919600, T112),
+    (938923200, T113),
+    (1086058800, T112),
+    (1087704000, T108),
+    (1198983600, T109),
+    (1205632800, T108),
+];
+
+static AMERICA_ARGENTINA_CORDOBA: &[(i64, FixedTimespan<'static>)] = &[
+    (-1567453392, T112),
+    (-1233432000, T113),
+    (-1222981200, T112),
+    (-1205956800, T113),
+    (-1194037200, T112),
+    (-1172865600, T113),
+    (-1162501200, T112),
+    (-1141329600, T113),
+    (-1130965200, T112),
+    (-1109793600, T113),
+    (-1099429200, T112),
+    (-1078257600, T113),
+    (-1067806800, T112),
+    (-1046635200, T113),
+    (-1036270800, T112),
+    (-1015099200, T113),
+    (-1004734800, T112),
+    (-983563200, T113),
+    (-973198800, T112),
+    (-952027200, T113),
+    (-941576400, T112),
+    (-931032000, T113),
+    (-900882000, T112),
+    (-890337600, T113),
+    (-833749200, T112),
+    (-827265600, T113),
+    (-752274000, T112),
+    (-733780800, T113),
+    (-197326800, T112),
+    (-190843200, T113),
+    (-184194000, T112),
+    (-164491200, T113),
+    (-152658000, T112),
+    (-132955200, T113),
+    (-121122000, T112),
+    (-101419200, T113),
+    (-86821200, T112),
+    (-71092800, T113),
+    (-54766800, T112),
+    (-39038400, T113),
+    (-23317200, T112),
+    (-7588800, T108),
+    (128142000, T109),
+    (136605600, T108),
+    (596948400, T109),
+    (605066400, T108),
+    (624423600, T109),
+    (636516000, T108),
+    (656478000, T109),
+    (667965600, T112),
+    (687931200, T109),
+    (699415200, T108),
+    (719377200, T109),
+    (731469600, T108),
+    (938919600, T112),
+    (938923200, T113),
+    (1198983600, T109),
+    (1205632800, T108),
+    (1224385200, T109),
+    (1237082400, T108),
+];
+
+static AMERICA_ARGENTINA_JUJUY: &[(i64, FixedTimespan<'static>)] = &[
+    (-2372096328, T111),
+    (-1567453392, T112),
+    (-1233432000, T113),
+    (-1222981200, T112),
+    (-1205956800, T113),
+    (-1194037200, T112),
+    (-1172865600, T113),
+    (-1162501200, T112),
+    (-1141329600, T113),
+    (-1130965200, T112),
+    (-1109793600, T113),
+    (-1099429200, T112),
+    (-1078257600, T113),
+    (-1067806800, T112),
+    (-1046635200, T113),
+    (-1036270800, T112),
+    (-1015099200, T113),
+    (-1004734800, T112),
+    (-983563200, T113),
+    (-973198800, T112),
+    (-952027200, T113),
+    (-941576400, T112),
+    (-931032000, T113),
+    (-900882000, T112),
+    (-890337600, T113),
+    (-833749200, T112),
+    (-827265600, T113),
+    (-752274000, T112),
+    (-733780800, T113),
+    (-197326800, T112),
+    (-190843200, T113),
+    (-184194000, T112),
+    (-164491200, T113),
+    (-152658000, T112),
+    (-132955200, T113),
+    (-121122000, T112),
+    (-101419200, T113),
+    (-86821200, T112),
+    (-71092800, T113),
+    (-54766800, T112),
+    (-39038400, T113),
+    (-23317200, T112),
+    (-7588800, T108),
+    (128142000, T109),
+    (136605600, T108),
+    (596948400, T109),
+    (605066400, T108),
+    (624423600, T109),
+    (636516000, T112),
+    (657086400, T113),
+    (669178800, T112),
+    (686721600, T109),
+    (699415200, T108),
+    (719377200, T109),
+    (731469600, T108),
+    (938919600, T112),
+    (938923200, T113),
+    (1198983600, T109),
+    (1205632800, T108),
+];
+
+static AMERICA_ARGENTINA_LA_RIOJA: &[(i64, FixedTimespan<'static>)] = &[
+    (-2372095956, T111),
+    (-1567453392, T112),
+    (-1233432000, T113),
+    (-1222981200, T112),
+    (-1205956800, T113),
+    (-1194037200, T112),
+    (-1172865600, T113),
+    (-1162501200, T112),
+    (-1141329600, T113),
+    (-1130965200, T112),
+    (-1109793600, T113),
+    (-1099429200, T112),
+    (-1078257600, T113),
+    (-1067806800, T112),
+    (-1046635200, T113),
+    (-1036270800, T112),
+    (-1015099200, T113),
+    (-1004734800, T112),
+    (-983563200, T113),
+    (-973198800, T112),
+    (-952027200, T113),
+    (-941576400, T112),
+    (-931032000, T113),
+    (-900882000, T112),
+    (-890337600, T113),
+    (-833749200, T112),
+    (-827265600, T113),
+    (-752274000, T112),
+    (-733780800, T113),
+    (-197326800, T112),
+    (-190843200, T113),
+    (-184194000, T112),
+    (-164491200, T113),
+    (-152658000, T112),
+    (-132955200, T113),
+    (-121122000, T112),
+    (-101419200, T113),
+    (-86821200, T112),
+    (-71092800, T113),
+    (-54766800, T112),
+    (-39038400, T113),
+    (-23317200, T112),
+    (-7588800, T108),
+    (128142000, T109),
+    (136605600, T108),
+    (596948400, T109),
+    (605066400, T108),
+    (624423600, T109),
+    (636516000, T108),
+    (656478000, T109),
+    (667792800, T112),
+    (673588800, T108),
+    (687927600, T109),
+    (699415200, T108),
+    (719377200, T109),
+    (731469600, T108),
+    (938919600, T112),
+    (938923200, T113),
+    (1086058800, T112),
+    (1087704000, T108),
+    (1198983600, T109),
+    (1205632800, T108),
+];
+
+static AMERICA_ARGENTINA_MENDOZA: &[(i64, FixedTimespan<'static>)] = &[
+    (-2372095484, T111),
+    (-1567453392, T112),
+    (-1233432000, T113),
+    (-1222981200, T112),
+    (-1205956800, T113),
+    (-1194037200, T112),
+    (-1172865600, T113),
+    (-1162501200, T112),
+    (-1141329600, T113),
+    (-1130965200, T112),
+    (-1109793600, T113),
+    (-1099429200, T112),
+    (-1078257600, T113),
+    (-1067806800, T112),
+    (-1046635200, T113),
+    (-1036270800, T112),
+    (-1015099200, T113),
+    (-1004734800, T112),
+    (-983563200, T113),
+    (-973198800, T112),
+    (-952027200, T113),
+    (-941576400, T112),
+    (-931032000, T113),
+    (-900882000, T112),
+    (-890337600, T113),
+    (-833749200, T112),
+    (-827265600, T113),
+    (-752274000, T112),
+    (-733780800, T113),
+    (-197326800, T112),
+    (-190843200, T113),
+    (-184194000, T112),
+    (-164491200, T113),
+    (-152658000, T112),
+    (-132955200, T113),
+    (-121122000, T112),
+    (-101419200, T113),
+    (-86821200, T112),
+    (-71092800, T113),
+    (-54766800, T112),
+    (-39038400, T113),
+    (-23317200, T112),
+    (-7588800, T108),
+    (128142000, T109),
+    (136605600, T108),
+    (596948400, T109),
+    (605066400, T108),
+    (624423600, T109),
+    (636516000, T112),
+    (655963200, T113),
+    (667796400, T112),
+    (687499200, T113),
+    (699418800, T112),
+    (719380800, T109),
+    (731469600, T108),
+    (938919600, T112),
+    (938923200, T113),
+    (1085281200, T112),
+    (1096171200, T108),
+    (1198983600, T109),
+    (1205632800, T108),
+];
+
+static AMERICA_ARGENTINA_RIO_GALLEGOS: &[(i64, FixedTimespan<'static>)] = &[
+    (-2372095388, T111),
+    (-1567453392, T112),
+    (-1233432000, T113),
+    (-1222981200, T112),
+    (-1205956800, T113),
+    (-1194037200, T112),
+    (-1172865600, T113),
+    (-1162501200, T112),
+    (-1141329600, T113),
+    (-1130965200, T112),
+    (-1109793600, T113),
+    (-1099429200, T112),
+    (-1078257600, T113),
+    (-1067806800, T112),
+    (-1046635200, T113),
+    (-1036270800, T112),
+    (-1015099200, T113),
+    (-1004734800, T112),
+    (-983563200, T113),
+    (-973198800, T112),
+    (-952027200, T113),
+    (-941576400, T112),
+    (-931032000, T113),
+    (-900882000, T112),
+    (-890337600, T113),
+    (-833749200, T112),
+    (-827265600, T113),
+    (-752274000, T112),
+    (-733780800, T113),
+    (-197326800, T112),
+    (-190843200, T113),
+    (-184194000, T112),
+    (-164491200, T113),
+    (-152658000, T112),
+    (-132955200, T113),
+    (-121122000, T112),
+    (-101419200, T113),
+    (-86821200, T112),
+    (-71092800, T113),
+    (-54766800, T112),
+    (-39038400, T113),
+    (-23317200, T112),
+    (-7588800, T108),
+    (128142000, T109),
+    (136605600, T108),
+    (596948400, T109),
+    (605066400, T108),
+    (624423600, T109),
+    (636516000, T108),
+    (656478000, T109),
+    (667965600, T108),
+    (687927600, T109),
+    (699415200, T108),
+    (719377200, T109),
+    (731469600, T108),
+    (938919600, T112),
+    (938923200, T113),
+    (1086058800, T112),
+    (1087704000, T108),
+    (1198983600, T109),
+    (1205632800, T108),
+];
+
+static AMERICA_ARGENTINA_SALTA: &[(i64, FixedTimespan<'static>)] = &[
+    (-2372096300, T111),
+    (-1567453392, T112),
+    (-1233432000, T113),
+    (-1222981200, T112),
+    (-1205956800, T113),
+    (-1194037200, T112),
+    (-1172865600, T113),
+    (-1162501200, T112),
+    (-1141329600, T113),
+    (-1130965200, T112),
+    (-1109793600, T113),
+    (-1099429200, T112),
+    (-1078257600, T113),
+    (-1067806800, T112),
+    (-1046635200, T113),
+    (-1036270800, T112),
+    (-1015099200, T113),
+    (-1004734800, T112),
+    (-983563200, T113),
+    (-973198800, T112),
+    (-952027200, T113),
+    (-941576400, T112),
+    (-931032000, T113),
+    (-900882000, T112),
+    (-890337600, T113),
+    (-833749200, T112),
+    (-827265600, T113),
+    (-752274000, T112),
+    (-733780800, T113),
+    (-197326800, T112),
+    (-190843200, T113),
+    (-184194000, T112),
+    (-164491200, T113),
+    (-152658000, T112),
+    (-132955200, T113),
+    (-121122000, T112),
+    (-101419200, T113),
+    (-86821200, T112),
+    (-71092800, T113),
+    (-54766800, T112),
+    (-39038400, T113),
+    (-23317200, T112),
+    (-7588800, T108),
+    (128142000, T109),
+    (136605600, T108),
+    (596948400, T109),
+    (605066400, T108),
+    (624423600, T109),
+    (636516000, T108),
+    (656478000, T109),
+    (667965600, T112),
+    (687931200, T109),
+    (699415200, T108),
+    (719377200, T109),
+    (731469600, T108),
+    (938919600, T112),
+    (938923200, T113),
+    (1198983600, T109),
+    (1205632800, T108),
+];
+
+static AMERICA_ARGENTINA_SAN_JUAN: &[(i64, FixedTimespan<'static>)] = &[
+    (-2372095556, T111),
+    (-1567453392, T112),
+    (-1233432000, T113),
+    (-1222981200, T112),
+    (-1205956800, T113),
+    (-1194037200, T112),
+    (-1172865600, T113),
+    (-1162501200, T112),
+    (-1141329600, T113),
+    (-1130965200, T112),
+    (-1109793600, T113),
+    (-1099429200, T112),
+    (-1078257600, T113),
+    (-1067806800, T112),
+    (-1046635200, T113),
+    (-1036270800, T112),
+    (-1015099200, T113),
+    (-1004734800, T112),
+    (-983563200, T113),
+    (-973198800, T112),
+    (-952027200, T113),
+    (-941576400, T112),
+    (-931032000, T113),
+    (-900882000, T112),
+    (-890337600, T113),
+    (-833749200, T112),
+    (-827265600, T113),
+    (-752274000, T112),
+    (-733780800, T113),
+    (-197326800, T112),
+    (-190843200, T113),
+    (-184194000, T112),
+    (-164491200, T113),
+    (-152658000, T112),
+    (-132955200, T113),
+    (-121122000, T112),
+    (-101419200, T113),
+    (-86821200, T112),
+    (-71092800, T113),
+    (-54766800, T112),
+    (-39038400, T113),
+    (-23317200, T112),
+    (-7588800, T108),
+    (128142000, T109),
+    (136605600, T108),
+    (596948400, T109),
+    (605066400, T108),
+    (624423600, T109),
+    (636516000, T108),
+    (656478000, T109),
+    (667792800, T112),
+    (673588800, T108),
+    (687927600, T109),
+    (699415200, T108),
+    (719377200, T109),
+    (731469600, T108),
+    (938919600, T112),
+    (938923200, T113),
+    (1085972400, T112),
+    (1090728000, T108),
+    (1198983600, T109),
+    (1205632800, T108),
+];
+
+static AMERICA_ARGENTINA_SAN_LUIS: &[(i64, FixedTimespan<'static>)] = &[
+    (-2372096076, T111),
+    (-1567453392, T112),
+    (-1233432000, T113),
+    (-1222981200, T112),
+    (-1205956800, T113),
+    (-1194037200, T112),
+    (-1172865600, T113),
+    (-1162501200, T112),
+    (-1141329600, T113),
+    (-1130965200, T112),
+    (-1109793600, T113),
+    (-1099429200, T112),
+    (-1078257600, T113),
+    (-1067806800, T112),
+    (-1046635200, T113),
+    (-1036270800, T112),
+    (-1015099200, T113),
+    (-1004734800, T112),
+    (-983563200, T113),
+    (-973198800, T112),
+    (-952027200, T113),
+    (-941576400, T112),
+    (-931032000, T113),
+    (-900882000, T112),
+    (-890337600, T113),
+    (-833749200, T112),
+    (-827265600, T113),
+    (-752274000, T112),
+    (-733780800, T113),
+    (-197326800, T112),
+    (-190843200, T113),
+    (-184194000, T112),
+    (-164491200, T113),
+    (-152658000, T112),
+    (-132955200, T113),
+    (-121122000, T112),
+    (-101419200, T113),
+    (-86821200, T112),
+    (-71092800, T113),
+    (-54766800, T112),
+    (-39038400, T113),
+    (-23317200, T112),
+    (-7588800, T108),
+    (128142000, T109),
+    (136605600, T108),
+    (596948400, T109),
+    (605066400, T108),
+    (624423600, T109),
+    (637380000, T112),
+    (655963200, T113),
+    (667796400, T112),
+    (675748800, T108),
+    (1085972400, T112),
+    (1090728000, T108),
+    (1198983600, T109),
+    (1200880800, T113),
+    (1205031600, T112),
+    (1223784000, T113),
+    (1236481200, T112),
+    (1255233600, T108),
+];
+
+static AMERICA_ARGENTINA_TUCUMAN: &[(i64, FixedTimespan<'static>)] = &[
+    (-2372096348, T111),
+    (-1567453392, T112),
+    (-1233432000, T113),
+    (-1222981200, T112),
+    (-1205956800, T113),
+    (-1194037200, T112),
+    (-1172865600, T113),
+    (-1162501200, T112),
+    (-1141329600, T113),
+    (-1130965200, T112),
+    (-1109793600, T113),
+    (-1099429200, T112),
+    (-1078257600, T113),
+    (-1067806800, T112),
+    (-1046635200, T113),
+    (-1036270800, T112),
+    (-1015099200, T113),
+    (-1004734800, T112),
+    (-983563200, T113),
+    (-973198800, T112),
+    (-952027200, T113),
+    (-941576400, T112),
+    (-931032000, T113),
+    (-900882000, T112),
+    (-890337600, T113),
+    (-833749200, T112),
+    (-827265600, T113),
+    (-752274000, T112),
+    (-733780800, T113),
+    (-197326800, T112),
+    (-190843200, T113),
+    (-184194000, T112),
+    (-164491200, T113),
+    (-152658000, T112),
+    (-132955200, T113),
+    (-121122000, T112),
+    (-101419200, T113),
+    (-86821200, T112),
+    (-71092800, T113),
+    (-54766800, T112),
+    (-39038400, T113),
+    (-23317200, T112),
+    (-7588800, T108),
+    (128142000, T109),
+    (136605600, T108),
+    (596948400, T109),
+    (605066400, T108),
+    (624423600, T109),
+    (636516000, T108),
+    (656478000, T109),
+    (667965600, T112),
+    (687931200, T109),
+    (699415200, T108),
+    (719377200, T109),
+    (731469600, T108),
+    (938919600, T112),
+    (938923200, T113),
+    (1086058800, T112),
+    (1087099200, T108),
+    (1198983600, T109),
+    (1205632800, T108),
+    (1224385200, T109),
+    (1237082400, T108),
+];
+
+static AMERICA_ARGENTINA_USHUAIA: &[(i64, FixedTimespan<'static>)] = &[
+    (-2372095608, T111),
+    (-1567453392, T112),
+    (-1233432000, T113),
+    (-1222981200, T112),
+    (-1205956800, T113),
+    (-1194037200, T112),
+    (-1172865600, T113),
+    (-1162501200, T112),
+    (-1141329600, T113),
+    (-1130965200, T112),
+    (-1109793600, T113),
+    (-1099429200, T112),
+    (-1078257600, T113),
+    (-1067806800, T112),
+    (-1046635200, T113),
+    (-1036270800, T112),
+    (-1015099200, T113),
+    (-1004734800, T112),
+    (-983563200, T113),
+    (-973198800, T112),
+    (-952027200, T113),
+    (-941576400, T112),
+    (-931032000, T113),
+    (-900882000, T112),
+    (-890337600, T113),
+    (-833749200, T112),
+    (-827265600, T113),
+    (-752274000, T112),
+    (-733780800, T113),
+    (-197326800, T112),
+    (-190843200, T113),
+    (-184194000, T112),
+    (-164491200, T113),
+    (-152658000, T112),
+    (-132955200, T113),
+    (-121122000, T112),
+    (-101419200, T113),
+    (-86821200, T112),
+    (-71092800, T113),
+    (-54766800, T112),
+    (-39038400, T113),
+    (-23317200, T112),
+    (-7588800, T108),
+    (128142000, T109),
+    (136605600, T108),
+    (596948400, T109),
+    (605066400, T108),
+    (624423600, T109),
+    (636516000, T108),
+    (656478000, T109),
+    (667965600, T108),
+    (687927600, T109),
+    (699415200, T108),
+    (719377200, T109),
+    (731469600, T108),
+    (938919600, T112),
+    (938923200, T113),
+    (1085886000, T112),
+    (1087704000, T108),
+    (1198983600, T109),
+    (1205632800, T108),
+];
+
+static AMERICA_ARUBA: &[(i64, FixedTimespan<'static>)] = &[
+    (-1826738376, T126),
+    (-157750200, T104),
+];
+
+static AMERICA_ASUNCION: &[(i64, FixedTimespan<'static>)] = &[
+    (-1206389360, T112),
+    (86760000, T108),
+    (134017200, T112),
+    (181368000, T113),
+    (194497200, T112),
+    (212990400, T113),
+    (226033200, T112),
+    (244526400, T113),
+    (257569200, T112),
+    (276062400, T113),
+    (291783600, T112),
+    (307598400, T113),
+    (323406000, T112),
+    (339220800, T113),
+    (354942000, T112),
+    (370756800, T113),
+    (386478000, T112),
+    (402292800, T113),
+    (418014000, T112),
+    (433828800, T113),
+    (449636400, T112),
+    (465451200, T113),
+    (481172400, T112),
+    (496987200, T113),
+    (512708400, T112),
+    (528523200, T113),
+    (544244400, T112),
+    (560059200, T113),
+    (575866800, T112),
+    (591681600, T113),
+    (607402800, T112),
+    (625032000, T113),
+    (638938800, T112),
+    (654753600, T113),
+    (670474800, T112),
+    (686721600, T113),
+    (699418800, T112),
+    (718257600, T113),
+    (733546800, T112),
+    (749448000, T113),
+    (762318000, T112),
+    (780984000, T113),
+    (793767600, T112),
+    (812520000, T113),
+    (825649200, T112),
+    (844574400, T113),
+    (856666800, T112),
+    (876024000, T113),
+    (888721200, T112),
+    (907473600, T113),
+    (920775600, T112),
+    (938923200, T113),
+    (952225200, T112),
+    (970372800, T113),
+    (983674800, T112),
+    (1002427200, T113),
+    (1018148400, T112),
+    (1030852800, T113),
+    (1049598000, T112),
+    (1062907200, T113),
+    (1081047600, T112),
+    (1097985600, T113),
+    (1110682800, T112),
+    (1129435200, T113),
+    (1142132400, T112),
+    (1160884800, T113),
+    (1173582000, T112),
+    (1192939200, T113),
+    (1205031600, T112),
+    (1224388800, T113),
+    (1236481200, T112),
+    (1255838400, T113),
+    (1270954800, T112),
+    (1286078400, T113),
+    (1302404400, T112),
+    (1317528000, T113),
+    (1333854000, T112),
+    (1349582400, T113),
+    (1364094000, T112),
+    (1381032000, T113),
+    (1395543600, T112),
+    (1412481600, T113),
+    (1426993200, T112),
+    (1443931200, T113),
+    (1459047600, T112),
+    (1475380800, T113),
+    (1490497200, T112),
+    (1506830400, T113),
+    (1521946800, T112),
+    (1538884800, T113),
+    (1553396400, T112),
+    (1570334400, T113),
+    (1584846000, T112),
+    (1601784000, T113),
+    (1616900400, T112),
+    (1633233600, T113),
+    (1648350000, T112),
+    (1664683200, T113),
+    (1679799600, T112),
+    (1696132800, T113),
+    (1711249200, T112),
+    (1728187200, T113),
+];
+
+static AMERICA_ATIKOKAN: &[(i64, FixedTimespan<'static>)] = &[
+    (-2366733212, T129),
+    (-1632067200, T130),
+    (-1615136400, T129),
+    (-923248800, T130),
+];
+
+static AMERICA_BAHIA: &[(i64, FixedTimespan<'static>)] = &[
+    (-1767216356, T108),
+    (-1206957600, T109),
+    (-1191362400, T108),
+    (-1175374800, T109),
+    (-1159826400, T108),
+    (-633819600, T109),
+    (-622069200, T108),
+    (-602283600, T109),
+    (-591832800, T108),
+    (-570747600, T109),
+    (-560210400, T108),
+    (-539125200, T109),
+    (-531352800, T108),
+    (-191365200, T109),
+    (-184197600, T108),
+    (-155163600, T109),
+    (-150069600, T108),
+    (-128898000, T109),
+    (-121125600, T108),
+    (-99954000, T109),
+    (-89589600, T108),
+    (-68418000, T109),
+    (-57967200, T108),
+    (499748400, T109),
+    (511236000, T108),
+    (530593200, T109),
+    (540266400, T108),
+    (562129200, T109),
+    (571197600, T108),
+    (592974000, T109),
+    (602042400, T108),
+    (624423600, T109),
+    (634701600, T108),
+    (656478000, T109),
+    (666756000, T108),
+    (687927600, T109),
+    (697600800, T108),
+    (719982000, T109),
+    (728445600, T108),
+    (750826800, T109),
+    (761709600, T108),
+    (782276400, T109),
+    (793159200, T108),
+    (813726000, T109),
+    (824004000, T108),
+    (844570800, T109),
+    (856058400, T108),
+    (876106800, T109),
+    (888717600, T108),
+    (908074800, T109),
+    (919562400, T108),
+    (938919600, T109),
+    (951616800, T108),
+    (970974000, T109),
+    (982461600, T108),
+    (1003028400, T109),
+    (1013911200, T108),
+    (1036292400, T109),
+    (1045360800, T108),
+    (1318734000, T109),
+    (1330221600, T108),
+];
+
+static AMERICA_BAHIA_BANDERAS: &[(i64, FixedTimespan<'static>)] = &[
+    (-1514739600, T133),
+    (-1343149200, T129),
+    (-1234807200, T133),
+    (-1220461200, T134),
+    (-1207159200, T133),
+    (-1191344400, T129),
+    (-873828000, T133),
+    (828867600, T134),
+    (846403200, T133),
+    (860317200, T134),
+    (877852800, T133),
+    (891766800, T134),
+    (909302400, T133),
+    (923216400, T134),
+    (941356800, T133),
+    (954666000, T134),
+    (972806400, T133),
+    (989139600, T134),
+    (1001836800, T133),
+    (1018170000, T134),
+    (1035705600, T133),
+    (1049619600, T134),
+    (1067155200, T133),
+    (1081069200, T134),
+    (1099209600, T133),
+    (1112518800, T134),
+    (1130659200, T133),
+    (1143968400, T134),
+    (1162108800, T133),
+    (1175418000, T134),
+    (1193558400, T133),
+    (1207472400, T134),
+    (1225008000, T133),
+    (1238922000, T134),
+    (1256457600, T133),
+    (1270371600, T130),
+    (1288508400, T129),
+    (1301817600, T130),
+    (1319958000, T129),
+    (1333267200, T130),
+    (1351407600, T129),
+    (1365321600, T130),
+    (1382857200, T129),
+    (1396771200, T130),
+    (1414306800, T129),
+    (1428220800, T130),
+    (1445756400, T129),
+    (1459670400, T130),
+    (1477810800, T129),
+    (1491120000, T130),
+    (1509260400, T129),
+    (1522569600, T130),
+    (1540710000, T129),
+    (1554624000, T130),
+    (1572159600, T129),
+    (1586073600, T130),
+    (1603609200, T129),
+    (1617523200, T130),
+    (1635663600, T129),
+    (1648972800, T130),
+    (1667113200, T129),
+];
+
+static AMERICA_BARBADOS: &[(i64, FixedTimespan<'static>)] = &[
+    (-1841256091, T104),
+    (-874263600, T136),
+    (-862682400, T104),
+    (-841604400, T136),
+    (-830714400, T104),
+    (-811882800, T137),
+    (-798660000, T104),
+    (234943200, T136),
+    (244616400, T104),
+    (261554400, T136),
+    (276066000, T104),
+    (293004000, T136),
+    (307515600, T104),
+    (325058400, T136),
+    (338706000, T104),
+];
+
+static AMERICA_BELEM: &[(i64, FixedTimespan<'static>)] = &[
+    (-1767213964, T108),
+    (-1206957600, T109),
+    (-1191362400, T108),
+    (-1175374800, T109),
+    (-1159826400, T108),
+    (-633819600, T109),
+    (-622069200, T108),
+    (-602283600, T109),
+    (-591832800, T108),
+    (-570747600, T109),
+    (-560210400, T108),
+    (-539125200, T109),
+    (-531352800, T108),
+    (-191365200, T109),
+    (-184197600, T108),
+    (-155163600, T109),
+    (-150069600, T108),
+    (-128898000, T109),
+    (-121125600, T108),
+    (-99954000, T109),
+    (-89589600, T108),
+    (-68418000, T109),
+    (-57967200, T108),
+    (499748400, T109),
+    (511236000, T108),
+    (530593200, T109),
+    (540266400, T108),
+    (562129200, T109),
+    (571197600, T108),
+];
+
+static AMERICA_BELIZE: &[(i64, FixedTimespan<'static>)] = &[
+    (-1822500432, T129),
+    (-1616954400, T140),
+    (-1606069800, T129),
+    (-1585504800, T140),
+    (-1574015400, T129),
+    (-1554055200, T140),
+    (-1542565800, T129),
+    (-1522605600, T140),
+    (-1511116200, T129),
+    (-1490551200, T140),
+    (-1479666600, T129),
+    (-1459101600, T140),
+    (-1448217000, T129),
+    (-1427652000, T140),
+    (-1416162600, T129),
+    (-1396202400, T140),
+    (-1384713000, T129),
+    (-1364752800, T140),
+    (-1353263400, T129),
+    (-1333303200, T140),
+    (-1321813800, T129),
+    (-1301248800, T140),
+    (-1290364200, T129),
+    (-1269799200, T140),
+    (-1258914600, T129),
+    (-1238349600, T140),
+    (-1226860200, T129),
+    (-1206900000, T140),
+    (-1195410600, T129),
+    (-1175450400, T140),
+    (-1163961000, T129),
+    (-1143396000, T140),
+    (-1132511400, T129),
+    (-1111946400, T140),
+    (-1101061800, T129),
+    (-1080496800, T140),
+    (-1069612200, T129),
+    (-1049047200, T140),
+    (-1037557800, T129),
+    (-1017597600, T140),
+    (-1006108200, T129),
+    (-986148000, T140),
+    (-974658600, T129),
+    (-954093600, T140),
+    (-943209000, T129),
+    (-922644000, T140),
+    (-911759400, T129),
+    (-891194400, T140),
+    (-879705000, T129),
+    (-868212000, T141),
+    (-758746800, T129),
+    (-701892000, T140),
+    (-690402600, T129),
+    (-670442400, T140),
+    (-658953000, T129),
+    (-638992800, T140),
+    (-627503400, T129),
+    (-606938400, T140),
+    (-596053800, T129),
+    (-575488800, T140),
+    (-564604200, T129),
+    (-544039200, T140),
+    (-532549800, T129),
+    (-512589600, T140),
+    (-501100200, T129),
+    (-481140000, T140),
+    (-469650600, T129),
+    (-449690400, T140),
+    (-438201000, T129),
+    (-417636000, T140),
+    (-406751400, T129),
+    (-386186400, T140),
+    (-375301800, T129),
+    (-354736800, T140),
+    (-343247400, T129),
+    (-323287200, T140),
+    (-311797800, T129),
+    (-291837600, T140),
+    (-280348200, T129),
+    (-259783200, T140),
+    (-248898600, T129),
+    (-228333600, T140),
+    (-217449000, T129),
+    (-196884000, T140),
+    (-185999400, T129),
+    (-165434400, T140),
+    (-153945000, T129),
+    (-133984800, T140),
+    (-122495400, T129),
+    (-102535200, T140),
+    (-91045800, T129),
+    (-70480800, T140),
+    (-59596200, T129),
+    (123919200, T130),
+    (129618000, T129),
+    (409039200, T130),
+    (413874000, T129),
+];
+
+static AMERICA_BLANC_MINUS_SABLON: &[(i64, FixedTimespan<'static>)] = &[
+    (-2713896692, T104),
+    (-1632074400, T136),
+    (-1615143600, T104),
+    (-880221600, T143),
+    (-765399600, T104),
+];
+
+static AMERICA_BOA_VISTA: &[(i64, FixedTimespan<'static>)] = &[
+    (-1767211040, T112),
+    (-1206954000, T113),
+    (-1191358800, T112),
+    (-1175371200, T113),
+    (-1159822800, T112),
+    (-633816000, T113),
+    (-622065600, T112),
+    (-602280000, T113),
+    (-591829200, T112),
+    (-570744000, T113),
+    (-560206800, T112),
+    (-539121600, T113),
+    (-531349200, T112),
+    (-191361600, T113),
+    (-184194000, T112),
+    (-155160000, T113),
+    (-150066000, T112),
+    (-128894400, T113),
+    (-121122000, T112),
+    (-99950400, T113),
+    (-89586000, T112),
+    (-68414400, T113),
+    (-57963600, T112),
+    (499752000, T113),
+    (511239600, T112),
+    (530596800, T113),
+    (540270000, T112),
+    (562132800, T113),
+    (571201200, T112),
+    (938923200, T113),
+    (951620400, T112),
+    (970977600, T113),
+    (971578800, T112),
+];
+
+static AMERICA_BOGOTA: &[(i64, FixedTimespan<'static>)] = &[
+    (-1739041424, T146),
+    (704869200, T147),
+    (729057600, T146),
+];
+
+static AMERICA_BOISE: &[(i64, FixedTimespan<'static>)] = &[
+    (-2717640000, T149),
+    (-1633269600, T150),
+    (-1615129200, T149),
+    (-1601820000, T150),
+    (-1583679600, T149),
+    (-1471788000, T133),
+    (-880210800, T151),
+    (-765388800, T133),
+    (-84380400, T134),
+    (-68659200, T133),
+    (-52930800, T134),
+    (-37209600, T133),
+    (-21481200, T134),
+    (-5760000, T133),
+    (9968400, T134),
+    (25689600, T133),
+    (41418000, T134),
+    (57744000, T133),
+    (73472400, T134),
+    (89193600, T133),
+    (104922000, T134),
+    (120643200, T133),
+    (129114000, T134),
+    (152092800, T133),
+    (162378000, T134),
+    (183542400, T133),
+    (199270800, T134),
+    (215596800, T133),
+    (230720400, T134),
+    (247046400, T133),
+    (262774800, T134),
+    (278496000, T133),
+    (294224400, T134),
+    (309945600, T133),
+    (325674000, T134),
+    (341395200, T133),
+    (357123600, T134),
+    (372844800, T133),
+    (388573200, T134),
+    (404899200, T133),
+    (420022800, T134),
+    (436348800, T133),
+    (452077200, T134),
+    (467798400, T133),
+    (483526800, T134),
+    (499248000, T133),
+    (514976400, T134),
+    (530697600, T133),
+    (544611600, T134),
+    (562147200, T133),
+    (576061200, T134),
+    (594201600, T133),
+    (607510800, T134),
+    (625651200, T133),
+    (638960400, T134),
+    (657100800, T133),
+    (671014800, T134),
+    (688550400, T133),
+    (702464400, T134),
+    (720000000, T133),
+    (733914000, T134),
+    (752054400, T133),
+    (765363600, T134),
+    (783504000, T133),
+    (796813200, T134),
+    (814953600, T133),
+    (828867600, T134),
+    (846403200, T133),
+    (860317200, T134),
+    (877852800, T133),
+    (891766800, T134),
+    (909302400, T133),
+    (923216400, T134),
+    (941356800, T133),
+    (954666000, T134),
+    (972806400, T133),
+    (986115600, T134),
+    (1004256000, T133),
+    (1018170000, T134),
+    (1035705600, T133),
+    (1049619600, T134),
+    (1067155200, T133),
+    (1081069200, T134),
+    (1099209600, T133),
+    (1112518800, T134),
+    (1130659200, T133),
+    (1143968400, T134),
+    (1162108800, T133),
+    (1173603600, T134),
+    (1194163200, T133),
+    (1205053200, T134),
+    (1225612800, T133),
+];
+
+static AMERICA_CAMBRIDGE_BAY: &[(i64, FixedTimespan<'static>)] = &[
+    (-1577923200, T133),
+    (-880210800, T151),
+    (-765388800, T133),
+    (73472400, T134),
+    (89193600, T133),
+    (104922000, T134),
+    (120643200, T133),
+    (136371600, T134),
+    (152092800, T133),
+    (167821200, T134),
+    (183542400, T133),
+    (199270800, T134),
+    (215596800, T133),
+    (230720400, T134),
+    (247046400, T133),
+    (262774800, T134),
+    (278496000, T133),
+    (294224400, T134),
+    (309945600, T133),
+    (325674000, T134),
+    (341395200, T133),
+    (357123600, T134),
+    (372844800, T133),
+    (388573200, T134),
+    (404899200, T133),
+    (420022800, T134),
+    (436348800, T133),
+    (452077200, T134),
+    (467798400, T133),
+    (483526800, T134),
+    (499248000, T133),
+    (514976400, T134),
+    (530697600, T133),
+    (544611600, T134),
+    (562147200, T133),
+    (576061200, T134),
+    (594201600, T133),
+    (607510800, T134),
+    (625651200, T133),
+    (638960400, T134),
+    (657100800, T133),
+    (671014800, T134),
+    (688550400, T133),
+    (702464400, T134),
+    (720000000, T133),
+    (733914000, T134),
+    (752054400, T133),
+    (765363600, T134),
+    (783504000, T133),
+    (796813200, T134),
+    (814953600, T133),
+    (828867600, T134),
+    (846403200, T133),
+    (860317200, T134),
+    (877852800, T133),
+    (891766800, T134),
+    (909302400, T133),
+    (923216400, T134),
+    (954662400, T130),
+    (973400400, T129),
+    (1004256000, T133),
+    (1018170000, T134),
+    (1035705600, T133),
+    (1049619600, T134),
+    (1067155200, T133),
+    (1081069200, T134),
+    (1099209600, T133),
+    (1112518800, T134),
+    (1130659200, T133),
+    (1143968400, T134),
+    (1162108800, T133),
+    (1173603600, T134),
+    (1194163200, T133),
+    (1205053200, T134),
+    (1225612800, T133),
+];
+
+static AMERICA_CAMPO_GRANDE: &[(i64, FixedTimespan<'static>)] = &[
+    (-1767212492, T112),
+    (-1206954000, T113),
+    (-1191358800, T112),
+    (-1175371200, T113),
+    (-1159822800, T112),
+    (-633816000, T113),
+    (-622065600, T112),
+    (-602280000, T113),
+    (-591829200, T112),
+    (-570744000, T113),
+    (-560206800, T112),
+    (-539121600, T113),
+    (-531349200, T112),
+    (-191361600, T113),
+    (-184194000, T112),
+    (-155160000, T113),
+    (-150066000, T112),
+    (-128894400, T113),
+    (-121122000, T112),
+    (-99950400, T113),
+    (-89586000, T112),
+    (-68414400, T113),
+    (-57963600, T112),
+    (499752000, T113),
+    (511239600, T112),
+    (530596800, T113),
+    (540270000, T112),
+    (562132800, T113),
+    (571201200, T112),
+    (592977600, T113),
+    (602046000, T112),
+    (624427200, T113),
+    (634705200, T112),
+    (656481600, T113),
+    (666759600, T112),
+    (687931200, T113),
+    (697604400, T112),
+    (719985600, T113),
+    (728449200, T112),
+    (750830400, T113),
+    (761713200, T112),
+    (782280000, T113),
+    (793162800, T112),
+    (813729600, T113),
+    (824007600, T112),
+    (844574400, T113),
+    (856062000, T112),
+    (876110400, T113),
+    (888721200, T112),
+    (908078400, T113),
+    (919566000, T112),
+    (938923200, T113),
+    (951620400, T112),
+    (970977600, T113),
+    (982465200, T112),
+    (1003032000, T113),
+    (1013914800, T112),
+    (1036296000, T113),
+    (1045364400, T112),
+    (1066536000, T113),
+    (1076814000, T112),
+    (1099368000, T113),
+    (1108868400, T112),
+    (1129435200, T113),
+    (1140318000, T112),
+    (1162699200, T113),
+    (1172372400, T112),
+    (1192334400, T113),
+    (1203217200, T112),
+    (1224388800, T113),
+    (1234666800, T112),
+    (1255838400, T113),
+    (1266721200, T112),
+    (1287288000, T113),
+    (1298170800, T112),
+    (1318737600, T113),
+    (1330225200, T112),
+    (1350792000, T113),
+    (1361070000, T112),
+    (1382241600, T113),
+    (1392519600, T112),
+    (1413691200, T113),
+    (1424574000, T112),
+    (1445140800, T113),
+    (1456023600, T112),
+    (1476590400, T113),
+    (1487473200, T112),
+    (1508040000, T113),
+    (1518922800, T112),
+    (1541304000, T113),
+    (1550372400, T112),
+];
+
+static AMERICA_CANCUN: &[(i64, FixedTimespan<'static>)] = &[
+    (-1514743200, T129),
+    (378201600, T106),
+    (410504400, T129),
+    (828864000, T130),
+    (846399600, T129),
+    (860313600, T130),
+    (891759600, T155),
+    (902037600, T130),
+    (909298800, T129),
+    (923212800, T130),
+    (941353200, T129),
+    (954662400, T130),
+    (972802800, T129),
+    (989136000, T130),
+    (1001833200, T129),
+    (1018166400, T130),
+    (1035702000, T129),
+    (1049616000, T130),
+    (1067151600, T129),
+    (1081065600, T130),
+    (1099206000, T129),
+    (1112515200, T130),
+    (1130655600, T129),
+    (1143964800, T130),
+    (1162105200, T129),
+    (1175414400, T130),
+    (1193554800, T129),
+    (1207468800, T130),
+    (1225004400, T129),
+    (1238918400, T130),
+    (1256454000, T129),
+    (1270368000, T130),
+    (1288508400, T129),
+    (1301817600, T130),
+    (1319958000, T129),
+    (1333267200, T130),
+    (1351407600, T129),
+    (1365321600, T130),
+    (1382857200, T129),
+    (1396771200, T130),
+    (1414306800, T129),
+    (1422777600, T106),
+];
+
+static AMERICA_CARACAS: &[(i64, FixedTimespan<'static>)] = &[
+    (-2524505536, T157),
+    (-1826739140, T126),
+    (-157750200, T112),
+    (1197183600, T126),
+    (1462086000, T112),
+];
+
+static AMERICA_CAYENNE: &[(i64, FixedTimespan<'static>)] = &[
+    (-1846269040, T112),
+    (-71092800, T108),
+];
+
+static AMERICA_CAYMAN: &[(i64, FixedTimespan<'static>)] = &[
+    (-2524502068, T160),
+    (-1827687170, T106),
+];
+
+static AMERICA_CHICAGO: &[(i64, FixedTimespan<'static>)] = &[
+    (-2717647200, T129),
+    (-1633276800, T130),
+    (-1615136400, T129),
+    (-1601827200, T130),
+    (-1583686800, T129),
+    (-1563724800, T130),
+    (-1551632400, T129),
+    (-1538928000, T130),
+    (-1520182800, T129),
+    (-1504454400, T130),
+    (-1491757200, T129),
+    (-1473004800, T130),
+    (-1459702800, T129),
+    (-1441555200, T130),
+    (-1428253200, T129),
+    (-1410105600, T130),
+    (-1396803600, T129),
+    (-1378656000, T130),
+    (-1365354000, T129),
+    (-1347206400, T130),
+    (-1333904400, T129),
+    (-1315152000, T130),
+    (-1301850000, T129),
+    (-1283702400, T130),
+    (-1270400400, T129),
+    (-1252252800, T130),
+    (-1238950800, T129),
+    (-1220803200, T130),
+    (-1207501200, T129),
+    (-1189353600, T130),
+    (-1176051600, T129),
+    (-1157299200, T130),
+    (-1144602000, T129),
+    (-1125849600, T130),
+    (-1112547600, T129),
+    (-1094400000, T130),
+    (-1081098000, T129),
+    (-1067788800, T106),
+    (-1045414800, T129),
+    (-1031500800, T130),
+    (-1018198800, T129),
+    (-1000051200, T130),
+    (-986749200, T129),
+    (-967996800, T130),
+    (-955299600, T129),
+    (-936547200, T130),
+    (-923245200, T129),
+    (-905097600, T130),
+    (-891795600, T129),
+    (-880214400, T141),
+    (-765392400, T129),
+    (-747244800, T130),
+    (-733942800, T129),
+    (-715795200, T130),
+    (-702493200, T129),
+    (-684345600, T130),
+    (-671043600, T129),
+    (-652896000, T130),
+    (-639594000, T129),
+    (-620841600, T130),
+    (-608144400, T129),
+    (-589392000, T130),
+    (-576090000, T129),
+    (-557942400, T130),
+    (-544640400, T129),
+    (-526492800, T130),
+    (-513190800, T129),
+    (-495043200, T130),
+    (-481741200, T129),
+    (-463593600, T130),
+    (-447267600, T129),
+    (-431539200, T130),
+    (-415818000, T129),
+    (-400089600, T130),
+    (-384368400, T129),
+    (-368640000, T130),
+    (-352918800, T129),
+    (-337190400, T130),
+    (-321469200, T129),
+    (-305740800, T130),
+    (-289414800, T129),
+    (-273686400, T130),
+    (-257965200, T129),
+    (-242236800, T130),
+    (-226515600, T129),
+    (-210787200, T130),
+    (-195066000, T129),
+    (-179337600, T130),
+    (-163616400, T129),
+    (-147888000, T130),
+    (-131562000, T129),
+    (-116438400, T130),
+    (-100112400, T129),
+    (-84384000, T130),
+    (-68662800, T129),
+    (-52934400, T130),
+    (-37213200, T129),
+    (-21484800, T130),
+    (-5763600, T129),
+    (9964800, T130),
+    (25686000, T129),
+    (41414400, T130),
+    (57740400, T129),
+    (73468800, T130),
+    (89190000, T129),
+    (104918400, T130),
+    (120639600, T129),
+    (126691200, T130),
+    (152089200, T129),
+    (162374400, T130),
+    (183538800, T129),
+    (199267200, T130),
+    (215593200, T129),
+    (230716800, T130),
+    (247042800, T129),
+    (262771200, T130),
+    (278492400, T129),
+    (294220800, T130),
+    (309942000, T129),
+    (325670400, T130),
+    (341391600, T129),
+    (357120000, T130),
+    (372841200, T129),
+    (388569600, T130),
+    (404895600, T129),
+    (420019200, T130),
+    (436345200, T129),
+    (452073600, T130),
+    (467794800, T129),
+    (483523200, T130),
+    (499244400, T129),
+    (514972800, T130),
+    (530694000, T129),
+    (544608000, T130),
+    (562143600, T129),
+    (576057600, T130),
+    (594198000, T129),
+    (607507200, T130),
+    (625647600, T129),
+    (638956800, T130),
+    (657097200, T129),
+    (671011200, T130),
+    (688546800, T129),
+    (702460800, T130),
+    (719996400, T129),
+    (733910400, T130),
+    (752050800, T129),
+    (765360000, T130),
+    (783500400, T129),
+    (796809600, T130),
+    (814950000, T129),
+    (828864000, T130),
+    (846399600, T129),
+    (860313600, T130),
+    (877849200, T129),
+    (891763200, T130),
+    (909298800, T129),
+    (923212800, T130),
+    (941353200, T129),
+    (954662400, T130),
+    (972802800, T129),
+    (986112000, T130),
+    (1004252400, T129),
+    (1018166400, T130),
+    (1035702000, T129),
+    (1049616000, T130),
+    (1067151600, T129),
+    (1081065600, T130),
+    (1099206000, T129),
+    (1112515200, T130),
+    (1130655600, T129),
+    (1143964800, T130),
+    (1162105200, T129),
+    (1173600000, T130),
+    (1194159600, T129),
+    (1205049600, T130),
+    (1225609200, T129),
+];
+
+static AMERICA_CHIHUAHUA: &[(i64, FixedTimespan<'static>)] = &[
+    (-1514739600, T133),
+    (-1343149200, T129),
+    (-1234807200, T133),
+    (-1220461200, T134),
+    (-1207159200, T133),
+    (-1191344400, T129),
+    (828864000, T130),
+    (846399600, T129),
+    (860313600, T130),
+    (877849200, T129),
+    (909302400, T133),
+    (923216400, T134),
+    (941356800, T133),
+    (954666000, T134),
+    (972806400, T133),
+    (989139600, T134),
+    (1001836800, T133),
+    (1018170000, T134),
+    (1035705600, T133),
+    (1049619600, T134),
+    (1067155200, T133),
+    (1081069200, T134),
+    (1099209600, T133),
+    (1112518800, T134),
+    (1130659200, T133),
+    (1143968400, T134),
+    (1162108800, T133),
+    (1175418000, T134),
+    (1193558400, T133),
+    (1207472400, T134),
+    (1225008000, T133),
+    (1238922000, T134),
+    (1256457600, T133),
+    (1270371600, T134),
+    (1288512000, T133),
+    (1301821200, T134),
+    (1319961600, T133),
+    (1333270800, T134),
+    (1351411200, T133),
+    (1365325200, T134),
+    (1382860800, T133),
+    (1396774800, T134),
+    (1414310400, T133),
+    (1428224400, T134),
+    (1445760000, T133),
+    (1459674000, T134),
+    (1477814400, T133),
+    (1491123600, T134),
+    (1509264000, T133),
+    (1522573200, T134),
+    (1540713600, T133),
+    (1554627600, T134),
+    (1572163200, T133),
+    (1586077200, T134),
+    (1603612800, T133),
+    (1617526800, T134),
+    (1635667200, T133),
+    (1648976400, T134),
+];
+
+static AMERICA_CIUDAD_JUAREZ: &[(i64, FixedTimespan<'static>)] = &[
+    (-1514739600, T133),
+    (-1343149200, T129),
+    (-1234807200, T133),
+    (-1220461200, T134),
+    (-1207159200, T133),
+    (-1191344400, T129),
+    (828864000, T130),
+    (846399600, T129),
+    (860313600, T130),
+    (877849200, T129),
+    (909302400, T133),
+    (923216400, T134),
+    (941356800, T133),
+    (954666000, T134),
+    (972806400, T133),
+    (989139600, T134),
+    (1001836800, T133),
+    (1018170000, T134),
+    (1035705600, T133),
+    (1049619600, T134),
+    (1067155200, T133),
+    (1081069200, T134),
+    (1099209600, T133),
+    (1112518800, T134),
+    (1130659200, T133),
+    (1143968400, T134),
+    (1162108800, T133),
+    (1175418000, T134),
+    (1193558400, T133),
+    (1207472400, T134),
+    (1225008000, T133),
+    (1238922000, T134),
+    (1256457600, T133),
+    (1268557200, T134),
+    (1289116800, T133),
+    (1300006800, T134),
+    (1320566400, T133),
+    (1331456400, T134),
+    (1352016000, T133),
+    (1362906000, T134),
+    (1383465600, T133),
+    (1394355600, T134),
+    (1414915200, T133),
+    (1425805200, T134),
+    (1446364800, T133),
+    (1457859600, T134),
+    (1478419200, T133),
+    (1489309200, T134),
+    (1509868800, T133),
+    (1520758800, T134),
+    (1541318400, T133),
+    (1552208400, T134),
+    (1572768000, T133),
+    (1583658000, T134),
+    (1604217600, T133),
+    (1615712400, T134),
+    (1636272000, T133),
+    (1647162000, T134),
+    (1669788000, T133),
+    (1678611600, T134),
+    (1699171200, T133),
+];
+
+static AMERICA_COSTA_RICA: &[(i64, FixedTimespan<'static>)] = &[
+    (-1545071027, T129),
+    (288770400, T130),
+    (297234000, T129),
+    (320220000, T130),
+    (328683600, T129),
+    (664264800, T130),
+    (678344400, T129),
+    (695714400, T130),
+    (700635600, T129),
+];
+
+static AMERICA_COYHAIQUE: &[(i64, FixedTimespan<'static>)] = &[
+    (-2524504304, T166),
+    (-1892661435, T146),
+    (-1688410800, T166),
+    (-1619205435, T112),
+    (-1593806400, T166),
+    (-1335986235, T146),
+    (-1335985200, T147),
+    (-1317585600, T146),
+    (-1304362800, T147),
+    (-1286049600, T146),
+    (-1272826800, T147),
+    (-1254513600, T146),
+    (-1241290800, T147),
+    (-1222977600, T146),
+    (-1209754800, T147),
+    (-1191355200, T146),
+    (-1178132400, T112),
+    (-870552000, T146),
+    (-865278000, T112),
+    (-718056000, T146),
+    (-713649600, T112),
+    (-36619200, T113),
+    (-23922000, T112),
+    (-3355200, T113),
+    (7527600, T112),
+    (24465600, T113),
+    (37767600, T112),
+    (55915200, T113),
+    (69217200, T112),
+    (87969600, T113),
+    (100666800, T112),
+    (118209600, T113),
+    (132116400, T112),
+    (150868800, T113),
+    (163566000, T112),
+    (182318400, T113),
+    (195620400, T112),
+    (213768000, T113),
+    (227070000, T112),
+    (245217600, T113),
+    (258519600, T112),
+    (277272000, T113),
+    (289969200, T112),
+    (308721600, T113),
+    (321418800, T112),
+    (340171200, T113),
+    (353473200, T112),
+    (371620800, T113),
+    (384922800, T112),
+    (403070400, T113),
+    (416372400, T112),
+    (434520000, T113),
+    (447822000, T112),
+    (466574400, T113),
+    (479271600, T112),
+    (498024000, T113),
+    (510721200, T112),
+    (529473600, T113),
+    (545194800, T112),
+    (560923200, T113),
+    (574225200, T112),
+    (592372800, T113),
+    (605674800, T112),
+    (624427200, T113),
+    (637124400, T112),
+    (653457600, T113),
+    (668574000, T112),
+    (687326400, T113),
+    (700628400, T112),
+    (718776000, T113),
+    (732078000, T112),
+    (750225600, T113),
+    (763527600, T112),
+    (781675200, T113),
+    (794977200, T112),
+    (813729600, T113),
+    (826426800, T112),
+    (845179200, T113),
+    (859690800, T112),
+    (876628800, T113),
+    (889930800, T112),
+    (906868800, T113),
+    (923194800, T112),
+    (939528000, T113),
+    (952830000, T112),
+    (971582400, T113),
+    (984279600, T112),
+    (1003032000, T113),
+    (1015729200, T112),
+    (1034481600, T113),
+    (1047178800, T112),
+    (1065931200, T113),
+    (1079233200, T112),
+    (1097380800, T113),
+    (1110682800, T112),
+    (1128830400, T113),
+    (1142132400, T112),
+    (1160884800, T113),
+    (1173582000, T112),
+    (1192334400, T113),
+    (1206846000, T112),
+    (1223784000, T113),
+    (1237086000, T112),
+    (1255233600, T113),
+    (1270350000, T112),
+    (1286683200, T113),
+    (1304823600, T112),
+    (1313899200, T113),
+    (1335668400, T112),
+    (1346558400, T113),
+    (1367118000, T112),
+    (1378612800, T113),
+    (1398567600, T112),
+    (1410062400, T113),
+    (1463281200, T112),
+    (1471147200, T113),
+    (1494730800, T112),
+    (1502596800, T113),
+    (1526180400, T112),
+    (1534046400, T113),
+    (1554606000, T112),
+    (1567915200, T113),
+    (1586055600, T112),
+    (1599364800, T113),
+    (1617505200, T112),
+    (1630814400, T113),
+    (1648954800, T112),
+    (1662868800, T113),
+    (1680404400, T112),
+    (1693713600, T113),
+    (1712458800, T112),
+    (1725768000, T113),
+];
+
+static AMERICA_CRESTON: &[(i64, FixedTimespan<'static>)] = &[
+    (-2713882436, T133),
+    (-1680454800, T149),
+    (-1627833600, T133),
+];
+
+static AMERICA_CUIABA: &[(i64, FixedTimespan<'static>)] = &[
+    (-1767212140, T112),
+    (-1206954000, T113),
+    (-1191358800, T112),
+    (-1175371200, T113),
+    (-1159822800, T112),
+    (-633816000, T113),
+    (-622065600, T112),
+    (-602280000, T113),
+    (-591829200, T112),
+    (-570744000, T113),
+    (-560206800, T112),
+    (-539121600, T113),
+    (-531349200, T112),
+    (-191361600, T113),
+    (-184194000, T112),
+    (-155160000, T113),
+    (-150066000, T112),
+    (-128894400, T113),
+    (-121122000, T112),
+    (-99950400, T113),
+    (-89586000, T112),
+    (-68414400, T113),
+    (-57963600, T112),
+    (499752000, T113),
+    (511239600, T112),
+    (530596800, T113),
+    (540270000, T112),
+    (562132800, T113),
+    (571201200, T112),
+    (592977600, T113),
+    (602046000, T112),
+    (624427200, T113),
+    (634705200, T112),
+    (656481600, T113),
+    (666759600, T112),
+    (687931200, T113),
+    (697604400, T112),
+    (719985600, T113),
+    (728449200, T112),
+    (750830400, T113),
+    (761713200, T112),
+    (782280000, T113),
+    (793162800, T112),
+    (813729600, T113),
+    (824007600, T112),
+    (844574400, T113),
+    (856062000, T112),
+    (876110400, T113),
+    (888721200, T112),
+    (908078400, T113),
+    (919566000, T112),
+    (938923200, T113),
+    (951620400, T112),
+    (970977600, T113),
+    (982465200, T112),
+    (1003032000, T113),
+    (1013914800, T112),
+    (1036296000, T113),
+    (1045364400, T112),
+    (1099368000, T113),
+    (1108868400, T112),
+    (1129435200, T113),
+    (1140318000, T112),
+    (1162699200, T113),
+    (1172372400, T112),
+    (1192334400, T113),
+    (1203217200, T112),
+    (1224388800, T113),
+    (1234666800, T112),
+    (1255838400, T113),
+    (1266721200, T112),
+    (1287288000, T113),
+    (1298170800, T112),
+    (1318737600, T113),
+    (1330225200, T112),
+    (1350792000, T113),
+    (1361070000, T112),
+    (1382241600, T113),
+    (1392519600, T112),
+    (1413691200, T113),
+    (1424574000, T112),
+    (1445140800, T113),
+    (1456023600, T112),
+    (1476590400, T113),
+    (1487473200, T112),
+    (1508040000, T113),
+    (1518922800, T112),
+    (1541304000, T113),
+    (1550372400, T112),
+];
+
+static AMERICA_CURACAO: &[(i64, FixedTimespan<'static>)] = &[
+    (-1826738653, T126),
+    (-157750200, T104),
+];
+
+static AMERICA_DANMARKSHAVN: &[(i64, FixedTimespan<'static>)] = &[
+    (-1686091520, T108),
+    (323845200, T109),
+    (338950800, T108),
+    (354675600, T109),
+    (370400400, T108),
+    (386125200, T109),
+    (401850000, T108),
+    (417574800, T109),
+    (433299600, T108),
+    (449024400, T109),
+    (465354000, T108),
+    (481078800, T109),
+    (496803600, T108),
+    (512528400, T109),
+    (528253200, T108),
+    (543978000, T109),
+    (559702800, T108),
+    (575427600, T109),
+    (591152400, T108),
+    (606877200, T109),
+    (622602000, T108),
+    (638326800, T109),
+    (654656400, T108),
+    (670381200, T109),
+    (686106000, T108),
+    (701830800, T109),
+    (717555600, T108),
+    (733280400, T109),
+    (749005200, T108),
+    (764730000, T109),
+    (780454800, T108),
+    (796179600, T109),
+    (811904400, T108),
+    (820465200, T1),
+];
+
+static AMERICA_DAWSON: &[(i64, FixedTimespan<'static>)] = &[
+    (-2188996940, T172),
+    (-1632056400, T173),
+    (-1615125600, T172),
+    (-1596978000, T173),
+    (-1583164800, T172),
+    (-880203600, T174),
+    (-765381600, T172),
+    (-147884400, T175),
+    (-131554800, T172),
+    (120646800, T149),
+    (325677600, T150),
+    (341398800, T149),
+    (357127200, T150),
+    (372848400, T149),
+    (388576800, T150),
+    (404902800, T149),
+    (420026400, T150),
+    (436352400, T149),
+    (452080800, T150),
+    (467802000, T149),
+    (483530400, T150),
+    (499251600, T149),
+    (514980000, T150),
+    (530701200, T149),
+    (544615200, T150),
+    (562150800, T149),
+    (576064800, T150),
+    (594205200, T149),
+    (607514400, T150),
+    (625654800, T149),
+    (638964000, T150),
+    (657104400, T149),
+    (671018400, T150),
+    (688554000, T149),
+    (702468000, T150),
+    (720003600, T149),
+    (733917600, T150),
+    (752058000, T149),
+    (765367200, T150),
+    (783507600, T149),
+    (796816800, T150),
+    (814957200, T149),
+    (828871200, T150),
+    (846406800, T149),
+    (860320800, T150),
+    (877856400, T149),
+    (891770400, T150),
+    (909306000, T149),
+    (923220000, T150),
+    (941360400, T149),
+    (954669600, T150),
+    (972810000, T149),
+    (986119200, T150),
+    (1004259600, T149),
+    (1018173600, T150),
+    (1035709200, T149),
+    (1049623200, T150),
+    (1067158800, T149),
+    (1081072800, T150),
+    (1099213200, T149),
+    (1112522400, T150),
+    (1130662800, T149),
+    (1143972000, T150),
+    (1162112400, T149),
+    (1173607200, T150),
+    (1194166800, T149),
+    (1205056800, T150),
+    (1225616400, T149),
+    (1236506400, T150),
+    (1257066000, T149),
+    (1268560800, T150),
+    (1289120400, T149),
+    (1300010400, T150),
+    (1320570000, T149),
+    (1331460000, T150),
+    (1352019600, T149),
+    (1362909600, T150),
+    (1383469200, T149),
+    (1394359200, T150),
+    (1414918800, T149),
+    (1425808800, T150),
+    (1446368400, T149),
+    (1457863200, T150),
+    (1478422800, T149),
+    (1489312800, T150),
+    (1509872400, T149),
+    (1520762400, T150),
+    (1541322000, T149),
+    (1552212000, T150),
+    (1572771600, T149),
+    (1583661600, T150),
+];
+
+static AMERICA_DAWSON_CREEK: &[(i64, FixedTimespan<'static>)] = &[
+    (-2713881544, T149),
+    (-1632060000, T150),
+    (-1615129200, T149),
+    (-880207200, T177),
+    (-765385200, T149),
+    (-715788000, T150),
+    (-702486000, T149),
+    (-684338400, T150),
+    (-671036400, T149),
+    (-652888800, T150),
+    (-639586800, T149),
+    (-620834400, T150),
+    (-608137200, T149),
+    (-589384800, T150),
+    (-576082800, T149),
+    (-557935200, T150),
+    (-544633200, T149),
+    (-526485600, T150),
+    (-513183600, T149),
+    (-495036000, T150),
+    (-481734000, T149),
+    (-463586400, T150),
+    (-450284400, T149),
+    (-431532000, T150),
+    (-418230000, T149),
+    (-400082400, T150),
+    (-386780400, T149),
+    (-368632800, T150),
+    (-355330800, T149),
+    (-337183200, T150),
+    (-323881200, T149),
+    (-305733600, T150),
+    (-292431600, T149),
+    (-273679200, T150),
+    (-260982000, T149),
+    (-242229600, T150),
+    (-226508400, T149),
+    (-210780000, T150),
+    (-195058800, T149),
+    (-179330400, T150),
+    (-163609200, T149),
+    (-147880800, T150),
+    (-131554800, T149),
+    (-116431200, T150),
+    (-100105200, T149),
+    (-84376800, T150),
+    (-68655600, T149),
+    (-52927200, T150),
+    (-37206000, T149),
+    (-21477600, T150),
+    (-5756400, T149),
+    (9972000, T150),
+    (25693200, T149),
+    (41421600, T150),
+    (57747600, T149),
+    (73476000, T150),
+];
+
+static AMERICA_DENVER: &[(i64, FixedTimespan<'static>)] = &[
+    (-2717643600, T133),
+    (-1633273200, T134),
+    (-1615132800, T133),
+    (-1601823600, T134),
+    (-1583683200, T133),
+    (-1570374000, T134),
+    (-1551628800, T133),
+    (-1538924400, T134),
+    (-1534089600, T133),
+    (-880210800, T151),
+    (-765388800, T133),
+    (-147884400, T134),
+    (-131558400, T133),
+    (-116434800, T134),
+    (-100108800, T133),
+    (-84380400, T134),
+    (-68659200, T133),
+    (-52930800, T134),
+    (-37209600, T133),
+    (-21481200, T134),
+    (-5760000, T133),
+    (9968400, T134),
+    (25689600, T133),
+    (41418000, T134),
+    (57744000, T133),
+    (73472400, T134),
+    (89193600, T133),
+    (104922000, T134),
+    (120643200, T133),
+    (126694800, T134),
+    (152092800, T133),
+    (162378000, T134),
+    (183542400, T133),
+    (199270800, T134),
+    (215596800, T133),
+    (230720400, T134),
+    (247046400, T133),
+    (262774800, T134),
+    (278496000, T133),
+    (294224400, T134),
+    (309945600, T133),
+    (325674000, T134),
+    (341395200, T133),
+    (357123600, T134),
+    (372844800, T133),
+    (388573200, T134),
+    (404899200, T133),
+    (420022800, T134),
+    (436348800, T133),
+    (452077200, T134),
+    (467798400, T133),
+    (483526800, T134),
+    (499248000, T133),
+    (514976400, T134),
+    (530697600, T133),
+    (544611600, T134),
+    (562147200, T133),
+    (576061200, T134),
+    (594201600, T133),
+    (607510800, T134),
+    (625651200, T133),
+    (638960400, T134),
+    (657100800, T133),
+    (671014800, T134),
+    (688550400, T133),
+    (702464400, T134),
+    (720000000, T133),
+    (733914000, T134),
+    (752054400, T133),
+    (765363600, T134),
+    (783504000, T133),
+    (796813200, T134),
+    (814953600, T133),
+    (828867600, T134),
+    (846403200, T133),
+    (860317200, T134),
+    (877852800, T133),
+    (891766800, T134),
+    (909302400, T133),
+    (923216400, T134),
+    (941356800, T133),
+    (954666000, T134),
+    (972806400, T133),
+    (986115600, T134),
+    (1004256000, T133),
+    (1018170000, T134),
+    (1035705600, T133),
+    (1049619600, T134),
+    (1067155200, T133),
+    (1081069200, T134),
+    (1099209600, T133),
+    (1112518800, T134),
+    (1130659200, T133),
+    (1143968400, T134),
+    (1162108800, T133),
+    (1173603600, T134),
+    (1194163200, T133),
+    (1205053200, T134),
+    (1225612800, T133),
+];
+
+static AMERICA_DETROIT: &[(i64, FixedTimespan<'static>)] = &[
+    (-2051202469, T129),
+    (-1724083200, T106),
+    (-880218000, T180),
+    (-765396000, T106),
+    (-684349200, T155),
+    (-671047200, T106),
+    (-80506740, T155),
+    (-68666400, T106),
+    (-52938000, T155),
+    (-37216800, T106),
+    (104914800, T155),
+    (120636000, T106),
+    (126687600, T155),
+    (152085600, T106),
+    (167814000, T155),
+    (183535200, T106),
+    (199263600, T155),
+    (215589600, T106),
+    (230713200, T155),
+    (247039200, T106),
+    (262767600, T155),
+    (278488800, T106),
+    (294217200, T155),
+    (309938400, T106),
+    (325666800, T155),
+    (341388000, T106),
+    (357116400, T155),
+    (372837600, T106),
+    (388566000, T155),
+    (404892000, T106),
+    (420015600, T155),
+    (436341600, T106),
+    (452070000, T155),
+    (467791200, T106),
+    (483519600, T155),
+    (499240800, T106),
+    (514969200, T155),
+    (530690400, T106),
+    (544604400, T155),
+    (562140000, T106),
+    (576054000, T155),
+    (594194400, T106),
+    (607503600, T155),
+    (625644000, T106),
+    (638953200, T155),
+    (657093600, T106),
+    (671007600, T155),
+    (688543200, T106),
+    (702457200, T155),
+    (719992800, T106),
+    (733906800, T155),
+    (752047200, T106),
+    (765356400, T155),
+    (783496800, T106),
+    (796806000, T155),
+    (814946400, T106),
+    (828860400, T155),
+    (846396000, T106),
+    (860310000, T155),
+    (877845600, T106),
+    (891759600, T155),
+    (909295200, T106),
+    (923209200, T155),
+    (941349600, T106),
+    (954658800, T155),
+    (972799200, T106),
+    (986108400, T155),
+    (1004248800, T106),
+    (1018162800, T155),
+    (1035698400, T106),
+    (1049612400, T155),
+    (1067148000, T106),
+    (1081062000, T155),
+    (1099202400, T106),
+    (1112511600, T155),
+    (1130652000, T106),
+    (1143961200, T155),
+    (1162101600, T106),
+    (1173596400, T155),
+    (1194156000, T106),
+    (1205046000, T155),
+    (1225605600, T106),
+];
+
+static AMERICA_DOMINICA: &[(i64, FixedTimespan<'static>)] = &[
+    (-1846266804, T104),
+];
+
+static AMERICA_EDMONTON: &[(i64, FixedTimespan<'static>)] = &[
+    (-1998663968, T133),
+    (-1632063600, T134),
+    (-1615132800, T133),
+    (-1600614000, T134),
+    (-1596816000, T133),
+    (-1567954800, T134),
+    (-1551628800, T133),
+    (-1536505200, T134),
+    (-1523203200, T133),
+    (-1504450800, T134),
+    (-1491753600, T133),
+    (-1473001200, T134),
+    (-1459699200, T133),
+    (-880210800, T151),
+    (-765388800, T133),
+    (-715791600, T134),
+    (-702489600, T133),
+    (73472400, T134),
+    (89193600, T133),
+    (104922000, T134),
+    (120643200, T133),
+    (136371600, T134),
+    (152092800, T133),
+    (167821200, T134),
+    (183542400, T133),
+    (199270800, T134),
+    (215596800, T133),
+    (230720400, T134),
+    (247046400, T133),
+    (262774800, T134),
+    (278496000, T133),
+    (294224400, T134),
+    (309945600, T133),
+    (325674000, T134),
+    (341395200, T133),
+    (357123600, T134),
+    (372844800, T133),
+    (388573200, T134),
+    (404899200, T133),
+    (420022800, T134),
+    (436348800, T133),
+    (452077200, T134),
+    (467798400, T133),
+    (483526800, T134),
+    (499248000, T133),
+    (514976400, T134),
+    (530697600, T133),
+    (544611600, T134),
+    (562147200, T133),
+    (576061200, T134),
+    (594201600, T133),
+    (607510800, T134),
+    (625651200, T133),
+    (638960400, T134),
+    (657100800, T133),
+    (671014800, T134),
+    (688550400, T133),
+    (702464400, T134),
+    (720000000, T133),
+    (733914000, T134),
+    (752054400, T133),
+    (765363600, T134),
+    (783504000, T133),
+    (796813200, T134),
+    (814953600, T133),
+    (828867600, T134),
+    (846403200, T133),
+    (860317200, T134),
+    (877852800, T133),
+    (891766800, T134),
+    (909302400, T133),
+    (923216400, T134),
+    (941356800, T133),
+    (954666000, T134),
+    (972806400, T133),
+    (986115600, T134),
+    (1004256000, T133),
+    (1018170000, T134),
+    (1035705600, T133),
+    (1049619600, T134),
+    (1067155200, T133),
+    (1081069200, T134),
+    (1099209600, T133),
+    (1112518800, T134),
+    (1130659200, T133),
+    (1143968400, T134),
+    (1162108800, T133),
+    (1173603600, T134),
+    (1194163200, T133),
+    (1205053200, T134),
+    (1225612800, T133),
+];
+
+static AMERICA_EIRUNEPE: &[(i64, FixedTimespan<'static>)] = &[
+    (-1767208832, T146),
+    (-1206950400, T147),
+    (-1191355200, T146),
+    (-1175367600, T147),
+    (-1159819200, T146),
+    (-633812400, T147),
+    (-622062000, T146),
+    (-602276400, T147),
+    (-591825600, T146),
+    (-570740400, T147),
+    (-560203200, T146),
+    (-539118000, T147),
+    (-531345600, T146),
+    (-191358000, T147),
+    (-184190400, T146),
+    (-155156400, T147),
+    (-150062400, T146),
+    (-128890800, T147),
+    (-121118400, T146),
+    (-99946800, T147),
+    (-89582400, T146),
+    (-68410800, T147),
+    (-57960000, T146),
+    (499755600, T147),
+    (511243200, T146),
+    (530600400, T147),
+    (540273600, T146),
+    (562136400, T147),
+    (571204800, T146),
+    (750834000, T147),
+    (761716800, T146),
+    (1214283600, T112),
+    (1384056000, T146),
+];
+
+static AMERICA_EL_SALVADOR: &[(i64, FixedTimespan<'static>)] = &[
+    (-1546279392, T129),
+    (547020000, T130),
+    (559717200, T129),
+    (578469600, T130),
+    (591166800, T129),
+];
+
+static AMERICA_FORT_NELSON: &[(i64, FixedTimespan<'static>)] = &[
+    (-2713880953, T149),
+    (-1632060000, T150),
+    (-1615129200, T149),
+    (-880207200, T177),
+    (-765385200, T149),
+    (-715788000, T150),
+    (-702486000, T149),
+    (-684338400, T150),
+    (-671036400, T149),
+    (-652888800, T150),
+    (-639586800, T149),
+    (-620834400, T150),
+    (-608137200, T149),
+    (-589384800, T150),
+    (-576082800, T149),
+    (-557935200, T150),
+    (-544633200, T149),
+    (-526485600, T150),
+    (-513183600, T149),
+    (-495036000, T150),
+    (-481734000, T149),
+    (-463586400, T150),
+    (-450284400, T149),
+    (-431532000, T150),
+    (-418230000, T149),
+    (-400082400, T150),
+    (-386780400, T149),
+    (-368632800, T150),
+    (-355330800, T149),
+    (-337183200, T150),
+    (-323881200, T149),
+    (-305733600, T150),
+    (-292431600, T149),
+    (-273679200, T150),
+    (-260982000, T149),
+    (-242229600, T150),
+    (-226508400, T149),
+    (-210780000, T150),
+    (-195058800, T149),
+    (-179330400, T150),
+    (-163609200, T149),
+    (-147880800, T150),
+    (-131554800, T149),
+    (-116431200, T150),
+    (-100105200, T149),
+    (-84376800, T150),
+    (-68655600, T149),
+    (-52927200, T150),
+    (-37206000, T149),
+    (-21477600, T150),
+    (-5756400, T149),
+    (9972000, T150),
+    (25693200, T149),
+    (41421600, T150),
+    (57747600, T149),
+    (73476000, T150),
+    (89197200, T149),
+    (104925600, T150),
+    (120646800, T149),
+    (136375200, T150),
+    (152096400, T149),
+    (167824800, T150),
+    (183546000, T149),
+    (199274400, T150),
+    (215600400, T149),
+    (230724000, T150),
+    (247050000, T149),
+    (262778400, T150),
+    (278499600, T149),
+    (294228000, T150),
+    (309949200, T149),
+    (325677600, T150),
+    (341398800, T149),
+    (357127200, T150),
+    (372848400, T149),
+    (388576800, T150),
+    (404902800, T149),
+    (420026400, T150),
+    (436352400, T149),
+    (452080800, T150),
+    (467802000, T149),
+    (483530400, T150),
+    (499251600, T149),
+    (514980000, T150),
+    (530701200, T149),
+    (544615200, T150),
+    (562150800, T149),
+    (576064800, T150),
+    (594205200, T149),
+    (607514400, T150),
+    (625654800, T149),
+    (638964000, T150),
+    (657104400, T149),
+    (671018400, T150),
+    (688554000, T149),
+    (702468000, T150),
+    (720003600, T149),
+    (733917600, T150),
+    (752058000, T149),
+    (765367200, T150),
+    (783507600, T149),
+    (796816800, T150),
+    (814957200, T149),
+    (828871200, T150),
+    (846406800, T149),
+    (860320800, T150),
+    (877856400, T149),
+    (891770400, T150),
+    (909306000, T149),
+    (923220000, T150),
+    (941360400, T149),
+    (954669600, T150),
+    (972810000, T149),
+    (986119200, T150),
+    (1004259600, T149),
+    (1018173600, T150),
+    (1035709200, T149),
+    (1049623200, T150),
+    (1067158800, T149),
+    (1081072800, T150),
+    (1099213200, T149),
+    (1112522400, T150),
+    (1130662800, T149),
+    (1143972000, T150),
+    (1162112400, T149),
+    (1173607200, T150),
+    (1194166800, T149),
+    (1205056800, T150),
+    (1225616400, T149),
+    (1236506400, T150),
+    (1257066000, T149),
+    (1268560800, T150),
+    (1289120400, T149),
+    (1300010400, T150),
+    (1320570000, T149),
+    (1331460000, T150),
+    (1352019600, T149),
+    (1362909600, T150),
+    (1383469200, T149),
+    (1394359200, T150),
+    (1414918800, T149),
+    (1425808800, T133),
+];
+
+static AMERICA_FORTALEZA: &[(i64, FixedTimespan<'static>)] = &[
+    (-1767216360, T108),
+    (-1206957600, T109),
+    (-1191362400, T108),
+    (-1175374800, T109),
+    (-1159826400, T108),
+    (-633819600, T109),
+    (-622069200, T108),
+    (-602283600, T109),
+    (-591832800, T108),
+    (-570747600, T109),
+    (-560210400, T108),
+    (-539125200, T109),
+    (-531352800, T108),
+    (-191365200, T109),
+    (-184197600, T108),
+    (-155163600, T109),
+    (-150069600, T108),
+    (-128898000, T109),
+    (-121125600, T108),
+    (-99954000, T109),
+    (-89589600, T108),
+    (-68418000, T109),
+    (-57967200, T108),
+    (499748400, T109),
+    (511236000, T108),
+    (530593200, T109),
+    (540266400, T108),
+    (562129200, T109),
+    (571197600, T108),
+    (592974000, T109),
+    (602042400, T108),
+    (624423600, T109),
+    (634701600, T108),
+    (938919600, T109),
+    (951616800, T108),
+    (970974000, T109),
+    (972180000, T108),
+    (1003028400, T109),
+    (1013911200, T108),
+];
+
+static AMERICA_GLACE_BAY: &[(i64, FixedTimespan<'static>)] = &[
+    (-2131646412, T104),
+    (-1632074400, T136),
+    (-1615143600, T104),
+    (-880221600, T143),
+    (-765399600, T104),
+    (-526500000, T136),
+    (-513198000, T104),
+    (73461600, T136),
+    (89182800, T104),
+    (104911200, T136),
+    (120632400, T104),
+    (136360800, T136),
+    (152082000, T104),
+    (167810400, T136),
+    (183531600, T104),
+    (199260000, T136),
+    (215586000, T104),
+    (230709600, T136),
+    (247035600, T104),
+    (262764000, T136),
+    (278485200, T104),
+    (294213600, T136),
+    (309934800, T104),
+    (325663200, T136),
+    (341384400, T104),
+    (357112800, T136),
+    (372834000, T104),
+    (388562400, T136),
+    (404888400, T104),
+    (420012000, T136),
+    (436338000, T104),
+    (452066400, T136),
+    (467787600, T104),
+    (483516000, T136),
+    (499237200, T104),
+    (514965600, T136),
+    (530686800, T104),
+    (544600800, T136),
+    (562136400, T104),
+    (576050400, T136),
+    (594190800, T104),
+    (607500000, T136),
+    (625640400, T104),
+    (638949600, T136),
+    (657090000, T104),
+    (671004000, T136),
+    (688539600, T104),
+    (702453600, T136),
+    (719989200, T104),
+    (733903200, T136),
+    (752043600, T104),
+    (765352800, T136),
+    (783493200, T104),
+    (796802400, T136),
+    (814942800, T104),
+    (828856800, T136),
+    (846392400, T104),
+    (860306400, T136),
+    (877842000, T104),
+    (891756000, T136),
+    (909291600, T104),
+    (923205600, T136),
+    (941346000, T104),
+    (954655200, T136),
+    (972795600, T104),
+    (986104800, T136),
+    (1004245200, T104),
+    (1018159200, T136),
+    (1035694800, T104),
+    (1049608800, T136),
+    (1067144400, T104),
+    (1081058400, T136),
+    (1099198800, T104),
+    (1112508000, T136),
+    (1130648400, T104),
+    (1143957600, T136),
+    (1162098000, T104),
+    (1173592800, T136),
+    (1194152400, T104),
+    (1205042400, T136),
+    (1225602000, T104),
+];
+
+static AMERICA_GOOSE_BAY: &[(i64, FixedTimespan<'static>)] = &[
+    (-2713895900, T189),
+    (-1632076148, T190),
+    (-1615145348, T189),
+    (-1096921748, T191),
+    (-1061670600, T192),
+    (-1048973400, T191),
+    (-1030221000, T192),
+    (-1017523800, T191),
+    (-998771400, T192),
+    (-986074200, T191),
+    (-966717000, T192),
+    (-954624600, T191),
+    (-935267400, T192),
+    (-922570200, T191),
+    (-903817800, T192),
+    (-891120600, T191),
+    (-872368200, T193),
+    (-765401400, T191),
+    (-746044200, T192),
+    (-733347000, T191),
+    (-714594600, T192),
+    (-701897400, T191),
+    (-683145000, T192),
+    (-670447800, T191),
+    (-651695400, T192),
+    (-638998200, T191),
+    (-619641000, T192),
+    (-606943800, T191),
+    (-589401000, T192),
+    (-576099000, T191),
+    (-557951400, T192),
+    (-544649400, T191),
+    (-526501800, T192),
+    (-513199800, T191),
+    (-495052200, T192),
+    (-481750200, T191),
+    (-463602600, T192),
+    (-450300600, T191),
+    (-431548200, T192),
+    (-418246200, T191),
+    (-400098600, T192),
+    (-386796600, T191),
+    (-368649000, T192),
+    (-355347000, T191),
+    (-337199400, T192),
+    (-323897400, T191),
+    (-305749800, T192),
+    (-289423800, T191),
+    (-273695400, T192),
+    (-257974200, T191),
+    (-242245800, T192),
+    (-226524600, T191),
+    (-210796200, T192),
+    (-195075000, T191),
+    (-179346600, T192),
+    (-163625400, T191),
+    (-147897000, T192),
+    (-131571000, T191),
+    (-119903400, T104),
+    (-116445600, T136),
+    (-100119600, T104),
+    (-84391200, T136),
+    (-68670000, T104),
+    (-52941600, T136),
+    (-37220400, T104),
+    (-21492000, T136),
+    (-5770800, T104),
+    (9957600, T136),
+    (25678800, T104),
+    (41407200, T136),
+    (57733200, T104),
+    (73461600, T136),
+    (89182800, T104),
+    (104911200, T136),
+    (120632400, T104),
+    (136360800, T136),
+    (152082000, T104),
+    (167810400, T136),
+    (183531600, T104),
+    (199260000, T136),
+    (215586000, T104),
+    (230709600, T136),
+    (247035600, T104),
+    (262764000, T136),
+    (278485200, T104),
+    (294213600, T136),
+    (309934800, T104),
+    (325663200, T136),
+    (341384400, T104),
+    (357112800, T136),
+    (372834000, T104),
+    (388562400, T136),
+    (404888400, T104),
+    (420012000, T136),
+    (436338000, T104),
+    (452066400, T136),
+    (467787600, T104),
+    (483516000, T136),
+    (499237200, T104),
+    (514965600, T136),
+    (530686800, T104),
+    (544593660, T136),
+    (562129260, T104),
+    (576043260, T194),
+    (594180060, T104),
+    (607492860, T136),
+    (625633260, T104),
+    (638942460, T136),
+    (657082860, T104),
+    (670996860, T136),
+    (688532460, T104),
+    (702446460, T136),
+    (719982060, T104),
+    (733896060, T136),
+    (752036460, T104),
+    (765345660, T136),
+    (783486060, T104),
+    (796795260, T136),
+    (814935660, T104),
+    (828849660, T136),
+    (846385260, T104),
+    (860299260, T136),
+    (877834860, T104),
+    (891748860, T136),
+    (909284460, T104),
+    (923198460, T136),
+    (941338860, T104),
+    (954648060, T136),
+    (972788460, T104),
+    (986097660, T136),
+    (1004238060, T104),
+    (1018152060, T136),
+    (1035687660, T104),
+    (1049601660, T136),
+    (1067137260, T104),
+    (1081051260, T136),
+    (1099191660, T104),
+    (1112500860, T136),
+    (1130641260, T104),
+    (1143950460, T136),
+    (1162090860, T104),
+    (1173585660, T136),
+    (1194145260, T104),
+    (1205035260, T136),
+    (1225594860, T104),
+    (1236484860, T136),
+    (1257044460, T104),
+    (1268539260, T136),
+    (1289098860, T104),
+    (1299988860, T136),
+    (1320555600, T104),
+    (1331445600, T136),
+    (1352005200, T104),
+];
+
+static AMERICA_GRAND_TURK: &[(i64, FixedTimespan<'static>)] = &[
+    (-2524504528, T160),
+    (-1827687170, T106),
+    (294217200, T155),
+    (309938400, T106),
+    (325666800, T155),
+    (341388000, T106),
+    (357116400, T155),
+    (372837600, T106),
+    (388566000, T155),
+    (404892000, T106),
+    (420015600, T155),
+    (436341600, T106),
+    (452070000, T155),
+    (467791200, T106),
+    (483519600, T155),
+    (499240800, T106),
+    (514969200, T155),
+    (530690400, T106),
+    (544604400, T155),
+    (562140000, T106),
+    (576054000, T155),
+    (594194400, T106),
+    (607503600, T155),
+    (625644000, T106),
+    (638953200, T155),
+    (657093600, T106),
+    (671007600, T155),
+    (688543200, T106),
+    (702457200, T155),
+    (719992800, T106),
+    (733906800, T155),
+    (752047200, T106),
+    (765356400, T155),
+    (783496800, T106),
+    (796806000, T155),
+    (814946400, T106),
+    (828860400, T155),
+    (846396000, T106),
+    (860310000, T155),
+    (877845600, T106),
+    (891759600, T155),
+    (909295200, T106),
+    (923209200, T155),
+    (941349600, T106),
+    (954658800, T155),
+    (972799200, T106),
+    (986108400, T155),
+    (1004248800, T106),
+    (1018162800, T155),
+    (1035698400, T106),
+    (1049612400, T155),
+    (1067148000, T106),
+    (1081062000, T155),
+    (1099202400, T106),
+    (1112511600, T155),
+    (1130652000, T106),
+    (1143961200, T155),
+    (1162101600, T106),
+    (1173596400, T155),
+    (1194156000, T106),
+    (1205046000, T155),
+    (1225605600, T106),
+    (1236495600, T155),
+    (1257055200, T106),
+    (1268550000, T155),
+    (1289109600, T106),
+    (1299999600, T155),
+    (1320559200, T106),
+    (1331449200, T155),
+    (1352008800, T106),
+    (1362898800, T155),
+    (1383458400, T106),
+    (1394348400, T155),
+    (1414908000, T106),
+    (1425798000, T104),
+    (1541311200, T106),
+    (1552201200, T155),
+    (1572760800, T106),
+];
+
+static AMERICA_GRENADA: &[(i64, FixedTimespan<'static>)] = &[
+    (-1846266780, T104),
+];
+
+static AMERICA_GUADELOUPE: &[(i64, FixedTimespan<'static>)] = &[
+    (-1848254032, T104),
+];
+
+static AMERICA_GUATEMALA: &[(i64, FixedTimespan<'static>)] = &[
+    (-1617040676, T129),
+    (123055200, T130),
+    (130914000, T129),
+    (422344800, T130),
+    (433054800, T129),
+    (669708000, T130),
+    (684219600, T129),
+    (1146376800, T130),
+    (1159678800, T129),
+];
+
+static AMERICA_GUAYAQUIL: &[(i64, FixedTimespan<'static>)] = &[
+    (-2524502440, T200),
+    (-1230749160, T146),
+    (722926800, T147),
+    (728884800, T146),
+];
+
+static AMERICA_GUYANA: &[(i64, FixedTimespan<'static>)] = &[
+    (-1843589241, T112),
+    (-1730577600, T202),
+    (176096700, T108),
+    (701841600, T112),
+];
+
+static AMERICA_HALIFAX: &[(i64, FixedTimespan<'static>)] = &[
+    (-2131645536, T104),
+    (-1696276800, T136),
+    (-1680469200, T104),
+    (-1632074400, T136),
+    (-1615143600, T104),
+    (-1566763200, T136),
+    (-1557090000, T104),
+    (-1535486400, T136),
+    (-1524949200, T104),
+    (-1504468800, T136),
+    (-1493413200, T104),
+    (-1472414400, T136),
+    (-1461963600, T104),
+    (-1440964800, T136),
+    (-1429390800, T104),
+    (-1409515200, T136),
+    (-1396731600, T104),
+    (-1376856000, T136),
+    (-1366491600, T104),
+    (-1346616000, T136),
+    (-1333832400, T104),
+    (-1313956800, T136),
+    (-1303678800, T104),
+    (-1282507200, T136),
+    (-1272661200, T104),
+    (-1251057600, T136),
+    (-1240088400, T104),
+    (-1219608000, T136),
+    (-1207429200, T104),
+    (-1188763200, T136),
+    (-1175979600, T104),
+    (-1157313600, T136),
+    (-1143925200, T104),
+    (-1124049600, T136),
+    (-1113771600, T104),
+    (-1091390400, T136),
+    (-1081026000, T104),
+    (-1059854400, T136),
+    (-1050786000, T104),
+    (-1030910400, T136),
+    (-1018126800, T104),
+    (-999460800, T136),
+    (-986677200, T104),
+    (-965592000, T136),
+    (-955227600, T104),
+    (-935956800, T136),
+    (-923173200, T104),
+    (-904507200, T136),
+    (-891723600, T104),
+    (-880221600, T143),
+    (-765399600, T104),
+    (-747252000, T136),
+    (-733950000, T104),
+    (-715802400, T136),
+    (-702500400, T104),
+    (-684352800, T136),
+    (-671050800, T104),
+    (-652903200, T136),
+    (-639601200, T104),
+    (-589399200, T136),
+    (-576097200, T104),
+    (-557949600, T136),
+    (-544647600, T104),
+    (-526500000, T136),
+    (-513198000, T104),
+    (-495050400, T136),
+    (-481748400, T104),
+    (-431546400, T136),
+    (-418244400, T104),
+    (-400096800, T136),
+    (-386794800, T104),
+    (-368647200, T136),
+    (-355345200, T104),
+    (-337197600, T136),
+    (-323895600, T104),
+    (-242244000, T136),
+    (-226522800, T104),
+    (-210794400, T136),
+    (-195073200, T104),
+    (-179344800, T136),
+    (-163623600, T104),
+    (-147895200, T136),
+    (-131569200, T104),
+    (-116445600, T136),
+    (-100119600, T104),
+    (-84391200, T136),
+    (-68670000, T104),
+    (-52941600, T136),
+    (-37220400, T104),
+    (-21492000, T136),
+    (-5770800, T104),
+    (9957600, T136),
+    (25678800, T104),
+    (41407200, T136),
+    (57733200, T104),
+    (73461600, T136),
+    (89182800, T104),
+    (104911200, T136),
+    (120632400, T104),
+    (136360800, T136),
+    (152082000, T104),
+    (167810400, T136),
+    (183531600, T104),
+    (199260000, T136),
+    (215586000, T104),
+    (230709600, T136),
+    (247035600, T104),
+    (262764000, T136),
+    (278485200, T104),
+    (294213600, T136),
+    (309934800, T104),
+    (325663200, T136),
+    (341384400, T104),
+    (357112800, T136),
+    (372834000, T104),
+    (388562400, T136),
+    (404888400, T104),
+    (420012000, T136),
+    (436338000, T104),
+    (452066400, T136),
+    (467787600, T104),
+    (483516000, T136),
+    (499237200, T104),
+    (514965600, T136),
+    (530686800, T104),
+    (544600800, T136),
+    (562136400, T104),
+    (576050400, T136),
+    (594190800, T104),
+    (607500000, T136),
+    (625640400, T104),
+    (638949600, T136),
+    (657090000, T104),
+    (671004000, T136),
+    (688539600, T104),
+    (702453600, T136),
+    (719989200, T104),
+    (733903200, T136),
+    (752043600, T104),
+    (765352800, T136),
+    (783493200, T104),
+    (796802400, T136),
+    (814942800, T104),
+    (828856800, T136),
+    (846392400, T104),
+    (860306400, T136),
+    (877842000, T104),
+    (891756000, T136),
+    (909291600, T104),
+    (923205600, T136),
+    (941346000, T104),
+    (954655200, T136),
+    (972795600, T104),
+    (986104800, T136),
+    (1004245200, T104),
+    (1018159200, T136),
+    (1035694800, T104),
+    (1049608800, T136),
+    (1067144400, T104),
+    (1081058400, T136),
+    (1099198800, T104),
+    (1112508000, T136),
+    (1130648400, T104),
+    (1143957600, T136),
+    (1162098000, T104),
+    (1173592800, T136),
+    (1194152400, T104),
+    (1205042400, T136),
+    (1225602000, T104),
+];
+
+static AMERICA_HAVANA: &[(i64, FixedTimespan<'static>)] = &[
+    (-2524501832, T205),
+    (-1402813824, T206),
+    (-1311534000, T207),
+    (-1300996800, T206),
+    (-933534000, T207),
+    (-925675200, T206),
+    (-902084400, T207),
+    (-893620800, T206),
+    (-870030000, T207),
+    (-862171200, T206),
+    (-775681200, T207),
+    (-767822400, T206),
+    (-744231600, T207),
+    (-736372800, T206),
+    (-144702000, T207),
+    (-134251200, T206),
+    (-113425200, T207),
+    (-102542400, T206),
+    (-86295600, T207),
+    (-72907200, T206),
+    (-54154800, T207),
+    (-41457600, T206),
+    (-21495600, T207),
+    (-5774400, T206),
+    (9954000, T207),
+    (25675200, T206),
+    (41403600, T207),
+    (57729600, T206),
+    (73458000, T207),
+    (87364800, T206),
+    (104907600, T207),
+    (118900800, T206),
+    (136357200, T207),
+    (150436800, T206),
+    (167806800, T207),
+    (183528000, T206),
+    (199256400, T207),
+    (215582400, T206),
+    (230706000, T207),
+    (247032000, T206),
+    (263365200, T207),
+    (276667200, T206),
+    (290581200, T207),
+    (308721600, T206),
+    (322030800, T207),
+    (340171200, T206),
+    (358318800, T207),
+    (371620800, T206),
+    (389768400, T207),
+    (403070400, T206),
+    (421218000, T207),
+    (434520000, T206),
+    (452667600, T207),
+    (466574400, T206),
+    (484117200, T207),
+    (498024000, T206),
+    (511333200, T207),
+    (529473600, T206),
+    (542782800, T207),
+    (560923200, T206),
+    (574837200, T207),
+    (592372800, T206),
+    (606286800, T207),
+    (623822400, T206),
+    (638946000, T207),
+    (655876800, T206),
+    (671000400, T207),
+    (687330000, T206),
+    (702450000, T207),
+    (718779600, T206),
+    (733899600, T207),
+    (750229200, T206),
+    (765349200, T207),
+    (781678800, T206),
+    (796798800, T207),
+    (813128400, T206),
+    (828853200, T207),
+    (844578000, T206),
+    (860302800, T207),
+    (876632400, T206),
+    (891147600, T207),
+    (909291600, T206),
+    (922597200, T207),
+    (941346000, T206),
+    (954651600, T207),
+    (972795600, T206),
+    (986101200, T207),
+    (1004245200, T206),
+    (1018155600, T207),
+    (1035694800, T206),
+    (1049605200, T207),
+    (1067144400, T206),
+    (1080450000, T207),
+    (1162098000, T206),
+    (1173589200, T207),
+    (1193547600, T206),
+    (1205643600, T207),
+    (1224997200, T206),
+    (1236488400, T207),
+    (1256446800, T206),
+    (1268542800, T207),
+    (1288501200, T206),
+    (1300597200, T207),
+    (1321160400, T206),
+    (1333256400, T207),
+    (1352005200, T206),
+    (1362891600, T207),
+    (1383454800, T206),
+    (1394341200, T207),
+    (1414904400, T206),
+];
+
+static AMERICA_HERMOSILLO: &[(i64, FixedTimespan<'static>)] = &[
+    (-1514739600, T133),
+    (-1343149200, T129),
+    (-1234807200, T133),
+    (-1220461200, T134),
+    (-1207159200, T133),
+    (-1191344400, T129),
+    (-873828000, T133),
+    (828867600, T134),
+    (846403200, T133),
+    (860317200, T134),
+    (877852800, T133),
+    (891766800, T134),
+    (909302400, T133),
+];
+
+static AMERICA_INDIANA_INDIANAPOLIS: &[(i64, FixedTimespan<'static>)] = &[
+    (-2717647200, T129),
+    (-1633276800, T130),
+    (-1615136400, T129),
+    (-1601827200, T130),
+    (-1583686800, T129),
+    (-900259200, T130),
+    (-891795600, T129),
+    (-880214400, T141),
+    (-765392400, T129),
+    (-747244800, T130),
+    (-733942800, T129),
+    (-715795200, T130),
+    (-702493200, T129),
+    (-684345600, T130),
+    (-671043600, T129),
+    (-652896000, T130),
+    (-639594000, T129),
+    (-620841600, T130),
+    (-608144400, T129),
+    (-589392000, T130),
+    (-576090000, T129),
+    (-557942400, T130),
+    (-544640400, T129),
+    (-526492800, T130),
+    (-513190800, T129),
+    (-495043200, T130),
+    (-481741200, T129),
+    (-463593600, T106),
+    (-386787600, T129),
+    (-368640000, T106),
+    (-21488400, T155),
+    (-5767200, T106),
+    (9961200, T155),
+    (25682400, T106),
+    (1143961200, T155),
+    (1162101600, T106),
+    (1173596400, T155),
+    (1194156000, T106),
+    (1205046000, T155),
+    (1225605600, T106),
+];
+
+static AMERICA_INDIANA_KNOX: &[(i64, FixedTimespan<'static>)] = &[
+    (-2717647200, T129),
+    (-1633276800, T130),
+    (-1615136400, T129),
+    (-1601827200, T130),
+    (-1583686800, T129),
+    (-880214400, T141),
+    (-765392400, T129),
+    (-715795200, T130),
+    (-702493200, T129),
+    (-684345600, T130),
+    (-671043600, T129),
+    (-652896000, T130),
+    (-639594000, T129),
+    (-620841600, T130),
+    (-608144400, T129),
+    (-589392000, T130),
+    (-576090000, T129),
+    (-557942400, T130),
+    (-544640400, T129),
+    (-526492800, T130),
+    (-513190800, T129),
+    (-495043200, T130),
+    (-481741200, T129),
+    (-463593600, T130),
+    (-447267600, T129),
+    (-431539200, T130),
+    (-415818000, T129),
+    (-400089600, T130),
+    (-386787600, T129),
+    (-368640000, T130),
+    (-355338000, T129),
+    (-337190400, T130),
+    (-321469200, T129),
+    (-305740800, T130),
+    (-289414800, T129),
+    (-273686400, T130),
+    (-257965200, T129),
+    (-242236800, T106),
+    (-195066000, T129),
+    (-84384000, T130),
+    (-68662800, T129),
+    (-52934400, T130),
+    (-37213200, T129),
+    (-21484800, T130),
+    (-5763600, T129),
+    (9964800, T130),
+    (25686000, T129),
+    (41414400, T130),
+    (57740400, T129),
+    (73468800, T130),
+    (89190000, T129),
+    (104918400, T130),
+    (120639600, T129),
+    (126691200, T130),
+    (152089200, T129),
+    (162374400, T130),
+    (183538800, T129),
+    (199267200, T130),
+    (215593200, T129),
+    (230716800, T130),
+    (247042800, T129),
+    (262771200, T130),
+    (278492400, T129),
+    (294220800, T130),
+    (309942000, T129),
+    (325670400, T130),
+    (341391600, T129),
+    (357120000, T130),
+    (372841200, T129),
+    (388569600, T130),
+    (404895600, T129),
+    (420019200, T130),
+    (436345200, T129),
+    (452073600, T130),
+    (467794800, T129),
+    (483523200, T130),
+    (499244400, T129),
+    (514972800, T130),
+    (530694000, T129),
+    (544608000, T130),
+    (562143600, T129),
+    (576057600, T130),
+    (594198000, T129),
+    (607507200, T130),
+    (625647600, T129),
+    (638956800, T130),
+    (657097200, T129),
+    (671011200, T130),
+    (1143961200, T129),
+    (1143964800, T130),
+    (1162105200, T129),
+    (1173600000, T130),
+    (1194159600, T129),
+    (1205049600, T130),
+    (1225609200, T129),
+];
+
+static AMERICA_INDIANA_MARENGO: &[(i64, FixedTimespan<'static>)] = &[
+    (-2717647200, T129),
+    (-1633276800, T130),
+    (-1615136400, T129),
+    (-1601827200, T130),
+    (-1583686800, T129),
+    (-880214400, T141),
+    (-765392400, T129),
+    (-589392000, T130),
+    (-576090000, T129),
+    (-495043200, T130),
+    (-481741200, T129),
+    (-463593600, T130),
+    (-450291600, T129),
+    (-431539200, T130),
+    (-418237200, T129),
+    (-400089600, T130),
+    (-386787600, T129),
+    (-368640000, T130),
+    (-355338000, T129),
+    (-337190400, T130),
+    (-323888400, T129),
+    (-305740800, T130),
+    (-292438800, T129),
+    (-273686400, T106),
+    (-21488400, T155),
+    (-5767200, T106),
+    (9961200, T155),
+    (25682400, T106),
+    (41410800, T155),
+    (57736800, T106),
+    (73465200, T155),
+    (89186400, T106),
+    (104914800, T155),
+    (120636000, T106),
+    (162370800, T155),
+    (183535200, T106),
+    (1143961200, T155),
+    (1162101600, T106),
+    (1173596400, T155),
+    (1194156000, T106),
+    (1205046000, T155),
+    (1225605600, T106),
+];
+
+static AMERICA_INDIANA_PETERSBURG: &[(i64, FixedTimespan<'static>)] = &[
+    (-2717647200, T129),
+    (-1633276800, T130),
+    (-1615136400, T129),
+    (-1601827200, T130),
+    (-1583686800, T129),
+    (-880214400, T141),
+    (-765392400, T129),
+    (-462996000, T130),
+    (-450291600, T129),
+    (-431539200, T130),
+    (-418237200, T129),
+    (-400089600, T130),
+    (-386787600, T129),
+    (-368640000, T130),
+    (-355338000, T129),
+    (-337190400, T130),
+    (-323888400, T129),
+    (-305740800, T130),
+    (-292438800, T129),
+    (-273686400, T130),
+    (-257965200, T129),
+    (-242236800, T130),
+    (-226515600, T129),
+    (-210787200, T130),
+    (-195066000, T129),
+    (-179337600, T130),
+    (-163616400, T129),
+    (-147888000, T106),
+    (-100112400, T129),
+    (-84384000, T130),
+    (-68662800, T129),
+    (-52934400, T130),
+    (-37213200, T129),
+    (-21484800, T130),
+    (-5763600, T129),
+    (9964800, T130),
+    (25686000, T129),
+    (41414400, T130),
+    (57740400, T129),
+    (73468800, T130),
+    (89190000, T129),
+    (104918400, T130),
+    (120639600, T129),
+    (126691200, T130),
+    (152089200, T129),
+    (162374400, T130),
+    (183538800, T129),
+    (199267200, T130),
+    (215593200, T129),
+    (230716800, T130),
+    (1143961200, T129),
+    (1143964800, T130),
+    (1162105200, T129),
+    (1173600000, T130),
+    (1205046000, T155),
+    (1225605600, T106),
+];
+
+static AMERICA_INDIANA_TELL_CITY: &[(i64, FixedTimespan<'static>)] = &[
+    (-2717647200, T129),
+    (-1633276800, T130),
+    (-1615136400, T129),
+    (-1601827200, T130),
+    (-1583686800, T129),
+    (-880214400, T141),
+    (-765392400, T129),
+    (-462996000, T130),
+    (-450291600, T129),
+    (-431539200, T130),
+    (-418237200, T129),
+    (-400089600, T130),
+    (-386787600, T129),
+    (-368640000, T130),
+    (-355338000, T129),
+    (-337190400, T130),
+    (-323888400, T129),
+    (-305740800, T130),
+    (-292438800, T129),
+    (-273686400, T130),
+    (-257965200, T129),
+    (-242236800, T130),
+    (-226515600, T129),
+    (-210787200, T130),
+    (-195066000, T129),
+    (-179337600, T106),
+    (-68662800, T129),
+    (-52934400, T130),
+    (-37213200, T129),
+    (-21484800, T155),
+    (-5767200, T106),
+    (9961200, T155),
+    (25682400, T106),
+    (1143961200, T129),
+    (1143964800, T130),
+    (1162105200, T129),
+    (1173600000, T130),
+    (1194159600, T129),
+    (1205049600, T130),
+    (1225609200, T129),
+];
+
+static AMERICA_INDIANA_VEVAY: &[(i64, FixedTimespan<'static>)] = &[
+    (-2717647200, T129),
+    (-1633276800, T130),
+    (-1615136400, T129),
+    (-1601827200, T130),
+    (-1583686800, T129),
+    (-880214400, T141),
+    (-765392400, T129),
+    (-495043200, T106),
+    (-21488400, T155),
+    (-5767200, T106),
+    (9961200, T155),
+    (25682400, T106),
+    (41410800, T155),
+    (57736800, T106),
+    (73465200, T155),
+    (89186400, T106),
+    (1143961200, T155),
+    (1162101600, T106),
+    (1173596400, T155),
+    (1194156000, T106),
+    (1205046000, T155),
+    (1225605600, T106),
+];
+
+static AMERICA_INDIANA_VINCENNES: &[(i64, FixedTimespan<'static>)] = &[
+    (-2717647200, T129),
+    (-1633276800, T130),
+    (-1615136400, T129),
+    (-1601827200, T130),
+    (-1583686800, T129),
+    (-880214400, T141),
+    (-765392400, T129),
+    (-747244800, T130),
+    (-733942800, T129),
+    (-526492800, T130),
+    (-513190800, T129),
+    (-495043200, T130),
+    (-481741200, T129),
+    (-462996000, T130),
+    (-450291600, T129),
+    (-431539200, T130),
+    (-418237200, T129),
+    (-400089600, T130),
+    (-386787600, T129),
+    (-368640000, T130),
+    (-355338000, T129),
+    (-337190400, T130),
+    (-323888400, T129),
+    (-305740800, T130),
+    (-289414800, T129),
+    (-273686400, T130),
+    (-260989200, T129),
+    (-242236800, T130),
+    (-226515600, T129),
+    (-210787200, T130),
+    (-195066000, T129),
+    (-179337600, T106),
+    (-21488400, T155),
+    (-5767200, T106),
+    (9961200, T155),
+    (25682400, T106),
+    (1143961200, T129),
+    (1143964800, T130),
+    (1162105200, T129),
+    (1173600000, T130),
+    (1205046000, T155),
+    (1225605600, T106),
+];
+
+static AMERICA_INDIANA_WINAMAC: &[(i64, FixedTimespan<'static>)] = &[
+    (-2717647200, T129),
+    (-1633276800, T130),
+    (-1615136400, T129),
+    (-1601827200, T130),
+    (-1583686800, T129),
+    (-880214400, T141),
+    (-765392400, T129),
+    (-747244800, T130),
+    (-733942800, T129),
+    (-715795200, T130),
+    (-702493200, T129),
+    (-684345600, T130),
+    (-671043600, T129),
+    (-652896000, T130),
+    (-639594000, T129),
+    (-620841600, T130),
+    (-608144400, T129),
+    (-589392000, T130),
+    (-576090000, T129),
+    (-557942400, T130),
+    (-544640400, T129),
+    (-526492800, T130),
+    (-513190800, T129),
+    (-495043200, T130),
+    (-481741200, T129),
+    (-463593600, T130),
+    (-447267600, T129),
+    (-431539200, T130),
+    (-415818000, T129),
+    (-400089600, T130),
+    (-386787600, T129),
+    (-368640000, T130),
+    (-355338000, T129),
+    (-337190400, T130),
+    (-323888400, T129),
+    (-305740800, T130),
+    (-292438800, T129),
+    (-273686400, T106),
+    (-21488400, T155),
+    (-5767200, T106),
+    (9961200, T155),
+    (25682400, T106),
+    (1143961200, T129),
+    (1143964800, T130),
+    (1162105200, T129),
+    (1173600000, T155),
+    (1194156000, T106),
+    (1205046000, T155),
+    (1225605600, T106),
+];
+
+static AMERICA_INUVIK: &[(i64, FixedTimespan<'static>)] = &[
+    (-536457600, T149),
+    (73476000, T150),
+    (89197200, T149),
+    (104925600, T150),
+    (120646800, T149),
+    (136375200, T150),
+    (152096400, T149),
+    (167824800, T150),
+    (183546000, T149),
+    (199274400, T150),
+    (215600400, T149),
+    (230724000, T150),
+    (247050000, T149),
+    (262778400, T150),
+    (278499600, T149),
+    (294228000, T134),
+    (309945600, T133),
+    (325674000, T134),
+    (341395200, T133),
+    (357123600, T134),
+    (372844800, T133),
+    (388573200, T134),
+    (404899200, T133),
+    (420022800, T134),
+    (436348800, T133),
+    (452077200, T134),
+    (467798400, T133),
+    (483526800, T134),
+    (499248000, T133),
+    (514976400, T134),
+    (530697600, T133),
+    (544611600, T134),
+    (562147200, T133),
+    (576061200, T134),
+    (594201600, T133),
+    (607510800, T134),
+    (625651200, T133),
+    (638960400, T134),
+    (657100800, T133),
+    (671014800, T134),
+    (688550400, T133),
+    (702464400, T134),
+    (720000000, T133),
+    (733914000, T134),
+    (752054400, T133),
+    (765363600, T134),
+    (783504000, T133),
+    (796813200, T134),
+    (814953600, T133),
+    (828867600, T134),
+    (846403200, T133),
+    (860317200, T134),
+    (877852800, T133),
+    (891766800, T134),
+    (909302400, T133),
+    (923216400, T134),
+    (941356800, T133),
+    (954666000, T134),
+    (972806400, T133),
+    (986115600, T134),
+    (1004256000, T133),
+    (1018170000, T134),
+    (1035705600, T133),
+    (1049619600, T134),
+    (1067155200, T133),
+    (1081069200, T134),
+    (1099209600, T133),
+    (1112518800, T134),
+    (1130659200, T133),
+    (1143968400, T134),
+    (1162108800, T133),
+    (1173603600, T134),
+    (1194163200, T133),
+    (1205053200, T134),
+    (1225612800, T133),
+];
+
+static AMERICA_IQALUIT: &[(i64, FixedTimespan<'static>)] = &[
+    (-865296000, T180),
+    (-765396000, T106),
+    (73465200, T155),
+    (89186400, T106),
+    (104914800, T155),
+    (120636000, T106),
+    (136364400, T155),
+    (152085600, T106),
+    (167814000, T155),
+    (183535200, T106),
+    (199263600, T155),
+    (215589600, T106),
+    (230713200, T155),
+    (247039200, T106),
+    (262767600, T155),
+    (278488800, T106),
+    (294217200, T155),
+    (309938400, T106),
+    (325666800, T155),
+    (341388000, T106),
+    (357116400, T155),
+    (372837600, T106),
+    (388566000, T155),
+    (404892000, T106),
+    (420015600, T155),
+    (436341600, T106),
+    (452070000, T155),
+    (467791200, T106),
+    (483519600, T155),
+    (499240800, T106),
+    (514969200, T155),
+    (530690400, T106),
+    (544604400, T155),
+    (562140000, T106),
+    (576054000, T155),
+    (594194400, T106),
+    (607503600, T155),
+    (625644000, T106),
+    (638953200, T155),
+    (657093600, T106),
+    (671007600, T155),
+    (688543200, T106),
+    (702457200, T155),
+    (719992800, T106),
+    (733906800, T155),
+    (752047200, T106),
+    (765356400, T155),
+    (783496800, T106),
+    (796806000, T155),
+    (814946400, T106),
+    (828860400, T155),
+    (846396000, T106),
+    (860310000, T155),
+    (877845600, T106),
+    (891759600, T155),
+    (909295200, T106),
+    (923209200, T155),
+    (941349600, T130),
+    (941353200, T129),
+    (954662400, T130),
+    (986108400, T155),
+    (1004248800, T106),
+    (1018162800, T155),
+    (1035698400, T106),
+    (1049612400, T155),
+    (1067148000, T106),
+    (1081062000, T155),
+    (1099202400, T106),
+    (1112511600, T155),
+    (1130652000, T106),
+    (1143961200, T155),
+    (1162101600, T106),
+    (1173596400, T155),
+    (1194156000, T106),
+    (1205046000, T155),
+    (1225605600, T106),
+];
+
+static AMERICA_JAMAICA: &[(i64, FixedTimespan<'static>)] = &[
+    (-1827687170, T106),
+    (126687600, T155),
+    (152085600, T106),
+    (162370800, T155),
+    (183535200, T106),
+    (199263600, T155),
+    (215589600, T106),
+    (230713200, T155),
+    (247039200, T106),
+    (262767600, T155),
+    (278488800, T106),
+    (294217200, T155),
+    (309938400, T106),
+    (325666800, T155),
+    (341388000, T106),
+    (357116400, T155),
+    (372837600, T106),
+    (388566000, T155),
+    (404892000, T106),
+    (420015600, T155),
+    (436341600, T106),
+];
+
+static AMERICA_JUNEAU: &[(i64, FixedTimespan<'static>)] = &[
+    (-3225223727, T219),
+    (-2188954939, T149),
+    (-880207200, T177),
+    (-765385200, T149),
+    (-21477600, T150),
+    (-5756400, T149),
+    (9972000, T150),
+    (25693200, T149),
+    (41421600, T150),
+    (57747600, T149),
+    (73476000, T150),
+    (89197200, T149),
+    (104925600, T150),
+    (120646800, T149),
+    (126698400, T150),
+    (152096400, T149),
+    (162381600, T150),
+    (183546000, T149),
+    (199274400, T150),
+    (215600400, T149),
+    (230724000, T150),
+    (247050000, T149),
+    (262778400, T150),
+    (278499600, T149),
+    (294228000, T150),
+    (309949200, T149),
+    (325677600, T172),
+    (325681200, T173),
+    (357127200, T150),
+    (372848400, T149),
+    (388576800, T150),
+    (404902800, T149),
+    (420026400, T150),
+    (436352400, T173),
+    (436356000, T172),
+    (452084400, T101),
+    (467805600, T102),
+    (483534000, T101),
+    (499255200, T102),
+    (514983600, T101),
+    (530704800, T102),
+    (544618800, T101),
+    (562154400, T102),
+    (576068400, T101),
+    (594208800, T102),
+    (607518000, T101),
+    (625658400, T102),
+    (638967600, T101),
+    (657108000, T102),
+    (671022000, T101),
+    (688557600, T102),
+    (702471600, T101),
+    (720007200, T102),
+    (733921200, T101),
+    (752061600, T102),
+    (765370800, T101),
+    (783511200, T102),
+    (796820400, T101),
+    (814960800, T102),
+    (828874800, T101),
+    (846410400, T102),
+    (860324400, T101),
+    (877860000, T102),
+    (891774000, T101),
+    (909309600, T102),
+    (923223600, T101),
+    (941364000, T102),
+    (954673200, T101),
+    (972813600, T102),
+    (986122800, T101),
+    (1004263200, T102),
+    (1018177200, T101),
+    (1035712800, T102),
+    (1049626800, T101),
+    (1067162400, T102),
+    (1081076400, T101),
+    (1099216800, T102),
+    (1112526000, T101),
+    (1130666400, T102),
+    (1143975600, T101),
+    (1162116000, T102),
+    (1173610800, T101),
+    (1194170400, T102),
+    (1205060400, T101),
+    (1225620000, T102),
+];
+
+static AMERICA_KENTUCKY_LOUISVILLE: &[(i64, FixedTimespan<'static>)] = &[
+    (-2717647200, T129),
+    (-1633276800, T130),
+    (-1615136400, T129),
+    (-1601827200, T130),
+    (-1583686800, T129),
+    (-1535904000, T130),
+    (-1525280400, T129),
+    (-905097600, T130),
+    (-891795600, T129),
+    (-880214400, T141),
+    (-765392400, T129),
+    (-747251940, T130),
+    (-744224400, T129),
+    (-620841600, T130),
+    (-608144400, T129),
+    (-589392000, T130),
+    (-576090000, T129),
+    (-557942400, T130),
+    (-544640400, T129),
+    (-526492800, T130),
+    (-513190800, T129),
+    (-495043200, T130),
+    (-481741200, T129),
+    (-463593600, T130),
+    (-450291600, T129),
+    (-431539200, T130),
+    (-415818000, T129),
+    (-400089600, T130),
+    (-384368400, T129),
+    (-368640000, T130),
+    (-352918800, T129),
+    (-337190400, T130),
+    (-321469200, T129),
+    (-305740800, T130),
+    (-289414800, T129),
+    (-273686400, T130),
+    (-52938000, T155),
+    (-37216800, T106),
+    (-21488400, T155),
+    (-5767200, T106),
+    (9961200, T155),
+    (25682400, T106),
+    (41410800, T155),
+    (57736800, T106),
+    (73465200, T155),
+    (89186400, T106),
+    (104914800, T155),
+    (120636000, T106),
+    (162370800, T155),
+    (183535200, T106),
+    (199263600, T155),
+    (215589600, T106),
+    (230713200, T155),
+    (247039200, T106),
+    (262767600, T155),
+    (278488800, T106),
+    (294217200, T155),
+    (309938400, T106),
+    (325666800, T155),
+    (341388000, T106),
+    (357116400, T155),
+    (372837600, T106),
+    (388566000, T155),
+    (404892000, T106),
+    (420015600, T155),
+    (436341600, T106),
+    (452070000, T155),
+    (467791200, T106),
+    (483519600, T155),
+    (499240800, T106),
+    (514969200, T155),
+    (530690400, T106),
+    (544604400, T155),
+    (562140000, T106),
+    (576054000, T155),
+    (594194400, T106),
+    (607503600, T155),
+    (625644000, T106),
+    (638953200, T155),
+    (657093600, T106),
+    (671007600, T155),
+    (688543200, T106),
+    (702457200, T155),
+    (719992800, T106),
+    (733906800, T155),
+    (752047200, T106),
+    (765356400, T155),
+    (783496800, T106),
+    (796806000, T155),
+    (814946400, T106),
+    (828860400, T155),
+    (846396000, T106),
+    (860310000, T155),
+    (877845600, T106),
+    (891759600, T155),
+    (909295200, T106),
+    (923209200, T155),
+    (941349600, T106),
+    (954658800, T155),
+    (972799200, T106),
+    (986108400, T155),
+    (1004248800, T106),
+    (1018162800, T155),
+    (1035698400, T106),
+    (1049612400, T155),
+    (1067148000, T106),
+    (1081062000, T155),
+    (1099202400, T106),
+    (1112511600, T155),
+    (1130652000, T106),
+    (1143961200, T155),
+    (1162101600, T106),
+    (1173596400, T155),
+    (1194156000, T106),
+    (1205046000, T155),
+    (1225605600, T106),
+];
+
+static AMERICA_KENTUCKY_MONTICELLO: &[(i64, FixedTimespan<'static>)] = &[
+    (-2717647200, T129),
+    (-1633276800, T130),
+    (-1615136400, T129),
+    (-1601827200, T130),
+    (-1583686800, T129),
+    (-880214400, T141),
+    (-765392400, T129),
+    (-52934400, T130),
+    (-37213200, T129),
+    (-21484800, T130),
+    (-5763600, T129),
+    (9964800, T130),
+    (25686000, T129),
+    (41414400, T130),
+    (57740400, T129),
+    (73468800, T130),
+    (89190000, T129),
+    (104918400, T130),
+    (120639600, T129),
+    (126691200, T130),
+    (152089200, T129),
+    (162374400, T130),
+    (183538800, T129),
+    (199267200, T130),
+    (215593200, T129),
+    (230716800, T130),
+    (247042800, T129),
+    (262771200, T130),
+    (278492400, T129),
+    (294220800, T130),
+    (309942000, T129),
+    (325670400, T130),
+    (341391600, T129),
+    (357120000, T130),
+    (372841200, T129),
+    (388569600, T130),
+    (404895600, T129),
+    (420019200, T130),
+    (436345200, T129),
+    (452073600, T130),
+    (467794800, T129),
+    (483523200, T130),
+    (499244400, T129),
+    (514972800, T130),
+    (530694000, T129),
+    (544608000, T130),
+    (562143600, T129),
+    (576057600, T130),
+    (594198000, T129),
+    (607507200, T130),
+    (625647600, T129),
+    (638956800, T130),
+    (657097200, T129),
+    (671011200, T130),
+    (688546800, T129),
+    (702460800, T130),
+    (719996400, T129),
+    (733910400, T130),
+    (752050800, T129),
+    (765360000, T130),
+    (783500400, T129),
+    (796809600, T130),
+    (814950000, T129),
+    (828864000, T130),
+    (846399600, T129),
+    (860313600, T130),
+    (877849200, T129),
+    (891763200, T130),
+    (909298800, T129),
+    (923212800, T130),
+    (941353200, T129),
+    (954662400, T130),
+    (986108400, T155),
+    (1004248800, T106),
+    (1018162800, T155),
+    (1035698400, T106),
+    (1049612400, T155),
+    (1067148000, T106),
+    (1081062000, T155),
+    (1099202400, T106),
+    (1112511600, T155),
+    (1130652000, T106),
+    (1143961200, T155),
+    (1162101600, T106),
+    (1173596400, T155),
+    (1194156000, T106),
+    (1205046000, T155),
+    (1225605600, T106),
+];
+
+static AMERICA_LA_PAZ: &[(i64, FixedTimespan<'static>)] = &[
+    (-1205954844, T223),
+    (-1192307244, T112),
+];
+
+static AMERICA_LIMA: &[(i64, FixedTimespan<'static>)] = &[
+    (-2524503108, T225),
+    (-1938538284, T146),
+    (-1009825200, T147),
+    (-1002052800, T146),
+    (-986756400, T147),
+    (-971035200, T146),
+    (-955306800, T147),
+    (-939585600, T146),
+    (504939600, T147),
+    (512712000, T146),
+    (536475600, T147),
+    (544248000, T146),
+    (631170000, T147),
+    (638942400, T146),
+    (757400400, T147),
+    (765172800, T146),
+];
+
+static AMERICA_LOS_ANGELES: &[(i64, FixedTimespan<'static>)] = &[
+    (-2717640000, T149),
+    (-1633269600, T150),
+    (-1615129200, T149),
+    (-1601820000, T150),
+    (-1583679600, T149),
+    (-880207200, T177),
+    (-765385200, T149),
+    (-687967140, T150),
+    (-662655600, T149),
+    (-620838000, T150),
+    (-608137200, T149),
+    (-589388400, T150),
+    (-576082800, T149),
+    (-557938800, T150),
+    (-544633200, T149),
+    (-526489200, T150),
+    (-513183600, T149),
+    (-495039600, T150),
+    (-481734000, T149),
+    (-463590000, T150),
+    (-450284400, T149),
+    (-431535600, T150),
+    (-418230000, T149),
+    (-400086000, T150),
+    (-386780400, T149),
+    (-368636400, T150),
+    (-355330800, T149),
+    (-337186800, T150),
+    (-323881200, T149),
+    (-305737200, T150),
+    (-292431600, T149),
+    (-273682800, T150),
+    (-260982000, T149),
+    (-242233200, T150),
+    (-226508400, T149),
+    (-210783600, T150),
+    (-195058800, T149),
+    (-179334000, T150),
+    (-163609200, T149),
+    (-147884400, T150),
+    (-131554800, T149),
+    (-116434800, T150),
+    (-100105200, T149),
+    (-84376800, T150),
+    (-68655600, T149),
+    (-52927200, T150),
+    (-37206000, T149),
+    (-21477600, T150),
+    (-5756400, T149),
+    (9972000, T150),
+    (25693200, T149),
+    (41421600, T150),
+    (57747600, T149),
+    (73476000, T150),
+    (89197200, T149),
+    (104925600, T150),
+    (120646800, T149),
+    (126698400, T150),
+    (152096400, T149),
+    (162381600, T150),
+    (183546000, T149),
+    (199274400, T150),
+    (215600400, T149),
+    (230724000, T150),
+    (247050000, T149),
+    (262778400, T150),
+    (278499600, T149),
+    (294228000, T150),
+    (309949200, T149),
+    (325677600, T150),
+    (341398800, T149),
+    (357127200, T150),
+    (372848400, T149),
+    (388576800, T150),
+    (404902800, T149),
+    (420026400, T150),
+    (436352400, T149),
+    (452080800, T150),
+    (467802000, T149),
+    (483530400, T150),
+    (499251600, T149),
+    (514980000, T150),
+    (530701200, T149),
+    (544615200, T150),
+    (562150800, T149),
+    (576064800, T150),
+    (594205200, T149),
+    (607514400, T150),
+    (625654800, T149),
+    (638964000, T150),
+    (657104400, T149),
+    (671018400, T150),
+    (688554000, T149),
+    (702468000, T150),
+    (720003600, T149),
+    (733917600, T150),
+    (752058000, T149),
+    (765367200, T150),
+    (783507600, T149),
+    (796816800, T150),
+    (814957200, T149),
+    (828871200, T150),
+    (846406800, T149),
+    (860320800, T150),
+    (877856400, T149),
+    (891770400, T150),
+    (909306000, T149),
+    (923220000, T150),
+    (941360400, T149),
+    (954669600, T150),
+    (972810000, T149),
+    (986119200, T150),
+    (1004259600, T149),
+    (1018173600, T150),
+    (1035709200, T149),
+    (1049623200, T150),
+    (1067158800, T149),
+    (1081072800, T150),
+    (1099213200, T149),
+    (1112522400, T150),
+    (1130662800, T149),
+    (1143972000, T150),
+    (1162112400, T149),
+    (1173607200, T150),
+    (1194166800, T149),
+    (1205056800, T150),
+    (1225616400, T149),
+];
+
+static AMERICA_MACEIO: &[(i64, FixedTimespan<'static>)] = &[
+    (-1767217028, T108),
+    (-1206957600, T109),
+    (-1191362400, T108),
+    (-1175374800, T109),
+    (-1159826400, T108),
+    (-633819600, T109),
+    (-622069200, T108),
+    (-602283600, T109),
+    (-591832800, T108),
+    (-570747600, T109),
+    (-560210400, T108),
+    (-539125200, T109),
+    (-531352800, T108),
+    (-191365200, T109),
+    (-184197600, T108),
+    (-155163600, T109),
+    (-150069600, T108),
+    (-128898000, T109),
+    (-121125600, T108),
+    (-99954000, T109),
+    (-89589600, T108),
+    (-68418000, T109),
+    (-57967200, T108),
+    (499748400, T109),
+    (511236000, T108),
+    (530593200, T109),
+    (540266400, T108),
+    (562129200, T109),
+    (571197600, T108),
+    (592974000, T109),
+    (602042400, T108),
+    (624423600, T109),
+    (634701600, T108),
+    (813726000, T109),
+    (824004000, T108),
+    (938919600, T109),
+    (951616800, T108),
+    (970974000, T109),
+    (972180000, T108),
+    (1003028400, T109),
+    (1013911200, T108),
+];
+
+static AMERICA_MANAGUA: &[(i64, FixedTimespan<'static>)] = &[
+    (-2524500892, T229),
+    (-1121105688, T129),
+    (105084000, T106),
+    (161758800, T129),
+    (290584800, T130),
+    (299134800, T129),
+    (322034400, T130),
+    (330584400, T129),
+    (694260000, T106),
+    (717310800, T129),
+    (725868000, T106),
+    (852094800, T129),
+    (1113112800, T130),
+    (1128229200, T129),
+    (1146384000, T130),
+    (1159682400, T129),
+];
+
+static AMERICA_MANAUS: &[(i64, FixedTimespan<'static>)] = &[
+    (-1767211196, T112),
+    (-1206954000, T113),
+    (-1191358800, T112),
+    (-1175371200, T113),
+    (-1159822800, T112),
+    (-633816000, T113),
+    (-622065600, T112),
+    (-602280000, T113),
+    (-591829200, T112),
+    (-570744000, T113),
+    (-560206800, T112),
+    (-539121600, T113),
+    (-531349200, T112),
+    (-191361600, T113),
+    (-184194000, T112),
+    (-155160000, T113),
+    (-150066000, T112),
+    (-128894400, T113),
+    (-121122000, T112),
+    (-99950400, T113),
+    (-89586000, T112),
+    (-68414400, T113),
+    (-57963600, T112),
+    (499752000, T113),
+    (511239600, T112),
+    (530596800, T113),
+    (540270000, T112),
+    (562132800, T113),
+    (571201200, T112),
+    (750830400, T113),
+    (761713200, T112),
+];
+
+static AMERICA_MARTINIQUE: &[(i64, FixedTimespan<'static>)] = &[
+    (-1851537340, T104),
+    (323841600, T136),
+    (338958000, T104),
+];
+
+static AMERICA_MATAMOROS: &[(i64, FixedTimespan<'static>)] = &[
+    (-1514743200, T129),
+    (576057600, T130),
+    (594198000, T129),
+    (828864000, T130),
+    (846399600, T129),
+    (860313600, T130),
+    (877849200, T129),
+    (891763200, T130),
+    (909298800, T129),
+    (923212800, T130),
+    (941353200, T129),
+    (954662400, T130),
+    (972802800, T129),
+    (989136000, T130),
+    (1001833200, T129),
+    (1018166400, T130),
+    (1035702000, T129),
+    (1049616000, T130),
+    (1067151600, T129),
+    (1081065600, T130),
+    (1099206000, T129),
+    (1112515200, T130),
+    (1130655600, T129),
+    (1143964800, T130),
+    (1162105200, T129),
+    (1175414400, T130),
+    (1193554800, T129),
+    (1207468800, T130),
+    (1225004400, T129),
+    (1238918400, T130),
+    (1256454000, T129),
+    (1268553600, T130),
+    (1289113200, T129),
+    (1300003200, T130),
+    (1320562800, T129),
+];
+
+static AMERICA_MAZATLAN: &[(i64, FixedTimespan<'static>)] = &[
+    (-1514739600, T133),
+    (-1343149200, T129),
+    (-1234807200, T133),
+    (-1220461200, T134),
+    (-1207159200, T133),
+    (-1191344400, T129),
+    (-873828000, T133),
+    (828867600, T134),
+    (846403200, T133),
+    (860317200, T134),
+    (877852800, T133),
+    (891766800, T134),
+    (909302400, T133),
+    (923216400, T134),
+    (941356800, T133),
+    (954666000, T134),
+    (972806400, T133),
+    (989139600, T134),
+    (1001836800, T133),
+    (1018170000, T134),
+    (1035705600, T133),
+    (1049619600, T134),
+    (1067155200, T133),
+    (1081069200, T134),
+    (1099209600, T133),
+    (1112518800, T134),
+    (1130659200, T133),
+    (1143968400, T134),
+    (1162108800, T133),
+    (1175418000, T134),
+    (1193558400, T133),
+    (1207472400, T134),
+    (1225008000, T133),
+    (1238922000, T134),
+    (1256457600, T133),
+    (1270371600, T134),
+    (1288512000, T133),
+    (1301821200, T134),
+    (1319961600, T133),
+    (1333270800, T134),
+    (1351411200, T133),
+    (1365325200, T134),
+    (1382860800, T133),
+    (1396774800, T134),
+    (1414310400, T133),
+    (1428224400, T134),
+    (1445760000, T133),
+    (1459674000, T134),
+    (1477814400, T133),
+    (1491123600, T134),
+    (1509264000, T133),
+    (1522573200, T134),
+    (1540713600, T133),
+    (1554627600, T134),
+    (1572163200, T133),
+    (1586077200, T134),
+    (1603612800, T133),
+    (1617526800, T134),
+    (1635667200, T133),
+    (1648976400, T134),
+    (1667116800, T133),
+];
+
+static AMERICA_MENOMINEE: &[(i64, FixedTimespan<'static>)] = &[
+    (-2659759773, T129),
+    (-1633276800, T130),
+    (-1615136400, T129),
+    (-1601827200, T130),
+    (-1583686800, T129),
+    (-880214400, T141),
+    (-765392400, T129),
+    (-747244800, T130),
+    (-733942800, T129),
+    (-116438400, T130),
+    (-100112400, T129),
+    (-21484800, T106),
+    (104914800, T129),
+    (104918400, T130),
+    (120639600, T129),
+    (126691200, T130),
+    (152089200, T129),
+    (162374400, T130),
+    (183538800, T129),
+    (199267200, T130),
+    (215593200, T129),
+    (230716800, T130),
+    (247042800, T129),
+    (262771200, T130),
+    (278492400, T129),
+    (294220800, T130),
+    (309942000, T129),
+    (325670400, T130),
+    (341391600, T129),
+    (357120000, T130),
+    (372841200, T129),
+    (388569600, T130),
+    (404895600, T129),
+    (420019200, T130),
+    (436345200, T129),
+    (452073600, T130),
+    (467794800, T129),
+    (483523200, T130),
+    (499244400, T129),
+    (514972800, T130),
+    (530694000, T129),
+    (544608000, T130),
+    (562143600, T129),
+    (576057600, T130),
+    (594198000, T129),
+    (607507200, T130),
+    (625647600, T129),
+    (638956800, T130),
+    (657097200, T129),
+    (671011200, T130),
+    (688546800, T129),
+    (702460800, T130),
+    (719996400, T129),
+    (733910400, T130),
+    (752050800, T129),
+    (765360000, T130),
+    (783500400, T129),
+    (796809600, T130),
+    (814950000, T129),
+    (828864000, T130),
+    (846399600, T129),
+    (860313600, T130),
+    (877849200, T129),
+    (891763200, T130),
+    (909298800, T129),
+    (923212800, T130),
+    (941353200, T129),
+    (954662400, T130),
+    (972802800, T129),
+    (986112000, T130),
+    (1004252400, T129),
+    (1018166400, T130),
+    (1035702000, T129),
+    (1049616000, T130),
+    (1067151600, T129),
+    (1081065600, T130),
+    (1099206000, T129),
+    (1112515200, T130),
+    (1130655600, T129),
+    (1143964800, T130),
+    (1162105200, T129),
+    (1173600000, T130),
+    (1194159600, T129),
+    (1205049600, T130),
+    (1225609200, T129),
+];
+
+static AMERICA_MERIDA: &[(i64, FixedTimespan<'static>)] = &[
+    (-1514743200, T129),
+    (378201600, T106),
+    (405068400, T129),
+    (828864000, T130),
+    (846399600, T129),
+    (860313600, T130),
+    (877849200, T129),
+    (891763200, T130),
+    (909298800, T129),
+    (923212800, T130),
+    (941353200, T129),
+    (954662400, T130),
+    (972802800, T129),
+    (989136000, T130),
+    (1001833200, T129),
+    (1018166400, T130),
+    (1035702000, T129),
+    (1049616000, T130),
+    (1067151600, T129),
+    (1081065600, T130),
+    (1099206000, T129),
+    (1112515200, T130),
+    (1130655600, T129),
+    (1143964800, T130),
+    (1162105200, T129),
+    (1175414400, T130),
+    (1193554800, T129),
+    (1207468800, T130),
+    (1225004400, T129),
+    (1238918400, T130),
+    (1256454000, T129),
+    (1270368000, T130),
+    (1288508400, T129),
+    (1301817600, T130),
+    (1319958000, T129),
+    (1333267200, T130),
+    (1351407600, T129),
+    (1365321600, T130),
+    (1382857200, T129),
+    (1396771200, T130),
+    (1414306800, T129),
+    (1428220800, T130),
+    (1445756400, T129),
+    (1459670400, T130),
+    (1477810800, T129),
+    (1491120000, T130),
+    (1509260400, T129),
+    (1522569600, T130),
+    (1540710000, T129),
+    (1554624000, T130),
+    (1572159600, T129),
+    (1586073600, T130),
+    (1603609200, T129),
+    (1617523200, T130),
+    (1635663600, T129),
+    (1648972800, T130),
+    (1667113200, T129),
+];
+
+static AMERICA_METLAKATLA: &[(i64, FixedTimespan<'static>)] = &[
+    (-3225223727, T237),
+    (-2188955622, T149),
+    (-880207200, T177),
+    (-765385200, T149),
+    (-21477600, T150),
+    (-5756400, T149),
+    (9972000, T150),
+    (25693200, T149),
+    (41421600, T150),
+    (57747600, T149),
+    (73476000, T150),
+    (89197200, T149),
+    (104925600, T150),
+    (120646800, T149),
+    (126698400, T150),
+    (152096400, T149),
+    (162381600, T150),
+    (183546000, T149),
+    (199274400, T150),
+    (215600400, T149),
+    (230724000, T150),
+    (247050000, T149),
+    (262778400, T150),
+    (278499600, T149),
+    (294228000, T150),
+    (309949200, T149),
+    (325677600, T150),
+    (341398800, T149),
+    (357127200, T150),
+    (372848400, T149),
+    (388576800, T150),
+    (404902800, T149),
+    (420026400, T150),
+    (436352400, T149),
+    (1446372000, T102),
+    (1457866800, T101),
+    (1478426400, T102),
+    (1489316400, T101),
+    (1509876000, T102),
+    (1520766000, T101),
+    (1547978400, T102),
+    (1552215600, T101),
+    (1572775200, T102),
+    (1583665200, T101),
+    (1604224800, T102),
+];
+
+static AMERICA_MEXICO_CITY: &[(i64, FixedTimespan<'static>)] = &[
+    (-1514739600, T133),
+    (-1343149200, T129),
+    (-1234807200, T133),
+    (-1220461200, T134),
+    (-1207159200, T133),
+    (-1191344400, T129),
+    (-975261600, T130),
+    (-963169200, T129),
+    (-917114400, T130),
+    (-907354800, T129),
+    (-821901600, T141),
+    (-810068400, T129),
+    (-627501600, T130),
+    (-612990000, T129),
+    (828864000, T130),
+    (846399600, T129),
+    (860313600, T130),
+    (877849200, T129),
+    (891763200, T130),
+    (909298800, T129),
+    (923212800, T130),
+    (941353200, T129),
+    (954662400, T130),
+    (972802800, T129),
+    (989136000, T130),
+    (1001833200, T129),
+    (1018166400, T130),
+    (1035702000, T129),
+    (1049616000, T130),
+    (1067151600, T129),
+    (1081065600, T130),
+    (1099206000, T129),
+    (1112515200, T130),
+    (1130655600, T129),
+    (1143964800, T130),
+    (1162105200, T129),
+    (1175414400, T130),
+    (1193554800, T129),
+    (1207468800, T130),
+    (1225004400, T129),
+    (1238918400, T130),
+    (1256454000, T129),
+    (1270368000, T130),
+    (1288508400, T129),
+    (1301817600, T130),
+    (1319958000, T129),
+    (1333267200, T130),
+    (1351407600, T129),
+    (1365321600, T130),
+    (1382857200, T129),
+    (1396771200, T130),
+    (1414306800, T129),
+    (1428220800, T130),
+    (1445756400, T129),
+    (1459670400, T130),
+    (1477810800, T129),
+    (1491120000, T130),
+    (1509260400, T129),
+    (1522569600, T130),
+    (1540710000, T129),
+    (1554624000, T130),
+    (1572159600, T129),
+    (1586073600, T130),
+    (1603609200, T129),
+    (1617523200, T130),
+    (1635663600, T129),
+    (1648972800, T130),
+    (1667113200, T129),
+];
+
+static AMERICA_MIQUELON: &[(i64, FixedTimespan<'static>)] = &[
+    (-1847650520, T104),
+    (326001600, T108),
+    (544597200, T109),
+    (562132800, T108),
+    (576046800, T109),
+    (594187200, T108),
+    (607496400, T109),
+    (625636800, T108),
+    (638946000, T109),
+    (657086400, T108),
+    (671000400, T109),
+    (688536000, T108),
+    (702450000, T109),
+    (719985600, T108),
+    (733899600, T109),
+    (752040000, T108),
+    (765349200, T109),
+    (783489600, T108),
+    (796798800, T109),
+    (814939200, T108),
+    (828853200, T109),
+    (846388800, T108),
+    (860302800, T109),
+    (877838400, T108),
+    (891752400, T109),
+    (909288000, T108),
+    (923202000, T109),
+    (941342400, T108),
+    (954651600, T109),
+    (972792000, T108),
+    (986101200, T109),
+    (1004241600, T108),
+    (1018155600, T109),
+    (1035691200, T108),
+    (1049605200, T109),
+    (1067140800, T108),
+    (1081054800, T109),
+    (1099195200, T108),
+    (1112504400, T109),
+    (1130644800, T108),
+    (1143954000, T109),
+    (1162094400, T108),
+    (1173589200, T109),
+    (1194148800, T108),
+    (1205038800, T109),
+    (1225598400, T108),
+];
+
+static AMERICA_MONCTON: &[(i64, FixedTimespan<'static>)] = &[
+    (-2715882052, T106),
+    (-2131642800, T104),
+    (-1632074400, T136),
+    (-1615143600, T104),
+    (-1153681200, T136),
+    (-1145822400, T104),
+    (-1122231600, T136),
+    (-1114372800, T104),
+    (-1090782000, T136),
+    (-1082923200, T104),
+    (-1059332400, T136),
+    (-1051473600, T104),
+    (-1027882800, T136),
+    (-1020024000, T104),
+    (-996433200, T136),
+    (-988574400, T104),
+    (-965674800, T136),
+    (-955396800, T104),
+    (-934743600, T136),
+    (-923947200, T104),
+    (-904503600, T136),
+    (-891892800, T104),
+    (-880221600, T143),
+    (-765399600, T104),
+    (-747252000, T136),
+    (-733950000, T104),
+    (-715802400, T136),
+    (-702500400, T104),
+    (-684352800, T136),
+    (-671050800, T104),
+    (-652903200, T136),
+    (-639601200, T104),
+    (-620848800, T136),
+    (-608151600, T104),
+    (-589399200, T136),
+    (-576097200, T104),
+    (-557949600, T136),
+    (-544647600, T104),
+    (-526500000, T136),
+    (-513198000, T104),
+    (-495050400, T136),
+    (-481748400, T104),
+    (-463600800, T136),
+    (-450298800, T104),
+    (-431546400, T136),
+    (-418244400, T104),
+    (-400096800, T136),
+    (-384375600, T104),
+    (-368647200, T136),
+    (-352926000, T104),
+    (-337197600, T136),
+    (-321476400, T104),
+    (-305748000, T136),
+    (-289422000, T104),
+    (-273693600, T136),
+    (-257972400, T104),
+    (-242244000, T136),
+    (-226522800, T104),
+    (-210794400, T136),
+    (-195073200, T104),
+    (-179344800, T136),
+    (-163623600, T104),
+    (-147895200, T136),
+    (-131569200, T104),
+    (-116445600, T136),
+    (-100119600, T104),
+    (-84391200, T136),
+    (-68670000, T104),
+    (-52941600, T136),
+    (-37220400, T104),
+    (-21492000, T136),
+    (-5770800, T104),
+    (9957600, T136),
+    (25678800, T104),
+    (41407200, T136),
+    (57733200, T104),
+    (73461600, T136),
+    (89182800, T104),
+    (136360800, T136),
+    (152082000, T104),
+    (167810400, T136),
+    (183531600, T104),
+    (199260000, T136),
+    (215586000, T104),
+    (230709600, T136),
+    (247035600, T104),
+    (262764000, T136),
+    (278485200, T104),
+    (294213600, T136),
+    (309934800, T104),
+    (325663200, T136),
+    (341384400, T104),
+    (357112800, T136),
+    (372834000, T104),
+    (388562400, T136),
+    (404888400, T104),
+    (420012000, T136),
+    (436338000, T104),
+    (452066400, T136),
+    (467787600, T104),
+    (483516000, T136),
+    (499237200, T104),
+    (514965600, T136),
+    (530686800, T104),
+    (544600800, T136),
+    (562136400, T104),
+    (576050400, T136),
+    (594190800, T104),
+    (607500000, T136),
+    (625640400, T104),
+    (638949600, T136),
+    (657090000, T104),
+    (671004000, T136),
+    (688539600, T104),
+    (702453600, T136),
+    (719989200, T104),
+    (733896060, T136),
+    (752036460, T104),
+    (765345660, T136),
+    (783486060, T104),
+    (796795260, T136),
+    (814935660, T104),
+    (828849660, T136),
+    (846385260, T104),
+    (860299260, T136),
+    (877834860, T104),
+    (891748860, T136),
+    (909284460, T104),
+    (923198460, T136),
+    (941338860, T104),
+    (954648060, T136),
+    (972788460, T104),
+    (986097660, T136),
+    (1004238060, T104),
+    (1018152060, T136),
+    (1035687660, T104),
+    (1049601660, T136),
+    (1067137260, T104),
+    (1081051260, T136),
+    (1099191660, T104),
+    (1112500860, T136),
+    (1130641260, T104),
+    (1143950460, T136),
+    (1162090860, T104),
+    (1173592800, T136),
+    (1194152400, T104),
+    (1205042400, T136),
+    (1225602000, T104),
+];
+
+static AMERICA_MONTERREY: &[(i64, FixedTimespan<'static>)] = &[
+    (-1514743200, T133),
+    (-1343149200, T129),
+    (-1234807200, T133),
+    (-1220461200, T134),
+    (-1207159200, T133),
+    (-1191344400, T129),
+    (576057600, T130),
+    (594198000, T129),
+    (828864000, T130),
+    (846399600, T129),
+    (860313600, T130),
+    (877849200, T129),
+    (891763200, T130),
+    (909298800, T129),
+    (923212800, T130),
+    (941353200, T129),
+    (954662400, T130),
+    (972802800, T129),
+    (989136000, T130),
+    (1001833200, T129),
+    (1018166400, T130),
+    (1035702000, T129),
+    (1049616000, T130),
+    (1067151600, T129),
+    (1081065600, T130),
+    (1099206000, T129),
+    (1112515200, T130),
+    (1130655600, T129),
+    (1143964800, T130),
+    (1162105200, T129),
+    (1175414400, T130),
+    (1193554800, T129),
+    (1207468800, T130),
+    (1225004400, T129),
+    (1238918400, T130),
+    (1256454000, T129),
+    (1270368000, T130),
+    (1288508400, T129),
+    (1301817600, T130),
+    (1319958000, T129),
+    (1333267200, T130),
+    (1351407600, T129),
+    (1365321600, T130),
+    (1382857200, T129),
+    (1396771200, T130),
+    (1414306800, T129),
+    (1428220800, T130),
+    (1445756400, T129),
+    (1459670400, T130),
+    (1477810800, T129),
+    (1491120000, T130),
+    (1509260400, T129),
+    (1522569600, T130),
+    (1540710000, T129),
+    (1554624000, T130),
+    (1572159600, T129),
+    (1586073600, T130),
+    (1603609200, T129),
+    (1617523200, T130),
+    (1635663600, T129),
+    (1648972800, T130),
+    (1667113200, T129),
+];
+
+static AMERICA_MONTEVIDEO: &[(i64, FixedTimespan<'static>)] = &[
+    (-1567455309, T112),
+    (-1459627200, T113),
+    (-1443819600, T243),
+    (-1428006600, T113),
+    (-1412283600, T243),
+    (-1396470600, T113),
+    (-1380747600, T243),
+    (-1141590600, T113),
+    (-1128286800, T243),
+    (-1110141000, T113),
+    (-1096837200, T243),
+    (-1078691400, T113),
+    (-1065387600, T243),
+    (-1047241800, T113),
+    (-1033938000, T243),
+    (-1015187400, T113),
+    (-1002488400, T243),
+    (-983737800, T113),
+    (-971038800, T243),
+    (-954707400, T113),
+    (-938984400, T243),
+    (-920838600, T113),
+    (-907534800, T243),
+    (-896819400, T113),
+    (-853621200, T244),
+    (-845847000, T108),
+    (-334789200, T244),
+    (-319671000, T108),
+    (-314226000, T109),
+    (-309996000, T108),
+    (-149720400, T109),
+    (-134604000, T108),
+    (-50446800, T244),
+    (-34205400, T108),
+    (9860400, T109),
+    (14176800, T108),
+    (72846000, T109),
+    (80100000, T108),
+    (127278000, T245),
+    (132111000, T244),
+    (147234600, T108),
+    (156913200, T109),
+    (165376800, T108),
+    (219812400, T109),
+    (226461600, T108),
+    (250052400, T109),
+    (257911200, T108),
+    (282711600, T109),
+    (289360800, T108),
+    (294202800, T109),
+    (322020000, T108),
+    (566449200, T109),
+    (573012000, T108),
+    (597812400, T109),
+    (605066400, T108),
+    (625633200, T109),
+    (635911200, T108),
+    (656478000, T109),
+    (667965600, T108),
+    (688532400, T109),
+    (699415200, T108),
+    (719377200, T109),
+    (730864800, T108),
+    (1095562800, T109),
+    (1111896000, T108),
+    (1128834000, T109),
+    (1142136000, T108),
+    (1159678800, T109),
+    (1173585600, T108),
+    (1191733200, T109),
+    (1205035200, T108),
+    (1223182800, T109),
+    (1236484800, T108),
+    (1254632400, T109),
+    (1268539200, T108),
+    (1286082000, T109),
+    (1299988800, T108),
+    (1317531600, T109),
+    (1331438400, T108),
+    (1349586000, T109),
+    (1362888000, T108),
+    (1381035600, T109),
+    (1394337600, T108),
+    (1412485200, T109),
+    (1425787200, T108),
+];
+
+static AMERICA_MONTSERRAT: &[(i64, FixedTimespan<'static>)] = &[
+    (-1846266608, T104),
+];
+
+static AMERICA_NASSAU: &[(i64, FixedTimespan<'static>)] = &[
+    (-1825095030, T106),
+    (-873140400, T180),
+    (-788904000, T106),
+    (-786222000, T180),
+    (-763848000, T106),
+    (-179341200, T155),
+    (-163620000, T106),
+    (-147891600, T155),
+    (-131565600, T106),
+    (-116442000, T155),
+    (-100116000, T106),
+    (-84387600, T155),
+    (-68666400, T106),
+    (-52938000, T155),
+    (-37216800, T106),
+    (-21488400, T155),
+    (-5767200, T106),
+    (9961200, T155),
+    (25682400, T106),
+    (41410800, T155),
+    (57736800, T106),
+    (73465200, T155),
+    (89186400, T106),
+    (104914800, T155),
+    (120636000, T106),
+    (136364400, T155),
+    (152085600, T106),
+    (167814000, T155),
+    (183535200, T106),
+    (199263600, T155),
+    (215589600, T106),
+    (230713200, T155),
+    (247039200, T106),
+    (262767600, T155),
+    (278488800, T106),
+    (294217200, T155),
+    (309938400, T106),
+    (325666800, T155),
+    (341388000, T106),
+    (357116400, T155),
+    (372837600, T106),
+    (388566000, T155),
+    (404892000, T106),
+    (420015600, T155),
+    (436341600, T106),
+    (452070000, T155),
+    (467791200, T106),
+    (483519600, T155),
+    (499240800, T106),
+    (514969200, T155),
+    (530690400, T106),
+    (544604400, T155),
+    (562140000, T106),
+    (576054000, T155),
+    (594194400, T106),
+    (607503600, T155),
+    (625644000, T106),
+    (638953200, T155),
+    (657093600, T106),
+    (671007600, T155),
+    (688543200, T106),
+    (702457200, T155),
+    (719992800, T106),
+    (733906800, T155),
+    (752047200, T106),
+    (765356400, T155),
+    (783496800, T106),
+    (796806000, T155),
+    (814946400, T106),
+    (828860400, T155),
+    (846396000, T106),
+    (860310000, T155),
+    (877845600, T106),
+    (891759600, T155),
+    (909295200, T106),
+    (923209200, T155),
+    (941349600, T106),
+    (954658800, T155),
+    (972799200, T106),
+    (986108400, T155),
+    (1004248800, T106),
+    (1018162800, T155),
+    (1035698400, T106),
+    (1049612400, T155),
+    (1067148000, T106),
+    (1081062000, T155),
+    (1099202400, T106),
+    (1112511600, T155),
+    (1130652000, T106),
+    (1143961200, T155),
+    (1162101600, T106),
+    (1173596400, T155),
+    (1194156000, T106),
+    (1205046000, T155),
+    (1225605600, T106),
+];
+
+static AMERICA_NEW_YORK: &[(i64, FixedTimespan<'static>)] = &[
+    (-2717650800, T106),
+    (-1633280400, T155),
+    (-1615140000, T106),
+    (-1601830800, T155),
+    (-1583690400, T106),
+    (-1570381200, T155),
+    (-1551636000, T106),
+    (-1536512400, T155),
+    (-1523210400, T106),
+    (-1504458000, T155),
+    (-1491760800, T106),
+    (-1473008400, T155),
+    (-1459706400, T106),
+    (-1441558800, T155),
+    (-1428256800, T106),
+    (-1410109200, T155),
+    (-1396807200, T106),
+    (-1378659600, T155),
+    (-1365357600, T106),
+    (-1347210000, T155),
+    (-1333908000, T106),
+    (-1315155600, T155),
+    (-1301853600, T106),
+    (-1283706000, T155),
+    (-1270404000, T106),
+    (-1252256400, T155),
+    (-1238954400, T106),
+    (-1220806800, T155),
+    (-1207504800, T106),
+    (-1189357200, T155),
+    (-1176055200, T106),
+    (-1157302800, T155),
+    (-1144605600, T106),
+    (-1125853200, T155),
+    (-1112551200, T106),
+    (-1094403600, T155),
+    (-1081101600, T106),
+    (-1062954000, T155),
+    (-1049652000, T106),
+    (-1031504400, T155),
+    (-1018202400, T106),
+    (-1000054800, T155),
+    (-986752800, T106),
+    (-968000400, T155),
+    (-955303200, T106),
+    (-936550800, T155),
+    (-923248800, T106),
+    (-905101200, T155),
+    (-891799200, T106),
+    (-880218000, T180),
+    (-765396000, T106),
+    (-747248400, T155),
+    (-733946400, T106),
+    (-715798800, T155),
+    (-702496800, T106),
+    (-684349200, T155),
+    (-671047200, T106),
+    (-652899600, T155),
+    (-639597600, T106),
+    (-620845200, T155),
+    (-608148000, T106),
+    (-589395600, T155),
+    (-576093600, T106),
+    (-557946000, T155),
+    (-544644000, T106),
+    (-526496400, T155),
+    (-513194400, T106),
+    (-495046800, T155),
+    (-481744800, T106),
+    (-463597200, T155),
+    (-447271200, T106),
+    (-431542800, T155),
+    (-415821600, T106),
+    (-400093200, T155),
+    (-384372000, T106),
+    (-368643600, T155),
+    (-352922400, T106),
+    (-337194000, T155),
+    (-321472800, T106),
+    (-305744400, T155),
+    (-289418400, T106),
+    (-273690000, T155),
+    (-257968800, T106),
+    (-242240400, T155),
+    (-226519200, T106),
+    (-210790800, T155),
+    (-195069600, T106),
+    (-179341200, T155),
+    (-163620000, T106),
+    (-147891600, T155),
+    (-131565600, T106),
+    (-116442000, T155),
+    (-100116000, T106),
+    (-84387600, T155),
+    (-68666400, T106),
+    (-52938000, T155),
+    (-37216800, T106),
+    (-21488400, T155),
+    (-5767200, T106),
+    (9961200, T155),
+    (25682400, T106),
+    (41410800, T155),
+    (57736800, T106),
+    (73465200, T155),
+    (89186400, T106),
+    (104914800, T155),
+    (120636000, T106),
+    (126687600, T155),
+    (152085600, T106),
+    (162370800, T155),
+    (183535200, T106),
+    (199263600, T155),
+    (215589600, T106),
+    (230713200, T155),
+    (247039200, T106),
+    (262767600, T155),
+    (278488800, T106),
+    (294217200, T155),
+    (309938400, T106),
+    (325666800, T155),
+    (341388000, T106),
+    (357116400, T155),
+    (372837600, T106),
+    (388566000, T155),
+    (404892000, T106),
+    (420015600, T155),
+    (436341600, T106),
+    (452070000, T155),
+    (467791200, T106),
+    (483519600, T155),
+    (499240800, T106),
+    (514969200, T155),
+    (530690400, T106),
+    (544604400, T155),
+    (562140000, T106),
+    (576054000, T155),
+    (594194400, T106),
+    (607503600, T155),
+    (625644000, T106),
+    (638953200, T155),
+    (657093600, T106),
+    (671007600, T155),
+    (688543200, T106),
+    (702457200, T155),
+    (719992800, T106),
+    (733906800, T155),
+    (752047200, T106),
+    (765356400, T155),
+    (783496800, T106),
+    (796806000, T155),
+    (814946400, T106),
+    (828860400, T155),
+    (846396000, T106),
+    (860310000, T155),
+    (877845600, T106),
+    (891759600, T155),
+    (909295200, T106),
+    (923209200, T155),
+    (941349600, T106),
+    (954658800, T155),
+    (972799200, T106),
+    (986108400, T155),
+    (1004248800, T106),
+    (1018162800, T155),
+    (1035698400, T106),
+    (1049612400, T155),
+    (1067148000, T106),
+    (1081062000, T155),
+    (1099202400, T106),
+    (1112511600, T155),
+    (1130652000, T106),
+    (1143961200, T155),
+    (1162101600, T106),
+    (1173596400, T155),
+    (1194156000, T106),
+    (1205046000, T155),
+    (1225605600, T106),
+];
+
+static AMERICA_NOME: &[(i64, FixedTimespan<'static>)] = &[
+    (-3225223727, T250),
+    (-2188947502, T89),
+    (-880196400, T90),
+    (-765374400, T89),
+    (-21466800, T91),
+    (-5745600, T92),
+    (9982800, T91),
+    (25704000, T92),
+    (41432400, T91),
+    (57758400, T92),
+    (73486800, T91),
+    (89208000, T92),
+    (104936400, T91),
+    (120657600, T92),
+    (126709200, T91),
+    (152107200, T92),
+    (162392400, T91),
+    (183556800, T92),
+    (199285200, T91),
+    (215611200, T92),
+    (230734800, T91),
+    (247060800, T92),
+    (262789200, T91),
+    (278510400, T92),
+    (294238800, T91),
+    (309960000, T92),
+    (325688400, T91),
+    (341409600, T92),
+    (357138000, T91),
+    (372859200, T92),
+    (388587600, T91),
+    (404913600, T92),
+    (420037200, T91),
+    (436363200, T172),
+    (452084400, T101),
+    (467805600, T102),
+    (483534000, T101),
+    (499255200, T102),
+    (514983600, T101),
+    (530704800, T102),
+    (544618800, T101),
+    (562154400, T102),
+    (576068400, T101),
+    (594208800, T102),
+    (607518000, T101),
+    (625658400, T102),
+    (638967600, T101),
+    (657108000, T102),
+    (671022000, T101),
+    (688557600, T102),
+    (702471600, T101),
+    (720007200, T102),
+    (733921200, T101),
+    (752061600, T102),
+    (765370800, T101),
+    (783511200, T102),
+    (796820400, T101),
+    (814960800, T102),
+    (828874800, T101),
+    (846410400, T102),
+    (860324400, T101),
+    (877860000, T102),
+    (891774000, T101),
+    (909309600, T102),
+    (923223600, T101),
+    (941364000, T102),
+    (954673200, T101),
+    (972813600, T102),
+    (986122800, T101),
+    (1004263200, T102),
+    (1018177200, T101),
+    (1035712800, T102),
+    (1049626800, T101),
+    (1067162400, T102),
+    (1081076400, T101),
+    (1099216800, T102),
+    (1112526000, T101),
+    (1130666400, T102),
+    (1143975600, T101),
+    (1162116000, T102),
+    (1173610800, T101),
+    (1194170400, T102),
+    (1205060400, T101),
+    (1225620000, T102),
+];
+
+static AMERICA_NORONHA: &[(i64, FixedTimespan<'static>)] = &[
+    (-1767217820, T252),
+    (-1206961200, T253),
+    (-1191366000, T252),
+    (-1175378400, T253),
+    (-1159830000, T252),
+    (-633823200, T253),
+    (-622072800, T252),
+    (-602287200, T253),
+    (-591836400, T252),
+    (-570751200, T253),
+    (-560214000, T252),
+    (-539128800, T253),
+    (-531356400, T252),
+    (-191368800, T253),
+    (-184201200, T252),
+    (-155167200, T253),
+    (-150073200, T252),
+    (-128901600, T253),
+    (-121129200, T252),
+    (-99957600, T253),
+    (-89593200, T252),
+    (-68421600, T253),
+    (-57970800, T252),
+    (499744800, T253),
+    (511232400, T252),
+    (530589600, T253),
+    (540262800, T252),
+    (562125600, T253),
+    (571194000, T252),
+    (592970400, T253),
+    (602038800, T252),
+    (624420000, T253),
+    (634698000, T252),
+    (938916000, T253),
+    (951613200, T252),
+    (970970400, T253),
+    (971571600, T252),
+    (1003024800, T253),
+    (1013907600, T252),
+];
+
+static AMERICA_NORTH_DAKOTA_BEULAH: &[(i64, FixedTimespan<'static>)] = &[
+    (-2717643600, T133),
+    (-1633273200, T134),
+    (-1615132800, T133),
+    (-1601823600, T134),
+    (-1583683200, T133),
+    (-880210800, T151),
+    (-765388800, T133),
+    (-84380400, T134),
+    (-68659200, T133),
+    (-52930800, T134),
+    (-37209600, T133),
+    (-21481200, T134),
+    (-5760000, T133),
+    (9968400, T134),
+    (25689600, T133),
+    (41418000, T134),
+    (57744000, T133),
+    (73472400, T134),
+    (89193600, T133),
+    (104922000, T134),
+    (120643200, T133),
+    (126694800, T134),
+    (152092800, T133),
+    (162378000, T134),
+    (183542400, T133),
+    (199270800, T134),
+    (215596800, T133),
+    (230720400, T134),
+    (247046400, T133),
+    (262774800, T134),
+    (278496000, T133),
+    (294224400, T134),
+    (309945600, T133),
+    (325674000, T134),
+    (341395200, T133),
+    (357123600, T134),
+    (372844800, T133),
+    (388573200, T134),
+    (404899200, T133),
+    (420022800, T134),
+    (436348800, T133),
+    (452077200, T134),
+    (467798400, T133),
+    (483526800, T134),
+    (499248000, T133),
+    (514976400, T134),
+    (530697600, T133),
+    (544611600, T134),
+    (562147200, T133),
+    (576061200, T134),
+    (594201600, T133),
+    (607510800, T134),
+    (625651200, T133),
+    (638960400, T134),
+    (657100800, T133),
+    (671014800, T134),
+    (688550400, T133),
+    (702464400, T134),
+    (720000000, T133),
+    (733914000, T134),
+    (752054400, T133),
+    (765363600, T134),
+    (783504000, T133),
+    (796813200, T134),
+    (814953600, T133),
+    (828867600, T134),
+    (846403200, T133),
+    (860317200, T134),
+    (877852800, T133),
+    (891766800, T134),
+    (909302400, T133),
+    (923216400, T134),
+    (941356800, T133),
+    (954666000, T134),
+    (972806400, T133),
+    (986115600, T134),
+    (1004256000, T133),
+    (1018170000, T134),
+    (1035705600, T133),
+    (1049619600, T134),
+    (1067155200, T133),
+    (1081069200, T134),
+    (1099209600, T133),
+    (1112518800, T134),
+    (1130659200, T133),
+    (1143968400, T134),
+    (1162108800, T133),
+    (1173603600, T134),
+    (1194163200, T133),
+    (1205053200, T134),
+    (1225612800, T133),
+    (1236502800, T134),
+    (1257062400, T133),
+    (1268557200, T134),
+    (1300003200, T130),
+    (1320562800, T129),
+];
+
+static AMERICA_NORTH_DAKOTA_CENTER: &[(i64, FixedTimespan<'static>)] = &[
+    (-2717643600, T133),
+    (-1633273200, T134),
+    (-1615132800, T133),
+    (-1601823600, T134),
+    (-1583683200, T133),
+    (-880210800, T151),
+    (-765388800, T133),
+    (-84380400, T134),
+    (-68659200, T133),
+    (-52930800, T134),
+    (-37209600, T133),
+    (-21481200, T134),
+    (-5760000, T133),
+    (9968400, T134),
+    (25689600, T133),
+    (41418000, T134),
+    (57744000, T133),
+    (73472400, T134),
+    (89193600, T133),
+    (104922000, T134),
+    (120643200, T133),
+    (126694800, T134),
+    (152092800, T133),
+    (162378000, T134),
+    (183542400, T133),
+    (199270800, T134),
+    (215596800, T133),
+    (230720400, T134),
+    (247046400, T133),
+    (262774800, T134),
+    (278496000, T133),
+    (294224400, T134),
+    (309945600, T133),
+    (325674000, T134),
+    (341395200, T133),
+    (357123600, T134),
+    (372844800, T133),
+    (388573200, T134),
+    (404899200, T133),
+    (420022800, T134),
+    (436348800, T133),
+    (452077200, T134),
+    (467798400, T133),
+    (483526800, T134),
+    (499248000, T133),
+    (514976400, T134),
+    (530697600, T133),
+    (544611600, T134),
+    (562147200, T133),
+    (576061200, T134),
+    (594201600, T133),
+    (607510800, T134),
+    (625651200, T133),
+    (638960400, T134),
+    (657100800, T133),
+    (671014800, T134),
+    (688550400, T133),
+    (702464400, T134),
+    (733910400, T130),
+    (752050800, T129),
+    (765360000, T130),
+    (783500400, T129),
+    (796809600, T130),
+    (814950000, T129),
+    (828864000, T130),
+    (846399600, T129),
+    (860313600, T130),
+    (877849200, T129),
+    (891763200, T130),
+    (909298800, T129),
+    (923212800, T130),
+    (941353200, T129),
+    (954662400, T130),
+    (972802800, T129),
+    (986112000, T130),
+    (1004252400, T129),
+    (1018166400, T130),
+    (1035702000, T129),
+    (1049616000, T130),
+    (1067151600, T129),
+    (1081065600, T130),
+    (1099206000, T129),
+    (1112515200, T130),
+    (1130655600, T129),
+    (1143964800, T130),
+    (1162105200, T129),
+    (1173600000, T130),
+    (1194159600, T129),
+    (1205049600, T130),
+    (1225609200, T129),
+];
+
+static AMERICA_NORTH_DAKOTA_NEW_SALEM: &[(i64, FixedTimespan<'static>)] = &[
+    (-2717643600, T133),
+    (-1633273200, T134),
+    (-1615132800, T133),
+    (-1601823600, T134),
+    (-1583683200, T133),
+    (-880210800, T151),
+    (-765388800, T133),
+    (-84380400, T134),
+    (-68659200, T133),
+    (-52930800, T134),
+    (-37209600, T133),
+    (-21481200, T134),
+    (-5760000, T133),
+    (9968400, T134),
+    (25689600, T133),
+    (41418000, T134),
+    (57744000, T133),
+    (73472400, T134),
+    (89193600, T133),
+    (104922000, T134),
+    (120643200, T133),
+    (126694800, T134),
+    (152092800, T133),
+    (162378000, T134),
+    (183542400, T133),
+    (199270800, T134),
+    (215596800, T133),
+    (230720400, T134),
+    (247046400, T133),
+    (262774800, T134),
+    (278496000, T133),
+    (294224400, T134),
+    (309945600, T133),
+    (325674000, T134),
+    (341395200, T133),
+    (357123600, T134),
+    (372844800, T133),
+    (388573200, T134),
+    (404899200, T133),
+    (420022800, T134),
+    (436348800, T133),
+    (452077200, T134),
+    (467798400, T133),
+    (483526800, T134),
+    (499248000, T133),
+    (514976400, T134),
+    (530697600, T133),
+    (544611600, T134),
+    (562147200, T133),
+    (576061200, T134),
+    (594201600, T133),
+    (607510800, T134),
+    (625651200, T133),
+    (638960400, T134),
+    (657100800, T133),
+    (671014800, T134),
+    (688550400, T133),
+    (702464400, T134),
+    (720000000, T133),
+    (733914000, T134),
+    (752054400, T133),
+    (765363600, T134),
+    (783504000, T133),
+    (796813200, T134),
+    (814953600, T133),
+    (828867600, T134),
+    (846403200, T133),
+    (860317200, T134),
+    (877852800, T133),
+    (891766800, T134),
+    (909302400, T133),
+    (923216400, T134),
+    (941356800, T133),
+    (954666000, T134),
+    (972806400, T133),
+    (986115600, T134),
+    (1004256000, T133),
+    (1018170000, T134),
+    (1035705600, T133),
+    (1049619600, T134),
+    (1081065600, T130),
+    (1099206000, T129),
+    (1112515200, T130),
+    (1130655600, T129),
+    (1143964800, T130),
+    (1162105200, T129),
+    (1173600000, T130),
+    (1194159600, T129),
+    (1205049600, T130),
+    (1225609200, T129),
+];
+
+static AMERICA_NUUK: &[(i64, FixedTimespan<'static>)] = &[
+    (-1686083584, T108),
+    (323845200, T109),
+    (338950800, T108),
+    (354675600, T109),
+    (370400400, T108),
+    (386125200, T109),
+    (401850000, T108),
+    (417574800, T109),
+    (433299600, T108),
+    (449024400, T109),
+    (465354000, T108),
+    (481078800, T109),
+    (496803600, T108),
+    (512528400, T109),
+    (528253200, T108),
+    (543978000, T109),
+    (559702800, T108),
+    (575427600, T109),
+    (591152400, T108),
+    (606877200, T109),
+    (622602000, T108),
+    (638326800, T109),
+    (654656400, T108),
+    (670381200, T109),
+    (686106000, T108),
+    (701830800, T109),
+    (717555600, T108),
+    (733280400, T109),
+    (749005200, T108),
+    (764730000, T109),
+    (780454800, T108),
+    (796179600, T109),
+    (811904400, T108),
+    (828234000, T109),
+    (846378000, T108),
+    (859683600, T109),
+    (877827600, T108),
+    (891133200, T109),
+    (909277200, T108),
+    (922582800, T109),
+    (941331600, T108),
+    (954032400, T109),
+    (972781200, T108),
+    (985482000, T109),
+    (1004230800, T108),
+    (1017536400, T109),
+    (1035680400, T108),
+    (1048986000, T109),
+    (1067130000, T108),
+    (1080435600, T109),
+    (1099184400, T108),
+    (1111885200, T109),
+    (1130634000, T108),
+    (1143334800, T109),
+    (1162083600, T108),
+    (1174784400, T109),
+    (1193533200, T108),
+    (1206838800, T109),
+    (1224982800, T108),
+    (1238288400, T109),
+    (1256432400, T108),
+    (1269738000, T109),
+    (1288486800, T108),
+    (1301187600, T109),
+    (1319936400, T108),
+    (1332637200, T109),
+    (1351386000, T108),
+    (1364691600, T109),
+    (1382835600, T108),
+    (1396141200, T109),
+    (1414285200, T108),
+    (1427590800, T109),
+    (1445734800, T108),
+    (1459040400, T109),
+    (1477789200, T108),
+    (1490490000, T109),
+    (1509238800, T108),
+    (1521939600, T109),
+    (1540688400, T108),
+    (1553994000, T109),
+    (1572138000, T108),
+    (1585443600, T109),
+    (1603587600, T108),
+    (1616893200, T109),
+    (1635642000, T108),
+    (1648342800, T109),
+    (1667091600, T108),
+    (1679792400, T252),
+    (1711846800, T253),
+    (1729990800, T252),
+];
+
+static AMERICA_OJINAGA: &[(i64, FixedTimespan<'static>)] = &[
+    (-1514739600, T133),
+    (-1343149200, T129),
+    (-1234807200, T133),
+    (-1220461200, T134),
+    (-1207159200, T133),
+    (-1191344400, T129),
+    (828864000, T130),
+    (846399600, T129),
+    (860313600, T130),
+    (877849200, T129),
+    (909302400, T133),
+    (923216400, T134),
+    (941356800, T133),
+    (954666000, T134),
+    (972806400, T133),
+    (989139600, T134),
+    (1001836800, T133),
+    (1018170000, T134),
+    (1035705600, T133),
+    (1049619600, T134),
+    (1067155200, T133),
+    (1081069200, T134),
+    (1099209600, T133),
+    (1112518800, T134),
+    (1130659200, T133),
+    (1143968400, T134),
+    (1162108800, T133),
+    (1175418000, T134),
+    (1193558400, T133),
+    (1207472400, T134),
+    (1225008000, T133),
+    (1238922000, T134),
+    (1256457600, T133),
+    (1268557200, T134),
+    (1289116800, T133),
+    (1300006800, T134),
+    (1320566400, T133),
+    (1331456400, T134),
+    (1352016000, T133),
+    (1362906000, T134),
+    (1383465600, T133),
+    (1394355600, T134),
+    (1414915200, T133),
+    (1425805200, T134),
+    (1446364800, T133),
+    (1457859600, T134),
+    (1478419200, T133),
+    (1489309200, T134),
+    (1509868800, T133),
+    (1520758800, T134),
+    (1541318400, T133),
+    (1552208400, T134),
+    (1572768000, T133),
+    (1583658000, T134),
+    (1604217600, T133),
+    (1615712400, T134),
+    (1636272000, T133),
+    (1647162000, T134),
+    (1678608000, T130),
+    (1699167600, T129),
+];
+
+static AMERICA_PANAMA: &[(i64, FixedTimespan<'static>)] = &[
+    (-2524502512, T260),
+    (-1946918424, T106),
+];
+
+static AMERICA_PARAMARIBO: &[(i64, FixedTimespan<'static>)] = &[
+    (-1861906760, T262),
+    (-1104524348, T263),
+    (-765317964, T243),
+    (465449400, T108),
+];
+
+static AMERICA_PHOENIX: &[(i64, FixedTimespan<'static>)] = &[
+    (-2717643600, T133),
+    (-1633273200, T134),
+    (-1615132800, T133),
+    (-1601823600, T134),
+    (-1583683200, T133),
+    (-880210800, T151),
+    (-820519140, T133),
+    (-812653140, T151),
+    (-796845540, T133),
+    (-84380400, T134),
+    (-68659200, T133),
+];
+
+static AMERICA_PORT_MINUS_AU_MINUS_PRINCE: &[(i64, FixedTimespan<'static>)] = &[
+    (-2524504240, T266),
+    (-1670483460, T106),
+    (421218000, T155),
+    (436334400, T106),
+    (452062800, T155),
+    (467784000, T106),
+    (483512400, T155),
+    (499233600, T106),
+    (514962000, T155),
+    (530683200, T106),
+    (546411600, T155),
+    (562132800, T106),
+    (576050400, T155),
+    (594194400, T106),
+    (607500000, T155),
+    (625644000, T106),
+    (638949600, T155),
+    (657093600, T106),
+    (671004000, T155),
+    (688543200, T106),
+    (702453600, T155),
+    (719992800, T106),
+    (733903200, T155),
+    (752047200, T106),
+    (765352800, T155),
+    (783496800, T106),
+    (796802400, T155),
+    (814946400, T106),
+    (828856800, T155),
+    (846396000, T106),
+    (860306400, T155),
+    (877845600, T106),
+    (1112504400, T155),
+    (1130644800, T106),
+    (1143954000, T155),
+    (1162094400, T106),
+    (1331449200, T155),
+    (1352008800, T106),
+    (1362898800, T155),
+    (1383458400, T106),
+    (1394348400, T155),
+    (1414908000, T106),
+    (1425798000, T155),
+    (1446357600, T106),
+    (1489302000, T155),
+    (1509861600, T106),
+    (1520751600, T155),
+    (1541311200, T106),
+];
+
+static AMERICA_PORT_OF_SPAIN: &[(i64, FixedTimespan<'static>)] = &[
+    (-1825098836, T104),
+];
+
+static AMERICA_PORTO_VELHO: &[(i64, FixedTimespan<'static>)] = &[
+    (-1767210264, T112),
+    (-1206954000, T113),
+    (-1191358800, T112),
+    (-1175371200, T113),
+    (-1159822800, T112),
+    (-633816000, T113),
+    (-622065600, T112),
+    (-602280000, T113),
+    (-591829200, T112),
+    (-570744000, T113),
+    (-560206800, T112),
+    (-539121600, T113),
+    (-531349200, T112),
+    (-191361600, T113),
+    (-184194000, T112),
+    (-155160000, T113),
+    (-150066000, T112),
+    (-128894400, T113),
+    (-121122000, T112),
+    (-99950400, T113),
+    (-89586000, T112),
+    (-68414400, T113),
+    (-57963600, T112),
+    (499752000, T113),
+    (511239600, T112),
+    (530596800, T113),
+    (540270000, T112),
+    (562132800, T113),
+    (571201200, T112),
+];
+
+static AMERICA_PUERTO_RICO: &[(i64, FixedTimespan<'static>)] = &[
+    (-2233035335, T104),
+    (-873057600, T143),
+    (-765399600, T104),
+];
+
+static AMERICA_PUNTA_ARENAS: &[(i64, FixedTimespan<'static>)] = &[
+    (-2524504580, T166),
+    (-1892661435, T146),
+    (-1688410800, T166),
+    (-1619205435, T112),
+    (-1593806400, T166),
+    (-1335986235, T146),
+    (-1335985200, T147),
+    (-1317585600, T146),
+    (-1304362800, T147),
+    (-1286049600, T146),
+    (-1272826800, T147),
+    (-1254513600, T146),
+    (-1241290800, T147),
+    (-1222977600, T146),
+    (-1209754800, T147),
+    (-1191355200, T146),
+    (-1178132400, T112),
+    (-870552000, T146),
+    (-865278000, T112),
+    (-718056000, T146),
+    (-713649600, T112),
+    (-36619200, T113),
+    (-23922000, T112),
+    (-3355200, T113),
+    (7527600, T112),
+    (24465600, T113),
+    (37767600, T112),
+    (55915200, T113),
+    (69217200, T112),
+    (87969600, T113),
+    (100666800, T112),
+    (118209600, T113),
+    (132116400, T112),
+    (150868800, T113),
+    (163566000, T112),
+    (182318400, T113),
+    (195620400, T112),
+    (213768000, T113),
+    (227070000, T112),
+    (245217600, T113),
+    (258519600, T112),
+    (277272000, T113),
+    (289969200, T112),
+    (308721600, T113),
+    (321418800, T112),
+    (340171200, T113),
+    (353473200, T112),
+    (371620800, T113),
+    (384922800, T112),
+    (403070400, T113),
+    (416372400, T112),
+    (434520000, T113),
+    (447822000, T112),
+    (466574400, T113),
+    (479271600, T112),
+    (498024000, T113),
+    (510721200, T112),
+    (529473600, T113),
+    (545194800, T112),
+    (560923200, T113),
+    (574225200, T112),
+    (592372800, T113),
+    (605674800, T112),
+    (624427200, T113),
+    (637124400, T112),
+    (653457600, T113),
+    (668574000, T112),
+    (687326400, T113),
+    (700628400, T112),
+    (718776000, T113),
+    (732078000, T112),
+    (750225600, T113),
+    (763527600, T112),
+    (781675200, T113),
+    (794977200, T112),
+    (813729600, T113),
+    (826426800, T112),
+    (845179200, T113),
+    (859690800, T112),
+    (876628800, T113),
+    (889930800, T112),
+    (906868800, T113),
+    (923194800, T112),
+    (939528000, T113),
+    (952830000, T112),
+    (971582400, T113),
+    (984279600, T112),
+    (1003032000, T113),
+    (1015729200, T112),
+    (1034481600, T113),
+    (1047178800, T112),
+    (1065931200, T113),
+    (1079233200, T112),
+    (1097380800, T113),
+    (1110682800, T112),
+    (1128830400, T113),
+    (1142132400, T112),
+    (1160884800, T113),
+    (1173582000, T112),
+    (1192334400, T113),
+    (1206846000, T112),
+    (1223784000, T113),
+    (1237086000, T112),
+    (1255233600, T113),
+    (1270350000, T112),
+    (1286683200, T113),
+    (1304823600, T112),
+    (1313899200, T113),
+    (1335668400, T112),
+    (1346558400, T113),
+    (1367118000, T112),
+    (1378612800, T113),
+    (1398567600, T112),
+    (1410062400, T113),
+    (1463281200, T112),
+    (1471147200, T113),
+];
+
+static AMERICA_RANKIN_INLET: &[(i64, FixedTimespan<'static>)] = &[
+    (-410227200, T129),
+    (73468800, T130),
+    (89190000, T129),
+    (104918400, T130),
+    (120639600, T129),
+    (136368000, T130),
+    (152089200, T129),
+    (167817600, T130),
+    (183538800, T129),
+    (199267200, T130),
+    (215593200, T129),
+    (230716800, T130),
+    (247042800, T129),
+    (262771200, T130),
+    (278492400, T129),
+    (294220800, T130),
+    (309942000, T129),
+    (325670400, T130),
+    (341391600, T129),
+    (357120000, T130),
+    (372841200, T129),
+    (388569600, T130),
+    (404895600, T129),
+    (420019200, T130),
+    (436345200, T129),
+    (452073600, T130),
+    (467794800, T129),
+    (483523200, T130),
+    (499244400, T129),
+    (514972800, T130),
+    (530694000, T129),
+    (544608000, T130),
+    (562143600, T129),
+    (576057600, T130),
+    (594198000, T129),
+    (607507200, T130),
+    (625647600, T129),
+    (638956800, T130),
+    (657097200, T129),
+    (671011200, T130),
+    (688546800, T129),
+    (702460800, T130),
+    (719996400, T129),
+    (733910400, T130),
+    (752050800, T129),
+    (765360000, T130),
+    (783500400, T129),
+    (796809600, T130),
+    (814950000, T129),
+    (828864000, T130),
+    (846399600, T129),
+    (860313600, T130),
+    (877849200, T129),
+    (891763200, T130),
+    (909298800, T129),
+    (923212800, T130),
+    (941353200, T129),
+    (954662400, T130),
+    (1004252400, T129),
+    (1018166400, T130),
+    (1035702000, T129),
+    (1049616000, T130),
+    (1067151600, T129),
+    (1081065600, T130),
+    (1099206000, T129),
+    (1112515200, T130),
+    (1130655600, T129),
+    (1143964800, T130),
+    (1162105200, T129),
+    (1173600000, T130),
+    (1194159600, T129),
+    (1205049600, T130),
+    (1225609200, T129),
+];
+
+static AMERICA_RECIFE: &[(i64, FixedTimespan<'static>)] = &[
+    (-1767217224, T108),
+    (-1206957600, T109),
+    (-1191362400, T108),
+    (-1175374800, T109),
+    (-1159826400, T108),
+    (-633819600, T109),
+    (-622069200, T108),
+    (-602283600, T109),
+    (-591832800, T108),
+    (-570747600, T109),
+    (-560210400, T108),
+    (-539125200, T109),
+    (-531352800, T108),
+    (-191365200, T109),
+    (-184197600, T108),
+    (-155163600, T109),
+    (-150069600, T108),
+    (-128898000, T109),
+    (-121125600, T108),
+    (-99954000, T109),
+    (-89589600, T108),
+    (-68418000, T109),
+    (-57967200, T108),
+    (499748400, T109),
+    (511236000, T108),
+    (530593200, T109),
+    (540266400, T108),
+    (562129200, T109),
+    (571197600, T108),
+    (592974000, T109),
+    (602042400, T108),
+    (624423600, T109),
+    (634701600, T108),
+    (938919600, T109),
+    (951616800, T108),
+    (970974000, T109),
+    (971575200, T108),
+    (1003028400, T109),
+    (1013911200, T108),
+];
+
+static AMERICA_REGINA: &[(i64, FixedTimespan<'static>)] = &[
+    (-2030202084, T133),
+    (-1632063600, T134),
+    (-1615132800, T133),
+    (-1251651600, T134),
+    (-1238349600, T133),
+    (-1220202000, T134),
+    (-1206900000, T133),
+    (-1188752400, T134),
+    (-1175450400, T133),
+    (-1156698000, T134),
+    (-1144000800, T133),
+    (-1125248400, T134),
+    (-1111946400, T133),
+    (-1032714000, T134),
+    (-1016992800, T133),
+    (-1001264400, T134),
+    (-986148000, T133),
+    (-969814800, T134),
+    (-954093600, T133),
+    (-937760400, T134),
+    (-922039200, T133),
+    (-906310800, T134),
+    (-890589600, T133),
+    (-880210800, T151),
+    (-765388800, T133),
+    (-748450800, T134),
+    (-732729600, T133),
+    (-715791600, T134),
+    (-702489600, T133),
+    (-684342000, T134),
+    (-671040000, T133),
+    (-652892400, T134),
+    (-639590400, T133),
+    (-620838000, T134),
+    (-608140800, T133),
+    (-589388400, T134),
+    (-576086400, T133),
+    (-557938800, T134),
+    (-544636800, T133),
+    (-526489200, T134),
+    (-513187200, T133),
+    (-495039600, T134),
+    (-481737600, T133),
+    (-463590000, T134),
+    (-450288000, T133),
+    (-431535600, T134),
+    (-418233600, T133),
+    (-400086000, T134),
+    (-386784000, T133),
+    (-337186800, T134),
+    (-321465600, T133),
+    (-305737200, T129),
+];
+
+static AMERICA_RESOLUTE: &[(i64, FixedTimespan<'static>)] = &[
+    (-704937600, T129),
+    (73468800, T130),
+    (89190000, T129),
+    (104918400, T130),
+    (120639600, T129),
+    (136368000, T130),
+    (152089200, T129),
+    (167817600, T130),
+    (183538800, T129),
+    (199267200, T130),
+    (215593200, T129),
+    (230716800, T130),
+    (247042800, T129),
+    (262771200, T130),
+    (278492400, T129),
+    (294220800, T130),
+    (309942000, T129),
+    (325670400, T130),
+    (341391600, T129),
+    (357120000, T130),
+    (372841200, T129),
+    (388569600, T130),
+    (404895600, T129),
+    (420019200, T130),
+    (436345200, T129),
+    (452073600, T130),
+    (467794800, T129),
+    (483523200, T130),
+    (499244400, T129),
+    (514972800, T130),
+    (530694000, T129),
+    (544608000, T130),
+    (562143600, T129),
+    (576057600, T130),
+    (594198000, T129),
+    (607507200, T130),
+    (625647600, T129),
+    (638956800, T130),
+    (657097200, T129),
+    (671011200, T130),
+    (688546800, T129),
+    (702460800, T130),
+    (719996400, T129),
+    (733910400, T130),
+    (752050800, T129),
+    (765360000, T130),
+    (783500400, T129),
+    (796809600, T130),
+    (814950000, T129),
+    (828864000, T130),
+    (846399600, T129),
+    (860313600, T130),
+    (877849200, T129),
+    (891763200, T130),
+    (909298800, T129),
+    (923212800, T130),
+    (941353200, T129),
+    (954662400, T130),
+    (1004252400, T129),
+    (1018166400, T130),
+    (1035702000, T129),
+    (1049616000, T130),
+    (1067151600, T129),
+    (1081065600, T130),
+    (1099206000, T129),
+    (1112515200, T130),
+    (1130655600, T129),
+    (1143964800, T130),
+    (1194159600, T129),
+    (1205049600, T130),
+    (1225609200, T129),
+];
+
+static AMERICA_RIO_BRANCO: &[(i64, FixedTimespan<'static>)] = &[
+    (-1767209328, T146),
+    (-1206950400, T147),
+    (-1191355200, T146),
+    (-1175367600, T147),
+    (-1159819200, T146),
+    (-633812400, T147),
+    (-622062000, T146),
+    (-602276400, T147),
+    (-591825600, T146),
+    (-570740400, T147),
+    (-560203200, T146),
+    (-539118000, T147),
+    (-531345600, T146),
+    (-191358000, T147),
+    (-184190400, T146),
+    (-155156400, T147),
+    (-150062400, T146),
+    (-128890800, T147),
+    (-121118400, T146),
+    (-99946800, T147),
+    (-89582400, T146),
+    (-68410800, T147),
+    (-57960000, T146),
+    (499755600, T147),
+    (511243200, T146),
+    (530600400, T147),
+    (540273600, T146),
+    (562136400, T147),
+    (571204800, T146),
+    (1214283600, T112),
+    (1384056000, T146),
+];
+
+static AMERICA_SANTAREM: &[(i64, FixedTimespan<'static>)] = &[
+    (-1767212472, T112),
+    (-1206954000, T113),
+    (-1191358800, T112),
+    (-1175371200, T113),
+    (-1159822800, T112),
+    (-633816000, T113),
+    (-622065600, T112),
+    (-602280000, T113),
+    (-591829200, T112),
+    (-570744000, T113),
+    (-560206800, T112),
+    (-539121600, T113),
+    (-531349200, T112),
+    (-191361600, T113),
+    (-184194000, T112),
+    (-155160000, T113),
+    (-150066000, T112),
+    (-128894400, T113),
+    (-121122000, T112),
+    (-99950400, T113),
+    (-89586000, T112),
+    (-68414400, T113),
+    (-57963600, T112),
+    (499752000, T113),
+    (511239600, T112),
+    (530596800, T113),
+    (540270000, T112),
+    (562132800, T113),
+    (571201200, T112),
+    (1214280000, T108),
+];
+
+static AMERICA_SANTIAGO: &[(i64, FixedTimespan<'static>)] = &[
+    (-1892661435, T146),
+    (-1688410800, T166),
+    (-1619205435, T112),
+    (-1593806400, T166),
+    (-1335986235, T146),
+    (-1335985200, T147),
+    (-1317585600, T146),
+    (-1304362800, T147),
+    (-1286049600, T146),
+    (-1272826800, T147),
+    (-1254513600, T146),
+    (-1241290800, T147),
+    (-1222977600, T146),
+    (-1209754800, T147),
+    (-1191355200, T146),
+    (-1178132400, T112),
+    (-870552000, T146),
+    (-865278000, T112),
+    (-740520000, T113),
+    (-736635600, T147),
+    (-718056000, T146),
+    (-713649600, T112),
+    (-36619200, T113),
+    (-23922000, T112),
+    (-3355200, T113),
+    (7527600, T112),
+    (24465600, T113),
+    (37767600, T112),
+    (55915200, T113),
+    (69217200, T112),
+    (87969600, T113),
+    (100666800, T112),
+    (118209600, T113),
+    (132116400, T112),
+    (150868800, T113),
+    (163566000, T112),
+    (182318400, T113),
+    (195620400, T112),
+    (213768000, T113),
+    (227070000, T112),
+    (245217600, T113),
+    (258519600, T112),
+    (277272000, T113),
+    (289969200, T112),
+    (308721600, T113),
+    (321418800, T112),
+    (340171200, T113),
+    (353473200, T112),
+    (371620800, T113),
+    (384922800, T112),
+    (403070400, T113),
+    (416372400, T112),
+    (434520000, T113),
+    (447822000, T112),
+    (466574400, T113),
+    (479271600, T112),
+    (498024000, T113),
+    (510721200, T112),
+    (529473600, T113),
+    (545194800, T112),
+    (560923200, T113),
+    (574225200, T112),
+    (592372800, T113),
+    (605674800, T112),
+    (624427200, T113),
+    (637124400, T112),
+    (653457600, T113),
+    (668574000, T112),
+    (687326400, T113),
+    (700628400, T112),
+    (718776000, T113),
+    (732078000, T112),
+    (750225600, T113),
+    (763527600, T112),
+    (781675200, T113),
+    (794977200, T112),
+    (813729600, T113),
+    (826426800, T112),
+    (845179200, T113),
+    (859690800, T112),
+    (876628800, T113),
+    (889930800, T112),
+    (906868800, T113),
+    (923194800, T112),
+    (939528000, T113),
+    (952830000, T112),
+    (971582400, T113),
+    (984279600, T112),
+    (1003032000, T113),
+    (1015729200, T112),
+    (1034481600, T113),
+    (1047178800, T112),
+    (1065931200, T113),
+    (1079233200, T112),
+    (1097380800, T113),
+    (1110682800, T112),
+    (1128830400, T113),
+    (1142132400, T112),
+    (1160884800, T113),
+    (1173582000, T112),
+    (1192334400, T113),
+    (1206846000, T112),
+    (1223784000, T113),
+    (1237086000, T112),
+    (1255233600, T113),
+    (1270350000, T112),
+    (1286683200, T113),
+    (1304823600, T112),
+    (1313899200, T113),
+    (1335668400, T112),
+    (1346558400, T113),
+    (1367118000, T112),
+    (1378612800, T113),
+    (1398567600, T112),
+    (1410062400, T113),
+    (1463281200, T112),
+    (1471147200, T113),
+    (1494730800, T112),
+    (1502596800, T113),
+    (1526180400, T112),
+    (1534046400, T113),
+    (1554606000, T112),
+    (1567915200, T113),
+    (1586055600, T112),
+    (1599364800, T113),
+    (1617505200, T112),
+    (1630814400, T113),
+    (1648954800, T112),
+    (1662868800, T113),
+    (1680404400, T112),
+    (1693713600, T113),
+    (1712458800, T112),
+    (1725768000, T113),
+];
+
+static AMERICA_SANTO_DOMINGO: &[(i64, FixedTimespan<'static>)] = &[
+    (-2524504824, T277),
+    (-1159773600, T106),
+    (-100119600, T155),
+    (-89668800, T106),
+    (-5770800, T278),
+    (4422600, T106),
+    (25678800, T278),
+    (33193800, T106),
+    (57733200, T278),
+    (64816200, T106),
+    (89182800, T278),
+    (96438600, T106),
+    (120632400, T278),
+    (127974600, T106),
+    (152082000, T104),
+    (972799200, T106),
+    (975823200, T104),
+];
+
+static AMERICA_SAO_PAULO: &[(i64, FixedTimespan<'static>)] = &[
+    (-1767214412, T108),
+    (-1206957600, T109),
+    (-1191362400, T108),
+    (-1175374800, T109),
+    (-1159826400, T108),
+    (-633819600, T109),
+    (-622069200, T108),
+    (-602283600, T109),
+    (-591832800, T108),
+    (-570747600, T109),
+    (-560210400, T108),
+    (-539125200, T109),
+    (-531352800, T108),
+    (-195426000, T109),
+    (-184197600, T108),
+    (-155163600, T109),
+    (-150069600, T108),
+    (-128898000, T109),
+    (-121125600, T108),
+    (-99954000, T109),
+    (-89589600, T108),
+    (-68418000, T109),
+    (-57967200, T108),
+    (499748400, T109),
+    (511236000, T108),
+    (530593200, T109),
+    (540266400, T108),
+    (562129200, T109),
+    (571197600, T108),
+    (592974000, T109),
+    (602042400, T108),
+    (624423600, T109),
+    (634701600, T108),
+    (656478000, T109),
+    (666756000, T108),
+    (687927600, T109),
+    (697600800, T108),
+    (719982000, T109),
+    (728445600, T108),
+    (750826800, T109),
+    (761709600, T108),
+    (782276400, T109),
+    (793159200, T108),
+    (813726000, T109),
+    (824004000, T108),
+    (844570800, T109),
+    (856058400, T108),
+    (876106800, T109),
+    (888717600, T108),
+    (908074800, T109),
+    (919562400, T108),
+    (938919600, T109),
+    (951616800, T108),
+    (970974000, T109),
+    (982461600, T108),
+    (1003028400, T109),
+    (1013911200, T108),
+    (1036292400, T109),
+    (1045360800, T108),
+    (1066532400, T109),
+    (1076810400, T108),
+    (1099364400, T109),
+    (1108864800, T108),
+    (1129431600, T109),
+    (1140314400, T108),
+    (1162695600, T109),
+    (1172368800, T108),
+    (1192330800, T109),
+    (1203213600, T108),
+    (1224385200, T109),
+    (1234663200, T108),
+    (1255834800, T109),
+    (1266717600, T108),
+    (1287284400, T109),
+    (1298167200, T108),
+    (1318734000, T109),
+    (1330221600, T108),
+    (1350788400, T109),
+    (1361066400, T108),
+    (1382238000, T109),
+    (1392516000, T108),
+    (1413687600, T109),
+    (1424570400, T108),
+    (1445137200, T109),
+    (1456020000, T108),
+    (1476586800, T109),
+    (1487469600, T108),
+    (1508036400, T109),
+    (1518919200, T108),
+    (1541300400, T109),
+    (1550368800, T108),
+];
+
+static AMERICA_SCORESBYSUND: &[(i64, FixedTimespan<'static>)] = &[
+    (-1686090728, T252),
+    (323841600, T253),
+    (338961600, T252),
+    (354679200, T35),
+    (370400400, T17),
+    (386125200, T35),
+    (401850000, T17),
+    (417574800, T35),
+    (433299600, T17),
+    (449024400, T35),
+    (465354000, T17),
+    (481078800, T35),
+    (496803600, T17),
+    (512528400, T35),
+    (528253200, T17),
+    (543978000, T35),
+    (559702800, T17),
+    (575427600, T35),
+    (591152400, T17),
+    (606877200, T35),
+    (622602000, T17),
+    (638326800, T35),
+    (654656400, T17),
+    (670381200, T35),
+    (686106000, T17),
+    (701830800, T35),
+    (717555600, T17),
+    (733280400, T35),
+    (749005200, T17),
+    (764730000, T35),
+    (780454800, T17),
+    (796179600, T35),
+    (811904400, T17),
+    (828234000, T35),
+    (846378000, T17),
+    (859683600, T35),
+    (877827600, T17),
+    (891133200, T35),
+    (909277200, T17),
+    (922582800, T35),
+    (941331600, T17),
+    (954032400, T35),
+    (972781200, T17),
+    (985482000, T35),
+    (1004230800, T17),
+    (1017536400, T35),
+    (1035680400, T17),
+    (1048986000, T35),
+    (1067130000, T17),
+    (1080435600, T35),
+    (1099184400, T17),
+    (1111885200, T35),
+    (1130634000, T17),
+    (1143334800, T35),
+    (1162083600, T17),
+    (1174784400, T35),
+    (1193533200, T17),
+    (1206838800, T35),
+    (1224982800, T17),
+    (1238288400, T35),
+    (1256432400, T17),
+    (1269738000, T35),
+    (1288486800, T17),
+    (1301187600, T35),
+    (1319936400, T17),
+    (1332637200, T35),
+    (1351386000, T17),
+    (1364691600, T35),
+    (1382835600, T17),
+    (1396141200, T35),
+    (1414285200, T17),
+    (1427590800, T35),
+    (1445734800, T17),
+    (1459040400, T35),
+    (1477789200, T17),
+    (1490490000, T35),
+    (1509238800, T17),
+    (1521939600, T35),
+    (1540688400, T17),
+    (1553994000, T35),
+    (1572138000, T17),
+    (1585443600, T35),
+    (1603587600, T17),
+    (1616893200, T35),
+    (1635642000, T17),
+    (1648342800, T35),
+    (1667091600, T17),
+    (1679792400, T35),
+    (1698541200, T17),
+    (1729990800, T252),
+    (1743296400, T253),
+    (1761440400, T252),
+];
+
+static AMERICA_SITKA: &[(i64, FixedTimespan<'static>)] = &[
+    (-3225223727, T282),
+    (-2188954727, T149),
+    (-880207200, T177),
+    (-765385200, T149),
+    (-21477600, T150),
+    (-5756400, T149),
+    (9972000, T150),
+    (25693200, T149),
+    (41421600, T150),
+    (57747600, T149),
+    (73476000, T150),
+    (89197200, T149),
+    (104925600, T150),
+    (120646800, T149),
+    (126698400, T150),
+    (152096400, T149),
+    (162381600, T150),
+    (183546000, T149),
+    (199274400, T150),
+    (215600400, T149),
+    (230724000, T150),
+    (247050000, T149),
+    (262778400, T150),
+    (278499600, T149),
+    (294228000, T150),
+    (309949200, T149),
+    (325677600, T150),
+    (341398800, T149),
+    (357127200, T150),
+    (372848400, T149),
+    (388576800, T150),
+    (404902800, T149),
+    (420026400, T150),
+    (436352400, T173),
+    (436356000, T172),
+    (452084400, T101),
+    (467805600, T102),
+    (483534000, T101),
+    (499255200, T102),
+    (514983600, T101),
+    (530704800, T102),
+    (544618800, T101),
+    (562154400, T102),
+    (576068400, T101),
+    (594208800, T102),
+    (607518000, T101),
+    (625658400, T102),
+    (638967600, T101),
+    (657108000, T102),
+    (671022000, T101),
+    (688557600, T102),
+    (702471600, T101),
+    (720007200, T102),
+    (733921200, T101),
+    (752061600, T102),
+    (765370800, T101),
+    (783511200, T102),
+    (796820400, T101),
+    (814960800, T102),
+    (828874800, T101),
+    (846410400, T102),
+    (860324400, T101),
+    (877860000, T102),
+    (891774000, T101),
+    (909309600, T102),
+    (923223600, T101),
+    (941364000, T102),
+    (954673200, T101),
+    (972813600, T102),
+    (986122800, T101),
+    (1004263200, T102),
+    (1018177200, T101),
+    (1035712800, T102),
+    (1049626800, T101),
+    (1067162400, T102),
+    (1081076400, T101),
+    (1099216800, T102),
+    (1112526000, T101),
+    (1130666400, T102),
+    (1143975600, T101),
+    (1162116000, T102),
+    (1173610800, T101),
+    (1194170400, T102),
+    (1205060400, T101),
+    (1225620000, T102),
+];
+
+static AMERICA_ST_JOHNS: &[(i64, FixedTimespan<'static>)] = &[
+    (-1664130548, T190),
+    (-1650137348, T189),
+    (-1632076148, T190),
+    (-1615145348, T189),
+    (-1598650148, T190),
+    (-1590100148, T189),
+    (-1567286948, T190),
+    (-1551565748, T189),
+    (-1535837348, T190),
+    (-1520116148, T189),
+    (-1503782948, T190),
+    (-1488666548, T189),
+    (-1472333348, T190),
+    (-1457216948, T189),
+    (-1440883748, T190),
+    (-1425767348, T189),
+    (-1409434148, T190),
+    (-1394317748, T189),
+    (-1377984548, T190),
+    (-1362263348, T189),
+    (-1346534948, T190),
+    (-1330813748, T189),
+    (-1314480548, T190),
+    (-1299364148, T189),
+    (-1283030948, T190),
+    (-1267914548, T189),
+    (-1251581348, T190),
+    (-1236464948, T189),
+    (-1220131748, T190),
+    (-1205015348, T189),
+    (-1188682148, T190),
+    (-1172960948, T189),
+    (-1156627748, T190),
+    (-1141511348, T189),
+    (-1125178148, T190),
+    (-1110061748, T189),
+    (-1096921748, T191),
+    (-1093728600, T192),
+    (-1078612200, T191),
+    (-1061670600, T192),
+    (-1048973400, T191),
+    (-1030221000, T192),
+    (-1017523800, T191),
+    (-998771400, T192),
+    (-986074200, T191),
+    (-966717000, T192),
+    (-954624600, T191),
+    (-935267400, T192),
+    (-922570200, T191),
+    (-903817800, T192),
+    (-891120600, T191),
+    (-872368200, T193),
+    (-765401400, T191),
+    (-746044200, T192),
+    (-733347000, T191),
+    (-714594600, T192),
+    (-701897400, T191),
+    (-683145000, T192),
+    (-670447800, T191),
+    (-651695400, T192),
+    (-638998200, T191),
+    (-619641000, T192),
+    (-606943800, T191),
+    (-589401000, T192),
+    (-576099000, T191),
+    (-557951400, T192),
+    (-544649400, T191),
+    (-526501800, T192),
+    (-513199800, T191),
+    (-495052200, T192),
+    (-481750200, T191),
+    (-463602600, T192),
+    (-450300600, T191),
+    (-431548200, T192),
+    (-418246200, T191),
+    (-400098600, T192),
+    (-386796600, T191),
+    (-368649000, T192),
+    (-355347000, T191),
+    (-337199400, T192),
+    (-323897400, T191),
+    (-305749800, T192),
+    (-289423800, T191),
+    (-273695400, T192),
+    (-257974200, T191),
+    (-242245800, T192),
+    (-226524600, T191),
+    (-210796200, T192),
+    (-195075000, T191),
+    (-179346600, T192),
+    (-163625400, T191),
+    (-147897000, T192),
+    (-131571000, T191),
+    (-116447400, T192),
+    (-100121400, T191),
+    (-84393000, T192),
+    (-68671800, T191),
+    (-52943400, T192),
+    (-37222200, T191),
+    (-21493800, T192),
+    (-5772600, T191),
+    (9955800, T192),
+    (25677000, T191),
+    (41405400, T192),
+    (57731400, T191),
+    (73459800, T192),
+    (89181000, T191),
+    (104909400, T192),
+    (120630600, T191),
+    (136359000, T192),
+    (152080200, T191),
+    (167808600, T192),
+    (183529800, T191),
+    (199258200, T192),
+    (215584200, T191),
+    (230707800, T192),
+    (247033800, T191),
+    (262762200, T192),
+    (278483400, T191),
+    (294211800, T192),
+    (309933000, T191),
+    (325661400, T192),
+    (341382600, T191),
+    (357111000, T192),
+    (372832200, T191),
+    (388560600, T192),
+    (404886600, T191),
+    (420010200, T192),
+    (436336200, T191),
+    (452064600, T192),
+    (467785800, T191),
+    (483514200, T192),
+    (499235400, T191),
+    (514963800, T192),
+    (530685000, T191),
+    (544591860, T192),
+    (562127460, T191),
+    (576041460, T284),
+    (594178260, T191),
+    (607491060, T192),
+    (625631460, T191),
+    (638940660, T192),
+    (657081060, T191),
+    (670995060, T192),
+    (688530660, T191),
+    (702444660, T192),
+    (719980260, T191),
+    (733894260, T192),
+    (752034660, T191),
+    (765343860, T192),
+    (783484260, T191),
+    (796793460, T192),
+    (814933860, T191),
+    (828847860, T192),
+    (846383460, T191),
+    (860297460, T192),
+    (877833060, T191),
+    (891747060, T192),
+    (909282660, T191),
+    (923196660, T192),
+    (941337060, T191),
+    (954646260, T192),
+    (972786660, T191),
+    (986095860, T192),
+    (1004236260, T191),
+    (1018150260, T192),
+    (1035685860, T191),
+    (1049599860, T192),
+    (1067135460, T191),
+    (1081049460, T192),
+    (1099189860, T191),
+    (1112499060, T192),
+    (1130639460, T191),
+    (1143948660, T192),
+    (1162089060, T191),
+    (1173583860, T192),
+    (1194143460, T191),
+    (1205033460, T192),
+    (1225593060, T191),
+    (1236483060, T192),
+    (1257042660, T191),
+    (1268537460, T192),
+    (1289097060, T191),
+    (1299987060, T192),
+    (1320553800, T191),
+    (1331443800, T192),
+    (1352003400, T191),
+];
+
+static AMERICA_ST_KITTS: &[(i64, FixedTimespan<'static>)] = &[
+    (-1825098548, T104),
+];
+
+static AMERICA_ST_LUCIA: &[(i64, FixedTimespan<'static>)] = &[
+    (-1830369360, T104),
+];
+
+static AMERICA_ST_THOMAS: &[(i64, FixedTimespan<'static>)] = &[
+    (-1846266016, T104),
+];
+
+static AMERICA_ST_VINCENT: &[(i64, FixedTimespan<'static>)] = &[
+    (-1830369304, T104),
+];
+
+static AMERICA_SWIFT_CURRENT: &[(i64, FixedTimespan<'static>)] = &[
+    (-2030201320, T133),
+    (-1632063600, T134),
+    (-1615132800, T133),
+    (-880210800, T151),
+    (-765388800, T133),
+    (-747241200, T134),
+    (-732729600, T133),
+    (-715791600, T134),
+    (-702489600, T133),
+    (-684342000, T134),
+    (-671040000, T133),
+    (-652892400, T134),
+    (-639590400, T133),
+    (-400086000, T134),
+    (-384364800, T133),
+    (-337186800, T134),
+    (-321465600, T133),
+    (-305737200, T134),
+    (-292435200, T133),
+    (-273682800, T134),
+    (-260985600, T133),
+    (73472400, T129),
+];
+
+static AMERICA_TEGUCIGALPA: &[(i64, FixedTimespan<'static>)] = &[
+    (-1538503868, T129),
+    (547020000, T130),
+    (559717200, T129),
+    (578469600, T130),
+    (591166800, T129),
+    (1146981600, T130),
+    (1154926800, T129),
+];
+
+static AMERICA_THULE: &[(i64, FixedTimespan<'static>)] = &[
+    (-1686079492, T104),
+    (670399200, T136),
+    (686120400, T104),
+    (701848800, T136),
+    (717570000, T104),
+    (733903200, T136),
+    (752043600, T104),
+    (765352800, T136),
+    (783493200, T104),
+    (796802400, T136),
+    (814942800, T104),
+    (828856800, T136),
+    (846392400, T104),
+    (860306400, T136),
+    (877842000, T104),
+    (891756000, T136),
+    (909291600, T104),
+    (923205600, T136),
+    (941346000, T104),
+    (954655200, T136),
+    (972795600, T104),
+    (986104800, T136),
+    (1004245200, T104),
+    (1018159200, T136),
+    (1035694800, T104),
+    (1049608800, T136),
+    (1067144400, T104),
+    (1081058400, T136),
+    (1099198800, T104),
+    (1112508000, T136),
+    (1130648400, T104),
+    (1143957600, T136),
+    (1162098000, T104),
+    (1173592800, T136),
+    (1194152400, T104),
+    (1205042400, T136),
+    (1225602000, T104),
+];
+
+static AMERICA_TIJUANA: &[(i64, FixedTimespan<'static>)] = &[
+    (-1514739600, T133),
+    (-1451667600, T149),
+    (-1343145600, T133),
+    (-1234803600, T149),
+    (-1222963200, T150),
+    (-1207242000, T149),
+    (-873820800, T177),
+    (-761418000, T149),
+    (-686073600, T150),
+    (-661539600, T149),
+    (-620755200, T150),
+    (-608144400, T149),
+    (-589384800, T150),
+    (-576082800, T149),
+    (-557935200, T150),
+    (-544633200, T149),
+    (-495039600, T150),
+    (-481734000, T149),
+    (-463590000, T150),
+    (-450284400, T149),
+    (-431535600, T150),
+    (-418230000, T149),
+    (-400086000, T150),
+    (-386780400, T149),
+    (-368636400, T150),
+    (-355330800, T149),
+    (-337186800, T150),
+    (-323881200, T149),
+    (-305737200, T150),
+    (-292431600, T149),
+    (199274400, T150),
+    (215600400, T149),
+    (230724000, T150),
+    (247050000, T149),
+    (262778400, T150),
+    (278499600, T149),
+    (294228000, T150),
+    (309949200, T149),
+    (325677600, T150),
+    (341398800, T149),
+    (357127200, T150),
+    (372848400, T149),
+    (388576800, T150),
+    (404902800, T149),
+    (420026400, T150),
+    (436352400, T149),
+    (452080800, T150),
+    (467802000, T149),
+    (483530400, T150),
+    (499251600, T149),
+    (514980000, T150),
+    (530701200, T149),
+    (544615200, T150),
+    (562150800, T149),
+    (576064800, T150),
+    (594205200, T149),
+    (607514400, T150),
+    (625654800, T149),
+    (638964000, T150),
+    (657104400, T149),
+    (671018400, T150),
+    (688554000, T149),
+    (702468000, T150),
+    (720003600, T149),
+    (733917600, T150),
+    (752058000, T149),
+    (765367200, T150),
+    (783507600, T149),
+    (796816800, T150),
+    (814957200, T149),
+    (828871200, T150),
+    (846406800, T149),
+    (860320800, T150),
+    (877856400, T149),
+    (891770400, T150),
+    (909306000, T149),
+    (923220000, T150),
+    (941360400, T149),
+    (954669600, T150),
+    (972810000, T149),
+    (986119200, T150),
+    (1004259600, T149),
+    (1018173600, T150),
+    (1035709200, T149),
+    (1049623200, T150),
+    (1067158800, T149),
+    (1081072800, T150),
+    (1099213200, T149),
+    (1112522400, T150),
+    (1130662800, T149),
+    (1143972000, T150),
+    (1162112400, T149),
+    (1175421600, T150),
+    (1193562000, T149),
+    (1207476000, T150),
+    (1225011600, T149),
+    (1238925600, T150),
+    (1256461200, T149),
+    (1268560800, T150),
+    (1289120400, T149),
+    (1300010400, T150),
+    (1320570000, T149),
+];
+
+static AMERICA_TORONTO: &[(i64, FixedTimespan<'static>)] = &[
+    (-2366736148, T106),
+    (-1632070800, T155),
+    (-1615140000, T106),
+    (-1601753400, T155),
+    (-1583697600, T106),
+    (-1567357200, T155),
+    (-1554667200, T106),
+    (-1534698000, T155),
+    (-1524074400, T106),
+    (-1503248400, T155),
+    (-1492365600, T106),
+    (-1471798800, T155),
+    (-1460916000, T106),
+    (-1440954000, T155),
+    (-1428861600, T106),
+    (-1409504400, T155),
+    (-1397412000, T106),
+    (-1378054800, T155),
+    (-1365962400, T106),
+    (-1346605200, T155),
+    (-1333908000, T106),
+    (-1315155600, T155),
+    (-1301853600, T106),
+    (-1283706000, T155),
+    (-1270404000, T106),
+    (-1252256400, T155),
+    (-1238954400, T106),
+    (-1220806800, T155),
+    (-1207504800, T106),
+    (-1188752400, T155),
+    (-1176055200, T106),
+    (-1157302800, T155),
+    (-1144000800, T106),
+    (-1125853200, T155),
+    (-1112551200, T106),
+    (-1094403600, T155),
+    (-1081101600, T106),
+    (-1062954000, T155),
+    (-1049652000, T106),
+    (-1031504400, T155),
+    (-1018202400, T106),
+    (-1000054800, T155),
+    (-986752800, T106),
+    (-968000400, T155),
+    (-955303200, T106),
+    (-936550800, T155),
+    (-765396000, T106),
+    (-747248400, T155),
+    (-733946400, T106),
+    (-715798800, T155),
+    (-702496800, T106),
+    (-684349200, T155),
+    (-671047200, T106),
+    (-652899600, T155),
+    (-634154400, T106),
+    (-620845200, T155),
+    (-602704800, T106),
+    (-589395600, T155),
+    (-576093600, T106),
+    (-557946000, T155),
+    (-544644000, T106),
+    (-526496400, T155),
+    (-513194400, T106),
+    (-495046800, T155),
+    (-481744800, T106),
+    (-463597200, T155),
+    (-450295200, T106),
+    (-431542800, T155),
+    (-418240800, T106),
+    (-400093200, T155),
+    (-384372000, T106),
+    (-368643600, T155),
+    (-352922400, T106),
+    (-337194000, T155),
+    (-321472800, T106),
+    (-305744400, T155),
+    (-289418400, T106),
+    (-273690000, T155),
+    (-257968800, T106),
+    (-242240400, T155),
+    (-226519200, T106),
+    (-210790800, T155),
+    (-195069600, T106),
+    (-179341200, T155),
+    (-163620000, T106),
+    (-147891600, T155),
+    (-131565600, T106),
+    (-116442000, T155),
+    (-100116000, T106),
+    (-84387600, T155),
+    (-68666400, T106),
+    (-52938000, T155),
+    (-37216800, T106),
+    (-21488400, T155),
+    (-5767200, T106),
+    (9961200, T155),
+    (25682400, T106),
+    (41410800, T155),
+    (57736800, T106),
+    (73465200, T155),
+    (89186400, T106),
+    (104914800, T155),
+    (120636000, T106),
+    (136364400, T155),
+    (152085600, T106),
+    (167814000, T155),
+    (183535200, T106),
+    (199263600, T155),
+    (215589600, T106),
+    (230713200, T155),
+    (247039200, T106),
+    (262767600, T155),
+    (278488800, T106),
+    (294217200, T155),
+    (309938400, T106),
+    (325666800, T155),
+    (341388000, T106),
+    (357116400, T155),
+    (372837600, T106),
+    (388566000, T155),
+    (404892000, T106),
+    (420015600, T155),
+    (436341600, T106),
+    (452070000, T155),
+    (467791200, T106),
+    (483519600, T155),
+    (499240800, T106),
+    (514969200, T155),
+    (530690400, T106),
+    (544604400, T155),
+    (562140000, T106),
+    (576054000, T155),
+    (594194400, T106),
+    (607503600, T155),
+    (625644000, T106),
+    (638953200, T155),
+    (657093600, T106),
+    (671007600, T155),
+    (688543200, T106),
+    (702457200, T155),
+    (719992800, T106),
+    (733906800, T155),
+    (752047200, T106),
+    (765356400, T155),
+    (783496800, T106),
+    (796806000, T155),
+    (814946400, T106),
+    (828860400, T155),
+    (846396000, T106),
+    (860310000, T155),
+    (877845600, T106),
+    (891759600, T155),
+    (909295200, T106),
+    (923209200, T155),
+    (941349600, T106),
+    (954658800, T155),
+    (972799200, T106),
+    (986108400, T155),
+    (1004248800, T106),
+    (1018162800, T155),
+    (1035698400, T106),
+    (1049612400, T155),
+    (1067148000, T106),
+    (1081062000, T155),
+    (1099202400, T106),
+    (1112511600, T155),
+    (1130652000, T106),
+    (1143961200, T155),
+    (1162101600, T106),
+    (1173596400, T155),
+    (1194156000, T106),
+    (1205046000, T155),
+    (1225605600, T106),
+];
+
+static AMERICA_TORTOLA: &[(i64, FixedTimespan<'static>)] = &[
+    (-1846266092, T104),
+];
+
+static AMERICA_VANCOUVER: &[(i64, FixedTimespan<'static>)] = &[
+    (-2713880852, T149),
+    (-1632060000, T150),
+    (-1615129200, T149),
+    (-880207200, T177),
+    (-765385200, T149),
+    (-747237600, T150),
+    (-733935600, T149),
+    (-715788000, T150),
+    (-702486000, T149),
+    (-684338400, T150),
+    (-671036400, T149),
+    (-652888800, T150),
+    (-639586800, T149),
+    (-620834400, T150),
+    (-608137200, T149),
+    (-589384800, T150),
+    (-576082800, T149),
+    (-557935200, T150),
+    (-544633200, T149),
+    (-526485600, T150),
+    (-513183600, T149),
+    (-495036000, T150),
+    (-481734000, T149),
+    (-463586400, T150),
+    (-450284400, T149),
+    (-431532000, T150),
+    (-418230000, T149),
+    (-400082400, T150),
+    (-386780400, T149),
+    (-368632800, T150),
+    (-355330800, T149),
+    (-337183200, T150),
+    (-323881200, T149),
+    (-305733600, T150),
+    (-292431600, T149),
+    (-273679200, T150),
+    (-260982000, T149),
+    (-242229600, T150),
+    (-226508400, T149),
+    (-210780000, T150),
+    (-195058800, T149),
+    (-179330400, T150),
+    (-163609200, T149),
+    (-147880800, T150),
+    (-131554800, T149),
+    (-116431200, T150),
+    (-100105200, T149),
+    (-84376800, T150),
+    (-68655600, T149),
+    (-52927200, T150),
+    (-37206000, T149),
+    (-21477600, T150),
+    (-5756400, T149),
+    (9972000, T150),
+    (25693200, T149),
+    (41421600, T150),
+    (57747600, T149),
+    (73476000, T150),
+    (89197200, T149),
+    (104925600, T150),
+    (120646800, T149),
+    (136375200, T150),
+    (152096400, T149),
+    (167824800, T150),
+    (183546000, T149),
+    (199274400, T150),
+    (215600400, T149),
+    (230724000, T150),
+    (247050000, T149),
+    (262778400, T150),
+    (278499600, T149),
+    (294228000, T150),
+    (309949200, T149),
+    (325677600, T150),
+    (341398800, T149),
+    (357127200, T150),
+    (372848400, T149),
+    (388576800, T150),
+    (404902800, T149),
+    (420026400, T150),
+    (436352400, T149),
+    (452080800, T150),
+    (467802000, T149),
+    (483530400, T150),
+    (499251600, T149),
+    (514980000, T150),
+    (530701200, T149),
+    (544615200, T150),
+    (562150800, T149),
+    (576064800, T150),
+    (594205200, T149),
+    (607514400, T150),
+    (625654800, T149),
+    (638964000, T150),
+    (657104400, T149),
+    (671018400, T150),
+    (688554000, T149),
+    (702468000, T150),
+    (720003600, T149),
+    (733917600, T150),
+    (752058000, T149),
+    (765367200, T150),
+    (783507600, T149),
+    (796816800, T150),
+    (814957200, T149),
+    (828871200, T150),
+    (846406800, T149),
+    (860320800, T150),
+    (877856400, T149),
+    (891770400, T150),
+    (909306000, T149),
+    (923220000, T150),
+    (941360400, T149),
+    (954669600, T150),
+    (972810000, T149),
+    (986119200, T150),
+    (1004259600, T149),
+    (1018173600, T150),
+    (1035709200, T149),
+    (1049623200, T150),
+    (1067158800, T149),
+    (1081072800, T150),
+    (1099213200, T149),
+    (1112522400, T150),
+    (1130662800, T149),
+    (1143972000, T150),
+    (1162112400, T149),
+    (1173607200, T150),
+    (1194166800, T149),
+    (1205056800, T150),
+    (1225616400, T149),
+];
+
+static AMERICA_WHITEHORSE: &[(i64, FixedTimespan<'static>)] = &[
+    (-2188997988, T172),
+    (-1632056400, T173),
+    (-1615125600, T172),
+    (-1596978000, T173),
+    (-1583164800, T172),
+    (-880203600, T174),
+    (-765381600, T172),
+    (-147884400, T175),
+    (-131554800, T172),
+    (-121273200, T149),
+    (325677600, T150),
+    (341398800, T149),
+    (357127200, T150),
+    (372848400, T149),
+    (388576800, T150),
+    (404902800, T149),
+    (420026400, T150),
+    (436352400, T149),
+    (452080800, T150),
+    (467802000, T149),
+    (483530400, T150),
+    (499251600, T149),
+    (514980000, T150),
+    (530701200, T149),
+    (544615200, T150),
+    (562150800, T149),
+    (576064800, T150),
+    (594205200, T149),
+    (607514400, T150),
+    (625654800, T149),
+    (638964000, T150),
+    (657104400, T149),
+    (671018400, T150),
+    (688554000, T149),
+    (702468000, T150),
+    (720003600, T149),
+    (733917600, T150),
+    (752058000, T149),
+    (765367200, T150),
+    (783507600, T149),
+    (796816800, T150),
+    (814957200, T149),
+    (828871200, T150),
+    (846406800, T149),
+    (860320800, T150),
+    (877856400, T149),
+    (891770400, T150),
+    (909306000, T149),
+    (923220000, T150),
+    (941360400, T149),
+    (954669600, T150),
+    (972810000, T149),
+    (986119200, T150),
+    (1004259600, T149),
+    (1018173600, T150),
+    (1035709200, T149),
+    (1049623200, T150),
+    (1067158800, T149),
+    (1081072800, T150),
+    (1099213200, T149),
+    (1112522400, T150),
+    (1130662800, T149),
+    (1143972000, T150),
+    (1162112400, T149),
+    (1173607200, T150),
+    (1194166800, T149),
+    (1205056800, T150),
+    (1225616400, T149),
+    (1236506400, T150),
+    (1257066000, T149),
+    (1268560800, T150),
+    (1289120400, T149),
+    (1300010400, T150),
+    (1320570000, T149),
+    (1331460000, T150),
+    (1352019600, T149),
+    (1362909600, T150),
+    (1383469200, T149),
+    (1394359200, T150),
+    (1414918800, T149),
+    (1425808800, T150),
+    (1446368400, T149),
+    (1457863200, T150),
+    (1478422800, T149),
+    (1489312800, T150),
+    (1509872400, T149),
+    (1520762400, T150),
+    (1541322000, T149),
+    (1552212000, T150),
+    (1572771600, T149),
+    (1583661600, T150),
+];
+
+static AMERICA_WINNIPEG: &[(i64, FixedTimespan<'static>)] = &[
+    (-2602258284, T129),
+    (-1694368800, T130),
+    (-1681671600, T129),
+    (-1632067200, T130),
+    (-1615136400, T129),
+    (-1029686400, T130),
+    (-1018198800, T129),
+    (-880214400, T141),
+    (-765392400, T129),
+    (-746035200, T130),
+    (-732733200, T129),
+    (-715795200, T130),
+    (-702493200, T129),
+    (-684345600, T130),
+    (-671043600, T129),
+    (-652896000, T130),
+    (-639594000, T129),
+    (-620755200, T130),
+    (-607626000, T129),
+    (-589392000, T130),
+    (-576090000, T129),
+    (-557942400, T130),
+    (-544640400, T129),
+    (-526492800, T130),
+    (-513190800, T129),
+    (-495043200, T130),
+    (-481741200, T129),
+    (-463593600, T130),
+    (-450291600, T129),
+    (-431539200, T130),
+    (-418237200, T129),
+    (-400089600, T130),
+    (-386787600, T129),
+    (-368640000, T130),
+    (-355338000, T129),
+    (-337190400, T130),
+    (-321469200, T129),
+    (-305740800, T130),
+    (-292438800, T129),
+    (-210787200, T130),
+    (-198090000, T129),
+    (-116438400, T130),
+    (-100108800, T129),
+    (-84384000, T130),
+    (-68659200, T129),
+    (-52934400, T130),
+    (-37209600, T129),
+    (-21484800, T130),
+    (-5760000, T129),
+    (9964800, T130),
+    (25689600, T129),
+    (41414400, T130),
+    (57744000, T129),
+    (73468800, T130),
+    (89193600, T129),
+    (104918400, T130),
+    (120643200, T129),
+    (136368000, T130),
+    (152092800, T129),
+    (167817600, T130),
+    (183542400, T129),
+    (199267200, T130),
+    (215596800, T129),
+    (230716800, T130),
+    (247046400, T129),
+    (262771200, T130),
+    (278496000, T129),
+    (294220800, T130),
+    (309945600, T129),
+    (325670400, T130),
+    (341395200, T129),
+    (357120000, T130),
+    (372844800, T129),
+    (388569600, T130),
+    (404899200, T129),
+    (420019200, T130),
+    (436348800, T129),
+    (452073600, T130),
+    (467798400, T129),
+    (483523200, T130),
+    (499248000, T129),
+    (514972800, T130),
+    (530697600, T129),
+    (544608000, T130),
+    (562147200, T129),
+    (576057600, T130),
+    (594201600, T129),
+    (607507200, T130),
+    (625651200, T129),
+    (638956800, T130),
+    (657100800, T129),
+    (671011200, T130),
+    (688550400, T129),
+    (702460800, T130),
+    (720000000, T129),
+    (733910400, T130),
+    (752054400, T129),
+    (765360000, T130),
+    (783504000, T129),
+    (796809600, T130),
+    (814953600, T129),
+    (828864000, T130),
+    (846403200, T129),
+    (860313600, T130),
+    (877852800, T129),
+    (891763200, T130),
+    (909302400, T129),
+    (923212800, T130),
+    (941356800, T129),
+    (954662400, T130),
+    (972806400, T129),
+    (986112000, T130),
+    (1004256000, T129),
+    (1018166400, T130),
+    (1035705600, T129),
+    (1049616000, T130),
+    (1067155200, T129),
+    (1081065600, T130),
+    (1099209600, T129),
+    (1112515200, T130),
+    (1130659200, T129),
+    (1143964800, T130),
+    (1162105200, T129),
+    (1173600000, T130),
+    (1194159600, T129),
+    (1205049600, T130),
+    (1225609200, T129),
+];
+
+static AMERICA_YAKUTAT: &[(i64, FixedTimespan<'static>)] = &[
+    (-3225223727, T299),
+    (-2188953665, T172),
+    (-880203600, T174),
+    (-765381600, T172),
+    (-21474000, T173),
+    (-5752800, T172),
+    (9975600, T173),
+    (25696800, T172),
+    (41425200, T173),
+    (57751200, T172),
+    (73479600, T173),
+    (89200800, T172),
+    (104929200, T173),
+    (120650400, T172),
+    (126702000, T173),
+    (152100000, T172),
+    (162385200, T173),
+    (183549600, T172),
+    (199278000, T173),
+    (215604000, T172),
+    (230727600, T173),
+    (247053600, T172),
+    (262782000, T173),
+    (278503200, T172),
+    (294231600, T173),
+    (309952800, T172),
+    (325681200, T173),
+    (341402400, T172),
+    (357130800, T173),
+    (372852000, T172),
+    (388580400, T173),
+    (404906400, T172),
+    (420030000, T173),
+    (436356000, T172),
+    (452084400, T101),
+    (467805600, T102),
+    (483534000, T101),
+    (499255200, T102),
+    (514983600, T101),
+    (530704800, T102),
+    (544618800, T101),
+    (562154400, T102),
+    (576068400, T101),
+    (594208800, T102),
+    (607518000, T101),
+    (625658400, T102),
+    (638967600, T101),
+    (657108000, T102),
+    (671022000, T101),
+    (688557600, T102),
+    (702471600, T101),
+    (720007200, T102),
+    (733921200, T101),
+    (752061600, T102),
+    (765370800, T101),
+    (783511200, T102),
+    (796820400, T101),
+    (814960800, T102),
+    (828874800, T101),
+    (846410400, T102),
+    (860324400, T101),
+    (877860000, T102),
+    (891774000, T101),
+    (909309600, T102),
+    (923223600, T101),
+    (941364000, T102),
+    (954673200, T101),
+    (972813600, T102),
+    (986122800, T101),
+    (1004263200, T102),
+    (1018177200, T101),
+    (1035712800, T102),
+    (1049626800, T101),
+    (1067162400, T102),
+    (1081076400, T101),
+    (1099216800, T102),
+    (1112526000, T101),
+    (1130666400, T102),
+    (1143975600, T101),
+    (1162116000, T102),
+    (1173610800, T101),
+    (1194170400, T102),
+    (1205060400, T101),
+    (1225620000, T102),
+];
+
+static ANTARCTICA_CASEY: &[(i64, FixedTimespan<'static>)] = &[
+    (-31536000, T300),
+    (1255802400, T301),
+    (1267714800, T300),
+    (1319738400, T301),
+    (1329843600, T300),
+    (1477065600, T301),
+    (1520701200, T300),
+    (1538856000, T301),
+    (1552752000, T300),
+    (1570129200, T301),
+    (1583596800, T300),
+    (1601740860, T301),
+    (1615640400, T300),
+    (1633190460, T301),
+    (1647090000, T300),
+    (1664640060, T301),
+    (1678291200, T300),
+];
+
+static ANTARCTICA_DAVIS: &[(i64, FixedTimespan<'static>)] = &[
+    (-409190400, T302),
+    (-163062000, T152),
+    (-28857600, T302),
+    (1255806000, T303),
+    (1268251200, T302),
+    (1319742000, T303),
+    (1329854400, T302),
+];
+
+static ANTARCTICA_DUMONTDURVILLE: &[(i64, FixedTimespan<'static>)] = &[
+    (-725846400, T304),
+    (-566992800, T152),
+    (-415497600, T304),
+];
+
+static ANTARCTICA_MACQUARIE: &[(i64, FixedTimespan<'static>)] = &[
+    (-2214259200, T305),
+    (-1680508800, T306),
+    (-1665388800, T305),
+    (-1601719200, T152),
+    (-687052800, T305),
+    (-71136000, T306),
+    (-55411200, T305),
+    (-37267200, T306),
+    (-25776000, T305),
+    (-5817600, T306),
+    (5673600, T305),
+    (25632000, T306),
+    (37728000, T305),
+    (57686400, T306),
+    (67968000, T305),
+    (89136000, T306),
+    (100022400, T305),
+    (120585600, T306),
+    (131472000, T305),
+    (152035200, T306),
+    (162921600, T305),
+    (183484800, T306),
+    (194976000, T305),
+    (215539200, T306),
+    (226425600, T305),
+    (246988800, T306),
+    (257875200, T305),
+    (278438400, T306),
+    (289324800, T305),
+    (309888000, T306),
+    (320774400, T305),
+    (341337600, T306),
+    (352224000, T305),
+    (372787200, T306),
+    (386092800, T305),
+    (404841600, T306),
+    (417542400, T305),
+    (436291200, T306),
+    (447177600, T305),
+    (467740800, T306),
+    (478627200, T305),
+    (499190400, T306),
+    (510076800, T305),
+    (530035200, T306),
+    (542736000, T305),
+    (562089600, T306),
+    (574790400, T305),
+    (594144000, T306),
+    (606240000, T305),
+    (625593600, T306),
+    (637689600, T305),
+    (657043200, T306),
+    (670348800, T305),
+    (686678400, T306),
+    (701798400, T305),
+    (718128000, T306),
+    (733248000, T305),
+    (749577600, T306),
+    (764697600, T305),
+    (781027200, T306),
+    (796147200, T305),
+    (812476800, T306),
+    (828201600, T305),
+    (844531200, T306),
+    (859651200, T305),
+    (875980800, T306),
+    (891100800, T305),
+    (907430400, T306),
+    (922550400, T305),
+    (938880000, T306),
+    (954000000, T305),
+    (967305600, T306),
+    (985449600, T305),
+    (1002384000, T306),
+    (1017504000, T305),
+    (1033833600, T306),
+    (1048953600, T305),
+    (1065283200, T306),
+    (1080403200, T305),
+    (1096732800, T306),
+    (1111852800, T305),
+    (1128182400, T306),
+    (1143907200, T305),
+    (1159632000, T306),
+    (1174752000, T305),
+    (1191686400, T306),
+    (1207411200, T305),
+    (1223136000, T306),
+    (1238860800, T305),
+    (1254585600, T306),
+    (1301760000, T305),
+    (1317484800, T306),
+];
+
+static ANTARCTICA_MAWSON: &[(i64, FixedTimespan<'static>)] = &[
+    (-501206400, T307),
+    (1255809600, T303),
+];
+
+static ANTARCTICA_MCMURDO: &[(i64, FixedTimespan<'static>)] = &[
+    (-441849600, T308),
+    (152632800, T309),
+    (162309600, T308),
+    (183477600, T309),
+    (194968800, T308),
+    (215532000, T309),
+    (226418400, T308),
+    (246981600, T309),
+    (257868000, T308),
+    (278431200, T309),
+    (289317600, T308),
+    (309880800, T309),
+    (320767200, T308),
+    (341330400, T309),
+    (352216800, T308),
+    (372780000, T309),
+    (384271200, T308),
+    (404834400, T309),
+    (415720800, T308),
+    (436284000, T309),
+    (447170400, T308),
+    (467733600, T309),
+    (478620000, T308),
+    (499183200, T309),
+    (510069600, T308),
+    (530632800, T309),
+    (541519200, T308),
+    (562082400, T309),
+    (573573600, T308),
+    (594136800, T309),
+    (605023200, T308),
+    (623772000, T309),
+    (637682400, T308),
+    (655221600, T309),
+    (669132000, T308),
+    (686671200, T309),
+    (700581600, T308),
+    (718120800, T309),
+    (732636000, T308),
+    (749570400, T309),
+    (764085600, T308),
+    (781020000, T309),
+    (795535200, T308),
+    (812469600, T309),
+    (826984800, T308),
+    (844524000, T309),
+    (858434400, T308),
+    (875973600, T309),
+    (889884000, T308),
+    (907423200, T309),
+    (921938400, T308),
+    (938872800, T309),
+    (953388000, T308),
+    (970322400, T309),
+    (984837600, T308),
+    (1002376800, T309),
+    (1016287200, T308),
+    (1033826400, T309),
+    (1047736800, T308),
+    (1065276000, T309),
+    (1079791200, T308),
+    (1096725600, T309),
+    (1111240800, T308),
+    (1128175200, T309),
+    (1142690400, T308),
+    (1159624800, T309),
+    (1174140000, T308),
+    (1191074400, T309),
+    (1207404000, T308),
+    (1222524000, T309),
+    (1238853600, T308),
+    (1253973600, T309),
+];
+
+static ANTARCTICA_PALMER: &[(i64, FixedTimespan<'static>)] = &[
+    (-157766400, T113),
+    (-152658000, T112),
+    (-132955200, T113),
+    (-121122000, T112),
+    (-101419200, T113),
+    (-86821200, T112),
+    (-71092800, T113),
+    (-54766800, T112),
+    (-39038400, T113),
+    (-23317200, T112),
+    (-7588800, T108),
+    (128142000, T109),
+    (136605600, T108),
+    (389070000, T112),
+    (403070400, T113),
+    (416372400, T112),
+    (434520000, T113),
+    (447822000, T112),
+    (466574400, T113),
+    (479271600, T112),
+    (498024000, T113),
+    (510721200, T112),
+    (529473600, T113),
+    (545194800, T112),
+    (560923200, T113),
+    (574225200, T112),
+    (592372800, T113),
+    (605674800, T112),
+    (624427200, T113),
+    (637124400, T112),
+    (653457600, T113),
+    (668574000, T112),
+    (687326400, T113),
+    (700628400, T112),
+    (718776000, T113),
+    (732078000, T112),
+    (750225600, T113),
+    (763527600, T112),
+    (781675200, T113),
+    (794977200, T112),
+    (813729600, T113),
+    (826426800, T112),
+    (845179200, T113),
+    (859690800, T112),
+    (876628800, T113),
+    (889930800, T112),
+    (906868800, T113),
+    (923194800, T112),
+    (939528000, T113),
+    (952830000, T112),
+    (971582400, T113),
+    (984279600, T112),
+    (1003032000, T113),
+    (1015729200, T112),
+    (1034481600, T113),
+    (1047178800, T112),
+    (1065931200, T113),
+    (1079233200, T112),
+    (1097380800, T113),
+    (1110682800, T112),
+    (1128830400, T113),
+    (1142132400, T112),
+    (1160884800, T113),
+    (1173582000, T112),
+    (1192334400, T113),
+    (1206846000, T112),
+    (1223784000, T113),
+    (1237086000, T112),
+    (1255233600, T113),
+    (1270350000, T112),
+    (1286683200, T113),
+    (1304823600, T112),
+    (1313899200, T113),
+    (1335668400, T112),
+    (1346558400, T113),
+    (1367118000, T112),
+    (1378612800, T113),
+    (1398567600, T112),
+    (1410062400, T113),
+    (1463281200, T112),
+    (1471147200, T113),
+];
+
+static ANTARCTICA_ROTHERA: &[(i64, FixedTimespan<'static>)] = &[
+    (218246400, T108),
+];
+
+static ANTARCTICA_SYOWA: &[(i64, FixedTimespan<'static>)] = &[
+    (-407808000, T310),
+];
+
+static ANTARCTICA_TROLL: &[(i64, FixedTimespan<'static>)] = &[
+    (1111885200, T311),
+    (1130634000, T32),
+    (1143334800, T311),
+    (1162083600, T32),
+];
+
+static ANTARCTICA_VOSTOK: &[(i64, FixedTimespan<'static>)] = &[
+    (-380073600, T302),
+    (760035600, T152),
+    (783648000, T302),
+    (1702839600, T303),
+];
+
+static ASIA_ADEN: &[(i64, FixedTimespan<'static>)] = &[
+    (-631162794, T310),
+];
+
+static ASIA_ALMATY: &[(i64, FixedTimespan<'static>)] = &[
+    (-1441170468, T303),
+    (-1247547600, T307),
+    (354909600, T314),
+    (370717200, T307),
+    (386445600, T314),
+    (402253200, T307),
+    (417981600, T314),
+    (433789200, T307),
+    (449604000, T314),
+    (465336000, T307),
+    (481060800, T314),
+    (496785600, T307),
+    (512510400, T314),
+    (528235200, T307),
+    (543960000, T314),
+    (559684800, T307),
+    (575409600, T314),
+    (591134400, T307),
+    (606859200, T314),
+    (622584000, T307),
+    (638308800, T314),
+    (654638400, T307),
+    (670363200, T303),
+    (670366800, T315),
+    (686091600, T303),
+    (695768400, T307),
+    (701812800, T314),
+    (717537600, T307),
+    (733262400, T314),
+    (748987200, T307),
+    (764712000, T314),
+    (780436800, T307),
+    (796161600, T314),
+    (811886400, T307),
+    (828216000, T314),
+    (846360000, T307),
+    (859665600, T314),
+    (877809600, T307),
+    (891115200, T314),
+    (909259200, T307),
+    (922564800, T314),
+    (941313600, T307),
+    (954014400, T314),
+    (972763200, T307),
+    (985464000, T314),
+    (1004212800, T307),
+    (1017518400, T314),
+    (1035662400, T307),
+    (1048968000, T314),
+    (1067112000, T307),
+    (1080417600, T314),
+    (1099166400, T307),
+    (1709229600, T303),
+];
+
+static ASIA_AMMAN: &[(i64, FixedTimespan<'static>)] = &[
+    (-1230776624, T29),
+    (108165600, T30),
+    (118270800, T29),
+    (136591200, T30),
+    (149806800, T29),
+    (168127200, T30),
+    (181342800, T29),
+    (199749600, T30),
+    (215643600, T29),
+    (231285600, T30),
+    (244501200, T29),
+    (262735200, T30),
+    (275950800, T29),
+    (481154400, T30),
+    (496962000, T29),
+    (512949600, T30),
+    (528670800, T29),
+    (544399200, T30),
+    (560120400, T29),
+    (575848800, T30),
+    (592174800, T29),
+    (610581600, T30),
+    (623624400, T29),
+    (641167200, T30),
+    (655074000, T29),
+    (671839200, T30),
+    (685918800, T29),
+    (702856800, T30),
+    (717973200, T29),
+    (733701600, T30),
+    (749422800, T29),
+    (765151200, T30),
+    (779662800, T29),
+    (797205600, T30),
+    (811116000, T29),
+    (828655200, T30),
+    (843170400, T29),
+    (860104800, T30),
+    (874620000, T29),
+    (891554400, T30),
+    (906069600, T29),
+    (930780000, T30),
+    (938124000, T29),
+    (954367200, T30),
+    (970178400, T29),
+    (985816800, T30),
+    (1001628000, T29),
+    (1017352800, T30),
+    (1033077600, T29),
+    (1048802400, T30),
+    (1066946400, T29),
+    (1080252000, T30),
+    (1097791200, T29),
+    (1112306400, T30),
+    (1128031200, T29),
+    (1143756000, T30),
+    (1161900000, T29),
+    (1175205600, T30),
+    (1193349600, T29),
+    (1206655200, T30),
+    (1225404000, T29),
+    (1238104800, T30),
+    (1256853600, T29),
+    (1269554400, T30),
+    (1288303200, T29),
+    (1301608800, T30),
+    (1319752800, T29),
+    (1333058400, T30),
+    (1387486800, T29),
+    (1395957600, T30),
+    (1414706400, T29),
+    (1427407200, T30),
+    (1446156000, T29),
+    (1459461600, T30),
+    (1477605600, T29),
+    (1490911200, T30),
+    (1509055200, T29),
+    (1522360800, T30),
+    (1540504800, T29),
+    (1553810400, T30),
+    (1571954400, T29),
+    (1585260000, T30),
+    (1604008800, T29),
+    (1616709600, T30),
+    (1635458400, T29),
+    (1645740000, T30),
+];
+
+static ASIA_ANADYR: &[(i64, FixedTimespan<'static>)] = &[
+    (-1441194596, T318),
+    (-1247572800, T319),
+    (354884400, T320),
+    (370692000, T319),
+    (386420400, T318),
+    (386424000, T321),
+    (402231600, T318),
+    (417960000, T321),
+    (433767600, T318),
+    (449582400, T321),
+    (465314400, T318),
+    (481039200, T321),
+    (496764000, T318),
+    (512488800, T321),
+    (528213600, T318),
+    (543938400, T321),
+    (559663200, T318),
+    (575388000, T321),
+    (591112800, T318),
+    (606837600, T321),
+    (622562400, T318),
+    (638287200, T321),
+    (654616800, T318),
+    (670341600, T301),
+    (670345200, T322),
+    (686070000, T301),
+    (695746800, T318),
+    (701791200, T321),
+    (717516000, T318),
+    (733240800, T321),
+    (748965600, T318),
+    (764690400, T321),
+    (780415200, T318),
+    (796140000, T321),
+    (811864800, T318),
+    (828194400, T321),
+    (846338400, T318),
+    (859644000, T321),
+    (877788000, T318),
+    (891093600, T321),
+    (909237600, T318),
+    (922543200, T321),
+    (941292000, T318),
+    (953992800, T321),
+    (972741600, T318),
+    (985442400, T321),
+    (1004191200, T318),
+    (1017496800, T321),
+    (1035640800, T318),
+    (1048946400, T321),
+    (1067090400, T318),
+    (1080396000, T321),
+    (1099144800, T318),
+    (1111845600, T321),
+    (1130594400, T318),
+    (1143295200, T321),
+    (1162044000, T318),
+    (1174744800, T321),
+    (1193493600, T318),
+    (1206799200, T321),
+    (1224943200, T318),
+    (1238248800, T321),
+    (1256392800, T318),
+    (1269698400, T301),
+    (1269702000, T322),
+    (1288450800, T301),
+    (1301151600, T318),
+];
+
+static ASIA_AQTAU: &[(i64, FixedTimespan<'static>)] = &[
+    (-1441164064, T324),
+    (-1247544000, T303),
+    (370724400, T307),
+    (386445600, T303),
+    (386449200, T315),
+    (402256800, T303),
+    (417985200, T315),
+    (433792800, T303),
+    (449607600, T315),
+    (465339600, T303),
+    (481064400, T315),
+    (496789200, T303),
+    (512514000, T315),
+    (528238800, T303),
+    (543963600, T315),
+    (559688400, T303),
+    (575413200, T315),
+    (591138000, T303),
+    (606862800, T315),
+    (622587600, T303),
+    (638312400, T315),
+    (654642000, T303),
+    (670366800, T324),
+    (670370400, T325),
+    (686095200, T324),
+    (695772000, T303),
+    (701816400, T315),
+    (717541200, T303),
+    (733266000, T315),
+    (748990800, T303),
+    (764715600, T315),
+    (780440400, T325),
+    (780444000, T324),
+    (796168800, T325),
+    (811893600, T324),
+    (828223200, T325),
+    (846367200, T324),
+    (859672800, T325),
+    (877816800, T324),
+    (891122400, T325),
+    (909266400, T324),
+    (922572000, T325),
+    (941320800, T324),
+    (954021600, T325),
+    (972770400, T324),
+    (985471200, T325),
+    (1004220000, T324),
+    (1017525600, T325),
+    (1035669600, T324),
+    (1048975200, T325),
+    (1067119200, T324),
+    (1080424800, T325),
+];
+
+static ASIA_AQTOBE: &[(i64, FixedTimespan<'static>)] = &[
+    (-1441165720, T324),
+    (-1247544000, T303),
+    (354913200, T315),
+    (386445600, T303),
+    (386449200, T315),
+    (402256800, T303),
+    (417985200, T315),
+    (433792800, T303),
+    (449607600, T315),
+    (465339600, T303),
+    (481064400, T315),
+    (496789200, T303),
+    (512514000, T315),
+    (528238800, T303),
+    (543963600, T315),
+    (559688400, T303),
+    (575413200, T315),
+    (591138000, T303),
+    (606862800, T315),
+    (622587600, T303),
+    (638312400, T315),
+    (654642000, T303),
+    (670366800, T324),
+    (670370400, T325),
+    (686095200, T324),
+    (695772000, T303),
+    (701816400, T315),
+    (717541200, T303),
+    (733266000, T315),
+    (748990800, T303),
+    (764715600, T315),
+    (780440400, T303),
+    (796165200, T315),
+    (811890000, T303),
+    (828219600, T315),
+    (846363600, T303),
+    (859669200, T315),
+    (877813200, T303),
+    (891118800, T315),
+    (909262800, T303),
+    (922568400, T315),
+    (941317200, T303),
+    (954018000, T315),
+    (972766800, T303),
+    (985467600, T315),
+    (1004216400, T303),
+    (1017522000, T315),
+    (1035666000, T303),
+    (1048971600, T315),
+    (1067115600, T303),
+    (1080421200, T315),
+    (1099170000, T303),
+];
+
+static ASIA_ASHGABAT: &[(i64, FixedTimespan<'static>)] = &[
+    (-1441166012, T324),
+    (-1247544000, T303),
+    (354913200, T315),
+    (370720800, T303),
+    (386449200, T315),
+    (402256800, T303),
+    (417985200, T315),
+    (433792800, T303),
+    (449607600, T315),
+    (465339600, T303),
+    (481064400, T315),
+    (496789200, T303),
+    (512514000, T315),
+    (528238800, T303),
+    (543963600, T315),
+    (559688400, T303),
+    (575413200, T315),
+    (591138000, T303),
+    (606862800, T315),
+    (622587600, T303),
+    (638312400, T315),
+    (654642000, T303),
+    (670366800, T324),
+    (670370400, T325),
+    (686095200, T324),
+    (695772000, T303),
+];
+
+static ASIA_ATYRAU: &[(i64, FixedTimespan<'static>)] = &[
+    (-1441164464, T310),
+    (-1247540400, T303),
+    (370724400, T307),
+    (386445600, T303),
+    (386449200, T315),
+    (402256800, T303),
+    (417985200, T315),
+    (433792800, T303),
+    (449607600, T315),
+    (465339600, T303),
+    (481064400, T315),
+    (496789200, T303),
+    (512514000, T315),
+    (528238800, T303),
+    (543963600, T315),
+    (559688400, T303),
+    (575413200, T315),
+    (591138000, T303),
+    (606862800, T315),
+    (622587600, T303),
+    (638312400, T315),
+    (654642000, T303),
+    (670366800, T324),
+    (670370400, T325),
+    (686095200, T324),
+    (695772000, T303),
+    (701816400, T315),
+    (717541200, T303),
+    (733266000, T315),
+    (748990800, T303),
+    (764715600, T315),
+    (780440400, T303),
+    (796165200, T315),
+    (811890000, T303),
+    (828219600, T315),
+    (846363600, T303),
+    (859669200, T315),
+    (877813200, T303),
+    (891118800, T315),
+    (909262800, T303),
+    (922568400, T324),
+    (922572000, T325),
+    (941320800, T324),
+    (954021600, T325),
+    (972770400, T324),
+    (985471200, T325),
+    (1004220000, T324),
+    (1017525600, T325),
+    (1035669600, T324),
+    (1048975200, T325),
+    (1067119200, T324),
+    (1080424800, T325),
+];
+
+static ASIA_BAGHDAD: &[(i64, FixedTimespan<'static>)] = &[
+    (-2524532260, T330),
+    (-1641005856, T310),
+    (389048400, T331),
+    (402264000, T310),
+    (417906000, T331),
+    (433800000, T310),
+    (449614800, T331),
+    (465422400, T310),
+    (481150800, T331),
+    (496792800, T310),
+    (512517600, T331),
+    (528242400, T310),
+    (543967200, T331),
+    (559692000, T310),
+    (575416800, T331),
+    (591141600, T310),
+    (606866400, T331),
+    (622591200, T310),
+    (638316000, T331),
+    (654645600, T310),
+    (670464000, T331),
+    (686275200, T310),
+    (702086400, T331),
+    (717897600, T310),
+    (733622400, T331),
+    (749433600, T310),
+    (765158400, T331),
+    (780969600, T310),
+    (796694400, T331),
+    (812505600, T310),
+    (828316800, T331),
+    (844128000, T310),
+    (859852800, T331),
+    (875664000, T310),
+    (891388800, T331),
+    (907200000, T310),
+    (922924800, T331),
+    (938736000, T310),
+    (954547200, T331),
+    (970358400, T310),
+    (986083200, T331),
+    (1001894400, T310),
+    (1017619200, T331),
+    (1033430400, T310),
+    (1049155200, T331),
+    (1064966400, T310),
+    (1080777600, T331),
+    (1096588800, T310),
+    (1112313600, T331),
+    (1128124800, T310),
+    (1143849600, T331),
+    (1159660800, T310),
+    (1175385600, T331),
+    (1191196800, T310),
+];
+
+static ASIA_BAHRAIN: &[(i64, FixedTimespan<'static>)] = &[
+    (-897880940, T333),
+    (-820553400, T324),
+    (76190400, T310),
+];
+
+static ASIA_BAKU: &[(i64, FixedTimespan<'static>)] = &[
+    (-1441163964, T310),
+    (-405140400, T324),
+    (354916800, T325),
+    (370724400, T324),
+    (386452800, T325),
+    (402260400, T324),
+    (417988800, T325),
+    (433796400, T324),
+    (449611200, T325),
+    (465343200, T324),
+    (481068000, T325),
+    (496792800, T324),
+    (512517600, T325),
+    (528242400, T324),
+    (543967200, T325),
+    (559692000, T324),
+    (575416800, T325),
+    (591141600, T324),
+    (606866400, T325),
+    (622591200, T324),
+    (638316000, T325),
+    (654645600, T324),
+    (670370400, T310),
+    (670374000, T331),
+    (686098800, T310),
+    (701823600, T331),
+    (828234000, T325),
+    (846378000, T324),
+    (859680000, T325),
+    (877824000, T324),
+    (891129600, T325),
+    (909273600, T324),
+    (922579200, T325),
+    (941328000, T324),
+    (954028800, T325),
+    (972777600, T324),
+    (985478400, T325),
+    (1004227200, T324),
+    (1017532800, T325),
+    (1035676800, T324),
+    (1048982400, T325),
+    (1067126400, T324),
+    (1080432000, T325),
+    (1099180800, T324),
+    (1111881600, T325),
+    (1130630400, T324),
+    (1143331200, T325),
+    (1162080000, T324),
+    (1174780800, T325),
+    (1193529600, T324),
+    (1206835200, T325),
+    (1224979200, T324),
+    (1238284800, T325),
+    (1256428800, T324),
+    (1269734400, T325),
+    (1288483200, T324),
+    (1301184000, T325),
+    (1319932800, T324),
+    (1332633600, T325),
+    (1351382400, T324),
+    (1364688000, T325),
+    (1382832000, T324),
+    (1396137600, T325),
+    (1414281600, T324),
+    (1427587200, T325),
+    (1445731200, T324),
+];
+
+static ASIA_BANGKOK: &[(i64, FixedTimespan<'static>)] = &[
+    (-1570084924, T302),
+];
+
+static ASIA_BARNAUL: &[(i64, FixedTimespan<'static>)] = &[
+    (-1579844100, T307),
+    (-1247551200, T302),
+    (354906000, T337),
+    (370713600, T302),
+    (386442000, T337),
+    (402249600, T302),
+    (417978000, T337),
+    (433785600, T302),
+    (449600400, T337),
+    (465332400, T302),
+    (481057200, T337),
+    (496782000, T302),
+    (512506800, T337),
+    (528231600, T302),
+    (543956400, T337),
+    (559681200, T302),
+    (575406000, T337),
+    (591130800, T302),
+    (606855600, T337),
+    (622580400, T302),
+    (638305200, T337),
+    (654634800, T302),
+    (670359600, T307),
+    (670363200, T314),
+    (686088000, T307),
+    (695764800, T302),
+    (701809200, T337),
+    (717534000, T302),
+    (733258800, T337),
+    (748983600, T302),
+    (764708400, T337),
+    (780433200, T302),
+    (796158000, T337),
+    (801590400, T314),
+    (811886400, T307),
+    (828216000, T314),
+    (846360000, T307),
+    (859665600, T314),
+    (877809600, T307),
+    (891115200, T314),
+    (909259200, T307),
+    (922564800, T314),
+    (941313600, T307),
+    (954014400, T314),
+    (972763200, T307),
+    (985464000, T314),
+    (1004212800, T307),
+    (1017518400, T314),
+    (1035662400, T307),
+    (1048968000, T314),
+    (1067112000, T307),
+    (1080417600, T314),
+    (1099166400, T307),
+    (1111867200, T314),
+    (1130616000, T307),
+    (1143316800, T314),
+    (1162065600, T307),
+    (1174766400, T314),
+    (1193515200, T307),
+    (1206820800, T314),
+    (1224964800, T307),
+    (1238270400, T314),
+    (1256414400, T307),
+    (1269720000, T314),
+    (1288468800, T307),
+    (1301169600, T302),
+    (1414263600, T307),
+    (1459022400, T302),
+];
+
+static ASIA_BEIRUT: &[(i64, FixedTimespan<'static>)] = &[
+    (-2840149320, T29),
+    (-1570413600, T30),
+    (-1552186800, T29),
+    (-1538359200, T30),
+    (-1522551600, T29),
+    (-1507514400, T30),
+    (-1490583600, T29),
+    (-1473645600, T30),
+    (-1460948400, T29),
+    (-399866400, T30),
+    (-386650800, T29),
+    (-368330400, T30),
+    (-355114800, T29),
+    (-336794400, T30),
+    (-323578800, T29),
+    (-305172000, T30),
+    (-291956400, T29),
+    (-273636000, T30),
+    (-260420400, T29),
+    (78012000, T30),
+    (86734800, T29),
+    (105055200, T30),
+    (118270800, T29),
+    (136591200, T30),
+    (149806800, T29),
+    (168127200, T30),
+    (181342800, T29),
+    (199749600, T30),
+    (212965200, T29),
+    (231285600, T30),
+    (244501200, T29),
+    (262735200, T30),
+    (275950800, T29),
+    (452210400, T30),
+    (466722000, T29),
+    (483746400, T30),
+    (498258000, T29),
+    (515282400, T30),
+    (529794000, T29),
+    (546818400, T30),
+    (561330000, T29),
+    (581119200, T30),
+    (592952400, T29),
+    (610754400, T30),
+    (624488400, T29),
+    (641512800, T30),
+    (656024400, T29),
+    (673048800, T30),
+    (687560400, T29),
+    (704671200, T30),
+    (718146000, T29),
+    (733269600, T30),
+    (748990800, T29),
+    (764719200, T30),
+    (780440400, T29),
+    (796168800, T30),
+    (811890000, T29),
+    (828223200, T30),
+    (843944400, T29),
+    (859672800, T30),
+    (875394000, T29),
+    (891122400, T30),
+    (906843600, T29),
+    (922572000, T30),
+    (941317200, T29),
+    (954021600, T30),
+    (972766800, T29),
+];
+
+static ASIA_BISHKEK: &[(i64, FixedTimespan<'static>)] = &[
+    (-1441169904, T303),
+    (-1247547600, T307),
+    (354909600, T314),
+    (370717200, T307),
+    (386445600, T314),
+    (402253200, T307),
+    (417981600, T314),
+    (433789200, T307),
+    (449604000, T314),
+    (465336000, T307),
+    (481060800, T314),
+    (496785600, T307),
+    (512510400, T314),
+    (528235200, T307),
+    (543960000, T314),
+    (559684800, T307),
+    (575409600, T314),
+    (591134400, T307),
+    (606859200, T314),
+    (622584000, T307),
+    (638308800, T314),
+    (654638400, T307),
+    (670363200, T303),
+    (670366800, T315),
+    (683582400, T303),
+    (703018800, T315),
+    (717530400, T303),
+    (734468400, T315),
+    (748980000, T303),
+    (765918000, T315),
+    (780429600, T303),
+    (797367600, T315),
+    (811879200, T303),
+    (828817200, T315),
+    (843933600, T303),
+    (859671000, T315),
+    (877811400, T303),
+    (891120600, T315),
+    (909261000, T303),
+    (922570200, T315),
+    (941315400, T303),
+    (954019800, T315),
+    (972765000, T303),
+    (985469400, T315),
+    (1004214600, T303),
+    (1017523800, T315),
+    (1035664200, T303),
+    (1048973400, T315),
+    (1067113800, T303),
+    (1080423000, T315),
+    (1099168200, T303),
+    (1111872600, T315),
+];
+
+static ASIA_BRUNEI: &[(i64, FixedTimespan<'static>)] = &[
+    (-1383464380, T341),
+    (-1167636600, T300),
+];
+
+static ASIA_CHITA: &[(i64, FixedTimespan<'static>)] = &[
+    (-1579419232, T300),
+    (-1247558400, T343),
+    (354898800, T344),
+    (370706400, T343),
+    (386434800, T344),
+    (402242400, T343),
+    (417970800, T344),
+    (433778400, T343),
+    (449593200, T344),
+    (465325200, T343),
+    (481050000, T344),
+    (496774800, T343),
+    (512499600, T344),
+    (528224400, T343),
+    (543949200, T344),
+    (559674000, T343),
+    (575398800, T344),
+    (591123600, T343),
+    (606848400, T344),
+    (622573200, T343),
+    (638298000, T344),
+    (654627600, T343),
+    (670352400, T300),
+    (670356000, T345),
+    (686080800, T300),
+    (695757600, T343),
+    (701802000, T344),
+    (717526800, T343),
+    (733251600, T344),
+    (748976400, T343),
+    (764701200, T344),
+    (780426000, T343),
+    (796150800, T344),
+    (811875600, T343),
+    (828205200, T344),
+    (846349200, T343),
+    (859654800, T344),
+    (877798800, T343),
+    (891104400, T344),
+    (909248400, T343),
+    (922554000, T344),
+    (941302800, T343),
+    (954003600, T344),
+    (972752400, T343),
+    (985453200, T344),
+    (1004202000, T343),
+    (1017507600, T344),
+    (1035651600, T343),
+    (1048957200, T344),
+    (1067101200, T343),
+    (1080406800, T344),
+    (1099155600, T343),
+    (1111856400, T344),
+    (1130605200, T343),
+    (1143306000, T344),
+    (1162054800, T343),
+    (1174755600, T344),
+    (1193504400, T343),
+    (1206810000, T344),
+    (1224954000, T343),
+    (1238259600, T344),
+    (1256403600, T343),
+    (1269709200, T344),
+    (1288458000, T343),
+    (1301158800, T304),
+    (1414252800, T300),
+    (1459015200, T343),
+];
+
+static ASIA_COLOMBO: &[(i64, FixedTimespan<'static>)] = &[
+    (-2840159964, T347),
+    (-2019705572, T348),
+    (-883287000, T315),
+    (-862639200, T349),
+    (-764051400, T348),
+    (832962600, T350),
+    (846266400, T307),
+    (1145039400, T348),
+];
+
+static ASIA_DAMASCUS: &[(i64, FixedTimespan<'static>)] = &[
+    (-1577931912, T29),
+    (-1568592000, T30),
+    (-1554080400, T29),
+    (-1537142400, T30),
+    (-1522630800, T29),
+    (-1505692800, T30),
+    (-1491181200, T29),
+    (-1474243200, T30),
+    (-1459126800, T29),
+    (-242265600, T30),
+    (-228877200, T29),
+    (-210556800, T30),
+    (-197427600, T29),
+    (-178934400, T30),
+    (-165718800, T29),
+    (-147398400, T30),
+    (-134269200, T29),
+    (-116467200, T30),
+    (-102646800, T29),
+    (-84326400, T30),
+    (-71110800, T29),
+    (-52704000, T30),
+    (-39488400, T29),
+    (-21168000, T30),
+    (-7952400, T29),
+    (10368000, T30),
+    (23583600, T29),
+    (41904000, T30),
+    (55119600, T29),
+    (73526400, T30),
+    (86742000, T29),
+    (105062400, T30),
+    (118278000, T29),
+    (136598400, T30),
+    (149814000, T29),
+    (168134400, T30),
+    (181350000, T29),
+    (199756800, T30),
+    (212972400, T29),
+    (231292800, T30),
+    (241916400, T29),
+    (262828800, T30),
+    (273452400, T29),
+    (418694400, T30),
+    (433810800, T29),
+    (450316800, T30),
+    (465433200, T29),
+    (508896000, T30),
+    (529196400, T29),
+    (541555200, T30),
+    (562633200, T29),
+    (574387200, T30),
+    (594255600, T29),
+    (607305600, T30),
+    (623199600, T29),
+    (638928000, T30),
+    (654649200, T29),
+    (670456800, T30),
+    (686264400, T29),
+    (702684000, T30),
+    (717886800, T29),
+    (733096800, T30),
+    (748904400, T29),
+    (765151200, T30),
+    (780958800, T29),
+    (796687200, T30),
+    (812494800, T29),
+    (828309600, T30),
+    (844117200, T29),
+    (859759200, T30),
+    (875653200, T29),
+    (891208800, T30),
+    (907189200, T29),
+    (922917600, T30),
+    (938725200, T29),
+    (954540000, T30),
+    (970347600, T29),
+    (986076000, T30),
+    (1001883600, T29),
+    (1017612000, T30),
+    (1033419600, T29),
+    (1049148000, T30),
+    (1064955600, T29),
+    (1080770400, T30),
+    (1096578000, T29),
+    (1112306400, T30),
+    (1128114000, T29),
+    (1143842400, T30),
+    (1158872400, T29),
+    (1175205600, T30),
+    (1193950800, T29),
+    (1207260000, T30),
+    (1225486800, T29),
+    (1238104800, T30),
+    (1256850000, T29),
+    (1270159200, T30),
+    (1288299600, T29),
+    (1301608800, T30),
+    (1319749200, T29),
+    (1333058400, T30),
+    (1351198800, T29),
+    (1364508000, T30),
+    (1382648400, T29),
+    (1395957600, T30),
+    (1414702800, T29),
+    (1427407200, T30),
+    (1446152400, T29),
+    (1458856800, T30),
+    (1477602000, T29),
+    (1490911200, T30),
+    (1509051600, T29),
+    (1522360800, T30),
+    (1540501200, T29),
+    (1553810400, T30),
+    (1571950800, T29),
+    (1585260000, T30),
+    (1604005200, T29),
+    (1616709600, T30),
+    (1635454800, T29),
+    (1648159200, T30),
+];
+
+static ASIA_DHAKA: &[(i64, FixedTimespan<'static>)] = &[
+    (-2524543300, T353),
+    (-891582800, T350),
+    (-872058600, T348),
+    (-862637400, T350),
+    (-576138600, T307),
+    (1245430800, T314),
+    (1262278800, T307),
+];
+
+static ASIA_DILI: &[(i64, FixedTimespan<'static>)] = &[
+    (-1830412800, T300),
+    (-879152400, T343),
+    (199897200, T300),
+    (969120000, T343),
+];
+
+static ASIA_DUBAI: &[(i64, FixedTimespan<'static>)] = &[
+    (-1577936472, T324),
+];
+
+static ASIA_DUSHANBE: &[(i64, FixedTimespan<'static>)] = &[
+    (-1441168512, T303),
+    (-1247547600, T307),
+    (354909600, T314),
+    (370717200, T307),
+    (386445600, T314),
+    (402253200, T307),
+    (417981600, T314),
+    (433789200, T307),
+    (449604000, T314),
+    (465336000, T307),
+    (481060800, T314),
+    (496785600, T307),
+    (512510400, T314),
+    (528235200, T307),
+    (543960000, T314),
+    (559684800, T307),
+    (575409600, T314),
+    (591134400, T307),
+    (606859200, T314),
+    (622584000, T307),
+    (638308800, T314),
+    (654638400, T307),
+    (684363600, T303),
+];
+
+static ASIA_FAMAGUSTA: &[(i64, FixedTimespan<'static>)] = &[
+    (-1518920148, T29),
+    (166572000, T30),
+    (182293200, T29),
+    (200959200, T30),
+    (213829200, T29),
+    (228866400, T30),
+    (243982800, T29),
+    (260316000, T30),
+    (276123600, T29),
+    (291765600, T30),
+    (307486800, T29),
+    (323820000, T30),
+    (338936400, T29),
+    (354664800, T30),
+    (370386000, T29),
+    (386114400, T30),
+    (401835600, T29),
+    (417564000, T30),
+    (433285200, T29),
+    (449013600, T30),
+    (465339600, T29),
+    (481068000, T30),
+    (496789200, T29),
+    (512517600, T30),
+    (528238800, T29),
+    (543967200, T30),
+    (559688400, T29),
+    (575416800, T30),
+    (591138000, T29),
+    (606866400, T30),
+    (622587600, T29),
+    (638316000, T30),
+    (654642000, T29),
+    (670370400, T30),
+    (686091600, T29),
+    (701820000, T30),
+    (717541200, T29),
+    (733269600, T30),
+    (748990800, T29),
+    (764719200, T30),
+    (780440400, T29),
+    (796168800, T30),
+    (811890000, T29),
+    (828223200, T30),
+    (843944400, T29),
+    (859672800, T30),
+    (875394000, T29),
+    (891122400, T30),
+    (909277200, T29),
+    (922582800, T30),
+    (941331600, T29),
+    (954032400, T30),
+    (972781200, T29),
+    (985482000, T30),
+    (1004230800, T29),
+    (1017536400, T30),
+    (1035680400, T29),
+    (1048986000, T30),
+    (1067130000, T29),
+    (1080435600, T30),
+    (1099184400, T29),
+    (1111885200, T30),
+    (1130634000, T29),
+    (1143334800, T30),
+    (1162083600, T29),
+    (1174784400, T30),
+    (1193533200, T29),
+    (1206838800, T30),
+    (1224982800, T29),
+    (1238288400, T30),
+    (1256432400, T29),
+    (1269738000, T30),
+    (1288486800, T29),
+    (1301187600, T30),
+    (1319936400, T29),
+    (1332637200, T30),
+    (1351386000, T29),
+    (1364691600, T30),
+    (1382835600, T29),
+    (1396141200, T30),
+    (1414285200, T29),
+    (1427590800, T30),
+    (1445734800, T29),
+    (1459040400, T30),
+    (1509238800, T29),
+    (1521939600, T30),
+    (1540688400, T29),
+];
+
+static ASIA_GAZA: &[(i64, FixedTimespan<'static>)] = &[
+    (-2185409872, T29),
+    (-933638400, T30),
+    (-923097600, T29),
+    (-919036800, T30),
+    (-857347200, T29),
+    (-844300800, T30),
+    (-825811200, T29),
+    (-812678400, T30),
+    (-794188800, T29),
+    (-779846400, T30),
+    (-762652800, T29),
+    (-748310400, T30),
+    (-731116800, T29),
+    (-399088800, T30),
+    (-386650800, T29),
+    (-368330400, T30),
+    (-355114800, T29),
+    (-336790800, T30),
+    (-323654400, T29),
+    (-305168400, T30),
+    (-292032000, T29),
+    (-273632400, T30),
+    (-260496000, T29),
+    (-242096400, T30),
+    (-228960000, T29),
+    (-210560400, T30),
+    (-197424000, T29),
+    (-178938000, T30),
+    (-165801600, T29),
+    (-147402000, T30),
+    (-134265600, T29),
+    (-115866000, T30),
+    (-102643200, T29),
+    (-84330000, T30),
+    (-81313200, T359),
+    (142380000, T360),
+    (150843600, T359),
+    (167176800, T360),
+    (178664400, T359),
+    (334101600, T360),
+    (337730400, T359),
+    (452642400, T360),
+    (462319200, T359),
+    (482277600, T360),
+    (494370000, T359),
+    (516751200, T360),
+    (526424400, T359),
+    (545436000, T360),
+    (558478800, T359),
+    (576626400, T360),
+    (589323600, T359),
+    (609890400, T360),
+    (620773200, T359),
+    (638316000, T360),
+    (651618000, T359),
+    (669765600, T360),
+    (683672400, T359),
+    (701820000, T360),
+    (715726800, T359),
+    (733701600, T360),
+    (747176400, T359),
+    (765151200, T360),
+    (778021200, T359),
+    (796600800, T360),
+    (810075600, T359),
+    (828655200, T30),
+    (843170400, T29),
+    (860104800, T30),
+    (874620000, T29),
+    (891554400, T30),
+    (906069600, T29),
+    (924213600, T30),
+    (939934800, T29),
+    (956268000, T30),
+    (971989200, T29),
+    (987717600, T30),
+    (1003438800, T29),
+    (1019167200, T30),
+    (1034888400, T29),
+    (1050616800, T30),
+    (1066338000, T29),
+    (1082066400, T30),
+    (1096581600, T29),
+    (1113516000, T30),
+    (1128380400, T29),
+    (1143842400, T30),
+    (1158872400, T29),
+    (1175378400, T30),
+    (1189638000, T29),
+    (1206655200, T30),
+    (1219957200, T29),
+    (1238104800, T30),
+    (1252015200, T29),
+    (1269640860, T30),
+    (1281474000, T29),
+    (1301608860, T30),
+    (1312146000, T29),
+    (1333058400, T30),
+    (1348178400, T29),
+    (1364508000, T30),
+    (1380229200, T29),
+    (1395957600, T30),
+    (1414098000, T29),
+    (1427493600, T30),
+    (1445551200, T29),
+    (1458946800, T30),
+    (1477692000, T29),
+    (1490396400, T30),
+    (1509141600, T29),
+    (1521846000, T30),
+    (1540591200, T29),
+    (1553810400, T30),
+    (1572037200, T29),
+    (1585346400, T30),
+    (1603490400, T29),
+    (1616796000, T30),
+    (1635458400, T29),
+    (1648332000, T30),
+    (1666998000, T29),
+    (1682726400, T30),
+    (1698447600, T29),
+    (1713571200, T30),
+    (1729897200, T29),
+    (1744416000, T30),
+    (1761346800, T29),
+    (1774656000, T30),
+    (1792796400, T29),
+    (1806105600, T30),
+    (1824850800, T29),
+    (1837555200, T30),
+    (1856300400, T29),
+    (1869004800, T30),
+    (1887750000, T29),
+    (1901059200, T30),
+    (1919199600, T29),
+    (1932508800, T30),
+    (1950649200, T29),
+    (1963958400, T30),
+    (1982703600, T29),
+    (1995408000, T30),
+    (2014153200, T29),
+    (2026857600, T30),
+    (2045602800, T29),
+    (2058307200, T30),
+    (2077052400, T29),
+    (2090361600, T30),
+    (2107897200, T29),
+    (2121811200, T30),
+    (2138742000, T29),
+    (2153260800, T30),
+    (2168982000, T29),
+    (2184710400, T30),
+    (2199826800, T29),
+    (2216160000, T30),
+    (2230066800, T29),
+    (2234304000, T30),
+    (2234905200, T29),
+    (2248214400, T30),
+    (2260911600, T29),
+    (2264544000, T30),
+    (2266354800, T29),
+    (2279664000, T30),
+    (2291756400, T29),
+    (2295388800, T30),
+    (2297804400, T29),
+    (2311113600, T30),
+    (2321996400, T29),
+    (2326233600, T30),
+    (2329254000, T29),
+    (2342563200, T30),
+    (2352841200, T29),
+    (2356473600, T30),
+    (2361308400, T29),
+    (2374012800, T30),
+    (2383686000, T29),
+    (2387318400, T30),
+    (2392758000, T29),
+    (2405462400, T30),
+    (2413926000, T29),
+    (2418163200, T30),
+    (2424207600, T29),
+    (2437516800, T30),
+    (2444770800, T29),
+    (2448403200, T30),
+    (2455657200, T29),
+    (2468966400, T30),
+    (2475010800, T29),
+    (2479248000, T30),
+    (2487106800, T29),
+    (2500416000, T30),
+    (2505855600, T29),
+    (2509488000, T30),
+    (2519161200, T29),
+    (2531865600, T30),
+    (2536700400, T29),
+    (2540332800, T30),
+    (2550610800, T29),
+    (2563315200, T30),
+    (2566940400, T29),
+    (2571177600, T30),
+    (2582060400, T29),
+    (2595369600, T30),
+    (2597785200, T29),
+    (2601417600, T30),
+    (2613510000, T29),
+    (2626819200, T30),
+    (2628025200, T29),
+    (2632262400, T30),
+    (2644959600, T29),
+    (2658268800, T30),
+    (2658870000, T29),
+    (2663107200, T30),
+    (2676409200, T29),
+    (2693347200, T30),
+    (2708463600, T29),
+    (2724192000, T30),
+    (2739913200, T29),
+    (2754432000, T30),
+    (2771362800, T29),
+    (2785276800, T30),
+    (2802812400, T29),
+    (2816121600, T30),
+    (2834262000, T29),
+    (2847571200, T30),
+    (2866316400, T29),
+    (2879020800, T30),
+    (2897766000, T29),
+    (2910470400, T30),
+    (2929215600, T29),
+    (2941920000, T30),
+    (2960665200, T29),
+    (2973974400, T30),
+    (2992114800, T29),
+    (3005424000, T30),
+    (3023564400, T29),
+    (3036873600, T30),
+    (3055618800, T29),
+    (3068323200, T30),
+    (3087068400, T29),
+    (3099772800, T30),
+    (3117913200, T29),
+    (3131827200, T30),
+    (3148758000, T29),
+    (3163276800, T30),
+    (3179602800, T29),
+    (3194726400, T30),
+    (3209842800, T29),
+    (3226176000, T30),
+    (3240687600, T29),
+    (3244320000, T30),
+    (3244921200, T29),
+    (3257625600, T30),
+    (3271532400, T29),
+    (3275164800, T30),
+    (3276370800, T29),
+    (3289075200, T30),
+    (3301772400, T29),
+    (3306009600, T30),
+    (3307820400, T29),
+    (3321129600, T30),
+    (3332617200, T29),
+    (3336249600, T30),
+    (3339270000, T29),
+    (3352579200, T30),
+    (3362857200, T29),
+    (3367094400, T30),
+    (3370719600, T29),
+    (3384028800, T30),
+    (3393702000, T29),
+    (3397939200, T30),
+    (3402774000, T29),
+    (3415478400, T30),
+    (3424546800, T29),
+    (3428179200, T30),
+    (3434223600, T29),
+    (3446928000, T30),
+    (3454786800, T29),
+    (3459024000, T30),
+    (3465673200, T29),
+    (3478982400, T30),
+    (3485631600, T29),
+    (3489264000, T30),
+    (3497122800, T29),
+    (3510432000, T30),
+    (3516476400, T29),
+    (3520108800, T30),
+    (3528572400, T29),
+    (3541881600, T30),
+    (3546716400, T29),
+    (3550953600, T30),
+    (3560022000, T29),
+    (3573331200, T30),
+    (3577561200, T29),
+    (3581193600, T30),
+    (3592076400, T29),
+    (3604780800, T30),
+    (3607801200, T29),
+    (3612038400, T30),
+    (3623526000, T29),
+    (3636230400, T30),
+    (3638646000, T29),
+    (3642883200, T30),
+    (3654975600, T29),
+    (3668284800, T30),
+    (3669490800, T29),
+    (3673123200, T30),
+    (3686425200, T29),
+    (3699734400, T30),
+    (3717874800, T29),
+];
+
+static ASIA_HEBRON: &[(i64, FixedTimespan<'static>)] = &[
+    (-2185410023, T29),
+    (-933638400, T30),
+    (-923097600, T29),
+    (-919036800, T30),
+    (-857347200, T29),
+    (-844300800, T30),
+    (-825811200, T29),
+    (-812678400, T30),
+    (-794188800, T29),
+    (-779846400, T30),
+    (-762652800, T29),
+    (-748310400, T30),
+    (-731116800, T29),
+    (-399088800, T30),
+    (-386650800, T29),
+    (-368330400, T30),
+    (-355114800, T29),
+    (-336790800, T30),
+    (-323654400, T29),
+    (-305168400, T30),
+    (-292032000, T29),
+    (-273632400, T30),
+    (-260496000, T29),
+    (-242096400, T30),
+    (-228960000, T29),
+    (-210560400, T30),
+    (-197424000, T29),
+    (-178938000, T30),
+    (-165801600, T29),
+    (-147402000, T30),
+    (-134265600, T29),
+    (-115866000, T30),
+    (-102643200, T29),
+    (-84330000, T30),
+    (-81313200, T359),
+    (142380000, T360),
+    (150843600, T359),
+    (167176800, T360),
+    (178664400, T359),
+    (334101600, T360),
+    (337730400, T359),
+    (452642400, T360),
+    (462319200, T359),
+    (482277600, T360),
+    (494370000, T359),
+    (516751200, T360),
+    (526424400, T359),
+    (545436000, T360),
+    (558478800, T359),
+    (576626400, T360),
+    (589323600, T359),
+    (609890400, T360),
+    (620773200, T359),
+    (638316000, T360),
+    (651618000, T359),
+    (669765600, T360),
+    (683672400, T359),
+    (701820000, T360),
+    (715726800, T359),
+    (733701600, T360),
+    (747176400, T359),
+    (765151200, T360),
+    (778021200, T359),
+    (796600800, T360),
+    (810075600, T359),
+    (828655200, T30),
+    (843170400, T29),
+    (860104800, T30),
+    (874620000, T29),
+    (891554400, T30),
+    (906069600, T29),
+    (924213600, T30),
+    (939934800, T29),
+    (956268000, T30),
+    (971989200, T29),
+    (987717600, T30),
+    (1003438800, T29),
+    (1019167200, T30),
+    (1034888400, T29),
+    (1050616800, T30),
+    (1066338000, T29),
+    (1082066400, T30),
+    (1096581600, T29),
+    (1113516000, T30),
+    (1128380400, T29),
+    (1143842400, T30),
+    (1158872400, T29),
+    (1175378400, T30),
+    (1189638000, T29),
+    (1206655200, T30),
+    (1220216400, T29),
+    (1238104800, T30),
+    (1252015200, T29),
+    (1269554400, T30),
+    (1281474000, T29),
+    (1301608860, T30),
+    (1312146000, T29),
+    (1314655200, T30),
+    (1317330000, T29),
+    (1333058400, T30),
+    (1348178400, T29),
+    (1364508000, T30),
+    (1380229200, T29),
+    (1395957600, T30),
+    (1414098000, T29),
+    (1427493600, T30),
+    (1445551200, T29),
+    (1458946800, T30),
+    (1477692000, T29),
+    (1490396400, T30),
+    (1509141600, T29),
+    (1521846000, T30),
+    (1540591200, T29),
+    (1553810400, T30),
+    (1572037200, T29),
+    (1585346400, T30),
+    (1603490400, T29),
+    (1616796000, T30),
+    (1635458400, T29),
+    (1648332000, T30),
+    (1666998000, T29),
+    (1682726400, T30),
+    (1698447600, T29),
+    (1713571200, T30),
+    (1729897200, T29),
+    (1744416000, T30),
+    (1761346800, T29),
+    (1774656000, T30),
+    (1792796400, T29),
+    (1806105600, T30),
+    (1824850800, T29),
+    (1837555200, T30),
+    (1856300400, T29),
+    (1869004800, T30),
+    (1887750000, T29),
+    (1901059200, T30),
+    (1919199600, T29),
+    (1932508800, T30),
+    (1950649200, T29),
+    (1963958400, T30),
+    (1982703600, T29),
+    (1995408000, T30),
+    (2014153200, T29),
+    (2026857600, T30),
+    (2045602800, T29),
+    (2058307200, T30),
+    (2077052400, T29),
+    (2090361600, T30),
+    (2107897200, T29),
+    (2121811200, T30),
+    (2138742000, T29),
+    (2153260800, T30),
+    (2168982000, T29),
+    (2184710400, T30),
+    (2199826800, T29),
+    (2216160000, T30),
+    (2230066800, T29),
+    (2234304000, T30),
+    (2234905200, T29),
+    (2248214400, T30),
+    (2260911600, T29),
+    (2264544000, T30),
+    (2266354800, T29),
+    (2279664000, T30),
+    (2291756400, T29),
+    (2295388800, T30),
+    (2297804400, T29),
+    (2311113600, T30),
+    (2321996400, T29),
+    (2326233600, T30),
+    (2329254000, T29),
+    (2342563200, T30),
+    (2352841200, T29),
+    (2356473600, T30),
+    (2361308400, T29),
+    (2374012800, T30),
+    (2383686000, T29),
+    (2387318400, T30),
+    (2392758000, T29),
+    (2405462400, T30),
+    (2413926000, T29),
+    (2418163200, T30),
+    (2424207600, T29),
+    (2437516800, T30),
+    (2444770800, T29),
+    (2448403200, T30),
+    (2455657200, T29),
+    (2468966400, T30),
+    (2475010800, T29),
+    (2479248000, T30),
+    (2487106800, T29),
+    (2500416000, T30),
+    (2505855600, T29),
+    (2509488000, T30),
+    (2519161200, T29),
+    (2531865600, T30),
+    (2536700400, T29),
+    (2540332800, T30),
+    (2550610800, T29),
+    (2563315200, T30),
+    (2566940400, T29),
+    (2571177600, T30),
+    (2582060400, T29),
+    (2595369600, T30),
+    (2597785200, T29),
+    (2601417600, T30),
+    (2613510000, T29),
+    (2626819200, T30),
+    (2628025200, T29),
+    (2632262400, T30),
+    (2644959600, T29),
+    (2658268800, T30),
+    (2658870000, T29),
+    (2663107200, T30),
+    (2676409200, T29),
+    (2693347200, T30),
+    (2708463600, T29),
+    (2724192000, T30),
+    (2739913200, T29),
+    (2754432000, T30),
+    (2771362800, T29),
+    (2785276800, T30),
+    (2802812400, T29),
+    (2816121600, T30),
+    (2834262000, T29),
+    (2847571200, T30),
+    (2866316400, T29),
+    (2879020800, T30),
+    (2897766000, T29),
+    (2910470400, T30),
+    (2929215600, T29),
+    (2941920000, T30),
+    (2960665200, T29),
+    (2973974400, T30),
+    (2992114800, T29),
+    (3005424000, T30),
+    (3023564400, T29),
+    (3036873600, T30),
+    (3055618800, T29),
+    (3068323200, T30),
+    (3087068400, T29),
+    (3099772800, T30),
+    (3117913200, T29),
+    (3131827200, T30),
+    (3148758000, T29),
+    (3163276800, T30),
+    (3179602800, T29),
+    (3194726400, T30),
+    (3209842800, T29),
+    (3226176000, T30),
+    (3240687600, T29),
+    (3244320000, T30),
+    (3244921200, T29),
+    (3257625600, T30),
+    (3271532400, T29),
+    (3275164800, T30),
+    (3276370800, T29),
+    (3289075200, T30),
+    (3301772400, T29),
+    (3306009600, T30),
+    (3307820400, T29),
+    (3321129600, T30),
+    (3332617200, T29),
+    (3336249600, T30),
+    (3339270000, T29),
+    (3352579200, T30),
+    (3362857200, T29),
+    (3367094400, T30),
+    (3370719600, T29),
+    (3384028800, T30),
+    (3393702000, T29),
+    (3397939200, T30),
+    (3402774000, T29),
+    (3415478400, T30),
+    (3424546800, T29),
+    (3428179200, T30),
+    (3434223600, T29),
+    (3446928000, T30),
+    (3454786800, T29),
+    (3459024000, T30),
+    (3465673200, T29),
+    (3478982400, T30),
+    (3485631600, T29),
+    (3489264000, T30),
+    (3497122800, T29),
+    (3510432000, T30),
+    (3516476400, T29),
+    (3520108800, T30),
+    (3528572400, T29),
+    (3541881600, T30),
+    (3546716400, T29),
+    (3550953600, T30),
+    (3560022000, T29),
+    (3573331200, T30),
+    (3577561200, T29),
+    (3581193600, T30),
+    (3592076400, T29),
+    (3604780800, T30),
+    (3607801200, T29),
+    (3612038400, T30),
+    (3623526000, T29),
+    (3636230400, T30),
+    (3638646000, T29),
+    (3642883200, T30),
+    (3654975600, T29),
+    (3668284800, T30),
+    (3669490800, T29),
+    (3673123200, T30),
+    (3686425200, T29),
+    (3699734400, T30),
+    (3717874800, T29),
+];
+
+static ASIA_HO_CHI_MINH: &[(i64, FixedTimespan<'static>)] = &[
+    (-1851577590, T302),
+    (-852105600, T300),
+    (-782643600, T343),
+    (-767869200, T302),
+    (-718095600, T300),
+    (-457772400, T302),
+    (-315648000, T300),
+    (171820800, T302),
+];
+
+static ASIA_HONG_KONG: &[(i64, FixedTimespan<'static>)] = &[
+    (-2056690800, T364),
+    (-900910800, T365),
+    (-891579600, T366),
+    (-884248200, T367),
+    (-761209200, T364),
+    (-747907200, T365),
+    (-728541000, T364),
+    (-717049800, T365),
+    (-697091400, T364),
+    (-683785800, T365),
+    (-668061000, T364),
+    (-654755400, T365),
+    (-636611400, T364),
+    (-623305800, T365),
+    (-605161800, T364),
+    (-591856200, T365),
+    (-573712200, T364),
+    (-559801800, T365),
+    (-541657800, T364),
+    (-528352200, T365),
+    (-510211800, T364),
+    (-498112200, T365),
+    (-478762200, T364),
+    (-466662600, T365),
+    (-446707800, T364),
+    (-435213000, T365),
+    (-415258200, T364),
+    (-403158600, T365),
+    (-383808600, T364),
+    (-371709000, T365),
+    (-352359000, T364),
+    (-340259400, T365),
+    (-320909400, T364),
+    (-308809800, T365),
+    (-288855000, T364),
+    (-277360200, T365),
+    (-257405400, T364),
+    (-245910600, T365),
+    (-225955800, T364),
+    (-213856200, T365),
+    (-194506200, T364),
+    (-182406600, T365),
+    (-163056600, T364),
+    (-148537800, T365),
+    (-132816600, T364),
+    (-117088200, T365),
+    (-101367000, T364),
+    (-85638600, T365),
+    (-69312600, T364),
+    (-53584200, T365),
+    (-37863000, T364),
+    (-22134600, T365),
+    (-6413400, T364),
+    (9315000, T365),
+    (25036200, T364),
+    (40764600, T365),
+    (56485800, T364),
+    (72214200, T365),
+    (88540200, T364),
+    (104268600, T365),
+    (119989800, T364),
+    (126041400, T365),
+    (151439400, T364),
+    (167167800, T365),
+    (182889000, T364),
+    (198617400, T365),
+    (214338600, T364),
+    (295385400, T365),
+    (309292200, T364),
+];
+
+static ASIA_HOVD: &[(i64, FixedTimespan<'static>)] = &[
+    (-2032927596, T307),
+    (252439200, T302),
+    (417978000, T337),
+    (433785600, T302),
+    (449600400, T337),
+    (465321600, T302),
+    (481050000, T337),
+    (496771200, T302),
+    (512499600, T337),
+    (528220800, T302),
+    (543949200, T337),
+    (559670400, T302),
+    (575398800, T337),
+    (591120000, T302),
+    (606848400, T337),
+    (622569600, T302),
+    (638298000, T337),
+    (654624000, T302),
+    (670352400, T337),
+    (686073600, T302),
+    (701802000, T337),
+    (717523200, T302),
+    (733251600, T337),
+    (748972800, T302),
+    (764701200, T337),
+    (780422400, T302),
+    (796150800, T337),
+    (811872000, T302),
+    (828205200, T337),
+    (843926400, T302),
+    (859654800, T337),
+    (875376000, T302),
+    (891104400, T337),
+    (906825600, T302),
+    (988398000, T337),
+    (1001700000, T302),
+    (1017428400, T337),
+    (1033149600, T302),
+    (1048878000, T337),
+    (1064599200, T302),
+    (1080327600, T337),
+    (1096048800, T302),
+    (1111777200, T337),
+    (1127498400, T302),
+    (1143226800, T337),
+    (1159552800, T302),
+    (1427482800, T337),
+    (1443196800, T302),
+    (1458932400, T337),
+    (1474646400, T302),
+];
+
+static ASIA_IRKUTSK: &[(i64, FixedTimespan<'static>)] = &[
+    (-1575874625, T302),
+    (-1247554800, T300),
+    (354902400, T345),
+    (370710000, T300),
+    (386438400, T345),
+    (402246000, T300),
+    (417974400, T345),
+    (433782000, T300),
+    (449596800, T345),
+    (465328800, T300),
+    (481053600, T345),
+    (496778400, T300),
+    (512503200, T345),
+    (528228000, T300),
+    (543952800, T345),
+    (559677600, T300),
+    (575402400, T345),
+    (591127200, T300),
+    (606852000, T345),
+    (622576800, T300),
+    (638301600, T345),
+    (654631200, T300),
+    (670356000, T302),
+    (670359600, T337),
+    (686084400, T302),
+    (695761200, T300),
+    (701805600, T345),
+    (717530400, T300),
+    (733255200, T345),
+    (748980000, T300),
+    (764704800, T345),
+    (780429600, T300),
+    (796154400, T345),
+    (811879200, T300),
+    (828208800, T345),
+    (846352800, T300),
+    (859658400, T345),
+    (877802400, T300),
+    (891108000, T345),
+    (909252000, T300),
+    (922557600, T345),
+    (941306400, T300),
+    (954007200, T345),
+    (972756000, T300),
+    (985456800, T345),
+    (1004205600, T300),
+    (1017511200, T345),
+    (1035655200, T300),
+    (1048960800, T345),
+    (1067104800, T300),
+    (1080410400, T345),
+    (1099159200, T300),
+    (1111860000, T345),
+    (1130608800, T300),
+    (1143309600, T345),
+    (1162058400, T300),
+    (1174759200, T345),
+    (1193508000, T300),
+    (1206813600, T345),
+    (1224957600, T300),
+    (1238263200, T345),
+    (1256407200, T300),
+    (1269712800, T345),
+    (1288461600, T300),
+    (1301162400, T343),
+    (1414256400, T300),
+];
+
+static ASIA_JAKARTA: &[(i64, FixedTimespan<'static>)] = &[
+    (-1451719200, T371),
+    (-1172906400, T341),
+    (-876641400, T343),
+    (-766054800, T341),
+    (-683883000, T300),
+    (-620812800, T341),
+    (-189415800, T372),
+];
+
+static ASIA_JAYAPURA: &[(i64, FixedTimespan<'static>)] = &[
+    (-1172913768, T343),
+    (-799491600, T374),
+    (-189423000, T375),
+];
+
+static ASIA_JERUSALEM: &[(i64, FixedTimespan<'static>)] = &[
+    (-2840149254, T377),
+    (-1641003640, T359),
+    (-933638400, T360),
+    (-923097600, T359),
+    (-919036800, T360),
+    (-857347200, T359),
+    (-844300800, T360),
+    (-825811200, T359),
+    (-812678400, T360),
+    (-794188800, T359),
+    (-779846400, T360),
+    (-762652800, T359),
+    (-748310400, T360),
+    (-731116800, T359),
+    (-681955200, T378),
+    (-673228800, T360),
+    (-667958400, T359),
+    (-652320000, T360),
+    (-636422400, T359),
+    (-622080000, T360),
+    (-608947200, T359),
+    (-591840000, T360),
+    (-572486400, T359),
+    (-558576000, T360),
+    (-542851200, T359),
+    (-527731200, T360),
+    (-514425600, T359),
+    (-490838400, T360),
+    (-482976000, T359),
+    (-459388800, T360),
+    (-451526400, T359),
+    (-428544000, T360),
+    (-418262400, T359),
+    (-400118400, T360),
+    (-387417600, T359),
+    (142380000, T360),
+    (150843600, T359),
+    (167176800, T360),
+    (178664400, T359),
+    (334101600, T360),
+    (337730400, T359),
+    (452642400, T360),
+    (462319200, T359),
+    (482277600, T360),
+    (494370000, T359),
+    (516751200, T360),
+    (526424400, T359),
+    (545436000, T360),
+    (558478800, T359),
+    (576626400, T360),
+    (589323600, T359),
+    (609890400, T360),
+    (620773200, T359),
+    (638316000, T360),
+    (651618000, T359),
+    (669765600, T360),
+    (683672400, T359),
+    (701820000, T360),
+    (715726800, T359),
+    (733701600, T360),
+    (747176400, T359),
+    (765151200, T360),
+    (778021200, T359),
+    (796600800, T360),
+    (810075600, T359),
+    (826840800, T360),
+    (842821200, T359),
+    (858895200, T360),
+    (874184400, T359),
+    (890344800, T360),
+    (905029200, T359),
+    (923011200, T360),
+    (936313200, T359),
+    (955670400, T360),
+    (970783200, T359),
+    (986770800, T360),
+    (1001282400, T359),
+    (1017356400, T360),
+    (1033941600, T359),
+    (1048806000, T360),
+    (1065132000, T359),
+    (1081292400, T360),
+    (1095804000, T359),
+    (1112313600, T360),
+    (1128812400, T359),
+    (1143763200, T360),
+    (1159657200, T359),
+    (1175212800, T360),
+    (1189897200, T359),
+    (1206662400, T360),
+    (1223161200, T359),
+    (1238112000, T360),
+    (1254006000, T359),
+    (1269561600, T360),
+    (1284246000, T359),
+    (1301616000, T360),
+    (1317510000, T359),
+    (1333065600, T360),
+    (1348354800, T359),
+    (1364515200, T360),
+    (1382828400, T359),
+    (1395964800, T360),
+    (1414278000, T359),
+];
+
+static ASIA_KABUL: &[(i64, FixedTimespan<'static>)] = &[
+    (-2524538208, T324),
+    (-788932800, T380),
+];
+
+static ASIA_KAMCHATKA: &[(i64, FixedTimespan<'static>)] = &[
+    (-1487759676, T301),
+    (-1247569200, T318),
+    (354888000, T321),
+    (370695600, T318),
+    (386424000, T321),
+    (402231600, T318),
+    (417960000, T321),
+    (433767600, T318),
+    (449582400, T321),
+    (465314400, T318),
+    (481039200, T321),
+    (496764000, T318),
+    (512488800, T321),
+    (528213600, T318),
+    (543938400, T321),
+    (559663200, T318),
+    (575388000, T321),
+    (591112800, T318),
+    (606837600, T321),
+    (622562400, T318),
+    (638287200, T321),
+    (654616800, T318),
+    (670341600, T301),
+    (670345200, T322),
+    (686070000, T301),
+    (695746800, T318),
+    (701791200, T321),
+    (717516000, T318),
+    (733240800, T321),
+    (748965600, T318),
+    (764690400, T321),
+    (780415200, T318),
+    (796140000, T321),
+    (811864800, T318),
+    (828194400, T321),
+    (846338400, T318),
+    (859644000, T321),
+    (877788000, T318),
+    (891093600, T321),
+    (909237600, T318),
+    (922543200, T321),
+    (941292000, T318),
+    (953992800, T321),
+    (972741600, T318),
+    (985442400, T321),
+    (1004191200, T318),
+    (1017496800, T321),
+    (1035640800, T318),
+    (1048946400, T321),
+    (1067090400, T318),
+    (1080396000, T321),
+    (1099144800, T318),
+    (1111845600, T321),
+    (1130594400, T318),
+    (1143295200, T321),
+    (1162044000, T318),
+    (1174744800, T321),
+    (1193493600, T318),
+    (1206799200, T321),
+    (1224943200, T318),
+    (1238248800, T321),
+    (1256392800, T318),
+    (1269698400, T301),
+    (1269702000, T322),
+    (1288450800, T301),
+    (1301151600, T318),
+];
+
+static ASIA_KARACHI: &[(i64, FixedTimespan<'static>)] = &[
+    (-1988166492, T348),
+    (-862637400, T349),
+    (-764145000, T348),
+    (-576135000, T303),
+    (1018119600, T383),
+    (1033840800, T384),
+    (1212260400, T383),
+    (1225476000, T384),
+    (1239735600, T383),
+    (1257012000, T384),
+];
+
+static ASIA_KATHMANDU: &[(i64, FixedTimespan<'static>)] = &[
+    (-1577943676, T348),
+    (504901800, T386),
+];
+
+static ASIA_KHANDYGA: &[(i64, FixedTimespan<'static>)] = &[
+    (-1579424533, T300),
+    (-1247558400, T343),
+    (354898800, T344),
+    (370706400, T343),
+    (386434800, T344),
+    (402242400, T343),
+    (417970800, T344),
+    (433778400, T343),
+    (449593200, T344),
+    (465325200, T343),
+    (481050000, T344),
+    (496774800, T343),
+    (512499600, T344),
+    (528224400, T343),
+    (543949200, T344),
+    (559674000, T343),
+    (575398800, T344),
+    (591123600, T343),
+    (606848400, T344),
+    (622573200, T343),
+    (638298000, T344),
+    (654627600, T343),
+    (670352400, T300),
+    (670356000, T345),
+    (686080800, T300),
+    (695757600, T343),
+    (701802000, T344),
+    (717526800, T343),
+    (733251600, T344),
+    (748976400, T343),
+    (764701200, T344),
+    (780426000, T343),
+    (796150800, T344),
+    (811875600, T343),
+    (828205200, T344),
+    (846349200, T343),
+    (859654800, T344),
+    (877798800, T343),
+    (891104400, T344),
+    (909248400, T343),
+    (922554000, T344),
+    (941302800, T343),
+    (954003600, T344),
+    (972752400, T343),
+    (985453200, T344),
+    (1004202000, T343),
+    (1017507600, T344),
+    (1035651600, T343),
+    (1048957200, T344),
+    (1067101200, T343),
+    (1072882800, T304),
+    (1080403200, T388),
+    (1099152000, T304),
+    (1111852800, T388),
+    (1130601600, T304),
+    (1143302400, T388),
+    (1162051200, T304),
+    (1174752000, T388),
+    (1193500800, T304),
+    (1206806400, T388),
+    (1224950400, T304),
+    (1238256000, T388),
+    (1256400000, T304),
+    (1269705600, T388),
+    (1288454400, T304),
+    (1301155200, T301),
+    (1315832400, T304),
+    (1414252800, T343),
+];
+
+static ASIA_KOLKATA: &[(i64, FixedTimespan<'static>)] = &[
+    (-3645237208, T353),
+    (-3155694800, T390),
+    (-2019705670, T391),
+    (-891581400, T349),
+    (-872058600, T391),
+    (-862637400, T349),
+    (-764145000, T391),
+];
+
+static ASIA_KRASNOYARSK: &[(i64, FixedTimespan<'static>)] = &[
+    (-1577513486, T307),
+    (-1247551200, T302),
+    (354906000, T337),
+    (370713600, T302),
+    (386442000, T337),
+    (402249600, T302),
+    (417978000, T337),
+    (433785600, T302),
+    (449600400, T337),
+    (465332400, T302),
+    (481057200, T337),
+    (496782000, T302),
+    (512506800, T337),
+    (528231600, T302),
+    (543956400, T337),
+    (559681200, T302),
+    (575406000, T337),
+    (591130800, T302),
+    (606855600, T337),
+    (622580400, T302),
+    (638305200, T337),
+    (654634800, T302),
+    (670359600, T307),
+    (670363200, T314),
+    (686088000, T307),
+    (695764800, T302),
+    (701809200, T337),
+    (717534000, T302),
+    (733258800, T337),
+    (748983600, T302),
+    (764708400, T337),
+    (780433200, T302),
+    (796158000, T337),
+    (811882800, T302),
+    (828212400, T337),
+    (846356400, T302),
+    (859662000, T337),
+    (877806000, T302),
+    (891111600, T337),
+    (909255600, T302),
+    (922561200, T337),
+    (941310000, T302),
+    (954010800, T337),
+    (972759600, T302),
+    (985460400, T337),
+    (1004209200, T302),
+    (1017514800, T337),
+    (1035658800, T302),
+    (1048964400, T337),
+    (1067108400, T302),
+    (1080414000, T337),
+    (1099162800, T302),
+    (1111863600, T337),
+    (1130612400, T302),
+    (1143313200, T337),
+    (1162062000, T302),
+    (1174762800, T337),
+    (1193511600, T302),
+    (1206817200, T337),
+    (1224961200, T302),
+    (1238266800, T337),
+    (1256410800, T302),
+    (1269716400, T337),
+    (1288465200, T302),
+    (1301166000, T300),
+    (1414260000, T302),
+];
+
+static ASIA_KUALA_LUMPUR: &[(i64, FixedTimespan<'static>)] = &[
+    (-2177477206, T394),
+    (-2038200925, T302),
+    (-1167634800, T395),
+    (-894180000, T341),
+    (-879665400, T343),
+    (-767005200, T341),
+    (378662400, T300),
+];
+
+static ASIA_KUCHING: &[(i64, FixedTimespan<'static>)] = &[
+    (-1383463280, T341),
+    (-1167636600, T300),
+    (-1082448000, T397),
+    (-1074586800, T300),
+    (-1050825600, T397),
+    (-1042964400, T300),
+    (-1019289600, T397),
+    (-1011428400, T300),
+    (-987753600, T397),
+    (-979892400, T300),
+    (-956217600, T397),
+    (-948356400, T300),
+    (-924595200, T397),
+    (-916734000, T300),
+    (-893059200, T397),
+    (-885198000, T300),
+    (-879667200, T343),
+    (-767005200, T300),
+];
+
+static ASIA_KUWAIT: &[(i64, FixedTimespan<'static>)] = &[
+    (-631163516, T310),
+];
+
+static ASIA_MACAU: &[(i64, FixedTimespan<'static>)] = &[
+    (-2056692850, T400),
+    (-884509200, T343),
+    (-873280800, T344),
+    (-855918000, T343),
+    (-841744800, T344),
+    (-828529200, T343),
+    (-765363600, T400),
+    (-747046800, T401),
+    (-733827600, T400),
+    (-716461200, T401),
+    (-697021200, T400),
+    (-683715600, T401),
+    (-667990800, T400),
+    (-654771600, T401),
+    (-636627600, T400),
+    (-623322000, T401),
+    (-605178000, T400),
+    (-591872400, T401),
+    (-573642000, T400),
+    (-559818000, T401),
+    (-541674000, T400),
+    (-528368400, T401),
+    (-510224400, T400),
+    (-498128400, T401),
+    (-478774800, T400),
+    (-466678800, T401),
+    (-446720400, T400),
+    (-435229200, T401),
+    (-415258200, T400),
+    (-403158600, T401),
+    (-383808600, T400),
+    (-371709000, T401),
+    (-352359000, T400),
+    (-340259400, T401),
+    (-320909400, T400),
+    (-308809800, T401),
+    (-288855000, T400),
+    (-277360200, T401),
+    (-257405400, T400),
+    (-245910600, T401),
+    (-225955800, T400),
+    (-213856200, T401),
+    (-194506200, T400),
+    (-182406600, T401),
+    (-163056600, T400),
+    (-148537800, T401),
+    (-132820200, T400),
+    (-117088200, T401),
+    (-101370600, T400),
+    (-85638600, T401),
+    (-69312600, T400),
+    (-53584200, T401),
+    (-37863000, T400),
+    (-22134600, T401),
+    (-6413400, T400),
+    (9315000, T401),
+    (25036200, T400),
+    (40764600, T401),
+    (56485800, T400),
+    (72214200, T401),
+    (88540200, T400),
+    (104268600, T401),
+    (119989800, T400),
+    (126041400, T401),
+    (151439400, T400),
+    (167167800, T401),
+    (182889000, T400),
+    (198617400, T401),
+    (214338600, T400),
+    (295385400, T401),
+    (309292200, T400),
+];
+
+static ASIA_MAGADAN: &[(i64, FixedTimespan<'static>)] = &[
+    (-1441188192, T304),
+    (-1247565600, T301),
+    (354891600, T322),
+    (370699200, T301),
+    (386427600, T322),
+    (402235200, T301),
+    (417963600, T322),
+    (433771200, T301),
+    (449586000, T322),
+    (465318000, T301),
+    (481042800, T322),
+    (496767600, T301),
+    (512492400, T322),
+    (528217200, T301),
+    (543942000, T322),
+    (559666800, T301),
+    (575391600, T322),
+    (591116400, T301),
+    (606841200, T322),
+    (622566000, T301),
+    (638290800, T322),
+    (654620400, T301),
+    (670345200, T304),
+    (670348800, T388),
+    (686073600, T304),
+    (695750400, T301),
+    (701794800, T322),
+    (717519600, T301),
+    (733244400, T322),
+    (748969200, T301),
+    (764694000, T322),
+    (780418800, T301),
+    (796143600, T322),
+    (811868400, T301),
+    (828198000, T322),
+    (846342000, T301),
+    (859647600, T322),
+    (877791600, T301),
+    (891097200, T322),
+    (909241200, T301),
+    (922546800, T322),
+    (941295600, T301),
+    (953996400, T322),
+    (972745200, T301),
+    (985446000, T322),
+    (1004194800, T301),
+    (1017500400, T322),
+    (1035644400, T301),
+    (1048950000, T322),
+    (1067094000, T301),
+    (1080399600, T322),
+    (1099148400, T301),
+    (1111849200, T322),
+    (1130598000, T301),
+    (1143298800, T322),
+    (1162047600, T301),
+    (1174748400, T322),
+    (1193497200, T301),
+    (1206802800, T322),
+    (1224946800, T301),
+    (1238252400, T322),
+    (1256396400, T301),
+    (1269702000, T322),
+    (1288450800, T301),
+    (1301151600, T318),
+    (1414245600, T304),
+    (1461427200, T301),
+];
+
+static ASIA_MAKASSAR: &[(i64, FixedTimespan<'static>)] = &[
+    (-1172908656, T300),
+    (-880272000, T343),
+    (-766054800, T404),
+];
+
+static ASIA_MANILA: &[(i64, FixedTimespan<'static>)] = &[
+    (-3944621032, T406),
+    (-2219083200, T407),
+    (-1046678400, T408),
+    (-1040115600, T407),
+    (-885024000, T408),
+    (-760093200, T407),
+    (-496224000, T408),
+    (-491562000, T407),
+    (228326400, T408),
+    (243702000, T407),
+    (643219200, T408),
+    (649177200, T407),
+];
+
+static ASIA_MUSCAT: &[(i64, FixedTimespan<'static>)] = &[
+    (-1577937264, T324),
+];
+
+static ASIA_NICOSIA: &[(i64, FixedTimespan<'static>)] = &[
+    (-1518920008, T29),
+    (166572000, T30),
+    (182293200, T29),
+    (200959200, T30),
+    (213829200, T29),
+    (228866400, T30),
+    (243982800, T29),
+    (260316000, T30),
+    (276123600, T29),
+    (291765600, T30),
+    (307486800, T29),
+    (323820000, T30),
+    (338936400, T29),
+    (354664800, T30),
+    (370386000, T29),
+    (386114400, T30),
+    (401835600, T29),
+    (417564000, T30),
+    (433285200, T29),
+    (449013600, T30),
+    (465339600, T29),
+    (481068000, T30),
+    (496789200, T29),
+    (512517600, T30),
+    (528238800, T29),
+    (543967200, T30),
+    (559688400, T29),
+    (575416800, T30),
+    (591138000, T29),
+    (606866400, T30),
+    (622587600, T29),
+    (638316000, T30),
+    (654642000, T29),
+    (670370400, T30),
+    (686091600, T29),
+    (701820000, T30),
+    (717541200, T29),
+    (733269600, T30),
+    (748990800, T29),
+    (764719200, T30),
+    (780440400, T29),
+    (796168800, T30),
+    (811890000, T29),
+    (828223200, T30),
+    (843944400, T29),
+    (859672800, T30),
+    (875394000, T29),
+    (891122400, T30),
+    (909277200, T29),
+    (922582800, T30),
+    (941331600, T29),
+];
+
+static ASIA_NOVOKUZNETSK: &[(i64, FixedTimespan<'static>)] = &[
+    (-1441259328, T307),
+    (-1247551200, T302),
+    (354906000, T337),
+    (370713600, T302),
+    (386442000, T337),
+    (402249600, T302),
+    (417978000, T337),
+    (433785600, T302),
+    (449600400, T337),
+    (465332400, T302),
+    (481057200, T337),
+    (496782000, T302),
+    (512506800, T337),
+    (528231600, T302),
+    (543956400, T337),
+    (559681200, T302),
+    (575406000, T337),
+    (591130800, T302),
+    (606855600, T337),
+    (622580400, T302),
+    (638305200, T337),
+    (654634800, T302),
+    (670359600, T307),
+    (670363200, T314),
+    (686088000, T307),
+    (695764800, T302),
+    (701809200, T337),
+    (717534000, T302),
+    (733258800, T337),
+    (748983600, T302),
+    (764708400, T337),
+    (780433200, T302),
+    (796158000, T337),
+    (811882800, T302),
+    (828212400, T337),
+    (846356400, T302),
+    (859662000, T337),
+    (877806000, T302),
+    (891111600, T337),
+    (909255600, T302),
+    (922561200, T337),
+    (941310000, T302),
+    (954010800, T337),
+    (972759600, T302),
+    (985460400, T337),
+    (1004209200, T302),
+    (1017514800, T337),
+    (1035658800, T302),
+    (1048964400, T337),
+    (1067108400, T302),
+    (1080414000, T337),
+    (1099162800, T302),
+    (1111863600, T337),
+    (1130612400, T302),
+    (1143313200, T337),
+    (1162062000, T302),
+    (1174762800, T337),
+    (1193511600, T302),
+    (1206817200, T337),
+    (1224961200, T302),
+    (1238266800, T337),
+    (1256410800, T302),
+    (1269716400, T307),
+    (1269720000, T314),
+    (1288468800, T307),
+    (1301169600, T302),
+];
+
+static ASIA_NOVOSIBIRSK: &[(i64, FixedTimespan<'static>)] = &[
+    (-1579476700, T307),
+    (-1247551200, T302),
+    (354906000, T337),
+    (370713600, T302),
+    (386442000, T337),
+    (402249600, T302),
+    (417978000, T337),
+    (433785600, T302),
+    (449600400, T337),
+    (465332400, T302),
+    (481057200, T337),
+    (496782000, T302),
+    (512506800, T337),
+    (528231600, T302),
+    (543956400, T337),
+    (559681200, T302),
+    (575406000, T337),
+    (591130800, T302),
+    (606855600, T337),
+    (622580400, T302),
+    (638305200, T337),
+    (654634800, T302),
+    (670359600, T307),
+    (670363200, T314),
+    (686088000, T307),
+    (695764800, T302),
+    (701809200, T337),
+    (717534000, T302),
+    (733258800, T337),
+    (738086400, T314),
+    (748987200, T307),
+    (764712000, T314),
+    (780436800, T307),
+    (796161600, T314),
+    (811886400, T307),
+    (828216000, T314),
+    (846360000, T307),
+    (859665600, T314),
+    (877809600, T307),
+    (891115200, T314),
+    (909259200, T307),
+    (922564800, T314),
+    (941313600, T307),
+    (954014400, T314),
+    (972763200, T307),
+    (985464000, T314),
+    (1004212800, T307),
+    (1017518400, T314),
+    (1035662400, T307),
+    (1048968000, T314),
+    (1067112000, T307),
+    (1080417600, T314),
+    (1099166400, T307),
+    (1111867200, T314),
+    (1130616000, T307),
+    (1143316800, T314),
+    (1162065600, T307),
+    (1174766400, T314),
+    (1193515200, T307),
+    (1206820800, T314),
+    (1224964800, T307),
+    (1238270400, T314),
+    (1256414400, T307),
+    (1269720000, T314),
+    (1288468800, T307),
+    (1301169600, T302),
+    (1414263600, T307),
+    (1469304000, T302),
+];
+
+static ASIA_OMSK: &[(i64, FixedTimespan<'static>)] = &[
+    (-1582088010, T303),
+    (-1247547600, T307),
+    (354909600, T314),
+    (370717200, T307),
+    (386445600, T314),
+    (402253200, T307),
+    (417981600, T314),
+    (433789200, T307),
+    (449604000, T314),
+    (465336000, T307),
+    (481060800, T314),
+    (496785600, T307),
+    (512510400, T314),
+    (528235200, T307),
+    (543960000, T314),
+    (559684800, T307),
+    (575409600, T314),
+    (591134400, T307),
+    (606859200, T314),
+    (622584000, T307),
+    (638308800, T314),
+    (654638400, T307),
+    (670363200, T303),
+    (670366800, T315),
+    (686091600, T303),
+    (695768400, T307),
+    (701812800, T314),
+    (717537600, T307),
+    (733262400, T314),
+    (748987200, T307),
+    (764712000, T314),
+    (780436800, T307),
+    (796161600, T314),
+    (811886400, T307),
+    (828216000, T314),
+    (846360000, T307),
+    (859665600, T314),
+    (877809600, T307),
+    (891115200, T314),
+    (909259200, T307),
+    (922564800, T314),
+    (941313600, T307),
+    (954014400, T314),
+    (972763200, T307),
+    (985464000, T314),
+    (1004212800, T307),
+    (1017518400, T314),
+    (1035662400, T307),
+    (1048968000, T314),
+    (1067112000, T307),
+    (1080417600, T314),
+    (1099166400, T307),
+    (1111867200, T314),
+    (1130616000, T307),
+    (1143316800, T314),
+    (1162065600, T307),
+    (1174766400, T314),
+    (1193515200, T307),
+    (1206820800, T314),
+    (1224964800, T307),
+    (1238270400, T314),
+    (1256414400, T307),
+    (1269720000, T314),
+    (1288468800, T307),
+    (1301169600, T302),
+    (1414263600, T307),
+];
+
+static ASIA_ORAL: &[(i64, FixedTimespan<'static>)] = &[
+    (-1441164324, T310),
+    (-1247540400, T303),
+    (354913200, T315),
+    (386445600, T303),
+    (386449200, T315),
+    (402256800, T303),
+    (417985200, T315),
+    (433792800, T303),
+    (449607600, T315),
+    (465339600, T303),
+    (481064400, T315),
+    (496789200, T303),
+    (512514000, T315),
+    (528238800, T303),
+    (543963600, T315),
+    (559688400, T303),
+    (575413200, T315),
+    (591138000, T303),
+    (606862800, T324),
+    (606866400, T325),
+    (622591200, T324),
+    (638316000, T325),
+    (654645600, T324),
+    (670370400, T325),
+    (686095200, T324),
+    (695772000, T303),
+    (701816400, T324),
+    (701820000, T325),
+    (717544800, T324),
+    (733269600, T325),
+    (748994400, T324),
+    (764719200, T325),
+    (780444000, T324),
+    (796168800, T325),
+    (811893600, T324),
+    (828223200, T325),
+    (846367200, T324),
+    (859672800, T325),
+    (877816800, T324),
+    (891122400, T325),
+    (909266400, T324),
+    (922572000, T325),
+    (941320800, T324),
+    (954021600, T325),
+    (972770400, T324),
+    (985471200, T325),
+    (1004220000, T324),
+    (1017525600, T325),
+    (1035669600, T324),
+    (1048975200, T325),
+    (1067119200, T324),
+    (1080424800, T325),
+];
+
+static ASIA_PHNOM_PENH: &[(i64, FixedTimespan<'static>)] = &[
+    (-2004073180, T416),
+    (-1851577590, T302),
+    (-852105600, T300),
+    (-782643600, T343),
+    (-767869200, T302),
+];
+
+static ASIA_PONTIANAK: &[(i64, FixedTimespan<'static>)] = &[
+    (-1172906240, T341),
+    (-881220600, T343),
+    (-766054800, T341),
+    (-683883000, T300),
+    (-620812800, T341),
+    (-189415800, T404),
+    (567964800, T372),
+];
+
+static ASIA_PYONGYANG: &[(i64, FixedTimespan<'static>)] = &[
+    (-1948782180, T419),
+    (-1830414600, T367),
+    (1439564400, T419),
+    (1525446000, T420),
+];
+
+static ASIA_QATAR: &[(i64, FixedTimespan<'static>)] = &[
+    (-1577935568, T324),
+    (76190400, T310),
+];
+
+static ASIA_QOSTANAY: &[(i64, FixedTimespan<'static>)] = &[
+    (-1441167268, T324),
+    (-1247544000, T303),
+    (354913200, T315),
+    (386445600, T303),
+    (386449200, T315),
+    (402256800, T303),
+    (417985200, T315),
+    (433792800, T303),
+    (449607600, T315),
+    (465339600, T303),
+    (481064400, T315),
+    (496789200, T303),
+    (512514000, T315),
+    (528238800, T303),
+    (543963600, T315),
+    (559688400, T303),
+    (575413200, T315),
+    (591138000, T303),
+    (606862800, T315),
+    (622587600, T303),
+    (638312400, T315),
+    (654642000, T303),
+    (670366800, T324),
+    (670370400, T325),
+    (686095200, T324),
+    (695772000, T303),
+    (701816400, T315),
+    (717541200, T303),
+    (733266000, T315),
+    (748990800, T303),
+    (764715600, T315),
+    (780440400, T303),
+    (796165200, T315),
+    (811890000, T303),
+    (828219600, T315),
+    (846363600, T303),
+    (859669200, T315),
+    (877813200, T303),
+    (891118800, T315),
+    (909262800, T303),
+    (922568400, T315),
+    (941317200, T303),
+    (954018000, T315),
+    (972766800, T303),
+    (985467600, T315),
+    (1004216400, T303),
+    (1017522000, T315),
+    (1035666000, T303),
+    (1048971600, T315),
+    (1067115600, T303),
+    (1080421200, T315),
+    (1709229600, T303),
+];
+
+static ASIA_QYZYLORDA: &[(i64, FixedTimespan<'static>)] = &[
+    (-1441167712, T324),
+    (-1247544000, T303),
+    (354913200, T315),
+    (386445600, T303),
+    (386449200, T315),
+    (402256800, T303),
+    (417985200, T315),
+    (433792800, T303),
+    (449607600, T315),
+    (465339600, T303),
+    (481064400, T315),
+    (496789200, T303),
+    (512514000, T315),
+    (528238800, T303),
+    (543963600, T315),
+    (559688400, T303),
+    (575413200, T315),
+    (591138000, T303),
+    (606862800, T315),
+    (622587600, T303),
+    (638312400, T315),
+    (654642000, T303),
+    (670366800, T324),
+    (670370400, T325),
+    (695768400, T307),
+    (701812800, T303),
+    (701816400, T315),
+    (717541200, T303),
+    (733266000, T315),
+    (748990800, T303),
+    (764715600, T315),
+    (780440400, T303),
+    (796165200, T315),
+    (811890000, T303),
+    (828219600, T315),
+    (846363600, T303),
+    (859669200, T315),
+    (877813200, T303),
+    (891118800, T315),
+    (909262800, T303),
+    (922568400, T315),
+    (941317200, T303),
+    (954018000, T315),
+    (972766800, T303),
+    (985467600, T315),
+    (1004216400, T303),
+    (1017522000, T315),
+    (1035666000, T303),
+    (1048971600, T315),
+    (1067115600, T303),
+    (1080421200, T315),
+    (1545328800, T303),
+];
+
+static ASIA_RIYADH: &[(i64, FixedTimespan<'static>)] = &[
+    (-719636812, T310),
+];
+
+static ASIA_SAKHALIN: &[(i64, FixedTimespan<'static>)] = &[
+    (-2031039048, T343),
+    (-768560400, T301),
+    (354891600, T322),
+    (370699200, T301),
+    (386427600, T322),
+    (402235200, T301),
+    (417963600, T322),
+    (433771200, T301),
+    (449586000, T322),
+    (465318000, T301),
+    (481042800, T322),
+    (496767600, T301),
+    (512492400, T322),
+    (528217200, T301),
+    (543942000, T322),
+    (559666800, T301),
+    (575391600, T322),
+    (591116400, T301),
+    (606841200, T322),
+    (622566000, T301),
+    (638290800, T322),
+    (654620400, T301),
+    (670345200, T304),
+    (670348800, T388),
+    (686073600, T304),
+    (695750400, T301),
+    (701794800, T322),
+    (717519600, T301),
+    (733244400, T322),
+    (748969200, T301),
+    (764694000, T322),
+    (780418800, T301),
+    (796143600, T322),
+    (811868400, T301),
+    (828198000, T322),
+    (846342000, T301),
+    (859647600, T304),
+    (859651200, T388),
+    (877795200, T304),
+    (891100800, T388),
+    (909244800, T304),
+    (922550400, T388),
+    (941299200, T304),
+    (954000000, T388),
+    (972748800, T304),
+    (985449600, T388),
+    (1004198400, T304),
+    (1017504000, T388),
+    (1035648000, T304),
+    (1048953600, T388),
+    (1067097600, T304),
+    (1080403200, T388),
+    (1099152000, T304),
+    (1111852800, T388),
+    (1130601600, T304),
+    (1143302400, T388),
+    (1162051200, T304),
+    (1174752000, T388),
+    (1193500800, T304),
+    (1206806400, T388),
+    (1224950400, T304),
+    (1238256000, T388),
+    (1256400000, T304),
+    (1269705600, T388),
+    (1288454400, T304),
+    (1301155200, T301),
+    (1414249200, T304),
+    (1459008000, T301),
+];
+
+static ASIA_SAMARKAND: &[(i64, FixedTimespan<'static>)] = &[
+    (-1441168073, T324),
+    (-1247544000, T303),
+    (354913200, T315),
+    (386445600, T303),
+    (386449200, T315),
+    (402256800, T303),
+    (417985200, T315),
+    (433792800, T303),
+    (449607600, T315),
+    (465339600, T303),
+    (481064400, T315),
+    (496789200, T303),
+    (512514000, T315),
+    (528238800, T303),
+    (543963600, T315),
+    (559688400, T303),
+    (575413200, T315),
+    (591138000, T303),
+    (606862800, T315),
+    (622587600, T303),
+    (638312400, T315),
+    (654642000, T303),
+    (670366800, T315),
+    (686091600, T303),
+];
+
+static ASIA_SEOUL: &[(i64, FixedTimespan<'static>)] = &[
+    (-1948782472, T419),
+    (-1830414600, T367),
+    (-681210000, T428),
+    (-672228000, T420),
+    (-654771600, T428),
+    (-640864800, T420),
+    (-623408400, T428),
+    (-609415200, T420),
+    (-588848400, T428),
+    (-577965600, T420),
+    (-498128400, T419),
+    (-462702600, T429),
+    (-451733400, T419),
+    (-429784200, T429),
+    (-418296600, T419),
+    (-399544200, T429),
+    (-387451800, T419),
+    (-368094600, T429),
+    (-356002200, T419),
+    (-336645000, T429),
+    (-324552600, T419),
+    (-305195400, T429),
+    (-293103000, T419),
+    (-264933000, T420),
+    (547578000, T428),
+    (560883600, T420),
+    (579027600, T428),
+    (592333200, T420),
+];
+
+static ASIA_SHANGHAI: &[(i64, FixedTimespan<'static>)] = &[
+    (-2177481943, T400),
+    (-1600675200, T401),
+    (-1585904400, T400),
+    (-933667200, T401),
+    (-922093200, T400),
+    (-908870400, T401),
+    (-888829200, T400),
+    (-881049600, T401),
+    (-767869200, T400),
+    (-745833600, T401),
+    (-733827600, T400),
+    (-716889600, T401),
+    (-699613200, T400),
+    (-683884800, T401),
+    (-670669200, T400),
+    (-652348800, T401),
+    (-650019600, T400),
+    (515527200, T401),
+    (527014800, T400),
+    (545162400, T401),
+    (558464400, T400),
+    (577216800, T401),
+    (589914000, T400),
+    (608666400, T401),
+    (621968400, T400),
+    (640116000, T401),
+    (653418000, T400),
+    (671565600, T401),
+    (684867600, T400),
+];
+
+static ASIA_SINGAPORE: &[(i64, FixedTimespan<'static>)] = &[
+    (-2038200925, T302),
+    (-1167634800, T395),
+    (-894180000, T341),
+    (-879665400, T343),
+    (-767005200, T341),
+    (378662400, T300),
+];
+
+static ASIA_SREDNEKOLYMSK: &[(i64, FixedTimespan<'static>)] = &[
+    (-1441188892, T304),
+    (-1247565600, T301),
+    (354891600, T322),
+    (370699200, T301),
+    (386427600, T322),
+    (402235200, T301),
+    (417963600, T322),
+    (433771200, T301),
+    (449586000, T322),
+    (465318000, T301),
+    (481042800, T322),
+    (496767600, T301),
+    (512492400, T322),
+    (528217200, T301),
+    (543942000, T322),
+    (559666800, T301),
+    (575391600, T322),
+    (591116400, T301),
+    (606841200, T322),
+    (622566000, T301),
+    (638290800, T322),
+    (654620400, T301),
+    (670345200, T304),
+    (670348800, T388),
+    (686073600, T304),
+    (695750400, T301),
+    (701794800, T322),
+    (717519600, T301),
+    (733244400, T322),
+    (748969200, T301),
+    (764694000, T322),
+    (780418800, T301),
+    (796143600, T322),
+    (811868400, T301),
+    (828198000, T322),
+    (846342000, T301),
+    (859647600, T322),
+    (877791600, T301),
+    (891097200, T322),
+    (909241200, T301),
+    (922546800, T322),
+    (941295600, T301),
+    (953996400, T322),
+    (972745200, T301),
+    (985446000, T322),
+    (1004194800, T301),
+    (1017500400, T322),
+    (1035644400, T301),
+    (1048950000, T322),
+    (1067094000, T301),
+    (1080399600, T322),
+    (1099148400, T301),
+    (1111849200, T322),
+    (1130598000, T301),
+    (1143298800, T322),
+    (1162047600, T301),
+    (1174748400, T322),
+    (1193497200, T301),
+    (1206802800, T322),
+    (1224946800, T301),
+    (1238252400, T322),
+    (1256396400, T301),
+    (1269702000, T322),
+    (1288450800, T301),
+    (1301151600, T318),
+    (1414245600, T301),
+];
+
+static ASIA_TAIPEI: &[(i64, FixedTimespan<'static>)] = &[
+    (-2335248360, T400),
+    (-1017820800, T367),
+    (-766224000, T400),
+    (-745833600, T401),
+    (-733827600, T400),
+    (-716889600, T401),
+    (-699613200, T400),
+    (-683884800, T401),
+    (-670669200, T400),
+    (-652348800, T401),
+    (-639133200, T400),
+    (-620812800, T401),
+    (-607597200, T400),
+    (-589276800, T401),
+    (-576061200, T400),
+    (-562924800, T401),
+    (-541760400, T400),
+    (-528710400, T401),
+    (-510224400, T400),
+    (-497174400, T401),
+    (-478688400, T400),
+    (-465638400, T401),
+    (-449830800, T400),
+    (-434016000, T401),
+    (-418208400, T400),
+    (-402480000, T401),
+    (-386672400, T400),
+    (-370944000, T401),
+    (-355136400, T400),
+    (-339408000, T401),
+    (-323600400, T400),
+    (-302515200, T401),
+    (-291978000, T400),
+    (-270979200, T401),
+    (-260442000, T400),
+    (133977600, T401),
+    (149785200, T400),
+    (165513600, T401),
+    (181321200, T400),
+    (299606400, T401),
+    (307551600, T400),
+];
+
+static ASIA_TASHKENT: &[(i64, FixedTimespan<'static>)] = &[
+    (-1441168631, T303),
+    (-1247547600, T307),
+    (354909600, T314),
+    (370717200, T307),
+    (386445600, T314),
+    (402253200, T307),
+    (417981600, T314),
+    (433789200, T307),
+    (449604000, T314),
+    (465336000, T307),
+    (481060800, T314),
+    (496785600, T307),
+    (512510400, T314),
+    (528235200, T307),
+    (543960000, T314),
+    (559684800, T307),
+    (575409600, T314),
+    (591134400, T307),
+    (606859200, T314),
+    (622584000, T307),
+    (638308800, T314),
+    (654638400, T307),
+    (670363200, T303),
+    (670366800, T315),
+    (686091600, T303),
+];
+
+static ASIA_TBILISI: &[(i64, FixedTimespan<'static>)] = &[
+    (-1441162751, T310),
+    (-405140400, T324),
+    (354916800, T325),
+    (370724400, T324),
+    (386452800, T325),
+    (402260400, T324),
+    (417988800, T325),
+    (433796400, T324),
+    (449611200, T325),
+    (465343200, T324),
+    (481068000, T325),
+    (496792800, T324),
+    (512517600, T325),
+    (528242400, T324),
+    (543967200, T325),
+    (559692000, T324),
+    (575416800, T325),
+    (591141600, T324),
+    (606866400, T325),
+    (622591200, T324),
+    (638316000, T325),
+    (654645600, T324),
+    (670370400, T310),
+    (670374000, T331),
+    (686098800, T310),
+    (701816400, T331),
+    (717537600, T310),
+    (733266000, T331),
+    (748987200, T310),
+    (764715600, T331),
+    (796161600, T325),
+    (811882800, T324),
+    (828216000, T325),
+    (859662000, T324),
+    (859665600, T325),
+    (877806000, T324),
+    (891115200, T325),
+    (909255600, T324),
+    (922564800, T325),
+    (941310000, T324),
+    (954014400, T325),
+    (972759600, T324),
+    (985464000, T325),
+    (1004209200, T324),
+    (1017518400, T325),
+    (1035658800, T324),
+    (1048968000, T325),
+    (1067108400, T324),
+    (1080417600, T325),
+    (1088276400, T331),
+    (1099177200, T310),
+    (1111878000, T324),
+];
+
+static ASIA_TEHRAN: &[(i64, FixedTimespan<'static>)] = &[
+    (-1090466744, T333),
+    (227820600, T437),
+    (246223800, T324),
+    (259617600, T325),
+    (271108800, T324),
+    (279576000, T333),
+    (296598600, T437),
+    (306531000, T333),
+    (322432200, T437),
+    (338499000, T333),
+    (673216200, T437),
+    (685481400, T333),
+    (701209800, T437),
+    (717103800, T333),
+    (732745800, T437),
+    (748639800, T333),
+    (764281800, T437),
+    (780175800, T333),
+    (795817800, T437),
+    (811711800, T333),
+    (827353800, T437),
+    (843247800, T333),
+    (858976200, T437),
+    (874870200, T333),
+    (890512200, T437),
+    (906406200, T333),
+    (922048200, T437),
+    (937942200, T333),
+    (953584200, T437),
+    (969478200, T333),
+    (985206600, T437),
+    (1001100600, T333),
+    (1016742600, T437),
+    (1032636600, T333),
+    (1048278600, T437),
+    (1064172600, T333),
+    (1079814600, T437),
+    (1095708600, T333),
+    (1111437000, T437),
+    (1127331000, T333),
+    (1206045000, T437),
+    (1221939000, T333),
+    (1237667400, T437),
+    (1253561400, T333),
+    (1269203400, T437),
+    (1285097400, T333),
+    (1300739400, T437),
+    (1316633400, T333),
+    (1332275400, T437),
+    (1348169400, T333),
+    (1363897800, T437),
+    (1379791800, T333),
+    (1395433800, T437),
+    (1411327800, T333),
+    (1426969800, T437),
+    (1442863800, T333),
+    (1458505800, T437),
+    (1474399800, T333),
+    (1490128200, T437),
+    (1506022200, T333),
+    (1521664200, T437),
+    (1537558200, T333),
+    (1553200200, T437),
+    (1569094200, T333),
+    (1584736200, T437),
+    (1600630200, T333),
+    (1616358600, T437),
+    (1632252600, T333),
+    (1647894600, T437),
+    (1663788600, T333),
+];
+
+static ASIA_THIMPHU: &[(i64, FixedTimespan<'static>)] = &[
+    (-706341516, T348),
+    (560025000, T307),
+];
+
+static ASIA_TOKYO: &[(i64, FixedTimespan<'static>)] = &[
+    (-2587712400, T367),
+    (-683802000, T440),
+    (-672310800, T367),
+    (-654771600, T440),
+    (-640861200, T367),
+    (-620298000, T440),
+    (-609411600, T367),
+    (-588848400, T440),
+    (-577962000, T367),
+];
+
+static ASIA_TOMSK: &[(i64, FixedTimespan<'static>)] = &[
+    (-1578807591, T307),
+    (-1247551200, T302),
+    (354906000, T337),
+    (370713600, T302),
+    (386442000, T337),
+    (402249600, T302),
+    (417978000, T337),
+    (433785600, T302),
+    (449600400, T337),
+    (465332400, T302),
+    (481057200, T337),
+    (496782000, T302),
+    (512506800, T337),
+    (528231600, T302),
+    (543956400, T337),
+    (559681200, T302),
+    (575406000, T337),
+    (591130800, T302),
+    (606855600, T337),
+    (622580400, T302),
+    (638305200, T337),
+    (654634800, T302),
+    (670359600, T307),
+    (670363200, T314),
+    (686088000, T307),
+    (695764800, T302),
+    (701809200, T337),
+    (717534000, T302),
+    (733258800, T337),
+    (748983600, T302),
+    (764708400, T337),
+    (780433200, T302),
+    (796158000, T337),
+    (811882800, T302),
+    (828212400, T337),
+    (846356400, T302),
+    (859662000, T337),
+    (877806000, T302),
+    (891111600, T337),
+    (909255600, T302),
+    (922561200, T337),
+    (941310000, T302),
+    (954010800, T337),
+    (972759600, T302),
+    (985460400, T337),
+    (1004209200, T302),
+    (1017514800, T337),
+    (1020193200, T314),
+    (1035662400, T307),
+    (1048968000, T314),
+    (1067112000, T307),
+    (1080417600, T314),
+    (1099166400, T307),
+    (1111867200, T314),
+    (1130616000, T307),
+    (1143316800, T314),
+    (1162065600, T307),
+    (1174766400, T314),
+    (1193515200, T307),
+    (1206820800, T314),
+    (1224964800, T307),
+    (1238270400, T314),
+    (1256414400, T307),
+    (1269720000, T314),
+    (1288468800, T307),
+    (1301169600, T302),
+    (1414263600, T307),
+    (1464465600, T302),
+];
+
+static ASIA_ULAANBAATAR: &[(i64, FixedTimespan<'static>)] = &[
+    (-2032931252, T302),
+    (252435600, T300),
+    (417974400, T345),
+    (433782000, T300),
+    (449596800, T345),
+    (465318000, T300),
+    (481046400, T345),
+    (496767600, T300),
+    (512496000, T345),
+    (528217200, T300),
+    (543945600, T345),
+    (559666800, T300),
+    (575395200, T345),
+    (591116400, T300),
+    (606844800, T345),
+    (622566000, T300),
+    (638294400, T345),
+    (654620400, T300),
+    (670348800, T345),
+    (686070000, T300),
+    (701798400, T345),
+    (717519600, T300),
+    (733248000, T345),
+    (748969200, T300),
+    (764697600, T345),
+    (780418800, T300),
+    (796147200, T345),
+    (811868400, T300),
+    (828201600, T345),
+    (843922800, T300),
+    (859651200, T345),
+    (875372400, T300),
+    (891100800, T345),
+    (906822000, T300),
+    (988394400, T345),
+    (1001696400, T300),
+    (1017424800, T345),
+    (1033146000, T300),
+    (1048874400, T345),
+    (1064595600, T300),
+    (1080324000, T345),
+    (1096045200, T300),
+    (1111773600, T345),
+    (1127494800, T300),
+    (1143223200, T345),
+    (1159549200, T300),
+    (1427479200, T345),
+    (1443193200, T300),
+    (1458928800, T345),
+    (1474642800, T300),
+];
+
+static ASIA_URUMQI: &[(i64, FixedTimespan<'static>)] = &[
+    (-1325483420, T307),
+];
+
+static ASIA_UST_MINUS_NERA: &[(i64, FixedTimespan<'static>)] = &[
+    (-1579426374, T300),
+    (-1247558400, T343),
+    (354898800, T322),
+    (370699200, T301),
+    (386427600, T322),
+    (402235200, T301),
+    (417963600, T322),
+    (433771200, T301),
+    (449586000, T322),
+    (465318000, T301),
+    (481042800, T322),
+    (496767600, T301),
+    (512492400, T322),
+    (528217200, T301),
+    (543942000, T322),
+    (559666800, T301),
+    (575391600, T322),
+    (591116400, T301),
+    (606841200, T322),
+    (622566000, T301),
+    (638290800, T322),
+    (654620400, T301),
+    (670345200, T304),
+    (670348800, T388),
+    (686073600, T304),
+    (695750400, T301),
+    (701794800, T322),
+    (717519600, T301),
+    (733244400, T322),
+    (748969200, T301),
+    (764694000, T322),
+    (780418800, T301),
+    (796143600, T322),
+    (811868400, T301),
+    (828198000, T322),
+    (846342000, T301),
+    (859647600, T322),
+    (877791600, T301),
+    (891097200, T322),
+    (909241200, T301),
+    (922546800, T322),
+    (941295600, T301),
+    (953996400, T322),
+    (972745200, T301),
+    (985446000, T322),
+    (1004194800, T301),
+    (1017500400, T322),
+    (1035644400, T301),
+    (1048950000, T322),
+    (1067094000, T301),
+    (1080399600, T322),
+    (1099148400, T301),
+    (1111849200, T322),
+    (1130598000, T301),
+    (1143298800, T322),
+    (1162047600, T301),
+    (1174748400, T322),
+    (1193497200, T301),
+    (1206802800, T322),
+    (1224946800, T301),
+    (1238252400, T322),
+    (1256396400, T301),
+    (1269702000, T322),
+    (1288450800, T301),
+    (1301151600, T318),
+    (1315828800, T301),
+    (1414249200, T304),
+];
+
+static ASIA_VIENTIANE: &[(i64, FixedTimespan<'static>)] = &[
+    (-2004072624, T416),
+    (-1851577590, T302),
+    (-852105600, T300),
+    (-782643600, T343),
+    (-767869200, T302),
+    (-718095600, T300),
+    (-464428800, T302),
+];
+
+static ASIA_VLADIVOSTOK: &[(i64, FixedTimespan<'static>)] = &[
+    (-1487321251, T343),
+    (-1247562000, T304),
+    (354895200, T388),
+    (370702800, T304),
+    (386431200, T388),
+    (402238800, T304),
+    (417967200, T388),
+    (433774800, T304),
+    (449589600, T388),
+    (465321600, T304),
+    (481046400, T388),
+    (496771200, T304),
+    (512496000, T388),
+    (528220800, T304),
+    (543945600, T388),
+    (559670400, T304),
+    (575395200, T388),
+    (591120000, T304),
+    (606844800, T388),
+    (622569600, T304),
+    (638294400, T388),
+    (654624000, T304),
+    (670348800, T343),
+    (670352400, T344),
+    (686077200, T343),
+    (695754000, T304),
+    (701798400, T388),
+    (717523200, T304),
+    (733248000, T388),
+    (748972800, T304),
+    (764697600, T388),
+    (780422400, T304),
+    (796147200, T388),
+    (811872000, T304),
+    (828201600, T388),
+    (846345600, T304),
+    (859651200, T388),
+    (877795200, T304),
+    (891100800, T388),
+    (909244800, T304),
+    (922550400, T388),
+    (941299200, T304),
+    (954000000, T388),
+    (972748800, T304),
+    (985449600, T388),
+    (1004198400, T304),
+    (1017504000, T388),
+    (1035648000, T304),
+    (1048953600, T388),
+    (1067097600, T304),
+    (1080403200, T388),
+    (1099152000, T304),
+    (1111852800, T388),
+    (1130601600, T304),
+    (1143302400, T388),
+    (1162051200, T304),
+    (1174752000, T388),
+    (1193500800, T304),
+    (1206806400, T388),
+    (1224950400, T304),
+    (1238256000, T388),
+    (1256400000, T304),
+    (1269705600, T388),
+    (1288454400, T304),
+    (1301155200, T301),
+    (1414249200, T304),
+];
+
+static ASIA_YAKUTSK: &[(i64, FixedTimespan<'static>)] = &[
+    (-1579423138, T300),
+    (-1247558400, T343),
+    (354898800, T344),
+    (370706400, T343),
+    (386434800, T344),
+    (402242400, T343),
+    (417970800, T344),
+    (433778400, T343),
+    (449593200, T344),
+    (465325200, T343),
+    (481050000, T344),
+    (496774800, T343),
+    (512499600, T344),
+    (528224400, T343),
+    (543949200, T344),
+    (559674000, T343),
+    (575398800, T344),
+    (591123600, T343),
+    (606848400, T344),
+    (622573200, T343),
+    (638298000, T344),
+    (654627600, T343),
+    (670352400, T300),
+    (670356000, T345),
+    (686080800, T300),
+    (695757600, T343),
+    (701802000, T344),
+    (717526800, T343),
+    (733251600, T344),
+    (748976400, T343),
+    (764701200, T344),
+    (780426000, T343),
+    (796150800, T344),
+    (811875600, T343),
+    (828205200, T344),
+    (846349200, T343),
+    (859654800, T344),
+    (877798800, T343),
+    (891104400, T344),
+    (909248400, T343),
+    (922554000, T344),
+    (941302800, T343),
+    (954003600, T344),
+    (972752400, T343),
+    (985453200, T344),
+    (1004202000, T343),
+    (1017507600, T344),
+    (1035651600, T343),
+    (1048957200, T344),
+    (1067101200, T343),
+    (1080406800, T344),
+    (1099155600, T343),
+    (1111856400, T344),
+    (1130605200, T343),
+    (1143306000, T344),
+    (1162054800, T343),
+    (1174755600, T344),
+    (1193504400, T343),
+    (1206810000, T344),
+    (1224954000, T343),
+    (1238259600, T344),
+    (1256403600, T343),
+    (1269709200, T344),
+    (1288458000, T343),
+    (1301158800, T304),
+    (1414252800, T343),
+];
+
+static ASIA_YANGON: &[(i64, FixedTimespan<'static>)] = &[
+    (-1577946287, T350),
+    (-873268200, T343),
+    (-778410000, T350),
+];
+
+static ASIA_YEKATERINBURG: &[(i64, FixedTimespan<'static>)] = &[
+    (-1688270553, T450),
+    (-1592610305, T324),
+    (-1247544000, T303),
+    (354913200, T315),
+    (370720800, T303),
+    (386449200, T315),
+    (402256800, T303),
+    (417985200, T315),
+    (433792800, T303),
+    (449607600, T315),
+    (465339600, T303),
+    (481064400, T315),
+    (496789200, T303),
+    (512514000, T315),
+    (528238800, T303),
+    (543963600, T315),
+    (559688400, T303),
+    (575413200, T315),
+    (591138000, T303),
+    (606862800, T315),
+    (622587600, T303),
+    (638312400, T315),
+    (654642000, T303),
+    (670366800, T324),
+    (670370400, T325),
+    (686095200, T324),
+    (695772000, T303),
+    (701816400, T315),
+    (717541200, T303),
+    (733266000, T315),
+    (748990800, T303),
+    (764715600, T315),
+    (780440400, T303),
+    (796165200, T315),
+    (811890000, T303),
+    (828219600, T315),
+    (846363600, T303),
+    (859669200, T315),
+    (877813200, T303),
+    (891118800, T315),
+    (909262800, T303),
+    (922568400, T315),
+    (941317200, T303),
+    (954018000, T315),
+    (972766800, T303),
+    (985467600, T315),
+    (1004216400, T303),
+    (1017522000, T315),
+    (1035666000, T303),
+    (1048971600, T315),
+    (1067115600, T303),
+    (1080421200, T315),
+    (1099170000, T303),
+    (1111870800, T315),
+    (1130619600, T303),
+    (1143320400, T315),
+    (1162069200, T303),
+    (1174770000, T315),
+    (1193518800, T303),
+    (1206824400, T315),
+    (1224968400, T303),
+    (1238274000, T315),
+    (1256418000, T303),
+    (1269723600, T315),
+    (1288472400, T303),
+    (1301173200, T307),
+    (1414267200, T303),
+];
+
+static ASIA_YEREVAN: &[(i64, FixedTimespan<'static>)] = &[
+    (-1441162680, T310),
+    (-405140400, T324),
+    (354916800, T325),
+    (370724400, T324),
+    (386452800, T325),
+    (402260400, T324),
+    (417988800, T325),
+    (433796400, T324),
+    (449611200, T325),
+    (465343200, T324),
+    (481068000, T325),
+    (496792800, T324),
+    (512517600, T325),
+    (528242400, T324),
+    (543967200, T325),
+    (559692000, T324),
+    (575416800, T325),
+    (591141600, T324),
+    (606866400, T325),
+    (622591200, T324),
+    (638316000, T325),
+    (654645600, T324),
+    (670370400, T310),
+    (670374000, T331),
+    (686098800, T310),
+    (701823600, T331),
+    (717548400, T310),
+    (733273200, T331),
+    (748998000, T310),
+    (764722800, T331),
+    (780447600, T310),
+    (796172400, T331),
+    (859672800, T325),
+    (877816800, T324),
+    (891122400, T325),
+    (909266400, T324),
+    (922572000, T325),
+    (941320800, T324),
+    (954021600, T325),
+    (972770400, T324),
+    (985471200, T325),
+    (1004220000, T324),
+    (1017525600, T325),
+    (1035669600, T324),
+    (1048975200, T325),
+    (1067119200, T324),
+    (1080424800, T325),
+    (1099173600, T324),
+    (1111874400, T325),
+    (1130623200, T324),
+    (1143324000, T325),
+    (1162072800, T324),
+    (1174773600, T325),
+    (1193522400, T324),
+    (1206828000, T325),
+    (1224972000, T324),
+    (1238277600, T325),
+    (1256421600, T324),
+    (1269727200, T325),
+    (1288476000, T324),
+    (1301176800, T325),
+    (1319925600, T324),
+];
+
+static ATLANTIC_AZORES: &[(i64, FixedTimespan<'static>)] = &[
+    (-2713904240, T453),
+    (-1830376800, T252),
+    (-1689548400, T253),
+    (-1677794400, T252),
+    (-1667426400, T253),
+    (-1647730800, T252),
+    (-1635890400, T253),
+    (-1616194800, T252),
+    (-1604354400, T253),
+    (-1584658800, T252),
+    (-1572732000, T253),
+    (-1553036400, T252),
+    (-1541196000, T253),
+    (-1521500400, T252),
+    (-1442444400, T253),
+    (-1427670000, T252),
+    (-1379286000, T253),
+    (-1364770800, T252),
+    (-1348441200, T253),
+    (-1333321200, T252),
+    (-1316386800, T253),
+    (-1301266800, T252),
+    (-1284332400, T253),
+    (-1269817200, T252),
+    (-1221433200, T253),
+    (-1206918000, T252),
+    (-1191193200, T253),
+    (-1175468400, T252),
+    (-1127689200, T253),
+    (-1111964400, T252),
+    (-1096844400, T253),
+    (-1080514800, T252),
+    (-1063580400, T253),
+    (-1049065200, T252),
+    (-1033340400, T253),
+    (-1017615600, T252),
+    (-1002495600, T253),
+    (-986166000, T252),
+    (-969231600, T253),
+    (-950482800, T252),
+    (-942015600, T253),
+    (-922489200, T252),
+    (-906937200, T253),
+    (-891126000, T252),
+    (-877302000, T253),
+    (-873676800, T35),
+    (-864000000, T253),
+    (-857948400, T252),
+    (-845852400, T253),
+    (-842832000, T35),
+    (-831340800, T253),
+    (-825894000, T252),
+    (-814402800, T253),
+    (-810777600, T35),
+    (-799891200, T253),
+    (-794444400, T252),
+    (-782953200, T253),
+    (-779328000, T35),
+    (-768441600, T253),
+    (-762994800, T252),
+    (-749084400, T253),
+    (-733359600, T252),
+    (-717624000, T253),
+    (-701899200, T252),
+    (-686174400, T253),
+    (-670449600, T252),
+    (-654724800, T253),
+    (-639000000, T252),
+    (-623275200, T253),
+    (-607550400, T252),
+    (-591825600, T253),
+    (-575496000, T252),
+    (-559771200, T253),
+    (-544046400, T252),
+    (-528321600, T253),
+    (-512596800, T252),
+    (-496872000, T253),
+    (-481147200, T252),
+    (-465422400, T253),
+    (-449697600, T252),
+    (-433972800, T253),
+    (-417643200, T252),
+    (-401918400, T253),
+    (-386193600, T252),
+    (-370468800, T253),
+    (-354744000, T252),
+    (-339019200, T253),
+    (-323294400, T252),
+    (-307569600, T253),
+    (-291844800, T252),
+    (-276120000, T253),
+    (-260395200, T252),
+    (-244670400, T253),
+    (-228340800, T252),
+    (-212616000, T253),
+    (-196891200, T252),
+    (-181166400, T253),
+    (-165441600, T252),
+    (-149716800, T253),
+    (-133992000, T252),
+    (-118267200, T253),
+    (386125200, T35),
+    (401850000, T17),
+    (417574800, T35),
+    (433299600, T17),
+    (449024400, T35),
+    (465354000, T17),
+    (481078800, T35),
+    (496803600, T17),
+    (512528400, T35),
+    (528253200, T17),
+    (543978000, T35),
+    (559702800, T17),
+    (575427600, T35),
+    (591152400, T17),
+    (606877200, T35),
+    (622602000, T17),
+    (638326800, T35),
+    (654656400, T17),
+    (670381200, T35),
+    (686106000, T17),
+    (701830800, T35),
+    (717555600, T17),
+    (725421600, T11),
+    (733280400, T12),
+    (740278800, T35),
+    (749005200, T17),
+    (764730000, T35),
+    (780454800, T17),
+    (796179600, T35),
+    (811904400, T17),
+    (828234000, T35),
+    (846378000, T17),
+    (859683600, T35),
+    (877827600, T17),
+];
+
+static ATLANTIC_BERMUDA: &[(i64, FixedTimespan<'static>)] = &[
+    (-1664307642, T455),
+    (-1648932042, T456),
+    (-1632080442, T455),
+    (-1618692042, T456),
+    (-1262281242, T104),
+    (-882727200, T136),
+    (-858538800, T104),
+    (-845229600, T136),
+    (-825879600, T104),
+    (-814384800, T136),
+    (-793825200, T104),
+    (-782935200, T136),
+    (-762375600, T104),
+    (-713988000, T136),
+    (-703710000, T104),
+    (-681933600, T136),
+    (-672865200, T104),
+    (-650484000, T136),
+    (-641415600, T104),
+    (-618429600, T136),
+    (-609966000, T104),
+    (-586980000, T136),
+    (-578516400, T104),
+    (-555530400, T136),
+    (-546462000, T104),
+    (-429127200, T136),
+    (-415825200, T104),
+    (136360800, T136),
+    (152082000, T104),
+    (167810400, T136),
+    (183531600, T104),
+    (199260000, T136),
+    (215586000, T104),
+    (230709600, T136),
+    (247035600, T104),
+    (262764000, T136),
+    (278485200, T104),
+    (294213600, T136),
+    (309934800, T104),
+    (325663200, T136),
+    (341384400, T104),
+    (357112800, T136),
+    (372834000, T104),
+    (388562400, T136),
+    (404888400, T104),
+    (420012000, T136),
+    (436338000, T104),
+    (452066400, T136),
+    (467787600, T104),
+    (483516000, T136),
+    (499237200, T104),
+    (514965600, T136),
+    (530686800, T104),
+    (544600800, T136),
+    (562136400, T104),
+    (576050400, T136),
+    (594190800, T104),
+    (607500000, T136),
+    (625640400, T104),
+    (638949600, T136),
+    (657090000, T104),
+    (671004000, T136),
+    (688539600, T104),
+    (702453600, T136),
+    (719989200, T104),
+    (733903200, T136),
+    (752043600, T104),
+    (765352800, T136),
+    (783493200, T104),
+    (796802400, T136),
+    (814942800, T104),
+    (828856800, T136),
+    (846392400, T104),
+    (860306400, T136),
+    (877842000, T104),
+    (891756000, T136),
+    (909291600, T104),
+    (923205600, T136),
+    (941346000, T104),
+    (954655200, T136),
+    (972795600, T104),
+    (986104800, T136),
+    (1004245200, T104),
+    (1018159200, T136),
+    (1035694800, T104),
+    (1049608800, T136),
+    (1067144400, T104),
+    (1081058400, T136),
+    (1099198800, T104),
+    (1112508000, T136),
+    (1130648400, T104),
+    (1143957600, T136),
+    (1162098000, T104),
+    (1173592800, T136),
+    (1194152400, T104),
+    (1205042400, T136),
+    (1225602000, T104),
+];
+
+static ATLANTIC_CANARY: &[(i64, FixedTimespan<'static>)] = &[
+    (-1509663504, T17),
+    (-733874400, T11),
+    (323827200, T12),
+    (338950800, T11),
+    (354675600, T12),
+    (370400400, T11),
+    (386125200, T12),
+    (401850000, T11),
+    (417574800, T12),
+    (433299600, T11),
+    (449024400, T12),
+    (465354000, T11),
+    (481078800, T12),
+    (496803600, T11),
+    (512528400, T12),
+    (528253200, T11),
+    (543978000, T12),
+    (559702800, T11),
+    (575427600, T12),
+    (591152400, T11),
+    (606877200, T12),
+    (622602000, T11),
+    (638326800, T12),
+    (654656400, T11),
+    (670381200, T12),
+    (686106000, T11),
+    (701830800, T12),
+    (717555600, T11),
+    (733280400, T12),
+    (749005200, T11),
+    (764730000, T12),
+    (780454800, T11),
+    (796179600, T12),
+    (811904400, T11),
+    (828234000, T12),
+    (846378000, T11),
+    (859683600, T12),
+    (877827600, T11),
+];
+
+static ATLANTIC_CAPE_VERDE: &[(i64, FixedTimespan<'static>)] = &[
+    (-1830376800, T252),
+    (-862610400, T253),
+    (-764118000, T252),
+    (186120000, T17),
+];
+
+static ATLANTIC_FAROE: &[(i64, FixedTimespan<'static>)] = &[
+    (-1955748776, T11),
+    (354675600, T12),
+    (370400400, T11),
+    (386125200, T12),
+    (401850000, T11),
+    (417574800, T12),
+    (433299600, T11),
+    (449024400, T12),
+    (465354000, T11),
+    (481078800, T12),
+    (496803600, T11),
+    (512528400, T12),
+    (528253200, T11),
+    (543978000, T12),
+    (559702800, T11),
+    (575427600, T12),
+    (591152400, T11),
+    (606877200, T12),
+    (622602000, T11),
+    (638326800, T12),
+    (654656400, T11),
+    (670381200, T12),
+    (686106000, T11),
+    (701830800, T12),
+    (717555600, T11),
+    (733280400, T12),
+    (749005200, T11),
+    (764730000, T12),
+    (780454800, T11),
+    (796179600, T12),
+    (811904400, T11),
+    (828234000, T12),
+    (846378000, T11),
+    (859683600, T12),
+    (877827600, T11),
+];
+
+static ATLANTIC_MADEIRA: &[(i64, FixedTimespan<'static>)] = &[
+    (-1830380400, T17),
+    (-1689552000, T35),
+    (-1677798000, T17),
+    (-1667430000, T35),
+    (-1647734400, T17),
+    (-1635894000, T35),
+    (-1616198400, T17),
+    (-1604358000, T35),
+    (-1584662400, T17),
+    (-1572735600, T35),
+    (-1553040000, T17),
+    (-1541199600, T35),
+    (-1521504000, T17),
+    (-1442448000, T35),
+    (-1427673600, T17),
+    (-1379289600, T35),
+    (-1364774400, T17),
+    (-1348444800, T35),
+    (-1333324800, T17),
+    (-1316390400, T35),
+    (-1301270400, T17),
+    (-1284336000, T35),
+    (-1269820800, T17),
+    (-1221436800, T35),
+    (-1206921600, T17),
+    (-1191196800, T35),
+    (-1175472000, T17),
+    (-1127692800, T35),
+    (-1111968000, T17),
+    (-1096848000, T35),
+    (-1080518400, T17),
+    (-1063584000, T35),
+    (-1049068800, T17),
+    (-1033344000, T35),
+    (-1017619200, T17),
+    (-1002499200, T35),
+    (-986169600, T17),
+    (-969235200, T35),
+    (-950486400, T17),
+    (-942019200, T35),
+    (-922492800, T17),
+    (-906940800, T35),
+    (-891129600, T17),
+    (-877305600, T35),
+    (-873680400, T33),
+    (-864003600, T35),
+    (-857952000, T17),
+    (-845856000, T35),
+    (-842835600, T33),
+    (-831344400, T35),
+    (-825897600, T17),
+    (-814406400, T35),
+    (-810781200, T33),
+    (-799894800, T35),
+    (-794448000, T17),
+    (-782956800, T35),
+    (-779331600, T33),
+    (-768445200, T35),
+    (-762998400, T17),
+    (-749088000, T35),
+    (-733363200, T17),
+    (-717627600, T35),
+    (-701902800, T17),
+    (-686178000, T35),
+    (-670453200, T17),
+    (-654728400, T35),
+    (-639003600, T17),
+    (-623278800, T35),
+    (-607554000, T17),
+    (-591829200, T35),
+    (-575499600, T17),
+    (-559774800, T35),
+    (-544050000, T17),
+    (-528325200, T35),
+    (-512600400, T17),
+    (-496875600, T35),
+    (-481150800, T17),
+    (-465426000, T35),
+    (-449701200, T17),
+    (-433976400, T35),
+    (-417646800, T17),
+    (-401922000, T35),
+    (-386197200, T17),
+    (-370472400, T35),
+    (-354747600, T17),
+    (-339022800, T35),
+    (-323298000, T17),
+    (-307573200, T35),
+    (-291848400, T17),
+    (-276123600, T35),
+    (-260398800, T17),
+    (-244674000, T35),
+    (-228344400, T17),
+    (-212619600, T35),
+    (-196894800, T17),
+    (-181170000, T35),
+    (-165445200, T17),
+    (-149720400, T35),
+    (-133995600, T17),
+    (-118270800, T35),
+    (386726400, T12),
+    (401846400, T11),
+    (417571200, T12),
+    (433296000, T11),
+    (449020800, T12),
+    (465350400, T11),
+    (481075200, T12),
+    (496800000, T11),
+    (512524800, T12),
+    (528253200, T11),
+    (543978000, T12),
+    (559702800, T11),
+    (575427600, T12),
+    (591152400, T11),
+    (606877200, T12),
+    (622602000, T11),
+    (638326800, T12),
+    (654656400, T11),
+    (670381200, T12),
+    (686106000, T11),
+    (701830800, T12),
+    (717555600, T11),
+    (733280400, T12),
+    (749005200, T11),
+    (764730000, T12),
+    (780454800, T11),
+    (796179600, T12),
+    (811904400, T11),
+    (828234000, T12),
+    (846378000, T11),
+    (859683600, T12),
+    (877827600, T11),
+];
+
+static ATLANTIC_REYKJAVIK: &[(i64, FixedTimespan<'static>)] = &[
+    (-1956609120, T17),
+    (-1668211200, T35),
+    (-1647212400, T17),
+    (-1636675200, T35),
+    (-1613430000, T17),
+    (-1605139200, T35),
+    (-1581894000, T17),
+    (-1539561600, T35),
+    (-1531350000, T17),
+    (-968025600, T35),
+    (-952293600, T17),
+    (-942008400, T35),
+    (-920239200, T17),
+    (-909957600, T35),
+    (-888789600, T17),
+    (-877903200, T35),
+    (-857944800, T17),
+    (-846453600, T35),
+    (-826495200, T17),
+    (-815004000, T35),
+    (-795045600, T17),
+    (-783554400, T35),
+    (-762991200, T17),
+    (-752104800, T35),
+    (-731541600, T17),
+    (-717631200, T35),
+    (-700092000, T17),
+    (-686181600, T35),
+    (-668642400, T17),
+    (-654732000, T35),
+    (-636588000, T17),
+    (-623282400, T35),
+    (-605743200, T17),
+    (-591832800, T35),
+    (-573688800, T17),
+    (-559778400, T35),
+    (-542239200, T17),
+    (-528328800, T35),
+    (-510789600, T17),
+    (-496879200, T35),
+    (-479340000, T17),
+    (-465429600, T35),
+    (-447890400, T17),
+    (-433980000, T35),
+    (-415836000, T17),
+    (-401925600, T35),
+    (-384386400, T17),
+    (-370476000, T35),
+    (-352936800, T17),
+    (-339026400, T35),
+    (-321487200, T17),
+    (-307576800, T35),
+    (-290037600, T17),
+    (-276127200, T35),
+    (-258588000, T17),
+    (-244677600, T35),
+    (-226533600, T17),
+    (-212623200, T35),
+    (-195084000, T17),
+    (-181173600, T35),
+    (-163634400, T17),
+    (-149724000, T35),
+    (-132184800, T17),
+    (-118274400, T35),
+    (-100735200, T17),
+    (-86824800, T35),
+    (-68680800, T17),
+    (-54770400, T1),
+];
+
+static ATLANTIC_SOUTH_GEORGIA: &[(i64, FixedTimespan<'static>)] = &[
+    (-2524512832, T252),
+];
+
+static ATLANTIC_ST_HELENA: &[(i64, FixedTimespan<'static>)] = &[
+    (-599614632, T1),
+];
+
+static ATLANTIC_STANLEY: &[(i64, FixedTimespan<'static>)] = &[
+    (-1824235716, T112),
+    (-1018209600, T113),
+    (-1003093200, T112),
+    (-986760000, T113),
+    (-971643600, T112),
+    (-954705600, T113),
+    (-939589200, T112),
+    (-923256000, T113),
+    (-908139600, T112),
+    (-891806400, T113),
+    (-876690000, T112),
+    (-860356800, T113),
+    (-852066000, T112),
+    (420609600, T108),
+    (433306800, T109),
+    (452052000, T108),
+    (464151600, T109),
+    (483501600, T108),
+    (495601200, T112),
+    (495604800, T113),
+    (514350000, T112),
+    (527054400, T113),
+    (545799600, T112),
+    (558504000, T113),
+    (577249200, T112),
+    (589953600, T113),
+    (608698800, T112),
+    (621403200, T113),
+    (640753200, T112),
+    (652852800, T113),
+    (672202800, T112),
+    (684907200, T113),
+    (703652400, T112),
+    (716356800, T113),
+    (735102000, T112),
+    (747806400, T113),
+    (766551600, T112),
+    (779256000, T113),
+    (798001200, T112),
+    (810705600, T113),
+    (830055600, T112),
+    (842760000, T113),
+    (861505200, T112),
+    (874209600, T113),
+    (892954800, T112),
+    (905659200, T113),
+    (924404400, T112),
+    (937108800, T113),
+    (955854000, T112),
+    (968558400, T113),
+    (987310800, T112),
+    (999410400, T113),
+    (1019365200, T112),
+    (1030860000, T113),
+    (1050814800, T112),
+    (1062914400, T113),
+    (1082264400, T112),
+    (1094364000, T113),
+    (1113714000, T112),
+    (1125813600, T113),
+    (1145163600, T112),
+    (1157263200, T113),
+    (1176613200, T112),
+    (1188712800, T113),
+    (1208667600, T112),
+    (1220767200, T113),
+    (1240117200, T112),
+    (1252216800, T113),
+    (1271566800, T112),
+    (1283666400, T108),
+];
+
+static AUSTRALIA_ADELAIDE: &[(i64, FixedTimespan<'static>)] = &[
+    (-2364110060, T466),
+    (-2230189200, T467),
+    (-1672558200, T468),
+    (-1665387000, T467),
+    (-883639800, T468),
+    (-876123000, T467),
+    (-860398200, T468),
+    (-844673400, T467),
+    (-828343800, T468),
+    (-813223800, T467),
+    (57688200, T468),
+    (67969800, T467),
+    (89137800, T468),
+    (100024200, T467),
+    (120587400, T468),
+    (131473800, T467),
+    (152037000, T468),
+    (162923400, T467),
+    (183486600, T468),
+    (194977800, T467),
+    (215541000, T468),
+    (226427400, T467),
+    (246990600, T468),
+    (257877000, T467),
+    (278440200, T468),
+    (289326600, T467),
+    (309889800, T468),
+    (320776200, T467),
+    (341339400, T468),
+    (352225800, T467),
+    (372789000, T468),
+    (384280200, T467),
+    (404843400, T468),
+    (415729800, T467),
+    (436293000, T468),
+    (447179400, T467),
+    (467742600, T468),
+    (478629000, T467),
+    (499192200, T468),
+    (511288200, T467),
+    (530037000, T468),
+    (542737800, T467),
+    (562091400, T468),
+    (574792200, T467),
+    (594145800, T468),
+    (606241800, T467),
+    (625595400, T468),
+    (637691400, T467),
+    (657045000, T468),
+    (667931400, T467),
+    (688494600, T468),
+    (701195400, T467),
+    (719944200, T468),
+    (731435400, T467),
+    (751998600, T468),
+    (764094600, T467),
+    (783448200, T468),
+    (796149000, T467),
+    (814897800, T468),
+    (828203400, T467),
+    (846347400, T468),
+    (859653000, T467),
+    (877797000, T468),
+    (891102600, T467),
+    (909246600, T468),
+    (922552200, T467),
+    (941301000, T468),
+    (954001800, T467),
+    (972750600, T468),
+    (985451400, T467),
+    (1004200200, T468),
+    (1017505800, T467),
+    (1035649800, T468),
+    (1048955400, T467),
+    (1067099400, T468),
+    (1080405000, T467),
+    (1099153800, T468),
+    (1111854600, T467),
+    (1130603400, T468),
+    (1143909000, T467),
+    (1162053000, T468),
+    (1174753800, T467),
+    (1193502600, T468),
+    (1207413000, T467),
+    (1223137800, T468),
+    (1238862600, T467),
+    (1254587400, T468),
+];
+
+static AUSTRALIA_BRISBANE: &[(i64, FixedTimespan<'static>)] = &[
+    (-2366791928, T305),
+    (-1672560000, T306),
+    (-1665388800, T305),
+    (-883641600, T306),
+    (-876124800, T305),
+    (-860400000, T306),
+    (-844675200, T305),
+    (-828345600, T306),
+    (-813225600, T305),
+    (57686400, T306),
+    (67968000, T305),
+    (625593600, T306),
+    (636480000, T305),
+    (657043200, T306),
+    (667929600, T305),
+    (688492800, T306),
+    (699379200, T305),
+];
+
+static AUSTRALIA_BROKEN_HILL: &[(i64, FixedTimespan<'static>)] = &[
+    (-2364110748, T305),
+    (-2314951200, T466),
+    (-2230189200, T467),
+    (-1672558200, T468),
+    (-1665387000, T467),
+    (-883639800, T468),
+    (-876123000, T467),
+    (-860398200, T468),
+    (-844673400, T467),
+    (-828343800, T468),
+    (-813223800, T467),
+    (57688200, T468),
+    (67969800, T467),
+    (89137800, T468),
+    (100024200, T467),
+    (120587400, T468),
+    (131473800, T467),
+    (152037000, T468),
+    (162923400, T467),
+    (183486600, T468),
+    (194977800, T467),
+    (215541000, T468),
+    (226427400, T467),
+    (246990600, T468),
+    (257877000, T467),
+    (278440200, T468),
+    (289326600, T467),
+    (309889800, T468),
+    (320776200, T467),
+    (341339400, T468),
+    (352225800, T467),
+    (372789000, T468),
+    (386699400, T467),
+    (404843400, T468),
+    (415729800, T467),
+    (436293000, T468),
+    (447179400, T467),
+    (467742600, T468),
+    (478629000, T467),
+    (499192200, T468),
+    (511288200, T467),
+    (530037000, T468),
+    (542737800, T467),
+    (562091400, T468),
+    (574792200, T467),
+    (594145800, T468),
+    (606241800, T467),
+    (625595400, T468),
+    (636481800, T467),
+    (657045000, T468),
+    (667931400, T467),
+    (688494600, T468),
+    (699381000, T467),
+    (719944200, T468),
+    (731435400, T467),
+    (751998600, T468),
+    (762885000, T467),
+    (783448200, T468),
+    (794334600, T467),
+    (814897800, T468),
+    (828203400, T467),
+    (846347400, T468),
+    (859653000, T467),
+    (877797000, T468),
+    (891102600, T467),
+    (909246600, T468),
+    (922552200, T467),
+    (941301000, T468),
+    (954001800, T467),
+    (972750600, T468),
+    (985451400, T467),
+    (1004200200, T468),
+    (1017505800, T467),
+    (1035649800, T468),
+    (1048955400, T467),
+    (1067099400, T468),
+    (1080405000, T467),
+    (1099153800, T468),
+    (1111854600, T467),
+    (1130603400, T468),
+    (1143909000, T467),
+    (1162053000, T468),
+    (1174753800, T467),
+    (1193502600, T468),
+    (1207413000, T467),
+    (1223137800, T468),
+    (1238862600, T467),
+    (1254587400, T468),
+];
+
+static AUSTRALIA_DARWIN: &[(i64, FixedTimespan<'static>)] = &[
+    (-2364108200, T466),
+    (-2230189200, T467),
+    (-1672558200, T468),
+    (-1665387000, T467),
+    (-883639800, T468),
+    (-876123000, T467),
+    (-860398200, T468),
+    (-844673400, T467),
+    (-828343800, T468),
+    (-813223800, T467),
+];
+
+static AUSTRALIA_EUCLA: &[(i64, FixedTimespan<'static>)] = &[
+    (-2337928528, T473),
+    (-1672555500, T474),
+    (-1665384300, T473),
+    (-883637100, T474),
+    (-876120300, T473),
+    (-860395500, T474),
+    (-844670700, T473),
+    (152039700, T474),
+    (162926100, T473),
+    (436295700, T474),
+    (447182100, T473),
+    (690311700, T474),
+    (699383700, T473),
+    (1165079700, T474),
+    (1174756500, T473),
+    (1193505300, T474),
+    (1206810900, T473),
+    (1224954900, T474),
+    (1238260500, T473),
+];
+
+static AUSTRALIA_HOBART: &[(i64, FixedTimespan<'static>)] = &[
+    (-2345795356, T305),
+    (-1680508800, T306),
+    (-1665388800, T305),
+    (-1646640000, T306),
+    (-1635753600, T305),
+    (-1615190400, T306),
+    (-1604304000, T305),
+    (-883641600, T306),
+    (-876124800, T305),
+    (-860400000, T306),
+    (-844675200, T305),
+    (-828345600, T306),
+    (-813225600, T305),
+    (-71136000, T306),
+    (-55411200, T305),
+    (-37267200, T306),
+    (-25776000, T305),
+    (-5817600, T306),
+    (5673600, T305),
+    (25632000, T306),
+    (37728000, T305),
+    (57686400, T306),
+    (67968000, T305),
+    (89136000, T306),
+    (100022400, T305),
+    (120585600, T306),
+    (131472000, T305),
+    (152035200, T306),
+    (162921600, T305),
+    (183484800, T306),
+    (194976000, T305),
+    (215539200, T306),
+    (226425600, T305),
+    (246988800, T306),
+    (257875200, T305),
+    (278438400, T306),
+    (289324800, T305),
+    (309888000, T306),
+    (320774400, T305),
+    (341337600, T306),
+    (352224000, T305),
+    (372787200, T306),
+    (386092800, T305),
+    (404841600, T306),
+    (417542400, T305),
+    (436291200, T306),
+    (447177600, T305),
+    (467740800, T306),
+    (478627200, T305),
+    (499190400, T306),
+    (510076800, T305),
+    (530035200, T306),
+    (542736000, T305),
+    (562089600, T306),
+    (574790400, T305),
+    (594144000, T306),
+    (606240000, T305),
+    (625593600, T306),
+    (637689600, T305),
+    (657043200, T306),
+    (670348800, T305),
+    (686678400, T306),
+    (701798400, T305),
+    (718128000, T306),
+    (733248000, T305),
+    (749577600, T306),
+    (764697600, T305),
+    (781027200, T306),
+    (796147200, T305),
+    (812476800, T306),
+    (828201600, T305),
+    (844531200, T306),
+    (859651200, T305),
+    (875980800, T306),
+    (891100800, T305),
+    (907430400, T306),
+    (922550400, T305),
+    (938880000, T306),
+    (954000000, T305),
+    (967305600, T306),
+    (985449600, T305),
+    (1002384000, T306),
+    (1017504000, T305),
+    (1033833600, T306),
+    (1048953600, T305),
+    (1065283200, T306),
+    (1080403200, T305),
+    (1096732800, T306),
+    (1111852800, T305),
+    (1128182400, T306),
+    (1143907200, T305),
+    (1159632000, T306),
+    (1174752000, T305),
+    (1191686400, T306),
+    (1207411200, T305),
+    (1223136000, T306),
+    (1238860800, T305),
+    (1254585600, T306),
+];
+
+static AUSTRALIA_LINDEMAN: &[(i64, FixedTimespan<'static>)] = &[
+    (-2366790956, T305),
+    (-1672560000, T306),
+    (-1665388800, T305),
+    (-883641600, T306),
+    (-876124800, T305),
+    (-860400000, T306),
+    (-844675200, T305),
+    (-828345600, T306),
+    (-813225600, T305),
+    (57686400, T306),
+    (67968000, T305),
+    (625593600, T306),
+    (636480000, T305),
+    (657043200, T306),
+    (667929600, T305),
+    (688492800, T306),
+    (699379200, T305),
+    (719942400, T306),
+    (731433600, T305),
+    (751996800, T306),
+    (762883200, T305),
+];
+
+static AUSTRALIA_LORD_HOWE: &[(i64, FixedTimespan<'static>)] = &[
+    (-2364114980, T305),
+    (352216800, T478),
+    (372785400, T479),
+    (384273000, T478),
+    (404839800, T479),
+    (415722600, T478),
+    (436289400, T479),
+    (447172200, T478),
+    (467739000, T479),
+    (478621800, T478),
+    (499188600, T388),
+    (511282800, T478),
+    (530033400, T388),
+    (542732400, T478),
+    (562087800, T388),
+    (574786800, T478),
+    (594142200, T388),
+    (606236400, T478),
+    (625591800, T388),
+    (636476400, T478),
+    (657041400, T388),
+    (667926000, T478),
+    (688491000, T388),
+    (699375600, T478),
+    (719940600, T388),
+    (731430000, T478),
+    (751995000, T388),
+    (762879600, T478),
+    (783444600, T388),
+    (794329200, T478),
+    (814894200, T388),
+    (828198000, T478),
+    (846343800, T388),
+    (859647600, T478),
+    (877793400, T388),
+    (891097200, T478),
+    (909243000, T388),
+    (922546800, T478),
+    (941297400, T388),
+    (953996400, T478),
+    (967303800, T388),
+    (985446000, T478),
+    (1004196600, T388),
+    (1017500400, T478),
+    (1035646200, T388),
+    (1048950000, T478),
+    (1067095800, T388),
+    (1080399600, T478),
+    (1099150200, T388),
+    (1111849200, T478),
+    (1130599800, T388),
+    (1143903600, T478),
+    (1162049400, T388),
+    (1174748400, T478),
+    (1193499000, T388),
+    (1207407600, T478),
+    (1223134200, T388),
+    (1238857200, T478),
+    (1254583800, T388),
+];
+
+static AUSTRALIA_MELBOURNE: &[(i64, FixedTimespan<'static>)] = &[
+    (-2364111592, T305),
+    (-1672560000, T306),
+    (-1665388800, T305),
+    (-883641600, T306),
+    (-876124800, T305),
+    (-860400000, T306),
+    (-844675200, T305),
+    (-828345600, T306),
+    (-813225600, T305),
+    (57686400, T306),
+    (67968000, T305),
+    (89136000, T306),
+    (100022400, T305),
+    (120585600, T306),
+    (131472000, T305),
+    (152035200, T306),
+    (162921600, T305),
+    (183484800, T306),
+    (194976000, T305),
+    (215539200, T306),
+    (226425600, T305),
+    (246988800, T306),
+    (257875200, T305),
+    (278438400, T306),
+    (289324800, T305),
+    (309888000, T306),
+    (320774400, T305),
+    (341337600, T306),
+    (352224000, T305),
+    (372787200, T306),
+    (384278400, T305),
+    (404841600, T306),
+    (415728000, T305),
+    (436291200, T306),
+    (447177600, T305),
+    (467740800, T306),
+    (478627200, T305),
+    (499190400, T306),
+    (511286400, T305),
+    (530035200, T306),
+    (542736000, T305),
+    (561484800, T306),
+    (574790400, T305),
+    (594144000, T306),
+    (606240000, T305),
+    (625593600, T306),
+    (637689600, T305),
+    (657043200, T306),
+    (667929600, T305),
+    (688492800, T306),
+    (699379200, T305),
+    (719942400, T306),
+    (731433600, T305),
+    (751996800, T306),
+    (762883200, T305),
+    (783446400, T306),
+    (796147200, T305),
+    (814896000, T306),
+    (828201600, T305),
+    (846345600, T306),
+    (859651200, T305),
+    (877795200, T306),
+    (891100800, T305),
+    (909244800, T306),
+    (922550400, T305),
+    (941299200, T306),
+    (954000000, T305),
+    (967305600, T306),
+    (985449600, T305),
+    (1004198400, T306),
+    (1017504000, T305),
+    (1035648000, T306),
+    (1048953600, T305),
+    (1067097600, T306),
+    (1080403200, T305),
+    (1099152000, T306),
+    (1111852800, T305),
+    (1130601600, T306),
+    (1143907200, T305),
+    (1162051200, T306),
+    (1174752000, T305),
+    (1193500800, T306),
+    (1207411200, T305),
+    (1223136000, T306),
+    (1238860800, T305),
+    (1254585600, T306),
+];
+
+static AUSTRALIA_PERTH: &[(i64, FixedTimespan<'static>)] = &[
+    (-2337925404, T482),
+    (-1672552800, T483),
+    (-1665381600, T482),
+    (-883634400, T483),
+    (-876117600, T482),
+    (-860392800, T483),
+    (-844668000, T482),
+    (152042400, T483),
+    (162928800, T482),
+    (436298400, T483),
+    (447184800, T482),
+    (690314400, T483),
+    (699386400, T482),
+    (1165082400, T483),
+    (1174759200, T482),
+    (1193508000, T483),
+    (1206813600, T482),
+    (1224957600, T483),
+    (1238263200, T482),
+];
+
+static AUSTRALIA_SYDNEY: &[(i64, FixedTimespan<'static>)] = &[
+    (-2364113092, T305),
+    (-1672560000, T306),
+    (-1665388800, T305),
+    (-883641600, T306),
+    (-876124800, T305),
+    (-860400000, T306),
+    (-844675200, T305),
+    (-828345600, T306),
+    (-813225600, T305),
+    (57686400, T306),
+    (67968000, T305),
+    (89136000, T306),
+    (100022400, T305),
+    (120585600, T306),
+    (131472000, T305),
+    (152035200, T306),
+    (162921600, T305),
+    (183484800, T306),
+    (194976000, T305),
+    (215539200, T306),
+    (226425600, T305),
+    (246988800, T306),
+    (257875200, T305),
+    (278438400, T306),
+    (289324800, T305),
+    (309888000, T306),
+    (320774400, T305),
+    (341337600, T306),
+    (352224000, T305),
+    (372787200, T306),
+    (386697600, T305),
+    (404841600, T306),
+    (415728000, T305),
+    (436291200, T306),
+    (447177600, T305),
+    (467740800, T306),
+    (478627200, T305),
+    (499190400, T306),
+    (511286400, T305),
+    (530035200, T306),
+    (542736000, T305),
+    (562089600, T306),
+    (574790400, T305),
+    (594144000, T306),
+    (606240000, T305),
+    (625593600, T306),
+    (636480000, T305),
+    (657043200, T306),
+    (667929600, T305),
+    (688492800, T306),
+    (699379200, T305),
+    (719942400, T306),
+    (731433600, T305),
+    (751996800, T306),
+    (762883200, T305),
+    (783446400, T306),
+    (794332800, T305),
+    (814896000, T306),
+    (828201600, T305),
+    (846345600, T306),
+    (859651200, T305),
+    (877795200, T306),
+    (891100800, T305),
+    (909244800, T306),
+    (922550400, T305),
+    (941299200, T306),
+    (954000000, T305),
+    (967305600, T306),
+    (985449600, T305),
+    (1004198400, T306),
+    (1017504000, T305),
+    (1035648000, T306),
+    (1048953600, T305),
+    (1067097600, T306),
+    (1080403200, T305),
+    (1099152000, T306),
+    (1111852800, T305),
+    (1130601600, T306),
+    (1143907200, T305),
+    (1162051200, T306),
+    (1174752000, T305),
+    (1193500800, T306),
+    (1207411200, T305),
+    (1223136000, T306),
+    (1238860800, T305),
+    (1254585600, T306),
+];
+
+static CET: &[(i64, FixedTimespan<'static>)] = &[
+    (-1693706400, T14),
+    (-1680483600, T13),
+    (-1663455600, T14),
+    (-1650150000, T13),
+    (-1632006000, T14),
+    (-1618700400, T13),
+    (-938905200, T14),
+    (-857257200, T13),
+    (-844556400, T14),
+    (-828226800, T13),
+    (-812502000, T14),
+    (-796777200, T13),
+    (-781052400, T14),
+    (-766623600, T13),
+    (228877200, T14),
+    (243997200, T13),
+    (260326800, T14),
+    (276051600, T13),
+    (291776400, T14),
+    (307501200, T13),
+    (323830800, T14),
+    (338950800, T13),
+    (354675600, T14),
+    (370400400, T13),
+    (386125200, T14),
+    (401850000, T13),
+    (417574800, T14),
+    (433299600, T13),
+    (449024400, T14),
+    (465354000, T13),
+    (481078800, T14),
+    (496803600, T13),
+    (512528400, T14),
+    (528253200, T13),
+    (543978000, T14),
+    (559702800, T13),
+    (575427600, T14),
+    (591152400, T13),
+    (606877200, T14),
+    (622602000, T13),
+    (638326800, T14),
+    (654656400, T13),
+    (670381200, T14),
+    (686106000, T13),
+    (701830800, T14),
+    (717555600, T13),
+    (733280400, T14),
+    (749005200, T13),
+    (764730000, T14),
+    (780454800, T13),
+    (796179600, T14),
+    (811904400, T13),
+    (828234000, T14),
+    (846378000, T13),
+    (859683600, T14),
+    (877827600, T13),
+];
+
+static CST6CDT: &[(i64, FixedTimespan<'static>)] = &[
+    (-1633276800, T130),
+    (-1615136400, T129),
+    (-1601827200, T130),
+    (-1583686800, T129),
+    (-880214400, T141),
+    (-765392400, T129),
+    (-84384000, T130),
+    (-68662800, T129),
+    (-52934400, T130),
+    (-37213200, T129),
+    (-21484800, T130),
+    (-5763600, T129),
+    (9964800, T130),
+    (25686000, T129),
+    (41414400, T130),
+    (57740400, T129),
+    (73468800, T130),
+    (89190000, T129),
+    (104918400, T130),
+    (120639600, T129),
+    (126691200, T130),
+    (152089200, T129),
+    (162374400, T130),
+    (183538800, T129),
+    (199267200, T130),
+    (215593200, T129),
+    (230716800, T130),
+    (247042800, T129),
+    (262771200, T130),
+    (278492400, T129),
+    (294220800, T130),
+    (309942000, T129),
+    (325670400, T130),
+    (341391600, T129),
+    (357120000, T130),
+    (372841200, T129),
+    (388569600, T130),
+    (404895600, T129),
+    (420019200, T130),
+    (436345200, T129),
+    (452073600, T130),
+    (467794800, T129),
+    (483523200, T130),
+    (499244400, T129),
+    (514972800, T130),
+    (530694000, T129),
+    (544608000, T130),
+    (562143600, T129),
+    (576057600, T130),
+    (594198000, T129),
+    (607507200, T130),
+    (625647600, T129),
+    (638956800, T130),
+    (657097200, T129),
+    (671011200, T130),
+    (688546800, T129),
+    (702460800, T130),
+    (719996400, T129),
+    (733910400, T130),
+    (752050800, T129),
+    (765360000, T130),
+    (783500400, T129),
+    (796809600, T130),
+    (814950000, T129),
+    (828864000, T130),
+    (846399600, T129),
+    (860313600, T130),
+    (877849200, T129),
+    (891763200, T130),
+    (909298800, T129),
+    (923212800, T130),
+    (941353200, T129),
+    (954662400, T130),
+    (972802800, T129),
+    (986112000, T130),
+    (1004252400, T129),
+    (1018166400, T130),
+    (1035702000, T129),
+    (1049616000, T130),
+    (1067151600, T129),
+    (1081065600, T130),
+    (1099206000, T129),
+    (1112515200, T130),
+    (1130655600, T129),
+    (1143964800, T130),
+    (1162105200, T129),
+    (1173600000, T130),
+    (1194159600, T129),
+    (1205049600, T130),
+    (1225609200, T129),
+];
+
+static EET: &[(i64, FixedTimespan<'static>)] = &[
+    (228877200, T30),
+    (243997200, T29),
+    (260326800, T30),
+    (276051600, T29),
+    (291776400, T30),
+    (307501200, T29),
+    (323830800, T30),
+    (338950800, T29),
+    (354675600, T30),
+    (370400400, T29),
+    (386125200, T30),
+    (401850000, T29),
+    (417574800, T30),
+    (433299600, T29),
+    (449024400, T30),
+    (465354000, T29),
+    (481078800, T30),
+    (496803600, T29),
+    (512528400, T30),
+    (528253200, T29),
+    (543978000, T30),
+    (559702800, T29),
+    (575427600, T30),
+    (591152400, T29),
+    (606877200, T30),
+    (622602000, T29),
+    (638326800, T30),
+    (654656400, T29),
+    (670381200, T30),
+    (686106000, T29),
+    (701830800, T30),
+    (717555600, T29),
+    (733280400, T30),
+    (749005200, T29),
+    (764730000, T30),
+    (780454800, T29),
+    (796179600, T30),
+    (811904400, T29),
+    (828234000, T30),
+    (846378000, T29),
+    (859683600, T30),
+    (877827600, T29),
+];
+
+static EST: &[(i64, FixedTimespan<'static>)] = &[
+];
+
+static EST5EDT: &[(i64, FixedTimespan<'static>)] = &[
+    (-1633280400, T155),
+    (-1615140000, T106),
+    (-1601830800, T155),
+    (-1583690400, T106),
+    (-880218000, T180),
+    (-765396000, T106),
+    (-84387600, T155),
+    (-68666400, T106),
+    (-52938000, T155),
+    (-37216800, T106),
+    (-21488400, T155),
+    (-5767200, T106),
+    (9961200, T155),
+    (25682400, T106),
+    (41410800, T155),
+    (57736800, T106),
+    (73465200, T155),
+    (89186400, T106),
+    (104914800, T155),
+    (120636000, T106),
+    (126687600, T155),
+    (152085600, T106),
+    (162370800, T155),
+    (183535200, T106),
+    (199263600, T155),
+    (215589600, T106),
+    (230713200, T155),
+    (247039200, T106),
+    (262767600, T155),
+    (278488800, T106),
+    (294217200, T155),
+    (309938400, T106),
+    (325666800, T155),
+    (341388000, T106),
+    (357116400, T155),
+    (372837600, T106),
+    (388566000, T155),
+    (404892000, T106),
+    (420015600, T155),
+    (436341600, T106),
+    (452070000, T155),
+    (467791200, T106),
+    (483519600, T155),
+    (499240800, T106),
+    (514969200, T155),
+    (530690400, T106),
+    (544604400, T155),
+    (562140000, T106),
+    (576054000, T155),
+    (594194400, T106),
+    (607503600, T155),
+    (625644000, T106),
+    (638953200, T155),
+    (657093600, T106),
+    (671007600, T155),
+    (688543200, T106),
+    (702457200, T155),
+    (719992800, T106),
+    (733906800, T155),
+    (752047200, T106),
+    (765356400, T155),
+    (783496800, T106),
+    (796806000, T155),
+    (814946400, T106),
+    (828860400, T155),
+    (846396000, T106),
+    (860310000, T155),
+    (877845600, T106),
+    (891759600, T155),
+    (909295200, T106),
+    (923209200, T155),
+    (941349600, T106),
+    (954658800, T155),
+    (972799200, T106),
+    (986108400, T155),
+    (1004248800, T106),
+    (1018162800, T155),
+    (1035698400, T106),
+    (1049612400, T155),
+    (1067148000, T106),
+    (1081062000, T155),
+    (1099202400, T106),
+    (1112511600, T155),
+    (1130652000, T106),
+    (1143961200, T155),
+    (1162101600, T106),
+    (1173596400, T155),
+    (1194156000, T106),
+    (1205046000, T155),
+    (1225605600, T106),
+];
+
+static ETC_GMT: &[(i64, FixedTimespan<'static>)] = &[
+];
+
+static ETC_GMT_PLUS_1: &[(i64, FixedTimespan<'static>)] = &[
+];
+
+static ETC_GMT_PLUS_10: &[(i64, FixedTimespan<'static>)] = &[
+];
+
+static ETC_GMT_PLUS_11: &[(i64, FixedTimespan<'static>)] = &[
+];
+
+static ETC_GMT_PLUS_12: &[(i64, FixedTimespan<'static>)] = &[
+];
+
+static ETC_GMT_PLUS_2: &[(i64, FixedTimespan<'static>)] = &[
+];
+
+static ETC_GMT_PLUS_3: &[(i64, FixedTimespan<'static>)] = &[
+];
+
+static ETC_GMT_PLUS_4: &[(i64, FixedTimespan<'static>)] = &[
+];
+
+static ETC_GMT_PLUS_5: &[(i64, FixedTimespan<'static>)] = &[
+];
+
+static ETC_GMT_PLUS_6: &[(i64, FixedTimespan<'static>)] = &[
+];
+
+static ETC_GMT_PLUS_7: &[(i64, FixedTimespan<'static>)] = &[
+];
+
+static ETC_GMT_PLUS_8: &[(i64, FixedTimespan<'static>)] = &[
+];
+
+static ETC_GMT_PLUS_9: &[(i64, FixedTimespan<'static>)] = &[
+];
+
+static ETC_GMT_MINUS_1: &[(i64, FixedTimespan<'static>)] = &[
+];
+
+static ETC_GMT_MINUS_10: &[(i64, FixedTimespan<'static>)] = &[
+];
+
+static ETC_GMT_MINUS_11: &[(i64, FixedTimespan<'static>)] = &[
+];
+
+static ETC_GMT_MINUS_12: &[(i64, FixedTimespan<'static>)] = &[
+];
+
+static ETC_GMT_MINUS_13: &[(i64, FixedTimespan<'static>)] = &[
+];
+
+static ETC_GMT_MINUS_14: &[(i64, FixedTimespan<'static>)] = &[
+];
+
+static ETC_GMT_MINUS_2: &[(i64, FixedTimespan<'static>)] = &[
+];
+
+static ETC_GMT_MINUS_3: &[(i64, FixedTimespan<'static>)] = &[
+];
+
+static ETC_GMT_MINUS_4: &[(i64, FixedTimespan<'static>)] = &[
+];
+
+static ETC_GMT_MINUS_5: &[(i64, FixedTimespan<'static>)] = &[
+];
+
+static ETC_GMT_MINUS_6: &[(i64, FixedTimespan<'static>)] = &[
+];
+
+static ETC_GMT_MINUS_7: &[(i64, FixedTimespan<'static>)] = &[
+];
+
+static ETC_GMT_MINUS_8: &[(i64, FixedTimespan<'static>)] = &[
+];
+
+static ETC_GMT_MINUS_9: &[(i64, FixedTimespan<'static>)] = &[
+];
+
+static ETC_UTC: &[(i64, FixedTimespan<'static>)] = &[
+];
+
+static EUROPE_AMSTERDAM: &[(i64, FixedTimespan<'static>)] = &[
+    (-1693700372, T496),
+    (-1680484772, T497),
+    (-1663453172, T496),
+    (-1650147572, T497),
+    (-1633213172, T496),
+    (-1617488372, T497),
+    (-1601158772, T496),
+    (-1586038772, T497),
+    (-1569709172, T496),
+    (-1554589172, T497),
+    (-1538259572, T496),
+    (-1523139572, T497),
+    (-1507501172, T496),
+    (-1490566772, T497),
+    (-1470176372, T496),
+    (-1459117172, T497),
+    (-1443997172, T496),
+    (-1427667572, T497),
+    (-1406672372, T496),
+    (-1396217972, T497),
+    (-1376950772, T496),
+    (-1364768372, T497),
+    (-1345414772, T496),
+    (-1333318772, T497),
+    (-1313792372, T496),
+    (-1301264372, T497),
+    (-1282256372, T496),
+    (-1269814772, T497),
+    (-1250720372, T496),
+    (-1238365172, T497),
+    (-1219184372, T496),
+    (-1206915572, T497),
+    (-1186957172, T496),
+    (-1175465972, T497),
+    (-1156025972, T496),
+    (-1143411572, T497),
+    (-1124489972, T496),
+    (-1111961972, T497),
+    (-1092953972, T496),
+    (-1080512372, T497),
+    (-1061331572, T496),
+    (-1049062772, T497),
+    (-1029190772, T496),
+    (-1025745572, T498),
+    (-1017613200, T499),
+    (-998259600, T498),
+    (-986163600, T499),
+    (-966723600, T498),
+    (-954109200, T499),
+    (-935022000, T14),
+    (-857257200, T13),
+    (-844556400, T14),
+    (-828226800, T13),
+    (-812502000, T14),
+    (-796777200, T13),
+    (-781052400, T14),
+    (-766623600, T13),
+    (228877200, T14),
+    (243997200, T13),
+    (260326800, T14),
+    (276051600, T13),
+    (291776400, T14),
+    (307501200, T13),
+    (323830800, T14),
+    (338950800, T13),
+    (354675600, T14),
+    (370400400, T13),
+    (386125200, T14),
+    (401850000, T13),
+    (417574800, T14),
+    (433299600, T13),
+    (449024400, T14),
+    (465354000, T13),
+    (481078800, T14),
+    (496803600, T13),
+    (512528400, T14),
+    (528253200, T13),
+    (543978000, T14),
+    (559702800, T13),
+    (575427600, T14),
+    (591152400, T13),
+    (606877200, T14),
+    (622602000, T13),
+    (638326800, T14),
+    (654656400, T13),
+    (670381200, T14),
+    (686106000, T13),
+    (701830800, T14),
+    (717555600, T13),
+    (733280400, T14),
+    (749005200, T13),
+    (764730000, T14),
+    (780454800, T13),
+    (796179600, T14),
+    (811904400, T13),
+    (828234000, T14),
+    (846378000, T13),
+    (859683600, T14),
+    (877827600, T13),
+];
+
+static EUROPE_ANDORRA: &[(i64, FixedTimespan<'static>)] = &[
+    (-2177453164, T11),
+    (-733881600, T13),
+    (481078800, T14),
+    (496803600, T13),
+    (512528400, T14),
+    (528253200, T13),
+    (543978000, T14),
+    (559702800, T13),
+    (575427600, T14),
+    (591152400, T13),
+    (606877200, T14),
+    (622602000, T13),
+    (638326800, T14),
+    (654656400, T13),
+    (670381200, T14),
+    (686106000, T13),
+    (701830800, T14),
+    (717555600, T13),
+    (733280400, T14),
+    (749005200, T13),
+    (764730000, T14),
+    (780454800, T13),
+    (796179600, T14),
+    (811904400, T13),
+    (828234000, T14),
+    (846378000, T13),
+    (859683600, T14),
+    (877827600, T13),
+];
+
+static EUROPE_ASTRAKHAN: &[(i64, FixedTimespan<'static>)] = &[
+    (-1441249932, T310),
+    (-1247540400, T324),
+    (354916800, T325),
+    (370724400, T324),
+    (386452800, T325),
+    (402260400, T324),
+    (417988800, T325),
+    (433796400, T324),
+    (449611200, T325),
+    (465343200, T324),
+    (481068000, T325),
+    (496792800, T324),
+    (512517600, T325),
+    (528242400, T324),
+    (543967200, T325),
+    (559692000, T324),
+    (575416800, T325),
+    (591141600, T324),
+    (606866400, T310),
+    (606870000, T331),
+    (622594800, T310),
+    (638319600, T331),
+    (654649200, T310),
+    (670374000, T324),
+    (701820000, T310),
+    (701823600, T331),
+    (717548400, T310),
+    (733273200, T331),
+    (748998000, T310),
+    (764722800, T331),
+    (780447600, T310),
+    (796172400, T331),
+    (811897200, T310),
+    (828226800, T331),
+    (846370800, T310),
+    (859676400, T331),
+    (877820400, T310),
+    (891126000, T331),
+    (909270000, T310),
+    (922575600, T331),
+    (941324400, T310),
+    (954025200, T331),
+    (972774000, T310),
+    (985474800, T331),
+    (1004223600, T310),
+    (1017529200, T331),
+    (1035673200, T310),
+    (1048978800, T331),
+    (1067122800, T310),
+    (1080428400, T331),
+    (1099177200, T310),
+    (1111878000, T331),
+    (1130626800, T310),
+    (1143327600, T331),
+    (1162076400, T310),
+    (1174777200, T331),
+    (1193526000, T310),
+    (1206831600, T331),
+    (1224975600, T310),
+    (1238281200, T331),
+    (1256425200, T310),
+    (1269730800, T331),
+    (1288479600, T310),
+    (1301180400, T324),
+    (1414274400, T310),
+    (1459033200, T324),
+];
+
+static EUROPE_ATHENS: &[(i64, FixedTimespan<'static>)] = &[
+    (-1686101632, T29),
+    (-1182996000, T30),
+    (-1178161200, T29),
+    (-906861600, T30),
+    (-904878000, T14),
+    (-857257200, T13),
+    (-844477200, T14),
+    (-828237600, T13),
+    (-812422800, T29),
+    (-552362400, T30),
+    (-541652400, T29),
+    (166485600, T30),
+    (186184800, T29),
+    (198028800, T30),
+    (213753600, T29),
+    (228873600, T30),
+    (244080000, T29),
+    (260323200, T30),
+    (275446800, T29),
+    (291798000, T30),
+    (307407600, T29),
+    (323388000, T30),
+    (338936400, T29),
+    (354675600, T30),
+    (370400400, T29),
+    (386125200, T30),
+    (401850000, T29),
+    (417574800, T30),
+    (433299600, T29),
+    (449024400, T30),
+    (465354000, T29),
+    (481078800, T30),
+    (496803600, T29),
+    (512528400, T30),
+    (528253200, T29),
+    (543978000, T30),
+    (559702800, T29),
+    (575427600, T30),
+    (591152400, T29),
+    (606877200, T30),
+    (622602000, T29),
+    (638326800, T30),
+    (654656400, T29),
+    (670381200, T30),
+    (686106000, T29),
+    (701830800, T30),
+    (717555600, T29),
+    (733280400, T30),
+    (749005200, T29),
+    (764730000, T30),
+    (780454800, T29),
+    (796179600, T30),
+    (811904400, T29),
+    (828234000, T30),
+    (846378000, T29),
+    (859683600, T30),
+    (877827600, T29),
+];
+
+static EUROPE_BELGRADE: &[(i64, FixedTimespan<'static>)] = &[
+    (-2713915320, T13),
+    (-905824800, T14),
+    (-857257200, T13),
+    (-844556400, T14),
+    (-828226800, T13),
+    (-812502000, T14),
+    (-796777200, T13),
+    (-777942000, T14),
+    (-766623600, T13),
+    (417574800, T14),
+    (433299600, T13),
+    (449024400, T14),
+    (465354000, T13),
+    (481078800, T14),
+    (496803600, T13),
+    (512528400, T14),
+    (528253200, T13),
+    (543978000, T14),
+    (559702800, T13),
+    (575427600, T14),
+    (591152400, T13),
+    (606877200, T14),
+    (622602000, T13),
+    (638326800, T14),
+    (654656400, T13),
+    (670381200, T14),
+    (686106000, T13),
+    (701830800, T14),
+    (717555600, T13),
+    (733280400, T14),
+    (749005200, T13),
+    (764730000, T14),
+    (780454800, T13),
+    (796179600, T14),
+    (811904400, T13),
+    (828234000, T14),
+    (846378000, T13),
+    (859683600, T14),
+    (877827600, T13),
+];
+
+static EUROPE_BERLIN: &[(i64, FixedTimespan<'static>)] = &[
+    (-2422054408, T13),
+    (-1693706400, T14),
+    (-1680483600, T13),
+    (-1663455600, T14),
+    (-1650150000, T13),
+    (-1632006000, T14),
+    (-1618700400, T13),
+    (-938905200, T14),
+    (-857257200, T13),
+    (-844556400, T14),
+    (-828226800, T13),
+    (-812502000, T14),
+    (-796777200, T13),
+    (-781052400, T14),
+    (-776563200, T13),
+    (-776559600, T505),
+    (-765936000, T14),
+    (-761180400, T13),
+    (-748479600, T14),
+    (-733273200, T13),
+    (-717631200, T14),
+    (-714610800, T505),
+    (-710380800, T14),
+    (-701910000, T13),
+    (-684975600, T14),
+    (-670460400, T13),
+    (-654130800, T14),
+    (-639010800, T13),
+    (323830800, T14),
+    (338950800, T13),
+    (354675600, T14),
+    (370400400, T13),
+    (386125200, T14),
+    (401850000, T13),
+    (417574800, T14),
+    (433299600, T13),
+    (449024400, T14),
+    (465354000, T13),
+    (481078800, T14),
+    (496803600, T13),
+    (512528400, T14),
+    (528253200, T13),
+    (543978000, T14),
+    (559702800, T13),
+    (575427600, T14),
+    (591152400, T13),
+    (606877200, T14),
+    (622602000, T13),
+    (638326800, T14),
+    (654656400, T13),
+    (670381200, T14),
+    (686106000, T13),
+    (701830800, T14),
+    (717555600, T13),
+    (733280400, T14),
+    (749005200, T13),
+    (764730000, T14),
+    (780454800, T13),
+    (796179600, T14),
+    (811904400, T13),
+    (828234000, T14),
+    (846378000, T13),
+    (859683600, T14),
+    (877827600, T13),
+];
+
+static EUROPE_BRUSSELS: &[(i64, FixedTimespan<'static>)] = &[
+    (-2450995200, T11),
+    (-1740355200, T13),
+    (-1693702800, T14),
+    (-1680483600, T13),
+    (-1663455600, T14),
+    (-1650150000, T13),
+    (-1632006000, T14),
+    (-1618700400, T13),
+    (-1613826000, T11),
+    (-1604278800, T12),
+    (-1585530000, T11),
+    (-1574038800, T12),
+    (-1552266000, T11),
+    (-1539997200, T12),
+    (-1520557200, T11),
+    (-1507510800, T12),
+    (-1490576400, T11),
+    (-1473642000, T12),
+    (-1459126800, T11),
+    (-1444006800, T12),
+    (-1427677200, T11),
+    (-1411952400, T12),
+    (-1396227600, T11),
+    (-1379293200, T12),
+    (-1364778000, T11),
+    (-1348448400, T12),
+    (-1333328400, T11),
+    (-1316394000, T12),
+    (-1301263200, T11),
+    (-1284328800, T12),
+    (-1269813600, T11),
+    (-1253484000, T12),
+    (-1238364000, T11),
+    (-1221429600, T12),
+    (-1206914400, T11),
+    (-1191189600, T12),
+    (-1175464800, T11),
+    (-1160344800, T12),
+    (-1143410400, T11),
+    (-1127685600, T12),
+    (-1111960800, T11),
+    (-1096840800, T12),
+    (-1080511200, T11),
+    (-1063576800, T12),
+    (-1049061600, T11),
+    (-1033336800, T12),
+    (-1017612000, T11),
+    (-1002492000, T12),
+    (-986162400, T11),
+    (-969228000, T12),
+    (-950479200, T11),
+    (-942012000, T12),
+    (-934668000, T14),
+    (-857257200, T13),
+    (-844556400, T14),
+    (-828226800, T13),
+    (-812502000, T14),
+    (-798073200, T13),
+    (-781052400, T14),
+    (-766623600, T13),
+    (-745455600, T14),
+    (-733273200, T13),
+    (228877200, T14),
+    (243997200, T13),
+    (260326800, T14),
+    (276051600, T13),
+    (291776400, T14),
+    (307501200, T13),
+    (323830800, T14),
+    (338950800, T13),
+    (354675600, T14),
+    (370400400, T13),
+    (386125200, T14),
+    (401850000, T13),
+    (417574800, T14),
+    (433299600, T13),
+    (449024400, T14),
+    (465354000, T13),
+    (481078800, T14),
+    (496803600, T13),
+    (512528400, T14),
+    (528253200, T13),
+    (543978000, T14),
+    (559702800, T13),
+    (575427600, T14),
+    (591152400, T13),
+    (606877200, T14),
+    (622602000, T13),
+    (638326800, T14),
+    (654656400, T13),
+    (670381200, T14),
+    (686106000, T13),
+    (701830800, T14),
+    (717555600, T13),
+    (733280400, T14),
+    (749005200, T13),
+    (764730000, T14),
+    (780454800, T13),
+    (796179600, T14),
+    (811904400, T13),
+    (828234000, T14),
+    (846378000, T13),
+    (859683600, T14),
+    (877827600, T13),
+];
+
+static EUROPE_BUCHAREST: &[(i64, FixedTimespan<'static>)] = &[
+    (-1213148664, T29),
+    (-1187056800, T30),
+    (-1175479200, T29),
+    (-1159754400, T30),
+    (-1144029600, T29),
+    (-1127700000, T30),
+    (-1111975200, T29),
+    (-1096250400, T30),
+    (-1080525600, T29),
+    (-1064800800, T30),
+    (-1049076000, T29),
+    (-1033351200, T30),
+    (-1017626400, T29),
+    (-1001901600, T30),
+    (-986176800, T29),
+    (-970452000, T30),
+    (-954727200, T29),
+    (296604000, T30),
+    (307486800, T29),
+    (323816400, T30),
+    (338940000, T29),
+    (354672000, T30),
+    (370396800, T29),
+    (386121600, T30),
+    (401846400, T29),
+    (417571200, T30),
+    (433296000, T29),
+    (449020800, T30),
+    (465350400, T29),
+    (481075200, T30),
+    (496800000, T29),
+    (512524800, T30),
+    (528249600, T29),
+    (543974400, T30),
+    (559699200, T29),
+    (575424000, T30),
+    (591148800, T29),
+    (606873600, T30),
+    (622598400, T29),
+    (638323200, T30),
+    (654652800, T29),
+    (670370400, T30),
+    (686095200, T29),
+    (701820000, T30),
+    (717544800, T29),
+    (733269600, T30),
+    (748994400, T29),
+    (764719200, T30),
+    (780440400, T29),
+    (796168800, T30),
+    (811890000, T29),
+    (828223200, T30),
+    (846363600, T29),
+    (859683600, T30),
+    (877827600, T29),
+];
+
+static EUROPE_BUDAPEST: &[(i64, FixedTimespan<'static>)] = &[
+    (-2498260580, T13),
+    (-1693706400, T14),
+    (-1680483600, T13),
+    (-1663455600, T14),
+    (-1650150000, T13),
+    (-1632006000, T14),
+    (-1618700400, T13),
+    (-1600470000, T14),
+    (-1587250800, T13),
+    (-1569711600, T14),
+    (-1555196400, T13),
+    (-906775200, T14),
+    (-857257200, T13),
+    (-844556400, T14),
+    (-828226800, T13),
+    (-812502000, T14),
+    (-796777200, T13),
+    (-778471200, T14),
+    (-762656400, T13),
+    (-749689200, T14),
+    (-733276800, T13),
+    (-717634800, T14),
+    (-701910000, T13),
+    (-686185200, T14),
+    (-670460400, T13),
+    (-654130800, T14),
+    (-639010800, T13),
+    (-492656400, T14),
+    (-481168800, T13),
+    (-461199600, T14),
+    (-449708400, T13),
+    (-428540400, T14),
+    (-418258800, T13),
+    (-397090800, T14),
+    (-386809200, T13),
+    (323823600, T14),
+    (338943600, T13),
+    (354668400, T14),
+    (370393200, T13),
+    (386118000, T14),
+    (401842800, T13),
+    (417567600, T14),
+    (433292400, T13),
+    (449024400, T14),
+    (465354000, T13),
+    (481078800, T14),
+    (496803600, T13),
+    (512528400, T14),
+    (528253200, T13),
+    (543978000, T14),
+    (559702800, T13),
+    (575427600, T14),
+    (591152400, T13),
+    (606877200, T14),
+    (622602000, T13),
+    (638326800, T14),
+    (654656400, T13),
+    (670381200, T14),
+    (686106000, T13),
+    (701830800, T14),
+    (717555600, T13),
+    (733280400, T14),
+    (749005200, T13),
+    (764730000, T14),
+    (780454800, T13),
+    (796179600, T14),
+    (811904400, T13),
+    (828234000, T14),
+    (846378000, T13),
+    (859683600, T14),
+    (877827600, T13),
+];
+
+static EUROPE_CHISINAU: &[(i64, FixedTimespan<'static>)] = &[
+    (-2840147720, T510),
+    (-1637114100, T511),
+    (-1213148664, T29),
+    (-1187056800, T30),
+    (-1175479200, T29),
+    (-1159754400, T30),
+    (-1144029600, T29),
+    (-1127700000, T30),
+    (-1111975200, T29),
+    (-1096250400, T30),
+    (-1080525600, T29),
+    (-1064800800, T30),
+    (-1049076000, T29),
+    (-1033351200, T30),
+    (-1017626400, T29),
+    (-1001901600, T30),
+    (-986176800, T29),
+    (-970452000, T30),
+    (-954727200, T29),
+    (-927165600, T30),
+    (-898138800, T14),
+    (-857257200, T13),
+    (-844556400, T14),
+    (-828226800, T13),
+    (-812502000, T14),
+    (-800157600, T512),
+    (354920400, T513),
+    (370728000, T512),
+    (386456400, T513),
+    (402264000, T512),
+    (417992400, T513),
+    (433800000, T512),
+    (449614800, T513),
+    (465346800, T512),
+    (481071600, T513),
+    (496796400, T512),
+    (512521200, T513),
+    (528246000, T512),
+    (543970800, T513),
+    (559695600, T512),
+    (575420400, T513),
+    (591145200, T512),
+    (606870000, T513),
+    (622594800, T512),
+    (638319600, T513),
+    (641944800, T30),
+    (654652800, T29),
+    (670377600, T30),
+    (686102400, T29),
+    (701820000, T30),
+    (717541200, T29),
+    (733269600, T30),
+    (748990800, T29),
+    (764719200, T30),
+    (780440400, T29),
+    (796168800, T30),
+    (811890000, T29),
+    (828223200, T30),
+    (846363600, T29),
+    (859680000, T30),
+    (877824000, T29),
+    (891129600, T30),
+    (909273600, T29),
+];
+
+static EUROPE_COPENHAGEN: &[(i64, FixedTimespan<'static>)] = &[
+    (-2398294220, T13),
+    (-1692496800, T14),
+    (-1680490800, T13),
+    (-935110800, T14),
+    (-857257200, T13),
+    (-844556400, T14),
+    (-828226800, T13),
+    (-812502000, T14),
+    (-796777200, T13),
+    (-781052400, T14),
+    (-769388400, T13),
+    (-747010800, T14),
+    (-736383600, T13),
+    (-715215600, T14),
+    (-706748400, T13),
+    (-683161200, T14),
+    (-675298800, T13),
+    (323830800, T14),
+    (338950800, T13),
+    (354675600, T14),
+    (370400400, T13),
+    (386125200, T14),
+    (401850000, T13),
+    (417574800, T14),
+    (433299600, T13),
+    (449024400, T14),
+    (465354000, T13),
+    (481078800, T14),
+    (496803600, T13),
+    (512528400, T14),
+    (528253200, T13),
+    (543978000, T14),
+    (559702800, T13),
+    (575427600, T14),
+    (591152400, T13),
+    (606877200, T14),
+    (622602000, T13),
+    (638326800, T14),
+    (654656400, T13),
+    (670381200, T14),
+    (686106000, T13),
+    (701830800, T14),
+    (717555600, T13),
+    (733280400, T14),
+    (749005200, T13),
+    (764730000, T14),
+    (780454800, T13),
+    (796179600, T14),
+    (811904400, T13),
+    (828234000, T14),
+    (846378000, T13),
+    (859683600, T14),
+    (877827600, T13),
+];
+
+static EUROPE_DUBLIN: &[(i64, FixedTimespan<'static>)] = &[
+    (-1691962479, T516),
+    (-1680471279, T1),
+    (-1664143200, T517),
+    (-1650146400, T1),
+    (-1633903200, T517),
+    (-1617487200, T1),
+    (-1601848800, T517),
+    (-1586037600, T1),
+    (-1570399200, T517),
+    (-1552168800, T1),
+    (-1538344800, T517),
+    (-1522533600, T1),
+    (-1507500000, T518),
+    (-1490565600, T1),
+    (-1473631200, T518),
+    (-1460930400, T1),
+    (-1442786400, T518),
+    (-1428876000, T1),
+    (-1410732000, T518),
+    (-1396216800, T1),
+    (-1379282400, T518),
+    (-1364767200, T1),
+    (-1348437600, T518),
+    (-1333317600, T1),
+    (-1315778400, T518),
+    (-1301263200, T1),
+    (-1284328800, T518),
+    (-1269813600, T1),
+    (-1253484000, T518),
+    (-1238364000, T1),
+    (-1221429600, T518),
+    (-1206914400, T1),
+    (-1189980000, T518),
+    (-1175464800, T1),
+    (-1159135200, T518),
+    (-1143410400, T1),
+    (-1126476000, T518),
+    (-1111960800, T1),
+    (-1095631200, T518),
+    (-1080511200, T1),
+    (-1063576800, T518),
+    (-1049061600, T1),
+    (-1032127200, T518),
+    (-1017612000, T1),
+    (-1001282400, T518),
+    (-986162400, T1),
+    (-969228000, T518),
+    (-950479200, T1),
+    (-942012000, T518),
+    (-733356000, T1),
+    (-719445600, T518),
+    (-699487200, T1),
+    (-684972000, T518),
+    (-668037600, T1),
+    (-654732000, T518),
+    (-636588000, T1),
+    (-622072800, T518),
+    (-605743200, T1),
+    (-590623200, T518),
+    (-574293600, T1),
+    (-558568800, T518),
+    (-542239200, T1),
+    (-527119200, T518),
+    (-512604000, T1),
+    (-496274400, T518),
+    (-481154400, T1),
+    (-464220000, T518),
+    (-449704800, T1),
+    (-432165600, T518),
+    (-417650400, T1),
+    (-401320800, T518),
+    (-386200800, T1),
+    (-369266400, T518),
+    (-354751200, T1),
+    (-337816800, T518),
+    (-323301600, T1),
+    (-306972000, T518),
+    (-291852000, T1),
+    (-276732000, T518),
+    (-257983200, T1),
+    (-245282400, T518),
+    (-226533600, T1),
+    (-213228000, T518),
+    (-195084000, T1),
+    (-182383200, T518),
+    (-163634400, T1),
+    (-150933600, T518),
+    (-132184800, T1),
+    (-119484000, T518),
+    (-100735200, T1),
+    (-88034400, T518),
+    (-68680800, T1),
+    (-59004000, T518),
+    (57722400, T519),
+    (69818400, T520),
+    (89172000, T519),
+    (101268000, T520),
+    (120621600, T519),
+    (132717600, T520),
+    (152071200, T519),
+    (164167200, T520),
+    (183520800, T519),
+    (196221600, T520),
+    (214970400, T519),
+    (227671200, T520),
+    (246420000, T519),
+    (259120800, T520),
+    (278474400, T519),
+    (290570400, T520),
+    (309924000, T519),
+    (322020000, T520),
+    (341373600, T519),
+    (354675600, T520),
+    (372819600, T519),
+    (386125200, T520),
+    (404269200, T519),
+    (417574800, T520),
+    (435718800, T519),
+    (449024400, T520),
+    (467773200, T519),
+    (481078800, T520),
+    (499222800, T519),
+    (512528400, T520),
+    (530672400, T519),
+    (543978000, T520),
+    (562122000, T519),
+    (575427600, T520),
+    (593571600, T519),
+    (606877200, T520),
+    (625626000, T519),
+    (638326800, T520),
+    (657075600, T519),
+    (670381200, T520),
+    (688525200, T519),
+    (701830800, T520),
+    (719974800, T519),
+    (733280400, T520),
+    (751424400, T519),
+    (764730000, T520),
+    (782874000, T519),
+    (796179600, T520),
+    (814323600, T519),
+    (828234000, T520),
+    (846378000, T519),
+    (859683600, T520),
+    (877827600, T519),
+];
+
+static EUROPE_GIBRALTAR: &[(i64, FixedTimespan<'static>)] = &[
+    (-2821649916, T1),
+    (-1691964000, T517),
+    (-1680472800, T1),
+    (-1664143200, T517),
+    (-1650146400, T1),
+    (-1633903200, T517),
+    (-1617487200, T1),
+    (-1601848800, T517),
+    (-1586037600, T1),
+    (-1570399200, T517),
+    (-1552168800, T1),
+    (-1538344800, T517),
+    (-1522533600, T1),
+    (-1507500000, T517),
+    (-1490565600, T1),
+    (-1473631200, T517),
+    (-1460930400, T1),
+    (-1442786400, T517),
+    (-1428876000, T1),
+    (-1410732000, T517),
+    (-1396216800, T1),
+    (-1379282400, T517),
+    (-1364767200, T1),
+    (-1348437600, T517),
+    (-1333317600, T1),
+    (-1315778400, T517),
+    (-1301263200, T1),
+    (-1284328800, T517),
+    (-1269813600, T1),
+    (-1253484000, T517),
+    (-1238364000, T1),
+    (-1221429600, T517),
+    (-1206914400, T1),
+    (-1189980000, T517),
+    (-1175464800, T1),
+    (-1159135200, T517),
+    (-1143410400, T1),
+    (-1126476000, T517),
+    (-1111960800, T1),
+    (-1095631200, T517),
+    (-1080511200, T1),
+    (-1063576800, T517),
+    (-1049061600, T1),
+    (-1032127200, T517),
+    (-1017612000, T1),
+    (-1001282400, T517),
+    (-986162400, T1),
+    (-969228000, T517),
+    (-950479200, T1),
+    (-942012000, T517),
+    (-904518000, T522),
+    (-896050800, T517),
+    (-875487600, T522),
+    (-864601200, T517),
+    (-844038000, T522),
+    (-832546800, T517),
+    (-812588400, T522),
+    (-798073200, T517),
+    (-781052400, T522),
+    (-772066800, T517),
+    (-764805600, T1),
+    (-748476000, T517),
+    (-733356000, T1),
+    (-719445600, T517),
+    (-717030000, T522),
+    (-706748400, T517),
+    (-699487200, T1),
+    (-687996000, T517),
+    (-668037600, T1),
+    (-654732000, T517),
+    (-636588000, T1),
+    (-622072800, T517),
+    (-605743200, T1),
+    (-590623200, T517),
+    (-574293600, T1),
+    (-558568800, T517),
+    (-542239200, T1),
+    (-527119200, T517),
+    (-512604000, T1),
+    (-496274400, T517),
+    (-481154400, T1),
+    (-464220000, T517),
+    (-449704800, T1),
+    (-432165600, T517),
+    (-417650400, T1),
+    (-401320800, T13),
+    (386125200, T14),
+    (401850000, T13),
+    (417574800, T14),
+    (433299600, T13),
+    (449024400, T14),
+    (465354000, T13),
+    (481078800, T14),
+    (496803600, T13),
+    (512528400, T14),
+    (528253200, T13),
+    (543978000, T14),
+    (559702800, T13),
+    (575427600, T14),
+    (591152400, T13),
+    (606877200, T14),
+    (622602000, T13),
+    (638326800, T14),
+    (654656400, T13),
+    (670381200, T14),
+    (686106000, T13),
+    (701830800, T14),
+    (717555600, T13),
+    (733280400, T14),
+    (749005200, T13),
+    (764730000, T14),
+    (780454800, T13),
+    (796179600, T14),
+    (811904400, T13),
+    (828234000, T14),
+    (846378000, T13),
+    (859683600, T14),
+    (877827600, T13),
+];
+
+static EUROPE_GUERNSEY: &[(i64, FixedTimespan<'static>)] = &[
+    (-1784245791, T1),
+    (-1691964000, T517),
+    (-1680472800, T1),
+    (-1664143200, T517),
+    (-1650146400, T1),
+    (-1633903200, T517),
+    (-1617487200, T1),
+    (-1601848800, T517),
+    (-1586037600, T1),
+    (-1570399200, T517),
+    (-1552168800, T1),
+    (-1538344800, T517),
+    (-1522533600, T1),
+    (-1507500000, T517),
+    (-1490565600, T1),
+    (-1473631200, T517),
+    (-1460930400, T1),
+    (-1442786400, T517),
+    (-1428876000, T1),
+    (-1410732000, T517),
+    (-1396216800, T1),
+    (-1379282400, T517),
+    (-1364767200, T1),
+    (-1348437600, T517),
+    (-1333317600, T1),
+    (-1315778400, T517),
+    (-1301263200, T1),
+    (-1284328800, T517),
+    (-1269813600, T1),
+    (-1253484000, T517),
+    (-1238364000, T1),
+    (-1221429600, T517),
+    (-1206914400, T1),
+    (-1189980000, T517),
+    (-1175464800, T1),
+    (-1159135200, T517),
+    (-1143410400, T1),
+    (-1126476000, T517),
+    (-1111960800, T1),
+    (-1095631200, T517),
+    (-1080511200, T1),
+    (-1063576800, T517),
+    (-1049061600, T1),
+    (-1032127200, T517),
+    (-1017612000, T1),
+    (-1001282400, T517),
+    (-986162400, T1),
+    (-969228000, T517),
+    (-950479200, T1),
+    (-942012000, T517),
+    (-930963600, T14),
+    (-857257200, T13),
+    (-844556400, T14),
+    (-828226800, T13),
+    (-812502000, T14),
+    (-796777200, T13),
+    (-781052400, T14),
+    (-772066800, T517),
+    (-764805600, T1),
+    (-748476000, T517),
+    (-733356000, T1),
+    (-719445600, T517),
+    (-717030000, T522),
+    (-706748400, T517),
+    (-699487200, T1),
+    (-687996000, T517),
+    (-668037600, T1),
+    (-654732000, T517),
+    (-636588000, T1),
+    (-622072800, T517),
+    (-605743200, T1),
+    (-590623200, T517),
+    (-574293600, T1),
+    (-558568800, T517),
+    (-542239200, T1),
+    (-527119200, T517),
+    (-512604000, T1),
+    (-496274400, T517),
+    (-481154400, T1),
+    (-464220000, T517),
+    (-449704800, T1),
+    (-432165600, T517),
+    (-417650400, T1),
+    (-401320800, T517),
+    (-386200800, T1),
+    (-369266400, T517),
+    (-354751200, T1),
+    (-337816800, T517),
+    (-323301600, T1),
+    (-306972000, T517),
+    (-291852000, T1),
+    (-276732000, T517),
+    (-257983200, T1),
+    (-245282400, T517),
+    (-226533600, T1),
+    (-213228000, T517),
+    (-195084000, T1),
+    (-182383200, T517),
+    (-163634400, T1),
+    (-150933600, T517),
+    (-132184800, T1),
+    (-119484000, T517),
+    (-100735200, T1),
+    (-88034400, T517),
+    (-68680800, T1),
+    (-59004000, T517),
+    (57722400, T1),
+    (69818400, T517),
+    (89172000, T1),
+    (101268000, T517),
+    (120621600, T1),
+    (132717600, T517),
+    (152071200, T1),
+    (164167200, T517),
+    (183520800, T1),
+    (196221600, T517),
+    (214970400, T1),
+    (227671200, T517),
+    (246420000, T1),
+    (259120800, T517),
+    (278474400, T1),
+    (290570400, T517),
+    (309924000, T1),
+    (322020000, T517),
+    (341373600, T1),
+    (354675600, T517),
+    (372819600, T1),
+    (386125200, T517),
+    (404269200, T1),
+    (417574800, T517),
+    (435718800, T1),
+    (449024400, T517),
+    (467773200, T1),
+    (481078800, T517),
+    (499222800, T1),
+    (512528400, T517),
+    (530672400, T1),
+    (543978000, T517),
+    (562122000, T1),
+    (575427600, T517),
+    (593571600, T1),
+    (606877200, T517),
+    (625626000, T1),
+    (638326800, T517),
+    (657075600, T1),
+    (670381200, T517),
+    (688525200, T1),
+    (701830800, T517),
+    (719974800, T1),
+    (733280400, T517),
+    (751424400, T1),
+    (764730000, T517),
+    (782874000, T1),
+    (796179600, T517),
+    (814323600, T1),
+    (828234000, T517),
+    (846378000, T1),
+    (859683600, T517),
+    (877827600, T1),
+];
+
+static EUROPE_HELSINKI: &[(i64, FixedTimespan<'static>)] = &[
+    (-1535938789, T29),
+    (-875671200, T30),
+    (-859773600, T29),
+    (354672000, T30),
+    (370396800, T29),
+    (386121600, T30),
+    (401846400, T29),
+    (417574800, T30),
+    (433299600, T29),
+    (449024400, T30),
+    (465354000, T29),
+    (481078800, T30),
+    (496803600, T29),
+    (512528400, T30),
+    (528253200, T29),
+    (543978000, T30),
+    (559702800, T29),
+    (575427600, T30),
+    (591152400, T29),
+    (606877200, T30),
+    (622602000, T29),
+    (638326800, T30),
+    (654656400, T29),
+    (670381200, T30),
+    (686106000, T29),
+    (701830800, T30),
+    (717555600, T29),
+    (733280400, T30),
+    (749005200, T29),
+    (764730000, T30),
+    (780454800, T29),
+    (796179600, T30),
+    (811904400, T29),
+    (828234000, T30),
+    (846378000, T29),
+    (859683600, T30),
+    (877827600, T29),
+];
+
+static EUROPE_ISLE_OF_MAN: &[(i64, FixedTimespan<'static>)] = &[
+    (-2737842125, T1),
+    (-1691964000, T517),
+    (-1680472800, T1),
+    (-1664143200, T517),
+    (-1650146400, T1),
+    (-1633903200, T517),
+    (-1617487200, T1),
+    (-1601848800, T517),
+    (-1586037600, T1),
+    (-1570399200, T517),
+    (-1552168800, T1),
+    (-1538344800, T517),
+    (-1522533600, T1),
+    (-1507500000, T517),
+    (-1490565600, T1),
+    (-1473631200, T517),
+    (-1460930400, T1),
+    (-1442786400, T517),
+    (-1428876000, T1),
+    (-1410732000, T517),
+    (-1396216800, T1),
+    (-1379282400, T517),
+    (-1364767200, T1),
+    (-1348437600, T517),
+    (-1333317600, T1),
+    (-1315778400, T517),
+    (-1301263200, T1),
+    (-1284328800, T517),
+    (-1269813600, T1),
+    (-1253484000, T517),
+    (-1238364000, T1),
+    (-1221429600, T517),
+    (-1206914400, T1),
+    (-1189980000, T517),
+    (-1175464800, T1),
+    (-1159135200, T517),
+    (-1143410400, T1),
+    (-1126476000, T517),
+    (-1111960800, T1),
+    (-1095631200, T517),
+    (-1080511200, T1),
+    (-1063576800, T517),
+    (-1049061600, T1),
+    (-1032127200, T517),
+    (-1017612000, T1),
+    (-1001282400, T517),
+    (-986162400, T1),
+    (-969228000, T517),
+    (-950479200, T1),
+    (-942012000, T517),
+    (-904518000, T522),
+    (-896050800, T517),
+    (-875487600, T522),
+    (-864601200, T517),
+    (-844038000, T522),
+    (-832546800, T517),
+    (-812588400, T522),
+    (-798073200, T517),
+    (-781052400, T522),
+    (-772066800, T517),
+    (-764805600, T1),
+    (-748476000, T517),
+    (-733356000, T1),
+    (-719445600, T517),
+    (-717030000, T522),
+    (-706748400, T517),
+    (-699487200, T1),
+    (-687996000, T517),
+    (-668037600, T1),
+    (-654732000, T517),
+    (-636588000, T1),
+    (-622072800, T517),
+    (-605743200, T1),
+    (-590623200, T517),
+    (-574293600, T1),
+    (-558568800, T517),
+    (-542239200, T1),
+    (-527119200, T517),
+    (-512604000, T1),
+    (-496274400, T517),
+    (-481154400, T1),
+    (-464220000, T517),
+    (-449704800, T1),
+    (-432165600, T517),
+    (-417650400, T1),
+    (-401320800, T517),
+    (-386200800, T1),
+    (-369266400, T517),
+    (-354751200, T1),
+    (-337816800, T517),
+    (-323301600, T1),
+    (-306972000, T517),
+    (-291852000, T1),
+    (-276732000, T517),
+    (-257983200, T1),
+    (-245282400, T517),
+    (-226533600, T1),
+    (-213228000, T517),
+    (-195084000, T1),
+    (-182383200, T517),
+    (-163634400, T1),
+    (-150933600, T517),
+    (-132184800, T1),
+    (-119484000, T517),
+    (-100735200, T1),
+    (-88034400, T517),
+    (-68680800, T1),
+    (-59004000, T517),
+    (57722400, T1),
+    (69818400, T517),
+    (89172000, T1),
+    (101268000, T517),
+    (120621600, T1),
+    (132717600, T517),
+    (152071200, T1),
+    (164167200, T517),
+    (183520800, T1),
+    (196221600, T517),
+    (214970400, T1),
+    (227671200, T517),
+    (246420000, T1),
+    (259120800, T517),
+    (278474400, T1),
+    (290570400, T517),
+    (309924000, T1),
+    (322020000, T517),
+    (341373600, T1),
+    (354675600, T517),
+    (372819600, T1),
+    (386125200, T517),
+    (404269200, T1),
+    (417574800, T517),
+    (435718800, T1),
+    (449024400, T517),
+    (467773200, T1),
+    (481078800, T517),
+    (499222800, T1),
+    (512528400, T517),
+    (530672400, T1),
+    (543978000, T517),
+    (562122000, T1),
+    (575427600, T517),
+    (593571600, T1),
+    (606877200, T517),
+    (625626000, T1),
+    (638326800, T517),
+    (657075600, T1),
+    (670381200, T517),
+    (688525200, T1),
+    (701830800, T517),
+    (719974800, T1),
+    (733280400, T517),
+    (751424400, T1),
+    (764730000, T517),
+    (782874000, T1),
+    (796179600, T517),
+    (814323600, T1),
+    (828234000, T517),
+    (846378000, T1),
+    (859683600, T517),
+    (877827600, T1),
+];
+
+static EUROPE_ISTANBUL: &[(i64, FixedTimespan<'static>)] = &[
+    (-2840147752, T527),
+    (-1869875816, T29),
+    (-1693706400, T30),
+    (-1680490800, T29),
+    (-1570413600, T30),
+    (-1552186800, T29),
+    (-1538359200, T30),
+    (-1522551600, T29),
+    (-1507514400, T30),
+    (-1490583600, T29),
+    (-1440208800, T30),
+    (-1428030000, T29),
+    (-1409709600, T30),
+    (-1396494000, T29),
+    (-931053600, T30),
+    (-922676400, T29),
+    (-917834400, T30),
+    (-892436400, T29),
+    (-875844000, T30),
+    (-764737200, T29),
+    (-744343200, T30),
+    (-733806000, T29),
+    (-716436000, T30),
+    (-701924400, T29),
+    (-684986400, T30),
+    (-670474800, T29),
+    (-654141600, T30),
+    (-639025200, T29),
+    (-622087200, T30),
+    (-606970800, T29),
+    (-590032800, T30),
+    (-575521200, T29),
+    (-235620000, T30),
+    (-194842800, T29),
+    (-177732000, T30),
+    (-165726000, T29),
+    (107910000, T30),
+    (121215600, T29),
+    (133920000, T30),
+    (152665200, T29),
+    (164678400, T30),
+    (184114800, T29),
+    (196214400, T30),
+    (215564400, T29),
+    (228873600, T30),
+    (245804400, T29),
+    (260323200, T30),
+    (428454000, T331),
+    (433893600, T310),
+    (468111600, T29),
+    (482799600, T30),
+    (496710000, T29),
+    (512521200, T30),
+    (528246000, T29),
+    (543970800, T30),
+    (559695600, T29),
+    (575420400, T30),
+    (591145200, T29),
+    (606870000, T30),
+    (622594800, T29),
+    (638319600, T30),
+    (654649200, T29),
+    (670374000, T30),
+    (686098800, T29),
+    (701823600, T30),
+    (717548400, T29),
+    (733273200, T30),
+    (748998000, T29),
+    (764118000, T30),
+    (780447600, T29),
+    (796172400, T30),
+    (811897200, T29),
+    (828226800, T30),
+    (846370800, T29),
+    (859676400, T30),
+    (877820400, T29),
+    (891126000, T30),
+    (909270000, T29),
+    (922575600, T30),
+    (941324400, T29),
+    (954025200, T30),
+    (972774000, T29),
+    (985474800, T30),
+    (1004223600, T29),
+    (1017529200, T30),
+    (1035673200, T29),
+    (1048978800, T30),
+    (1067122800, T29),
+    (1080428400, T30),
+    (1099177200, T29),
+    (1111878000, T30),
+    (1130626800, T29),
+    (1143327600, T30),
+    (1162076400, T29),
+    (1174784400, T30),
+    (1193533200, T29),
+    (1206838800, T30),
+    (1224982800, T29),
+    (1238288400, T30),
+    (1256432400, T29),
+    (1269738000, T30),
+    (1288486800, T29),
+    (1301274000, T30),
+    (1319936400, T29),
+    (1332637200, T30),
+    (1351386000, T29),
+    (1364691600, T30),
+    (1382835600, T29),
+    (1396227600, T30),
+    (1414285200, T29),
+    (1427590800, T30),
+    (1446944400, T29),
+    (1459040400, T30),
+];
+
+static EUROPE_JERSEY: &[(i64, FixedTimespan<'static>)] = &[
+    (-2258092800, T1),
+    (-1691964000, T517),
+    (-1680472800, T1),
+    (-1664143200, T517),
+    (-1650146400, T1),
+    (-1633903200, T517),
+    (-1617487200, T1),
+    (-1601848800, T517),
+    (-1586037600, T1),
+    (-1570399200, T517),
+    (-1552168800, T1),
+    (-1538344800, T517),
+    (-1522533600, T1),
+    (-1507500000, T517),
+    (-1490565600, T1),
+    (-1473631200, T517),
+    (-1460930400, T1),
+    (-1442786400, T517),
+    (-1428876000, T1),
+    (-1410732000, T517),
+    (-1396216800, T1),
+    (-1379282400, T517),
+    (-1364767200, T1),
+    (-1348437600, T517),
+    (-1333317600, T1),
+    (-1315778400, T517),
+    (-1301263200, T1),
+    (-1284328800, T517),
+    (-1269813600, T1),
+    (-1253484000, T517),
+    (-1238364000, T1),
+    (-1221429600, T517),
+    (-1206914400, T1),
+    (-1189980000, T517),
+    (-1175464800, T1),
+    (-1159135200, T517),
+    (-1143410400, T1),
+    (-1126476000, T517),
+    (-1111960800, T1),
+    (-1095631200, T517),
+    (-1080511200, T1),
+    (-1063576800, T517),
+    (-1049061600, T1),
+    (-1032127200, T517),
+    (-1017612000, T1),
+    (-1001282400, T517),
+    (-986162400, T1),
+    (-969228000, T517),
+    (-950479200, T1),
+    (-942012000, T517),
+    (-930963600, T14),
+    (-857257200, T13),
+    (-844556400, T14),
+    (-828226800, T13),
+    (-812502000, T14),
+    (-796777200, T13),
+    (-781052400, T14),
+    (-772066800, T517),
+    (-764805600, T1),
+    (-748476000, T517),
+    (-733356000, T1),
+    (-719445600, T517),
+    (-717030000, T522),
+    (-706748400, T517),
+    (-699487200, T1),
+    (-687996000, T517),
+    (-668037600, T1),
+    (-654732000, T517),
+    (-636588000, T1),
+    (-622072800, T517),
+    (-605743200, T1),
+    (-590623200, T517),
+    (-574293600, T1),
+    (-558568800, T517),
+    (-542239200, T1),
+    (-527119200, T517),
+    (-512604000, T1),
+    (-496274400, T517),
+    (-481154400, T1),
+    (-464220000, T517),
+    (-449704800, T1),
+    (-432165600, T517),
+    (-417650400, T1),
+    (-401320800, T517),
+    (-386200800, T1),
+    (-369266400, T517),
+    (-354751200, T1),
+    (-337816800, T517),
+    (-323301600, T1),
+    (-306972000, T517),
+    (-291852000, T1),
+    (-276732000, T517),
+    (-257983200, T1),
+    (-245282400, T517),
+    (-226533600, T1),
+    (-213228000, T517),
+    (-195084000, T1),
+    (-182383200, T517),
+    (-163634400, T1),
+    (-150933600, T517),
+    (-132184800, T1),
+    (-119484000, T517),
+    (-100735200, T1),
+    (-88034400, T517),
+    (-68680800, T1),
+    (-59004000, T517),
+    (57722400, T1),
+    (69818400, T517),
+    (89172000, T1),
+    (101268000, T517),
+    (120621600, T1),
+    (132717600, T517),
+    (152071200, T1),
+    (164167200, T517),
+    (183520800, T1),
+    (196221600, T517),
+    (214970400, T1),
+    (227671200, T517),
+    (246420000, T1),
+    (259120800, T517),
+    (278474400, T1),
+    (290570400, T517),
+    (309924000, T1),
+    (322020000, T517),
+    (341373600, T1),
+    (354675600, T517),
+    (372819600, T1),
+    (386125200, T517),
+    (404269200, T1),
+    (417574800, T517),
+    (435718800, T1),
+    (449024400, T517),
+    (467773200, T1),
+    (481078800, T517),
+    (499222800, T1),
+    (512528400, T517),
+    (530672400, T1),
+    (543978000, T517),
+    (562122000, T1),
+    (575427600, T517),
+    (593571600, T1),
+    (606877200, T517),
+    (625626000, T1),
+    (638326800, T517),
+    (657075600, T1),
+    (670381200, T517),
+    (688525200, T1),
+    (701830800, T517),
+    (719974800, T1),
+    (733280400, T517),
+    (751424400, T1),
+    (764730000, T517),
+    (782874000, T1),
+    (796179600, T517),
+    (814323600, T1),
+    (828234000, T517),
+    (846378000, T1),
+    (859683600, T517),
+    (877827600, T1),
+];
+
+static EUROPE_KALININGRAD: &[(i64, FixedTimespan<'static>)] = &[
+    (-2422056120, T13),
+    (-1693706400, T14),
+    (-1680483600, T13),
+    (-1663455600, T14),
+    (-1650150000, T13),
+    (-1632006000, T14),
+    (-1618700400, T13),
+    (-938905200, T14),
+    (-857257200, T13),
+    (-844556400, T14),
+    (-828226800, T13),
+    (-812502000, T14),
+    (-796777200, T13),
+    (-781052400, T14),
+    (-778730400, T30),
+    (-762663600, T29),
+    (-749095200, T512),
+    (354920400, T513),
+    (370728000, T512),
+    (386456400, T513),
+    (402264000, T512),
+    (417992400, T513),
+    (433800000, T512),
+    (449614800, T513),
+    (465346800, T512),
+    (481071600, T513),
+    (496796400, T512),
+    (512521200, T513),
+    (528246000, T512),
+    (543970800, T513),
+    (559695600, T512),
+    (575420400, T513),
+    (591145200, T512),
+    (606870000, T29),
+    (606873600, T30),
+    (622598400, T29),
+    (638323200, T30),
+    (654652800, T29),
+    (670377600, T30),
+    (686102400, T29),
+    (701827200, T30),
+    (717552000, T29),
+    (733276800, T30),
+    (749001600, T29),
+    (764726400, T30),
+    (780451200, T29),
+    (796176000, T30),
+    (811900800, T29),
+    (828230400, T30),
+    (846374400, T29),
+    (859680000, T30),
+    (877824000, T29),
+    (891129600, T30),
+    (909273600, T29),
+    (922579200, T30),
+    (941328000, T29),
+    (954028800, T30),
+    (972777600, T29),
+    (985478400, T30),
+    (1004227200, T29),
+    (1017532800, T30),
+    (1035676800, T29),
+    (1048982400, T30),
+    (1067126400, T29),
+    (1080432000, T30),
+    (1099180800, T29),
+    (1111881600, T30),
+    (1130630400, T29),
+    (1143331200, T30),
+    (1162080000, T29),
+    (1174780800, T30),
+    (1193529600, T29),
+    (1206835200, T30),
+    (1224979200, T29),
+    (1238284800, T30),
+    (1256428800, T29),
+    (1269734400, T30),
+    (1288483200, T29),
+    (1301184000, T310),
+    (1414278000, T29),
+];
+
+static EUROPE_KIROV: &[(i64, FixedTimespan<'static>)] = &[
+    (-1593820800, T310),
+    (-1247540400, T324),
+    (354916800, T325),
+    (370724400, T324),
+    (386452800, T325),
+    (402260400, T324),
+    (417988800, T325),
+    (433796400, T324),
+    (449611200, T325),
+    (465343200, T324),
+    (481068000, T325),
+    (496792800, T324),
+    (512517600, T325),
+    (528242400, T324),
+    (543967200, T325),
+    (559692000, T324),
+    (575416800, T325),
+    (591141600, T324),
+    (606866400, T512),
+    (606870000, T513),
+    (622594800, T512),
+    (638319600, T513),
+    (654649200, T512),
+    (670374000, T324),
+    (701820000, T512),
+    (701823600, T513),
+    (717548400, T512),
+    (733273200, T513),
+    (748998000, T512),
+    (764722800, T513),
+    (780447600, T512),
+    (796172400, T513),
+    (811897200, T512),
+    (828226800, T513),
+    (846370800, T512),
+    (859676400, T513),
+    (877820400, T512),
+    (891126000, T513),
+    (909270000, T512),
+    (922575600, T513),
+    (941324400, T512),
+    (954025200, T513),
+    (972774000, T512),
+    (985474800, T513),
+    (1004223600, T512),
+    (1017529200, T513),
+    (1035673200, T512),
+    (1048978800, T513),
+    (1067122800, T512),
+    (1080428400, T513),
+    (1099177200, T512),
+    (1111878000, T513),
+    (1130626800, T512),
+    (1143327600, T513),
+    (1162076400, T512),
+    (1174777200, T513),
+    (1193526000, T512),
+    (1206831600, T513),
+    (1224975600, T512),
+    (1238281200, T513),
+    (1256425200, T512),
+    (1269730800, T513),
+    (1288479600, T512),
+    (1301180400, T530),
+    (1414274400, T512),
+];
+
+static EUROPE_KYIV: &[(i64, FixedTimespan<'static>)] = &[
+    (-1441159324, T29),
+    (-1247536800, T512),
+    (-892522800, T14),
+    (-857257200, T13),
+    (-844556400, T14),
+    (-828226800, T13),
+    (-825382800, T512),
+    (354920400, T513),
+    (370728000, T512),
+    (386456400, T513),
+    (402264000, T512),
+    (417992400, T513),
+    (433800000, T512),
+    (449614800, T513),
+    (465346800, T512),
+    (481071600, T513),
+    (496796400, T512),
+    (512521200, T513),
+    (528246000, T512),
+    (543970800, T513),
+    (559695600, T512),
+    (575420400, T513),
+    (591145200, T512),
+    (606870000, T513),
+    (622594800, T512),
+    (638319600, T513),
+    (646783200, T30),
+    (686102400, T29),
+    (701827200, T30),
+    (717552000, T29),
+    (733276800, T30),
+    (749001600, T29),
+    (764726400, T30),
+    (780451200, T29),
+    (796176000, T30),
+    (811900800, T29),
+    (828230400, T30),
+    (846378000, T29),
+    (859683600, T30),
+    (877827600, T29),
+];
+
+static EUROPE_LISBON: &[(i64, FixedTimespan<'static>)] = &[
+    (-1830384000, T11),
+    (-1689555600, T12),
+    (-1677801600, T11),
+    (-1667433600, T12),
+    (-1647738000, T11),
+    (-1635897600, T12),
+    (-1616202000, T11),
+    (-1604361600, T12),
+    (-1584666000, T11),
+    (-1572739200, T12),
+    (-1553043600, T11),
+    (-1541203200, T12),
+    (-1521507600, T11),
+    (-1442451600, T12),
+    (-1427677200, T11),
+    (-1379293200, T12),
+    (-1364778000, T11),
+    (-1348448400, T12),
+    (-1333328400, T11),
+    (-1316394000, T12),
+    (-1301274000, T11),
+    (-1284339600, T12),
+    (-1269824400, T11),
+    (-1221440400, T12),
+    (-1206925200, T11),
+    (-1191200400, T12),
+    (-1175475600, T11),
+    (-1127696400, T12),
+    (-1111971600, T11),
+    (-1096851600, T12),
+    (-1080522000, T11),
+    (-1063587600, T12),
+    (-1049072400, T11),
+    (-1033347600, T12),
+    (-1017622800, T11),
+    (-1002502800, T12),
+    (-986173200, T11),
+    (-969238800, T12),
+    (-950490000, T11),
+    (-942022800, T12),
+    (-922496400, T11),
+    (-906944400, T12),
+    (-891133200, T11),
+    (-877309200, T12),
+    (-873684000, T532),
+    (-864007200, T12),
+    (-857955600, T11),
+    (-845859600, T12),
+    (-842839200, T532),
+    (-831348000, T12),
+    (-825901200, T11),
+    (-814410000, T12),
+    (-810784800, T532),
+    (-799898400, T12),
+    (-794451600, T11),
+    (-782960400, T12),
+    (-779335200, T532),
+    (-768448800, T12),
+    (-763002000, T11),
+    (-749091600, T12),
+    (-733366800, T11),
+    (-717631200, T12),
+    (-701906400, T11),
+    (-686181600, T12),
+    (-670456800, T11),
+    (-654732000, T12),
+    (-639007200, T11),
+    (-623282400, T12),
+    (-607557600, T11),
+    (-591832800, T12),
+    (-575503200, T11),
+    (-559778400, T12),
+    (-544053600, T11),
+    (-528328800, T12),
+    (-512604000, T11),
+    (-496879200, T12),
+    (-481154400, T11),
+    (-465429600, T12),
+    (-449704800, T11),
+    (-433980000, T12),
+    (-417650400, T11),
+    (-401925600, T12),
+    (-386200800, T11),
+    (-370476000, T12),
+    (-354751200, T11),
+    (-339026400, T12),
+    (-323301600, T11),
+    (-307576800, T12),
+    (-291852000, T11),
+    (-276127200, T12),
+    (-260402400, T11),
+    (-244677600, T12),
+    (-228348000, T11),
+    (-212623200, T12),
+    (-196898400, T11),
+    (-181173600, T12),
+    (-165448800, T11),
+    (-149724000, T12),
+    (-133999200, T11),
+    (-118274400, T12),
+    (212544000, T11),
+    (228268800, T12),
+    (243993600, T11),
+    (260326800, T12),
+    (276051600, T11),
+    (291776400, T12),
+    (307501200, T11),
+    (323830800, T12),
+    (338950800, T11),
+    (354672000, T12),
+    (370396800, T11),
+    (386121600, T12),
+    (401846400, T11),
+    (417571200, T12),
+    (433296000, T11),
+    (449020800, T12),
+    (465350400, T11),
+    (481075200, T12),
+    (496800000, T11),
+    (512528400, T12),
+    (528253200, T11),
+    (543978000, T12),
+    (559702800, T11),
+    (575427600, T12),
+    (591152400, T11),
+    (606877200, T12),
+    (622602000, T11),
+    (638326800, T12),
+    (654656400, T11),
+    (670381200, T12),
+    (686106000, T11),
+    (701830800, T12),
+    (733280400, T14),
+    (749005200, T13),
+    (764730000, T14),
+    (780454800, T13),
+    (796179600, T14),
+    (811904400, T13),
+    (846378000, T11),
+    (859683600, T12),
+    (877827600, T11),
+];
+
+static EUROPE_LJUBLJANA: &[(i64, FixedTimespan<'static>)] = &[
+    (-2713913884, T13),
+    (-905824800, T14),
+    (-857257200, T13),
+    (-844556400, T14),
+    (-828226800, T13),
+    (-812502000, T14),
+    (-796777200, T13),
+    (-781052400, T14),
+    (-766623600, T13),
+    (417574800, T14),
+    (433299600, T13),
+    (449024400, T14),
+    (465354000, T13),
+    (481078800, T14),
+    (496803600, T13),
+    (512528400, T14),
+    (528253200, T13),
+    (543978000, T14),
+    (559702800, T13),
+    (575427600, T14),
+    (591152400, T13),
+    (606877200, T14),
+    (622602000, T13),
+    (638326800, T14),
+    (654656400, T13),
+    (670381200, T14),
+    (686106000, T13),
+    (701830800, T14),
+    (717555600, T13),
+    (733280400, T14),
+    (749005200, T13),
+    (764730000, T14),
+    (780454800, T13),
+    (796179600, T14),
+    (811904400, T13),
+    (828234000, T14),
+    (846378000, T13),
+    (859683600, T14),
+    (877827600, T13),
+];
+
+static EUROPE_LONDON: &[(i64, FixedTimespan<'static>)] = &[
+    (-3852662325, T1),
+    (-1691964000, T517),
+    (-1680472800, T1),
+    (-1664143200, T517),
+    (-1650146400, T1),
+    (-1633903200, T517),
+    (-1617487200, T1),
+    (-1601848800, T517),
+    (-1586037600, T1),
+    (-1570399200, T517),
+    (-1552168800, T1),
+    (-1538344800, T517),
+    (-1522533600, T1),
+    (-1507500000, T517),
+    (-1490565600, T1),
+    (-1473631200, T517),
+    (-1460930400, T1),
+    (-1442786400, T517),
+    (-1428876000, T1),
+    (-1410732000, T517),
+    (-1396216800, T1),
+    (-1379282400, T517),
+    (-1364767200, T1),
+    (-1348437600, T517),
+    (-1333317600, T1),
+    (-1315778400, T517),
+    (-1301263200, T1),
+    (-1284328800, T517),
+    (-1269813600, T1),
+    (-1253484000, T517),
+    (-1238364000, T1),
+    (-1221429600, T517),
+    (-1206914400, T1),
+    (-1189980000, T517),
+    (-1175464800, T1),
+    (-1159135200, T517),
+    (-1143410400, T1),
+    (-1126476000, T517),
+    (-1111960800, T1),
+    (-1095631200, T517),
+    (-1080511200, T1),
+    (-1063576800, T517),
+    (-1049061600, T1),
+    (-1032127200, T517),
+    (-1017612000, T1),
+    (-1001282400, T517),
+    (-986162400, T1),
+    (-969228000, T517),
+    (-950479200, T1),
+    (-942012000, T517),
+    (-904518000, T522),
+    (-896050800, T517),
+    (-875487600, T522),
+    (-864601200, T517),
+    (-844038000, T522),
+    (-832546800, T517),
+    (-812588400, T522),
+    (-798073200, T517),
+    (-781052400, T522),
+    (-772066800, T517),
+    (-764805600, T1),
+    (-748476000, T517),
+    (-733356000, T1),
+    (-719445600, T517),
+    (-717030000, T522),
+    (-706748400, T517),
+    (-699487200, T1),
+    (-687996000, T517),
+    (-668037600, T1),
+    (-654732000, T517),
+    (-636588000, T1),
+    (-622072800, T517),
+    (-605743200, T1),
+    (-590623200, T517),
+    (-574293600, T1),
+    (-558568800, T517),
+    (-542239200, T1),
+    (-527119200, T517),
+    (-512604000, T1),
+    (-496274400, T517),
+    (-481154400, T1),
+    (-464220000, T517),
+    (-449704800, T1),
+    (-432165600, T517),
+    (-417650400, T1),
+    (-401320800, T517),
+    (-386200800, T1),
+    (-369266400, T517),
+    (-354751200, T1),
+    (-337816800, T517),
+    (-323301600, T1),
+    (-306972000, T517),
+    (-291852000, T1),
+    (-276732000, T517),
+    (-257983200, T1),
+    (-245282400, T517),
+    (-226533600, T1),
+    (-213228000, T517),
+    (-195084000, T1),
+    (-182383200, T517),
+    (-163634400, T1),
+    (-150933600, T517),
+    (-132184800, T1),
+    (-119484000, T517),
+    (-100735200, T1),
+    (-88034400, T517),
+    (-68680800, T1),
+    (-59004000, T517),
+    (57722400, T1),
+    (69818400, T517),
+    (89172000, T1),
+    (101268000, T517),
+    (120621600, T1),
+    (132717600, T517),
+    (152071200, T1),
+    (164167200, T517),
+    (183520800, T1),
+    (196221600, T517),
+    (214970400, T1),
+    (227671200, T517),
+    (246420000, T1),
+    (259120800, T517),
+    (278474400, T1),
+    (290570400, T517),
+    (309924000, T1),
+    (322020000, T517),
+    (341373600, T1),
+    (354675600, T517),
+    (372819600, T1),
+    (386125200, T517),
+    (404269200, T1),
+    (417574800, T517),
+    (435718800, T1),
+    (449024400, T517),
+    (467773200, T1),
+    (481078800, T517),
+    (499222800, T1),
+    (512528400, T517),
+    (530672400, T1),
+    (543978000, T517),
+    (562122000, T1),
+    (575427600, T517),
+    (593571600, T1),
+    (606877200, T517),
+    (625626000, T1),
+    (638326800, T517),
+    (657075600, T1),
+    (670381200, T517),
+    (688525200, T1),
+    (701830800, T517),
+    (719974800, T1),
+    (733280400, T517),
+    (751424400, T1),
+    (764730000, T517),
+    (782874000, T1),
+    (796179600, T517),
+    (814323600, T1),
+    (828234000, T517),
+    (846378000, T1),
+    (859683600, T517),
+    (877827600, T1),
+];
+
+static EUROPE_LUXEMBOURG: &[(i64, FixedTimespan<'static>)] = &[
+    (-2069713476, T13),
+    (-1692496800, T14),
+    (-1680483600, T13),
+    (-1662343200, T14),
+    (-1650157200, T13),
+    (-1632006000, T14),
+    (-1618700400, T13),
+    (-1612659600, T11),
+    (-1604278800, T12),
+    (-1585519200, T11),
+    (-1574038800, T12),
+    (-1552258800, T11),
+    (-1539997200, T12),
+    (-1520550000, T11),
+    (-1507510800, T12),
+    (-1490572800, T11),
+    (-1473642000, T12),
+    (-1459119600, T11),
+    (-1444006800, T12),
+    (-1427673600, T11),
+    (-1411866000, T12),
+    (-1396224000, T11),
+    (-1379293200, T12),
+    (-1364774400, T11),
+    (-1348448400, T12),
+    (-1333324800, T11),
+    (-1316394000, T12),
+    (-1301270400, T11),
+    (-1284339600, T12),
+    (-1269813600, T11),
+    (-1253484000, T12),
+    (-1238364000, T11),
+    (-1221429600, T12),
+    (-1206914400, T11),
+    (-1191189600, T12),
+    (-1175464800, T11),
+    (-1160344800, T12),
+    (-1143410400, T11),
+    (-1127685600, T12),
+    (-1111960800, T11),
+    (-1096840800, T12),
+    (-1080511200, T11),
+    (-1063576800, T12),
+    (-1049061600, T11),
+    (-1033336800, T12),
+    (-1017612000, T11),
+    (-1002492000, T12),
+    (-986162400, T11),
+    (-969228000, T12),
+    (-950479200, T11),
+    (-942012000, T12),
+    (-935186400, T536),
+    (-857257200, T537),
+    (-844556400, T536),
+    (-828226800, T537),
+    (-812502000, T536),
+    (-797986800, T13),
+    (-781052400, T14),
+    (-766623600, T13),
+    (-745455600, T14),
+    (-733273200, T13),
+    (228877200, T14),
+    (243997200, T13),
+    (260326800, T14),
+    (276051600, T13),
+    (291776400, T14),
+    (307501200, T13),
+    (323830800, T14),
+    (338950800, T13),
+    (354675600, T14),
+    (370400400, T13),
+    (386125200, T14),
+    (401850000, T13),
+    (417574800, T14),
+    (433299600, T13),
+    (449024400, T14),
+    (465354000, T13),
+    (481078800, T14),
+    (496803600, T13),
+    (512528400, T14),
+    (528253200, T13),
+    (543978000, T14),
+    (559702800, T13),
+    (575427600, T14),
+    (591152400, T13),
+    (606877200, T14),
+    (622602000, T13),
+    (638326800, T14),
+    (654656400, T13),
+    (670381200, T14),
+    (686106000, T13),
+    (701830800, T14),
+    (717555600, T13),
+    (733280400, T14),
+    (749005200, T13),
+    (764730000, T14),
+    (780454800, T13),
+    (796179600, T14),
+    (811904400, T13),
+    (828234000, T14),
+    (846378000, T13),
+    (859683600, T14),
+    (877827600, T13),
+];
+
+static EUROPE_MADRID: &[(i64, FixedTimespan<'static>)] = &[
+    (-2177452800, T11),
+    (-1631926800, T12),
+    (-1616889600, T11),
+    (-1601168400, T12),
+    (-1585353600, T11),
+    (-1442451600, T12),
+    (-1427673600, T11),
+    (-1379293200, T12),
+    (-1364774400, T11),
+    (-1348448400, T12),
+    (-1333324800, T11),
+    (-1316390400, T12),
+    (-1301270400, T11),
+    (-1284339600, T12),
+    (-1269820800, T11),
+    (-1026954000, T12),
+    (-1017619200, T11),
+    (-1001898000, T12),
+    (-999482400, T532),
+    (-986090400, T12),
+    (-954115200, T11),
+    (-940208400, T13),
+    (-873079200, T14),
+    (-862621200, T13),
+    (-842839200, T14),
+    (-828320400, T13),
+    (-811389600, T14),
+    (-796870800, T13),
+    (-779940000, T14),
+    (-765421200, T13),
+    (-748490400, T14),
+    (-733971600, T13),
+    (-652327200, T14),
+    (-639018000, T13),
+    (135122400, T14),
+    (150246000, T13),
+    (166572000, T14),
+    (181695600, T13),
+    (196812000, T14),
+    (212540400, T13),
+    (228866400, T14),
+    (243990000, T13),
+    (260326800, T14),
+    (276051600, T13),
+    (291776400, T14),
+    (307501200, T13),
+    (323830800, T14),
+    (338950800, T13),
+    (354675600, T14),
+    (370400400, T13),
+    (386125200, T14),
+    (401850000, T13),
+    (417574800, T14),
+    (433299600, T13),
+    (449024400, T14),
+    (465354000, T13),
+    (481078800, T14),
+    (496803600, T13),
+    (512528400, T14),
+    (528253200, T13),
+    (543978000, T14),
+    (559702800, T13),
+    (575427600, T14),
+    (591152400, T13),
+    (606877200, T14),
+    (622602000, T13),
+    (638326800, T14),
+    (654656400, T13),
+    (670381200, T14),
+    (686106000, T13),
+    (701830800, T14),
+    (717555600, T13),
+    (733280400, T14),
+    (749005200, T13),
+    (764730000, T14),
+    (780454800, T13),
+    (796179600, T14),
+    (811904400, T13),
+    (828234000, T14),
+    (846378000, T13),
+    (859683600, T14),
+    (877827600, T13),
+];
+
+static EUROPE_MALTA: &[(i64, FixedTimespan<'static>)] = &[
+    (-2403478684, T13),
+    (-1690765200, T14),
+    (-1680487200, T13),
+    (-1664758800, T14),
+    (-1648951200, T13),
+    (-1635123600, T14),
+    (-1616896800, T13),
+    (-1604278800, T14),
+    (-1585533600, T13),
+    (-1571014800, T14),
+    (-1555293600, T13),
+    (-932432400, T14),
+    (-857257200, T13),
+    (-844556400, T14),
+    (-828226800, T13),
+    (-812588400, T14),
+    (-798073200, T13),
+    (-781052400, T14),
+    (-766717200, T13),
+    (-750898800, T14),
+    (-733359600, T13),
+    (-719456400, T14),
+    (-701917200, T13),
+    (-689209200, T14),
+    (-670460400, T13),
+    (-114051600, T14),
+    (-103168800, T13),
+    (-81997200, T14),
+    (-71715600, T13),
+    (-50547600, T14),
+    (-40266000, T13),
+    (-18493200, T14),
+    (-8211600, T13),
+    (12956400, T14),
+    (23238000, T13),
+    (43801200, T14),
+    (54687600, T13),
+    (75855600, T14),
+    (86742000, T13),
+    (102380400, T14),
+    (118105200, T13),
+    (135730800, T14),
+    (148518000, T13),
+    (167187600, T14),
+    (180489600, T13),
+    (198637200, T14),
+    (211939200, T13),
+    (230086800, T14),
+    (243388800, T13),
+    (261536400, T14),
+    (274838400, T13),
+    (292986000, T14),
+    (306288000, T13),
+    (323312400, T14),
+    (338342400, T13),
+    (354675600, T14),
+    (370400400, T13),
+    (386125200, T14),
+    (401850000, T13),
+    (417574800, T14),
+    (433299600, T13),
+    (449024400, T14),
+    (465354000, T13),
+    (481078800, T14),
+    (496803600, T13),
+    (512528400, T14),
+    (528253200, T13),
+    (543978000, T14),
+    (559702800, T13),
+    (575427600, T14),
+    (591152400, T13),
+    (606877200, T14),
+    (622602000, T13),
+    (638326800, T14),
+    (654656400, T13),
+    (670381200, T14),
+    (686106000, T13),
+    (701830800, T14),
+    (717555600, T13),
+    (733280400, T14),
+    (749005200, T13),
+    (764730000, T14),
+    (780454800, T13),
+    (796179600, T14),
+    (811904400, T13),
+    (828234000, T14),
+    (846378000, T13),
+    (859683600, T14),
+    (877827600, T13),
+];
+
+static EUROPE_MINSK: &[(i64, FixedTimespan<'static>)] = &[
+    (-2840147416, T540),
+    (-1441158600, T29),
+    (-1247536800, T512),
+    (-899780400, T14),
+    (-857257200, T13),
+    (-844556400, T14),
+    (-828226800, T13),
+    (-812502000, T14),
+    (-804650400, T512),
+    (354920400, T513),
+    (370728000, T512),
+    (386456400, T513),
+    (402264000, T512),
+    (417992400, T513),
+    (433800000, T512),
+    (449614800, T513),
+    (465346800, T512),
+    (481071600, T513),
+    (496796400, T512),
+    (512521200, T513),
+    (528246000, T512),
+    (543970800, T513),
+    (559695600, T512),
+    (575420400, T513),
+    (591145200, T512),
+    (606870000, T513),
+    (622594800, T512),
+    (670374000, T29),
+    (670377600, T30),
+    (686102400, T29),
+    (701827200, T30),
+    (717552000, T29),
+    (733276800, T30),
+    (749001600, T29),
+    (764726400, T30),
+    (780451200, T29),
+    (796176000, T30),
+    (811900800, T29),
+    (828230400, T30),
+    (846374400, T29),
+    (859680000, T30),
+    (877824000, T29),
+    (891129600, T30),
+    (909273600, T29),
+    (922579200, T30),
+    (941328000, T29),
+    (954028800, T30),
+    (972777600, T29),
+    (985478400, T30),
+    (1004227200, T29),
+    (1017532800, T30),
+    (1035676800, T29),
+    (1048982400, T30),
+    (1067126400, T29),
+    (1080432000, T30),
+    (1099180800, T29),
+    (1111881600, T30),
+    (1130630400, T29),
+    (1143331200, T30),
+    (1162080000, T29),
+    (1174780800, T30),
+    (1193529600, T29),
+    (1206835200, T30),
+    (1224979200, T29),
+    (1238284800, T30),
+    (1256428800, T29),
+    (1269734400, T30),
+    (1288483200, T29),
+    (1301184000, T310),
+];
+
+static EUROPE_MONACO: &[(i64, FixedTimespan<'static>)] = &[
+    (-2448318572, T10),
+    (-1854403761, T11),
+    (-1689814800, T12),
+    (-1680397200, T11),
+    (-1665363600, T12),
+    (-1648342800, T11),
+    (-1635123600, T12),
+    (-1616893200, T11),
+    (-1604278800, T12),
+    (-1585443600, T11),
+    (-1574038800, T12),
+    (-1552266000, T11),
+    (-1539997200, T12),
+    (-1520557200, T11),
+    (-1507510800, T12),
+    (-1490576400, T11),
+    (-1470618000, T12),
+    (-1459126800, T11),
+    (-1444006800, T12),
+    (-1427677200, T11),
+    (-1411952400, T12),
+    (-1396227600, T11),
+    (-1379293200, T12),
+    (-1364778000, T11),
+    (-1348448400, T12),
+    (-1333328400, T11),
+    (-1316394000, T12),
+    (-1301274000, T11),
+    (-1284339600, T12),
+    (-1269824400, T11),
+    (-1253494800, T12),
+    (-1238374800, T11),
+    (-1221440400, T12),
+    (-1206925200, T11),
+    (-1191200400, T12),
+    (-1175475600, T11),
+    (-1160355600, T12),
+    (-1143421200, T11),
+    (-1127696400, T12),
+    (-1111971600, T11),
+    (-1096851600, T12),
+    (-1080522000, T11),
+    (-1063587600, T12),
+    (-1049072400, T11),
+    (-1033347600, T12),
+    (-1017622800, T11),
+    (-1002502800, T12),
+    (-986173200, T11),
+    (-969238800, T12),
+    (-950490000, T11),
+    (-942012000, T12),
+    (-904438800, T532),
+    (-891136800, T12),
+    (-877827600, T532),
+    (-857257200, T12),
+    (-844556400, T532),
+    (-828226800, T12),
+    (-812502000, T532),
+    (-796266000, T12),
+    (-781052400, T532),
+    (-766623600, T13),
+    (196819200, T14),
+    (212540400, T13),
+    (228877200, T14),
+    (243997200, T13),
+    (260326800, T14),
+    (276051600, T13),
+    (291776400, T14),
+    (307501200, T13),
+    (323830800, T14),
+    (338950800, T13),
+    (354675600, T14),
+    (370400400, T13),
+    (386125200, T14),
+    (401850000, T13),
+    (417574800, T14),
+    (433299600, T13),
+    (449024400, T14),
+    (465354000, T13),
+    (481078800, T14),
+    (496803600, T13),
+    (512528400, T14),
+    (528253200, T13),
+    (543978000, T14),
+    (559702800, T13),
+    (575427600, T14),
+    (591152400, T13),
+    (606877200, T14),
+    (622602000, T13),
+    (638326800, T14),
+    (654656400, T13),
+    (670381200, T14),
+    (686106000, T13),
+    (701830800, T14),
+    (717555600, T13),
+    (733280400, T14),
+    (749005200, T13),
+    (764730000, T14),
+    (780454800, T13),
+    (796179600, T14),
+    (811904400, T13),
+    (828234000, T14),
+    (846378000, T13),
+    (859683600, T14),
+    (877827600, T13),
+];
+
+static EUROPE_MOSCOW: &[(i64, FixedTimespan<'static>)] = &[
+    (-1688265017, T543),
+    (-1656819079, T544),
+    (-1641353479, T543),
+    (-1627965079, T545),
+    (-1618716679, T544),
+    (-1596429079, T545),
+    (-1593820800, T513),
+    (-1589860800, T512),
+    (-1542427200, T513),
+    (-1539493200, T325),
+    (-1525323600, T513),
+    (-1522728000, T512),
+    (-1491188400, T29),
+    (-1247536800, T512),
+    (354920400, T513),
+    (370728000, T512),
+    (386456400, T513),
+    (402264000, T512),
+    (417992400, T513),
+    (433800000, T512),
+    (449614800, T513),
+    (465346800, T512),
+    (481071600, T513),
+    (496796400, T512),
+    (512521200, T513),
+    (528246000, T512),
+    (543970800, T513),
+    (559695600, T512),
+    (575420400, T513),
+    (591145200, T512),
+    (606870000, T513),
+    (622594800, T512),
+    (638319600, T513),
+    (654649200, T512),
+    (670374000, T29),
+    (670377600, T30),
+    (686102400, T29),
+    (695779200, T512),
+    (701823600, T513),
+    (717548400, T512),
+    (733273200, T513),
+    (748998000, T512),
+    (764722800, T513),
+    (780447600, T512),
+    (796172400, T513),
+    (811897200, T512),
+    (828226800, T513),
+    (846370800, T512),
+    (859676400, T513),
+    (877820400, T512),
+    (891126000, T513),
+    (909270000, T512),
+    (922575600, T513),
+    (941324400, T512),
+    (954025200, T513),
+    (972774000, T512),
+    (985474800, T513),
+    (1004223600, T512),
+    (1017529200, T513),
+    (1035673200, T512),
+    (1048978800, T513),
+    (1067122800, T512),
+    (1080428400, T513),
+    (1099177200, T512),
+    (1111878000, T513),
+    (1130626800, T512),
+    (1143327600, T513),
+    (1162076400, T512),
+    (1174777200, T513),
+    (1193526000, T512),
+    (1206831600, T513),
+    (1224975600, T512),
+    (1238281200, T513),
+    (1256425200, T512),
+    (1269730800, T513),
+    (1288479600, T512),
+    (1301180400, T530),
+    (1414274400, T512),
+];
+
+static EUROPE_OSLO: &[(i64, FixedTimespan<'static>)] = &[
+    (-2366757780, T13),
+    (-1691884800, T14),
+    (-1680573600, T13),
+    (-927511200, T14),
+    (-857257200, T13),
+    (-844556400, T14),
+    (-828226800, T13),
+    (-812502000, T14),
+    (-796777200, T13),
+    (-781052400, T14),
+    (-765327600, T13),
+    (-340844400, T14),
+    (-324514800, T13),
+    (-308790000, T14),
+    (-293065200, T13),
+    (-277340400, T14),
+    (-261615600, T13),
+    (-245890800, T14),
+    (-230166000, T13),
+    (-214441200, T14),
+    (-198716400, T13),
+    (-182991600, T14),
+    (-166662000, T13),
+    (-147913200, T14),
+    (-135212400, T13),
+    (323830800, T14),
+    (338950800, T13),
+    (354675600, T14),
+    (370400400, T13),
+    (386125200, T14),
+    (401850000, T13),
+    (417574800, T14),
+    (433299600, T13),
+    (449024400, T14),
+    (465354000, T13),
+    (481078800, T14),
+    (496803600, T13),
+    (512528400, T14),
+    (528253200, T13),
+    (543978000, T14),
+    (559702800, T13),
+    (575427600, T14),
+    (591152400, T13),
+    (606877200, T14),
+    (622602000, T13),
+    (638326800, T14),
+    (654656400, T13),
+    (670381200, T14),
+    (686106000, T13),
+    (701830800, T14),
+    (717555600, T13),
+    (733280400, T14),
+    (749005200, T13),
+    (764730000, T14),
+    (780454800, T13),
+    (796179600, T14),
+    (811904400, T13),
+    (828234000, T14),
+    (846378000, T13),
+    (859683600, T14),
+    (877827600, T13),
+];
+
+static EUROPE_PARIS: &[(i64, FixedTimespan<'static>)] = &[
+    (-1855958961, T11),
+    (-1689814800, T12),
+    (-1680397200, T11),
+    (-1665363600, T12),
+    (-1648342800, T11),
+    (-1635123600, T12),
+    (-1616893200, T11),
+    (-1604278800, T12),
+    (-1585443600, T11),
+    (-1574038800, T12),
+    (-1552266000, T11),
+    (-1539997200, T12),
+    (-1520557200, T11),
+    (-1507510800, T12),
+    (-1490576400, T11),
+    (-1470618000, T12),
+    (-1459126800, T11),
+    (-1444006800, T12),
+    (-1427677200, T11),
+    (-1411952400, T12),
+    (-1396227600, T11),
+    (-1379293200, T12),
+    (-1364778000, T11),
+    (-1348448400, T12),
+    (-1333328400, T11),
+    (-1316394000, T12),
+    (-1301274000, T11),
+    (-1284339600, T12),
+    (-1269824400, T11),
+    (-1253494800, T12),
+    (-1238374800, T11),
+    (-1221440400, T12),
+    (-1206925200, T11),
+    (-1191200400, T12),
+    (-1175475600, T11),
+    (-1160355600, T12),
+    (-1143421200, T11),
+    (-1127696400, T12),
+    (-1111971600, T11),
+    (-1096851600, T12),
+    (-1080522000, T11),
+    (-1063587600, T12),
+    (-1049072400, T11),
+    (-1033347600, T12),
+    (-1017622800, T11),
+    (-1002502800, T12),
+    (-986173200, T11),
+    (-969238800, T12),
+    (-950490000, T11),
+    (-942012000, T12),
+    (-932436000, T14),
+    (-857257200, T13),
+    (-844556400, T14),
+    (-828226800, T13),
+    (-812502000, T14),
+    (-796266000, T12),
+    (-781052400, T532),
+    (-766623600, T13),
+    (196819200, T14),
+    (212540400, T13),
+    (228877200, T14),
+    (243997200, T13),
+    (260326800, T14),
+    (276051600, T13),
+    (291776400, T14),
+    (307501200, T13),
+    (323830800, T14),
+    (338950800, T13),
+    (354675600, T14),
+    (370400400, T13),
+    (386125200, T14),
+    (401850000, T13),
+    (417574800, T14),
+    (433299600, T13),
+    (449024400, T14),
+    (465354000, T13),
+    (481078800, T14),
+    (496803600, T13),
+    (512528400, T14),
+    (528253200, T13),
+    (543978000, T14),
+    (559702800, T13),
+    (575427600, T14),
+    (591152400, T13),
+    (606877200, T14),
+    (622602000, T13),
+    (638326800, T14),
+    (654656400, T13),
+    (670381200, T14),
+    (686106000, T13),
+    (701830800, T14),
+    (717555600, T13),
+    (733280400, T14),
+    (749005200, T13),
+    (764730000, T14),
+    (780454800, T13),
+    (796179600, T14),
+    (811904400, T13),
+    (828234000, T14),
+    (846378000, T13),
+    (859683600, T14),
+    (877827600, T13),
+];
+
+static EUROPE_PRAGUE: &[(i64, FixedTimespan<'static>)] = &[
+    (-2469401864, T13),
+    (-1693706400, T14),
+    (-1680483600, T13),
+    (-1663455600, T14),
+    (-1650150000, T13),
+    (-1632006000, T14),
+    (-1618700400, T13),
+    (-938905200, T14),
+    (-857257200, T13),
+    (-844556400, T14),
+    (-828226800, T13),
+    (-812502000, T14),
+    (-796777200, T13),
+    (-781052400, T14),
+    (-765327600, T13),
+    (-746578800, T14),
+    (-733359600, T13),
+    (-728517600, T519),
+    (-721260000, T13),
+    (-716425200, T14),
+    (-701910000, T13),
+    (-684975600, T14),
+    (-670460400, T13),
+    (-654217200, T14),
+    (-639010800, T13),
+    (291776400, T14),
+    (307501200, T13),
+    (323830800, T14),
+    (338950800, T13),
+    (354675600, T14),
+    (370400400, T13),
+    (386125200, T14),
+    (401850000, T13),
+    (417574800, T14),
+    (433299600, T13),
+    (449024400, T14),
+    (465354000, T13),
+    (481078800, T14),
+    (496803600, T13),
+    (512528400, T14),
+    (528253200, T13),
+    (543978000, T14),
+    (559702800, T13),
+    (575427600, T14),
+    (591152400, T13),
+    (606877200, T14),
+    (622602000, T13),
+    (638326800, T14),
+    (654656400, T13),
+    (670381200, T14),
+    (686106000, T13),
+    (701830800, T14),
+    (717555600, T13),
+    (733280400, T14),
+    (749005200, T13),
+    (764730000, T14),
+    (780454800, T13),
+    (796179600, T14),
+    (811904400, T13),
+    (828234000, T14),
+    (846378000, T13),
+    (859683600, T14),
+    (877827600, T13),
+];
+
+static EUROPE_RIGA: &[(i64, FixedTimespan<'static>)] = &[
+    (-1632008194, T550),
+    (-1618702594, T551),
+    (-1601681794, T550),
+    (-1597275394, T551),
+    (-1377308194, T29),
+    (-928029600, T512),
+    (-899521200, T14),
+    (-857257200, T13),
+    (-844556400, T14),
+    (-828226800, T13),
+    (-812502000, T14),
+    (-796777200, T13),
+    (-795834000, T512),
+    (354920400, T513),
+    (370728000, T512),
+    (386456400, T513),
+    (402264000, T512),
+    (417992400, T513),
+    (433800000, T512),
+    (449614800, T513),
+    (465346800, T512),
+    (481071600, T513),
+    (496796400, T512),
+    (512521200, T513),
+    (528246000, T512),
+    (543970800, T513),
+    (559695600, T512),
+    (575420400, T513),
+    (591145200, T512),
+    (622598400, T29),
+    (638323200, T30),
+    (654652800, T29),
+    (670377600, T30),
+    (686102400, T29),
+    (701827200, T30),
+    (717552000, T29),
+    (733276800, T30),
+    (749001600, T29),
+    (764726400, T30),
+    (780451200, T29),
+    (796176000, T30),
+    (811900800, T29),
+    (828230400, T30),
+    (843955200, T29),
+    (859683600, T30),
+    (877827600, T29),
+    (891133200, T30),
+    (909277200, T29),
+    (922582800, T30),
+    (941331600, T29),
+    (985482000, T30),
+    (1004230800, T29),
+    (1017536400, T30),
+    (1035680400, T29),
+];
+
+static EUROPE_ROME: &[(i64, FixedTimespan<'static>)] = &[
+    (-2403565200, T13),
+    (-1690765200, T14),
+    (-1680487200, T13),
+    (-1664758800, T14),
+    (-1648951200, T13),
+    (-1635123600, T14),
+    (-1616896800, T13),
+    (-1604278800, T14),
+    (-1585533600, T13),
+    (-1571014800, T14),
+    (-1555293600, T13),
+    (-932432400, T14),
+    (-857257200, T13),
+    (-844556400, T14),
+    (-828226800, T13),
+    (-812502000, T14),
+    (-798073200, T13),
+    (-781052400, T14),
+    (-766717200, T13),
+    (-750898800, T14),
+    (-733359600, T13),
+    (-719456400, T14),
+    (-701917200, T13),
+    (-689209200, T14),
+    (-670460400, T13),
+    (-114051600, T14),
+    (-103168800, T13),
+    (-81997200, T14),
+    (-71715600, T13),
+    (-50547600, T14),
+    (-40266000, T13),
+    (-18493200, T14),
+    (-8211600, T13),
+    (12956400, T14),
+    (23238000, T13),
+    (43801200, T14),
+    (54687600, T13),
+    (75855600, T14),
+    (86742000, T13),
+    (107910000, T14),
+    (118191600, T13),
+    (138754800, T14),
+    (149641200, T13),
+    (170809200, T14),
+    (181090800, T13),
+    (202258800, T14),
+    (212540400, T13),
+    (233103600, T14),
+    (243990000, T13),
+    (265158000, T14),
+    (276044400, T13),
+    (296607600, T14),
+    (307494000, T13),
+    (323830800, T14),
+    (338950800, T13),
+    (354675600, T14),
+    (370400400, T13),
+    (386125200, T14),
+    (401850000, T13),
+    (417574800, T14),
+    (433299600, T13),
+    (449024400, T14),
+    (465354000, T13),
+    (481078800, T14),
+    (496803600, T13),
+    (512528400, T14),
+    (528253200, T13),
+    (543978000, T14),
+    (559702800, T13),
+    (575427600, T14),
+    (591152400, T13),
+    (606877200, T14),
+    (622602000, T13),
+    (638326800, T14),
+    (654656400, T13),
+    (670381200, T14),
+    (686106000, T13),
+    (701830800, T14),
+    (717555600, T13),
+    (733280400, T14),
+    (749005200, T13),
+    (764730000, T14),
+    (780454800, T13),
+    (796179600, T14),
+    (811904400, T13),
+    (828234000, T14),
+    (846378000, T13),
+    (859683600, T14),
+    (877827600, T13),
+];
+
+static EUROPE_SAMARA: &[(i64, FixedTimespan<'static>)] = &[
+    (-1593820800, T310),
+    (-1247540400, T324),
+    (354916800, T325),
+    (370724400, T324),
+    (386452800, T325),
+    (402260400, T324),
+    (417988800, T325),
+    (433796400, T324),
+    (449611200, T325),
+    (465343200, T324),
+    (481068000, T325),
+    (496792800, T324),
+    (512517600, T325),
+    (528242400, T324),
+    (543967200, T325),
+    (559692000, T324),
+    (575416800, T325),
+    (591141600, T324),
+    (606866400, T310),
+    (606870000, T331),
+    (622594800, T310),
+    (638319600, T331),
+    (654649200, T310),
+    (670374000, T493),
+    (670377600, T554),
+    (687916800, T324),
+    (701820000, T325),
+    (717544800, T324),
+    (733269600, T325),
+    (748994400, T324),
+    (764719200, T325),
+    (780444000, T324),
+    (796168800, T325),
+    (811893600, T324),
+    (828223200, T325),
+    (846367200, T324),
+    (859672800, T325),
+    (877816800, T324),
+    (891122400, T325),
+    (909266400, T324),
+    (922572000, T325),
+    (941320800, T324),
+    (954021600, T325),
+    (972770400, T324),
+    (985471200, T325),
+    (1004220000, T324),
+    (1017525600, T325),
+    (1035669600, T324),
+    (1048975200, T325),
+    (1067119200, T324),
+    (1080424800, T325),
+    (1099173600, T324),
+    (1111874400, T325),
+    (1130623200, T324),
+    (1143324000, T325),
+    (1162072800, T324),
+    (1174773600, T325),
+    (1193522400, T324),
+    (1206828000, T325),
+    (1224972000, T324),
+    (1238277600, T325),
+    (1256421600, T324),
+    (1269727200, T310),
+    (1269730800, T331),
+    (1288479600, T310),
+    (1301180400, T324),
+];
+
+static EUROPE_SARAJEVO: &[(i64, FixedTimespan<'static>)] = &[
+    (-2713914820, T13),
+    (-905824800, T14),
+    (-857257200, T13),
+    (-844556400, T14),
+    (-828226800, T13),
+    (-812502000, T14),
+    (-796777200, T13),
+    (-781052400, T14),
+    (-766623600, T13),
+    (417574800, T14),
+    (433299600, T13),
+    (449024400, T14),
+    (465354000, T13),
+    (481078800, T14),
+    (496803600, T13),
+    (512528400, T14),
+    (528253200, T13),
+    (543978000, T14),
+    (559702800, T13),
+    (575427600, T14),
+    (591152400, T13),
+    (606877200, T14),
+    (622602000, T13),
+    (638326800, T14),
+    (654656400, T13),
+    (670381200, T14),
+    (686106000, T13),
+    (701830800, T14),
+    (717555600, T13),
+    (733280400, T14),
+    (749005200, T13),
+    (764730000, T14),
+    (780454800, T13),
+    (796179600, T14),
+    (811904400, T13),
+    (828234000, T14),
+    (846378000, T13),
+    (859683600, T14),
+    (877827600, T13),
+];
+
+static EUROPE_SARATOV: &[(i64, FixedTimespan<'static>)] = &[
+    (-1593820800, T310),
+    (-1247540400, T324),
+    (354916800, T325),
+    (370724400, T324),
+    (386452800, T325),
+    (402260400, T324),
+    (417988800, T325),
+    (433796400, T324),
+    (449611200, T325),
+    (465343200, T324),
+    (481068000, T325),
+    (496792800, T324),
+    (512517600, T325),
+    (528242400, T324),
+    (543967200, T325),
+    (559692000, T324),
+    (575416800, T310),
+    (575420400, T331),
+    (591145200, T310),
+    (606870000, T331),
+    (622594800, T310),
+    (638319600, T331),
+    (654649200, T310),
+    (670374000, T324),
+    (701820000, T310),
+    (701823600, T331),
+    (717548400, T310),
+    (733273200, T331),
+    (748998000, T310),
+    (764722800, T331),
+    (780447600, T310),
+    (796172400, T331),
+    (811897200, T310),
+    (828226800, T331),
+    (846370800, T310),
+    (859676400, T331),
+    (877820400, T310),
+    (891126000, T331),
+    (909270000, T310),
+    (922575600, T331),
+    (941324400, T310),
+    (954025200, T331),
+    (972774000, T310),
+    (985474800, T331),
+    (1004223600, T310),
+    (1017529200, T331),
+    (1035673200, T310),
+    (1048978800, T331),
+    (1067122800, T310),
+    (1080428400, T331),
+    (1099177200, T310),
+    (1111878000, T331),
+    (1130626800, T310),
+    (1143327600, T331),
+    (1162076400, T310),
+    (1174777200, T331),
+    (1193526000, T310),
+    (1206831600, T331),
+    (1224975600, T310),
+    (1238281200, T331),
+    (1256425200, T310),
+    (1269730800, T331),
+    (1288479600, T310),
+    (1301180400, T324),
+    (1414274400, T310),
+    (1480806000, T324),
+];
+
+static EUROPE_SIMFEROPOL: &[(i64, FixedTimespan<'static>)] = &[
+    (-2840148984, T558),
+    (-1441160160, T29),
+    (-1247536800, T512),
+    (-888894000, T14),
+    (-857257200, T13),
+    (-844556400, T14),
+    (-828226800, T13),
+    (-812502000, T14),
+    (-811648800, T512),
+    (354920400, T513),
+    (370728000, T512),
+    (386456400, T513),
+    (402264000, T512),
+    (417992400, T513),
+    (433800000, T512),
+    (449614800, T513),
+    (465346800, T512),
+    (481071600, T513),
+    (496796400, T512),
+    (512521200, T513),
+    (528246000, T512),
+    (543970800, T513),
+    (559695600, T512),
+    (575420400, T513),
+    (591145200, T512),
+    (606870000, T513),
+    (622594800, T512),
+    (646786800, T29),
+    (701827200, T30),
+    (717552000, T29),
+    (733276800, T30),
+    (749001600, T29),
+    (764726400, T30),
+    (767739600, T513),
+    (780447600, T512),
+    (796172400, T513),
+    (811897200, T512),
+    (828219600, T513),
+    (846374400, T512),
+    (877827600, T29),
+    (891133200, T30),
+    (909277200, T29),
+    (922582800, T30),
+    (941331600, T29),
+    (954032400, T30),
+    (972781200, T29),
+    (985482000, T30),
+    (1004230800, T29),
+    (1017536400, T30),
+    (1035680400, T29),
+    (1048986000, T30),
+    (1067130000, T29),
+    (1080435600, T30),
+    (1099184400, T29),
+    (1111885200, T30),
+    (1130634000, T29),
+    (1143334800, T30),
+    (1162083600, T29),
+    (1174784400, T30),
+    (1193533200, T29),
+    (1206838800, T30),
+    (1224982800, T29),
+    (1238288400, T30),
+    (1256432400, T29),
+    (1269738000, T30),
+    (1288486800, T29),
+    (1301187600, T30),
+    (1319936400, T29),
+    (1332637200, T30),
+    (1351386000, T29),
+    (1364691600, T30),
+    (1382835600, T29),
+    (1396137600, T530),
+    (1414274400, T512),
+];
+
+static EUROPE_SKOPJE: &[(i64, FixedTimespan<'static>)] = &[
+    (-2713915544, T13),
+    (-905824800, T14),
+    (-857257200, T13),
+    (-844556400, T14),
+    (-828226800, T13),
+    (-812502000, T14),
+    (-796777200, T13),
+    (-781052400, T14),
+    (-766623600, T13),
+    (417574800, T14),
+    (433299600, T13),
+    (449024400, T14),
+    (465354000, T13),
+    (481078800, T14),
+    (496803600, T13),
+    (512528400, T14),
+    (528253200, T13),
+    (543978000, T14),
+    (559702800, T13),
+    (575427600, T14),
+    (591152400, T13),
+    (606877200, T14),
+    (622602000, T13),
+    (638326800, T14),
+    (654656400, T13),
+    (670381200, T14),
+    (686106000, T13),
+    (701830800, T14),
+    (717555600, T13),
+    (733280400, T14),
+    (749005200, T13),
+    (764730000, T14),
+    (780454800, T13),
+    (796179600, T14),
+    (811904400, T13),
+    (828234000, T14),
+    (846378000, T13),
+    (859683600, T14),
+    (877827600, T13),
+];
+
+static EUROPE_SOFIA: &[(i64, FixedTimespan<'static>)] = &[
+    (-2840146396, T527),
+    (-2369527016, T29),
+    (-857257200, T13),
+    (-844556400, T14),
+    (-828226800, T13),
+    (-812502000, T14),
+    (-796777200, T13),
+    (-781048800, T29),
+    (291762000, T30),
+    (307576800, T29),
+    (323816400, T30),
+    (339026400, T29),
+    (355266000, T30),
+    (370393200, T29),
+    (386715600, T30),
+    (401846400, T29),
+    (417571200, T30),
+    (433296000, T29),
+    (449020800, T30),
+    (465350400, T29),
+    (481075200, T30),
+    (496800000, T29),
+    (512524800, T30),
+    (528249600, T29),
+    (543974400, T30),
+    (559699200, T29),
+    (575424000, T30),
+    (591148800, T29),
+    (606873600, T30),
+    (622598400, T29),
+    (638323200, T30),
+    (654652800, T29),
+    (670370400, T30),
+    (686091600, T29),
+    (701820000, T30),
+    (717541200, T29),
+    (733269600, T30),
+    (748990800, T29),
+    (764719200, T30),
+    (780440400, T29),
+    (796168800, T30),
+    (811890000, T29),
+    (828223200, T30),
+    (846363600, T29),
+    (859683600, T30),
+    (877827600, T29),
+];
+
+static EUROPE_STOCKHOLM: &[(i64, FixedTimespan<'static>)] = &[
+    (-2871681132, T562),
+    (-2208992414, T13),
+    (-1692496800, T14),
+    (-1680483600, T13),
+    (323830800, T14),
+    (338950800, T13),
+    (354675600, T14),
+    (370400400, T13),
+    (386125200, T14),
+    (401850000, T13),
+    (417574800, T14),
+    (433299600, T13),
+    (449024400, T14),
+    (465354000, T13),
+    (481078800, T14),
+    (496803600, T13),
+    (512528400, T14),
+    (528253200, T13),
+    (543978000, T14),
+    (559702800, T13),
+    (575427600, T14),
+    (591152400, T13),
+    (606877200, T14),
+    (622602000, T13),
+    (638326800, T14),
+    (654656400, T13),
+    (670381200, T14),
+    (686106000, T13),
+    (701830800, T14),
+    (717555600, T13),
+    (733280400, T14),
+    (749005200, T13),
+    (764730000, T14),
+    (780454800, T13),
+    (796179600, T14),
+    (811904400, T13),
+    (828234000, T14),
+    (846378000, T13),
+    (859683600, T14),
+    (877827600, T13),
+];
+
+static EUROPE_TALLINN: &[(i64, FixedTimespan<'static>)] = &[
+    (-1638322740, T13),
+    (-1632006000, T14),
+    (-1618700400, T13),
+    (-1593824400, T564),
+    (-1535938740, T29),
+    (-927943200, T512),
+    (-892954800, T14),
+    (-857257200, T13),
+    (-844556400, T14),
+    (-828226800, T13),
+    (-812502000, T14),
+    (-797652000, T512),
+    (354920400, T513),
+    (370728000, T512),
+    (386456400, T513),
+    (402264000, T512),
+    (417992400, T513),
+    (433800000, T512),
+    (449614800, T513),
+    (465346800, T512),
+    (481071600, T513),
+    (496796400, T512),
+    (512521200, T513),
+    (528246000, T512),
+    (543970800, T513),
+    (559695600, T512),
+    (575420400, T513),
+    (591145200, T512),
+    (622598400, T29),
+    (638323200, T30),
+    (654652800, T29),
+    (670377600, T30),
+    (686102400, T29),
+    (701827200, T30),
+    (717552000, T29),
+    (733276800, T30),
+    (749001600, T29),
+    (764726400, T30),
+    (780451200, T29),
+    (796176000, T30),
+    (811900800, T29),
+    (828230400, T30),
+    (846374400, T29),
+    (859680000, T30),
+    (877824000, T29),
+    (891129600, T30),
+    (909277200, T29),
+    (922582800, T30),
+    (941331600, T29),
+    (1017536400, T30),
+    (1035680400, T29),
+    (1048986000, T30),
+    (1067130000, T29),
+];
+
+static EUROPE_TIRANE: &[(i64, FixedTimespan<'static>)] = &[
+    (-1767230360, T13),
+    (-932346000, T14),
+    (-857257200, T13),
+    (-844556400, T14),
+    (-843519600, T13),
+    (136854000, T14),
+    (149896800, T13),
+    (168130800, T14),
+    (181432800, T13),
+    (199839600, T14),
+    (213141600, T13),
+    (231894000, T14),
+    (244591200, T13),
+    (263257200, T14),
+    (276040800, T13),
+    (294706800, T14),
+    (307490400, T13),
+    (326156400, T14),
+    (339458400, T13),
+    (357087600, T14),
+    (370389600, T13),
+    (389142000, T14),
+    (402444000, T13),
+    (419468400, T14),
+    (433807200, T13),
+    (449622000, T14),
+    (465354000, T13),
+    (481078800, T14),
+    (496803600, T13),
+    (512528400, T14),
+    (528253200, T13),
+    (543978000, T14),
+    (559702800, T13),
+    (575427600, T14),
+    (591152400, T13),
+    (606877200, T14),
+    (622602000, T13),
+    (638326800, T14),
+    (654656400, T13),
+    (670381200, T14),
+    (686106000, T13),
+    (701830800, T14),
+    (717555600, T13),
+    (733280400, T14),
+    (749005200, T13),
+    (764730000, T14),
+    (780454800, T13),
+    (796179600, T14),
+    (811904400, T13),
+    (828234000, T14),
+    (846378000, T13),
+    (859683600, T14),
+    (877827600, T13),
+];
+
+static EUROPE_ULYANOVSK: &[(i64, FixedTimespan<'static>)] = &[
+    (-1593820800, T310),
+    (-1247540400, T324),
+    (354916800, T325),
+    (370724400, T324),
+    (386452800, T325),
+    (402260400, T324),
+    (417988800, T325),
+    (433796400, T324),
+    (449611200, T325),
+    (465343200, T324),
+    (481068000, T325),
+    (496792800, T324),
+    (512517600, T325),
+    (528242400, T324),
+    (543967200, T325),
+    (559692000, T324),
+    (575416800, T325),
+    (591141600, T324),
+    (606866400, T310),
+    (606870000, T331),
+    (622594800, T310),
+    (638319600, T331),
+    (654649200, T310),
+    (670374000, T493),
+    (670377600, T554),
+    (686102400, T493),
+    (695779200, T310),
+    (701823600, T331),
+    (717548400, T310),
+    (733273200, T331),
+    (748998000, T310),
+    (764722800, T331),
+    (780447600, T310),
+    (796172400, T331),
+    (811897200, T310),
+    (828226800, T331),
+    (846370800, T310),
+    (859676400, T331),
+    (877820400, T310),
+    (891126000, T331),
+    (909270000, T310),
+    (922575600, T331),
+    (941324400, T310),
+    (954025200, T331),
+    (972774000, T310),
+    (985474800, T331),
+    (1004223600, T310),
+    (1017529200, T331),
+    (1035673200, T310),
+    (1048978800, T331),
+    (1067122800, T310),
+    (1080428400, T331),
+    (1099177200, T310),
+    (1111878000, T331),
+    (1130626800, T310),
+    (1143327600, T331),
+    (1162076400, T310),
+    (1174777200, T331),
+    (1193526000, T310),
+    (1206831600, T331),
+    (1224975600, T310),
+    (1238281200, T331),
+    (1256425200, T310),
+    (1269730800, T331),
+    (1288479600, T310),
+    (1301180400, T324),
+    (1414274400, T310),
+    (1459033200, T324),
+];
+
+static EUROPE_VADUZ: &[(i64, FixedTimespan<'static>)] = &[
+    (-2385247084, T13),
+    (-904435200, T14),
+    (-891129600, T13),
+    (-872985600, T14),
+    (-859680000, T13),
+    (354675600, T14),
+    (370400400, T13),
+    (386125200, T14),
+    (401850000, T13),
+    (417574800, T14),
+    (433299600, T13),
+    (449024400, T14),
+    (465354000, T13),
+    (481078800, T14),
+    (496803600, T13),
+    (512528400, T14),
+    (528253200, T13),
+    (543978000, T14),
+    (559702800, T13),
+    (575427600, T14),
+    (591152400, T13),
+    (606877200, T14),
+    (622602000, T13),
+    (638326800, T14),
+    (654656400, T13),
+    (670381200, T14),
+    (686106000, T13),
+    (701830800, T14),
+    (717555600, T13),
+    (733280400, T14),
+    (749005200, T13),
+    (764730000, T14),
+    (780454800, T13),
+    (796179600, T14),
+    (811904400, T13),
+    (828234000, T14),
+    (846378000, T13),
+    (859683600, T14),
+    (877827600, T13),
+];
+
+static EUROPE_VIENNA: &[(i64, FixedTimespan<'static>)] = &[
+    (-2422055121, T13),
+    (-1693706400, T14),
+    (-1680483600, T13),
+    (-1663455600, T14),
+    (-1650150000, T13),
+    (-1632006000, T14),
+    (-1618700400, T13),
+    (-1569711600, T14),
+    (-1555801200, T13),
+    (-938905200, T14),
+    (-857257200, T13),
+    (-844556400, T14),
+    (-828226800, T13),
+    (-812502000, T14),
+    (-796777200, T13),
+    (-781052400, T14),
+    (-780188400, T13),
+    (-748479600, T14),
+    (-733273200, T13),
+    (-717634800, T14),
+    (-701910000, T13),
+    (-684975600, T14),
+    (-670460400, T13),
+    (323823600, T14),
+    (338940000, T13),
+    (354675600, T14),
+    (370400400, T13),
+    (386125200, T14),
+    (401850000, T13),
+    (417574800, T14),
+    (433299600, T13),
+    (449024400, T14),
+    (465354000, T13),
+    (481078800, T14),
+    (496803600, T13),
+    (512528400, T14),
+    (528253200, T13),
+    (543978000, T14),
+    (559702800, T13),
+    (575427600, T14),
+    (591152400, T13),
+    (606877200, T14),
+    (622602000, T13),
+    (638326800, T14),
+    (654656400, T13),
+    (670381200, T14),
+    (686106000, T13),
+    (701830800, T14),
+    (717555600, T13),
+    (733280400, T14),
+    (749005200, T13),
+    (764730000, T14),
+    (780454800, T13),
+    (796179600, T14),
+    (811904400, T13),
+    (828234000, T14),
+    (846378000, T13),
+    (859683600, T14),
+    (877827600, T13),
+];
+
+static EUROPE_VILNIUS: &[(i64, FixedTimespan<'static>)] = &[
+    (-2840146876, T570),
+    (-1672536240, T571),
+    (-1585100136, T13),
+    (-1561251600, T29),
+    (-1553565600, T13),
+    (-928198800, T512),
+    (-900126000, T14),
+    (-857257200, T13),
+    (-844556400, T14),
+    (-828226800, T13),
+    (-812502000, T14),
+    (-802144800, T512),
+    (354920400, T513),
+    (370728000, T512),
+    (386456400, T513),
+    (402264000, T512),
+    (417992400, T513),
+    (433800000, T512),
+    (449614800, T513),
+    (465346800, T512),
+    (481071600, T513),
+    (496796400, T512),
+    (512521200, T513),
+    (528246000, T512),
+    (543970800, T513),
+    (559695600, T512),
+    (575420400, T513),
+    (591145200, T512),
+    (606870000, T29),
+    (606873600, T30),
+    (622598400, T29),
+    (638323200, T30),
+    (654652800, T29),
+    (670377600, T30),
+    (686102400, T29),
+    (701827200, T30),
+    (717552000, T29),
+    (733276800, T30),
+    (749001600, T29),
+    (764726400, T30),
+    (780451200, T29),
+    (796176000, T30),
+    (811900800, T29),
+    (828230400, T30),
+    (846374400, T29),
+    (859680000, T30),
+    (877824000, T29),
+    (909277200, T13),
+    (922582800, T14),
+    (1048986000, T30),
+    (1067130000, T29),
+];
+
+static EUROPE_VOLGOGRAD: &[(i64, FixedTimespan<'static>)] = &[
+    (-1577761060, T310),
+    (-1247540400, T324),
+    (354916800, T325),
+    (370724400, T324),
+    (386452800, T325),
+    (402260400, T324),
+    (417988800, T325),
+    (433796400, T324),
+    (449611200, T325),
+    (465343200, T324),
+    (481068000, T325),
+    (496792800, T324),
+    (512517600, T325),
+    (528242400, T324),
+    (543967200, T325),
+    (559692000, T324),
+    (575416800, T512),
+    (575420400, T513),
+    (591145200, T512),
+    (606870000, T513),
+    (622594800, T512),
+    (638319600, T513),
+    (654649200, T512),
+    (670374000, T324),
+    (701820000, T512),
+    (701823600, T513),
+    (717548400, T512),
+    (733273200, T513),
+    (748998000, T512),
+    (764722800, T513),
+    (780447600, T512),
+    (796172400, T513),
+    (811897200, T512),
+    (828226800, T513),
+    (846370800, T512),
+    (859676400, T513),
+    (877820400, T512),
+    (891126000, T513),
+    (909270000, T512),
+    (922575600, T513),
+    (941324400, T512),
+    (954025200, T513),
+    (972774000, T512),
+    (985474800, T513),
+    (1004223600, T512),
+    (1017529200, T513),
+    (1035673200, T512),
+    (1048978800, T513),
+    (1067122800, T512),
+    (1080428400, T513),
+    (1099177200, T512),
+    (1111878000, T513),
+    (1130626800, T512),
+    (1143327600, T513),
+    (1162076400, T512),
+    (1174777200, T513),
+    (1193526000, T512),
+    (1206831600, T513),
+    (1224975600, T512),
+    (1238281200, T513),
+    (1256425200, T512),
+    (1269730800, T513),
+    (1288479600, T512),
+    (1301180400, T530),
+    (1414274400, T512),
+    (1540681200, T324),
+    (1609020000, T512),
+];
+
+static EUROPE_WARSAW: &[(i64, FixedTimespan<'static>)] = &[
+    (-1717032240, T13),
+    (-1693706400, T14),
+    (-1680483600, T13),
+    (-1663455600, T14),
+    (-1650150000, T13),
+    (-1632006000, T14),
+    (-1600473600, T30),
+    (-1587168000, T29),
+    (-1501725600, T13),
+    (-931734000, T14),
+    (-857257200, T13),
+    (-844556400, T14),
+    (-828226800, T13),
+    (-812502000, T14),
+    (-796608000, T13),
+    (-778726800, T14),
+    (-762660000, T13),
+    (-748486800, T14),
+    (-733273200, T13),
+    (-715215600, T14),
+    (-701910000, T13),
+    (-684975600, T14),
+    (-670460400, T13),
+    (-654130800, T14),
+    (-639010800, T13),
+    (-397094400, T14),
+    (-386812800, T13),
+    (-371088000, T14),
+    (-355363200, T13),
+    (-334195200, T14),
+    (-323308800, T13),
+    (-307584000, T14),
+    (-291859200, T13),
+    (-271296000, T14),
+    (-260409600, T13),
+    (-239846400, T14),
+    (-228960000, T13),
+    (-208396800, T14),
+    (-197510400, T13),
+    (-176342400, T14),
+    (-166060800, T13),
+    (228873600, T14),
+    (243993600, T13),
+    (260323200, T14),
+    (276048000, T13),
+    (291772800, T14),
+    (307497600, T13),
+    (323827200, T14),
+    (338947200, T13),
+    (354672000, T14),
+    (370396800, T13),
+    (386121600, T14),
+    (401846400, T13),
+    (417571200, T14),
+    (433296000, T13),
+    (449020800, T14),
+    (465350400, T13),
+    (481075200, T14),
+    (496800000, T13),
+    (512524800, T14),
+    (528249600, T13),
+    (543974400, T14),
+    (559699200, T13),
+    (575427600, T14),
+    (591152400, T13),
+    (606877200, T14),
+    (622602000, T13),
+    (638326800, T14),
+    (654656400, T13),
+    (670381200, T14),
+    (686106000, T13),
+    (701830800, T14),
+    (717555600, T13),
+    (733280400, T14),
+    (749005200, T13),
+    (764730000, T14),
+    (780454800, T13),
+    (796179600, T14),
+    (811904400, T13),
+    (828234000, T14),
+    (846378000, T13),
+    (859683600, T14),
+    (877827600, T13),
+];
+
+static EUROPE_ZAGREB: &[(i64, FixedTimespan<'static>)] = &[
+    (-2713914232, T13),
+    (-905824800, T14),
+    (-857257200, T13),
+    (-844556400, T14),
+    (-828226800, T13),
+    (-812502000, T14),
+    (-796777200, T13),
+    (-781052400, T14),
+    (-766623600, T13),
+    (417574800, T14),
+    (433299600, T13),
+    (449024400, T14),
+    (465354000, T13),
+    (481078800, T14),
+    (496803600, T13),
+    (512528400, T14),
+    (528253200, T13),
+    (543978000, T14),
+    (559702800, T13),
+    (575427600, T14),
+    (591152400, T13),
+    (606877200, T14),
+    (622602000, T13),
+    (638326800, T14),
+    (654656400, T13),
+    (670381200, T14),
+    (686106000, T13),
+    (701830800, T14),
+    (717555600, T13),
+    (733280400, T14),
+    (749005200, T13),
+    (764730000, T14),
+    (780454800, T13),
+    (796179600, T14),
+    (811904400, T13),
+    (828234000, T14),
+    (846378000, T13),
+    (859683600, T14),
+    (877827600, T13),
+];
+
+static EUROPE_ZURICH: &[(i64, FixedTimespan<'static>)] = &[
+    (-3675198848, T575),
+    (-2385246586, T13),
+    (-904435200, T14),
+    (-891129600, T13),
+    (-872985600, T14),
+    (-859680000, T13),
+    (354675600, T14),
+    (370400400, T13),
+    (386125200, T14),
+    (401850000, T13),
+    (417574800, T14),
+    (433299600, T13),
+    (449024400, T14),
+    (465354000, T13),
+    (481078800, T14),
+    (496803600, T13),
+    (512528400, T14),
+    (528253200, T13),
+    (543978000, T14),
+    (559702800, T13),
+    (575427600, T14),
+    (591152400, T13),
+    (606877200, T14),
+    (622602000, T13),
+    (638326800, T14),
+    (654656400, T13),
+    (670381200, T14),
+    (686106000, T13),
+    (701830800, T14),
+    (717555600, T13),
+    (733280400, T14),
+    (749005200, T13),
+    (764730000, T14),
+    (780454800, T13),
+    (796179600, T14),
+    (811904400, T13),
+    (828234000, T14),
+    (846378000, T13),
+    (859683600, T14),
+    (877827600, T13),
+];
+
+static FACTORY: &[(i64, FixedTimespan<'static>)] = &[
+];
+
+static HST: &[(i64, FixedTimespan<'static>)] = &[
+];
+
+static INDIAN_ANTANANARIVO: &[(i64, FixedTimespan<'static>)] = &[
+    (-1846293004, T8),
+    (-499924800, T577),
+    (-492062400, T8),
+];
+
+static INDIAN_CHAGOS: &[(i64, FixedTimespan<'static>)] = &[
+    (-1988167780, T303),
+    (820436400, T307),
+];
+
+static INDIAN_CHRISTMAS: &[(i64, FixedTimespan<'static>)] = &[
+    (-2364102172, T302),
+];
+
+static INDIAN_COCOS: &[(i64, FixedTimespan<'static>)] = &[
+    (-2209012060, T350),
+];
+
+static INDIAN_COMORO: &[(i64, FixedTimespan<'static>)] = &[
+    (-1846291984, T8),
+];
+
+static INDIAN_KERGUELEN: &[(i64, FixedTimespan<'static>)] = &[
+    (-631152000, T303),
+];
+
+static INDIAN_MAHE: &[(i64, FixedTimespan<'static>)] = &[
+    (-1988163708, T324),
+];
+
+static INDIAN_MALDIVES: &[(i64, FixedTimespan<'static>)] = &[
+    (-315636840, T303),
+];
+
+static INDIAN_MAURITIUS: &[(i64, FixedTimespan<'static>)] = &[
+    (-1988164200, T324),
+    (403041600, T325),
+    (417034800, T324),
+    (1224972000, T325),
+    (1238274000, T324),
+];
+
+static INDIAN_MAYOTTE: &[(i64, FixedTimespan<'static>)] = &[
+    (-1846292456, T8),
+];
+
+static INDIAN_REUNION: &[(i64, FixedTimespan<'static>)] = &[
+    (-1848886912, T324),
+];
+
+static MET: &[(i64, FixedTimespan<'static>)] = &[
+    (-1693706400, T588),
+    (-1680483600, T587),
+    (-1663455600, T588),
+    (-1650150000, T587),
+    (-1632006000, T588),
+    (-1618700400, T587),
+    (-938905200, T588),
+    (-857257200, T587),
+    (-844556400, T588),
+    (-828226800, T587),
+    (-812502000, T588),
+    (-796777200, T587),
+    (-781052400, T588),
+    (-766623600, T587),
+    (228877200, T588),
+    (243997200, T587),
+    (260326800, T588),
+    (276051600, T587),
+    (291776400, T588),
+    (307501200, T587),
+    (323830800, T588),
+    (338950800, T587),
+    (354675600, T588),
+    (370400400, T587),
+    (386125200, T588),
+    (401850000, T587),
+    (417574800, T588),
+    (433299600, T587),
+    (449024400, T588),
+    (465354000, T587),
+    (481078800, T588),
+    (496803600, T587),
+    (512528400, T588),
+    (528253200, T587),
+    (543978000, T588),
+    (559702800, T587),
+    (575427600, T588),
+    (591152400, T587),
+    (606877200, T588),
+    (622602000, T587),
+    (638326800, T588),
+    (654656400, T587),
+    (670381200, T588),
+    (686106000, T587),
+    (701830800, T588),
+    (717555600, T587),
+    (733280400, T588),
+    (749005200, T587),
+    (764730000, T588),
+    (780454800, T587),
+    (796179600, T588),
+    (811904400, T587),
+    (828234000, T588),
+    (846378000, T587),
+    (859683600, T588),
+    (877827600, T587),
+];
+
+static MST: &[(i64, FixedTimespan<'static>)] = &[
+];
+
+static MST7MDT: &[(i64, FixedTimespan<'static>)] = &[
+    (-1633273200, T134),
+    (-1615132800, T133),
+    (-1601823600, T134),
+    (-1583683200, T133),
+    (-880210800, T151),
+    (-765388800, T133),
+    (-84380400, T134),
+    (-68659200, T133),
+    (-52930800, T134),
+    (-37209600, T133),
+    (-21481200, T134),
+    (-5760000, T133),
+    (9968400, T134),
+    (25689600, T133),
+    (41418000, T134),
+    (57744000, T133),
+    (73472400, T134),
+    (89193600, T133),
+    (104922000, T134),
+    (120643200, T133),
+    (126694800, T134),
+    (152092800, T133),
+    (162378000, T134),
+    (183542400, T133),
+    (199270800, T134),
+    (215596800, T133),
+    (230720400, T134),
+    (247046400, T133),
+    (262774800, T134),
+    (278496000, T133),
+    (294224400, T134),
+    (309945600, T133),
+    (325674000, T134),
+    (341395200, T133),
+    (357123600, T134),
+    (372844800, T133),
+    (388573200, T134),
+    (404899200, T133),
+    (420022800, T134),
+    (436348800, T133),
+    (452077200, T134),
+    (467798400, T133),
+    (483526800, T134),
+    (499248000, T133),
+    (514976400, T134),
+    (530697600, T133),
+    (544611600, T134),
+    (562147200, T133),
+    (576061200, T134),
+    (594201600, T133),
+    (607510800, T134),
+    (625651200, T133),
+    (638960400, T134),
+    (657100800, T133),
+    (671014800, T134),
+    (688550400, T133),
+    (702464400, T134),
+    (720000000, T133),
+    (733914000, T134),
+    (752054400, T133),
+    (765363600, T134),
+    (783504000, T133),
+    (796813200, T134),
+    (814953600, T133),
+    (828867600, T134),
+    (846403200, T133),
+    (860317200, T134),
+    (877852800, T133),
+    (891766800, T134),
+    (909302400, T133),
+    (923216400, T134),
+    (941356800, T133),
+    (954666000, T134),
+    (972806400, T133),
+    (986115600, T134),
+    (1004256000, T133),
+    (1018170000, T134),
+    (1035705600, T133),
+    (1049619600, T134),
+    (1067155200, T133),
+    (1081069200, T134),
+    (1099209600, T133),
+    (1112518800, T134),
+    (1130659200, T133),
+    (1143968400, T134),
+    (1162108800, T133),
+    (1173603600, T134),
+    (1194163200, T133),
+    (1205053200, T134),
+    (1225612800, T133),
+];
+
+static PST8PDT: &[(i64, FixedTimespan<'static>)] = &[
+    (-1633269600, T150),
+    (-1615129200, T149),
+    (-1601820000, T150),
+    (-1583679600, T149),
+    (-880207200, T177),
+    (-765385200, T149),
+    (-84376800, T150),
+    (-68655600, T149),
+    (-52927200, T150),
+    (-37206000, T149),
+    (-21477600, T150),
+    (-5756400, T149),
+    (9972000, T150),
+    (25693200, T149),
+    (41421600, T150),
+    (57747600, T149),
+    (73476000, T150),
+    (89197200, T149),
+    (104925600, T150),
+    (120646800, T149),
+    (126698400, T150),
+    (152096400, T149),
+    (162381600, T150),
+    (183546000, T149),
+    (199274400, T150),
+    (215600400, T149),
+    (230724000, T150),
+    (247050000, T149),
+    (262778400, T150),
+    (278499600, T149),
+    (294228000, T150),
+    (309949200, T149),
+    (325677600, T150),
+    (341398800, T149),
+    (357127200, T150),
+    (372848400, T149),
+    (388576800, T150),
+    (404902800, T149),
+    (420026400, T150),
+    (436352400, T149),
+    (452080800, T150),
+    (467802000, T149),
+    (483530400, T150),
+    (499251600, T149),
+    (514980000, T150),
+    (530701200, T149),
+    (544615200, T150),
+    (562150800, T149),
+    (576064800, T150),
+    (594205200, T149),
+    (607514400, T150),
+    (625654800, T149),
+    (638964000, T150),
+    (657104400, T149),
+    (671018400, T150),
+    (688554000, T149),
+    (702468000, T150),
+    (720003600, T149),
+    (733917600, T150),
+    (752058000, T149),
+    (765367200, T150),
+    (783507600, T149),
+    (796816800, T150),
+    (814957200, T149),
+    (828871200, T150),
+    (846406800, T149),
+    (860320800, T150),
+    (877856400, T149),
+    (891770400, T150),
+    (909306000, T149),
+    (923220000, T150),
+    (941360400, T149),
+    (954669600, T150),
+    (972810000, T149),
+    (986119200, T150),
+    (1004259600, T149),
+    (1018173600, T150),
+    (1035709200, T149),
+    (1049623200, T150),
+    (1067158800, T149),
+    (1081072800, T150),
+    (1099213200, T149),
+    (1112522400, T150),
+    (1130662800, T149),
+    (1143972000, T150),
+    (1162112400, T149),
+    (1173607200, T150),
+    (1194166800, T149),
+    (1205056800, T150),
+    (1225616400, T149),
+];
+
+static PACIFIC_APIA: &[(i64, FixedTimespan<'static>)] = &[
+    (-2445424384, T590),
+    (-1861878784, T591),
+    (-631110600, T486),
+    (1285498800, T592),
+    (1301752800, T486),
+    (1316872800, T592),
+    (1325239200, T320),
+    (1333202400, T319),
+    (1348927200, T320),
+    (1365256800, T319),
+    (1380376800, T320),
+    (1396706400, T319),
+    (1411826400, T320),
+    (1428156000, T319),
+    (1443276000, T320),
+    (1459605600, T319),
+    (1474725600, T320),
+    (1491055200, T319),
+    (1506175200, T320),
+    (1522504800, T319),
+    (1538229600, T320),
+    (1554559200, T319),
+    (1569679200, T320),
+    (1586008800, T319),
+    (1601128800, T320),
+    (1617458400, T319),
+];
+
+static PACIFIC_AUCKLAND: &[(i64, FixedTimespan<'static>)] = &[
+    (-3192435544, T594),
+    (-1330335000, T595),
+    (-1320057000, T594),
+    (-1300699800, T596),
+    (-1287396000, T594),
+    (-1269250200, T596),
+    (-1255946400, T594),
+    (-1237800600, T596),
+    (-1224496800, T594),
+    (-1206351000, T596),
+    (-1192442400, T594),
+    (-1174901400, T596),
+    (-1160992800, T594),
+    (-1143451800, T596),
+    (-1125914400, T594),
+    (-1112607000, T596),
+    (-1094464800, T594),
+    (-1081157400, T596),
+    (-1063015200, T594),
+    (-1049707800, T596),
+    (-1031565600, T594),
+    (-1018258200, T596),
+    (-1000116000, T594),
+    (-986808600, T596),
+    (-968061600, T594),
+    (-955359000, T596),
+    (-936612000, T594),
+    (-923304600, T596),
+    (152632800, T309),
+    (162309600, T308),
+    (183477600, T309),
+    (194968800, T308),
+    (215532000, T309),
+    (226418400, T308),
+    (246981600, T309),
+    (257868000, T308),
+    (278431200, T309),
+    (289317600, T308),
+    (309880800, T309),
+    (320767200, T308),
+    (341330400, T309),
+    (352216800, T308),
+    (372780000, T309),
+    (384271200, T308),
+    (404834400, T309),
+    (415720800, T308),
+    (436284000, T309),
+    (447170400, T308),
+    (467733600, T309),
+    (478620000, T308),
+    (499183200, T309),
+    (510069600, T308),
+    (530632800, T309),
+    (541519200, T308),
+    (562082400, T309),
+    (573573600, T308),
+    (594136800, T309),
+    (605023200, T308),
+    (623772000, T309),
+    (637682400, T308),
+    (655221600, T309),
+    (669132000, T308),
+    (686671200, T309),
+    (700581600, T308),
+    (718120800, T309),
+    (732636000, T308),
+    (749570400, T309),
+    (764085600, T308),
+    (781020000, T309),
+    (795535200, T308),
+    (812469600, T309),
+    (826984800, T308),
+    (844524000, T309),
+    (858434400, T308),
+    (875973600, T309),
+    (889884000, T308),
+    (907423200, T309),
+    (921938400, T308),
+    (938872800, T309),
+    (953388000, T308),
+    (970322400, T309),
+    (984837600, T308),
+    (1002376800, T309),
+    (1016287200, T308),
+    (1033826400, T309),
+    (1047736800, T308),
+    (1065276000, T309),
+    (1079791200, T308),
+    (1096725600, T309),
+    (1111240800, T308),
+    (1128175200, T309),
+    (1142690400, T308),
+    (1159624800, T309),
+    (1174140000, T308),
+    (1191074400, T309),
+    (1207404000, T308),
+    (1222524000, T309),
+    (1238853600, T308),
+    (1253973600, T309),
+];
+
+static PACIFIC_BOUGAINVILLE: &[(i64, FixedTimespan<'static>)] = &[
+    (-2840178136, T598),
+    (-2366790512, T304),
+    (-868010400, T343),
+    (-768906000, T304),
+    (1419696000, T301),
+];
+
+static PACIFIC_CHATHAM: &[(i64, FixedTimespan<'static>)] = &[
+    (-3192437628, T600),
+    (-757426500, T601),
+    (152632800, T602),
+    (162309600, T601),
+    (183477600, T602),
+    (194968800, T601),
+    (215532000, T602),
+    (226418400, T601),
+    (246981600, T602),
+    (257868000, T601),
+    (278431200, T602),
+    (289317600, T601),
+    (309880800, T602),
+    (320767200, T601),
+    (341330400, T602),
+    (352216800, T601),
+    (372780000, T602),
+    (384271200, T601),
+    (404834400, T602),
+    (415720800, T601),
+    (436284000, T602),
+    (447170400, T601),
+    (467733600, T602),
+    (478620000, T601),
+    (499183200, T602),
+    (510069600, T601),
+    (530632800, T602),
+    (541519200, T601),
+    (562082400, T602),
+    (573573600, T601),
+    (594136800, T602),
+    (605023200, T601),
+    (623772000, T602),
+    (637682400, T601),
+    (655221600, T602),
+    (669132000, T601),
+    (686671200, T602),
+    (700581600, T601),
+    (718120800, T602),
+    (732636000, T601),
+    (749570400, T602),
+    (764085600, T601),
+    (781020000, T602),
+    (795535200, T601),
+    (812469600, T602),
+    (826984800, T601),
+    (844524000, T602),
+    (858434400, T601),
+    (875973600, T602),
+    (889884000, T601),
+    (907423200, T602),
+    (921938400, T601),
+    (938872800, T602),
+    (953388000, T601),
+    (970322400, T602),
+    (984837600, T601),
+    (1002376800, T602),
+    (1016287200, T601),
+    (1033826400, T602),
+    (1047736800, T601),
+    (1065276000, T602),
+    (1079791200, T601),
+    (1096725600, T602),
+    (1111240800, T601),
+    (1128175200, T602),
+    (1142690400, T601),
+    (1159624800, T602),
+    (1174140000, T601),
+    (1191074400, T602),
+    (1207404000, T601),
+    (1222524000, T602),
+    (1238853600, T601),
+    (1253973600, T602),
+];
+
+static PACIFIC_CHUUK: &[(i64, FixedTimespan<'static>)] = &[
+    (-3944628428, T604),
+    (-2177489228, T304),
+    (-1743674400, T343),
+    (-1606813200, T304),
+    (-907408800, T343),
+    (-770634000, T304),
+];
+
+static PACIFIC_EASTER: &[(i64, FixedTimespan<'static>)] = &[
+    (-1178124152, T489),
+    (-36619200, T606),
+    (-23922000, T489),
+    (-3355200, T606),
+    (7527600, T489),
+    (24465600, T606),
+    (37767600, T489),
+    (55915200, T606),
+    (69217200, T489),
+    (87969600, T606),
+    (100666800, T489),
+    (118209600, T606),
+    (132116400, T489),
+    (150868800, T606),
+    (163566000, T489),
+    (182318400, T606),
+    (195620400, T489),
+    (213768000, T606),
+    (227070000, T489),
+    (245217600, T606),
+    (258519600, T489),
+    (277272000, T606),
+    (289969200, T489),
+    (308721600, T606),
+    (321418800, T489),
+    (340171200, T606),
+    (353473200, T489),
+    (371620800, T606),
+    (403070400, T607),
+    (416372400, T488),
+    (434520000, T607),
+    (447822000, T488),
+    (466574400, T607),
+    (479271600, T488),
+    (498024000, T607),
+    (510721200, T488),
+    (529473600, T607),
+    (545194800, T488),
+    (560923200, T607),
+    (574225200, T488),
+    (592372800, T607),
+    (605674800, T488),
+    (624427200, T607),
+    (637124400, T488),
+    (653457600, T607),
+    (668574000, T488),
+    (687326400, T607),
+    (700628400, T488),
+    (718776000, T607),
+    (732078000, T488),
+    (750225600, T607),
+    (763527600, T488),
+    (781675200, T607),
+    (794977200, T488),
+    (813729600, T607),
+    (826426800, T488),
+    (845179200, T607),
+    (859690800, T488),
+    (876628800, T607),
+    (889930800, T488),
+    (906868800, T607),
+    (923194800, T488),
+    (939528000, T607),
+    (952830000, T488),
+    (971582400, T607),
+    (984279600, T488),
+    (1003032000, T607),
+    (1015729200, T488),
+    (1034481600, T607),
+    (1047178800, T488),
+    (1065931200, T607),
+    (1079233200, T488),
+    (1097380800, T607),
+    (1110682800, T488),
+    (1128830400, T607),
+    (1142132400, T488),
+    (1160884800, T607),
+    (1173582000, T488),
+    (1192334400, T607),
+    (1206846000, T488),
+    (1223784000, T607),
+    (1237086000, T488),
+    (1255233600, T607),
+    (1270350000, T488),
+    (1286683200, T607),
+    (1304823600, T488),
+    (1313899200, T607),
+    (1335668400, T488),
+    (1346558400, T607),
+    (1367118000, T488),
+    (1378612800, T607),
+    (1398567600, T488),
+    (1410062400, T607),
+    (1463281200, T488),
+    (1471147200, T607),
+    (1494730800, T488),
+    (1502596800, T607),
+    (1526180400, T488),
+    (1534046400, T607),
+    (1554606000, T488),
+    (1567915200, T607),
+    (1586055600, T488),
+    (1599364800, T607),
+    (1617505200, T488),
+    (1630814400, T607),
+    (1648954800, T488),
+    (1662868800, T607),
+    (1680404400, T488),
+    (1693713600, T607),
+    (1712458800, T488),
+    (1725768000, T607),
+];
+
+static PACIFIC_EFATE: &[(i64, FixedTimespan<'static>)] = &[
+    (-1829387596, T301),
+    (125409600, T322),
+    (133876800, T301),
+    (433256400, T322),
+    (448977600, T301),
+    (464706000, T322),
+    (480427200, T301),
+    (496760400, T322),
+    (511876800, T301),
+    (528210000, T322),
+    (543931200, T301),
+    (559659600, T322),
+    (575380800, T301),
+    (591109200, T322),
+    (606830400, T301),
+    (622558800, T322),
+    (638280000, T301),
+    (654008400, T322),
+    (669729600, T301),
+    (686062800, T322),
+    (696340800, T301),
+    (719931600, T322),
+    (727790400, T301),
+];
+
+static PACIFIC_FAKAOFO: &[(i64, FixedTimespan<'static>)] = &[
+    (-2177411704, T486),
+    (1325242800, T319),
+];
+
+static PACIFIC_FIJI: &[(i64, FixedTimespan<'static>)] = &[
+    (-1709985344, T318),
+    (909842400, T321),
+    (920124000, T318),
+    (941896800, T321),
+    (951573600, T318),
+    (1259416800, T321),
+    (1269698400, T318),
+    (1287842400, T321),
+    (1299333600, T318),
+    (1319292000, T321),
+    (1327154400, T318),
+    (1350741600, T321),
+    (1358604000, T318),
+    (1382796000, T321),
+    (1390050000, T318),
+    (1414850400, T321),
+    (1421503200, T318),
+    (1446300000, T321),
+    (1452952800, T318),
+    (1478354400, T321),
+    (1484402400, T318),
+    (1509804000, T321),
+    (1515852000, T318),
+    (1541253600, T321),
+    (1547301600, T318),
+    (1573308000, T321),
+    (1578751200, T318),
+    (1608386400, T321),
+    (1610805600, T318),
+];
+
+static PACIFIC_FUNAFUTI: &[(i64, FixedTimespan<'static>)] = &[
+    (-2177495812, T318),
+];
+
+static PACIFIC_GALAPAGOS: &[(i64, FixedTimespan<'static>)] = &[
+    (-1230746496, T146),
+    (504939600, T488),
+    (722930400, T607),
+    (728888400, T488),
+];
+
+static PACIFIC_GAMBIER: &[(i64, FixedTimespan<'static>)] = &[
+    (-1806678012, T491),
+];
+
+static PACIFIC_GUADALCANAL: &[(i64, FixedTimespan<'static>)] = &[
+    (-1806748788, T301),
+];
+
+static PACIFIC_GUAM: &[(i64, FixedTimespan<'static>)] = &[
+    (-3944626740, T616),
+    (-2177487540, T617),
+    (-885549600, T343),
+    (-802256400, T617),
+    (-331891200, T618),
+    (-281610000, T617),
+    (-73728000, T618),
+    (-29415540, T617),
+    (-16704000, T618),
+    (-10659600, T617),
+    (9907200, T618),
+    (21394800, T617),
+    (41356800, T618),
+    (52844400, T617),
+    (124819200, T618),
+    (130863600, T617),
+    (201888000, T618),
+    (209487660, T617),
+    (230659200, T618),
+    (241542000, T617),
+];
+
+static PACIFIC_HONOLULU: &[(i64, FixedTimespan<'static>)] = &[
+    (-2334101314, T621),
+    (-1157283000, T622),
+    (-1155436200, T621),
+    (-880198200, T623),
+    (-765376200, T621),
+    (-712150200, T94),
+];
+
+static PACIFIC_KANTON: &[(i64, FixedTimespan<'static>)] = &[
+    (-1020470400, T487),
+    (307627200, T486),
+    (788871600, T319),
+];
+
+static PACIFIC_KIRITIMATI: &[(i64, FixedTimespan<'static>)] = &[
+    (-2177415040, T625),
+    (307622400, T485),
+    (788868000, T492),
+];
+
+static PACIFIC_KOSRAE: &[(i64, FixedTimespan<'static>)] = &[
+    (-3944631116, T627),
+    (-2177491916, T301),
+    (-1743678000, T343),
+    (-1606813200, T301),
+    (-1041418800, T304),
+    (-907408800, T343),
+    (-770634000, T301),
+    (-7988400, T318),
+    (915105600, T301),
+];
+
+static PACIFIC_KWAJALEIN: &[(i64, FixedTimespan<'static>)] = &[
+    (-2177492960, T301),
+    (-1041418800, T304),
+    (-907408800, T343),
+    (-817462800, T301),
+    (-7988400, T487),
+    (745934400, T318),
+];
+
+static PACIFIC_MAJURO: &[(i64, FixedTimespan<'static>)] = &[
+    (-2177493888, T301),
+    (-1743678000, T343),
+    (-1606813200, T301),
+    (-1041418800, T304),
+    (-907408800, T343),
+    (-818067600, T301),
+    (-7988400, T318),
+];
+
+static PACIFIC_MARQUESAS: &[(i64, FixedTimespan<'static>)] = &[
+    (-1806676920, T631),
+];
+
+static PACIFIC_MIDWAY: &[(i64, FixedTimespan<'static>)] = &[
+    (-2177410232, T486),
+    (-428504400, T592),
+    (-420645600, T633),
+];
+
+static PACIFIC_NAURU: &[(i64, FixedTimespan<'static>)] = &[
+    (-1545131260, T635),
+    (-862918200, T343),
+    (-767350800, T635),
+    (287418600, T318),
+];
+
+static PACIFIC_NIUE: &[(i64, FixedTimespan<'static>)] = &[
+    (-543069620, T637),
+    (-173623200, T486),
+];
+
+static PACIFIC_NORFOLK: &[(i64, FixedTimespan<'static>)] = &[
+    (-2177493112, T639),
+    (-599656320, T635),
+    (152029800, T640),
+    (162916200, T635),
+    (1443882600, T301),
+    (1570287600, T322),
+    (1586012400, T301),
+    (1601737200, T322),
+];
+
+static PACIFIC_NOUMEA: &[(i64, FixedTimespan<'static>)] = &[
+    (-1829387148, T301),
+    (250002000, T322),
+    (257342400, T301),
+    (281451600, T322),
+    (288878400, T301),
+    (849366000, T322),
+    (857228400, T301),
+];
+
+static PACIFIC_PAGO_PAGO: &[(i64, FixedTimespan<'static>)] = &[
+    (-2445424632, T643),
+    (-1861879032, T633),
+];
+
+static PACIFIC_PALAU: &[(i64, FixedTimespan<'static>)] = &[
+    (-3944624276, T645),
+    (-2177485076, T343),
+];
+
+static PACIFIC_PITCAIRN: &[(i64, FixedTimespan<'static>)] = &[
+    (-2177421580, T647),
+    (893665800, T490),
+];
+
+static PACIFIC_POHNPEI: &[(i64, FixedTimespan<'static>)] = &[
+    (-3944629972, T649),
+    (-2177490772, T301),
+    (-1743678000, T343),
+    (-1606813200, T301),
+    (-1041418800, T304),
+    (-907408800, T343),
+    (-770634000, T301),
+];
+
+static PACIFIC_PORT_MORESBY: &[(i64, FixedTimespan<'static>)] = &[
+    (-2840176120, T598),
+    (-2366790512, T304),
+];
+
+static PACIFIC_RAROTONGA: &[(i64, FixedTimespan<'static>)] = &[
+    (-2209555256, T652),
+    (-543072056, T653),
+    (279714600, T654),
+    (289387800, T485),
+    (309952800, T654),
+    (320837400, T485),
+    (341402400, T654),
+    (352287000, T485),
+    (372852000, T654),
+    (384341400, T485),
+    (404906400, T654),
+    (415791000, T485),
+    (436356000, T654),
+    (447240600, T485),
+    (467805600, T654),
+    (478690200, T485),
+    (499255200, T654),
+    (510139800, T485),
+    (530704800, T654),
+    (541589400, T485),
+    (562154400, T654),
+    (573643800, T485),
+    (594208800, T654),
+    (605093400, T485),
+    (625658400, T654),
+    (636543000, T485),
+    (657108000, T654),
+    (667992600, T485),
+];
+
+static PACIFIC_SAIPAN: &[(i64, FixedTimespan<'static>)] = &[
+    (-3944626980, T656),
+    (-2177487780, T343),
+    (-804157200, T617),
+    (-331891200, T618),
+    (-281610000, T617),
+    (-73728000, T618),
+    (-29415540, T617),
+    (-16704000, T618),
+    (-10659600, T617),
+    (9907200, T618),
+    (21394800, T617),
+    (41356800, T618),
+    (52844400, T617),
+    (124819200, T618),
+    (130863600, T617),
+    (201888000, T618),
+    (209487660, T617),
+    (230659200, T618),
+    (241542000, T617),
+];
+
+static PACIFIC_TAHITI: &[(i64, FixedTimespan<'static>)] = &[
+    (-1806674504, T485),
+];
+
+static PACIFIC_TARAWA: &[(i64, FixedTimespan<'static>)] = &[
+    (-2177494324, T318),
+];
+
+static PACIFIC_TONGATAPU: &[(i64, FixedTimespan<'static>)] = &[
+    (-767189952, T660),
+    (-284041200, T319),
+    (939214800, T320),
+    (953384400, T319),
+    (973342800, T320),
+    (980596800, T319),
+    (1004792400, T320),
+    (1012046400, T319),
+    (1478350800, T320),
+    (1484398800, T319),
+];
+
+static PACIFIC_WAKE: &[(i64, FixedTimespan<'static>)] = &[
+    (-2177492788, T318),
+];
+
+static PACIFIC_WALLIS: &[(i64, FixedTimespan<'static>)] = &[
+    (-2177496920, T318),
+];
+
+static WET: &[(i64, FixedTimespan<'static>)] = &[
+    (228877200, T12),
+    (243997200, T11),
+    (260326800, T12),
+    (276051600, T11),
+    (291776400, T12),
+    (307501200, T11),
+    (323830800, T12),
+    (338950800, T11),
+    (354675600, T12),
+    (370400400, T11),
+    (386125200, T12),
+    (401850000, T11),
+    (417574800, T12),
+    (433299600, T11),
+    (449024400, T12),
+    (465354000, T11),
+    (481078800, T12),
+    (496803600, T11),
+    (512528400, T12),
+    (528253200, T11),
+    (543978000, T12),
+    (559702800, T11),
+    (575427600, T12),
+    (591152400, T11),
+    (606877200, T12),
+    (622602000, T11),
+    (638326800, T12),
+    (654656400, T11),
+    (670381200, T12),
+    (686106000, T11),
+    (701830800, T12),
+    (717555600, T11),
+    (733280400, T12),
+    (749005200, T11),
+    (764730000, T12),
+    (780454800, T11),
+    (796179600, T12),
+    (811904400, T11),
+    (828234000, T12),
+    (846378000, T11),
+    (859683600, T12),
+    (877827600, T11),
+];
+
+/// Every zone and link in the database, sorted by name.
+pub static ZONES: &[StaticTimeZone<'static>] = &[
+    StaticTimeZone { name: "Africa/Abidjan", fixed_timespans: FixedTimespanSet { first: T0, rest: AFRICA_ABIDJAN, extension: Some(PosixTimeZone { standard: T1, daylight: None }) } },
+    StaticTimeZone { name: "Africa/Accra", fixed_timespans: FixedTimespanSet { first: T2, rest: AFRICA_ACCRA, extension: None } },
+    StaticTimeZone { name: "Africa/Addis_Ababa", fixed_timespans: FixedTimespanSet { first: T6, rest: AFRICA_ADDIS_ABABA, extension: Some(PosixTimeZone { standard: T8, daylight: None }) } },
+    StaticTimeZone { name: "Africa/Algiers", fixed_timespans: FixedTimespanSet { first: T9, rest: AFRICA_ALGIERS, extension: Some(PosixTimeZone { standard: T13, daylight: None }) } },
+    StaticTimeZone { name: "Africa/Asmara", fixed_timespans: FixedTimespanSet { first: T15, rest: AFRICA_ASMARA, extension: Some(PosixTimeZone { standard: T8, daylight: None }) } },
+    StaticTimeZone { name: "Africa/Asmera", fixed_timespans: FixedTimespanSet { first: T73, rest: AFRICA_NAIROBI, extension: Some(PosixTimeZone { standard: T8, daylight: None }) } },
+    StaticTimeZone { name: "Africa/Bamako", fixed_timespans: FixedTimespanSet { first: T16, rest: AFRICA_BAMAKO, extension: Some(PosixTimeZone { standard: T1, daylight: None }) } },
+    StaticTimeZone { name: "Africa/Bangui", fixed_timespans: FixedTimespanSet { first: T18, rest: AFRICA_BANGUI, extension: Some(PosixTimeZone { standard: T19, daylight: None }) } },
+    StaticTimeZone { name: "Africa/Banjul", fixed_timespans: FixedTimespanSet { first: T20, rest: AFRICA_BANJUL, extension: Some(PosixTimeZone { standard: T1, daylight: None }) } },
+    StaticTimeZone { name: "Africa/Bissau", fixed_timespans: FixedTimespanSet { first: T21, rest: AFRICA_BISSAU, extension: Some(PosixTimeZone { standard: T1, daylight: None }) } },
+    StaticTimeZone { name: "Africa/Blantyre", fixed_timespans: FixedTimespanSet { first: T22, rest: AFRICA_BLANTYRE, extension: Some(PosixTimeZone { standard: T25, daylight: None }) } },
+    StaticTimeZone { name: "Africa/Brazzaville", fixed_timespans: FixedTimespanSet { first: T26, rest: AFRICA_BRAZZAVILLE, extension: Some(PosixTimeZone { standard: T19, daylight: None }) } },
+    StaticTimeZone { name: "Africa/Bujumbura", fixed_timespans: FixedTimespanSet { first: T27, rest: AFRICA_BUJUMBURA, extension: Some(PosixTimeZone { standard: T25, daylight: None }) } },
+    StaticTimeZone { name: "Africa/Cairo", fixed_timespans: FixedTimespanSet { first: T28, rest: AFRICA_CAIRO, extension: Some(PosixTimeZone { standard: T29, daylight: Some(DaylightRule { timespan: T30, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::April, week: 5, weekday: Weekday::Friday }, time: 0 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Thursday }, time: 86400 } }) }) } },
+    StaticTimeZone { name: "Africa/Casablanca", fixed_timespans: FixedTimespanSet { first: T31, rest: AFRICA_CASABLANCA, extension: None } },
+    StaticTimeZone { name: "Africa/Ceuta", fixed_timespans: FixedTimespanSet { first: T36, rest: AFRICA_CEUTA, extension: Some(PosixTimeZone { standard: T13, daylight: Some(DaylightRule { timespan: T14, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Africa/Conakry", fixed_timespans: FixedTimespanSet { first: T37, rest: AFRICA_CONAKRY, extension: Some(PosixTimeZone { standard: T1, daylight: None }) } },
+    StaticTimeZone { name: "Africa/Dakar", fixed_timespans: FixedTimespanSet { first: T38, rest: AFRICA_DAKAR, extension: Some(PosixTimeZone { standard: T1, daylight: None }) } },
+    StaticTimeZone { name: "Africa/Dar_es_Salaam", fixed_timespans: FixedTimespanSet { first: T39, rest: AFRICA_DAR_ES_SALAAM, extension: Some(PosixTimeZone { standard: T8, daylight: None }) } },
+    StaticTimeZone { name: "Africa/Djibouti", fixed_timespans: FixedTimespanSet { first: T41, rest: AFRICA_DJIBOUTI, extension: Some(PosixTimeZone { standard: T8, daylight: None }) } },
+    StaticTimeZone { name: "Africa/Douala", fixed_timespans: FixedTimespanSet { first: T42, rest: AFRICA_DOUALA, extension: Some(PosixTimeZone { standard: T19, daylight: None }) } },
+    StaticTimeZone { name: "Africa/El_Aaiun", fixed_timespans: FixedTimespanSet { first: T43, rest: AFRICA_EL_AAIUN, extension: None } },
+    StaticTimeZone { name: "Africa/Freetown", fixed_timespans: FixedTimespanSet { first: T44, rest: AFRICA_FREETOWN, extension: Some(PosixTimeZone { standard: T1, daylight: None }) } },
+    StaticTimeZone { name: "Africa/Gaborone", fixed_timespans: FixedTimespanSet { first: T46, rest: AFRICA_GABORONE, extension: Some(PosixTimeZone { standard: T25, daylight: None }) } },
+    StaticTimeZone { name: "Africa/Harare", fixed_timespans: FixedTimespanSet { first: T49, rest: AFRICA_HARARE, extension: Some(PosixTimeZone { standard: T25, daylight: None }) } },
+    StaticTimeZone { name: "Africa/Johannesburg", fixed_timespans: FixedTimespanSet { first: T50, rest: AFRICA_JOHANNESBURG, extension: None } },
+    StaticTimeZone { name: "Africa/Juba", fixed_timespans: FixedTimespanSet { first: T53, rest: AFRICA_JUBA, extension: Some(PosixTimeZone { standard: T25, daylight: None }) } },
+    StaticTimeZone { name: "Africa/Kampala", fixed_timespans: FixedTimespanSet { first: T54, rest: AFRICA_KAMPALA, extension: Some(PosixTimeZone { standard: T8, daylight: None }) } },
+    StaticTimeZone { name: "Africa/Khartoum", fixed_timespans: FixedTimespanSet { first: T56, rest: AFRICA_KHARTOUM, extension: Some(PosixTimeZone { standard: T25, daylight: None }) } },
+    StaticTimeZone { name: "Africa/Kigali", fixed_timespans: FixedTimespanSet { first: T57, rest: AFRICA_KIGALI, extension: Some(PosixTimeZone { standard: T25, daylight: None }) } },
+    StaticTimeZone { name: "Africa/Kinshasa", fixed_timespans: FixedTimespanSet { first: T58, rest: AFRICA_KINSHASA, extension: Some(PosixTimeZone { standard: T19, daylight: None }) } },
+    StaticTimeZone { name: "Africa/Lagos", fixed_timespans: FixedTimespanSet { first: T59, rest: AFRICA_LAGOS, extension: Some(PosixTimeZone { standard: T19, daylight: None }) } },
+    StaticTimeZone { name: "Africa/Libreville", fixed_timespans: FixedTimespanSet { first: T60, rest: AFRICA_LIBREVILLE, extension: Some(PosixTimeZone { standard: T19, daylight: None }) } },
+    StaticTimeZone { name: "Africa/Lome", fixed_timespans: FixedTimespanSet { first: T61, rest: AFRICA_LOME, extension: Some(PosixTimeZone { standard: T1, daylight: None }) } },
+    StaticTimeZone { name: "Africa/Luanda", fixed_timespans: FixedTimespanSet { first: T62, rest: AFRICA_LUANDA, extension: Some(PosixTimeZone { standard: T19, daylight: None }) } },
+    StaticTimeZone { name: "Africa/Lubumbashi", fixed_timespans: FixedTimespanSet { first: T64, rest: AFRICA_LUBUMBASHI, extension: Some(PosixTimeZone { standard: T25, daylight: None }) } },
+    StaticTimeZone { name: "Africa/Lusaka", fixed_timespans: FixedTimespanSet { first: T65, rest: AFRICA_LUSAKA, extension: Some(PosixTimeZone { standard: T25, daylight: None }) } },
+    StaticTimeZone { name: "Africa/Malabo", fixed_timespans: FixedTimespanSet { first: T66, rest: AFRICA_MALABO, extension: Some(PosixTimeZone { standard: T19, daylight: None }) } },
+    StaticTimeZone { name: "Africa/Maputo", fixed_timespans: FixedTimespanSet { first: T67, rest: AFRICA_MAPUTO, extension: Some(PosixTimeZone { standard: T25, daylight: None }) } },
+    StaticTimeZone { name: "Africa/Maseru", fixed_timespans: FixedTimespanSet { first: T68, rest: AFRICA_MASERU, extension: Some(PosixTimeZone { standard: T51, daylight: None }) } },
+    StaticTimeZone { name: "Africa/Mbabane", fixed_timespans: FixedTimespanSet { first: T69, rest: AFRICA_MBABANE, extension: Some(PosixTimeZone { standard: T51, daylight: None }) } },
+    StaticTimeZone { name: "Africa/Mogadishu", fixed_timespans: FixedTimespanSet { first: T70, rest: AFRICA_MOGADISHU, extension: Some(PosixTimeZone { standard: T8, daylight: None }) } },
+    StaticTimeZone { name: "Africa/Monrovia", fixed_timespans: FixedTimespanSet { first: T71, rest: AFRICA_MONROVIA, extension: Some(PosixTimeZone { standard: T1, daylight: None }) } },
+    StaticTimeZone { name: "Africa/Nairobi", fixed_timespans: FixedTimespanSet { first: T73, rest: AFRICA_NAIROBI, extension: Some(PosixTimeZone { standard: T8, daylight: None }) } },
+    StaticTimeZone { name: "Africa/Ndjamena", fixed_timespans: FixedTimespanSet { first: T74, rest: AFRICA_NDJAMENA, extension: Some(PosixTimeZone { standard: T19, daylight: None }) } },
+    StaticTimeZone { name: "Africa/Niamey", fixed_timespans: FixedTimespanSet { first: T76, rest: AFRICA_NIAMEY, extension: Some(PosixTimeZone { standard: T19, daylight: None }) } },
+    StaticTimeZone { name: "Africa/Nouakchott", fixed_timespans: FixedTimespanSet { first: T77, rest: AFRICA_NOUAKCHOTT, extension: Some(PosixTimeZone { standard: T1, daylight: None }) } },
+    StaticTimeZone { name: "Africa/Ouagadougou", fixed_timespans: FixedTimespanSet { first: T78, rest: AFRICA_OUAGADOUGOU, extension: Some(PosixTimeZone { standard: T1, daylight: None }) } },
+    StaticTimeZone { name: "Africa/Porto-Novo", fixed_timespans: FixedTimespanSet { first: T79, rest: AFRICA_PORTO_MINUS_NOVO, extension: Some(PosixTimeZone { standard: T19, daylight: None }) } },
+    StaticTimeZone { name: "Africa/Sao_Tome", fixed_timespans: FixedTimespanSet { first: T80, rest: AFRICA_SAO_TOME, extension: Some(PosixTimeZone { standard: T1, daylight: None }) } },
+    StaticTimeZone { name: "Africa/Timbuktu", fixed_timespans: FixedTimespanSet { first: T0, rest: AFRICA_ABIDJAN, extension: Some(PosixTimeZone { standard: T1, daylight: None }) } },
+    StaticTimeZone { name: "Africa/Tripoli", fixed_timespans: FixedTimespanSet { first: T82, rest: AFRICA_TRIPOLI, extension: Some(PosixTimeZone { standard: T29, daylight: None }) } },
+    StaticTimeZone { name: "Africa/Tunis", fixed_timespans: FixedTimespanSet { first: T83, rest: AFRICA_TUNIS, extension: None } },
+    StaticTimeZone { name: "Africa/Windhoek", fixed_timespans: FixedTimespanSet { first: T84, rest: AFRICA_WINDHOEK, extension: None } },
+    StaticTimeZone { name: "America/Adak", fixed_timespans: FixedTimespanSet { first: T87, rest: AMERICA_ADAK, extension: Some(PosixTimeZone { standard: T94, daylight: Some(DaylightRule { timespan: T93, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Anchorage", fixed_timespans: FixedTimespanSet { first: T95, rest: AMERICA_ANCHORAGE, extension: Some(PosixTimeZone { standard: T102, daylight: Some(DaylightRule { timespan: T101, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Anguilla", fixed_timespans: FixedTimespanSet { first: T103, rest: AMERICA_ANGUILLA, extension: Some(PosixTimeZone { standard: T104, daylight: None }) } },
+    StaticTimeZone { name: "America/Antigua", fixed_timespans: FixedTimespanSet { first: T105, rest: AMERICA_ANTIGUA, extension: Some(PosixTimeZone { standard: T104, daylight: None }) } },
+    StaticTimeZone { name: "America/Araguaina", fixed_timespans: FixedTimespanSet { first: T107, rest: AMERICA_ARAGUAINA, extension: Some(PosixTimeZone { standard: T108, daylight: None }) } },
+    StaticTimeZone { name: "America/Argentina/Buenos_Aires", fixed_timespans: FixedTimespanSet { first: T110, rest: AMERICA_ARGENTINA_BUENOS_AIRES, extension: None } },
+    StaticTimeZone { name: "America/Argentina/Catamarca", fixed_timespans: FixedTimespanSet { first: T114, rest: AMERICA_ARGENTINA_CATAMARCA, extension: Some(PosixTimeZone { standard: T108, daylight: None }) } },
+    StaticTimeZone { name: "America/Argentina/ComodRivadavia", fixed_timespans: FixedTimespanSet { first: T114, rest: AMERICA_ARGENTINA_CATAMARCA, extension: Some(PosixTimeZone { standard: T108, daylight: None }) } },
+    StaticTimeZone { name: "America/Argentina/Cordoba", fixed_timespans: FixedTimespanSet { first: T115, rest: AMERICA_ARGENTINA_CORDOBA, extension: None } },
+    StaticTimeZone { name: "America/Argentina/Jujuy", fixed_timespans: FixedTimespanSet { first: T116, rest: AMERICA_ARGENTINA_JUJUY, extension: Some(PosixTimeZone { standard: T108, daylight: None }) } },
+    StaticTimeZone { name: "America/Argentina/La_Rioja", fixed_timespans: FixedTimespanSet { first: T117, rest: AMERICA_ARGENTINA_LA_RIOJA, extension: Some(PosixTimeZone { standard: T108, daylight: None }) } },
+    StaticTimeZone { name: "America/Argentina/Mendoza", fixed_timespans: FixedTimespanSet { first: T118, rest: AMERICA_ARGENTINA_MENDOZA, extension: Some(PosixTimeZone { standard: T108, daylight: None }) } },
+    StaticTimeZone { name: "America/Argentina/Rio_Gallegos", fixed_timespans: FixedTimespanSet { first: T119, rest: AMERICA_ARGENTINA_RIO_GALLEGOS, extension: Some(PosixTimeZone { standard: T108, daylight: None }) } },
+    StaticTimeZone { name: "America/Argentina/Salta", fixed_timespans: FixedTimespanSet { first: T120, rest: AMERICA_ARGENTINA_SALTA, extension: Some(PosixTimeZone { standard: T108, daylight: None }) } },
+    StaticTimeZone { name: "America/Argentina/San_Juan", fixed_timespans: FixedTimespanSet { first: T121, rest: AMERICA_ARGENTINA_SAN_JUAN, extension: Some(PosixTimeZone { standard: T108, daylight: None }) } },
+    StaticTimeZone { name: "America/Argentina/San_Luis", fixed_timespans: FixedTimespanSet { first: T122, rest: AMERICA_ARGENTINA_SAN_LUIS, extension: Some(PosixTimeZone { standard: T108, daylight: None }) } },
+    StaticTimeZone { name: "America/Argentina/Tucuman", fixed_timespans: FixedTimespanSet { first: T123, rest: AMERICA_ARGENTINA_TUCUMAN, extension: None } },
+    StaticTimeZone { name: "America/Argentina/Ushuaia", fixed_timespans: FixedTimespanSet { first: T124, rest: AMERICA_ARGENTINA_USHUAIA, extension: Some(PosixTimeZone { standard: T108, daylight: None }) } },
+    StaticTimeZone { name: "America/Aruba", fixed_timespans: FixedTimespanSet { first: T125, rest: AMERICA_ARUBA, extension: Some(PosixTimeZone { standard: T104, daylight: None }) } },
+    StaticTimeZone { name: "America/Asuncion", fixed_timespans: FixedTimespanSet { first: T127, rest: AMERICA_ASUNCION, extension: Some(PosixTimeZone { standard: T108, daylight: None }) } },
+    StaticTimeZone { name: "America/Atikokan", fixed_timespans: FixedTimespanSet { first: T128, rest: AMERICA_ATIKOKAN, extension: Some(PosixTimeZone { standard: T106, daylight: None }) } },
+    StaticTimeZone { name: "America/Atka", fixed_timespans: FixedTimespanSet { first: T87, rest: AMERICA_ADAK, extension: Some(PosixTimeZone { standard: T94, daylight: Some(DaylightRule { timespan: T93, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Bahia", fixed_timespans: FixedTimespanSet { first: T131, rest: AMERICA_BAHIA, extension: Some(PosixTimeZone { standard: T108, daylight: None }) } },
+    StaticTimeZone { name: "America/Bahia_Banderas", fixed_timespans: FixedTimespanSet { first: T132, rest: AMERICA_BAHIA_BANDERAS, extension: None } },
+    StaticTimeZone { name: "America/Barbados", fixed_timespans: FixedTimespanSet { first: T135, rest: AMERICA_BARBADOS, extension: None } },
+    StaticTimeZone { name: "America/Belem", fixed_timespans: FixedTimespanSet { first: T138, rest: AMERICA_BELEM, extension: Some(PosixTimeZone { standard: T108, daylight: None }) } },
+    StaticTimeZone { name: "America/Belize", fixed_timespans: FixedTimespanSet { first: T139, rest: AMERICA_BELIZE, extension: None } },
+    StaticTimeZone { name: "America/Blanc-Sablon", fixed_timespans: FixedTimespanSet { first: T142, rest: AMERICA_BLANC_MINUS_SABLON, extension: Some(PosixTimeZone { standard: T104, daylight: None }) } },
+    StaticTimeZone { name: "America/Boa_Vista", fixed_timespans: FixedTimespanSet { first: T144, rest: AMERICA_BOA_VISTA, extension: Some(PosixTimeZone { standard: T112, daylight: None }) } },
+    StaticTimeZone { name: "America/Bogota", fixed_timespans: FixedTimespanSet { first: T145, rest: AMERICA_BOGOTA, extension: None } },
+    StaticTimeZone { name: "America/Boise", fixed_timespans: FixedTimespanSet { first: T148, rest: AMERICA_BOISE, extension: Some(PosixTimeZone { standard: T133, daylight: Some(DaylightRule { timespan: T134, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Buenos_Aires", fixed_timespans: FixedTimespanSet { first: T110, rest: AMERICA_ARGENTINA_BUENOS_AIRES, extension: None } },
+    StaticTimeZone { name: "America/Cambridge_Bay", fixed_timespans: FixedTimespanSet { first: T152, rest: AMERICA_CAMBRIDGE_BAY, extension: Some(PosixTimeZone { standard: T133, daylight: Some(DaylightRule { timespan: T134, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Campo_Grande", fixed_timespans: FixedTimespanSet { first: T153, rest: AMERICA_CAMPO_GRANDE, extension: None } },
+    StaticTimeZone { name: "America/Cancun", fixed_timespans: FixedTimespanSet { first: T154, rest: AMERICA_CANCUN, extension: Some(PosixTimeZone { standard: T106, daylight: None }) } },
+    StaticTimeZone { name: "America/Caracas", fixed_timespans: FixedTimespanSet { first: T156, rest: AMERICA_CARACAS, extension: Some(PosixTimeZone { standard: T112, daylight: None }) } },
+    StaticTimeZone { name: "America/Catamarca", fixed_timespans: FixedTimespanSet { first: T114, rest: AMERICA_ARGENTINA_CATAMARCA, extension: Some(PosixTimeZone { standard: T108, daylight: None }) } },
+    StaticTimeZone { name: "America/Cayenne", fixed_timespans: FixedTimespanSet { first: T158, rest: AMERICA_CAYENNE, extension: Some(PosixTimeZone { standard: T108, daylight: None }) } },
+    StaticTimeZone { name: "America/Cayman", fixed_timespans: FixedTimespanSet { first: T159, rest: AMERICA_CAYMAN, extension: Some(PosixTimeZone { standard: T106, daylight: None }) } },
+    StaticTimeZone { name: "America/Chicago", fixed_timespans: FixedTimespanSet { first: T161, rest: AMERICA_CHICAGO, extension: Some(PosixTimeZone { standard: T129, daylight: Some(DaylightRule { timespan: T130, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Chihuahua", fixed_timespans: FixedTimespanSet { first: T162, rest: AMERICA_CHIHUAHUA, extension: Some(PosixTimeZone { standard: T129, daylight: None }) } },
+    StaticTimeZone { name: "America/Ciudad_Juarez", fixed_timespans: FixedTimespanSet { first: T163, rest: AMERICA_CIUDAD_JUAREZ, extension: Some(PosixTimeZone { standard: T133, daylight: Some(DaylightRule { timespan: T134, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Coral_Harbour", fixed_timespans: FixedTimespanSet { first: T259, rest: AMERICA_PANAMA, extension: Some(PosixTimeZone { standard: T106, daylight: None }) } },
+    StaticTimeZone { name: "America/Cordoba", fixed_timespans: FixedTimespanSet { first: T115, rest: AMERICA_ARGENTINA_CORDOBA, extension: None } },
+    StaticTimeZone { name: "America/Costa_Rica", fixed_timespans: FixedTimespanSet { first: T164, rest: AMERICA_COSTA_RICA, extension: None } },
+    StaticTimeZone { name: "America/Coyhaique", fixed_timespans: FixedTimespanSet { first: T165, rest: AMERICA_COYHAIQUE, extension: Some(PosixTimeZone { standard: T108, daylight: None }) } },
+    StaticTimeZone { name: "America/Creston", fixed_timespans: FixedTimespanSet { first: T167, rest: AMERICA_CRESTON, extension: Some(PosixTimeZone { standard: T133, daylight: None }) } },
+    StaticTimeZone { name: "America/Cuiaba", fixed_timespans: FixedTimespanSet { first: T168, rest: AMERICA_CUIABA, extension: None } },
+    StaticTimeZone { name: "America/Curacao", fixed_timespans: FixedTimespanSet { first: T169, rest: AMERICA_CURACAO, extension: Some(PosixTimeZone { standard: T104, daylight: None }) } },
+    StaticTimeZone { name: "America/Danmarkshavn", fixed_timespans: FixedTimespanSet { first: T170, rest: AMERICA_DANMARKSHAVN, extension: Some(PosixTimeZone { standard: T1, daylight: None }) } },
+    StaticTimeZone { name: "America/Dawson", fixed_timespans: FixedTimespanSet { first: T171, rest: AMERICA_DAWSON, extension: Some(PosixTimeZone { standard: T133, daylight: None }) } },
+    StaticTimeZone { name: "America/Dawson_Creek", fixed_timespans: FixedTimespanSet { first: T176, rest: AMERICA_DAWSON_CREEK, extension: Some(PosixTimeZone { standard: T133, daylight: None }) } },
+    StaticTimeZone { name: "America/Denver", fixed_timespans: FixedTimespanSet { first: T178, rest: AMERICA_DENVER, extension: Some(PosixTimeZone { standard: T133, daylight: Some(DaylightRule { timespan: T134, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Detroit", fixed_timespans: FixedTimespanSet { first: T179, rest: AMERICA_DETROIT, extension: Some(PosixTimeZone { standard: T106, daylight: Some(DaylightRule { timespan: T155, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Dominica", fixed_timespans: FixedTimespanSet { first: T181, rest: AMERICA_DOMINICA, extension: Some(PosixTimeZone { standard: T104, daylight: None }) } },
+    StaticTimeZone { name: "America/Edmonton", fixed_timespans: FixedTimespanSet { first: T182, rest: AMERICA_EDMONTON, extension: Some(PosixTimeZone { standard: T133, daylight: Some(DaylightRule { timespan: T134, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Eirunepe", fixed_timespans: FixedTimespanSet { first: T183, rest: AMERICA_EIRUNEPE, extension: Some(PosixTimeZone { standard: T146, daylight: None }) } },
+    StaticTimeZone { name: "America/El_Salvador", fixed_timespans: FixedTimespanSet { first: T184, rest: AMERICA_EL_SALVADOR, extension: None } },
+    StaticTimeZone { name: "America/Ensenada", fixed_timespans: FixedTimespanSet { first: T292, rest: AMERICA_TIJUANA, extension: Some(PosixTimeZone { standard: T149, daylight: Some(DaylightRule { timespan: T150, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Fort_Nelson", fixed_timespans: FixedTimespanSet { first: T185, rest: AMERICA_FORT_NELSON, extension: Some(PosixTimeZone { standard: T133, daylight: None }) } },
+    StaticTimeZone { name: "America/Fort_Wayne", fixed_timespans: FixedTimespanSet { first: T209, rest: AMERICA_INDIANA_INDIANAPOLIS, extension: Some(PosixTimeZone { standard: T106, daylight: Some(DaylightRule { timespan: T155, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Fortaleza", fixed_timespans: FixedTimespanSet { first: T186, rest: AMERICA_FORTALEZA, extension: Some(PosixTimeZone { standard: T108, daylight: None }) } },
+    StaticTimeZone { name: "America/Glace_Bay", fixed_timespans: FixedTimespanSet { first: T187, rest: AMERICA_GLACE_BAY, extension: Some(PosixTimeZone { standard: T104, daylight: Some(DaylightRule { timespan: T136, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Godthab", fixed_timespans: FixedTimespanSet { first: T257, rest: AMERICA_NUUK, extension: Some(PosixTimeZone { standard: T252, daylight: Some(DaylightRule { timespan: T253, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: -3600 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 0 } }) }) } },
+    StaticTimeZone { name: "America/Goose_Bay", fixed_timespans: FixedTimespanSet { first: T188, rest: AMERICA_GOOSE_BAY, extension: Some(PosixTimeZone { standard: T104, daylight: Some(DaylightRule { timespan: T136, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Grand_Turk", fixed_timespans: FixedTimespanSet { first: T195, rest: AMERICA_GRAND_TURK, extension: Some(PosixTimeZone { standard: T106, daylight: Some(DaylightRule { timespan: T155, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Grenada", fixed_timespans: FixedTimespanSet { first: T196, rest: AMERICA_GRENADA, extension: Some(PosixTimeZone { standard: T104, daylight: None }) } },
+    StaticTimeZone { name: "America/Guadeloupe", fixed_timespans: FixedTimespanSet { first: T197, rest: AMERICA_GUADELOUPE, extension: Some(PosixTimeZone { standard: T104, daylight: None }) } },
+    StaticTimeZone { name: "America/Guatemala", fixed_timespans: FixedTimespanSet { first: T198, rest: AMERICA_GUATEMALA, extension: None } },
+    StaticTimeZone { name: "America/Guayaquil", fixed_timespans: FixedTimespanSet { first: T199, rest: AMERICA_GUAYAQUIL, extension: None } },
+    StaticTimeZone { name: "America/Guyana", fixed_timespans: FixedTimespanSet { first: T201, rest: AMERICA_GUYANA, extension: Some(PosixTimeZone { standard: T112, daylight: None }) } },
+    StaticTimeZone { name: "America/Halifax", fixed_timespans: FixedTimespanSet { first: T203, rest: AMERICA_HALIFAX, extension: Some(PosixTimeZone { standard: T104, daylight: Some(DaylightRule { timespan: T136, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Havana", fixed_timespans: FixedTimespanSet { first: T204, rest: AMERICA_HAVANA, extension: Some(PosixTimeZone { standard: T206, daylight: Some(DaylightRule { timespan: T207, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 0 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 3600 } }) }) } },
+    StaticTimeZone { name: "America/Hermosillo", fixed_timespans: FixedTimespanSet { first: T208, rest: AMERICA_HERMOSILLO, extension: Some(PosixTimeZone { standard: T133, daylight: None }) } },
+    StaticTimeZone { name: "America/Indiana/Indianapolis", fixed_timespans: FixedTimespanSet { first: T209, rest: AMERICA_INDIANA_INDIANAPOLIS, extension: Some(PosixTimeZone { standard: T106, daylight: Some(DaylightRule { timespan: T155, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Indiana/Knox", fixed_timespans: FixedTimespanSet { first: T210, rest: AMERICA_INDIANA_KNOX, extension: Some(PosixTimeZone { standard: T129, daylight: Some(DaylightRule { timespan: T130, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Indiana/Marengo", fixed_timespans: FixedTimespanSet { first: T211, rest: AMERICA_INDIANA_MARENGO, extension: Some(PosixTimeZone { standard: T106, daylight: Some(DaylightRule { timespan: T155, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Indiana/Petersburg", fixed_timespans: FixedTimespanSet { first: T212, rest: AMERICA_INDIANA_PETERSBURG, extension: Some(PosixTimeZone { standard: T106, daylight: Some(DaylightRule { timespan: T155, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Indiana/Tell_City", fixed_timespans: FixedTimespanSet { first: T213, rest: AMERICA_INDIANA_TELL_CITY, extension: Some(PosixTimeZone { standard: T129, daylight: Some(DaylightRule { timespan: T130, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Indiana/Vevay", fixed_timespans: FixedTimespanSet { first: T214, rest: AMERICA_INDIANA_VEVAY, extension: Some(PosixTimeZone { standard: T106, daylight: Some(DaylightRule { timespan: T155, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Indiana/Vincennes", fixed_timespans: FixedTimespanSet { first: T215, rest: AMERICA_INDIANA_VINCENNES, extension: Some(PosixTimeZone { standard: T106, daylight: Some(DaylightRule { timespan: T155, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Indiana/Winamac", fixed_timespans: FixedTimespanSet { first: T216, rest: AMERICA_INDIANA_WINAMAC, extension: Some(PosixTimeZone { standard: T106, daylight: Some(DaylightRule { timespan: T155, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Indianapolis", fixed_timespans: FixedTimespanSet { first: T209, rest: AMERICA_INDIANA_INDIANAPOLIS, extension: Some(PosixTimeZone { standard: T106, daylight: Some(DaylightRule { timespan: T155, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Inuvik", fixed_timespans: FixedTimespanSet { first: T152, rest: AMERICA_INUVIK, extension: Some(PosixTimeZone { standard: T133, daylight: Some(DaylightRule { timespan: T134, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Iqaluit", fixed_timespans: FixedTimespanSet { first: T152, rest: AMERICA_IQALUIT, extension: Some(PosixTimeZone { standard: T106, daylight: Some(DaylightRule { timespan: T155, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Jamaica", fixed_timespans: FixedTimespanSet { first: T217, rest: AMERICA_JAMAICA, extension: Some(PosixTimeZone { standard: T106, daylight: None }) } },
+    StaticTimeZone { name: "America/Jujuy", fixed_timespans: FixedTimespanSet { first: T116, rest: AMERICA_ARGENTINA_JUJUY, extension: Some(PosixTimeZone { standard: T108, daylight: None }) } },
+    StaticTimeZone { name: "America/Juneau", fixed_timespans: FixedTimespanSet { first: T218, rest: AMERICA_JUNEAU, extension: Some(PosixTimeZone { standard: T102, daylight: Some(DaylightRule { timespan: T101, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Kentucky/Louisville", fixed_timespans: FixedTimespanSet { first: T220, rest: AMERICA_KENTUCKY_LOUISVILLE, extension: Some(PosixTimeZone { standard: T106, daylight: Some(DaylightRule { timespan: T155, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Kentucky/Monticello", fixed_timespans: FixedTimespanSet { first: T221, rest: AMERICA_KENTUCKY_MONTICELLO, extension: Some(PosixTimeZone { standard: T106, daylight: Some(DaylightRule { timespan: T155, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Knox_IN", fixed_timespans: FixedTimespanSet { first: T210, rest: AMERICA_INDIANA_KNOX, extension: Some(PosixTimeZone { standard: T129, daylight: Some(DaylightRule { timespan: T130, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Kralendijk", fixed_timespans: FixedTimespanSet { first: T269, rest: AMERICA_PUERTO_RICO, extension: Some(PosixTimeZone { standard: T104, daylight: None }) } },
+    StaticTimeZone { name: "America/La_Paz", fixed_timespans: FixedTimespanSet { first: T222, rest: AMERICA_LA_PAZ, extension: Some(PosixTimeZone { standard: T112, daylight: None }) } },
+    StaticTimeZone { name: "America/Lima", fixed_timespans: FixedTimespanSet { first: T224, rest: AMERICA_LIMA, extension: None } },
+    StaticTimeZone { name: "America/Los_Angeles", fixed_timespans: FixedTimespanSet { first: T226, rest: AMERICA_LOS_ANGELES, extension: Some(PosixTimeZone { standard: T149, daylight: Some(DaylightRule { timespan: T150, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Louisville", fixed_timespans: FixedTimespanSet { first: T220, rest: AMERICA_KENTUCKY_LOUISVILLE, extension: Some(PosixTimeZone { standard: T106, daylight: Some(DaylightRule { timespan: T155, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Lower_Princes", fixed_timespans: FixedTimespanSet { first: T269, rest: AMERICA_PUERTO_RICO, extension: Some(PosixTimeZone { standard: T104, daylight: None }) } },
+    StaticTimeZone { name: "America/Maceio", fixed_timespans: FixedTimespanSet { first: T227, rest: AMERICA_MACEIO, extension: Some(PosixTimeZone { standard: T108, daylight: None }) } },
+    StaticTimeZone { name: "America/Managua", fixed_timespans: FixedTimespanSet { first: T228, rest: AMERICA_MANAGUA, extension: None } },
+    StaticTimeZone { name: "America/Manaus", fixed_timespans: FixedTimespanSet { first: T230, rest: AMERICA_MANAUS, extension: Some(PosixTimeZone { standard: T112, daylight: None }) } },
+    StaticTimeZone { name: "America/Marigot", fixed_timespans: FixedTimespanSet { first: T269, rest: AMERICA_PUERTO_RICO, extension: Some(PosixTimeZone { standard: T104, daylight: None }) } },
+    StaticTimeZone { name: "America/Martinique", fixed_timespans: FixedTimespanSet { first: T231, rest: AMERICA_MARTINIQUE, extension: Some(PosixTimeZone { standard: T104, daylight: None }) } },
+    StaticTimeZone { name: "America/Matamoros", fixed_timespans: FixedTimespanSet { first: T232, rest: AMERICA_MATAMOROS, extension: Some(PosixTimeZone { standard: T129, daylight: Some(DaylightRule { timespan: T130, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Mazatlan", fixed_timespans: FixedTimespanSet { first: T233, rest: AMERICA_MAZATLAN, extension: None } },
+    StaticTimeZone { name: "America/Mendoza", fixed_timespans: FixedTimespanSet { first: T118, rest: AMERICA_ARGENTINA_MENDOZA, extension: Some(PosixTimeZone { standard: T108, daylight: None }) } },
+    StaticTimeZone { name: "America/Menominee", fixed_timespans: FixedTimespanSet { first: T234, rest: AMERICA_MENOMINEE, extension: Some(PosixTimeZone { standard: T129, daylight: Some(DaylightRule { timespan: T130, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Merida", fixed_timespans: FixedTimespanSet { first: T235, rest: AMERICA_MERIDA, extension: None } },
+    StaticTimeZone { name: "America/Metlakatla", fixed_timespans: FixedTimespanSet { first: T236, rest: AMERICA_METLAKATLA, extension: Some(PosixTimeZone { standard: T102, daylight: Some(DaylightRule { timespan: T101, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Mexico_City", fixed_timespans: FixedTimespanSet { first: T238, rest: AMERICA_MEXICO_CITY, extension: None } },
+    StaticTimeZone { name: "America/Miquelon", fixed_timespans: FixedTimespanSet { first: T239, rest: AMERICA_MIQUELON, extension: Some(PosixTimeZone { standard: T108, daylight: Some(DaylightRule { timespan: T109, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Moncton", fixed_timespans: FixedTimespanSet { first: T240, rest: AMERICA_MONCTON, extension: Some(PosixTimeZone { standard: T104, daylight: Some(DaylightRule { timespan: T136, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Monterrey", fixed_timespans: FixedTimespanSet { first: T241, rest: AMERICA_MONTERREY, extension: None } },
+    StaticTimeZone { name: "America/Montevideo", fixed_timespans: FixedTimespanSet { first: T242, rest: AMERICA_MONTEVIDEO, extension: None } },
+    StaticTimeZone { name: "America/Montreal", fixed_timespans: FixedTimespanSet { first: T293, rest: AMERICA_TORONTO, extension: Some(PosixTimeZone { standard: T106, daylight: Some(DaylightRule { timespan: T155, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Montserrat", fixed_timespans: FixedTimespanSet { first: T246, rest: AMERICA_MONTSERRAT, extension: Some(PosixTimeZone { standard: T104, daylight: None }) } },
+    StaticTimeZone { name: "America/Nassau", fixed_timespans: FixedTimespanSet { first: T247, rest: AMERICA_NASSAU, extension: Some(PosixTimeZone { standard: T106, daylight: Some(DaylightRule { timespan: T155, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/New_York", fixed_timespans: FixedTimespanSet { first: T248, rest: AMERICA_NEW_YORK, extension: Some(PosixTimeZone { standard: T106, daylight: Some(DaylightRule { timespan: T155, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Nipigon", fixed_timespans: FixedTimespanSet { first: T293, rest: AMERICA_TORONTO, extension: Some(PosixTimeZone { standard: T106, daylight: Some(DaylightRule { timespan: T155, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Nome", fixed_timespans: FixedTimespanSet { first: T249, rest: AMERICA_NOME, extension: Some(PosixTimeZone { standard: T102, daylight: Some(DaylightRule { timespan: T101, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Noronha", fixed_timespans: FixedTimespanSet { first: T251, rest: AMERICA_NORONHA, extension: Some(PosixTimeZone { standard: T252, daylight: None }) } },
+    StaticTimeZone { name: "America/North_Dakota/Beulah", fixed_timespans: FixedTimespanSet { first: T254, rest: AMERICA_NORTH_DAKOTA_BEULAH, extension: Some(PosixTimeZone { standard: T129, daylight: Some(DaylightRule { timespan: T130, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/North_Dakota/Center", fixed_timespans: FixedTimespanSet { first: T255, rest: AMERICA_NORTH_DAKOTA_CENTER, extension: Some(PosixTimeZone { standard: T129, daylight: Some(DaylightRule { timespan: T130, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/North_Dakota/New_Salem", fixed_timespans: FixedTimespanSet { first: T256, rest: AMERICA_NORTH_DAKOTA_NEW_SALEM, extension: Some(PosixTimeZone { standard: T129, daylight: Some(DaylightRule { timespan: T130, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Nuuk", fixed_timespans: FixedTimespanSet { first: T257, rest: AMERICA_NUUK, extension: Some(PosixTimeZone { standard: T252, daylight: Some(DaylightRule { timespan: T253, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: -3600 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 0 } }) }) } },
+    StaticTimeZone { name: "America/Ojinaga", fixed_timespans: FixedTimespanSet { first: T258, rest: AMERICA_OJINAGA, extension: Some(PosixTimeZone { standard: T129, daylight: Some(DaylightRule { timespan: T130, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Panama", fixed_timespans: FixedTimespanSet { first: T259, rest: AMERICA_PANAMA, extension: Some(PosixTimeZone { standard: T106, daylight: None }) } },
+    StaticTimeZone { name: "America/Pangnirtung", fixed_timespans: FixedTimespanSet { first: T152, rest: AMERICA_IQALUIT, extension: Some(PosixTimeZone { standard: T106, daylight: Some(DaylightRule { timespan: T155, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Paramaribo", fixed_timespans: FixedTimespanSet { first: T261, rest: AMERICA_PARAMARIBO, extension: Some(PosixTimeZone { standard: T108, daylight: None }) } },
+    StaticTimeZone { name: "America/Phoenix", fixed_timespans: FixedTimespanSet { first: T264, rest: AMERICA_PHOENIX, extension: Some(PosixTimeZone { standard: T133, daylight: None }) } },
+    StaticTimeZone { name: "America/Port-au-Prince", fixed_timespans: FixedTimespanSet { first: T265, rest: AMERICA_PORT_MINUS_AU_MINUS_PRINCE, extension: Some(PosixTimeZone { standard: T106, daylight: Some(DaylightRule { timespan: T155, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Port_of_Spain", fixed_timespans: FixedTimespanSet { first: T267, rest: AMERICA_PORT_OF_SPAIN, extension: Some(PosixTimeZone { standard: T104, daylight: None }) } },
+    StaticTimeZone { name: "America/Porto_Acre", fixed_timespans: FixedTimespanSet { first: T273, rest: AMERICA_RIO_BRANCO, extension: Some(PosixTimeZone { standard: T146, daylight: None }) } },
+    StaticTimeZone { name: "America/Porto_Velho", fixed_timespans: FixedTimespanSet { first: T268, rest: AMERICA_PORTO_VELHO, extension: Some(PosixTimeZone { standard: T112, daylight: None }) } },
+    StaticTimeZone { name: "America/Puerto_Rico", fixed_timespans: FixedTimespanSet { first: T269, rest: AMERICA_PUERTO_RICO, extension: Some(PosixTimeZone { standard: T104, daylight: None }) } },
+    StaticTimeZone { name: "America/Punta_Arenas", fixed_timespans: FixedTimespanSet { first: T270, rest: AMERICA_PUNTA_ARENAS, extension: Some(PosixTimeZone { standard: T108, daylight: None }) } },
+    StaticTimeZone { name: "America/Rainy_River", fixed_timespans: FixedTimespanSet { first: T297, rest: AMERICA_WINNIPEG, extension: Some(PosixTimeZone { standard: T129, daylight: Some(DaylightRule { timespan: T130, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Rankin_Inlet", fixed_timespans: FixedTimespanSet { first: T152, rest: AMERICA_RANKIN_INLET, extension: Some(PosixTimeZone { standard: T129, daylight: Some(DaylightRule { timespan: T130, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Recife", fixed_timespans: FixedTimespanSet { first: T271, rest: AMERICA_RECIFE, extension: Some(PosixTimeZone { standard: T108, daylight: None }) } },
+    StaticTimeZone { name: "America/Regina", fixed_timespans: FixedTimespanSet { first: T272, rest: AMERICA_REGINA, extension: Some(PosixTimeZone { standard: T129, daylight: None }) } },
+    StaticTimeZone { name: "America/Resolute", fixed_timespans: FixedTimespanSet { first: T152, rest: AMERICA_RESOLUTE, extension: Some(PosixTimeZone { standard: T129, daylight: Some(DaylightRule { timespan: T130, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Rio_Branco", fixed_timespans: FixedTimespanSet { first: T273, rest: AMERICA_RIO_BRANCO, extension: Some(PosixTimeZone { standard: T146, daylight: None }) } },
+    StaticTimeZone { name: "America/Rosario", fixed_timespans: FixedTimespanSet { first: T115, rest: AMERICA_ARGENTINA_CORDOBA, extension: None } },
+    StaticTimeZone { name: "America/Santa_Isabel", fixed_timespans: FixedTimespanSet { first: T292, rest: AMERICA_TIJUANA, extension: Some(PosixTimeZone { standard: T149, daylight: Some(DaylightRule { timespan: T150, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Santarem", fixed_timespans: FixedTimespanSet { first: T274, rest: AMERICA_SANTAREM, extension: Some(PosixTimeZone { standard: T108, daylight: None }) } },
+    StaticTimeZone { name: "America/Santiago", fixed_timespans: FixedTimespanSet { first: T275, rest: AMERICA_SANTIAGO, extension: Some(PosixTimeZone { standard: T112, daylight: Some(DaylightRule { timespan: T113, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::September, week: 1, weekday: Weekday::Saturday }, time: 86400 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::April, week: 1, weekday: Weekday::Saturday }, time: 86400 } }) }) } },
+    StaticTimeZone { name: "America/Santo_Domingo", fixed_timespans: FixedTimespanSet { first: T276, rest: AMERICA_SANTO_DOMINGO, extension: Some(PosixTimeZone { standard: T104, daylight: None }) } },
+    StaticTimeZone { name: "America/Sao_Paulo", fixed_timespans: FixedTimespanSet { first: T279, rest: AMERICA_SAO_PAULO, extension: None } },
+    StaticTimeZone { name: "America/Scoresbysund", fixed_timespans: FixedTimespanSet { first: T280, rest: AMERICA_SCORESBYSUND, extension: Some(PosixTimeZone { standard: T252, daylight: Some(DaylightRule { timespan: T253, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: -3600 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 0 } }) }) } },
+    StaticTimeZone { name: "America/Shiprock", fixed_timespans: FixedTimespanSet { first: T178, rest: AMERICA_DENVER, extension: Some(PosixTimeZone { standard: T133, daylight: Some(DaylightRule { timespan: T134, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Sitka", fixed_timespans: FixedTimespanSet { first: T281, rest: AMERICA_SITKA, extension: Some(PosixTimeZone { standard: T102, daylight: Some(DaylightRule { timespan: T101, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/St_Barthelemy", fixed_timespans: FixedTimespanSet { first: T269, rest: AMERICA_PUERTO_RICO, extension: Some(PosixTimeZone { standard: T104, daylight: None }) } },
+    StaticTimeZone { name: "America/St_Johns", fixed_timespans: FixedTimespanSet { first: T283, rest: AMERICA_ST_JOHNS, extension: Some(PosixTimeZone { standard: T191, daylight: Some(DaylightRule { timespan: T192, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/St_Kitts", fixed_timespans: FixedTimespanSet { first: T285, rest: AMERICA_ST_KITTS, extension: Some(PosixTimeZone { standard: T104, daylight: None }) } },
+    StaticTimeZone { name: "America/St_Lucia", fixed_timespans: FixedTimespanSet { first: T286, rest: AMERICA_ST_LUCIA, extension: Some(PosixTimeZone { standard: T104, daylight: None }) } },
+    StaticTimeZone { name: "America/St_Thomas", fixed_timespans: FixedTimespanSet { first: T287, rest: AMERICA_ST_THOMAS, extension: Some(PosixTimeZone { standard: T104, daylight: None }) } },
+    StaticTimeZone { name: "America/St_Vincent", fixed_timespans: FixedTimespanSet { first: T288, rest: AMERICA_ST_VINCENT, extension: Some(PosixTimeZone { standard: T104, daylight: None }) } },
+    StaticTimeZone { name: "America/Swift_Current", fixed_timespans: FixedTimespanSet { first: T289, rest: AMERICA_SWIFT_CURRENT, extension: Some(PosixTimeZone { standard: T129, daylight: None }) } },
+    StaticTimeZone { name: "America/Tegucigalpa", fixed_timespans: FixedTimespanSet { first: T290, rest: AMERICA_TEGUCIGALPA, extension: None } },
+    StaticTimeZone { name: "America/Thule", fixed_timespans: FixedTimespanSet { first: T291, rest: AMERICA_THULE, extension: Some(PosixTimeZone { standard: T104, daylight: Some(DaylightRule { timespan: T136, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Thunder_Bay", fixed_timespans: FixedTimespanSet { first: T293, rest: AMERICA_TORONTO, extension: Some(PosixTimeZone { standard: T106, daylight: Some(DaylightRule { timespan: T155, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Tijuana", fixed_timespans: FixedTimespanSet { first: T292, rest: AMERICA_TIJUANA, extension: Some(PosixTimeZone { standard: T149, daylight: Some(DaylightRule { timespan: T150, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Toronto", fixed_timespans: FixedTimespanSet { first: T293, rest: AMERICA_TORONTO, extension: Some(PosixTimeZone { standard: T106, daylight: Some(DaylightRule { timespan: T155, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Tortola", fixed_timespans: FixedTimespanSet { first: T294, rest: AMERICA_TORTOLA, extension: Some(PosixTimeZone { standard: T104, daylight: None }) } },
+    StaticTimeZone { name: "America/Vancouver", fixed_timespans: FixedTimespanSet { first: T295, rest: AMERICA_VANCOUVER, extension: Some(PosixTimeZone { standard: T149, daylight: Some(DaylightRule { timespan: T150, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Virgin", fixed_timespans: FixedTimespanSet { first: T269, rest: AMERICA_PUERTO_RICO, extension: Some(PosixTimeZone { standard: T104, daylight: None }) } },
+    StaticTimeZone { name: "America/Whitehorse", fixed_timespans: FixedTimespanSet { first: T296, rest: AMERICA_WHITEHORSE, extension: Some(PosixTimeZone { standard: T133, daylight: None }) } },
+    StaticTimeZone { name: "America/Winnipeg", fixed_timespans: FixedTimespanSet { first: T297, rest: AMERICA_WINNIPEG, extension: Some(PosixTimeZone { standard: T129, daylight: Some(DaylightRule { timespan: T130, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Yakutat", fixed_timespans: FixedTimespanSet { first: T298, rest: AMERICA_YAKUTAT, extension: Some(PosixTimeZone { standard: T102, daylight: Some(DaylightRule { timespan: T101, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "America/Yellowknife", fixed_timespans: FixedTimespanSet { first: T182, rest: AMERICA_EDMONTON, extension: Some(PosixTimeZone { standard: T133, daylight: Some(DaylightRule { timespan: T134, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "Antarctica/Casey", fixed_timespans: FixedTimespanSet { first: T152, rest: ANTARCTICA_CASEY, extension: Some(PosixTimeZone { standard: T300, daylight: None }) } },
+    StaticTimeZone { name: "Antarctica/Davis", fixed_timespans: FixedTimespanSet { first: T152, rest: ANTARCTICA_DAVIS, extension: Some(PosixTimeZone { standard: T302, daylight: None }) } },
+    StaticTimeZone { name: "Antarctica/DumontDUrville", fixed_timespans: FixedTimespanSet { first: T152, rest: ANTARCTICA_DUMONTDURVILLE, extension: Some(PosixTimeZone { standard: T304, daylight: None }) } },
+    StaticTimeZone { name: "Antarctica/Macquarie", fixed_timespans: FixedTimespanSet { first: T152, rest: ANTARCTICA_MACQUARIE, extension: Some(PosixTimeZone { standard: T305, daylight: Some(DaylightRule { timespan: T306, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 1, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::April, week: 1, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Antarctica/Mawson", fixed_timespans: FixedTimespanSet { first: T152, rest: ANTARCTICA_MAWSON, extension: Some(PosixTimeZone { standard: T303, daylight: None }) } },
+    StaticTimeZone { name: "Antarctica/McMurdo", fixed_timespans: FixedTimespanSet { first: T152, rest: ANTARCTICA_MCMURDO, extension: Some(PosixTimeZone { standard: T308, daylight: Some(DaylightRule { timespan: T309, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::September, week: 5, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::April, week: 1, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Antarctica/Palmer", fixed_timespans: FixedTimespanSet { first: T152, rest: ANTARCTICA_PALMER, extension: Some(PosixTimeZone { standard: T108, daylight: None }) } },
+    StaticTimeZone { name: "Antarctica/Rothera", fixed_timespans: FixedTimespanSet { first: T152, rest: ANTARCTICA_ROTHERA, extension: Some(PosixTimeZone { standard: T108, daylight: None }) } },
+    StaticTimeZone { name: "Antarctica/South_Pole", fixed_timespans: FixedTimespanSet { first: T593, rest: PACIFIC_AUCKLAND, extension: Some(PosixTimeZone { standard: T308, daylight: Some(DaylightRule { timespan: T309, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::September, week: 5, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::April, week: 1, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Antarctica/Syowa", fixed_timespans: FixedTimespanSet { first: T152, rest: ANTARCTICA_SYOWA, extension: Some(PosixTimeZone { standard: T310, daylight: None }) } },
+    StaticTimeZone { name: "Antarctica/Troll", fixed_timespans: FixedTimespanSet { first: T152, rest: ANTARCTICA_TROLL, extension: Some(PosixTimeZone { standard: T32, daylight: Some(DaylightRule { timespan: T311, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 3600 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Antarctica/Vostok", fixed_timespans: FixedTimespanSet { first: T152, rest: ANTARCTICA_VOSTOK, extension: Some(PosixTimeZone { standard: T303, daylight: None }) } },
+    StaticTimeZone { name: "Arctic/Longyearbyen", fixed_timespans: FixedTimespanSet { first: T504, rest: EUROPE_BERLIN, extension: Some(PosixTimeZone { standard: T13, daylight: Some(DaylightRule { timespan: T14, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Asia/Aden", fixed_timespans: FixedTimespanSet { first: T312, rest: ASIA_ADEN, extension: Some(PosixTimeZone { standard: T310, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Almaty", fixed_timespans: FixedTimespanSet { first: T313, rest: ASIA_ALMATY, extension: Some(PosixTimeZone { standard: T303, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Amman", fixed_timespans: FixedTimespanSet { first: T316, rest: ASIA_AMMAN, extension: Some(PosixTimeZone { standard: T310, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Anadyr", fixed_timespans: FixedTimespanSet { first: T317, rest: ASIA_ANADYR, extension: Some(PosixTimeZone { standard: T318, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Aqtau", fixed_timespans: FixedTimespanSet { first: T323, rest: ASIA_AQTAU, extension: Some(PosixTimeZone { standard: T303, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Aqtobe", fixed_timespans: FixedTimespanSet { first: T326, rest: ASIA_AQTOBE, extension: Some(PosixTimeZone { standard: T303, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Ashgabat", fixed_timespans: FixedTimespanSet { first: T327, rest: ASIA_ASHGABAT, extension: Some(PosixTimeZone { standard: T303, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Ashkhabad", fixed_timespans: FixedTimespanSet { first: T327, rest: ASIA_ASHGABAT, extension: Some(PosixTimeZone { standard: T303, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Atyrau", fixed_timespans: FixedTimespanSet { first: T328, rest: ASIA_ATYRAU, extension: Some(PosixTimeZone { standard: T303, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Baghdad", fixed_timespans: FixedTimespanSet { first: T329, rest: ASIA_BAGHDAD, extension: None } },
+    StaticTimeZone { name: "Asia/Bahrain", fixed_timespans: FixedTimespanSet { first: T332, rest: ASIA_BAHRAIN, extension: Some(PosixTimeZone { standard: T310, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Baku", fixed_timespans: FixedTimespanSet { first: T334, rest: ASIA_BAKU, extension: None } },
+    StaticTimeZone { name: "Asia/Bangkok", fixed_timespans: FixedTimespanSet { first: T335, rest: ASIA_BANGKOK, extension: Some(PosixTimeZone { standard: T302, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Barnaul", fixed_timespans: FixedTimespanSet { first: T336, rest: ASIA_BARNAUL, extension: Some(PosixTimeZone { standard: T302, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Beirut", fixed_timespans: FixedTimespanSet { first: T338, rest: ASIA_BEIRUT, extension: Some(PosixTimeZone { standard: T29, daylight: Some(DaylightRule { timespan: T30, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 0 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 0 } }) }) } },
+    StaticTimeZone { name: "Asia/Bishkek", fixed_timespans: FixedTimespanSet { first: T339, rest: ASIA_BISHKEK, extension: Some(PosixTimeZone { standard: T307, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Brunei", fixed_timespans: FixedTimespanSet { first: T340, rest: ASIA_BRUNEI, extension: Some(PosixTimeZone { standard: T300, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Calcutta", fixed_timespans: FixedTimespanSet { first: T389, rest: ASIA_KOLKATA, extension: Some(PosixTimeZone { standard: T391, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Chita", fixed_timespans: FixedTimespanSet { first: T342, rest: ASIA_CHITA, extension: Some(PosixTimeZone { standard: T343, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Choibalsan", fixed_timespans: FixedTimespanSet { first: T442, rest: ASIA_ULAANBAATAR, extension: None } },
+    StaticTimeZone { name: "Asia/Chongqing", fixed_timespans: FixedTimespanSet { first: T430, rest: ASIA_SHANGHAI, extension: None } },
+    StaticTimeZone { name: "Asia/Chungking", fixed_timespans: FixedTimespanSet { first: T430, rest: ASIA_SHANGHAI, extension: None } },
+    StaticTimeZone { name: "Asia/Colombo", fixed_timespans: FixedTimespanSet { first: T346, rest: ASIA_COLOMBO, extension: Some(PosixTimeZone { standard: T348, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Dacca", fixed_timespans: FixedTimespanSet { first: T352, rest: ASIA_DHAKA, extension: None } },
+    StaticTimeZone { name: "Asia/Damascus", fixed_timespans: FixedTimespanSet { first: T351, rest: ASIA_DAMASCUS, extension: Some(PosixTimeZone { standard: T310, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Dhaka", fixed_timespans: FixedTimespanSet { first: T352, rest: ASIA_DHAKA, extension: None } },
+    StaticTimeZone { name: "Asia/Dili", fixed_timespans: FixedTimespanSet { first: T354, rest: ASIA_DILI, extension: Some(PosixTimeZone { standard: T343, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Dubai", fixed_timespans: FixedTimespanSet { first: T355, rest: ASIA_DUBAI, extension: Some(PosixTimeZone { standard: T324, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Dushanbe", fixed_timespans: FixedTimespanSet { first: T356, rest: ASIA_DUSHANBE, extension: Some(PosixTimeZone { standard: T303, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Famagusta", fixed_timespans: FixedTimespanSet { first: T357, rest: ASIA_FAMAGUSTA, extension: Some(PosixTimeZone { standard: T29, daylight: Some(DaylightRule { timespan: T30, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 10800 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 14400 } }) }) } },
+    StaticTimeZone { name: "Asia/Gaza", fixed_timespans: FixedTimespanSet { first: T358, rest: ASIA_GAZA, extension: Some(PosixTimeZone { standard: T29, daylight: Some(DaylightRule { timespan: T30, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 4, weekday: Weekday::Thursday }, time: 180000 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 4, weekday: Weekday::Thursday }, time: 180000 } }) }) } },
+    StaticTimeZone { name: "Asia/Harbin", fixed_timespans: FixedTimespanSet { first: T430, rest: ASIA_SHANGHAI, extension: None } },
+    StaticTimeZone { name: "Asia/Hebron", fixed_timespans: FixedTimespanSet { first: T361, rest: ASIA_HEBRON, extension: Some(PosixTimeZone { standard: T29, daylight: Some(DaylightRule { timespan: T30, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 4, weekday: Weekday::Thursday }, time: 180000 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 4, weekday: Weekday::Thursday }, time: 180000 } }) }) } },
+    StaticTimeZone { name: "Asia/Ho_Chi_Minh", fixed_timespans: FixedTimespanSet { first: T362, rest: ASIA_HO_CHI_MINH, extension: Some(PosixTimeZone { standard: T302, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Hong_Kong", fixed_timespans: FixedTimespanSet { first: T363, rest: ASIA_HONG_KONG, extension: None } },
+    StaticTimeZone { name: "Asia/Hovd", fixed_timespans: FixedTimespanSet { first: T368, rest: ASIA_HOVD, extension: None } },
+    StaticTimeZone { name: "Asia/Irkutsk", fixed_timespans: FixedTimespanSet { first: T369, rest: ASIA_IRKUTSK, extension: Some(PosixTimeZone { standard: T300, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Istanbul", fixed_timespans: FixedTimespanSet { first: T526, rest: EUROPE_ISTANBUL, extension: Some(PosixTimeZone { standard: T310, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Jakarta", fixed_timespans: FixedTimespanSet { first: T370, rest: ASIA_JAKARTA, extension: Some(PosixTimeZone { standard: T372, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Jayapura", fixed_timespans: FixedTimespanSet { first: T373, rest: ASIA_JAYAPURA, extension: Some(PosixTimeZone { standard: T375, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Jerusalem", fixed_timespans: FixedTimespanSet { first: T376, rest: ASIA_JERUSALEM, extension: Some(PosixTimeZone { standard: T359, daylight: Some(DaylightRule { timespan: T360, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 4, weekday: Weekday::Thursday }, time: 93600 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "Asia/Kabul", fixed_timespans: FixedTimespanSet { first: T379, rest: ASIA_KABUL, extension: Some(PosixTimeZone { standard: T380, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Kamchatka", fixed_timespans: FixedTimespanSet { first: T381, rest: ASIA_KAMCHATKA, extension: Some(PosixTimeZone { standard: T318, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Karachi", fixed_timespans: FixedTimespanSet { first: T382, rest: ASIA_KARACHI, extension: None } },
+    StaticTimeZone { name: "Asia/Kashgar", fixed_timespans: FixedTimespanSet { first: T443, rest: ASIA_URUMQI, extension: Some(PosixTimeZone { standard: T307, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Kathmandu", fixed_timespans: FixedTimespanSet { first: T385, rest: ASIA_KATHMANDU, extension: Some(PosixTimeZone { standard: T386, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Katmandu", fixed_timespans: FixedTimespanSet { first: T385, rest: ASIA_KATHMANDU, extension: Some(PosixTimeZone { standard: T386, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Khandyga", fixed_timespans: FixedTimespanSet { first: T387, rest: ASIA_KHANDYGA, extension: Some(PosixTimeZone { standard: T343, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Kolkata", fixed_timespans: FixedTimespanSet { first: T389, rest: ASIA_KOLKATA, extension: Some(PosixTimeZone { standard: T391, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Krasnoyarsk", fixed_timespans: FixedTimespanSet { first: T392, rest: ASIA_KRASNOYARSK, extension: Some(PosixTimeZone { standard: T302, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Kuala_Lumpur", fixed_timespans: FixedTimespanSet { first: T393, rest: ASIA_KUALA_LUMPUR, extension: Some(PosixTimeZone { standard: T300, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Kuching", fixed_timespans: FixedTimespanSet { first: T396, rest: ASIA_KUCHING, extension: Some(PosixTimeZone { standard: T300, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Kuwait", fixed_timespans: FixedTimespanSet { first: T398, rest: ASIA_KUWAIT, extension: Some(PosixTimeZone { standard: T310, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Macao", fixed_timespans: FixedTimespanSet { first: T399, rest: ASIA_MACAU, extension: None } },
+    StaticTimeZone { name: "Asia/Macau", fixed_timespans: FixedTimespanSet { first: T399, rest: ASIA_MACAU, extension: None } },
+    StaticTimeZone { name: "Asia/Magadan", fixed_timespans: FixedTimespanSet { first: T402, rest: ASIA_MAGADAN, extension: Some(PosixTimeZone { standard: T301, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Makassar", fixed_timespans: FixedTimespanSet { first: T403, rest: ASIA_MAKASSAR, extension: Some(PosixTimeZone { standard: T404, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Manila", fixed_timespans: FixedTimespanSet { first: T405, rest: ASIA_MANILA, extension: None } },
+    StaticTimeZone { name: "Asia/Muscat", fixed_timespans: FixedTimespanSet { first: T409, rest: ASIA_MUSCAT, extension: Some(PosixTimeZone { standard: T324, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Nicosia", fixed_timespans: FixedTimespanSet { first: T410, rest: ASIA_NICOSIA, extension: Some(PosixTimeZone { standard: T29, daylight: Some(DaylightRule { timespan: T30, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 10800 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 14400 } }) }) } },
+    StaticTimeZone { name: "Asia/Novokuznetsk", fixed_timespans: FixedTimespanSet { first: T411, rest: ASIA_NOVOKUZNETSK, extension: Some(PosixTimeZone { standard: T302, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Novosibirsk", fixed_timespans: FixedTimespanSet { first: T412, rest: ASIA_NOVOSIBIRSK, extension: Some(PosixTimeZone { standard: T302, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Omsk", fixed_timespans: FixedTimespanSet { first: T413, rest: ASIA_OMSK, extension: Some(PosixTimeZone { standard: T307, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Oral", fixed_timespans: FixedTimespanSet { first: T414, rest: ASIA_ORAL, extension: Some(PosixTimeZone { standard: T303, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Phnom_Penh", fixed_timespans: FixedTimespanSet { first: T415, rest: ASIA_PHNOM_PENH, extension: Some(PosixTimeZone { standard: T302, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Pontianak", fixed_timespans: FixedTimespanSet { first: T417, rest: ASIA_PONTIANAK, extension: Some(PosixTimeZone { standard: T372, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Pyongyang", fixed_timespans: FixedTimespanSet { first: T418, rest: ASIA_PYONGYANG, extension: Some(PosixTimeZone { standard: T420, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Qatar", fixed_timespans: FixedTimespanSet { first: T421, rest: ASIA_QATAR, extension: Some(PosixTimeZone { standard: T310, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Qostanay", fixed_timespans: FixedTimespanSet { first: T422, rest: ASIA_QOSTANAY, extension: Some(PosixTimeZone { standard: T303, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Qyzylorda", fixed_timespans: FixedTimespanSet { first: T423, rest: ASIA_QYZYLORDA, extension: Some(PosixTimeZone { standard: T303, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Rangoon", fixed_timespans: FixedTimespanSet { first: T448, rest: ASIA_YANGON, extension: Some(PosixTimeZone { standard: T350, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Riyadh", fixed_timespans: FixedTimespanSet { first: T424, rest: ASIA_RIYADH, extension: Some(PosixTimeZone { standard: T310, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Saigon", fixed_timespans: FixedTimespanSet { first: T362, rest: ASIA_HO_CHI_MINH, extension: Some(PosixTimeZone { standard: T302, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Sakhalin", fixed_timespans: FixedTimespanSet { first: T425, rest: ASIA_SAKHALIN, extension: Some(PosixTimeZone { standard: T301, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Samarkand", fixed_timespans: FixedTimespanSet { first: T426, rest: ASIA_SAMARKAND, extension: Some(PosixTimeZone { standard: T303, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Seoul", fixed_timespans: FixedTimespanSet { first: T427, rest: ASIA_SEOUL, extension: None } },
+    StaticTimeZone { name: "Asia/Shanghai", fixed_timespans: FixedTimespanSet { first: T430, rest: ASIA_SHANGHAI, extension: None } },
+    StaticTimeZone { name: "Asia/Singapore", fixed_timespans: FixedTimespanSet { first: T431, rest: ASIA_SINGAPORE, extension: Some(PosixTimeZone { standard: T300, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Srednekolymsk", fixed_timespans: FixedTimespanSet { first: T432, rest: ASIA_SREDNEKOLYMSK, extension: Some(PosixTimeZone { standard: T301, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Taipei", fixed_timespans: FixedTimespanSet { first: T433, rest: ASIA_TAIPEI, extension: None } },
+    StaticTimeZone { name: "Asia/Tashkent", fixed_timespans: FixedTimespanSet { first: T434, rest: ASIA_TASHKENT, extension: Some(PosixTimeZone { standard: T303, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Tbilisi", fixed_timespans: FixedTimespanSet { first: T435, rest: ASIA_TBILISI, extension: Some(PosixTimeZone { standard: T324, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Tehran", fixed_timespans: FixedTimespanSet { first: T436, rest: ASIA_TEHRAN, extension: None } },
+    StaticTimeZone { name: "Asia/Tel_Aviv", fixed_timespans: FixedTimespanSet { first: T376, rest: ASIA_JERUSALEM, extension: Some(PosixTimeZone { standard: T359, daylight: Some(DaylightRule { timespan: T360, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 4, weekday: Weekday::Thursday }, time: 93600 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "Asia/Thimbu", fixed_timespans: FixedTimespanSet { first: T438, rest: ASIA_THIMPHU, extension: Some(PosixTimeZone { standard: T307, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Thimphu", fixed_timespans: FixedTimespanSet { first: T438, rest: ASIA_THIMPHU, extension: Some(PosixTimeZone { standard: T307, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Tokyo", fixed_timespans: FixedTimespanSet { first: T439, rest: ASIA_TOKYO, extension: None } },
+    StaticTimeZone { name: "Asia/Tomsk", fixed_timespans: FixedTimespanSet { first: T441, rest: ASIA_TOMSK, extension: Some(PosixTimeZone { standard: T302, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Ujung_Pandang", fixed_timespans: FixedTimespanSet { first: T403, rest: ASIA_MAKASSAR, extension: Some(PosixTimeZone { standard: T404, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Ulaanbaatar", fixed_timespans: FixedTimespanSet { first: T442, rest: ASIA_ULAANBAATAR, extension: None } },
+    StaticTimeZone { name: "Asia/Ulan_Bator", fixed_timespans: FixedTimespanSet { first: T442, rest: ASIA_ULAANBAATAR, extension: None } },
+    StaticTimeZone { name: "Asia/Urumqi", fixed_timespans: FixedTimespanSet { first: T443, rest: ASIA_URUMQI, extension: Some(PosixTimeZone { standard: T307, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Ust-Nera", fixed_timespans: FixedTimespanSet { first: T444, rest: ASIA_UST_MINUS_NERA, extension: Some(PosixTimeZone { standard: T304, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Vientiane", fixed_timespans: FixedTimespanSet { first: T445, rest: ASIA_VIENTIANE, extension: Some(PosixTimeZone { standard: T302, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Vladivostok", fixed_timespans: FixedTimespanSet { first: T446, rest: ASIA_VLADIVOSTOK, extension: Some(PosixTimeZone { standard: T304, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Yakutsk", fixed_timespans: FixedTimespanSet { first: T447, rest: ASIA_YAKUTSK, extension: Some(PosixTimeZone { standard: T343, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Yangon", fixed_timespans: FixedTimespanSet { first: T448, rest: ASIA_YANGON, extension: Some(PosixTimeZone { standard: T350, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Yekaterinburg", fixed_timespans: FixedTimespanSet { first: T449, rest: ASIA_YEKATERINBURG, extension: Some(PosixTimeZone { standard: T303, daylight: None }) } },
+    StaticTimeZone { name: "Asia/Yerevan", fixed_timespans: FixedTimespanSet { first: T451, rest: ASIA_YEREVAN, extension: None } },
+    StaticTimeZone { name: "Atlantic/Azores", fixed_timespans: FixedTimespanSet { first: T452, rest: ATLANTIC_AZORES, extension: Some(PosixTimeZone { standard: T17, daylight: Some(DaylightRule { timespan: T35, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 0 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 3600 } }) }) } },
+    StaticTimeZone { name: "Atlantic/Bermuda", fixed_timespans: FixedTimespanSet { first: T454, rest: ATLANTIC_BERMUDA, extension: Some(PosixTimeZone { standard: T104, daylight: Some(DaylightRule { timespan: T136, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "Atlantic/Canary", fixed_timespans: FixedTimespanSet { first: T457, rest: ATLANTIC_CANARY, extension: Some(PosixTimeZone { standard: T11, daylight: Some(DaylightRule { timespan: T12, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 3600 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "Atlantic/Cape_Verde", fixed_timespans: FixedTimespanSet { first: T458, rest: ATLANTIC_CAPE_VERDE, extension: Some(PosixTimeZone { standard: T17, daylight: None }) } },
+    StaticTimeZone { name: "Atlantic/Faeroe", fixed_timespans: FixedTimespanSet { first: T459, rest: ATLANTIC_FAROE, extension: Some(PosixTimeZone { standard: T11, daylight: Some(DaylightRule { timespan: T12, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 3600 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "Atlantic/Faroe", fixed_timespans: FixedTimespanSet { first: T459, rest: ATLANTIC_FAROE, extension: Some(PosixTimeZone { standard: T11, daylight: Some(DaylightRule { timespan: T12, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 3600 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "Atlantic/Jan_Mayen", fixed_timespans: FixedTimespanSet { first: T504, rest: EUROPE_BERLIN, extension: Some(PosixTimeZone { standard: T13, daylight: Some(DaylightRule { timespan: T14, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Atlantic/Madeira", fixed_timespans: FixedTimespanSet { first: T460, rest: ATLANTIC_MADEIRA, extension: Some(PosixTimeZone { standard: T11, daylight: Some(DaylightRule { timespan: T12, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 3600 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "Atlantic/Reykjavik", fixed_timespans: FixedTimespanSet { first: T461, rest: ATLANTIC_REYKJAVIK, extension: Some(PosixTimeZone { standard: T1, daylight: None }) } },
+    StaticTimeZone { name: "Atlantic/South_Georgia", fixed_timespans: FixedTimespanSet { first: T462, rest: ATLANTIC_SOUTH_GEORGIA, extension: Some(PosixTimeZone { standard: T252, daylight: None }) } },
+    StaticTimeZone { name: "Atlantic/St_Helena", fixed_timespans: FixedTimespanSet { first: T463, rest: ATLANTIC_ST_HELENA, extension: Some(PosixTimeZone { standard: T1, daylight: None }) } },
+    StaticTimeZone { name: "Atlantic/Stanley", fixed_timespans: FixedTimespanSet { first: T464, rest: ATLANTIC_STANLEY, extension: Some(PosixTimeZone { standard: T108, daylight: None }) } },
+    StaticTimeZone { name: "Australia/ACT", fixed_timespans: FixedTimespanSet { first: T484, rest: AUSTRALIA_SYDNEY, extension: Some(PosixTimeZone { standard: T305, daylight: Some(DaylightRule { timespan: T306, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 1, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::April, week: 1, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Australia/Adelaide", fixed_timespans: FixedTimespanSet { first: T465, rest: AUSTRALIA_ADELAIDE, extension: Some(PosixTimeZone { standard: T467, daylight: Some(DaylightRule { timespan: T468, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 1, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::April, week: 1, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Australia/Brisbane", fixed_timespans: FixedTimespanSet { first: T469, rest: AUSTRALIA_BRISBANE, extension: None } },
+    StaticTimeZone { name: "Australia/Broken_Hill", fixed_timespans: FixedTimespanSet { first: T470, rest: AUSTRALIA_BROKEN_HILL, extension: Some(PosixTimeZone { standard: T467, daylight: Some(DaylightRule { timespan: T468, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 1, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::April, week: 1, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Australia/Canberra", fixed_timespans: FixedTimespanSet { first: T484, rest: AUSTRALIA_SYDNEY, extension: Some(PosixTimeZone { standard: T305, daylight: Some(DaylightRule { timespan: T306, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 1, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::April, week: 1, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Australia/Currie", fixed_timespans: FixedTimespanSet { first: T475, rest: AUSTRALIA_HOBART, extension: Some(PosixTimeZone { standard: T305, daylight: Some(DaylightRule { timespan: T306, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 1, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::April, week: 1, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Australia/Darwin", fixed_timespans: FixedTimespanSet { first: T471, rest: AUSTRALIA_DARWIN, extension: None } },
+    StaticTimeZone { name: "Australia/Eucla", fixed_timespans: FixedTimespanSet { first: T472, rest: AUSTRALIA_EUCLA, extension: None } },
+    StaticTimeZone { name: "Australia/Hobart", fixed_timespans: FixedTimespanSet { first: T475, rest: AUSTRALIA_HOBART, extension: Some(PosixTimeZone { standard: T305, daylight: Some(DaylightRule { timespan: T306, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 1, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::April, week: 1, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Australia/LHI", fixed_timespans: FixedTimespanSet { first: T477, rest: AUSTRALIA_LORD_HOWE, extension: Some(PosixTimeZone { standard: T478, daylight: Some(DaylightRule { timespan: T388, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 1, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::April, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "Australia/Lindeman", fixed_timespans: FixedTimespanSet { first: T476, rest: AUSTRALIA_LINDEMAN, extension: None } },
+    StaticTimeZone { name: "Australia/Lord_Howe", fixed_timespans: FixedTimespanSet { first: T477, rest: AUSTRALIA_LORD_HOWE, extension: Some(PosixTimeZone { standard: T478, daylight: Some(DaylightRule { timespan: T388, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 1, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::April, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "Australia/Melbourne", fixed_timespans: FixedTimespanSet { first: T480, rest: AUSTRALIA_MELBOURNE, extension: Some(PosixTimeZone { standard: T305, daylight: Some(DaylightRule { timespan: T306, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 1, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::April, week: 1, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Australia/NSW", fixed_timespans: FixedTimespanSet { first: T484, rest: AUSTRALIA_SYDNEY, extension: Some(PosixTimeZone { standard: T305, daylight: Some(DaylightRule { timespan: T306, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 1, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::April, week: 1, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Australia/North", fixed_timespans: FixedTimespanSet { first: T471, rest: AUSTRALIA_DARWIN, extension: None } },
+    StaticTimeZone { name: "Australia/Perth", fixed_timespans: FixedTimespanSet { first: T481, rest: AUSTRALIA_PERTH, extension: None } },
+    StaticTimeZone { name: "Australia/Queensland", fixed_timespans: FixedTimespanSet { first: T469, rest: AUSTRALIA_BRISBANE, extension: None } },
+    StaticTimeZone { name: "Australia/South", fixed_timespans: FixedTimespanSet { first: T465, rest: AUSTRALIA_ADELAIDE, extension: Some(PosixTimeZone { standard: T467, daylight: Some(DaylightRule { timespan: T468, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 1, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::April, week: 1, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Australia/Sydney", fixed_timespans: FixedTimespanSet { first: T484, rest: AUSTRALIA_SYDNEY, extension: Some(PosixTimeZone { standard: T305, daylight: Some(DaylightRule { timespan: T306, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 1, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::April, week: 1, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Australia/Tasmania", fixed_timespans: FixedTimespanSet { first: T475, rest: AUSTRALIA_HOBART, extension: Some(PosixTimeZone { standard: T305, daylight: Some(DaylightRule { timespan: T306, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 1, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::April, week: 1, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Australia/Victoria", fixed_timespans: FixedTimespanSet { first: T480, rest: AUSTRALIA_MELBOURNE, extension: Some(PosixTimeZone { standard: T305, daylight: Some(DaylightRule { timespan: T306, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 1, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::April, week: 1, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Australia/West", fixed_timespans: FixedTimespanSet { first: T481, rest: AUSTRALIA_PERTH, extension: None } },
+    StaticTimeZone { name: "Australia/Yancowinna", fixed_timespans: FixedTimespanSet { first: T470, rest: AUSTRALIA_BROKEN_HILL, extension: Some(PosixTimeZone { standard: T467, daylight: Some(DaylightRule { timespan: T468, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 1, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::April, week: 1, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Brazil/Acre", fixed_timespans: FixedTimespanSet { first: T273, rest: AMERICA_RIO_BRANCO, extension: Some(PosixTimeZone { standard: T146, daylight: None }) } },
+    StaticTimeZone { name: "Brazil/DeNoronha", fixed_timespans: FixedTimespanSet { first: T251, rest: AMERICA_NORONHA, extension: Some(PosixTimeZone { standard: T252, daylight: None }) } },
+    StaticTimeZone { name: "Brazil/East", fixed_timespans: FixedTimespanSet { first: T279, rest: AMERICA_SAO_PAULO, extension: None } },
+    StaticTimeZone { name: "Brazil/West", fixed_timespans: FixedTimespanSet { first: T230, rest: AMERICA_MANAUS, extension: Some(PosixTimeZone { standard: T112, daylight: None }) } },
+    StaticTimeZone { name: "CET", fixed_timespans: FixedTimespanSet { first: T13, rest: CET, extension: Some(PosixTimeZone { standard: T13, daylight: Some(DaylightRule { timespan: T14, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "CST6CDT", fixed_timespans: FixedTimespanSet { first: T129, rest: CST6CDT, extension: Some(PosixTimeZone { standard: T129, daylight: Some(DaylightRule { timespan: T130, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "Canada/Atlantic", fixed_timespans: FixedTimespanSet { first: T203, rest: AMERICA_HALIFAX, extension: Some(PosixTimeZone { standard: T104, daylight: Some(DaylightRule { timespan: T136, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "Canada/Central", fixed_timespans: FixedTimespanSet { first: T297, rest: AMERICA_WINNIPEG, extension: Some(PosixTimeZone { standard: T129, daylight: Some(DaylightRule { timespan: T130, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "Canada/Eastern", fixed_timespans: FixedTimespanSet { first: T293, rest: AMERICA_TORONTO, extension: Some(PosixTimeZone { standard: T106, daylight: Some(DaylightRule { timespan: T155, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "Canada/Mountain", fixed_timespans: FixedTimespanSet { first: T182, rest: AMERICA_EDMONTON, extension: Some(PosixTimeZone { standard: T133, daylight: Some(DaylightRule { timespan: T134, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "Canada/Newfoundland", fixed_timespans: FixedTimespanSet { first: T283, rest: AMERICA_ST_JOHNS, extension: Some(PosixTimeZone { standard: T191, daylight: Some(DaylightRule { timespan: T192, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "Canada/Pacific", fixed_timespans: FixedTimespanSet { first: T295, rest: AMERICA_VANCOUVER, extension: Some(PosixTimeZone { standard: T149, daylight: Some(DaylightRule { timespan: T150, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "Canada/Saskatchewan", fixed_timespans: FixedTimespanSet { first: T272, rest: AMERICA_REGINA, extension: Some(PosixTimeZone { standard: T129, daylight: None }) } },
+    StaticTimeZone { name: "Canada/Yukon", fixed_timespans: FixedTimespanSet { first: T296, rest: AMERICA_WHITEHORSE, extension: Some(PosixTimeZone { standard: T133, daylight: None }) } },
+    StaticTimeZone { name: "Chile/Continental", fixed_timespans: FixedTimespanSet { first: T275, rest: AMERICA_SANTIAGO, extension: Some(PosixTimeZone { standard: T112, daylight: Some(DaylightRule { timespan: T113, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::September, week: 1, weekday: Weekday::Saturday }, time: 86400 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::April, week: 1, weekday: Weekday::Saturday }, time: 86400 } }) }) } },
+    StaticTimeZone { name: "Chile/EasterIsland", fixed_timespans: FixedTimespanSet { first: T605, rest: PACIFIC_EASTER, extension: Some(PosixTimeZone { standard: T488, daylight: Some(DaylightRule { timespan: T607, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::September, week: 1, weekday: Weekday::Saturday }, time: 79200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::April, week: 1, weekday: Weekday::Saturday }, time: 79200 } }) }) } },
+    StaticTimeZone { name: "Cuba", fixed_timespans: FixedTimespanSet { first: T204, rest: AMERICA_HAVANA, extension: Some(PosixTimeZone { standard: T206, daylight: Some(DaylightRule { timespan: T207, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 0 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 3600 } }) }) } },
+    StaticTimeZone { name: "EET", fixed_timespans: FixedTimespanSet { first: T29, rest: EET, extension: Some(PosixTimeZone { standard: T29, daylight: Some(DaylightRule { timespan: T30, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 10800 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 14400 } }) }) } },
+    StaticTimeZone { name: "EST", fixed_timespans: FixedTimespanSet { first: T106, rest: EST, extension: Some(PosixTimeZone { standard: T106, daylight: None }) } },
+    StaticTimeZone { name: "EST5EDT", fixed_timespans: FixedTimespanSet { first: T106, rest: EST5EDT, extension: Some(PosixTimeZone { standard: T106, daylight: Some(DaylightRule { timespan: T155, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "Egypt", fixed_timespans: FixedTimespanSet { first: T28, rest: AFRICA_CAIRO, extension: Some(PosixTimeZone { standard: T29, daylight: Some(DaylightRule { timespan: T30, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::April, week: 5, weekday: Weekday::Friday }, time: 0 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Thursday }, time: 86400 } }) }) } },
+    StaticTimeZone { name: "Eire", fixed_timespans: FixedTimespanSet { first: T515, rest: EUROPE_DUBLIN, extension: Some(PosixTimeZone { standard: T520, daylight: Some(DaylightRule { timespan: T519, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 3600 } }) }) } },
+    StaticTimeZone { name: "Etc/GMT", fixed_timespans: FixedTimespanSet { first: T1, rest: ETC_GMT, extension: Some(PosixTimeZone { standard: T1, daylight: None }) } },
+    StaticTimeZone { name: "Etc/GMT+0", fixed_timespans: FixedTimespanSet { first: T1, rest: ETC_GMT, extension: Some(PosixTimeZone { standard: T1, daylight: None }) } },
+    StaticTimeZone { name: "Etc/GMT+1", fixed_timespans: FixedTimespanSet { first: T17, rest: ETC_GMT_PLUS_1, extension: Some(PosixTimeZone { standard: T17, daylight: None }) } },
+    StaticTimeZone { name: "Etc/GMT+10", fixed_timespans: FixedTimespanSet { first: T485, rest: ETC_GMT_PLUS_10, extension: Some(PosixTimeZone { standard: T485, daylight: None }) } },
+    StaticTimeZone { name: "Etc/GMT+11", fixed_timespans: FixedTimespanSet { first: T486, rest: ETC_GMT_PLUS_11, extension: Some(PosixTimeZone { standard: T486, daylight: None }) } },
+    StaticTimeZone { name: "Etc/GMT+12", fixed_timespans: FixedTimespanSet { first: T487, rest: ETC_GMT_PLUS_12, extension: Some(PosixTimeZone { standard: T487, daylight: None }) } },
+    StaticTimeZone { name: "Etc/GMT+2", fixed_timespans: FixedTimespanSet { first: T252, rest: ETC_GMT_PLUS_2, extension: Some(PosixTimeZone { standard: T252, daylight: None }) } },
+    StaticTimeZone { name: "Etc/GMT+3", fixed_timespans: FixedTimespanSet { first: T108, rest: ETC_GMT_PLUS_3, extension: Some(PosixTimeZone { standard: T108, daylight: None }) } },
+    StaticTimeZone { name: "Etc/GMT+4", fixed_timespans: FixedTimespanSet { first: T112, rest: ETC_GMT_PLUS_4, extension: Some(PosixTimeZone { standard: T112, daylight: None }) } },
+    StaticTimeZone { name: "Etc/GMT+5", fixed_timespans: FixedTimespanSet { first: T146, rest: ETC_GMT_PLUS_5, extension: Some(PosixTimeZone { standard: T146, daylight: None }) } },
+    StaticTimeZone { name: "Etc/GMT+6", fixed_timespans: FixedTimespanSet { first: T488, rest: ETC_GMT_PLUS_6, extension: Some(PosixTimeZone { standard: T488, daylight: None }) } },
+    StaticTimeZone { name: "Etc/GMT+7", fixed_timespans: FixedTimespanSet { first: T489, rest: ETC_GMT_PLUS_7, extension: Some(PosixTimeZone { standard: T489, daylight: None }) } },
+    StaticTimeZone { name: "Etc/GMT+8", fixed_timespans: FixedTimespanSet { first: T490, rest: ETC_GMT_PLUS_8, extension: Some(PosixTimeZone { standard: T490, daylight: None }) } },
+    StaticTimeZone { name: "Etc/GMT+9", fixed_timespans: FixedTimespanSet { first: T491, rest: ETC_GMT_PLUS_9, extension: Some(PosixTimeZone { standard: T491, daylight: None }) } },
+    StaticTimeZone { name: "Etc/GMT-0", fixed_timespans: FixedTimespanSet { first: T1, rest: ETC_GMT, extension: Some(PosixTimeZone { standard: T1, daylight: None }) } },
+    StaticTimeZone { name: "Etc/GMT-1", fixed_timespans: FixedTimespanSet { first: T34, rest: ETC_GMT_MINUS_1, extension: Some(PosixTimeZone { standard: T34, daylight: None }) } },
+    StaticTimeZone { name: "Etc/GMT-10", fixed_timespans: FixedTimespanSet { first: T304, rest: ETC_GMT_MINUS_10, extension: Some(PosixTimeZone { standard: T304, daylight: None }) } },
+    StaticTimeZone { name: "Etc/GMT-11", fixed_timespans: FixedTimespanSet { first: T301, rest: ETC_GMT_MINUS_11, extension: Some(PosixTimeZone { standard: T301, daylight: None }) } },
+    StaticTimeZone { name: "Etc/GMT-12", fixed_timespans: FixedTimespanSet { first: T318, rest: ETC_GMT_MINUS_12, extension: Some(PosixTimeZone { standard: T318, daylight: None }) } },
+    StaticTimeZone { name: "Etc/GMT-13", fixed_timespans: FixedTimespanSet { first: T319, rest: ETC_GMT_MINUS_13, extension: Some(PosixTimeZone { standard: T319, daylight: None }) } },
+    StaticTimeZone { name: "Etc/GMT-14", fixed_timespans: FixedTimespanSet { first: T492, rest: ETC_GMT_MINUS_14, extension: Some(PosixTimeZone { standard: T492, daylight: None }) } },
+    StaticTimeZone { name: "Etc/GMT-2", fixed_timespans: FixedTimespanSet { first: T493, rest: ETC_GMT_MINUS_2, extension: Some(PosixTimeZone { standard: T493, daylight: None }) } },
+    StaticTimeZone { name: "Etc/GMT-3", fixed_timespans: FixedTimespanSet { first: T310, rest: ETC_GMT_MINUS_3, extension: Some(PosixTimeZone { standard: T310, daylight: None }) } },
+    StaticTimeZone { name: "Etc/GMT-4", fixed_timespans: FixedTimespanSet { first: T324, rest: ETC_GMT_MINUS_4, extension: Some(PosixTimeZone { standard: T324, daylight: None }) } },
+    StaticTimeZone { name: "Etc/GMT-5", fixed_timespans: FixedTimespanSet { first: T303, rest: ETC_GMT_MINUS_5, extension: Some(PosixTimeZone { standard: T303, daylight: None }) } },
+    StaticTimeZone { name: "Etc/GMT-6", fixed_timespans: FixedTimespanSet { first: T307, rest: ETC_GMT_MINUS_6, extension: Some(PosixTimeZone { standard: T307, daylight: None }) } },
+    StaticTimeZone { name: "Etc/GMT-7", fixed_timespans: FixedTimespanSet { first: T302, rest: ETC_GMT_MINUS_7, extension: Some(PosixTimeZone { standard: T302, daylight: None }) } },
+    StaticTimeZone { name: "Etc/GMT-8", fixed_timespans: FixedTimespanSet { first: T300, rest: ETC_GMT_MINUS_8, extension: Some(PosixTimeZone { standard: T300, daylight: None }) } },
+    StaticTimeZone { name: "Etc/GMT-9", fixed_timespans: FixedTimespanSet { first: T343, rest: ETC_GMT_MINUS_9, extension: Some(PosixTimeZone { standard: T343, daylight: None }) } },
+    StaticTimeZone { name: "Etc/GMT0", fixed_timespans: FixedTimespanSet { first: T1, rest: ETC_GMT, extension: Some(PosixTimeZone { standard: T1, daylight: None }) } },
+    StaticTimeZone { name: "Etc/Greenwich", fixed_timespans: FixedTimespanSet { first: T1, rest: ETC_GMT, extension: Some(PosixTimeZone { standard: T1, daylight: None }) } },
+    StaticTimeZone { name: "Etc/UCT", fixed_timespans: FixedTimespanSet { first: T494, rest: ETC_UTC, extension: Some(PosixTimeZone { standard: T494, daylight: None }) } },
+    StaticTimeZone { name: "Etc/UTC", fixed_timespans: FixedTimespanSet { first: T494, rest: ETC_UTC, extension: Some(PosixTimeZone { standard: T494, daylight: None }) } },
+    StaticTimeZone { name: "Etc/Universal", fixed_timespans: FixedTimespanSet { first: T494, rest: ETC_UTC, extension: Some(PosixTimeZone { standard: T494, daylight: None }) } },
+    StaticTimeZone { name: "Etc/Zulu", fixed_timespans: FixedTimespanSet { first: T494, rest: ETC_UTC, extension: Some(PosixTimeZone { standard: T494, daylight: None }) } },
+    StaticTimeZone { name: "Europe/Amsterdam", fixed_timespans: FixedTimespanSet { first: T495, rest: EUROPE_AMSTERDAM, extension: Some(PosixTimeZone { standard: T13, daylight: Some(DaylightRule { timespan: T14, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Europe/Andorra", fixed_timespans: FixedTimespanSet { first: T500, rest: EUROPE_ANDORRA, extension: Some(PosixTimeZone { standard: T13, daylight: Some(DaylightRule { timespan: T14, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Europe/Astrakhan", fixed_timespans: FixedTimespanSet { first: T501, rest: EUROPE_ASTRAKHAN, extension: Some(PosixTimeZone { standard: T324, daylight: None }) } },
+    StaticTimeZone { name: "Europe/Athens", fixed_timespans: FixedTimespanSet { first: T502, rest: EUROPE_ATHENS, extension: Some(PosixTimeZone { standard: T29, daylight: Some(DaylightRule { timespan: T30, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 10800 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 14400 } }) }) } },
+    StaticTimeZone { name: "Europe/Belfast", fixed_timespans: FixedTimespanSet { first: T534, rest: EUROPE_LONDON, extension: Some(PosixTimeZone { standard: T1, daylight: Some(DaylightRule { timespan: T517, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 3600 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "Europe/Belgrade", fixed_timespans: FixedTimespanSet { first: T503, rest: EUROPE_BELGRADE, extension: Some(PosixTimeZone { standard: T13, daylight: Some(DaylightRule { timespan: T14, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Europe/Berlin", fixed_timespans: FixedTimespanSet { first: T504, rest: EUROPE_BERLIN, extension: Some(PosixTimeZone { standard: T13, daylight: Some(DaylightRule { timespan: T14, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Europe/Bratislava", fixed_timespans: FixedTimespanSet { first: T548, rest: EUROPE_PRAGUE, extension: Some(PosixTimeZone { standard: T13, daylight: Some(DaylightRule { timespan: T14, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Europe/Brussels", fixed_timespans: FixedTimespanSet { first: T506, rest: EUROPE_BRUSSELS, extension: Some(PosixTimeZone { standard: T13, daylight: Some(DaylightRule { timespan: T14, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Europe/Bucharest", fixed_timespans: FixedTimespanSet { first: T507, rest: EUROPE_BUCHAREST, extension: Some(PosixTimeZone { standard: T29, daylight: Some(DaylightRule { timespan: T30, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 10800 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 14400 } }) }) } },
+    StaticTimeZone { name: "Europe/Budapest", fixed_timespans: FixedTimespanSet { first: T508, rest: EUROPE_BUDAPEST, extension: Some(PosixTimeZone { standard: T13, daylight: Some(DaylightRule { timespan: T14, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Europe/Busingen", fixed_timespans: FixedTimespanSet { first: T574, rest: EUROPE_ZURICH, extension: Some(PosixTimeZone { standard: T13, daylight: Some(DaylightRule { timespan: T14, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Europe/Chisinau", fixed_timespans: FixedTimespanSet { first: T509, rest: EUROPE_CHISINAU, extension: Some(PosixTimeZone { standard: T29, daylight: Some(DaylightRule { timespan: T30, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Europe/Copenhagen", fixed_timespans: FixedTimespanSet { first: T514, rest: EUROPE_COPENHAGEN, extension: Some(PosixTimeZone { standard: T13, daylight: Some(DaylightRule { timespan: T14, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Europe/Dublin", fixed_timespans: FixedTimespanSet { first: T515, rest: EUROPE_DUBLIN, extension: Some(PosixTimeZone { standard: T520, daylight: Some(DaylightRule { timespan: T519, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 3600 } }) }) } },
+    StaticTimeZone { name: "Europe/Gibraltar", fixed_timespans: FixedTimespanSet { first: T521, rest: EUROPE_GIBRALTAR, extension: Some(PosixTimeZone { standard: T13, daylight: Some(DaylightRule { timespan: T14, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Europe/Guernsey", fixed_timespans: FixedTimespanSet { first: T523, rest: EUROPE_GUERNSEY, extension: Some(PosixTimeZone { standard: T1, daylight: Some(DaylightRule { timespan: T517, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 3600 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "Europe/Helsinki", fixed_timespans: FixedTimespanSet { first: T524, rest: EUROPE_HELSINKI, extension: Some(PosixTimeZone { standard: T29, daylight: Some(DaylightRule { timespan: T30, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 10800 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 14400 } }) }) } },
+    StaticTimeZone { name: "Europe/Isle_of_Man", fixed_timespans: FixedTimespanSet { first: T525, rest: EUROPE_ISLE_OF_MAN, extension: Some(PosixTimeZone { standard: T1, daylight: Some(DaylightRule { timespan: T517, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 3600 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "Europe/Istanbul", fixed_timespans: FixedTimespanSet { first: T526, rest: EUROPE_ISTANBUL, extension: Some(PosixTimeZone { standard: T310, daylight: None }) } },
+    StaticTimeZone { name: "Europe/Jersey", fixed_timespans: FixedTimespanSet { first: T528, rest: EUROPE_JERSEY, extension: Some(PosixTimeZone { standard: T1, daylight: Some(DaylightRule { timespan: T517, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 3600 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "Europe/Kaliningrad", fixed_timespans: FixedTimespanSet { first: T503, rest: EUROPE_KALININGRAD, extension: Some(PosixTimeZone { standard: T29, daylight: None }) } },
+    StaticTimeZone { name: "Europe/Kiev", fixed_timespans: FixedTimespanSet { first: T531, rest: EUROPE_KYIV, extension: Some(PosixTimeZone { standard: T29, daylight: Some(DaylightRule { timespan: T30, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 10800 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 14400 } }) }) } },
+    StaticTimeZone { name: "Europe/Kirov", fixed_timespans: FixedTimespanSet { first: T529, rest: EUROPE_KIROV, extension: Some(PosixTimeZone { standard: T512, daylight: None }) } },
+    StaticTimeZone { name: "Europe/Kyiv", fixed_timespans: FixedTimespanSet { first: T531, rest: EUROPE_KYIV, extension: Some(PosixTimeZone { standard: T29, daylight: Some(DaylightRule { timespan: T30, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 10800 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 14400 } }) }) } },
+    StaticTimeZone { name: "Europe/Lisbon", fixed_timespans: FixedTimespanSet { first: T81, rest: EUROPE_LISBON, extension: Some(PosixTimeZone { standard: T11, daylight: Some(DaylightRule { timespan: T12, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 3600 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "Europe/Ljubljana", fixed_timespans: FixedTimespanSet { first: T533, rest: EUROPE_LJUBLJANA, extension: Some(PosixTimeZone { standard: T13, daylight: Some(DaylightRule { timespan: T14, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Europe/London", fixed_timespans: FixedTimespanSet { first: T534, rest: EUROPE_LONDON, extension: Some(PosixTimeZone { standard: T1, daylight: Some(DaylightRule { timespan: T517, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 3600 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "Europe/Luxembourg", fixed_timespans: FixedTimespanSet { first: T535, rest: EUROPE_LUXEMBOURG, extension: Some(PosixTimeZone { standard: T13, daylight: Some(DaylightRule { timespan: T14, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Europe/Madrid", fixed_timespans: FixedTimespanSet { first: T538, rest: EUROPE_MADRID, extension: Some(PosixTimeZone { standard: T13, daylight: Some(DaylightRule { timespan: T14, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Europe/Malta", fixed_timespans: FixedTimespanSet { first: T533, rest: EUROPE_MALTA, extension: Some(PosixTimeZone { standard: T13, daylight: Some(DaylightRule { timespan: T14, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Europe/Mariehamn", fixed_timespans: FixedTimespanSet { first: T524, rest: EUROPE_HELSINKI, extension: Some(PosixTimeZone { standard: T29, daylight: Some(DaylightRule { timespan: T30, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 10800 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 14400 } }) }) } },
+    StaticTimeZone { name: "Europe/Minsk", fixed_timespans: FixedTimespanSet { first: T539, rest: EUROPE_MINSK, extension: Some(PosixTimeZone { standard: T310, daylight: None }) } },
+    StaticTimeZone { name: "Europe/Monaco", fixed_timespans: FixedTimespanSet { first: T541, rest: EUROPE_MONACO, extension: Some(PosixTimeZone { standard: T13, daylight: Some(DaylightRule { timespan: T14, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Europe/Moscow", fixed_timespans: FixedTimespanSet { first: T542, rest: EUROPE_MOSCOW, extension: Some(PosixTimeZone { standard: T512, daylight: None }) } },
+    StaticTimeZone { name: "Europe/Nicosia", fixed_timespans: FixedTimespanSet { first: T410, rest: ASIA_NICOSIA, extension: Some(PosixTimeZone { standard: T29, daylight: Some(DaylightRule { timespan: T30, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 10800 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 14400 } }) }) } },
+    StaticTimeZone { name: "Europe/Oslo", fixed_timespans: FixedTimespanSet { first: T546, rest: EUROPE_OSLO, extension: Some(PosixTimeZone { standard: T13, daylight: Some(DaylightRule { timespan: T14, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Europe/Paris", fixed_timespans: FixedTimespanSet { first: T547, rest: EUROPE_PARIS, extension: Some(PosixTimeZone { standard: T13, daylight: Some(DaylightRule { timespan: T14, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Europe/Podgorica", fixed_timespans: FixedTimespanSet { first: T503, rest: EUROPE_BELGRADE, extension: Some(PosixTimeZone { standard: T13, daylight: Some(DaylightRule { timespan: T14, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Europe/Prague", fixed_timespans: FixedTimespanSet { first: T548, rest: EUROPE_PRAGUE, extension: Some(PosixTimeZone { standard: T13, daylight: Some(DaylightRule { timespan: T14, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Europe/Riga", fixed_timespans: FixedTimespanSet { first: T549, rest: EUROPE_RIGA, extension: Some(PosixTimeZone { standard: T29, daylight: Some(DaylightRule { timespan: T30, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 10800 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 14400 } }) }) } },
+    StaticTimeZone { name: "Europe/Rome", fixed_timespans: FixedTimespanSet { first: T552, rest: EUROPE_ROME, extension: Some(PosixTimeZone { standard: T13, daylight: Some(DaylightRule { timespan: T14, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Europe/Samara", fixed_timespans: FixedTimespanSet { first: T553, rest: EUROPE_SAMARA, extension: Some(PosixTimeZone { standard: T324, daylight: None }) } },
+    StaticTimeZone { name: "Europe/San_Marino", fixed_timespans: FixedTimespanSet { first: T552, rest: EUROPE_ROME, extension: Some(PosixTimeZone { standard: T13, daylight: Some(DaylightRule { timespan: T14, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Europe/Sarajevo", fixed_timespans: FixedTimespanSet { first: T555, rest: EUROPE_SARAJEVO, extension: Some(PosixTimeZone { standard: T13, daylight: Some(DaylightRule { timespan: T14, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Europe/Saratov", fixed_timespans: FixedTimespanSet { first: T556, rest: EUROPE_SARATOV, extension: Some(PosixTimeZone { standard: T324, daylight: None }) } },
+    StaticTimeZone { name: "Europe/Simferopol", fixed_timespans: FixedTimespanSet { first: T557, rest: EUROPE_SIMFEROPOL, extension: Some(PosixTimeZone { standard: T512, daylight: None }) } },
+    StaticTimeZone { name: "Europe/Skopje", fixed_timespans: FixedTimespanSet { first: T559, rest: EUROPE_SKOPJE, extension: Some(PosixTimeZone { standard: T13, daylight: Some(DaylightRule { timespan: T14, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Europe/Sofia", fixed_timespans: FixedTimespanSet { first: T560, rest: EUROPE_SOFIA, extension: Some(PosixTimeZone { standard: T29, daylight: Some(DaylightRule { timespan: T30, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 10800 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 14400 } }) }) } },
+    StaticTimeZone { name: "Europe/Stockholm", fixed_timespans: FixedTimespanSet { first: T561, rest: EUROPE_STOCKHOLM, extension: Some(PosixTimeZone { standard: T13, daylight: Some(DaylightRule { timespan: T14, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Europe/Tallinn", fixed_timespans: FixedTimespanSet { first: T563, rest: EUROPE_TALLINN, extension: Some(PosixTimeZone { standard: T29, daylight: Some(DaylightRule { timespan: T30, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 10800 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 14400 } }) }) } },
+    StaticTimeZone { name: "Europe/Tirane", fixed_timespans: FixedTimespanSet { first: T565, rest: EUROPE_TIRANE, extension: Some(PosixTimeZone { standard: T13, daylight: Some(DaylightRule { timespan: T14, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Europe/Tiraspol", fixed_timespans: FixedTimespanSet { first: T509, rest: EUROPE_CHISINAU, extension: Some(PosixTimeZone { standard: T29, daylight: Some(DaylightRule { timespan: T30, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Europe/Ulyanovsk", fixed_timespans: FixedTimespanSet { first: T566, rest: EUROPE_ULYANOVSK, extension: Some(PosixTimeZone { standard: T324, daylight: None }) } },
+    StaticTimeZone { name: "Europe/Uzhgorod", fixed_timespans: FixedTimespanSet { first: T531, rest: EUROPE_KYIV, extension: Some(PosixTimeZone { standard: T29, daylight: Some(DaylightRule { timespan: T30, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 10800 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 14400 } }) }) } },
+    StaticTimeZone { name: "Europe/Vaduz", fixed_timespans: FixedTimespanSet { first: T567, rest: EUROPE_VADUZ, extension: Some(PosixTimeZone { standard: T13, daylight: Some(DaylightRule { timespan: T14, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Europe/Vatican", fixed_timespans: FixedTimespanSet { first: T552, rest: EUROPE_ROME, extension: Some(PosixTimeZone { standard: T13, daylight: Some(DaylightRule { timespan: T14, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Europe/Vienna", fixed_timespans: FixedTimespanSet { first: T568, rest: EUROPE_VIENNA, extension: Some(PosixTimeZone { standard: T13, daylight: Some(DaylightRule { timespan: T14, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Europe/Vilnius", fixed_timespans: FixedTimespanSet { first: T569, rest: EUROPE_VILNIUS, extension: Some(PosixTimeZone { standard: T29, daylight: Some(DaylightRule { timespan: T30, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 10800 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 14400 } }) }) } },
+    StaticTimeZone { name: "Europe/Volgograd", fixed_timespans: FixedTimespanSet { first: T329, rest: EUROPE_VOLGOGRAD, extension: Some(PosixTimeZone { standard: T512, daylight: None }) } },
+    StaticTimeZone { name: "Europe/Warsaw", fixed_timespans: FixedTimespanSet { first: T572, rest: EUROPE_WARSAW, extension: Some(PosixTimeZone { standard: T13, daylight: Some(DaylightRule { timespan: T14, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Europe/Zagreb", fixed_timespans: FixedTimespanSet { first: T573, rest: EUROPE_ZAGREB, extension: Some(PosixTimeZone { standard: T13, daylight: Some(DaylightRule { timespan: T14, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Europe/Zaporozhye", fixed_timespans: FixedTimespanSet { first: T531, rest: EUROPE_KYIV, extension: Some(PosixTimeZone { standard: T29, daylight: Some(DaylightRule { timespan: T30, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 10800 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 14400 } }) }) } },
+    StaticTimeZone { name: "Europe/Zurich", fixed_timespans: FixedTimespanSet { first: T574, rest: EUROPE_ZURICH, extension: Some(PosixTimeZone { standard: T13, daylight: Some(DaylightRule { timespan: T14, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Factory", fixed_timespans: FixedTimespanSet { first: T152, rest: FACTORY, extension: Some(PosixTimeZone { standard: T152, daylight: None }) } },
+    StaticTimeZone { name: "GB", fixed_timespans: FixedTimespanSet { first: T534, rest: EUROPE_LONDON, extension: Some(PosixTimeZone { standard: T1, daylight: Some(DaylightRule { timespan: T517, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 3600 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "GB-Eire", fixed_timespans: FixedTimespanSet { first: T534, rest: EUROPE_LONDON, extension: Some(PosixTimeZone { standard: T1, daylight: Some(DaylightRule { timespan: T517, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 3600 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "GMT", fixed_timespans: FixedTimespanSet { first: T1, rest: ETC_GMT, extension: Some(PosixTimeZone { standard: T1, daylight: None }) } },
+    StaticTimeZone { name: "GMT+0", fixed_timespans: FixedTimespanSet { first: T1, rest: ETC_GMT, extension: Some(PosixTimeZone { standard: T1, daylight: None }) } },
+    StaticTimeZone { name: "GMT-0", fixed_timespans: FixedTimespanSet { first: T1, rest: ETC_GMT, extension: Some(PosixTimeZone { standard: T1, daylight: None }) } },
+    StaticTimeZone { name: "GMT0", fixed_timespans: FixedTimespanSet { first: T1, rest: ETC_GMT, extension: Some(PosixTimeZone { standard: T1, daylight: None }) } },
+    StaticTimeZone { name: "Greenwich", fixed_timespans: FixedTimespanSet { first: T1, rest: ETC_GMT, extension: Some(PosixTimeZone { standard: T1, daylight: None }) } },
+    StaticTimeZone { name: "HST", fixed_timespans: FixedTimespanSet { first: T94, rest: HST, extension: Some(PosixTimeZone { standard: T94, daylight: None }) } },
+    StaticTimeZone { name: "Hongkong", fixed_timespans: FixedTimespanSet { first: T363, rest: ASIA_HONG_KONG, extension: None } },
+    StaticTimeZone { name: "Iceland", fixed_timespans: FixedTimespanSet { first: T0, rest: AFRICA_ABIDJAN, extension: Some(PosixTimeZone { standard: T1, daylight: None }) } },
+    StaticTimeZone { name: "Indian/Antananarivo", fixed_timespans: FixedTimespanSet { first: T576, rest: INDIAN_ANTANANARIVO, extension: Some(PosixTimeZone { standard: T8, daylight: None }) } },
+    StaticTimeZone { name: "Indian/Chagos", fixed_timespans: FixedTimespanSet { first: T578, rest: INDIAN_CHAGOS, extension: Some(PosixTimeZone { standard: T307, daylight: None }) } },
+    StaticTimeZone { name: "Indian/Christmas", fixed_timespans: FixedTimespanSet { first: T579, rest: INDIAN_CHRISTMAS, extension: Some(PosixTimeZone { standard: T302, daylight: None }) } },
+    StaticTimeZone { name: "Indian/Cocos", fixed_timespans: FixedTimespanSet { first: T580, rest: INDIAN_COCOS, extension: Some(PosixTimeZone { standard: T350, daylight: None }) } },
+    StaticTimeZone { name: "Indian/Comoro", fixed_timespans: FixedTimespanSet { first: T581, rest: INDIAN_COMORO, extension: Some(PosixTimeZone { standard: T8, daylight: None }) } },
+    StaticTimeZone { name: "Indian/Kerguelen", fixed_timespans: FixedTimespanSet { first: T152, rest: INDIAN_KERGUELEN, extension: Some(PosixTimeZone { standard: T303, daylight: None }) } },
+    StaticTimeZone { name: "Indian/Mahe", fixed_timespans: FixedTimespanSet { first: T582, rest: INDIAN_MAHE, extension: Some(PosixTimeZone { standard: T324, daylight: None }) } },
+    StaticTimeZone { name: "Indian/Maldives", fixed_timespans: FixedTimespanSet { first: T583, rest: INDIAN_MALDIVES, extension: Some(PosixTimeZone { standard: T303, daylight: None }) } },
+    StaticTimeZone { name: "Indian/Mauritius", fixed_timespans: FixedTimespanSet { first: T584, rest: INDIAN_MAURITIUS, extension: None } },
+    StaticTimeZone { name: "Indian/Mayotte", fixed_timespans: FixedTimespanSet { first: T585, rest: INDIAN_MAYOTTE, extension: Some(PosixTimeZone { standard: T8, daylight: None }) } },
+    StaticTimeZone { name: "Indian/Reunion", fixed_timespans: FixedTimespanSet { first: T586, rest: INDIAN_REUNION, extension: Some(PosixTimeZone { standard: T324, daylight: None }) } },
+    StaticTimeZone { name: "Iran", fixed_timespans: FixedTimespanSet { first: T436, rest: ASIA_TEHRAN, extension: None } },
+    StaticTimeZone { name: "Israel", fixed_timespans: FixedTimespanSet { first: T376, rest: ASIA_JERUSALEM, extension: Some(PosixTimeZone { standard: T359, daylight: Some(DaylightRule { timespan: T360, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 4, weekday: Weekday::Thursday }, time: 93600 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "Jamaica", fixed_timespans: FixedTimespanSet { first: T217, rest: AMERICA_JAMAICA, extension: Some(PosixTimeZone { standard: T106, daylight: None }) } },
+    StaticTimeZone { name: "Japan", fixed_timespans: FixedTimespanSet { first: T439, rest: ASIA_TOKYO, extension: None } },
+    StaticTimeZone { name: "Kwajalein", fixed_timespans: FixedTimespanSet { first: T628, rest: PACIFIC_KWAJALEIN, extension: Some(PosixTimeZone { standard: T318, daylight: None }) } },
+    StaticTimeZone { name: "Libya", fixed_timespans: FixedTimespanSet { first: T82, rest: AFRICA_TRIPOLI, extension: Some(PosixTimeZone { standard: T29, daylight: None }) } },
+    StaticTimeZone { name: "MET", fixed_timespans: FixedTimespanSet { first: T587, rest: MET, extension: Some(PosixTimeZone { standard: T587, daylight: Some(DaylightRule { timespan: T588, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "MST", fixed_timespans: FixedTimespanSet { first: T133, rest: MST, extension: Some(PosixTimeZone { standard: T133, daylight: None }) } },
+    StaticTimeZone { name: "MST7MDT", fixed_timespans: FixedTimespanSet { first: T133, rest: MST7MDT, extension: Some(PosixTimeZone { standard: T133, daylight: Some(DaylightRule { timespan: T134, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "Mexico/BajaNorte", fixed_timespans: FixedTimespanSet { first: T292, rest: AMERICA_TIJUANA, extension: Some(PosixTimeZone { standard: T149, daylight: Some(DaylightRule { timespan: T150, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "Mexico/BajaSur", fixed_timespans: FixedTimespanSet { first: T233, rest: AMERICA_MAZATLAN, extension: None } },
+    StaticTimeZone { name: "Mexico/General", fixed_timespans: FixedTimespanSet { first: T238, rest: AMERICA_MEXICO_CITY, extension: None } },
+    StaticTimeZone { name: "NZ", fixed_timespans: FixedTimespanSet { first: T593, rest: PACIFIC_AUCKLAND, extension: Some(PosixTimeZone { standard: T308, daylight: Some(DaylightRule { timespan: T309, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::September, week: 5, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::April, week: 1, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "NZ-CHAT", fixed_timespans: FixedTimespanSet { first: T599, rest: PACIFIC_CHATHAM, extension: Some(PosixTimeZone { standard: T601, daylight: Some(DaylightRule { timespan: T602, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::September, week: 5, weekday: Weekday::Sunday }, time: 9900 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::April, week: 1, weekday: Weekday::Sunday }, time: 13500 } }) }) } },
+    StaticTimeZone { name: "Navajo", fixed_timespans: FixedTimespanSet { first: T178, rest: AMERICA_DENVER, extension: Some(PosixTimeZone { standard: T133, daylight: Some(DaylightRule { timespan: T134, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "PRC", fixed_timespans: FixedTimespanSet { first: T430, rest: ASIA_SHANGHAI, extension: None } },
+    StaticTimeZone { name: "PST8PDT", fixed_timespans: FixedTimespanSet { first: T149, rest: PST8PDT, extension: Some(PosixTimeZone { standard: T149, daylight: Some(DaylightRule { timespan: T150, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "Pacific/Apia", fixed_timespans: FixedTimespanSet { first: T589, rest: PACIFIC_APIA, extension: None } },
+    StaticTimeZone { name: "Pacific/Auckland", fixed_timespans: FixedTimespanSet { first: T593, rest: PACIFIC_AUCKLAND, extension: Some(PosixTimeZone { standard: T308, daylight: Some(DaylightRule { timespan: T309, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::September, week: 5, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::April, week: 1, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Pacific/Bougainville", fixed_timespans: FixedTimespanSet { first: T597, rest: PACIFIC_BOUGAINVILLE, extension: Some(PosixTimeZone { standard: T301, daylight: None }) } },
+    StaticTimeZone { name: "Pacific/Chatham", fixed_timespans: FixedTimespanSet { first: T599, rest: PACIFIC_CHATHAM, extension: Some(PosixTimeZone { standard: T601, daylight: Some(DaylightRule { timespan: T602, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::September, week: 5, weekday: Weekday::Sunday }, time: 9900 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::April, week: 1, weekday: Weekday::Sunday }, time: 13500 } }) }) } },
+    StaticTimeZone { name: "Pacific/Chuuk", fixed_timespans: FixedTimespanSet { first: T603, rest: PACIFIC_CHUUK, extension: Some(PosixTimeZone { standard: T304, daylight: None }) } },
+    StaticTimeZone { name: "Pacific/Easter", fixed_timespans: FixedTimespanSet { first: T605, rest: PACIFIC_EASTER, extension: Some(PosixTimeZone { standard: T488, daylight: Some(DaylightRule { timespan: T607, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::September, week: 1, weekday: Weekday::Saturday }, time: 79200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::April, week: 1, weekday: Weekday::Saturday }, time: 79200 } }) }) } },
+    StaticTimeZone { name: "Pacific/Efate", fixed_timespans: FixedTimespanSet { first: T608, rest: PACIFIC_EFATE, extension: None } },
+    StaticTimeZone { name: "Pacific/Enderbury", fixed_timespans: FixedTimespanSet { first: T152, rest: PACIFIC_KANTON, extension: Some(PosixTimeZone { standard: T319, daylight: None }) } },
+    StaticTimeZone { name: "Pacific/Fakaofo", fixed_timespans: FixedTimespanSet { first: T609, rest: PACIFIC_FAKAOFO, extension: Some(PosixTimeZone { standard: T319, daylight: None }) } },
+    StaticTimeZone { name: "Pacific/Fiji", fixed_timespans: FixedTimespanSet { first: T610, rest: PACIFIC_FIJI, extension: None } },
+    StaticTimeZone { name: "Pacific/Funafuti", fixed_timespans: FixedTimespanSet { first: T611, rest: PACIFIC_FUNAFUTI, extension: Some(PosixTimeZone { standard: T318, daylight: None }) } },
+    StaticTimeZone { name: "Pacific/Galapagos", fixed_timespans: FixedTimespanSet { first: T612, rest: PACIFIC_GALAPAGOS, extension: None } },
+    StaticTimeZone { name: "Pacific/Gambier", fixed_timespans: FixedTimespanSet { first: T613, rest: PACIFIC_GAMBIER, extension: Some(PosixTimeZone { standard: T491, daylight: None }) } },
+    StaticTimeZone { name: "Pacific/Guadalcanal", fixed_timespans: FixedTimespanSet { first: T614, rest: PACIFIC_GUADALCANAL, extension: Some(PosixTimeZone { standard: T301, daylight: None }) } },
+    StaticTimeZone { name: "Pacific/Guam", fixed_timespans: FixedTimespanSet { first: T615, rest: PACIFIC_GUAM, extension: Some(PosixTimeZone { standard: T619, daylight: None }) } },
+    StaticTimeZone { name: "Pacific/Honolulu", fixed_timespans: FixedTimespanSet { first: T620, rest: PACIFIC_HONOLULU, extension: Some(PosixTimeZone { standard: T94, daylight: None }) } },
+    StaticTimeZone { name: "Pacific/Johnston", fixed_timespans: FixedTimespanSet { first: T620, rest: PACIFIC_HONOLULU, extension: Some(PosixTimeZone { standard: T94, daylight: None }) } },
+    StaticTimeZone { name: "Pacific/Kanton", fixed_timespans: FixedTimespanSet { first: T152, rest: PACIFIC_KANTON, extension: Some(PosixTimeZone { standard: T319, daylight: None }) } },
+    StaticTimeZone { name: "Pacific/Kiritimati", fixed_timespans: FixedTimespanSet { first: T624, rest: PACIFIC_KIRITIMATI, extension: Some(PosixTimeZone { standard: T492, daylight: None }) } },
+    StaticTimeZone { name: "Pacific/Kosrae", fixed_timespans: FixedTimespanSet { first: T626, rest: PACIFIC_KOSRAE, extension: Some(PosixTimeZone { standard: T301, daylight: None }) } },
+    StaticTimeZone { name: "Pacific/Kwajalein", fixed_timespans: FixedTimespanSet { first: T628, rest: PACIFIC_KWAJALEIN, extension: Some(PosixTimeZone { standard: T318, daylight: None }) } },
+    StaticTimeZone { name: "Pacific/Majuro", fixed_timespans: FixedTimespanSet { first: T629, rest: PACIFIC_MAJURO, extension: Some(PosixTimeZone { standard: T318, daylight: None }) } },
+    StaticTimeZone { name: "Pacific/Marquesas", fixed_timespans: FixedTimespanSet { first: T630, rest: PACIFIC_MARQUESAS, extension: Some(PosixTimeZone { standard: T631, daylight: None }) } },
+    StaticTimeZone { name: "Pacific/Midway", fixed_timespans: FixedTimespanSet { first: T632, rest: PACIFIC_MIDWAY, extension: Some(PosixTimeZone { standard: T633, daylight: None }) } },
+    StaticTimeZone { name: "Pacific/Nauru", fixed_timespans: FixedTimespanSet { first: T634, rest: PACIFIC_NAURU, extension: Some(PosixTimeZone { standard: T318, daylight: None }) } },
+    StaticTimeZone { name: "Pacific/Niue", fixed_timespans: FixedTimespanSet { first: T636, rest: PACIFIC_NIUE, extension: Some(PosixTimeZone { standard: T486, daylight: None }) } },
+    StaticTimeZone { name: "Pacific/Norfolk", fixed_timespans: FixedTimespanSet { first: T638, rest: PACIFIC_NORFOLK, extension: Some(PosixTimeZone { standard: T301, daylight: Some(DaylightRule { timespan: T322, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 1, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::April, week: 1, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Pacific/Noumea", fixed_timespans: FixedTimespanSet { first: T641, rest: PACIFIC_NOUMEA, extension: None } },
+    StaticTimeZone { name: "Pacific/Pago_Pago", fixed_timespans: FixedTimespanSet { first: T642, rest: PACIFIC_PAGO_PAGO, extension: Some(PosixTimeZone { standard: T633, daylight: None }) } },
+    StaticTimeZone { name: "Pacific/Palau", fixed_timespans: FixedTimespanSet { first: T644, rest: PACIFIC_PALAU, extension: Some(PosixTimeZone { standard: T343, daylight: None }) } },
+    StaticTimeZone { name: "Pacific/Pitcairn", fixed_timespans: FixedTimespanSet { first: T646, rest: PACIFIC_PITCAIRN, extension: Some(PosixTimeZone { standard: T490, daylight: None }) } },
+    StaticTimeZone { name: "Pacific/Pohnpei", fixed_timespans: FixedTimespanSet { first: T648, rest: PACIFIC_POHNPEI, extension: Some(PosixTimeZone { standard: T301, daylight: None }) } },
+    StaticTimeZone { name: "Pacific/Ponape", fixed_timespans: FixedTimespanSet { first: T614, rest: PACIFIC_GUADALCANAL, extension: Some(PosixTimeZone { standard: T301, daylight: None }) } },
+    StaticTimeZone { name: "Pacific/Port_Moresby", fixed_timespans: FixedTimespanSet { first: T650, rest: PACIFIC_PORT_MORESBY, extension: Some(PosixTimeZone { standard: T304, daylight: None }) } },
+    StaticTimeZone { name: "Pacific/Rarotonga", fixed_timespans: FixedTimespanSet { first: T651, rest: PACIFIC_RAROTONGA, extension: None } },
+    StaticTimeZone { name: "Pacific/Saipan", fixed_timespans: FixedTimespanSet { first: T655, rest: PACIFIC_SAIPAN, extension: Some(PosixTimeZone { standard: T619, daylight: None }) } },
+    StaticTimeZone { name: "Pacific/Samoa", fixed_timespans: FixedTimespanSet { first: T642, rest: PACIFIC_PAGO_PAGO, extension: Some(PosixTimeZone { standard: T633, daylight: None }) } },
+    StaticTimeZone { name: "Pacific/Tahiti", fixed_timespans: FixedTimespanSet { first: T657, rest: PACIFIC_TAHITI, extension: Some(PosixTimeZone { standard: T485, daylight: None }) } },
+    StaticTimeZone { name: "Pacific/Tarawa", fixed_timespans: FixedTimespanSet { first: T658, rest: PACIFIC_TARAWA, extension: Some(PosixTimeZone { standard: T318, daylight: None }) } },
+    StaticTimeZone { name: "Pacific/Tongatapu", fixed_timespans: FixedTimespanSet { first: T659, rest: PACIFIC_TONGATAPU, extension: None } },
+    StaticTimeZone { name: "Pacific/Truk", fixed_timespans: FixedTimespanSet { first: T650, rest: PACIFIC_PORT_MORESBY, extension: Some(PosixTimeZone { standard: T304, daylight: None }) } },
+    StaticTimeZone { name: "Pacific/Wake", fixed_timespans: FixedTimespanSet { first: T661, rest: PACIFIC_WAKE, extension: Some(PosixTimeZone { standard: T318, daylight: None }) } },
+    StaticTimeZone { name: "Pacific/Wallis", fixed_timespans: FixedTimespanSet { first: T662, rest: PACIFIC_WALLIS, extension: Some(PosixTimeZone { standard: T318, daylight: None }) } },
+    StaticTimeZone { name: "Pacific/Yap", fixed_timespans: FixedTimespanSet { first: T650, rest: PACIFIC_PORT_MORESBY, extension: Some(PosixTimeZone { standard: T304, daylight: None }) } },
+    StaticTimeZone { name: "Poland", fixed_timespans: FixedTimespanSet { first: T572, rest: EUROPE_WARSAW, extension: Some(PosixTimeZone { standard: T13, daylight: Some(DaylightRule { timespan: T14, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 10800 } }) }) } },
+    StaticTimeZone { name: "Portugal", fixed_timespans: FixedTimespanSet { first: T81, rest: EUROPE_LISBON, extension: Some(PosixTimeZone { standard: T11, daylight: Some(DaylightRule { timespan: T12, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 3600 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "ROC", fixed_timespans: FixedTimespanSet { first: T433, rest: ASIA_TAIPEI, extension: None } },
+    StaticTimeZone { name: "ROK", fixed_timespans: FixedTimespanSet { first: T427, rest: ASIA_SEOUL, extension: None } },
+    StaticTimeZone { name: "Singapore", fixed_timespans: FixedTimespanSet { first: T431, rest: ASIA_SINGAPORE, extension: Some(PosixTimeZone { standard: T300, daylight: None }) } },
+    StaticTimeZone { name: "Turkey", fixed_timespans: FixedTimespanSet { first: T526, rest: EUROPE_ISTANBUL, extension: Some(PosixTimeZone { standard: T310, daylight: None }) } },
+    StaticTimeZone { name: "UCT", fixed_timespans: FixedTimespanSet { first: T494, rest: ETC_UTC, extension: Some(PosixTimeZone { standard: T494, daylight: None }) } },
+    StaticTimeZone { name: "US/Alaska", fixed_timespans: FixedTimespanSet { first: T95, rest: AMERICA_ANCHORAGE, extension: Some(PosixTimeZone { standard: T102, daylight: Some(DaylightRule { timespan: T101, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "US/Aleutian", fixed_timespans: FixedTimespanSet { first: T87, rest: AMERICA_ADAK, extension: Some(PosixTimeZone { standard: T94, daylight: Some(DaylightRule { timespan: T93, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "US/Arizona", fixed_timespans: FixedTimespanSet { first: T264, rest: AMERICA_PHOENIX, extension: Some(PosixTimeZone { standard: T133, daylight: None }) } },
+    StaticTimeZone { name: "US/Central", fixed_timespans: FixedTimespanSet { first: T161, rest: AMERICA_CHICAGO, extension: Some(PosixTimeZone { standard: T129, daylight: Some(DaylightRule { timespan: T130, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "US/East-Indiana", fixed_timespans: FixedTimespanSet { first: T209, rest: AMERICA_INDIANA_INDIANAPOLIS, extension: Some(PosixTimeZone { standard: T106, daylight: Some(DaylightRule { timespan: T155, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "US/Eastern", fixed_timespans: FixedTimespanSet { first: T248, rest: AMERICA_NEW_YORK, extension: Some(PosixTimeZone { standard: T106, daylight: Some(DaylightRule { timespan: T155, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "US/Hawaii", fixed_timespans: FixedTimespanSet { first: T620, rest: PACIFIC_HONOLULU, extension: Some(PosixTimeZone { standard: T94, daylight: None }) } },
+    StaticTimeZone { name: "US/Indiana-Starke", fixed_timespans: FixedTimespanSet { first: T210, rest: AMERICA_INDIANA_KNOX, extension: Some(PosixTimeZone { standard: T129, daylight: Some(DaylightRule { timespan: T130, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "US/Michigan", fixed_timespans: FixedTimespanSet { first: T179, rest: AMERICA_DETROIT, extension: Some(PosixTimeZone { standard: T106, daylight: Some(DaylightRule { timespan: T155, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "US/Mountain", fixed_timespans: FixedTimespanSet { first: T178, rest: AMERICA_DENVER, extension: Some(PosixTimeZone { standard: T133, daylight: Some(DaylightRule { timespan: T134, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "US/Pacific", fixed_timespans: FixedTimespanSet { first: T226, rest: AMERICA_LOS_ANGELES, extension: Some(PosixTimeZone { standard: T149, daylight: Some(DaylightRule { timespan: T150, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday }, time: 7200 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "US/Samoa", fixed_timespans: FixedTimespanSet { first: T642, rest: PACIFIC_PAGO_PAGO, extension: Some(PosixTimeZone { standard: T633, daylight: None }) } },
+    StaticTimeZone { name: "UTC", fixed_timespans: FixedTimespanSet { first: T494, rest: ETC_UTC, extension: Some(PosixTimeZone { standard: T494, daylight: None }) } },
+    StaticTimeZone { name: "Universal", fixed_timespans: FixedTimespanSet { first: T494, rest: ETC_UTC, extension: Some(PosixTimeZone { standard: T494, daylight: None }) } },
+    StaticTimeZone { name: "W-SU", fixed_timespans: FixedTimespanSet { first: T542, rest: EUROPE_MOSCOW, extension: Some(PosixTimeZone { standard: T512, daylight: None }) } },
+    StaticTimeZone { name: "WET", fixed_timespans: FixedTimespanSet { first: T11, rest: WET, extension: Some(PosixTimeZone { standard: T11, daylight: Some(DaylightRule { timespan: T12, start: TransitionRule { day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday }, time: 3600 }, end: TransitionRule { day: RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday }, time: 7200 } }) }) } },
+    StaticTimeZone { name: "Zulu", fixed_timespans: FixedTimespanSet { first: T494, rest: ETC_UTC, extension: Some(PosixTimeZone { standard: T494, daylight: None }) } },
+];
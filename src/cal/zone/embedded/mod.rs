@@ -0,0 +1,60 @@
+//! A copy of the IANA time zone database compiled into the library, for
+//! systems that have no zoneinfo directory.
+//!
+//! This module is only present when the `embedded-tzdata` feature is
+//! enabled. Its tables are generated from the tzdata source files by
+//! `examples/generate_embedded.rs`, and are used by `TimeZone::named`
+//! for any zone that can’t be found on disk.
+
+use cal::zone::{TimeZone, TimeZoneSource};
+
+mod data;
+
+/// The version of the time zone database that was embedded, such as
+/// “2025b”.
+pub use self::data::VERSION;
+
+
+/// Returns the embedded time zone with the given name, such as
+/// “Europe/London”, or `None` if there isn’t one. Links, such as “GB”,
+/// are included too, and keep their own name.
+pub fn lookup(name: &str) -> Option<TimeZone> {
+    data::ZONES.binary_search_by(|zone| zone.name.cmp(name))
+               .ok()
+               .map(|index| TimeZone(TimeZoneSource::Static(&data::ZONES[index])))
+}
+
+/// Returns the names of all the embedded zones and links, in
+/// alphabetical order.
+pub fn zone_names() -> Vec<&'static str> {
+    data::ZONES.iter().map(|zone| zone.name).collect()
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn sorted() {
+        let names = zone_names();
+        assert!(names.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn found() {
+        let zone = lookup("Europe/London").unwrap();
+        assert_eq!(zone.zone_name(), Some("Europe/London"));
+    }
+
+    #[test]
+    fn link() {
+        let zone = lookup("GB").unwrap();
+        assert_eq!(zone.zone_name(), Some("GB"));
+    }
+
+    #[test]
+    fn not_found() {
+        assert!(lookup("Europe/Atlantis").is_none());
+    }
+}
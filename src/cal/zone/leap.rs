@@ -0,0 +1,328 @@
+//! Leap seconds, and converting between UTC and the TAI and GPS time
+//! scales.
+//!
+//! The rest of this library ignores leap seconds: every day is 86,400
+//! seconds long, and an `Instant` counts UTC seconds the same way Unix
+//! time does. Time scales such as TAI and GPS have no leap seconds, so
+//! converting to them means knowing how many have been inserted so far.
+//! That’s what a `LeapSecondTable` holds, read either from the leap second
+//! records in a TZif file from the `right/` zoneinfo directory, or from the
+//! `leap-seconds.list` file published by the IERS.
+//!
+//! Unix time has no timestamp of its own for an inserted leap second such
+//! as 23:59:60, so it shares the timestamp of 23:59:59, the second before
+//! it.
+
+use std::error::Error as ErrorTrait;
+use std::fmt;
+
+use cal::zone::tzif::Tzif;
+use duration::Duration;
+use instant::Instant;
+
+
+/// The difference between TAI and UTC when UTC began counting whole leap
+/// seconds at the start of 1972, in seconds.
+pub const INITIAL_TAI_OFFSET: i32 = 10;
+
+/// The difference between TAI and GPS time, in seconds. GPS time was set
+/// to UTC at its epoch, when TAI was 19 seconds ahead, and has not had any
+/// leap seconds since.
+pub const GPS_TAI_OFFSET: i32 = 19;
+
+/// The Unix timestamp of the GPS epoch, 00:00:00 UTC on 6th January 1980.
+pub const GPS_EPOCH: i64 = 315_964_800;
+
+/// The difference between the Unix epoch and the NTP epoch of 1st January
+/// 1900, which `leap-seconds.list` uses for its timestamps.
+const NTP_UNIX_OFFSET: i64 = 2_208_988_800;
+
+
+/// A change in the difference between TAI and UTC.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct LeapSecond {
+
+    /// The Unix timestamp at which the new difference takes effect. This is
+    /// midnight UTC at the end of the day containing the leap second.
+    pub time: i64,
+
+    /// The number of seconds that TAI is ahead of UTC from this time on.
+    pub tai_offset: i32,
+}
+
+/// A table of every leap second, used to convert between UTC and the
+/// TAI and GPS time scales.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct LeapSecondTable {
+    records: Vec<LeapSecond>,
+    expires: Option<i64>,
+}
+
+impl LeapSecondTable {
+
+    /// Creates a table from a list of leap seconds, which must be sorted
+    /// by time, and the Unix timestamp at which the table expires, if it
+    /// has one.
+    pub fn new(records: Vec<LeapSecond>, expires: Option<i64>) -> Result<Self, Error> {
+        for (index, pair) in records.windows(2).enumerate() {
+            if pair[0].time >= pair[1].time {
+                return Err(Error::UnsortedRecords { index: index + 1 });
+            }
+        }
+
+        Ok(Self { records, expires })
+    }
+
+    /// Reads the leap second records from a parsed TZif file.
+    ///
+    /// Each TZif record gives the time of a correction in the leap-second
+    /// time scale, counting the leap seconds before it, along with the
+    /// total correction since 1972. A final record that repeats the
+    /// previous correction marks when the table expires.
+    pub fn from_tzif(tzif: &Tzif) -> Result<Self, Error> {
+        let mut records = Vec::with_capacity(tzif.leap_seconds.len());
+        let mut expires = None;
+        let mut previous = 0;
+
+        for (index, &(occurrence, correction)) in tzif.leap_seconds.iter().enumerate() {
+            let time = occurrence - i64::from(previous);
+
+            if correction == previous && index > 0 && index == tzif.leap_seconds.len() - 1 {
+                expires = Some(time);
+                break;
+            }
+
+            records.push(LeapSecond { time, tai_offset: INITIAL_TAI_OFFSET + correction });
+            previous = correction;
+        }
+
+        Self::new(records, expires)
+    }
+
+    /// Parses the contents of a `leap-seconds.list` file.
+    ///
+    /// Each line of the file gives an NTP timestamp and the difference
+    /// between TAI and UTC from that time on. The first line, for the start
+    /// of 1972, sets the initial difference rather than adding a leap
+    /// second, so it’s skipped. The expiry date comes from the `#@` line.
+    pub fn parse_list(input: &str) -> Result<Self, Error> {
+        let mut records = Vec::new();
+        let mut expires = None;
+        let mut previous = INITIAL_TAI_OFFSET;
+
+        for (number, line) in input.lines().enumerate() {
+            let invalid = || Error::InvalidLine { line: number + 1 };
+
+            if let Some(expiry) = line.strip_prefix("#@") {
+                let ntp: i64 = expiry.trim().parse().map_err(|_| invalid())?;
+                expires = Some(ntp - NTP_UNIX_OFFSET);
+                continue;
+            }
+
+            let data = line.split('#').next().unwrap_or("");
+            if data.trim().is_empty() {
+                continue;
+            }
+
+            let mut fields = data.split_whitespace();
+            let ntp: i64 = fields.next().and_then(|f| f.parse().ok()).ok_or_else(invalid)?;
+            let tai_offset: i32 = fields.next().and_then(|f| f.parse().ok()).ok_or_else(invalid)?;
+            if fields.next().is_some() {
+                return Err(invalid());
+            }
+
+            if tai_offset != previous {
+                records.push(LeapSecond { time: ntp - NTP_UNIX_OFFSET, tai_offset });
+                previous = tai_offset;
+            }
+        }
+
+        Self::new(records, expires)
+    }
+
+    /// Returns every leap second in this table, in order.
+    pub fn records(&self) -> &[LeapSecond] {
+        &self.records
+    }
+
+    /// Returns the instant after which this table can no longer be relied
+    /// upon, as more leap seconds may have been announced, if it has one.
+    pub fn expires(&self) -> Option<Instant> {
+        self.expires.map(Instant::at)
+    }
+
+    /// Returns the number of seconds that TAI is ahead of UTC at the given
+    /// instant. Instants before 1972 use the initial difference of ten
+    /// seconds.
+    pub fn tai_offset(&self, instant: Instant) -> i32 {
+        let index = self.records.partition_point(|r| r.time <= instant.seconds());
+        self.offset_before(index)
+    }
+
+    fn offset_before(&self, index: usize) -> i32 {
+        match index {
+            0  => INITIAL_TAI_OFFSET,
+            i  => self.records[i - 1].tai_offset,
+        }
+    }
+
+    /// Returns whether the given instant falls on an inserted leap second.
+    ///
+    /// As Unix time has no timestamp for the leap second itself, this is
+    /// true for the second that gets repeated: 23:59:59 UTC on the day a
+    /// leap second is inserted.
+    pub fn is_leap_second(&self, instant: Instant) -> bool {
+        let index = self.records.partition_point(|r| r.time <= instant.seconds());
+        match self.records.get(index) {
+            Some(next)  => next.time - 1 == instant.seconds() && next.tai_offset > self.offset_before(index),
+            None        => false,
+        }
+    }
+
+    /// Converts a UTC instant to TAI, counting seconds since 00:00:00 TAI
+    /// on 1st January 1970, the same as Linux’s `CLOCK_TAI`.
+    pub fn to_tai(&self, instant: Instant) -> Instant {
+        instant.saturating_add(Duration::of(i64::from(self.tai_offset(instant))))
+    }
+
+    /// Converts a TAI instant, counting seconds since 00:00:00 TAI on 1st
+    /// January 1970, to UTC.
+    ///
+    /// Both the TAI second of an inserted leap second and the one before it
+    /// convert to 23:59:59 UTC, as Unix time can’t tell them apart.
+    pub fn from_tai(&self, tai: Instant) -> Instant {
+        let index = self.records.partition_point(|r| r.time.saturating_add(i64::from(r.tai_offset)) <= tai.seconds());
+        let utc = tai.saturating_sub(Duration::of(i64::from(self.offset_before(index))));
+
+        match self.records.get(index) {
+            Some(next) if utc.seconds() >= next.time  => Instant::at_ns(next.time.saturating_sub(1), tai.nanoseconds()),
+            _                                         => utc,
+        }
+    }
+
+    /// Converts a UTC instant to GPS time, counting seconds since the GPS
+    /// epoch of 00:00:00 UTC on 6th January 1980.
+    pub fn to_gps(&self, instant: Instant) -> Instant {
+        self.to_tai(instant).saturating_sub(Duration::of(GPS_EPOCH + i64::from(GPS_TAI_OFFSET)))
+    }
+
+    /// Converts a GPS time, counting seconds since the GPS epoch of
+    /// 00:00:00 UTC on 6th January 1980, to UTC.
+    pub fn from_gps(&self, gps: Instant) -> Instant {
+        self.from_tai(gps.saturating_add(Duration::of(GPS_EPOCH + i64::from(GPS_TAI_OFFSET))))
+    }
+}
+
+
+/// An error that can occur when reading a leap second table.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum Error {
+
+    /// A line of a `leap-seconds.list` file could not be parsed.
+    InvalidLine { line: usize },
+
+    /// The record at this index is not later than the one before it.
+    UnsortedRecords { index: usize },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::InvalidLine { line }        => write!(f, "line {}: invalid leap second line", line),
+            Error::UnsortedRecords { index }   => write!(f, "leap second {} is not in ascending order", index),
+        }
+    }
+}
+
+impl ErrorTrait for Error {
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn table() -> LeapSecondTable {
+        LeapSecondTable::parse_list("\
+            #$ 3676924800\n\
+            #@ 3991593600\n\
+            2272060800  10  # 1 Jan 1972\n\
+            2287785600  11  # 1 Jul 1972\n\
+            2303683200  12  # 1 Jan 1973\n").unwrap()
+    }
+
+    #[test]
+    fn parse_list() {
+        let table = table();
+        assert_eq!(table.records(), &[
+            LeapSecond { time: 78_796_800, tai_offset: 11 },
+            LeapSecond { time: 94_694_400, tai_offset: 12 },
+        ]);
+        assert_eq!(table.expires(), Some(Instant::at(3_991_593_600 - NTP_UNIX_OFFSET)));
+    }
+
+    #[test]
+    fn invalid_line() {
+        assert_eq!(LeapSecondTable::parse_list("# header\n2272060800\n"), Err(Error::InvalidLine { line: 2 }));
+        assert_eq!(LeapSecondTable::parse_list("2272060800 ten\n"), Err(Error::InvalidLine { line: 1 }));
+    }
+
+    #[test]
+    fn unsorted() {
+        let records = vec![ LeapSecond { time: 20, tai_offset: 11 }, LeapSecond { time: 10, tai_offset: 12 } ];
+        assert_eq!(LeapSecondTable::new(records, None), Err(Error::UnsortedRecords { index: 1 }));
+    }
+
+    #[test]
+    fn offsets() {
+        let table = table();
+        assert_eq!(table.tai_offset(Instant::at(0)), 10);
+        assert_eq!(table.tai_offset(Instant::at(78_796_799)), 10);
+        assert_eq!(table.tai_offset(Instant::at(78_796_800)), 11);
+        assert_eq!(table.tai_offset(Instant::at(100_000_000)), 12);
+    }
+
+    #[test]
+    fn leap_second() {
+        let table = table();
+        assert!(!table.is_leap_second(Instant::at(78_796_798)));
+        assert!(table.is_leap_second(Instant::at(78_796_799)));
+        assert!(!table.is_leap_second(Instant::at(78_796_800)));
+        assert!(table.is_leap_second(Instant::at(94_694_399)));
+    }
+
+    #[test]
+    fn through_a_leap_second() {
+        let table = table();
+        let utc: Vec<i64> = (78_796_808 .. 78_796_812).map(|s| table.from_tai(Instant::at(s)).seconds()).collect();
+        assert_eq!(utc, vec![ 78_796_798, 78_796_799, 78_796_799, 78_796_800 ]);
+    }
+
+    #[test]
+    fn range_ends() {
+        let table = table();
+        assert_eq!(table.to_tai(Instant::MAX), Instant::MAX);
+        assert_eq!(table.from_tai(Instant::MIN), Instant::MIN);
+        assert_eq!(table.to_gps(Instant::MIN), Instant::MIN);
+        assert_eq!(table.from_gps(Instant::MAX), Instant::MAX.saturating_sub(Duration::of(12)));
+
+        let records = vec![ LeapSecond { time: i64::MAX, tai_offset: 11 } ];
+        let table = LeapSecondTable::new(records, None).unwrap();
+        assert_eq!(table.from_tai(Instant::MAX), Instant::MAX.saturating_sub(Duration::of(10)));
+    }
+
+    #[test]
+    fn from_tzif() {
+        let tzif = Tzif {
+            version: 4,
+            transitions: Vec::new(),
+            local_time_types: Vec::new(),
+            leap_seconds: vec![ (78_796_800, 1), (94_694_401, 2), (200_000_002, 2) ],
+            footer: None,
+        };
+
+        let read = LeapSecondTable::from_tzif(&tzif).unwrap();
+        assert_eq!(read.records(), table().records());
+        assert_eq!(read.expires(), Some(Instant::at(200_000_000)));
+    }
+}
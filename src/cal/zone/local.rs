@@ -0,0 +1,272 @@
+//! Finding the system’s local time zone.
+//!
+//! There’s no single place that holds the local time zone, so several are
+//! checked in turn, the same way the C library does:
+//!
+//! 1. The `TZ` environment variable, which can hold a zone name such as
+//!    `Europe/London`, a path to a TZif file preceded by a colon such as
+//!    `:/etc/localtime`, or a POSIX TZ string such as `EST5EDT`;
+//! 2. The `/etc/timezone` file, which holds a zone name on Debian-based
+//!    systems;
+//! 3. The `/etc/localtime` file, which is usually a symlink into the
+//!    zoneinfo directory, but is sometimes a plain copy of the TZif file,
+//!    such as in many Docker images.
+//!
+//! If none of these give a time zone, UTC is used.
+
+use std::borrow::Cow;
+use std::env;
+use std::ffi::OsString;
+use std::fs;
+use std::path::PathBuf;
+
+use cal::zone::{FixedTimespan, FixedTimespanSet, StaticTimeZone, TimeZone, TimeZoneSource};
+use system::{extract_timezone, zoneinfo_directories};
+
+
+/// Where the local time zone was found.
+#[derive(PartialEq, Debug, Clone)]
+pub enum LocalSource {
+
+    /// The `TZ` environment variable held the name of a zone in the
+    /// zoneinfo database, with or without a leading colon.
+    EnvironmentName(String),
+
+    /// The `TZ` environment variable held the path to a TZif file,
+    /// preceded by a colon.
+    EnvironmentPath(PathBuf),
+
+    /// The `TZ` environment variable held a POSIX TZ string.
+    EnvironmentRule(String),
+
+    /// The `/etc/timezone` file held the name of a zone.
+    TimezoneFile(String),
+
+    /// `/etc/localtime` was a symlink to the zone with this name.
+    LocaltimeLink(String),
+
+    /// `/etc/localtime` was a TZif file that isn’t linked to a named zone.
+    LocaltimeFile,
+
+    /// None of the sources gave a time zone, so UTC was used.
+    Default,
+}
+
+/// The zone used when no local time zone could be found.
+static UTC: StaticTimeZone<'static> = StaticTimeZone {
+    name: "UTC",
+    fixed_timespans: FixedTimespanSet {
+        first: FixedTimespan {
+            offset: 0,
+            is_dst: false,
+            name: Cow::Borrowed("UTC"),
+        },
+        rest: &[],
+        extension: None,
+    },
+};
+
+
+/// The places to look for the local time zone. These are only ever the
+/// real system locations outside of tests.
+#[derive(Debug)]
+struct Locations {
+    tz: Option<OsString>,
+    timezone_file: PathBuf,
+    localtime: PathBuf,
+    zoneinfo: Vec<PathBuf>,
+}
+
+impl Locations {
+    fn system() -> Self {
+        Self {
+            tz:             env::var_os("TZ"),
+            timezone_file:  PathBuf::from("/etc/timezone"),
+            localtime:      PathBuf::from("/etc/localtime"),
+            zoneinfo:       zoneinfo_directories(),
+        }
+    }
+
+    fn find(&self) -> (TimeZone, LocalSource) {
+        self.check_environment()
+            .or_else(|| self.check_timezone_file())
+            .or_else(|| self.check_localtime())
+            .unwrap_or((TimeZone(TimeZoneSource::Static(&UTC)), LocalSource::Default))
+    }
+
+    fn check_environment(&self) -> Option<(TimeZone, LocalSource)> {
+        let tz = self.tz.as_ref()?.to_str()?;
+
+        if let Some(rest) = tz.strip_prefix(':') {
+            if rest.starts_with('/') {
+                let bytes = fs::read(rest).ok()?;
+                let zone = TimeZone::from_tzif(None, &bytes).ok()?;
+                return Some((zone, LocalSource::EnvironmentPath(PathBuf::from(rest))));
+            }
+
+            let zone = TimeZone::named_in_directories(&self.zoneinfo, rest).ok()?;
+            return Some((zone, LocalSource::EnvironmentName(rest.to_owned())));
+        }
+
+        if tz.is_empty() {
+            return None;
+        }
+
+        // A name such as “EST5EDT” is both a zone and a POSIX TZ string,
+        // so the zoneinfo database gets checked first.
+        if let Ok(zone) = TimeZone::named_in_directories(&self.zoneinfo, tz) {
+            return Some((zone, LocalSource::EnvironmentName(tz.to_owned())));
+        }
+
+        let zone = TimeZone::from_posix(tz).ok()?;
+        Some((zone, LocalSource::EnvironmentRule(tz.to_owned())))
+    }
+
+    fn check_timezone_file(&self) -> Option<(TimeZone, LocalSource)> {
+        let contents = fs::read_to_string(&self.timezone_file).ok()?;
+        let name = contents.lines().next()?.trim();
+        let zone = TimeZone::named_in_directories(&self.zoneinfo, name).ok()?;
+        Some((zone, LocalSource::TimezoneFile(name.to_owned())))
+    }
+
+    fn check_localtime(&self) -> Option<(TimeZone, LocalSource)> {
+        let name = fs::read_link(&self.localtime).ok()
+                      .and_then(|link| extract_timezone(&link))
+                      .filter(|name| !name.is_empty());
+
+        if let Some(name) = name {
+            if let Ok(zone) = TimeZone::named_in_directories(&self.zoneinfo, &name) {
+                return Some((zone, LocalSource::LocaltimeLink(name)));
+            }
+        }
+
+        let bytes = fs::read(&self.localtime).ok()?;
+        let zone = TimeZone::from_tzif(None, &bytes).ok()?;
+        Some((zone, LocalSource::LocaltimeFile))
+    }
+}
+
+
+/// Finds the system’s local time zone, returning it along with where it
+/// was found.
+pub fn find() -> (TimeZone, LocalSource) {
+    Locations::system().find()
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::path::Path;
+    use std::process;
+
+    /// A temporary directory that gets removed when it’s dropped.
+    struct Scratch(PathBuf);
+
+    impl Scratch {
+        fn new(test: &str) -> Self {
+            let path = env::temp_dir().join(format!("datetime-local-{}-{}", test, process::id()));
+            let _ = fs::remove_dir_all(&path);
+            fs::create_dir_all(&path).unwrap();
+            Scratch(path)
+        }
+    }
+
+    impl Drop for Scratch {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn fixtures() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/zoneinfo")
+    }
+
+    fn locations(scratch: &Scratch, tz: Option<&str>) -> Locations {
+        Locations {
+            tz:             tz.map(OsString::from),
+            timezone_file:  scratch.0.join("timezone"),
+            localtime:      scratch.0.join("localtime"),
+            zoneinfo:       vec![ fixtures() ],
+        }
+    }
+
+    #[test]
+    fn environment_name() {
+        let scratch = Scratch::new("environment-name");
+        let (zone, source) = locations(&scratch, Some("Europe/London")).find();
+        assert_eq!(source, LocalSource::EnvironmentName("Europe/London".into()));
+        assert_eq!(zone.zone_name(), Some("Europe/London"));
+    }
+
+    #[test]
+    fn environment_colon_name() {
+        let scratch = Scratch::new("environment-colon-name");
+        let (_, source) = locations(&scratch, Some(":America/New_York")).find();
+        assert_eq!(source, LocalSource::EnvironmentName("America/New_York".into()));
+    }
+
+    #[test]
+    fn environment_path() {
+        let scratch = Scratch::new("environment-path");
+        let path = fixtures().join("Europe/London");
+        let tz = format!(":{}", path.display());
+        let (zone, source) = locations(&scratch, Some(&tz)).find();
+        assert_eq!(source, LocalSource::EnvironmentPath(path));
+        assert!(!zone.is_fixed());
+    }
+
+    #[test]
+    fn environment_rule() {
+        let scratch = Scratch::new("environment-rule");
+        let (zone, source) = locations(&scratch, Some("CET-1CEST,M3.5.0,M10.5.0/3")).find();
+        assert_eq!(source, LocalSource::EnvironmentRule("CET-1CEST,M3.5.0,M10.5.0/3".into()));
+        assert_eq!(zone.zone_name(), None);
+    }
+
+    #[test]
+    fn invalid_environment_falls_through() {
+        let scratch = Scratch::new("invalid-environment");
+        let (_, source) = locations(&scratch, Some("Not a zone")).find();
+        assert_eq!(source, LocalSource::Default);
+    }
+
+    #[test]
+    fn timezone_file() {
+        let scratch = Scratch::new("timezone-file");
+        fs::write(scratch.0.join("timezone"), "America/New_York\n").unwrap();
+        let (zone, source) = locations(&scratch, None).find();
+        assert_eq!(source, LocalSource::TimezoneFile("America/New_York".into()));
+        assert_eq!(zone.zone_name(), Some("America/New_York"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn localtime_link() {
+        use std::os::unix::fs::symlink;
+
+        let scratch = Scratch::new("localtime-link");
+        symlink(fixtures().join("Europe/London"), scratch.0.join("localtime")).unwrap();
+        let (zone, source) = locations(&scratch, None).find();
+        assert_eq!(source, LocalSource::LocaltimeLink("Europe/London".into()));
+        assert_eq!(zone.zone_name(), Some("Europe/London"));
+    }
+
+    #[test]
+    fn localtime_file() {
+        let scratch = Scratch::new("localtime-file");
+        let _ = fs::copy(fixtures().join("Europe/London"), scratch.0.join("localtime")).unwrap();
+        let (zone, source) = locations(&scratch, None).find();
+        assert_eq!(source, LocalSource::LocaltimeFile);
+        assert!(!zone.is_fixed());
+    }
+
+    #[test]
+    fn default() {
+        let scratch = Scratch::new("default");
+        let (zone, source) = locations(&scratch, None).find();
+        assert_eq!(source, LocalSource::Default);
+        assert_eq!(zone.zone_name(), Some("UTC"));
+        assert!(zone.is_fixed());
+    }
+}
@@ -0,0 +1,1480 @@
+//! Datetimes with a variable UTC offset, and time zone calculations.
+
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::error::Error as ErrorTrait;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::ops::{Add, Sub};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use duration::Duration;
+use instant::Instant;
+use cal::{LocalDate, LocalDateTime, DatePiece, TimePiece, Month, Weekday};
+use cal::datetime::{Year, Error as DateTimeError};
+use cal::parse;
+use system::zoneinfo_directories;
+use util::RangeExt;
+
+pub mod abbreviation;
+pub mod catalogue;
+pub mod diff;
+#[cfg(feature = "embedded-tzdata")]
+pub mod embedded;
+pub mod leap;
+pub mod local;
+pub mod posix;
+pub mod registry;
+pub mod tzdata;
+pub mod tzif;
+pub mod windows;
+
+
+/// A **time zone**, which here is a list of timespans, each containing a
+/// fixed offset for the current location’s time from UTC.
+#[derive(Debug, Clone)]
+pub struct TimeZone(pub TimeZoneSource<'static>);
+
+#[derive(Debug, Clone)]
+pub enum TimeZoneSource<'a> {
+    Static(&'a StaticTimeZone<'a>),
+    Runtime(Arc<runtime::OwnedTimeZone>),
+}
+
+#[derive(PartialEq, Debug)]
+pub struct StaticTimeZone<'a> {
+
+    /// This zone’s name in the zoneinfo database, such as “America/New_York”.
+    pub name: &'a str,
+
+    /// The set of timespans used in this time zone.
+    pub fixed_timespans: FixedTimespanSet<'a>,
+}
+
+impl TimeZone {
+
+    /// Reads a time zone from the contents of a compiled TZif file, such
+    /// as one of the files in `/usr/share/zoneinfo`, giving it the name
+    /// passed in.
+    pub fn from_tzif(name: Option<String>, input: &[u8]) -> Result<Self, tzif::Error> {
+        let tzif = tzif::parse(input)?;
+        Ok(Self(TimeZoneSource::Runtime(Arc::new(tzif.to_time_zone(name)?))))
+    }
+
+    /// Creates a time zone from a POSIX TZ string, such as
+    /// `CET-1CEST,M3.5.0,M10.5.0/3`, in the format used by the `TZ`
+    /// environment variable. The zone has no transition table, so its
+    /// rules are applied to every year.
+    pub fn from_posix(tz_string: &str) -> Result<Self, posix::Error> {
+        let rule: posix::PosixTimeZone<'static> = tz_string.parse()?;
+        let set = runtime::OwnedFixedTimespanSet {
+            first:      rule.standard.clone(),
+            rest:       Vec::new(),
+            extension:  Some(rule),
+        };
+
+        let zone = runtime::OwnedTimeZone::new(None, set).map_err(|_| posix::Error::InvalidOffset)?;
+        Ok(Self(TimeZoneSource::Runtime(Arc::new(zone))))
+    }
+
+    /// Loads the time zone with the given name, such as “Europe/London”,
+    /// from the system’s zoneinfo database.
+    ///
+    /// The directory in the `TZDIR` environment variable is searched
+    /// first, followed by `/usr/share/zoneinfo` and `/usr/lib/zoneinfo`.
+    /// With the `embedded-tzdata` feature, the embedded database is used
+    /// for zones that aren’t found in any of these.
+    pub fn named(name: &str) -> Result<Self, Error> {
+        Self::named_in_directories(&zoneinfo_directories(), name)
+    }
+
+    /// Returns the system’s local time zone, falling back to UTC if none
+    /// could be found. See the `local` module for where it looks.
+    pub fn local() -> Self {
+        local::find().0
+    }
+
+    /// Returns the system’s local time zone, along with where it was
+    /// found.
+    pub fn local_with_source() -> (Self, local::LocalSource) {
+        local::find()
+    }
+
+    /// Loads the time zone with the given name from the first of the given
+    /// directories that has it, falling back to the embedded database.
+    fn named_in_directories(directories: &[PathBuf], name: &str) -> Result<Self, Error> {
+        for directory in directories {
+            match Self::named_in(directory, name) {
+                Err(Error::NotFound(_))  => continue,
+                result                   => return result,
+            }
+        }
+
+        #[cfg(feature = "embedded-tzdata")]
+        {
+            if let Some(zone) = embedded::lookup(name) {
+                return Ok(zone);
+            }
+        }
+
+        Err(Error::NotFound(name.to_owned()))
+    }
+
+    /// Loads the time zone with the given name from the zoneinfo database
+    /// in the given directory.
+    pub fn named_in(directory: &Path, name: &str) -> Result<Self, Error> {
+        if !is_valid_zone_name(name) {
+            return Err(Error::InvalidName(name.to_owned()));
+        }
+
+        let path = directory.join(name);
+        if !path.is_file() {
+            return Err(Error::NotFound(name.to_owned()));
+        }
+
+        let bytes = fs::read(&path).map_err(Error::Io)?;
+        Self::from_tzif(Some(name.to_owned()), &bytes).map_err(Error::Tzif)
+    }
+
+    pub fn zone_name(&self) -> Option<&str> {
+        self.0.zone_name()
+    }
+
+    /// Returns the total offset from UTC, in seconds, that this time zone
+    /// has at the given datetime.
+    pub fn offset(&self, datetime: LocalDateTime) -> i64 {
+        match self.0 {
+            TimeZoneSource::Static(ref tz)   => tz.fixed_timespans.offset(datetime),
+            TimeZoneSource::Runtime(ref arc) => arc.fixed_timespans.borrow().offset(datetime),
+        }
+    }
+
+    /// Returns the time zone abbreviation that this time zone has at the
+    /// given datetime. As always, abbreviations are notoriously vague, and
+    /// should only be used when referring to a known timezone.
+    pub fn name(&self, datetime: LocalDateTime) -> String {
+        match self.0 {
+            TimeZoneSource::Static(ref tz)   => tz.fixed_timespans.name(datetime),
+            TimeZoneSource::Runtime(ref arc) => arc.fixed_timespans.borrow().name(datetime),
+        }
+    }
+
+    /// Whether this time zone is “fixed”: a fixed time zone has no
+    /// transitions, meaning it will always be at the same offset from UTC.
+    ///
+    /// There are relatively few of these, namely the European timezones
+    /// WET, CET, MET, and EET, and the North American timezones EST5EDT,
+    /// CST6CDT, MST7MDT, and PST8PDT, none of which actually corresponds to
+    /// a geographical location.
+    pub fn is_fixed(&self) -> bool {
+        match self.0 {
+            TimeZoneSource::Static(ref tz)   => tz.fixed_timespans.is_fixed(),
+            TimeZoneSource::Runtime(ref arc) => arc.fixed_timespans.borrow().is_fixed(),
+        }
+    }
+
+    /// Converts a local datetime in UTC to a zoned datetime that uses this
+    /// time zone.
+    pub fn to_zoned(&self, datetime: LocalDateTime) -> LocalDateTime {
+        datetime + Duration::of(self.offset(datetime))
+    }
+
+    /// Converts a local datetime that is *already* informally in this time
+    /// zone into a zoned datetime that actually uses this time zone.
+    ///
+    /// For example, say you have the current time for a time zone, but you
+    /// *don’t* know what the current offset from UTC is. This method
+    /// computes the offset, then *subtracts* rather than adds it, resulting
+    /// in a value that gets displayed as the current time. In other words,
+    /// calling `hour()` or `year()` or any of the other view methods on one
+    /// of the resulting values will *always* return the same as the
+    /// datetime initially passed in, no matter what the current offset is.
+    ///
+    /// This method can return 0, 1, or 2 values, depending on whether the
+    /// datetime passed in falls between two timespans (an impossible time)
+    /// or overlaps two separate timespans (an ambiguous time). The result
+    /// will *almost* always be precise, but there are edge cases you need
+    /// to watch out for.
+    pub fn convert_local(&self, local: LocalDateTime) -> LocalTimes<'static> {
+        self.0.convert_local(local)
+    }
+
+    /// Returns the zoned datetime at the given instant in this time zone.
+    ///
+    /// Near either end of the range of instants, the local time can be
+    /// past `LocalDateTime::MIN` or `MAX`, in which case it stops there.
+    pub fn at(&self, instant: Instant) -> ZonedDateTime<'static> {
+        ZonedDateTime::from_instant(instant, self.0.clone())
+    }
+
+    /// Converts a local datetime that is already informally in this time
+    /// zone into a zoned datetime, like `convert_local`, but always picks
+    /// a single result, using the given strategy to decide what to do
+    /// with local times that are ambiguous or that were skipped.
+    ///
+    /// A local time that was skipped by a transition is moved by the
+    /// length of the gap: `Earlier` moves it backwards, into the timespan
+    /// before the transition, and `Later` and `Compatible` move it
+    /// forwards, into the timespan after it. So when the clocks go forward
+    /// from 1:00 to 2:00, 1:30 becomes 0:30 or 2:30.
+    pub fn resolve_local(&self, local: LocalDateTime, disambiguation: Disambiguation) -> Result<ZonedDateTime<'static>, ResolveError> {
+        self.0.resolve_local(local, disambiguation)
+    }
+
+    /// Returns the first transition that happens strictly after the given
+    /// instant, or `None` if the offset never changes again.
+    pub fn next_transition(&self, after: Instant) -> Option<Transition<'_>> {
+        let raw = match self.0 {
+            TimeZoneSource::Static(ref tz)   => tz.fixed_timespans.next_transition(after.seconds()),
+            TimeZoneSource::Runtime(ref arc) => arc.fixed_timespans.borrow().next_transition(after.seconds()),
+        };
+
+        raw.map(Transition::from_raw)
+    }
+
+    /// Returns the last transition that happens strictly before the given
+    /// instant, or `None` if the offset has never changed before it.
+    pub fn previous_transition(&self, before: Instant) -> Option<Transition<'_>> {
+        // Transitions happen on whole seconds, so one in the same second
+        // as an instant with a fraction is still before it.
+        let before = if before.nanoseconds() > 0 { before.seconds().saturating_add(1) }
+                                              else { before.seconds() };
+
+        let raw = match self.0 {
+            TimeZoneSource::Static(ref tz)   => tz.fixed_timespans.previous_transition(before),
+            TimeZoneSource::Runtime(ref arc) => arc.fixed_timespans.borrow().previous_transition(before),
+        };
+
+        raw.map(Transition::from_raw)
+    }
+
+    /// Returns an iterator over the transitions that happen at or after
+    /// `from`, and before `to`, in order. Transitions produced by the
+    /// zone’s extension rule are included, so this works for any range,
+    /// such as every daylight-saving change in a year decades from now.
+    pub fn transitions(&self, from: Instant, to: Instant) -> Transitions<'_> {
+        let after = if from.nanoseconds() > 0 { from.seconds() }
+                                            else { from.seconds().saturating_sub(1) };
+
+        Transitions { zone: self, after, to, finished: false }
+    }
+}
+
+
+/// How `TimeZone::resolve_local` should handle local times that happen
+/// twice, or not at all, because of a transition.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum Disambiguation {
+
+    /// Use the earlier of two ambiguous times, and move skipped times
+    /// backwards by the length of the gap.
+    Earlier,
+
+    /// Use the later of two ambiguous times, and move skipped times
+    /// forwards by the length of the gap.
+    Later,
+
+    /// Use the earlier of two ambiguous times, and move skipped times
+    /// forwards by the length of the gap. This is what RFC 5545 and
+    /// JavaScript’s Temporal do.
+    Compatible,
+
+    /// Return an error for ambiguous or skipped times.
+    Reject,
+}
+
+/// The error returned by `TimeZone::resolve_local` when it rejects a
+/// local time.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum ResolveError {
+
+    /// The local time was skipped by a transition, such as the clocks
+    /// going forward, so it never happened in this zone.
+    Skipped(LocalDateTime),
+
+    /// The local time happened twice in this zone, such as when the
+    /// clocks went back.
+    Ambiguous(LocalDateTime),
+
+    /// The resulting datetime would be outside the range from
+    /// `LocalDateTime::MIN` to `LocalDateTime::MAX`.
+    OutOfRange,
+}
+
+impl fmt::Display for ResolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ResolveError::Skipped(ref local)    => write!(f, "local time {:?} was skipped in this time zone", local),
+            ResolveError::Ambiguous(ref local)  => write!(f, "local time {:?} is ambiguous in this time zone", local),
+            ResolveError::OutOfRange            => write!(f, "datetime out of range"),
+        }
+    }
+}
+
+impl ErrorTrait for ResolveError {
+}
+
+
+/// A change from one timespan to another in a time zone, such as the
+/// start or end of daylight-saving time.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Transition<'a> {
+
+    /// The instant at which the change happens.
+    pub instant: Instant,
+
+    /// The timespan in effect before the change.
+    pub before: FixedTimespan<'a>,
+
+    /// The timespan in effect after the change.
+    pub after: FixedTimespan<'a>,
+}
+
+impl<'a> Transition<'a> {
+    fn from_raw((time, before, after): (i64, FixedTimespan<'a>, FixedTimespan<'a>)) -> Self {
+        Self { instant: Instant::at(time), before, after }
+    }
+}
+
+/// An iterator over the transitions of a time zone in a range of
+/// instants, returned by `TimeZone::transitions`.
+#[derive(Debug, Clone)]
+pub struct Transitions<'a> {
+    zone: &'a TimeZone,
+    after: i64,
+    to: Instant,
+    finished: bool,
+}
+
+impl<'a> Iterator for Transitions<'a> {
+    type Item = Transition<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        let transition = self.zone.next_transition(Instant::at(self.after));
+        let transition = match transition {
+            Some(t) if t.instant < self.to  => t,
+            _                               => { self.finished = true; return None; },
+        };
+
+        self.after = transition.instant.seconds();
+        Some(transition)
+    }
+}
+
+
+/// Returns whether the given string can be used as the name of a zone in
+/// a zoneinfo directory, which rules out anything that could escape it.
+fn is_valid_zone_name(name: &str) -> bool {
+    !name.is_empty()
+        && !name.contains('\\')
+        && !name.contains('\0')
+        && name.split('/').all(|c| !c.is_empty() && c != "." && c != "..")
+}
+
+
+/// An error that can occur when loading a time zone by name.
+#[derive(Debug)]
+pub enum Error {
+
+    /// The name is empty, absolute, or contains `.` or `..` components.
+    InvalidName(String),
+
+    /// No zoneinfo directory contains a zone with this name.
+    NotFound(String),
+
+    /// The zone’s file exists, but could not be read.
+    Io(io::Error),
+
+    /// The zone’s file was read, but is not a valid TZif file.
+    Tzif(tzif::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::InvalidName(ref name)  => write!(f, "invalid time zone name {:?}", name),
+            Error::NotFound(ref name)     => write!(f, "time zone {:?} not found", name),
+            Error::Io(ref e)              => write!(f, "error reading time zone: {}", e),
+            Error::Tzif(ref e)            => write!(f, "error parsing time zone: {}", e),
+        }
+    }
+}
+
+impl ErrorTrait for Error {
+    fn source(&self) -> Option<&(dyn ErrorTrait + 'static)> {
+        match *self {
+            Error::Io(ref e)    => Some(e),
+            Error::Tzif(ref e)  => Some(e),
+            _                   => None,
+        }
+    }
+}
+
+
+impl<'a> TimeZoneSource<'a> {
+
+    fn zone_name(&self) -> Option<&str> {
+        match *self {
+            TimeZoneSource::Static(tz)       => Some(tz.name),
+            TimeZoneSource::Runtime(ref arc) => arc.name.as_deref(),
+        }
+    }
+
+    /// Returns the total offset from UTC, in seconds, in effect at the
+    /// given Unix timestamp.
+    fn offset_at(&self, time: i64) -> i64 {
+        self.with_timespan(time, |timespan| timespan.offset)
+    }
+
+    /// Calls the function with the timespan in effect at the given Unix
+    /// timestamp.
+    fn with_timespan<T, F: FnOnce(&FixedTimespan) -> T>(&self, time: i64, f: F) -> T {
+        match *self {
+            TimeZoneSource::Static(tz)       => f(tz.fixed_timespans.find(time)),
+            TimeZoneSource::Runtime(ref arc) => f(arc.fixed_timespans.borrow().find(time)),
+        }
+    }
+
+    /// Calls the function with this zone’s whole set of timespans.
+    fn with_timespans<T, F: FnOnce(&FixedTimespanSet) -> T>(&self, f: F) -> T {
+        match *self {
+            TimeZoneSource::Static(tz)       => f(&tz.fixed_timespans),
+            TimeZoneSource::Runtime(ref arc) => f(&arc.fixed_timespans.borrow()),
+        }
+    }
+
+    fn convert_local(&self, local: LocalDateTime) -> LocalTimes<'a> {
+        match self.with_timespans(|set| set.local_offsets(local)) {
+            LocalOffsets::Precise(offset)               => LocalTimes::Precise(self.zonify(local, offset)),
+            LocalOffsets::Ambiguous { earlier, later }  => LocalTimes::Ambiguous { earlier: self.zonify(local, earlier), later: self.zonify(local, later) },
+            LocalOffsets::Gap { .. }                    => LocalTimes::Impossible,
+        }
+    }
+
+    fn resolve_local(&self, local: LocalDateTime, disambiguation: Disambiguation) -> Result<ZonedDateTime<'a>, ResolveError> {
+        match self.with_timespans(|set| set.local_offsets(local)) {
+            LocalOffsets::Precise(offset) => Ok(self.zonify(local, offset)),
+
+            LocalOffsets::Ambiguous { earlier, later } => match disambiguation {
+                Disambiguation::Earlier | Disambiguation::Compatible  => Ok(self.zonify(local, earlier)),
+                Disambiguation::Later                                 => Ok(self.zonify(local, later)),
+                Disambiguation::Reject                                => Err(ResolveError::Ambiguous(local)),
+            },
+
+            LocalOffsets::Gap { before, after } => {
+                let gap = Duration::of(after.checked_sub(before).ok_or(ResolveError::OutOfRange)?);
+
+                let (adjusted, offset) = match disambiguation {
+                    Disambiguation::Earlier                            => (local.checked_sub(gap), before),
+                    Disambiguation::Later | Disambiguation::Compatible => (local.checked_add(gap), after),
+                    Disambiguation::Reject                             => return Err(ResolveError::Skipped(local)),
+                };
+
+                Ok(self.zonify(adjusted.ok_or(ResolveError::OutOfRange)?, offset))
+            },
+        }
+    }
+
+    fn zonify(&self, adjusted: LocalDateTime, current_offset: i64) -> ZonedDateTime<'a> {
+        ZonedDateTime { adjusted, current_offset, time_zone: self.clone() }
+    }
+}
+
+
+/// A set of timespans, separated by the instances at which the timespans
+/// change over. There will always be one more timespan than transitions.
+///
+/// The fields are public so that sets can be written as constants, which
+/// means a set can be made that `validate` would reject. Using such a set
+/// doesn’t cause a panic, but the offsets and conversions it gives are
+/// unspecified.
+#[derive(PartialEq, Debug, Clone)]
+pub struct FixedTimespanSet<'a> {
+
+    /// The first timespan, which is assumed to have been in effect up until
+    /// the initial transition instant (if any). Each set has to have at
+    /// least one timespan.
+    pub first: FixedTimespan<'a>,
+
+    /// The rest of the timespans, as a slice of tuples, each containing:
+    ///
+    /// 1. A transition instant at which the previous timespan ends and the
+    ///    next one begins, stored as a Unix timestamp;
+    /// 2. The actual timespan to transition into.
+    pub rest: &'a [ (i64, FixedTimespan<'a>) ],
+
+    /// The rule that produces further transitions after the last one in
+    /// `rest`, such as the POSIX TZ string from the footer of a TZif
+    /// file. Without one, the last timespan lasts forever.
+    pub extension: Option<posix::PosixTimeZone<'a>>,
+}
+
+/// The reason a `FixedTimespanSet` is malformed.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum ValidationError {
+
+    /// The transition at this index in `rest` is at or before the one
+    /// preceding it.
+    UnsortedTransitions { index: usize },
+
+    /// The transition at this index in `rest` changes to a timespan with
+    /// the same offset as the one before it.
+    RepeatedOffset { index: usize },
+
+    /// A timespan has an offset, in seconds, more than a day from UTC.
+    OffsetOutOfRange(i64),
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ValidationError::UnsortedTransitions { index }  => write!(f, "transition {} is out of order", index),
+            ValidationError::RepeatedOffset { index }       => write!(f, "transition {} does not change the offset", index),
+            ValidationError::OffsetOutOfRange(offset)       => write!(f, "offset of {} seconds is out of range", offset),
+        }
+    }
+}
+
+impl ErrorTrait for ValidationError {
+}
+
+
+/// An individual timespan with a fixed offset.
+#[derive(PartialEq, Debug, Clone)]
+pub struct FixedTimespan<'a> {
+
+    /// The *total* offset in effect during this timespan, in seconds. This
+    /// is the sum of the standard offset from UTC (the zone’s standard
+    /// time), and any extra daylight-saving offset.
+    pub offset: i64,
+
+    /// Whether there was any daylight-saving offset in effect during this
+    /// timespan.
+    pub is_dst: bool,
+
+    /// The abbreviation in use during this timespan, such as “GMT” or
+    /// “PDT”. Abbreviations are notoriously vague, and should only be used
+    /// for referring to a known timezone.
+    pub name: Cow<'a, str>,
+}
+
+impl<'a> FixedTimespanSet<'a> {
+
+    /// Checks that this set is well-formed: its transitions must be in
+    /// strictly ascending order, each one must change the offset, and
+    /// every offset, including those of the extension rule, must be no
+    /// more than a day away from UTC.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let mut timespans = vec![ &self.first ];
+        timespans.extend(self.rest.iter().map(|t| &t.1));
+        if let Some(ref extension) = self.extension {
+            timespans.push(&extension.standard);
+            timespans.extend(extension.daylight.as_ref().map(|d| &d.timespan));
+        }
+
+        if let Some(timespan) = timespans.into_iter().find(|t| !t.offset.is_within(-86400 .. 86401)) {
+            return Err(ValidationError::OffsetOutOfRange(timespan.offset));
+        }
+
+        for (index, transition) in self.rest.iter().enumerate() {
+            let previous = match index {
+                0  => &self.first,
+                _  => &self.rest[index - 1].1,
+            };
+
+            if index > 0 && transition.0 <= self.rest[index - 1].0 {
+                return Err(ValidationError::UnsortedTransitions { index });
+            }
+
+            if transition.1.offset == previous.offset {
+                return Err(ValidationError::RepeatedOffset { index });
+            }
+        }
+
+        Ok(())
+    }
+
+    fn find(&self, time: i64) -> &FixedTimespan<'a> {
+        if let Some(generated) = self.extension_surroundings(time) {
+            return generated.current;
+        }
+
+        match self.transitions_before(time) {
+            0  => &self.first,
+            n  => &self.rest[n - 1].1,
+        }
+    }
+
+    fn offset(&self, datetime: LocalDateTime) -> i64 {
+        let unix_timestamp = datetime.to_instant().seconds();
+        self.find(unix_timestamp).offset
+    }
+
+    fn name(&self, datetime: LocalDateTime) -> String {
+        let unix_timestamp = datetime.to_instant().seconds();
+        self.find(unix_timestamp).name.to_string()
+    }
+
+    fn is_fixed(&self) -> bool {
+        self.rest.is_empty()
+            && self.extension.as_ref().map_or(true, |e| e.daylight.is_none())
+    }
+
+    /// Works out which offsets the local datetime could have, along with
+    /// the offsets either side of the gap if it was skipped over.
+    fn local_offsets(&self, local: LocalDateTime) -> LocalOffsets {
+        let unix_timestamp = local.to_instant().seconds();
+
+        let timespans = self.extension_surroundings(unix_timestamp)
+                            .unwrap_or_else(|| self.find_with_surroundings(unix_timestamp));
+
+        if let Some((previous_zone, previous_transition_time)) = timespans.previous {
+
+            // Test whether this timestamp is in the *overlap* after the
+            // current timespan starts but before the previous one ends.
+            if previous_zone.offset > timespans.current.offset
+            && unix_timestamp.saturating_sub(previous_transition_time).is_within(timespans.current.offset .. previous_zone.offset) {
+                return LocalOffsets::Ambiguous {
+                    earlier:  previous_zone.offset,
+                    later:    timespans.current.offset,
+                };
+            }
+
+            // Test whether this timestamp is in the *space* after the
+            // previous timespan ends but before the current one starts.
+            if previous_zone.offset < timespans.current.offset
+            && unix_timestamp.saturating_sub(previous_transition_time).is_within(previous_zone.offset .. timespans.current.offset) {
+                return LocalOffsets::Gap {
+                    before:  previous_zone.offset,
+                    after:   timespans.current.offset,
+                };
+            }
+        }
+
+        if let Some((next_transition_time, next_zone)) = timespans.next {
+
+            // Test whether this timestamp is in the *overlap* after the
+            // next timespan starts but before the current one ends.
+            if timespans.current.offset > next_zone.offset
+            && unix_timestamp.saturating_sub(next_transition_time).is_within(next_zone.offset .. timespans.current.offset) {
+                return LocalOffsets::Ambiguous {
+                    earlier:  timespans.current.offset,
+                    later:    next_zone.offset,
+                };
+            }
+
+            // Test whether this timestamp is in the *space* after the
+            // current timespan ends but before the next one starts.
+            if timespans.current.offset < next_zone.offset
+            && unix_timestamp.saturating_sub(next_transition_time).is_within(timespans.current.offset .. next_zone.offset) {
+                return LocalOffsets::Gap {
+                    before:  timespans.current.offset,
+                    after:   next_zone.offset,
+                };
+            }
+        }
+
+        LocalOffsets::Precise(timespans.current.offset)
+    }
+
+    /// Returns the first transition after the given time, as its time,
+    /// the timespan before it, and the timespan after it.
+    fn next_transition(&self, after: i64) -> Option<(i64, FixedTimespan<'a>, FixedTimespan<'a>)> {
+        let position = self.transitions_before(after.saturating_add(1));
+        if position < self.rest.len() {
+            let previous = if position == 0 { &self.first } else { &self.rest[position - 1].1 };
+            return Some((self.rest[position].0, previous.clone(), self.rest[position].1.clone()));
+        }
+
+        // Every transition in the table is at or before the time, so the
+        // only ones left are those generated by the extension rule.
+        let generated = self.extension_surroundings(after.saturating_add(1))?;
+        let (time, next) = generated.next?;
+        Some((time, generated.current.clone(), next.clone()))
+    }
+
+    /// Returns the last transition before the given time, as its time,
+    /// the timespan before it, and the timespan after it.
+    fn previous_transition(&self, before: i64) -> Option<(i64, FixedTimespan<'a>, FixedTimespan<'a>)> {
+        if let Some(generated) = self.extension_surroundings(before) {
+            let (previous, time) = generated.previous?;
+            return Some((time, previous.clone(), generated.current.clone()));
+        }
+
+        let position = self.transitions_before(before).checked_sub(1)?;
+        let previous = if position == 0 { &self.first } else { &self.rest[position - 1].1 };
+        Some((self.rest[position].0, previous.clone(), self.rest[position].1.clone()))
+    }
+
+    /// Returns the number of transitions in `rest` that happen before the
+    /// given time, which is also the index of the first one that doesn’t.
+    /// The transitions are sorted, so this is a binary search.
+    fn transitions_before(&self, time: i64) -> usize {
+        self.rest.partition_point(|t| t.0 < time)
+    }
+
+    fn find_with_surroundings(&self, time: i64) -> Surroundings<'_, 'a> {
+        if let Some(position) = self.transitions_before(time).checked_sub(1) {
+            // There’s a matching time in the ‘rest’ list, so return that
+            // time along with the two sets of details around it.
+
+            let previous_details = if position == 0 {
+                &self.first
+            }
+            else {
+                &self.rest[position - 1].1
+            };
+
+            Surroundings {
+                previous:  Some((previous_details, self.rest[position].0)),
+                current:   &self.rest[position].1,
+                next:      self.rest.get(position + 1).map(|t| (t.0, &t.1)),
+            }
+        }
+        else {
+            // If there’s no matching time in the ‘rest’ list, it must be
+            // the ‘first’ one.
+            Surroundings {
+                previous: None,
+                current:  &self.first,
+                next:     self.rest.first().map(|t| (t.0, &t.1)),
+            }
+        }
+    }
+
+    /// Returns the timespans around the given time using this set’s
+    /// extension rule, if the time is after the last transition in `rest`
+    /// and there is a rule to extend it with.
+    fn extension_surroundings(&self, time: i64) -> Option<Surroundings<'_, 'a>> {
+        let extension = self.extension.as_ref()?;
+
+        let (table_end, last) = match self.rest.last() {
+            Some(&(end, _)) if time <= end  => return None,
+            Some(&(end, ref last))          => (Some(end), last),
+            None                            => (None, &self.first),
+        };
+
+        let before_last = match self.rest.len() {
+            0 | 1  => &self.first,
+            n      => &self.rest[n - 2].1,
+        };
+
+        let mut surroundings = Surroundings {
+            previous:  table_end.map(|end| (before_last, end)),
+            current:   last,
+            next:      None,
+        };
+
+        // Walk through the rule’s transitions for the years around this
+        // time, skipping any that are covered by the table, or that don’t
+        // change the offset. When two transitions happen at the same
+        // instant, such as with a rule for daylight-saving time all year
+        // round, only the later one takes effect.
+        let year = LocalDateTime::at(time).year();
+        let mut transitions = (year - 1 ..= year + 1)
+            .flat_map(|y| extension.transition_pair(y))
+            .flatten()
+            .filter(|t| table_end.map_or(true, |end| t.0 > end))
+            .peekable();
+
+        while let Some((transition_time, timespan)) = transitions.next() {
+            if transitions.peek().map_or(false, |t| t.0 == transition_time)
+            || timespan.offset == surroundings.current.offset {
+                continue;
+            }
+
+            if transition_time < time {
+                surroundings.previous = Some((surroundings.current, transition_time));
+                surroundings.current = timespan;
+            }
+            else {
+                surroundings.next = Some((transition_time, timespan));
+                break;
+            }
+        }
+
+        Some(surroundings)
+    }
+}
+
+
+#[derive(PartialEq, Debug)]
+struct Surroundings<'s, 'a> {
+    previous:  Option<(&'s FixedTimespan<'a>, i64)>,
+    current:   &'s FixedTimespan<'a>,
+    next:      Option<(i64, &'s FixedTimespan<'a>)>,
+}
+
+
+/// The result of converting a *local* time to a *zoned* time with the same
+/// time components. See `TimeZone::convert_local` for more information.
+#[derive(Debug)]
+pub enum LocalTimes<'a> {
+
+    /// This local time is impossible (when a time occurs between two
+    /// timespans, which should never be shown on a wall clock).
+    Impossible,
+
+    /// This local time can be defined unambiguously.
+    Precise(ZonedDateTime<'a>),
+
+    /// This local time is ambiguous (when a time overlaps two timespans,
+    /// which happens twice on a wall clock rather than once).
+    Ambiguous { earlier: ZonedDateTime<'a>, later: ZonedDateTime<'a> },
+}
+
+impl<'a> LocalTimes<'a> {
+
+    /// Extracts the *precise* zoned date time, if present; **panics otherwise**.
+    ///
+    /// It is almost always preferable to use pattern matching on a
+    /// `LocalTimes` value and handle the impossible/ambiguous cases
+    /// explicitly, rather than risking a panic.
+    pub fn unwrap_precise(self) -> ZonedDateTime<'a> {
+        match self {
+            LocalTimes::Precise(p)        => p,
+            LocalTimes::Impossible        => panic!("called `LocalTimes::unwrap()` on an `Impossible` value"),
+            LocalTimes::Ambiguous { .. }  => panic!("called `LocalTimes::unwrap()` on an `Ambiguous` value: {:?}", self),
+        }
+    }
+
+    /// Returns whether this local times result is impossible (when a time
+    /// occurs between two timespans, which should never be shown on a wall
+    /// clock).
+    pub fn is_impossible(&self) -> bool {
+        match *self {
+            LocalTimes::Impossible => true,
+            _                      => false,
+        }
+    }
+
+    /// Returns whether this local times result is ambiguous (when a time
+    /// overlaps two timespans, which happens twice on a wall clock rather
+    /// than once).
+    pub fn is_ambiguous(&self) -> bool {
+        match *self {
+            LocalTimes::Ambiguous { .. } => true,
+            _                            => false,
+        }
+    }
+}
+
+/// The offsets, in seconds, that a local time could have in a set of
+/// timespans, before they get turned into zoned datetimes.
+#[derive(PartialEq, Debug, Copy, Clone)]
+enum LocalOffsets {
+
+    /// The local time has exactly one offset.
+    Precise(i64),
+
+    /// The local time happens twice, once with each offset.
+    Ambiguous { earlier: i64, later: i64 },
+
+    /// The local time was skipped over when the offset changed from
+    /// `before` to `after`.
+    Gap { before: i64, after: i64 },
+}
+
+
+#[derive(Debug, Clone)]
+pub struct ZonedDateTime<'a> {
+    adjusted: LocalDateTime,
+    current_offset: i64,
+    time_zone: TimeZoneSource<'a>,
+}
+
+impl<'a> ZonedDateTime<'a> {
+
+    /// Returns the instant of this datetime. Local times at the very ends
+    /// of the range can be past the range of instants, so these stop at
+    /// `Instant::MIN` or `MAX`.
+    pub fn to_instant(&self) -> Instant {
+        self.adjusted.saturating_sub(Duration::of(self.current_offset)).to_instant()
+    }
+
+    /// Returns the datetime at the same instant as this one, but in the
+    /// given time zone.
+    pub fn with_zone(&self, time_zone: &TimeZone) -> ZonedDateTime<'static> {
+        time_zone.at(self.to_instant())
+    }
+
+    /// Returns the wall-clock date and time of this datetime in its zone.
+    pub fn local_datetime(&self) -> LocalDateTime {
+        self.adjusted
+    }
+
+    /// Returns the total offset from UTC, in seconds, in effect at this
+    /// datetime.
+    pub fn offset(&self) -> i64 {
+        self.current_offset
+    }
+
+    /// Returns the time zone abbreviation in effect at this datetime,
+    /// such as “BST”.
+    pub fn abbreviation(&self) -> String {
+        self.time_zone.with_timespan(self.to_instant().seconds(), |timespan| timespan.name.to_string())
+    }
+
+    /// Returns whether daylight-saving time is in effect at this datetime.
+    pub fn is_dst(&self) -> bool {
+        self.time_zone.with_timespan(self.to_instant().seconds(), |timespan| timespan.is_dst)
+    }
+
+    /// Returns the name of this datetime’s time zone, such as
+    /// “Europe/London”, if it has one.
+    pub fn zone_name(&self) -> Option<&str> {
+        self.time_zone.zone_name()
+    }
+
+    /// Returns the zoned datetime at the given instant in the given time
+    /// zone, with the local time kept within the range of datetimes.
+    fn from_instant(instant: Instant, time_zone: TimeZoneSource<'a>) -> Self {
+        let instant = instant.max(Instant::MIN).min(Instant::MAX);
+        let current_offset = time_zone.offset_at(instant.seconds());
+        let adjusted = LocalDateTime::from_instant(instant.saturating_add(Duration::of(current_offset)));
+        ZonedDateTime { adjusted, current_offset, time_zone }
+    }
+
+    /// Returns the datetime with the same wall-clock time on the day that
+    /// is the given number of days away, which may be a different number
+    /// of hours away if there’s a transition in between. If that time is
+    /// ambiguous or skipped, the disambiguation strategy picks one. Days
+    /// that are out of range return `ResolveError::OutOfRange`.
+    pub fn plus_days(&self, days: i64, disambiguation: Disambiguation) -> Result<Self, ResolveError> {
+        let date = self.adjusted.date().checked_add_days(days).ok_or(ResolveError::OutOfRange)?;
+        let local = LocalDateTime::new(date, self.adjusted.time());
+        self.time_zone.resolve_local(local, disambiguation)
+    }
+
+    /// Returns the datetime with the same wall-clock time on the same day
+    /// of the month, the given number of months away. If that month is
+    /// too short, the last day of the month is used instead. If that time
+    /// is ambiguous or skipped, the disambiguation strategy picks one.
+    /// Months that are out of range return `ResolveError::OutOfRange`.
+    pub fn plus_months(&self, months: i64, disambiguation: Disambiguation) -> Result<Self, ResolveError> {
+        let date = self.adjusted.date();
+        let total = (date.year() * 12 + date.month().months_from_january() as i64)
+            .checked_add(months)
+            .ok_or(ResolveError::OutOfRange)?;
+        let year = total.div_euclid(12);
+        let month = Month::from_zero(total.rem_euclid(12) as i8).unwrap();
+        let day = date.day().min(month.days_in_month(Year(year).is_leap_year()));
+
+        // The day is always within the month, so this can only fail
+        // because the year is out of range.
+        let date = LocalDate::ymd(year, month, day).map_err(|_| ResolveError::OutOfRange)?;
+        let local = LocalDateTime::new(date, self.adjusted.time());
+        self.time_zone.resolve_local(local, disambiguation)
+    }
+}
+
+impl ZonedDateTime<'static> {
+
+    /// Parses a zoned datetime in the format of RFC 9557, such as
+    /// `2026-03-29T01:30:00+01:00[Europe/London]`, using the given strategy
+    /// when the offset doesn’t match the named zone. The `FromStr`
+    /// implementation uses `OffsetMismatch::Reject`.
+    pub fn parse_with(input: &str, mismatch: OffsetMismatch) -> Result<Self, parse::Error<ParseError>> {
+        parse::zoned_datetime(input, mismatch)
+    }
+}
+
+/// Rounds an offset, in seconds, to the nearest whole minute, with half a
+/// minute rounding away from zero. RFC 3339 offsets can’t have seconds,
+/// so offsets such as local mean time’s are written this way.
+pub(crate) fn round_offset_to_minutes(offset: i64) -> i64 {
+    offset.signum() * ((offset.abs() + 30) / 60 * 60)
+}
+
+/// What to do when parsing a zoned datetime whose offset isn’t one that
+/// its time zone has at that local time, which can happen when the zone’s
+/// rules changed after the datetime was written.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum OffsetMismatch {
+
+    /// Return an error.
+    Reject,
+
+    /// Keep the instant given by the local time and offset, and show it
+    /// in the zone with the zone’s offset.
+    PreferOffset,
+
+    /// Keep the local time, and use the zone’s offset at that time
+    /// instead of the one given.
+    PreferZone,
+}
+
+/// An error that occurs when parsing a zoned datetime.
+#[derive(Debug)]
+pub enum ParseError {
+
+    /// The date or time fields were out of range.
+    Date(DateTimeError),
+
+    /// The offset was missing, malformed, or out of range.
+    InvalidOffset(String),
+
+    /// There was no bracketed time zone after the datetime.
+    MissingZone,
+
+    /// A bracketed annotation was malformed, or was marked as critical
+    /// with a `!` but isn’t understood.
+    InvalidAnnotation(String),
+
+    /// The named time zone couldn’t be loaded.
+    Zone(Error),
+
+    /// The offset isn’t one that the zone has at the local time, and the
+    /// strategy was to reject it.
+    OffsetMismatch { local: LocalDateTime, offset: i64 },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParseError::Date(ref e)                => write!(f, "{}", e),
+            ParseError::InvalidOffset(ref offset)  => write!(f, "invalid offset {:?}", offset),
+            ParseError::MissingZone                => write!(f, "missing bracketed time zone"),
+            ParseError::InvalidAnnotation(ref a)   => write!(f, "invalid annotation {:?}", a),
+            ParseError::Zone(ref e)                => write!(f, "{}", e),
+            ParseError::OffsetMismatch { local, offset } => {
+                write!(f, "offset of {} seconds is not valid for {:?} in this time zone", offset, local)
+            },
+        }
+    }
+}
+
+impl ErrorTrait for ParseError {
+    fn source(&self) -> Option<&(dyn ErrorTrait + 'static)> {
+        match *self {
+            ParseError::Date(ref e)  => Some(e),
+            ParseError::Zone(ref e)  => Some(e),
+            _                        => None,
+        }
+    }
+}
+
+
+/// Zoned datetimes are equal when they refer to the same instant, even if
+/// they are in different time zones.
+impl<'a> PartialEq for ZonedDateTime<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_instant() == other.to_instant()
+    }
+}
+
+impl<'a> Eq for ZonedDateTime<'a> {
+}
+
+/// Zoned datetimes are ordered by the instant they refer to.
+impl<'a> PartialOrd for ZonedDateTime<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> Ord for ZonedDateTime<'a> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.to_instant().cmp(&other.to_instant())
+    }
+}
+
+/// Adding a duration to a zoned datetime adds that much elapsed time, so
+/// the result may show a different wall-clock time if there’s a
+/// transition in between.
+impl<'a> Add<Duration> for ZonedDateTime<'a> {
+    type Output = Self;
+
+    fn add(self, duration: Duration) -> Self {
+        let instant = self.to_instant() + duration;
+        ZonedDateTime::from_instant(instant, self.time_zone)
+    }
+}
+
+impl<'a> Sub<Duration> for ZonedDateTime<'a> {
+    type Output = Self;
+
+    fn sub(self, duration: Duration) -> Self {
+        let instant = self.to_instant() - duration;
+        ZonedDateTime::from_instant(instant, self.time_zone)
+    }
+}
+
+impl<'a> DatePiece for ZonedDateTime<'a> {
+    fn year(&self) -> i64 { self.adjusted.year() }
+    fn month(&self) -> Month { self.adjusted.month() }
+    fn day(&self) -> i8 { self.adjusted.day() }
+    fn yearday(&self) -> i16 { self.adjusted.yearday() }
+    fn weekday(&self) -> Weekday { self.adjusted.weekday() }
+}
+
+impl<'a> TimePiece for ZonedDateTime<'a> {
+    fn hour(&self) -> i8 { self.adjusted.hour() }
+    fn minute(&self) -> i8 { self.adjusted.minute() }
+    fn second(&self) -> i8 { self.adjusted.second() }
+    fn millisecond(&self) -> i16 { self.adjusted.millisecond() }
+    fn nanosecond(&self) -> i32 { self.adjusted.nanosecond() }
+}
+
+
+/// The “type” of time that a transition is specified in.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum TimeType {
+
+    /// Wall-clock time: a transition specified when the current time in
+    /// that zone, including any daylight-saving matches, matches the
+    /// transition’s time spec.
+    Wall,
+
+    /// Standard Time: a transition specified when the *standard* time in
+    /// that zone, which excludes any daylight-saving offset, matches the
+    /// transition’s time spec.
+    Standard,
+
+    /// UTC: a transition specified when the time in UTC matches the
+    /// transition’s time spec.
+    UTC,
+}
+
+pub mod runtime {
+    use std::borrow::Cow;
+
+    use super::{FixedTimespan, FixedTimespanSet, ValidationError};
+    use super::posix::{PosixTimeZone, DaylightRule};
+
+    #[derive(PartialEq, Debug)]
+    pub struct OwnedTimeZone {
+        pub name: Option<String>,
+        pub fixed_timespans: OwnedFixedTimespanSet,
+    }
+
+    impl OwnedTimeZone {
+
+        /// Creates a time zone from a set of timespans, after checking
+        /// that the set is well-formed.
+        pub fn new(name: Option<String>, fixed_timespans: OwnedFixedTimespanSet) -> Result<Self, ValidationError> {
+            fixed_timespans.borrow().validate()?;
+            Ok(Self { name, fixed_timespans })
+        }
+    }
+
+    #[derive(PartialEq, Debug)]
+    pub struct OwnedFixedTimespanSet {
+        pub first: FixedTimespan<'static>,
+        pub rest: Vec<(i64, FixedTimespan<'static>)>,
+        pub extension: Option<PosixTimeZone<'static>>,
+    }
+
+    impl OwnedFixedTimespanSet {
+        /// Borrows this set, without copying any of the abbreviations, as
+        /// this happens on every lookup.
+        pub fn borrow(&self) -> FixedTimespanSet<'_> {
+            FixedTimespanSet {
+                first: borrow_timespan(&self.first),
+                rest: &self.rest,
+                extension: self.extension.as_ref().map(|extension| PosixTimeZone {
+                    standard: borrow_timespan(&extension.standard),
+                    daylight: extension.daylight.as_ref().map(|daylight| DaylightRule {
+                        timespan: borrow_timespan(&daylight.timespan),
+                        start: daylight.start,
+                        end: daylight.end,
+                    }),
+                }),
+            }
+        }
+    }
+
+    fn borrow_timespan<'a>(timespan: &'a FixedTimespan<'static>) -> FixedTimespan<'a> {
+        FixedTimespan {
+            offset: timespan.offset,
+            is_dst: timespan.is_dst,
+            name: Cow::Borrowed(&*timespan.name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use super::Surroundings;
+    use std::borrow::Cow;
+
+    #[test]
+    fn valid_zone_names() {
+        assert!(is_valid_zone_name("Europe/London"));
+        assert!(is_valid_zone_name("America/Argentina/Buenos_Aires"));
+        assert!(is_valid_zone_name("UTC"));
+    }
+
+    #[test]
+    fn invalid_zone_names() {
+        assert!(!is_valid_zone_name(""));
+        assert!(!is_valid_zone_name("/etc/passwd"));
+        assert!(!is_valid_zone_name("../../etc/passwd"));
+        assert!(!is_valid_zone_name("Europe/../../secret"));
+        assert!(!is_valid_zone_name("Europe/./London"));
+        assert!(!is_valid_zone_name("Europe//London"));
+        assert!(!is_valid_zone_name("Europe\\London"));
+    }
+
+    const NONE: FixedTimespanSet<'static> = FixedTimespanSet {
+        first: FixedTimespan {
+            offset: 0,
+            is_dst: false,
+            name: Cow::Borrowed("ZONE_A"),
+        },
+        rest: &[],
+        extension: None,
+    };
+
+    #[test]
+    fn empty() {
+        assert_eq!(NONE.find_with_surroundings(1184000000), Surroundings {
+            previous: None,
+            current: &FixedTimespan {
+                offset: 0,
+                is_dst: false,
+                name: Cow::Borrowed("ZONE_A"),
+            },
+            next: None,
+        })
+    }
+
+    const ONE: FixedTimespanSet<'static> = FixedTimespanSet {
+        first: FixedTimespan {
+            offset: 0,
+            is_dst: false,
+            name: Cow::Borrowed("ZONE_A"),
+        },
+        rest: &[
+            (1174784400, FixedTimespan {
+                offset: 3600,
+                is_dst: false,
+                name: Cow::Borrowed("ZONE_B"),
+            }),
+        ],
+        extension: None,
+    };
+
+    #[test]
+    fn just_one_first() {
+        assert_eq!(ONE.find_with_surroundings(1184000000), Surroundings {
+            previous: Some((
+                &FixedTimespan {
+                    offset: 0,
+                    is_dst: false,
+                    name: Cow::Borrowed("ZONE_A"),
+                },
+                1174784400,
+            )),
+            current: &FixedTimespan {
+                offset: 3600,
+                is_dst: false,
+                name: Cow::Borrowed("ZONE_B"),
+            },
+            next: None,
+        });
+    }
+
+    #[test]
+    fn just_one_other() {
+        assert_eq!(ONE.find_with_surroundings(1174000000), Surroundings {
+            previous: None,
+            current: &FixedTimespan {
+                offset: 0,
+                is_dst: false,
+                name: Cow::Borrowed("ZONE_A"),
+            },
+            next: Some((
+                1174784400,
+                &FixedTimespan {
+                    offset: 3600,
+                    is_dst: false,
+                    name: Cow::Borrowed("ZONE_B"),
+                },
+            )),
+        })
+    }
+
+    const MANY: FixedTimespanSet<'static> = FixedTimespanSet {
+        first: FixedTimespan {
+            offset: 0,
+            is_dst: false,
+            name: Cow::Borrowed("ZONE_A"),
+        },
+        rest: &[
+            (1174784400, FixedTimespan {
+                offset: 3600,
+                is_dst: false,
+                name: Cow::Borrowed("ZONE_B"),
+            }),
+            (1193533200, FixedTimespan {
+                offset: 0,
+                is_dst: false,
+                name: Cow::Borrowed("ZONE_C"),
+            }),
+        ],
+        extension: None,
+    };
+
+    #[test]
+    fn multiple_second() {
+        assert_eq!(MANY.find_with_surroundings(1184000000), Surroundings {
+            previous: Some((
+                &FixedTimespan {
+                    offset: 0,
+                    is_dst: false,
+                    name: Cow::Borrowed("ZONE_A"),
+                },
+                1174784400,
+            )),
+            current: &FixedTimespan {
+                offset: 3600,
+                is_dst: false,
+                name: Cow::Borrowed("ZONE_B"),
+            },
+            next: Some((
+                1193533200,
+                &FixedTimespan {
+                    offset: 0,
+                    is_dst: false,
+                    name: Cow::Borrowed("ZONE_C"),
+                }
+            )),
+        });
+    }
+
+    #[test]
+    fn multiple_last() {
+        assert_eq!(MANY.find_with_surroundings(1200000000), Surroundings {
+            previous: Some((
+                &FixedTimespan {
+                    offset: 3600,
+                    is_dst: false,
+                    name: Cow::Borrowed("ZONE_B"),
+                },
+                1193533200,
+            )),
+            current: &FixedTimespan {
+                offset: 0,
+                is_dst: false,
+                name: Cow::Borrowed("ZONE_C"),
+            },
+            next: None,
+        });
+    }
+
+    #[test]
+    fn valid_sets() {
+        assert_eq!(NONE.validate(), Ok(()));
+        assert_eq!(ONE.validate(), Ok(()));
+        assert_eq!(MANY.validate(), Ok(()));
+    }
+
+    const REPEATED: FixedTimespanSet<'static> = FixedTimespanSet {
+        first: FixedTimespan {
+            offset: 0,
+            is_dst: false,
+            name: Cow::Borrowed("ZONE_A"),
+        },
+        rest: &[
+            (1174784400, FixedTimespan {
+                offset: 0,
+                is_dst: true,
+                name: Cow::Borrowed("ZONE_B"),
+            }),
+        ],
+        extension: None,
+    };
+
+    #[test]
+    fn repeated_offset() {
+        assert_eq!(REPEATED.validate(), Err(ValidationError::RepeatedOffset { index: 0 }));
+    }
+
+    #[test]
+    fn repeated_offset_does_not_panic() {
+        let local = LocalDateTime::at(1174784400);
+        assert_eq!(REPEATED.local_offsets(local), LocalOffsets::Precise(0));
+    }
+
+    #[test]
+    fn unsorted() {
+        let set = FixedTimespanSet {
+            rest: &[
+                (1193533200, FixedTimespan { offset: 3600, is_dst: false, name: Cow::Borrowed("ZONE_B") }),
+                (1174784400, FixedTimespan { offset: 0,    is_dst: false, name: Cow::Borrowed("ZONE_C") }),
+            ],
+            .. NONE
+        };
+
+        assert_eq!(set.validate(), Err(ValidationError::UnsortedTransitions { index: 1 }));
+    }
+
+    #[test]
+    fn out_of_range() {
+        let set = FixedTimespanSet {
+            first: FixedTimespan { offset: 90000, is_dst: false, name: Cow::Borrowed("ZONE_A") },
+            .. NONE
+        };
+
+        assert_eq!(set.validate(), Err(ValidationError::OffsetOutOfRange(90000)));
+    }
+
+    #[test]
+    fn malformed_sets_do_not_panic() {
+        use super::posix::{PosixTimeZone, DaylightRule, TransitionRule, RuleDay};
+        use super::runtime::{OwnedTimeZone, OwnedFixedTimespanSet};
+
+        let timespan = |offset| FixedTimespan { offset, is_dst: false, name: Cow::Borrowed("ZONE") };
+        let rule = |day, time| TransitionRule { day, time };
+
+        let sets = vec![
+            OwnedFixedTimespanSet {
+                first: timespan(0),
+                rest: vec![ (i64::MAX, timespan(3600)), (i64::MIN, timespan(0)), (0, timespan(-3600)) ],
+                extension: None,
+            },
+            OwnedFixedTimespanSet {
+                first: timespan(i64::MAX),
+                rest: vec![ (0, timespan(i64::MIN)) ],
+                extension: None,
+            },
+            OwnedFixedTimespanSet {
+                first: timespan(0),
+                rest: vec![ (0, timespan(0)) ],
+                extension: Some(PosixTimeZone {
+                    standard: timespan(i64::MIN),
+                    daylight: Some(DaylightRule {
+                        timespan: timespan(i64::MAX),
+                        start: rule(RuleDay::JulianIgnoringLeap(i16::MAX), i64::MAX),
+                        end: rule(RuleDay::MonthWeekday { month: Month::March, week: -128, weekday: Weekday::Sunday }, i64::MIN),
+                    }),
+                }),
+            },
+        ];
+
+        for set in sets {
+            let zone = TimeZone(TimeZoneSource::Runtime(Arc::new(OwnedTimeZone { name: None, fixed_timespans: set })));
+
+            for &time in &[ i64::MIN, Instant::MIN.seconds(), 0, Instant::MAX.seconds(), i64::MAX ] {
+                let _ = zone.at(Instant::at(time)).to_instant();
+                let _ = zone.next_transition(Instant::at(time));
+                let _ = zone.previous_transition(Instant::at(time));
+            }
+
+            for &local in &[ LocalDateTime::MIN, LocalDateTime::at(0), LocalDateTime::MAX ] {
+                let _ = zone.offset(local);
+                let _ = zone.convert_local(local);
+                let _ = zone.resolve_local(local, Disambiguation::Earlier).map(|z| z.to_instant());
+                let _ = zone.resolve_local(local, Disambiguation::Later).map(|z| z.to_instant());
+            }
+        }
+    }
+}
@@ -0,0 +1,611 @@
+//! POSIX TZ strings, which describe a time zone’s current rules.
+//!
+//! A TZ string such as `CET-1CEST,M3.5.0,M10.5.0/3` gives a standard
+//! abbreviation and offset, and optionally a daylight-saving abbreviation
+//! and offset along with the rules for when daylight-saving time starts
+//! and ends each year. These strings can be found in the `TZ` environment
+//! variable, and in the footer of TZif files, where they extend the list
+//! of transitions into the future.
+//!
+//! Note that the offsets in a TZ string are written as *west* of UTC, so
+//! “CET-1” means one hour *ahead* of UTC. Offsets in this library are
+//! always stored as east of UTC.
+
+use std::borrow::Cow;
+use std::error::Error as ErrorTrait;
+use std::fmt;
+use std::str::FromStr;
+
+use cal::{DatePiece, LocalDate, LocalDateTime, LocalTime, Month};
+use cal::datetime::{Weekday, Year};
+use cal::zone::FixedTimespan;
+
+
+/// A time zone described by a POSIX TZ string.
+#[derive(PartialEq, Debug, Clone)]
+pub struct PosixTimeZone<'a> {
+
+    /// The timespan in effect outside of daylight-saving time, or all of
+    /// the time if there is no daylight-saving rule.
+    pub standard: FixedTimespan<'a>,
+
+    /// The daylight-saving timespan, and when it begins and ends each year.
+    pub daylight: Option<DaylightRule<'a>>,
+}
+
+/// The yearly daylight-saving time rule of a POSIX TZ string.
+#[derive(PartialEq, Debug, Clone)]
+pub struct DaylightRule<'a> {
+
+    /// The timespan in effect during daylight-saving time.
+    pub timespan: FixedTimespan<'a>,
+
+    /// When daylight-saving time starts, in standard time.
+    pub start: TransitionRule,
+
+    /// When daylight-saving time ends, in daylight-saving time.
+    pub end: TransitionRule,
+}
+
+/// A rule for the day and time of a transition in every year.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct TransitionRule {
+
+    /// The day the transition happens on.
+    pub day: RuleDay,
+
+    /// The time of the transition, as a number of seconds after local
+    /// midnight on that day. This can be negative, or more than a day, to
+    /// represent transitions that happen on the days around it.
+    pub time: i64,
+}
+
+/// The day of the year that a transition happens on.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum RuleDay {
+
+    /// `Jn`: the Julian day *n*, from 1 to 365, where the 29th of February
+    /// is never counted, so day 60 is always the 1st of March.
+    JulianIgnoringLeap(i16),
+
+    /// `n`: the zero-based day of the year, from 0 to 365, where the 29th
+    /// of February is counted in leap years. Day 365 only exists in leap
+    /// years, so in other years it means the 31st of December.
+    JulianZero(i16),
+
+    /// `Mm.w.d`: the *d*th weekday of week *w* of month *m*, where week 5
+    /// means the last such weekday in the month.
+    MonthWeekday { month: Month, week: i8, weekday: Weekday },
+}
+
+/// The rule used when a TZ string has a daylight-saving abbreviation but
+/// no transition rules, which matches the current US rules, as the
+/// reference tz code does.
+const DEFAULT_START: TransitionRule = TransitionRule {
+    day: RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday },
+    time: 2 * 3600,
+};
+
+const DEFAULT_END: TransitionRule = TransitionRule {
+    day: RuleDay::MonthWeekday { month: Month::November, week: 1, weekday: Weekday::Sunday },
+    time: 2 * 3600,
+};
+
+
+impl<'a> PosixTimeZone<'a> {
+
+    /// Returns the transitions that happen in the given year, in order,
+    /// each as a Unix timestamp and the timespan that begins at that time.
+    /// There are either two transitions or none at all, which is also the
+    /// case for years outside the range that dates can be created in.
+    pub fn transitions_in_year(&self, year: i64) -> Vec<(i64, FixedTimespan<'a>)> {
+        match self.transition_pair(year) {
+            Some(pair)  => pair.iter().map(|&(time, timespan)| (time, timespan.clone())).collect(),
+            None        => Vec::new(),
+        }
+    }
+
+    /// Returns the same transitions as `transitions_in_year`, but with
+    /// the timespans borrowed from this rule, so nothing gets allocated.
+    pub(crate) fn transition_pair(&self, year: i64) -> Option<[(i64, &FixedTimespan<'a>); 2]> {
+        let daylight = self.daylight.as_ref()?;
+
+        let start = daylight.start.timestamp(year, self.standard.offset)?;
+        let end   = daylight.end.timestamp(year, daylight.timespan.offset)?;
+
+        if end < start {
+            Some([ (end, &self.standard), (start, &daylight.timespan) ])
+        }
+        else {
+            Some([ (start, &daylight.timespan), (end, &self.standard) ])
+        }
+    }
+
+    /// Returns the timespan in effect at the given Unix timestamp.
+    pub fn timespan_at(&self, time: i64) -> FixedTimespan<'a> {
+        let year = LocalDateTime::at(time).year();
+
+        (year - 1 ..= year).flat_map(|y| self.transitions_in_year(y))
+                           .rfind(|t| t.0 < time)
+                           .map_or_else(|| self.standard.clone(), |t| t.1)
+    }
+}
+
+impl TransitionRule {
+
+    /// Returns the Unix timestamp at which this rule applies in the given
+    /// year, when the local time is at the given offset from UTC, or
+    /// `None` if the year is out of range.
+    fn timestamp(&self, year: i64, offset: i64) -> Option<i64> {
+        let date = self.day.date(year)?;
+        let midnight = LocalDateTime::new(date, LocalTime::midnight()).to_instant().seconds();
+        Some(midnight.saturating_add(self.time).saturating_sub(offset))
+    }
+}
+
+impl RuleDay {
+
+    /// Returns the date this rule refers to in the given year, or `None`
+    /// if the year is outside the range that dates can be created in.
+    fn date(&self, year: i64) -> Option<LocalDate> {
+        let is_leap_year = Year(year).is_leap_year();
+
+        // The values here are range-checked when parsing, but rules can
+        // also be written by hand, so they get clamped to their ranges to
+        // keep the dates valid whenever the year is.
+        let date = match *self {
+            RuleDay::JulianIgnoringLeap(day) => {
+                let day = day.clamp(1, 365);
+                let day = if is_leap_year && day >= 60 { day + 1 } else { day };
+                LocalDate::yd(year, day as i64)
+            },
+
+            RuleDay::JulianZero(day) => {
+                let day = if is_leap_year { day.clamp(0, 365) } else { day.clamp(0, 364) };
+                LocalDate::yd(year, day as i64 + 1)
+            },
+
+            RuleDay::MonthWeekday { month, week, weekday } => {
+                let week = week.clamp(1, 5);
+                let first = LocalDate::ymd(year, month, 1).ok()?;
+                let first_weekday = first.weekday() as i8;
+                let mut day = 1 + (weekday as i8 - first_weekday + 7) % 7 + (week - 1) * 7;
+
+                while day > month.days_in_month(is_leap_year) {
+                    day -= 7;
+                }
+
+                LocalDate::ymd(year, month, day)
+            },
+        };
+
+        date.ok()
+    }
+}
+
+
+impl FromStr for PosixTimeZone<'static> {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser { input: input.as_bytes(), position: 0 };
+
+        let standard_name = parser.name()?;
+        let standard_offset = -parser.offset()?;
+        let standard = FixedTimespan {
+            offset: standard_offset,
+            is_dst: false,
+            name:   Cow::Owned(standard_name),
+        };
+
+        if parser.is_finished() {
+            return Ok(Self { standard, daylight: None });
+        }
+
+        let daylight_name = parser.name()?;
+        let daylight_offset = match parser.peek() {
+            Some(b',') | None  => standard_offset + 3600,
+            Some(_)            => -parser.offset()?,
+        };
+
+        let (start, end) = if parser.is_finished() {
+            (DEFAULT_START, DEFAULT_END)
+        }
+        else {
+            parser.expect(b',')?;
+            let start = parser.rule()?;
+            parser.expect(b',')?;
+            let end = parser.rule()?;
+            (start, end)
+        };
+
+        if !parser.is_finished() {
+            return Err(Error::TrailingInput);
+        }
+
+        let timespan = FixedTimespan {
+            offset: daylight_offset,
+            is_dst: true,
+            name:   Cow::Owned(daylight_name),
+        };
+
+        Ok(Self { standard, daylight: Some(DaylightRule { timespan, start, end }) })
+    }
+}
+
+/// Writes the zone back out as a TZ string, such as the footer of a TZif
+/// file. Parts that have default values, such as a daylight-saving offset
+/// one hour ahead of standard time, are left out, the way `zic` does.
+impl<'a> fmt::Display for PosixTimeZone<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_name(f, &self.standard.name)?;
+        write_hms(f, -self.standard.offset)?;
+
+        if let Some(ref daylight) = self.daylight {
+            write_name(f, &daylight.timespan.name)?;
+            if daylight.timespan.offset != self.standard.offset + 3600 {
+                write_hms(f, -daylight.timespan.offset)?;
+            }
+
+            write!(f, ",{},{}", daylight.start, daylight.end)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for TransitionRule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.day {
+            RuleDay::JulianIgnoringLeap(day)                => write!(f, "J{}", day)?,
+            RuleDay::JulianZero(day)                        => write!(f, "{}", day)?,
+            RuleDay::MonthWeekday { month, week, weekday }  => write!(f, "M{}.{}.{}", month as i8, week, weekday as i8)?,
+        }
+
+        if self.time != 2 * 3600 {
+            write!(f, "/")?;
+            write_hms(f, self.time)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Writes an abbreviation, quoting it in angle brackets unless it’s made
+/// up entirely of letters.
+fn write_name(f: &mut fmt::Formatter, name: &str) -> fmt::Result {
+    if name.bytes().all(|b| b.is_ascii_alphabetic()) {
+        write!(f, "{}", name)
+    }
+    else {
+        write!(f, "<{}>", name)
+    }
+}
+
+/// Writes a signed number of seconds as `hh[:mm[:ss]]`, leaving out the
+/// minutes and seconds when they’re zero.
+fn write_hms(f: &mut fmt::Formatter, seconds: i64) -> fmt::Result {
+    if seconds < 0 {
+        write!(f, "-")?;
+    }
+
+    let seconds = seconds.abs();
+    write!(f, "{}", seconds / 3600)?;
+
+    match (seconds / 60 % 60, seconds % 60) {
+        (0, 0)  => Ok(()),
+        (m, 0)  => write!(f, ":{:02}", m),
+        (m, s)  => write!(f, ":{:02}:{:02}", m, s),
+    }
+}
+
+
+struct Parser<'a> {
+    input: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<u8> {
+        self.input.get(self.position).cloned()
+    }
+
+    fn is_finished(&self) -> bool {
+        self.position == self.input.len()
+    }
+
+    fn next_if(&mut self, wanted: u8) -> bool {
+        if self.peek() == Some(wanted) {
+            self.position += 1;
+            true
+        }
+        else {
+            false
+        }
+    }
+
+    fn expect(&mut self, wanted: u8) -> Result<(), Error> {
+        if self.next_if(wanted) { Ok(()) }
+                           else { Err(Error::InvalidRule) }
+    }
+
+    /// Reads an abbreviation, which is either three or more letters, or
+    /// three or more letters, digits, and signs in angle brackets.
+    fn name(&mut self) -> Result<String, Error> {
+        let quoted = self.next_if(b'<');
+        let start = self.position;
+
+        while let Some(b) = self.peek() {
+            let allowed = if quoted { b.is_ascii_alphanumeric() || b == b'+' || b == b'-' }
+                                     else { b.is_ascii_alphabetic() };
+            if !allowed { break }
+            self.position += 1;
+        }
+
+        let name = &self.input[start .. self.position];
+        if name.len() < 3 || (quoted && !self.next_if(b'>')) {
+            return Err(Error::InvalidName);
+        }
+
+        // The name only contains ASCII characters, so this can’t fail.
+        Ok(String::from_utf8(name.to_vec()).unwrap())
+    }
+
+    /// Reads an unsigned number with at most the given number of digits.
+    fn number(&mut self, max_digits: usize) -> Option<i64> {
+        let start = self.position;
+        let mut number = 0;
+
+        while let Some(b) = self.peek() {
+            if !b.is_ascii_digit() || self.position - start == max_digits { break }
+            number = number * 10 + (b - b'0') as i64;
+            self.position += 1;
+        }
+
+        if self.position == start { None }
+                              else { Some(number) }
+    }
+
+    /// Reads a signed `hh[:mm[:ss]]` value, returning a number of seconds.
+    fn hms(&mut self, max_hours: i64) -> Option<i64> {
+        let sign = if self.next_if(b'-') { -1 }
+              else { let _ = self.next_if(b'+'); 1 };
+
+        let hours = self.number(3).filter(|&h| h <= max_hours)?;
+        let mut seconds = hours * 3600;
+
+        if self.next_if(b':') {
+            seconds += self.number(2).filter(|&m| m < 60)? * 60;
+
+            if self.next_if(b':') {
+                seconds += self.number(2).filter(|&s| s < 60)?;
+            }
+        }
+
+        Some(sign * seconds)
+    }
+
+    /// Reads a UTC offset, which is positive *west* of UTC.
+    fn offset(&mut self) -> Result<i64, Error> {
+        self.hms(24).ok_or(Error::InvalidOffset)
+    }
+
+    /// Reads a transition rule: a day, optionally followed by a slash and
+    /// the time of day, which may be negative or past 24 hours.
+    fn rule(&mut self) -> Result<TransitionRule, Error> {
+        let day = if self.next_if(b'J') {
+            match self.number(3) {
+                Some(n) if (1 ..= 365).contains(&n)  => RuleDay::JulianIgnoringLeap(n as i16),
+                _                                    => return Err(Error::InvalidRule),
+            }
+        }
+        else if self.next_if(b'M') {
+            let month = self.number(2).and_then(|m| Month::from_one(m as i8).ok());
+            let week = if self.next_if(b'.') { self.number(1).filter(|w| (1 ..= 5).contains(w)) } else { None };
+            let weekday = if self.next_if(b'.') { self.number(1).and_then(|d| Weekday::from_zero(d as i8).ok()) } else { None };
+
+            match (month, week, weekday) {
+                (Some(month), Some(week), Some(weekday))  => RuleDay::MonthWeekday { month, week: week as i8, weekday },
+                _                                         => return Err(Error::InvalidRule),
+            }
+        }
+        else {
+            match self.number(3) {
+                Some(n) if n <= 365  => RuleDay::JulianZero(n as i16),
+                _                    => return Err(Error::InvalidRule),
+            }
+        };
+
+        let time = if self.next_if(b'/') {
+            self.hms(167).ok_or(Error::InvalidTime)?
+        }
+        else {
+            2 * 3600
+        };
+
+        Ok(TransitionRule { day, time })
+    }
+}
+
+
+/// An error that can occur when parsing a POSIX TZ string.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum Error {
+
+    /// An abbreviation is missing, too short, or has an unclosed bracket.
+    InvalidName,
+
+    /// An offset from UTC is missing or out of range.
+    InvalidOffset,
+
+    /// A daylight-saving rule is missing or has an out-of-range day.
+    InvalidRule,
+
+    /// A daylight-saving rule has an out-of-range time.
+    InvalidTime,
+
+    /// There was more input after the end of the rules.
+    TrailingInput,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::InvalidName    => write!(f, "invalid time zone abbreviation"),
+            Error::InvalidOffset  => write!(f, "invalid UTC offset"),
+            Error::InvalidRule    => write!(f, "invalid daylight-saving rule"),
+            Error::InvalidTime    => write!(f, "invalid daylight-saving rule time"),
+            Error::TrailingInput  => write!(f, "unexpected input after TZ string"),
+        }
+    }
+}
+
+impl ErrorTrait for Error {
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(input: &str) -> Result<PosixTimeZone<'static>, Error> {
+        input.parse()
+    }
+
+    #[test]
+    fn standard_only() {
+        let tz = parse("<-03>3").unwrap();
+        assert_eq!(tz.standard.name, "-03");
+        assert_eq!(tz.standard.offset, -3 * 3600);
+        assert_eq!(tz.daylight, None);
+    }
+
+    #[test]
+    fn utc() {
+        let tz = parse("UTC0").unwrap();
+        assert_eq!(tz.standard.offset, 0);
+        assert!(tz.transitions_in_year(2030).is_empty());
+    }
+
+    #[test]
+    fn minutes() {
+        let tz = parse("<+0330>-3:30").unwrap();
+        assert_eq!(tz.standard.offset, 3 * 3600 + 30 * 60);
+    }
+
+    #[test]
+    fn central_europe() {
+        let tz = parse("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+        let daylight = tz.daylight.unwrap();
+        assert_eq!(daylight.timespan.name, "CEST");
+        assert_eq!(daylight.timespan.offset, 7200);
+        assert_eq!(daylight.start, TransitionRule {
+            day: RuleDay::MonthWeekday { month: Month::March, week: 5, weekday: Weekday::Sunday },
+            time: 7200,
+        });
+        assert_eq!(daylight.end.time, 3 * 3600);
+    }
+
+    #[test]
+    fn default_rules() {
+        let tz = parse("EST5EDT").unwrap();
+        let daylight = tz.daylight.unwrap();
+        assert_eq!(daylight.timespan.offset, -4 * 3600);
+        assert_eq!(daylight.start, DEFAULT_START);
+        assert_eq!(daylight.end, DEFAULT_END);
+    }
+
+    #[test]
+    fn negative_and_extended_times() {
+        let tz = parse("<-02>2<-01>,M3.5.0/-1,M10.5.0/0").unwrap();
+        assert_eq!(tz.daylight.unwrap().start.time, -3600);
+
+        let tz = parse("IST-2IDT,M3.4.4/26,M10.5.0").unwrap();
+        assert_eq!(tz.daylight.unwrap().start.time, 26 * 3600);
+    }
+
+    #[test]
+    fn julian_days() {
+        let tz = parse("XXX3YYY,J60/0,300/0").unwrap();
+        let daylight = tz.daylight.unwrap();
+        assert_eq!(daylight.start.day, RuleDay::JulianIgnoringLeap(60));
+        assert_eq!(daylight.end.day, RuleDay::JulianZero(300));
+
+        // J60 is always the 1st of March, whether or not it’s a leap year.
+        assert_eq!(RuleDay::JulianIgnoringLeap(60).date(2024), Some(LocalDate::ymd(2024, Month::March, 1).unwrap()));
+        assert_eq!(RuleDay::JulianIgnoringLeap(60).date(2023), Some(LocalDate::ymd(2023, Month::March, 1).unwrap()));
+        assert_eq!(RuleDay::JulianZero(59).date(2024), Some(LocalDate::ymd(2024, Month::February, 29).unwrap()));
+    }
+
+    #[test]
+    fn last_julian_zero_day() {
+        assert_eq!(RuleDay::JulianZero(365).date(2024), Some(LocalDate::ymd(2024, Month::December, 31).unwrap()));
+        assert_eq!(RuleDay::JulianZero(365).date(2023), Some(LocalDate::ymd(2023, Month::December, 31).unwrap()));
+        assert_eq!(RuleDay::JulianZero(364).date(2023), Some(LocalDate::ymd(2023, Month::December, 31).unwrap()));
+    }
+
+    #[test]
+    fn last_sunday() {
+        let day = RuleDay::MonthWeekday { month: Month::October, week: 5, weekday: Weekday::Sunday };
+        assert_eq!(day.date(2027), Some(LocalDate::ymd(2027, Month::October, 31).unwrap()));
+        assert_eq!(day.date(2028), Some(LocalDate::ymd(2028, Month::October, 29).unwrap()));
+    }
+
+    #[test]
+    fn second_sunday() {
+        let day = RuleDay::MonthWeekday { month: Month::March, week: 2, weekday: Weekday::Sunday };
+        assert_eq!(day.date(2027), Some(LocalDate::ymd(2027, Month::March, 14).unwrap()));
+    }
+
+    #[test]
+    fn transitions() {
+        let tz = parse("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+        let transitions = tz.transitions_in_year(2027);
+
+        // 28th March 2027, 01:00 UTC, and 31st October 2027, 01:00 UTC.
+        assert_eq!(transitions[0].0, 1806195600);
+        assert_eq!(transitions[0].1.name, "CEST");
+        assert_eq!(transitions[1].0, 1824944400);
+        assert_eq!(transitions[1].1.name, "CET");
+    }
+
+    #[test]
+    fn southern_hemisphere() {
+        let tz = parse("AEST-10AEDT,M10.1.0,M4.1.0/3").unwrap();
+        let transitions = tz.transitions_in_year(2030);
+        assert_eq!(transitions[0].1.name, "AEST");
+        assert_eq!(transitions[1].1.name, "AEDT");
+
+        assert_eq!(tz.timespan_at(1893456000).name, "AEDT");  // 1st January 2030
+        assert_eq!(tz.timespan_at(1909051200).name, "AEST");  // 1st July 2030
+    }
+
+    #[test]
+    fn display() {
+        for input in &[ "UTC0", "<-03>3", "<+0330>-3:30", "CET-1CEST,M3.5.0,M10.5.0/3",
+                        "<-02>2<-01>,M3.5.0/-1,M10.5.0/0", "IST-2IDT,M3.4.4/26,M10.5.0",
+                        "XXX3YYY,J60/0,300/0", "LMT0:01:15", "<+1030>-10:30<+11>-11,M10.1.0,M4.1.0" ] {
+            assert_eq!(parse(input).unwrap().to_string(), *input);
+        }
+    }
+
+    #[test]
+    fn display_default_rules() {
+        assert_eq!(parse("EST5EDT").unwrap().to_string(), "EST5EDT,M3.2.0,M11.1.0");
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(parse(""), Err(Error::InvalidName));
+        assert_eq!(parse("AB1"), Err(Error::InvalidName));
+        assert_eq!(parse("<UTC0"), Err(Error::InvalidName));
+        assert_eq!(parse("CET"), Err(Error::InvalidOffset));
+        assert_eq!(parse("CET25"), Err(Error::InvalidOffset));
+        assert_eq!(parse("CET-1CEST,M13.5.0,M10.5.0"), Err(Error::InvalidRule));
+        assert_eq!(parse("CET-1CEST,M3.6.0,M10.5.0"), Err(Error::InvalidRule));
+        assert_eq!(parse("CET-1CEST,M3.5.0"), Err(Error::InvalidRule));
+        assert_eq!(parse("CET-1CEST,J0,J365"), Err(Error::InvalidRule));
+        assert_eq!(parse("CET-1CEST,M3.5.0/200,M10.5.0"), Err(Error::InvalidTime));
+        assert_eq!(parse("CET-1CEST,M3.5.0,M10.5.0 "), Err(Error::TrailingInput));
+    }
+}
@@ -0,0 +1,149 @@
+//! A shared cache of time zones loaded from the zoneinfo database.
+//!
+//! Loading a zone with `TimeZone::named` reads and parses its file every
+//! time. A `TimeZoneRegistry` keeps each zone it loads, so asking for the
+//! same zone again is only a map lookup, and the `TimeZone` values it hands
+//! out share their timespans rather than copying them.
+//!
+//! A registry can be shared between threads, such as by putting it in an
+//! `Arc` or a `static`. When the system’s tzdata package is updated, call
+//! `reload` to read every cached zone again without restarting.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard, PoisonError};
+
+use cal::zone::{TimeZone, Error};
+use system::zoneinfo_directories;
+
+
+/// A thread-safe cache of time zones, keyed by name.
+#[derive(Debug)]
+pub struct TimeZoneRegistry {
+    directories: Vec<PathBuf>,
+    zones: RwLock<HashMap<String, TimeZone>>,
+}
+
+impl Default for TimeZoneRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TimeZoneRegistry {
+
+    /// Creates a new, empty registry that loads zones from the system’s
+    /// zoneinfo database, searching the same directories as
+    /// `TimeZone::named`.
+    pub fn new() -> Self {
+        Self::with_directories(zoneinfo_directories())
+    }
+
+    /// Creates a new, empty registry that loads zones from the first of the
+    /// given directories that has them.
+    pub fn with_directories(directories: Vec<PathBuf>) -> Self {
+        Self { directories, zones: RwLock::new(HashMap::new()) }
+    }
+
+    /// Returns the directories that zones are loaded from.
+    pub fn directories(&self) -> &[PathBuf] {
+        &self.directories
+    }
+
+    /// Returns the time zone with the given name, such as “Europe/London”,
+    /// loading it if it isn’t already cached.
+    ///
+    /// Zones that fail to load aren’t cached, so asking for one again will
+    /// try to load it again.
+    pub fn get(&self, name: &str) -> Result<TimeZone, Error> {
+        if let Some(zone) = self.read().get(name) {
+            return Ok(zone.clone());
+        }
+
+        let zone = TimeZone::named_in_directories(&self.directories, name)?;
+
+        // Another thread may have loaded the same zone in the meantime, in
+        // which case its copy is kept so every caller shares the same one.
+        let mut zones = self.write();
+        Ok(zones.entry(name.to_owned()).or_insert(zone).clone())
+    }
+
+    /// Returns whether the zone with the given name is cached.
+    pub fn contains(&self, name: &str) -> bool {
+        self.read().contains_key(name)
+    }
+
+    /// Returns the names of every cached zone, in alphabetical order.
+    pub fn cached_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.read().keys().cloned().collect();
+        names.sort();
+        names
+    }
+
+    /// Reads every cached zone from disk again, so that later calls to
+    /// `get` return the updated zones. `TimeZone` values that were handed
+    /// out before are unaffected.
+    ///
+    /// Zones that no longer exist are removed from the cache. If any other
+    /// zone fails to load, the error is returned and the cache is left as
+    /// it was.
+    pub fn reload(&self) -> Result<(), Error> {
+        let names = self.cached_names();
+        let mut reloaded = HashMap::with_capacity(names.len());
+
+        for name in &names {
+            match TimeZone::named_in_directories(&self.directories, name) {
+                Ok(zone)                 => { let _ = reloaded.insert(name.clone(), zone); },
+                Err(Error::NotFound(_))  => continue,
+                Err(e)                   => return Err(e),
+            }
+        }
+
+        // Zones first asked for while the others were being read are kept,
+        // as they’re already up to date.
+        let mut zones = self.write();
+        for (name, zone) in zones.drain() {
+            if names.binary_search(&name).is_err() {
+                let _ = reloaded.insert(name, zone);
+            }
+        }
+        *zones = reloaded;
+        Ok(())
+    }
+
+    /// Removes every zone from the cache.
+    pub fn clear(&self) {
+        self.write().clear();
+    }
+
+    // A panic while holding the lock can’t leave the map half-updated, so
+    // a poisoned lock is still safe to use.
+
+    fn read(&self) -> RwLockReadGuard<'_, HashMap<String, TimeZone>> {
+        self.zones.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, HashMap<String, TimeZone>> {
+        self.zones.write().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn is_send_and_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn thread_safe() {
+        is_send_and_sync::<TimeZoneRegistry>();
+    }
+
+    #[test]
+    fn empty() {
+        let registry = TimeZoneRegistry::with_directories(Vec::new());
+        assert!(registry.cached_names().is_empty());
+        assert!(registry.reload().is_ok());
+    }
+}
@@ -0,0 +1,1068 @@
+//! Compiling time zones from tzdata source files.
+//!
+//! The IANA time zone database is distributed as a set of text files,
+//! such as `africa`, `europe`, and `northamerica`, made up of three kinds
+//! of line:
+//!
+//! - **Rule** lines, which describe when a named group of daylight-saving
+//!   rules changes the clocks, and by how much;
+//! - **Zone** lines, which describe a location’s standard offset from UTC
+//!   over a span of history, and which rules to apply during it;
+//! - **Link** lines, which give another name to an existing zone.
+//!
+//! This module reads these files and expands the rules into lists of
+//! transitions, the same way `zic` does when it produces TZif files. It
+//! accepts the abbreviations that `zic` accepts, so it can also read the
+//! condensed `tzdata.zi` file.
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::error::Error as ErrorTrait;
+use std::fmt;
+use std::sync::Arc;
+
+use cal::{DatePiece, LocalDate, LocalDateTime, LocalTime, Month};
+use cal::datetime::{Weekday, Year};
+use cal::zone::{FixedTimespan, TimeType, TimeZone, TimeZoneSource, ValidationError};
+use cal::zone::posix::{DaylightRule, PosixTimeZone, RuleDay, TransitionRule};
+use cal::zone::runtime::{OwnedTimeZone, OwnedFixedTimespanSet};
+
+
+/// A set of parsed tzdata source files.
+///
+/// Rules can be used by zones in other files, so every file should be
+/// added to the database before any zones are compiled.
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct Database {
+    rules: BTreeMap<String, Vec<Rule>>,
+    zones: BTreeMap<String, Vec<ZoneLine>>,
+    links: BTreeMap<String, String>,
+}
+
+/// A **Rule** line, which describes a transition that happens in each
+/// year of a span of years.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Rule {
+
+    /// The first year this rule applies in.
+    pub from: i64,
+
+    /// The last year this rule applies in, which is `i64::MAX` for rules
+    /// that apply forever.
+    pub to: i64,
+
+    /// The month the transition happens in.
+    pub month: Month,
+
+    /// The day of the month the transition happens on.
+    pub day: DaySpec,
+
+    /// The time of day the transition happens at, in seconds.
+    pub time: i64,
+
+    /// Whether `time` is in wall-clock, standard, or UTC time.
+    pub time_type: TimeType,
+
+    /// The amount of time, in seconds, added to the standard offset after
+    /// the transition.
+    pub save: i64,
+
+    /// Whether the time after the transition is daylight-saving time.
+    pub is_dst: bool,
+
+    /// The letters substituted into a zone’s format after the transition,
+    /// such as the “D” in “EDT”.
+    pub letters: String,
+}
+
+/// The day of a month on which a rule or a zone line’s end applies.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum DaySpec {
+
+    /// A fixed day of the month, such as `5`.
+    Fixed(i8),
+
+    /// The last given weekday of the month, such as `lastSun`.
+    Last(Weekday),
+
+    /// The first given weekday on or after a day, such as `Sun>=8`.
+    OnOrAfter(Weekday, i8),
+
+    /// The last given weekday on or before a day, such as `Sun<=25`.
+    OnOrBefore(Weekday, i8),
+}
+
+/// A **Zone** line, or one of its continuation lines, which describes a
+/// location’s time over a span of history.
+#[derive(PartialEq, Debug, Clone)]
+pub struct ZoneLine {
+
+    /// The standard offset from UTC, in seconds.
+    pub offset: i64,
+
+    /// The daylight-saving rules to apply during this span.
+    pub rules: ZoneRules,
+
+    /// The format of the abbreviation, such as `E%sT`, `GMT/BST`, or `%z`.
+    pub format: String,
+
+    /// When this span ends, or `None` if it lasts forever.
+    pub until: Option<Until>,
+}
+
+/// The daylight-saving rules used by a zone line.
+#[derive(PartialEq, Debug, Clone)]
+pub enum ZoneRules {
+
+    /// Standard time applies all the time.
+    Standard,
+
+    /// A fixed amount of daylight-saving time, in seconds, applies all the
+    /// time.
+    Fixed(i64),
+
+    /// The rules with the given name apply.
+    Named(String),
+}
+
+/// The time at which a zone line stops applying.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct Until {
+    pub year: i64,
+    pub month: Month,
+    pub day: DaySpec,
+    pub time: i64,
+    pub time_type: TimeType,
+}
+
+
+impl Database {
+
+    /// Creates a new database with no rules, zones, or links.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses the given tzdata source file, adding its rules, zones, and
+    /// links to this database.
+    pub fn parse(&mut self, source: &str) -> Result<(), Error> {
+        let mut continuing: Option<String> = None;
+
+        for (index, line) in source.lines().enumerate() {
+            let fail = |kind| Error::Parse { line: index + 1, kind };
+
+            let line = match line.find('#') {
+                Some(hash) => &line[.. hash],
+                None       => line,
+            };
+
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.is_empty() {
+                continue;
+            }
+
+            if let Some(name) = continuing.take() {
+                let zone_line = parse_zone_line(&fields).map_err(fail)?;
+                if zone_line.until.is_some() {
+                    continuing = Some(name.clone());
+                }
+                self.zones.get_mut(&name).unwrap().push(zone_line);
+                continue;
+            }
+
+            match lookup(fields[0], &[ "Rule", "Zone", "Link" ]) {
+                Some(0) => {
+                    if fields.len() != 10 {
+                        return Err(fail(ParseError::FieldCount));
+                    }
+
+                    let rule = parse_rule(&fields[2 ..]).map_err(fail)?;
+                    self.rules.entry(fields[1].to_owned()).or_default().push(rule);
+                },
+
+                Some(1) => {
+                    if fields.len() < 5 {
+                        return Err(fail(ParseError::FieldCount));
+                    }
+
+                    let name = fields[1].to_owned();
+                    if self.zones.contains_key(&name) || self.links.contains_key(&name) {
+                        return Err(fail(ParseError::Duplicate(name)));
+                    }
+
+                    let zone_line = parse_zone_line(&fields[2 ..]).map_err(fail)?;
+                    if zone_line.until.is_some() {
+                        continuing = Some(name.clone());
+                    }
+                    let _ = self.zones.insert(name, vec![ zone_line ]);
+                },
+
+                Some(_) => {
+                    if fields.len() != 3 {
+                        return Err(fail(ParseError::FieldCount));
+                    }
+
+                    let name = fields[2].to_owned();
+                    if self.zones.contains_key(&name) || self.links.contains_key(&name) {
+                        return Err(fail(ParseError::Duplicate(name)));
+                    }
+
+                    let _ = self.links.insert(name, fields[1].to_owned());
+                },
+
+                None => return Err(fail(ParseError::UnknownLineType)),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the names of all the zones in this database, excluding
+    /// links, in alphabetical order.
+    pub fn zone_names(&self) -> Vec<&str> {
+        self.zones.keys().map(|k| &**k).collect()
+    }
+
+    /// Returns the names of all the links in this database, along with
+    /// the names they link to, in alphabetical order.
+    pub fn links(&self) -> Vec<(&str, &str)> {
+        self.links.iter().map(|(k, v)| (&**k, &**v)).collect()
+    }
+
+    /// Returns the zone lines for the zone with the given name, following
+    /// any links.
+    pub fn zone_lines(&self, name: &str) -> Result<&[ZoneLine], Error> {
+        let mut target = name;
+
+        // Links should point straight to a zone, but chains of them are
+        // allowed. Limit the length of the chain to catch loops.
+        for _ in 0 .. 16 {
+            if let Some(lines) = self.zones.get(target) {
+                return Ok(lines);
+            }
+
+            match self.links.get(target) {
+                Some(next)  => target = next,
+                None        => break,
+            }
+        }
+
+        Err(Error::UnknownZone(name.to_owned()))
+    }
+
+    /// Compiles the zone with the given name into a set of timespans.
+    pub fn compile(&self, name: &str) -> Result<OwnedTimeZone, Error> {
+        let lines = self.zone_lines(name)?;
+        let mut builder = Builder { timespans: Vec::new() };
+        let mut start: Option<i64> = None;
+        let mut extension = None;
+
+        for (index, line) in lines.iter().enumerate() {
+            let is_last = index == lines.len() - 1;
+
+            let save = match line.rules {
+                ZoneRules::Standard => {
+                    let _ = builder.push(start, line.timespan(0, false, ""));
+                    0
+                },
+
+                ZoneRules::Fixed(save) => {
+                    let _ = builder.push(start, line.timespan(save, save != 0, ""));
+                    save
+                },
+
+                ZoneRules::Named(ref rule_name) => {
+                    let rules = match self.rules.get(rule_name) {
+                        Some(rules)  => rules,
+                        None         => return Err(Error::UnknownRule(rule_name.clone())),
+                    };
+
+                    if is_last {
+                        extension = line.extension(rules);
+                    }
+
+                    line.expand(rules, start, extension.is_some(), &mut builder)
+                },
+            };
+
+            if is_last {
+                if let ZoneRules::Fixed(_) | ZoneRules::Standard = line.rules {
+                    extension = Some(PosixTimeZone { standard: line.timespan(save, save != 0, ""), daylight: None });
+                }
+            }
+            else if let Some(until) = line.until {
+                start = Some(until.timestamp(line.offset, save));
+            }
+        }
+
+        OwnedTimeZone::new(Some(name.to_owned()), builder.finish(extension))
+            .map_err(Error::InvalidTimespans)
+    }
+
+    /// Compiles the zone with the given name into a time zone.
+    pub fn time_zone(&self, name: &str) -> Result<TimeZone, Error> {
+        let zone = self.compile(name)?;
+        Ok(TimeZone(TimeZoneSource::Runtime(Arc::new(zone))))
+    }
+}
+
+
+/// The years that are always generated for zones that use rules that
+/// can’t be turned into a POSIX TZ string, as `zic` does.
+const LAST_GENERATED_YEAR: i64 = 2037;
+
+/// A rule’s transition in a particular year, before it has been turned
+/// into a UTC timestamp.
+struct Event<'a> {
+    rule: &'a Rule,
+    local: i64,
+}
+
+impl<'a> Event<'a> {
+    fn timestamp(&self, offset: i64, save: i64) -> i64 {
+        to_utc(self.local, self.rule.time_type, offset, save)
+    }
+}
+
+impl ZoneLine {
+
+    /// Returns the timespan this line produces with the given amount of
+    /// daylight-saving time and rule letters.
+    fn timespan(&self, save: i64, is_dst: bool, letters: &str) -> FixedTimespan<'static> {
+        FixedTimespan {
+            offset: self.offset + save,
+            is_dst,
+            name:   Cow::Owned(abbreviation(&self.format, letters, is_dst, self.offset + save)),
+        }
+    }
+
+    /// Expands the given rules into transitions during the span of this
+    /// line, starting at the given time, and returns the amount of
+    /// daylight-saving time in effect when the line ends.
+    fn expand(&self, rules: &[Rule], start: Option<i64>, has_extension: bool, builder: &mut Builder) -> i64 {
+        let start_year = start.map(|s| LocalDateTime::at(s).year());
+
+        // Rules from before the start of the line still matter, as the
+        // last one to happen determines the line’s initial timespan, but
+        // only the last time each rule happened does. Lines without a start
+        // are taken to begin in 1800, so rules from long before then, or
+        // rules from `min`, don’t produce a transition for every year.
+        let before_start = start_year.map_or(1800, |y| y - 1);
+
+        let last_year = match self.until {
+            Some(until) => until.year,
+            None => {
+                let last_rule_year = rules.iter().map(|r| if r.to == i64::MAX { r.from } else { r.to }).max().unwrap_or(0);
+                let last_year = last_rule_year.max(start_year.unwrap_or(0)) + 1;
+                if has_extension { last_year } else { last_year.max(LAST_GENERATED_YEAR) }
+            },
+        };
+
+        let mut events = Vec::new();
+        for rule in rules {
+            // Rules can run right up to the last year that dates can be
+            // made in, so the years they cover are kept within that range.
+            let first_year = rule.from.max(rule.to.min(before_start)).max(LocalDate::MIN.year());
+            let last_year = rule.to.min(last_year).min(LocalDate::MAX.year());
+
+            for year in first_year ..= last_year {
+                let local = day_number(year, rule.month, rule.day) * SECONDS_IN_DAY + rule.time;
+                events.push(Event { rule, local });
+            }
+        }
+
+        events.sort_by_key(|e| e.timestamp(self.offset, 0));
+
+        let mut save = 0;
+        let mut is_dst = false;
+        let mut letters: Option<&str> = None;
+        let mut started = false;
+
+        // The index of the line’s initial timespan, while its abbreviation
+        // still needs letters from a later rule.
+        let mut pending: Option<usize> = None;
+
+        for event in &events {
+            let time = event.timestamp(self.offset, save);
+
+            if let Some(until) = self.until {
+                if time >= until.timestamp(self.offset, save) {
+                    break;
+                }
+            }
+
+            if start.map_or(false, |s| time < s) {
+                save = event.rule.save;
+                is_dst = event.rule.is_dst;
+                letters = Some(&event.rule.letters);
+                continue;
+            }
+
+            if !started {
+                started = true;
+                let index = builder.push(start, self.timespan(save, is_dst, letters.unwrap_or("")));
+                if letters.is_none() {
+                    pending = Some(index);
+                }
+            }
+
+            // If no rule was in effect when this line started, its
+            // abbreviation uses the letters of the first later rule that
+            // gives the same offset, as `zic` does.
+            if let Some(index) = pending {
+                if event.rule.save == 0 {
+                    builder.timespans[index].1 = self.timespan(0, false, &event.rule.letters);
+                    pending = None;
+                }
+            }
+
+            if start == Some(time) {
+                pending = None;
+            }
+
+            save = event.rule.save;
+            is_dst = event.rule.is_dst;
+            letters = Some(&event.rule.letters);
+            let _ = builder.push(Some(time), self.timespan(save, is_dst, &event.rule.letters));
+        }
+
+        if !started {
+            let _ = builder.push(start, self.timespan(save, is_dst, letters.unwrap_or("")));
+        }
+
+        save
+    }
+
+    /// Turns the rules that apply forever into a POSIX TZ rule, if they
+    /// can be expressed as one, for use after the last transition.
+    fn extension(&self, rules: &[Rule]) -> Option<PosixTimeZone<'static>> {
+        let forever: Vec<&Rule> = rules.iter().filter(|r| r.to == i64::MAX).collect();
+
+        let (standard, daylight) = match forever[..] {
+            [ a, b ] if a.save == 0 && b.save != 0  => (a, b),
+            [ a, b ] if a.save != 0 && b.save == 0  => (b, a),
+            _                                       => return None,
+        };
+
+        // The start of daylight-saving time is given in standard time, and
+        // its end in daylight-saving time.
+        let start_time = match daylight.time_type {
+            TimeType::UTC  => daylight.time + self.offset,
+            _              => daylight.time,
+        };
+
+        let end_time = match standard.time_type {
+            TimeType::UTC       => standard.time + self.offset + daylight.save,
+            TimeType::Standard  => standard.time + daylight.save,
+            TimeType::Wall      => standard.time,
+        };
+
+        Some(PosixTimeZone {
+            standard: self.timespan(0, false, &standard.letters),
+            daylight: Some(DaylightRule {
+                timespan: self.timespan(daylight.save, daylight.is_dst, &daylight.letters),
+                start:    transition_rule(daylight.month, daylight.day, start_time)?,
+                end:      transition_rule(standard.month, standard.day, end_time)?,
+            }),
+        })
+    }
+}
+
+impl Until {
+
+    /// Returns the Unix timestamp of this time, given the offset and the
+    /// amount of daylight-saving time in effect just before it.
+    fn timestamp(&self, offset: i64, save: i64) -> i64 {
+        let day = day_number(self.year, self.month, self.day);
+        to_utc(day * SECONDS_IN_DAY + self.time, self.time_type, offset, save)
+    }
+}
+
+
+/// Collects the timespans produced while compiling a zone.
+struct Builder {
+    timespans: Vec<(Option<i64>, FixedTimespan<'static>)>,
+}
+
+impl Builder {
+
+    /// Adds a timespan starting at the given time, or at the beginning of
+    /// time for `None`, replacing any that would start at or after it.
+    /// Returns the index the timespan was added at.
+    fn push(&mut self, time: Option<i64>, timespan: FixedTimespan<'static>) -> usize {
+        while let Some(&(previous, _)) = self.timespans.last() {
+            if previous.is_some() && previous >= time {
+                let _ = self.timespans.pop();
+            }
+            else {
+                break;
+            }
+        }
+
+        if time.is_none() {
+            self.timespans.clear();
+        }
+
+        self.timespans.push((time, timespan));
+        self.timespans.len() - 1
+    }
+
+    /// Turns the timespans into a set, folding together any adjacent
+    /// timespans with the same offset.
+    fn finish(self, extension: Option<PosixTimeZone<'static>>) -> OwnedFixedTimespanSet {
+        let mut timespans = self.timespans.into_iter();
+        let first = timespans.next().expect("zone has no lines").1;
+
+        let mut rest: Vec<(i64, FixedTimespan<'static>)> = Vec::new();
+        for (time, timespan) in timespans {
+            let current_offset = rest.last().map_or(first.offset, |t| t.1.offset);
+
+            if let Some(time) = time {
+                if timespan.offset != current_offset {
+                    rest.push((time, timespan));
+                }
+            }
+        }
+
+        OwnedFixedTimespanSet { first, rest, extension }
+    }
+}
+
+
+const SECONDS_IN_DAY: i64 = 86400;
+
+/// Converts a local time, in seconds since the Unix epoch as though it
+/// were UTC, to an actual Unix timestamp.
+fn to_utc(local: i64, time_type: TimeType, offset: i64, save: i64) -> i64 {
+    match time_type {
+        TimeType::UTC       => local,
+        TimeType::Standard  => local - offset,
+        TimeType::Wall      => local - offset - save,
+    }
+}
+
+/// Returns the number of days since the Unix epoch of the given day in
+/// the given month. Days past the end of the month spill into the next.
+fn day_number(year: i64, month: Month, day: DaySpec) -> i64 {
+
+    // The first of the month always exists, and years are checked when
+    // parsing and kept in range when expanding rules, so this can’t fail.
+    let first = LocalDate::ymd(year, month, 1).unwrap();
+    let month_start = LocalDateTime::new(first, LocalTime::midnight()).to_instant().seconds() / SECONDS_IN_DAY;
+
+    // The Unix epoch was a Thursday, which is day 4 counting from Sunday.
+    let weekday_of = |days: i64| (days + 4).rem_euclid(7);
+    let days_in_month = month.days_in_month(Year(year).is_leap_year()) as i64;
+
+    match day {
+        DaySpec::Fixed(d) => {
+            month_start + d as i64 - 1
+        },
+
+        DaySpec::Last(weekday) => {
+            let last = month_start + days_in_month - 1;
+            last - (weekday_of(last) - weekday as i64).rem_euclid(7)
+        },
+
+        DaySpec::OnOrAfter(weekday, d) => {
+            let from = month_start + d as i64 - 1;
+            from + (weekday as i64 - weekday_of(from)).rem_euclid(7)
+        },
+
+        DaySpec::OnOrBefore(weekday, d) => {
+            let from = month_start + d as i64 - 1;
+            from - (weekday_of(from) - weekday as i64).rem_euclid(7)
+        },
+    }
+}
+
+/// Turns a rule’s day and local time into a POSIX TZ transition rule, if
+/// it can be expressed as one.
+fn transition_rule(month: Month, day: DaySpec, time: i64) -> Option<TransitionRule> {
+    let (day, time) = match day {
+        DaySpec::Fixed(d) if month != Month::February || d < 29 => {
+            let days_before: i64 = (0 .. month.months_from_january())
+                .map(|m| Month::from_zero(m as i8).unwrap().days_in_month(false) as i64)
+                .sum();
+            (RuleDay::JulianIgnoringLeap((days_before + d as i64) as i16), time)
+        },
+
+        DaySpec::Last(weekday) => {
+            (RuleDay::MonthWeekday { month, week: 5, weekday }, time)
+        },
+
+        // “Sun>=8” is the second Sunday, but “Sun>=9” is not expressible
+        // directly, so it becomes the day after the second Saturday.
+        DaySpec::OnOrAfter(weekday, d) | DaySpec::OnOrBefore(weekday, d) => {
+            let d = if let DaySpec::OnOrBefore(..) = day { d - 6 } else { d };
+            if d < 1 {
+                return None;
+            }
+
+            let shift = (d - 1) % 7;
+            let week = (d - 1) / 7 + 1;
+            if week > 4 {
+                return None;
+            }
+
+            let weekday = Weekday::from_zero((weekday as i8 - shift).rem_euclid(7)).unwrap();
+            (RuleDay::MonthWeekday { month, week, weekday }, time + shift as i64 * SECONDS_IN_DAY)
+        },
+
+        DaySpec::Fixed(_) => return None,
+    };
+
+    if time.abs() > 167 * 3600 {
+        return None;
+    }
+
+    Some(TransitionRule { day, time })
+}
+
+/// Expands a zone line’s format into an abbreviation.
+fn abbreviation(format: &str, letters: &str, is_dst: bool, offset: i64) -> String {
+    if let Some(slash) = format.find('/') {
+        if is_dst { format[slash + 1 ..].to_owned() }
+             else { format[.. slash].to_owned() }
+    }
+    else if format.contains("%s") {
+        format.replace("%s", letters)
+    }
+    else if format.contains("%z") {
+        let sign = if offset < 0 { '-' } else { '+' };
+        let offset = offset.abs();
+        let (hours, minutes, seconds) = (offset / 3600, offset / 60 % 60, offset % 60);
+
+        let numeric = if seconds != 0 { format!("{}{:02}{:02}{:02}", sign, hours, minutes, seconds) }
+                 else if minutes != 0 { format!("{}{:02}{:02}", sign, hours, minutes) }
+                                 else { format!("{}{:02}", sign, hours) };
+        format.replace("%z", &numeric)
+    }
+    else {
+        format.to_owned()
+    }
+}
+
+
+/// Finds the index of the word in the table that the input is an
+/// abbreviation of, ignoring case. An exact match always wins; otherwise
+/// the input must be a prefix of exactly one word.
+fn lookup(input: &str, table: &[&str]) -> Option<usize> {
+    let input = input.to_ascii_lowercase();
+    if input.is_empty() {
+        return None;
+    }
+
+    if let Some(index) = table.iter().position(|w| w.to_ascii_lowercase() == input) {
+        return Some(index);
+    }
+
+    let mut matches = table.iter().enumerate().filter(|&(_, w)| w.to_ascii_lowercase().starts_with(&input));
+    match (matches.next(), matches.next()) {
+        (Some((index, _)), None)  => Some(index),
+        _                         => None,
+    }
+}
+
+const MONTH_NAMES: &[&str] = &[
+    "January", "February", "March", "April", "May", "June", "July",
+    "August", "September", "October", "November", "December",
+];
+
+const WEEKDAY_NAMES: &[&str] = &[
+    "Sunday", "Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday",
+];
+
+fn parse_rule(fields: &[&str]) -> Result<Rule, ParseError> {
+    let from = match lookup(fields[0], &[ "minimum" ]) {
+        Some(_) => i64::MIN,
+        None    => parse_year(fields[0])?,
+    };
+
+    let to = match lookup(fields[1], &[ "minimum", "maximum", "only" ]) {
+        Some(0) => i64::MIN,
+        Some(1) => i64::MAX,
+        Some(_) => from,
+        None    => parse_year(fields[1])?,
+    };
+
+    if to < from {
+        return Err(ParseError::InvalidYear);
+    }
+
+    if fields[2] != "-" {
+        return Err(ParseError::InvalidRuleType);
+    }
+
+    let month = parse_month(fields[3])?;
+    let day = parse_day(fields[4])?;
+    let (time, time_type) = parse_time_with_type(fields[5])?;
+
+    let (save, is_dst) = match fields[6].chars().last() {
+        Some('s')  => (parse_time(&fields[6][.. fields[6].len() - 1])?, false),
+        Some('d')  => (parse_time(&fields[6][.. fields[6].len() - 1])?, true),
+        _          => { let save = parse_time(fields[6])?; (save, save != 0) },
+    };
+
+    let letters = if fields[7] == "-" { String::new() } else { fields[7].to_owned() };
+
+    Ok(Rule { from, to, month, day, time, time_type, save, is_dst, letters })
+}
+
+fn parse_zone_line(fields: &[&str]) -> Result<ZoneLine, ParseError> {
+    if fields.len() < 3 || fields.len() > 7 {
+        return Err(ParseError::FieldCount);
+    }
+
+    let offset = parse_time(fields[0])?;
+
+    let rules = if fields[1] == "-" {
+        ZoneRules::Standard
+    }
+    else if fields[1].starts_with(|c: char| c.is_ascii_digit() || c == '-') {
+        ZoneRules::Fixed(parse_time(fields[1])?)
+    }
+    else {
+        ZoneRules::Named(fields[1].to_owned())
+    };
+
+    let until = if fields.len() > 3 {
+        let month = match fields.get(4) { Some(m) => parse_month(m)?, None => Month::January };
+        let day = match fields.get(5) { Some(d) => parse_day(d)?, None => DaySpec::Fixed(1) };
+        let (time, time_type) = match fields.get(6) { Some(t) => parse_time_with_type(t)?, None => (0, TimeType::Wall) };
+        Some(Until { year: parse_year(fields[3])?, month, day, time, time_type })
+    }
+    else {
+        None
+    };
+
+    Ok(ZoneLine { offset, rules, format: fields[2].to_owned(), until })
+}
+
+/// Parses a year, which has to be one that dates can be created in.
+fn parse_year(input: &str) -> Result<i64, ParseError> {
+    match input.parse() {
+        Ok(year) if (LocalDate::MIN.year() ..= LocalDate::MAX.year()).contains(&year)  => Ok(year),
+        _                                                                            => Err(ParseError::InvalidYear),
+    }
+}
+
+fn parse_month(input: &str) -> Result<Month, ParseError> {
+    lookup(input, MONTH_NAMES)
+        .map(|index| Month::from_zero(index as i8).unwrap())
+        .ok_or(ParseError::InvalidMonth)
+}
+
+fn parse_weekday(input: &str) -> Result<Weekday, ParseError> {
+    lookup(input, WEEKDAY_NAMES)
+        .map(|index| Weekday::from_zero(index as i8).unwrap())
+        .ok_or(ParseError::InvalidDay)
+}
+
+fn parse_day(input: &str) -> Result<DaySpec, ParseError> {
+    let day_of_month = |s: &str| match s.parse() {
+        Ok(d) if (1 ..= 31).contains(&d)  => Ok(d),
+        _                                 => Err(ParseError::InvalidDay),
+    };
+
+    if let Some(weekday) = input.strip_prefix("last") {
+        Ok(DaySpec::Last(parse_weekday(weekday)?))
+    }
+    else if let Some(index) = input.find(">=") {
+        Ok(DaySpec::OnOrAfter(parse_weekday(&input[.. index])?, day_of_month(&input[index + 2 ..])?))
+    }
+    else if let Some(index) = input.find("<=") {
+        Ok(DaySpec::OnOrBefore(parse_weekday(&input[.. index])?, day_of_month(&input[index + 2 ..])?))
+    }
+    else {
+        Ok(DaySpec::Fixed(day_of_month(input)?))
+    }
+}
+
+/// Parses a time in the form `[-]h[:mm[:ss]]`, or `-` for zero, returning
+/// a number of seconds.
+fn parse_time(input: &str) -> Result<i64, ParseError> {
+    if input == "-" {
+        return Ok(0);
+    }
+
+    let (sign, input) = match input.strip_prefix('-') {
+        Some(rest)  => (-1, rest),
+        None        => (1, input),
+    };
+
+    let mut seconds = 0;
+    let mut parts = 0;
+    for (index, part) in input.split(':').enumerate() {
+        let number: i64 = match part.parse() {
+            Ok(n) if !part.starts_with('+') && (index == 0 || (part.len() <= 2 && n < 60))  => n,
+            _                                                                                 => return Err(ParseError::InvalidTime),
+        };
+
+        seconds = seconds * 60 + number;
+        parts += 1;
+    }
+
+    if parts > 3 {
+        return Err(ParseError::InvalidTime);
+    }
+
+    for _ in parts .. 3 {
+        seconds *= 60;
+    }
+
+    Ok(sign * seconds)
+}
+
+/// Parses a time with an optional suffix saying whether it’s in wall
+/// clock time (`w`), standard time (`s`), or UTC (`u`, `g`, or `z`).
+fn parse_time_with_type(input: &str) -> Result<(i64, TimeType), ParseError> {
+    let time_type = match input.chars().last() {
+        Some('w')                          => TimeType::Wall,
+        Some('s')                          => TimeType::Standard,
+        Some('u') | Some('g') | Some('z')  => TimeType::UTC,
+        _                                  => return Ok((parse_time(input)?, TimeType::Wall)),
+    };
+
+    Ok((parse_time(&input[.. input.len() - 1])?, time_type))
+}
+
+
+/// An error that can occur when parsing or compiling tzdata files.
+#[derive(PartialEq, Debug, Clone)]
+pub enum Error {
+
+    /// A line of a source file could not be parsed.
+    Parse { line: usize, kind: ParseError },
+
+    /// There is no zone or link with this name.
+    UnknownZone(String),
+
+    /// A zone line refers to rules with this name, which don’t exist.
+    UnknownRule(String),
+
+    /// The zone compiled to a malformed set of timespans, such as one with
+    /// an offset more than a day from UTC.
+    InvalidTimespans(ValidationError),
+}
+
+/// The reason a line could not be parsed.
+#[derive(PartialEq, Debug, Clone)]
+pub enum ParseError {
+
+    /// The line doesn’t start with `Rule`, `Zone`, or `Link`.
+    UnknownLineType,
+
+    /// The line has too many or too few fields.
+    FieldCount,
+
+    /// A zone or link with this name has already been defined.
+    Duplicate(String),
+
+    /// A year field is invalid, or a rule ends before it begins.
+    InvalidYear,
+
+    /// A month name is invalid or ambiguous.
+    InvalidMonth,
+
+    /// A day of the month or weekday name is invalid.
+    InvalidDay,
+
+    /// A time of day, offset, or amount of saved time is invalid.
+    InvalidTime,
+
+    /// A rule’s obsolete “type” field is something other than `-`.
+    InvalidRuleType,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Parse { line, ref kind }  => write!(f, "line {}: {}", line, kind),
+            Error::UnknownZone(ref name)     => write!(f, "unknown zone {:?}", name),
+            Error::UnknownRule(ref name)     => write!(f, "unknown rule {:?}", name),
+            Error::InvalidTimespans(ref e)   => write!(f, "invalid time zone: {}", e),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParseError::UnknownLineType     => write!(f, "unknown line type"),
+            ParseError::FieldCount          => write!(f, "wrong number of fields"),
+            ParseError::Duplicate(ref name) => write!(f, "duplicate zone name {:?}", name),
+            ParseError::InvalidYear         => write!(f, "invalid year"),
+            ParseError::InvalidMonth        => write!(f, "invalid month"),
+            ParseError::InvalidDay          => write!(f, "invalid day"),
+            ParseError::InvalidTime         => write!(f, "invalid time"),
+            ParseError::InvalidRuleType     => write!(f, "invalid rule type"),
+        }
+    }
+}
+
+impl ErrorTrait for Error {
+    fn source(&self) -> Option<&(dyn ErrorTrait + 'static)> {
+        match *self {
+            Error::InvalidTimespans(ref e)  => Some(e),
+            _                               => None,
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn lookups() {
+        assert_eq!(lookup("Ja", MONTH_NAMES), Some(0));
+        assert_eq!(lookup("mar", MONTH_NAMES), Some(2));
+        assert_eq!(lookup("Ma", MONTH_NAMES), None);
+        assert_eq!(lookup("May", MONTH_NAMES), Some(4));
+        assert_eq!(lookup("Su", WEEKDAY_NAMES), Some(0));
+        assert_eq!(lookup("S", WEEKDAY_NAMES), None);
+        assert_eq!(lookup("", WEEKDAY_NAMES), None);
+    }
+
+    #[test]
+    fn times() {
+        assert_eq!(parse_time("2"), Ok(7200));
+        assert_eq!(parse_time("2:00"), Ok(7200));
+        assert_eq!(parse_time("-0:01:15"), Ok(-75));
+        assert_eq!(parse_time("-4:56:2"), Ok(-(4 * 3600 + 56 * 60 + 2)));
+        assert_eq!(parse_time("25:00"), Ok(25 * 3600));
+        assert_eq!(parse_time("-"), Ok(0));
+        assert_eq!(parse_time("1:60"), Err(ParseError::InvalidTime));
+        assert_eq!(parse_time("1:00:00:00"), Err(ParseError::InvalidTime));
+        assert_eq!(parse_time_with_type("1u"), Ok((3600, TimeType::UTC)));
+        assert_eq!(parse_time_with_type("2:00s"), Ok((7200, TimeType::Standard)));
+    }
+
+    #[test]
+    fn years() {
+        assert_eq!(parse_year("1996"), Ok(1996));
+        assert_eq!(parse_year("-999999999"), Ok(-999_999_999));
+        assert_eq!(parse_year("1000000000"), Err(ParseError::InvalidYear));
+        assert_eq!(parse_year("-1000000000"), Err(ParseError::InvalidYear));
+        assert_eq!(parse_year("99999999999999999999"), Err(ParseError::InvalidYear));
+    }
+
+    #[test]
+    fn days() {
+        assert_eq!(parse_day("lastSun"), Ok(DaySpec::Last(Weekday::Sunday)));
+        assert_eq!(parse_day("Sun>=8"), Ok(DaySpec::OnOrAfter(Weekday::Sunday, 8)));
+        assert_eq!(parse_day("Fri<=1"), Ok(DaySpec::OnOrBefore(Weekday::Friday, 1)));
+        assert_eq!(parse_day("15"), Ok(DaySpec::Fixed(15)));
+        assert_eq!(parse_day("32"), Err(ParseError::InvalidDay));
+        assert_eq!(parse_day("lastS"), Err(ParseError::InvalidDay));
+    }
+
+    #[test]
+    fn day_numbers() {
+        // 2027-03-28 was the last Sunday in March, 20905 days after 1970.
+        assert_eq!(day_number(2027, Month::March, DaySpec::Last(Weekday::Sunday)), 20905);
+        assert_eq!(day_number(2027, Month::March, DaySpec::OnOrAfter(Weekday::Sunday, 22)), 20905);
+        assert_eq!(day_number(2027, Month::April, DaySpec::OnOrBefore(Weekday::Sunday, 3)), 20905);
+        assert_eq!(day_number(2027, Month::March, DaySpec::Fixed(28)), 20905);
+    }
+
+    #[test]
+    fn abbreviations() {
+        assert_eq!(abbreviation("E%sT", "D", true, -14400), "EDT");
+        assert_eq!(abbreviation("GMT/BST", "", true, 3600), "BST");
+        assert_eq!(abbreviation("GMT/BST", "", false, 0), "GMT");
+        assert_eq!(abbreviation("%z", "", false, 19800), "+0530");
+        assert_eq!(abbreviation("%z", "", false, -10800), "-03");
+        assert_eq!(abbreviation("LMT", "", false, -75), "LMT");
+    }
+
+    #[test]
+    fn shifted_rules() {
+        // Israel’s “Fri>=23” becomes the day after the fourth Thursday.
+        let rule = transition_rule(Month::March, DaySpec::OnOrAfter(Weekday::Friday, 23), 7200).unwrap();
+        assert_eq!(rule, TransitionRule {
+            day: RuleDay::MonthWeekday { month: Month::March, week: 4, weekday: Weekday::Thursday },
+            time: 26 * 3600,
+        });
+    }
+
+    #[test]
+    fn unknown_line() {
+        let mut database = Database::new();
+        assert_eq!(database.parse("\n# comment\nRull EU 1981 max - Mar lastSun 1:00u 1:00 S\n"),
+                   Err(Error::Parse { line: 3, kind: ParseError::UnknownLineType }));
+    }
+
+    #[test]
+    fn rules_in_the_last_year() {
+        let mut database = Database::new();
+        database.parse("Rule R 999999999 max - Mar lastSun 1:00u 1:00 S\n\
+                        Rule R 999999999 max - Oct lastSun 1:00u 0 -\n\
+                        Zone Test/Last 0 R X%sT\n").unwrap();
+
+        let zone = database.time_zone("Test/Last").unwrap();
+        let summer = LocalDateTime::new(LocalDate::ymd(LocalDate::MAX.year(), Month::July, 1).unwrap(), LocalTime::midnight());
+        assert_eq!(zone.offset(summer), 3600);
+        assert_eq!(zone.offset(LocalDateTime::MAX), 0);
+    }
+
+    #[test]
+    fn rules_from_long_ago() {
+        let mut database = Database::new();
+        database.parse("Rule R -9999999 max - Mar lastSun 1:00u 1:00 S\n\
+                        Rule R -9999999 max - Oct lastSun 1:00u 0 -\n\
+                        Rule Old 1900 only - Jun 1 0:00 1:00 S\n\
+                        Zone Test/Long 0 R X%sT 1970\n\
+                        \t1:00 R Y%sT\n\
+                        Zone Test/Old 0 - XMT 1970\n\
+                        \t1:00 Old Y%sT\n").unwrap();
+
+        // Only the years the lines are in effect get transitions.
+        let zone = database.compile("Test/Long").unwrap();
+        assert!(zone.fixed_timespans.rest.len() < 1000);
+
+        let zone = database.time_zone("Test/Long").unwrap();
+        let summer = LocalDateTime::new(LocalDate::ymd(1960, Month::July, 1).unwrap(), LocalTime::midnight());
+        assert_eq!(zone.offset(summer), 3600);
+        let winter = LocalDateTime::new(LocalDate::ymd(1990, Month::January, 1).unwrap(), LocalTime::midnight());
+        assert_eq!(zone.offset(winter), 3600);
+
+        // A rule that last happened long before a line starts still sets
+        // the line’s initial timespan.
+        let zone = database.time_zone("Test/Old").unwrap();
+        let later = LocalDateTime::new(LocalDate::ymd(1990, Month::January, 1).unwrap(), LocalTime::midnight());
+        assert_eq!(zone.offset(later), 7200);
+    }
+
+    #[test]
+    fn out_of_range_years() {
+        let mut database = Database::new();
+        assert_eq!(database.parse("Rule EU 1981 5000000000 - Mar lastSun 1:00u 1:00 S\n"),
+                   Err(Error::Parse { line: 1, kind: ParseError::InvalidYear }));
+        assert_eq!(database.parse("Zone Test/Zone 1:00 - CET -2000000000\n"),
+                   Err(Error::Parse { line: 1, kind: ParseError::InvalidYear }));
+    }
+
+    #[test]
+    fn duplicate_zone() {
+        let mut database = Database::new();
+        assert_eq!(database.parse("Zone Etc/UTC 0 - UTC\nZone Etc/UTC 0 - UTC\n"),
+                   Err(Error::Parse { line: 2, kind: ParseError::Duplicate("Etc/UTC".into()) }));
+    }
+
+    #[test]
+    fn unknown_rule() {
+        let mut database = Database::new();
+        database.parse("Zone Test/Zone 1:00 Nope CE%sT\n").unwrap();
+        assert_eq!(database.compile("Test/Zone"), Err(Error::UnknownRule("Nope".into())));
+    }
+
+    #[test]
+    fn link_loop() {
+        let mut database = Database::new();
+        database.parse("Link A B\nLink B A\n").unwrap();
+        assert_eq!(database.compile("A"), Err(Error::UnknownZone("A".into())));
+    }
+}
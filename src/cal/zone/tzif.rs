@@ -0,0 +1,779 @@
+//! Reading and writing compiled time zone files in the TZif format.
+//!
+//! TZif is the binary format produced by `zic` and installed into
+//! directories such as `/usr/share/zoneinfo`. It is specified by RFC 8536.
+//! Version 1 files only contain 32-bit transition times; version 2 and
+//! later files follow the 32-bit block with a second header and a 64-bit
+//! block, then a POSIX TZ string footer for times past the last
+//! transition.
+
+use std::borrow::Cow;
+use std::convert::TryFrom;
+use std::error::Error as ErrorTrait;
+use std::fmt;
+use std::str;
+
+use cal::zone::{FixedTimespan, ValidationError};
+use cal::zone::posix::PosixTimeZone;
+use cal::zone::runtime::{OwnedTimeZone, OwnedFixedTimespanSet};
+
+
+/// The contents of a TZif file, with all its indices checked, but not yet
+/// turned into a set of timespans.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Tzif {
+
+    /// The version of the format the file was written in: 1, 2, 3, or 4.
+    pub version: u8,
+
+    /// The transitions in this zone, each containing a Unix timestamp and
+    /// an index into the `local_time_types` vector. These are sorted in
+    /// ascending order of time.
+    pub transitions: Vec<(i64, usize)>,
+
+    /// The local time types that the transitions refer to. The first one is
+    /// in effect before the first transition. There is always at least one.
+    pub local_time_types: Vec<LocalTimeType>,
+
+    /// The leap second records, each containing the Unix timestamp at which
+    /// the correction takes effect, and the total correction in seconds.
+    pub leap_seconds: Vec<(i64, i32)>,
+
+    /// The POSIX TZ string from the footer of a version 2 or later file,
+    /// used for times after the last transition, if one was present. This
+    /// has been checked to be a valid TZ string.
+    pub footer: Option<String>,
+}
+
+/// A local time type record, the TZif equivalent of a `FixedTimespan`.
+#[derive(PartialEq, Debug, Clone)]
+pub struct LocalTimeType {
+
+    /// The total offset from UTC, in seconds.
+    pub offset: i64,
+
+    /// Whether this type describes daylight-saving time.
+    pub is_dst: bool,
+
+    /// The abbreviation for this type, such as “GMT” or “BST”.
+    pub name: String,
+}
+
+impl LocalTimeType {
+    fn to_timespan(&self) -> FixedTimespan<'static> {
+        FixedTimespan {
+            offset: self.offset,
+            is_dst: self.is_dst,
+            name:   Cow::Owned(self.name.clone()),
+        }
+    }
+}
+
+impl Tzif {
+
+    /// Converts this file’s transitions into a set of timespans.
+    ///
+    /// A `FixedTimespanSet` requires every transition to change the offset,
+    /// so transitions that only change the abbreviation or the
+    /// daylight-saving flag are folded into the timespan before them.
+    ///
+    /// The footer, if there is one, becomes the set’s extension rule.
+    pub fn to_timespan_set(&self) -> OwnedFixedTimespanSet {
+        let first = self.local_time_types[0].to_timespan();
+        let mut rest: Vec<(i64, FixedTimespan<'static>)> = Vec::new();
+
+        for &(time, index) in &self.transitions {
+            let next = &self.local_time_types[index];
+            let current_offset = rest.last().map_or(first.offset, |t| t.1.offset);
+
+            if next.offset != current_offset {
+                rest.push((time, next.to_timespan()));
+            }
+        }
+
+        // The footer was checked while parsing, so this never discards it.
+        let extension = self.footer.as_ref().and_then(|f| f.parse::<PosixTimeZone>().ok());
+
+        OwnedFixedTimespanSet { first, rest, extension }
+    }
+
+    /// Converts this file into a time zone with the given name, checking
+    /// that its timespans are well-formed.
+    pub fn to_time_zone(&self, name: Option<String>) -> Result<OwnedTimeZone, Error> {
+        OwnedTimeZone::new(name, self.to_timespan_set()).map_err(Error::InvalidTimespans)
+    }
+
+    /// Converts a time zone into the contents of a version 2 TZif file.
+    ///
+    /// Each distinct timespan becomes a local time type, with the zone’s
+    /// first timespan as type 0, and the zone’s extension rule becomes the
+    /// footer. Set `footer` to `None` before writing to leave it out.
+    pub fn from_time_zone(zone: &OwnedTimeZone) -> Self {
+        let set = &zone.fixed_timespans;
+        let mut local_time_types = vec![ LocalTimeType::from_timespan(&set.first) ];
+        let mut transitions = Vec::with_capacity(set.rest.len());
+
+        for &(time, ref timespan) in &set.rest {
+            let local_time_type = LocalTimeType::from_timespan(timespan);
+            let index = match local_time_types.iter().position(|t| *t == local_time_type) {
+                Some(index)  => index,
+                None         => { local_time_types.push(local_time_type); local_time_types.len() - 1 },
+            };
+
+            transitions.push((time, index));
+        }
+
+        Tzif {
+            version: 2,
+            transitions,
+            local_time_types,
+            leap_seconds: Vec::new(),
+            footer: set.extension.as_ref().map(|e| e.to_string()),
+        }
+    }
+
+    /// Writes this file out in the TZif format.
+    ///
+    /// Files of version 2 or later get both data blocks: the 32-bit block
+    /// holds the transitions that fit in 32 bits, so readers that only
+    /// understand version 1 still see the years around now, and the 64-bit
+    /// block holds all of them. Version 1 files only have the 32-bit
+    /// block, so every time in them has to fit in 32 bits.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        self.check()?;
+
+        let mut writer = Writer { output: Vec::new() };
+        if self.version == 1 {
+            writer.data_block(self, 1, &self.transitions, &self.leap_seconds, 4);
+            return Ok(writer.output);
+        }
+
+        let leap_seconds: Vec<_> = self.leap_seconds.iter().filter(|l| fits_in_32_bits(l.0)).cloned().collect();
+        writer.data_block(self, self.version, &self.version_one_transitions(), &leap_seconds, 4);
+        writer.data_block(self, self.version, &self.transitions, &self.leap_seconds, 8);
+
+        writer.output.push(b'\n');
+        if let Some(ref footer) = self.footer {
+            writer.output.extend_from_slice(footer.as_bytes());
+        }
+        writer.output.push(b'\n');
+
+        Ok(writer.output)
+    }
+
+    /// Checks that everything in this file can be written, as its fields
+    /// can be changed freely after reading.
+    fn check(&self) -> Result<(), Error> {
+        if !(1 ..= 4).contains(&self.version) {
+            return Err(Error::UnsupportedVersion(self.version));
+        }
+
+        if self.local_time_types.is_empty() {
+            return Err(Error::NoLocalTimeTypes);
+        }
+
+        if self.local_time_types.len() > 256 || self.abbreviations().1.iter().any(|&i| i > 255) {
+            return Err(Error::TooManyLocalTimeTypes);
+        }
+
+        for t in &self.local_time_types {
+            if t.offset <= i64::from(i32::MIN) || t.offset > i64::from(i32::MAX) {
+                return Err(Error::InvalidLocalTimeType);
+            }
+
+            if t.name.contains('\0') {
+                return Err(Error::InvalidAbbreviation);
+            }
+        }
+
+        for (index, &(time, type_index)) in self.transitions.iter().enumerate() {
+            if type_index >= self.local_time_types.len() {
+                return Err(Error::InvalidTypeIndex(u8::try_from(type_index).unwrap_or(u8::MAX)));
+            }
+
+            if index > 0 && self.transitions[index - 1].0 >= time {
+                return Err(Error::UnsortedTransitions);
+            }
+        }
+
+        if self.version == 1 {
+            let mut times = self.transitions.iter().map(|t| t.0).chain(self.leap_seconds.iter().map(|l| l.0));
+            if !times.all(fits_in_32_bits) {
+                return Err(Error::TimeOutOfRange);
+            }
+        }
+
+        match self.footer {
+            Some(ref f) if f.contains('\n') || f.parse::<PosixTimeZone>().is_err()  => Err(Error::InvalidFooter),
+            _                                                                        => Ok(()),
+        }
+    }
+
+    /// Returns the transitions for the 32-bit data block.
+    ///
+    /// If any transitions are too early to fit, one is added at the
+    /// earliest 32-bit time to the local time type in effect then, so
+    /// readers don’t fall back to type 0 for the years that do fit.
+    fn version_one_transitions(&self) -> Vec<(i64, usize)> {
+        let (min, max) = (i64::from(i32::MIN), i64::from(i32::MAX));
+        let mut transitions = Vec::new();
+
+        if let Some(&(_, index)) = self.transitions.iter().take_while(|t| t.0 < min).last() {
+            if !self.transitions.iter().any(|t| t.0 == min) {
+                transitions.push((min, index));
+            }
+        }
+
+        transitions.extend(self.transitions.iter().filter(|t| t.0 >= min && t.0 <= max));
+        transitions
+    }
+
+    /// Returns the abbreviation characters, with each abbreviation written
+    /// once and terminated by a NUL, and the index of each local time
+    /// type’s abbreviation in them.
+    fn abbreviations(&self) -> (Vec<u8>, Vec<usize>) {
+        let mut names = Vec::new();
+        let mut seen: Vec<(&str, usize)> = Vec::new();
+        let mut indices = Vec::with_capacity(self.local_time_types.len());
+
+        for t in &self.local_time_types {
+            let index = match seen.iter().find(|s| s.0 == t.name) {
+                Some(&(_, index))  => index,
+                None => {
+                    let index = names.len();
+                    names.extend_from_slice(t.name.as_bytes());
+                    names.push(0);
+                    seen.push((&t.name, index));
+                    index
+                },
+            };
+
+            indices.push(index);
+        }
+
+        (names, indices)
+    }
+}
+
+impl LocalTimeType {
+    fn from_timespan(timespan: &FixedTimespan) -> Self {
+        LocalTimeType {
+            offset: timespan.offset,
+            is_dst: timespan.is_dst,
+            name:   timespan.name.to_string(),
+        }
+    }
+}
+
+
+/// Returns whether a time can be written in a 32-bit data block.
+fn fits_in_32_bits(time: i64) -> bool {
+    time >= i64::from(i32::MIN) && time <= i64::from(i32::MAX)
+}
+
+/// Writes a time zone as a version 2 TZif file, with its extension rule,
+/// if it has one, as the footer.
+pub fn write(zone: &OwnedTimeZone) -> Result<Vec<u8>, Error> {
+    Tzif::from_time_zone(zone).to_bytes()
+}
+
+struct Writer {
+    output: Vec<u8>,
+}
+
+impl Writer {
+    fn u32(&mut self, number: u32) {
+        self.output.extend_from_slice(&number.to_be_bytes());
+    }
+
+    fn time(&mut self, time: i64, time_size: usize) {
+        if time_size == 4 { self.output.extend_from_slice(&(time as i32).to_be_bytes()) }
+                     else { self.output.extend_from_slice(&time.to_be_bytes()) }
+    }
+
+    fn data_block(&mut self, tzif: &Tzif, version: u8, transitions: &[(i64, usize)], leap_seconds: &[(i64, i32)], time_size: usize) {
+        let (names, name_indices) = tzif.abbreviations();
+
+        self.output.extend_from_slice(b"TZif");
+        self.output.push(if version == 1 { 0 } else { b'0' + version });
+        self.output.extend_from_slice(&[0; 15]);
+
+        self.u32(0);
+        self.u32(0);
+        self.u32(leap_seconds.len() as u32);
+        self.u32(transitions.len() as u32);
+        self.u32(tzif.local_time_types.len() as u32);
+        self.u32(names.len() as u32);
+
+        for &(time, _) in transitions {
+            self.time(time, time_size);
+        }
+
+        for &(_, index) in transitions {
+            self.output.push(index as u8);
+        }
+
+        for (t, &name_index) in tzif.local_time_types.iter().zip(&name_indices) {
+            self.u32(t.offset as i32 as u32);
+            self.output.push(t.is_dst as u8);
+            self.output.push(name_index as u8);
+        }
+
+        self.output.extend_from_slice(&names);
+
+        for &(time, correction) in leap_seconds {
+            self.time(time, time_size);
+            self.u32(correction as u32);
+        }
+    }
+}
+
+
+/// Parses the contents of a TZif file.
+///
+/// For files of version 2 or later, the 32-bit data block is skipped, and
+/// only the 64-bit block and the footer are read.
+pub fn parse(input: &[u8]) -> Result<Tzif, Error> {
+    let mut reader = Reader { input };
+
+    let header = reader.header()?;
+    if header.version == 1 {
+        return reader.data_block(&header, 4, 1);
+    }
+
+    let length = header.data_block_length(4);
+    reader.expect(length)?;
+    reader.skip(length as usize)?;
+
+    let header = reader.header()?;
+    let mut tzif = reader.data_block(&header, 8, header.version)?;
+    tzif.footer = reader.footer()?;
+    Ok(tzif)
+}
+
+
+/// The counts from a TZif header, which determine the length of the data
+/// block following it.
+#[derive(PartialEq, Debug, Copy, Clone)]
+struct Header {
+    version: u8,
+    isutcnt: usize,
+    isstdcnt: usize,
+    leapcnt: usize,
+    timecnt: usize,
+    typecnt: usize,
+    charcnt: usize,
+}
+
+impl Header {
+    /// Returns the number of bytes in the data block after this header.
+    /// This is computed in 64 bits so corrupt counts cannot overflow it.
+    fn data_block_length(&self, time_size: usize) -> u64 {
+        let count = |n: usize| n as u64;
+        count(self.timecnt) * count(time_size + 1)
+            + count(self.typecnt) * 6
+            + count(self.charcnt)
+            + count(self.leapcnt) * count(time_size + 4)
+            + count(self.isstdcnt)
+            + count(self.isutcnt)
+    }
+}
+
+struct Reader<'a> {
+    input: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, count: usize) -> Result<&'a [u8], Error> {
+        if self.input.len() < count {
+            return Err(Error::Truncated);
+        }
+
+        let (taken, rest) = self.input.split_at(count);
+        self.input = rest;
+        Ok(taken)
+    }
+
+    fn skip(&mut self, count: usize) -> Result<(), Error> {
+        self.take(count).map(|_| ())
+    }
+
+    /// Checks that at least the given number of bytes remain, so the
+    /// counts in a header can be trusted before allocating anything.
+    fn expect(&self, count: u64) -> Result<(), Error> {
+        if (self.input.len() as u64) < count { Err(Error::Truncated) }
+                                          else { Ok(()) }
+    }
+
+    fn u8(&mut self) -> Result<u8, Error> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u32(&mut self) -> Result<u32, Error> {
+        let b = self.take(4)?;
+        Ok(u32::from_be_bytes([ b[0], b[1], b[2], b[3] ]))
+    }
+
+    fn i32(&mut self) -> Result<i32, Error> {
+        self.u32().map(|n| n as i32)
+    }
+
+    fn i64(&mut self) -> Result<i64, Error> {
+        let b = self.take(8)?;
+        Ok(i64::from_be_bytes([ b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7] ]))
+    }
+
+    fn time(&mut self, time_size: usize) -> Result<i64, Error> {
+        if time_size == 4 { self.i32().map(i64::from) }
+                     else { self.i64() }
+    }
+
+    fn header(&mut self) -> Result<Header, Error> {
+        if self.take(4)? != b"TZif" {
+            return Err(Error::BadMagic);
+        }
+
+        let version = match self.u8()? {
+            0                     => 1,
+            v @ b'2' ..= b'4'     => v - b'0',
+            v                     => return Err(Error::UnsupportedVersion(v)),
+        };
+
+        self.skip(15)?;
+
+        let header = Header {
+            version,
+            isutcnt:  self.u32()? as usize,
+            isstdcnt: self.u32()? as usize,
+            leapcnt:  self.u32()? as usize,
+            timecnt:  self.u32()? as usize,
+            typecnt:  self.u32()? as usize,
+            charcnt:  self.u32()? as usize,
+        };
+
+        if header.typecnt == 0 {
+            return Err(Error::NoLocalTimeTypes);
+        }
+
+        if (header.isutcnt != 0 && header.isutcnt != header.typecnt)
+        || (header.isstdcnt != 0 && header.isstdcnt != header.typecnt) {
+            return Err(Error::BadIndicatorCount);
+        }
+
+        Ok(header)
+    }
+
+    fn data_block(&mut self, header: &Header, time_size: usize, version: u8) -> Result<Tzif, Error> {
+        self.expect(header.data_block_length(time_size))?;
+
+        let mut times = Vec::with_capacity(header.timecnt);
+        for _ in 0 .. header.timecnt {
+            let time = self.time(time_size)?;
+            if times.last().map_or(false, |&previous| previous >= time) {
+                return Err(Error::UnsortedTransitions);
+            }
+            times.push(time);
+        }
+
+        let mut transitions = Vec::with_capacity(header.timecnt);
+        for time in times {
+            let index = self.u8()?;
+            if index as usize >= header.typecnt {
+                return Err(Error::InvalidTypeIndex(index));
+            }
+            transitions.push((time, index as usize));
+        }
+
+        let mut records = Vec::with_capacity(header.typecnt);
+        for _ in 0 .. header.typecnt {
+            let offset = self.i32()?;
+            let is_dst = self.u8()?;
+            let name_index = self.u8()?;
+            records.push((offset, is_dst, name_index as usize));
+        }
+
+        let names = self.take(header.charcnt)?;
+        let mut local_time_types = Vec::with_capacity(header.typecnt);
+        for (offset, is_dst, name_index) in records {
+            if offset == i32::MIN || is_dst > 1 {
+                return Err(Error::InvalidLocalTimeType);
+            }
+
+            local_time_types.push(LocalTimeType {
+                offset: i64::from(offset),
+                is_dst: is_dst == 1,
+                name:   abbreviation(names, name_index)?,
+            });
+        }
+
+        let mut leap_seconds = Vec::with_capacity(header.leapcnt);
+        for _ in 0 .. header.leapcnt {
+            let time = self.time(time_size)?;
+            let correction = self.i32()?;
+            leap_seconds.push((time, correction));
+        }
+
+        // The standard/wall and UT/local indicators are only needed when
+        // applying the POSIX rules from the old `posixrules` file, so they
+        // are checked for presence but otherwise discarded.
+        self.skip(header.isstdcnt + header.isutcnt)?;
+
+        Ok(Tzif { version, transitions, local_time_types, leap_seconds, footer: None })
+    }
+
+    fn footer(&mut self) -> Result<Option<String>, Error> {
+        if self.u8()? != b'\n' {
+            return Err(Error::InvalidFooter);
+        }
+
+        let end = match self.input.iter().position(|&b| b == b'\n') {
+            Some(end) => end,
+            None      => return Err(Error::Truncated),
+        };
+
+        let footer = self.take(end)?;
+        self.skip(1)?;
+
+        match str::from_utf8(footer) {
+            Ok("")                                         => Ok(None),
+            Ok(s) if s.parse::<PosixTimeZone>().is_ok()  => Ok(Some(s.to_owned())),
+            _                                              => Err(Error::InvalidFooter),
+        }
+    }
+}
+
+/// Reads the NUL-terminated abbreviation starting at the given index into
+/// the abbreviation characters.
+fn abbreviation(names: &[u8], index: usize) -> Result<String, Error> {
+    if index >= names.len() {
+        return Err(Error::InvalidAbbreviation);
+    }
+
+    let names = &names[index ..];
+    let end = match names.iter().position(|&b| b == 0) {
+        Some(end) => end,
+        None      => return Err(Error::InvalidAbbreviation),
+    };
+
+    match str::from_utf8(&names[.. end]) {
+        Ok(s)   => Ok(s.to_owned()),
+        Err(_)  => Err(Error::InvalidAbbreviation),
+    }
+}
+
+
+/// An error that can occur when reading or writing a TZif file.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum Error {
+
+    /// The file does not start with the “TZif” magic bytes.
+    BadMagic,
+
+    /// The file has a version byte that this library does not recognise.
+    UnsupportedVersion(u8),
+
+    /// The file ended before all of the data its header describes.
+    Truncated,
+
+    /// The file’s header says it has no local time types.
+    NoLocalTimeTypes,
+
+    /// The number of standard/wall or UT/local indicators is neither zero
+    /// nor the number of local time types.
+    BadIndicatorCount,
+
+    /// A transition refers to a local time type that does not exist.
+    InvalidTypeIndex(u8),
+
+    /// A local time type has an out-of-range offset or daylight-saving flag.
+    InvalidLocalTimeType,
+
+    /// A local time type’s abbreviation is out of bounds, unterminated, or
+    /// not valid UTF-8.
+    InvalidAbbreviation,
+
+    /// The transition times are not in strictly ascending order.
+    UnsortedTransitions,
+
+    /// The footer is not enclosed in newlines, or is not a valid POSIX TZ
+    /// string.
+    InvalidFooter,
+
+    /// The file was read, but its timespans are malformed, such as by
+    /// having an offset more than a day from UTC.
+    InvalidTimespans(ValidationError),
+
+    /// There are more local time types, or more abbreviation characters,
+    /// than the one-byte indices in a TZif file can refer to.
+    TooManyLocalTimeTypes,
+
+    /// A version 1 file has a transition or leap second at a time that
+    /// doesn’t fit in 32 bits.
+    TimeOutOfRange,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::BadMagic                => write!(f, "not a TZif file"),
+            Error::UnsupportedVersion(v)   => write!(f, "unsupported TZif version byte {:#04x}", v),
+            Error::Truncated               => write!(f, "TZif file is truncated"),
+            Error::NoLocalTimeTypes        => write!(f, "TZif file has no local time types"),
+            Error::BadIndicatorCount       => write!(f, "TZif file has a bad indicator count"),
+            Error::InvalidTypeIndex(i)     => write!(f, "transition refers to missing local time type {}", i),
+            Error::InvalidLocalTimeType    => write!(f, "invalid local time type"),
+            Error::InvalidAbbreviation     => write!(f, "invalid time zone abbreviation"),
+            Error::UnsortedTransitions     => write!(f, "transition times are not in ascending order"),
+            Error::InvalidFooter           => write!(f, "invalid TZif footer"),
+            Error::InvalidTimespans(e)     => write!(f, "invalid time zone: {}", e),
+            Error::TooManyLocalTimeTypes   => write!(f, "too many local time types for a TZif file"),
+            Error::TimeOutOfRange          => write!(f, "time does not fit in a version 1 TZif file"),
+        }
+    }
+}
+
+impl ErrorTrait for Error {
+    fn source(&self) -> Option<&(dyn ErrorTrait + 'static)> {
+        match *self {
+            Error::InvalidTimespans(ref e)  => Some(e),
+            _                               => None,
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Builds a version 1 file with a single transition from GMT to BST.
+    fn version_one() -> Vec<u8> {
+        let mut file = Vec::new();
+        file.extend_from_slice(b"TZif\0");
+        file.extend_from_slice(&[0; 15]);
+        for count in &[ 0_u32, 0, 0, 1, 2, 8 ] {
+            file.extend_from_slice(&count.to_be_bytes());
+        }
+        file.extend_from_slice(&1_206_838_800_i32.to_be_bytes());
+        file.push(1);
+        file.extend_from_slice(&0_i32.to_be_bytes());
+        file.extend_from_slice(&[0, 0]);
+        file.extend_from_slice(&3600_i32.to_be_bytes());
+        file.extend_from_slice(&[1, 4]);
+        file.extend_from_slice(b"GMT\0BST\0");
+        file
+    }
+
+    #[test]
+    fn v1() {
+        let tzif = parse(&version_one()).unwrap();
+        assert_eq!(tzif.version, 1);
+        assert_eq!(tzif.transitions, vec![ (1_206_838_800, 1) ]);
+        assert_eq!(tzif.local_time_types[1], LocalTimeType {
+            offset: 3600,
+            is_dst: true,
+            name:   "BST".into(),
+        });
+        assert_eq!(tzif.footer, None);
+    }
+
+    #[test]
+    fn v1_timespans() {
+        let set = parse(&version_one()).unwrap().to_timespan_set();
+        assert_eq!(set.first.name, "GMT");
+        assert_eq!(set.rest.len(), 1);
+        assert_eq!(set.rest[0].1.offset, 3600);
+    }
+
+    #[test]
+    fn bad_magic() {
+        let mut file = version_one();
+        file[0] = b'X';
+        assert_eq!(parse(&file), Err(Error::BadMagic));
+    }
+
+    #[test]
+    fn bad_version() {
+        let mut file = version_one();
+        file[4] = b'9';
+        assert_eq!(parse(&file), Err(Error::UnsupportedVersion(b'9')));
+    }
+
+    #[test]
+    fn truncated() {
+        let file = version_one();
+        for length in 0 .. file.len() {
+            assert!(parse(&file[.. length]).is_err(), "length {} should fail", length);
+        }
+    }
+
+    #[test]
+    fn bad_type_index() {
+        let mut file = version_one();
+        file[48] = 7;
+        assert_eq!(parse(&file), Err(Error::InvalidTypeIndex(7)));
+    }
+
+    #[test]
+    fn bad_abbreviation_index() {
+        let mut file = version_one();
+        file[60] = 8;
+        assert_eq!(parse(&file), Err(Error::InvalidAbbreviation));
+    }
+
+    #[test]
+    fn unterminated_abbreviation() {
+        let mut file = version_one();
+        let last = file.len() - 1;
+        file[last] = b'!';
+        assert_eq!(parse(&file), Err(Error::InvalidAbbreviation));
+    }
+
+    #[test]
+    fn write_v1() {
+        let tzif = parse(&version_one()).unwrap();
+        assert_eq!(tzif.to_bytes().unwrap(), version_one());
+    }
+
+    #[test]
+    fn write_v2() {
+        let mut tzif = parse(&version_one()).unwrap();
+        tzif.version = 2;
+        tzif.footer = Some("GMT0BST,M3.5.0/1,M10.5.0".into());
+        assert_eq!(parse(&tzif.to_bytes().unwrap()).unwrap(), tzif);
+    }
+
+    #[test]
+    fn shared_abbreviations() {
+        let mut tzif = parse(&version_one()).unwrap();
+        tzif.local_time_types.push(LocalTimeType { offset: 7200, is_dst: true, name: "BST".into() });
+        assert_eq!(tzif.abbreviations(), (b"GMT\0BST\0".to_vec(), vec![ 0, 4, 4 ]));
+    }
+
+    #[test]
+    fn early_transitions() {
+        let mut tzif = parse(&version_one()).unwrap();
+        tzif.transitions = vec![ (-3_000_000_000, 1), (-2_500_000_000, 0), (0, 1), (5_000_000_000, 0) ];
+
+        assert_eq!(tzif.version_one_transitions(), vec![ (i64::from(i32::MIN), 0), (0, 1) ]);
+    }
+
+    #[test]
+    fn write_errors() {
+        let mut tzif = parse(&version_one()).unwrap();
+        tzif.transitions.push((0, 1));
+        assert_eq!(tzif.to_bytes(), Err(Error::UnsortedTransitions));
+
+        let mut tzif = parse(&version_one()).unwrap();
+        tzif.transitions[0].1 = 2;
+        assert_eq!(tzif.to_bytes(), Err(Error::InvalidTypeIndex(2)));
+
+        let mut tzif = parse(&version_one()).unwrap();
+        tzif.footer = Some("not a TZ string".into());
+        assert_eq!(tzif.to_bytes(), Err(Error::InvalidFooter));
+
+        let mut tzif = parse(&version_one()).unwrap();
+        tzif.local_time_types = (0 .. 300).map(|n| LocalTimeType { offset: n, is_dst: false, name: "UTC".into() }).collect();
+        assert_eq!(tzif.to_bytes(), Err(Error::TooManyLocalTimeTypes));
+    }
+}
@@ -0,0 +1,964 @@
+// This file is generated by examples/generate_windows_zones.rs from the
+// CLDR windowsZones.xml supplemental data. Do not edit it by hand.
+
+/// Every Windows zone ID and territory, with the zones used there, sorted
+/// by Windows zone ID and then territory. The territory “001” gives the
+/// zone to use when the territory isn’t known.
+pub static MAPPINGS: &[(&str, &str, &[&str])] = &[
+    ("AUS Central Standard Time", "001", &[ "Australia/Darwin" ]),
+    ("AUS Central Standard Time", "AU", &[ "Australia/Darwin" ]),
+    ("AUS Eastern Standard Time", "001", &[ "Australia/Sydney" ]),
+    ("AUS Eastern Standard Time", "AU", &[ "Australia/Sydney", "Australia/Melbourne" ]),
+    ("Afghanistan Standard Time", "001", &[ "Asia/Kabul" ]),
+    ("Afghanistan Standard Time", "AF", &[ "Asia/Kabul" ]),
+    ("Alaskan Standard Time", "001", &[ "America/Anchorage" ]),
+    ("Alaskan Standard Time", "US", &[ "America/Anchorage", "America/Juneau", "America/Metlakatla", "America/Nome", "America/Sitka", "America/Yakutat" ]),
+    ("Aleutian Standard Time", "001", &[ "America/Adak" ]),
+    ("Aleutian Standard Time", "US", &[ "America/Adak" ]),
+    ("Altai Standard Time", "001", &[ "Asia/Barnaul" ]),
+    ("Altai Standard Time", "RU", &[ "Asia/Barnaul" ]),
+    ("Arab Standard Time", "001", &[ "Asia/Riyadh" ]),
+    ("Arab Standard Time", "BH", &[ "Asia/Bahrain" ]),
+    ("Arab Standard Time", "KW", &[ "Asia/Kuwait" ]),
+    ("Arab Standard Time", "QA", &[ "Asia/Qatar" ]),
+    ("Arab Standard Time", "SA", &[ "Asia/Riyadh" ]),
+    ("Arab Standard Time", "YE", &[ "Asia/Aden" ]),
+    ("Arabian Standard Time", "001", &[ "Asia/Dubai" ]),
+    ("Arabian Standard Time", "AE", &[ "Asia/Dubai" ]),
+    ("Arabian Standard Time", "OM", &[ "Asia/Muscat" ]),
+    ("Arabian Standard Time", "ZZ", &[ "Etc/GMT-4" ]),
+    ("Arabic Standard Time", "001", &[ "Asia/Baghdad" ]),
+    ("Arabic Standard Time", "IQ", &[ "Asia/Baghdad" ]),
+    ("Argentina Standard Time", "001", &[ "America/Buenos_Aires" ]),
+    ("Argentina Standard Time", "AR", &[ "America/Buenos_Aires", "America/Argentina/La_Rioja", "America/Argentina/Rio_Gallegos", "America/Argentina/Salta", "America/Argentina/San_Juan", "America/Argentina/San_Luis", "America/Argentina/Tucuman", "America/Argentina/Ushuaia", "America/Catamarca", "America/Cordoba", "America/Jujuy", "America/Mendoza" ]),
+    ("Astrakhan Standard Time", "001", &[ "Europe/Astrakhan" ]),
+    ("Astrakhan Standard Time", "RU", &[ "Europe/Astrakhan", "Europe/Ulyanovsk" ]),
+    ("Atlantic Standard Time", "001", &[ "America/Halifax" ]),
+    ("Atlantic Standard Time", "BM", &[ "Atlantic/Bermuda" ]),
+    ("Atlantic Standard Time", "CA", &[ "America/Halifax", "America/Glace_Bay", "America/Goose_Bay", "America/Moncton" ]),
+    ("Atlantic Standard Time", "GL", &[ "America/Thule" ]),
+    ("Aus Central W. Standard Time", "001", &[ "Australia/Eucla" ]),
+    ("Aus Central W. Standard Time", "AU", &[ "Australia/Eucla" ]),
+    ("Azerbaijan Standard Time", "001", &[ "Asia/Baku" ]),
+    ("Azerbaijan Standard Time", "AZ", &[ "Asia/Baku" ]),
+    ("Azores Standard Time", "001", &[ "Atlantic/Azores" ]),
+    ("Azores Standard Time", "GL", &[ "America/Scoresbysund" ]),
+    ("Azores Standard Time", "PT", &[ "Atlantic/Azores" ]),
+    ("Bahia Standard Time", "001", &[ "America/Bahia" ]),
+    ("Bahia Standard Time", "BR", &[ "America/Bahia" ]),
+    ("Bangladesh Standard Time", "001", &[ "Asia/Dhaka" ]),
+    ("Bangladesh Standard Time", "BD", &[ "Asia/Dhaka" ]),
+    ("Bangladesh Standard Time", "BT", &[ "Asia/Thimphu" ]),
+    ("Belarus Standard Time", "001", &[ "Europe/Minsk" ]),
+    ("Belarus Standard Time", "BY", &[ "Europe/Minsk" ]),
+    ("Bougainville Standard Time", "001", &[ "Pacific/Bougainville" ]),
+    ("Bougainville Standard Time", "PG", &[ "Pacific/Bougainville" ]),
+    ("Canada Central Standard Time", "001", &[ "America/Regina" ]),
+    ("Canada Central Standard Time", "CA", &[ "America/Regina", "America/Swift_Current" ]),
+    ("Cape Verde Standard Time", "001", &[ "Atlantic/Cape_Verde" ]),
+    ("Cape Verde Standard Time", "CV", &[ "Atlantic/Cape_Verde" ]),
+    ("Cape Verde Standard Time", "ZZ", &[ "Etc/GMT+1" ]),
+    ("Caucasus Standard Time", "001", &[ "Asia/Yerevan" ]),
+    ("Caucasus Standard Time", "AM", &[ "Asia/Yerevan" ]),
+    ("Cen. Australia Standard Time", "001", &[ "Australia/Adelaide" ]),
+    ("Cen. Australia Standard Time", "AU", &[ "Australia/Adelaide", "Australia/Broken_Hill" ]),
+    ("Central America Standard Time", "001", &[ "America/Guatemala" ]),
+    ("Central America Standard Time", "BZ", &[ "America/Belize" ]),
+    ("Central America Standard Time", "CR", &[ "America/Costa_Rica" ]),
+    ("Central America Standard Time", "EC", &[ "Pacific/Galapagos" ]),
+    ("Central America Standard Time", "GT", &[ "America/Guatemala" ]),
+    ("Central America Standard Time", "HN", &[ "America/Tegucigalpa" ]),
+    ("Central America Standard Time", "NI", &[ "America/Managua" ]),
+    ("Central America Standard Time", "SV", &[ "America/El_Salvador" ]),
+    ("Central America Standard Time", "ZZ", &[ "Etc/GMT+6" ]),
+    ("Central Asia Standard Time", "001", &[ "Asia/Bishkek" ]),
+    ("Central Asia Standard Time", "CN", &[ "Asia/Urumqi" ]),
+    ("Central Asia Standard Time", "IO", &[ "Indian/Chagos" ]),
+    ("Central Asia Standard Time", "KG", &[ "Asia/Bishkek" ]),
+    ("Central Asia Standard Time", "KZ", &[ "Asia/Almaty", "Asia/Qostanay" ]),
+    ("Central Asia Standard Time", "ZZ", &[ "Etc/GMT-6" ]),
+    ("Central Brazilian Standard Time", "001", &[ "America/Cuiaba" ]),
+    ("Central Brazilian Standard Time", "BR", &[ "America/Cuiaba", "America/Campo_Grande" ]),
+    ("Central Europe Standard Time", "001", &[ "Europe/Budapest" ]),
+    ("Central Europe Standard Time", "AL", &[ "Europe/Tirane" ]),
+    ("Central Europe Standard Time", "CZ", &[ "Europe/Prague" ]),
+    ("Central Europe Standard Time", "HU", &[ "Europe/Budapest" ]),
+    ("Central Europe Standard Time", "ME", &[ "Europe/Podgorica" ]),
+    ("Central Europe Standard Time", "RS", &[ "Europe/Belgrade" ]),
+    ("Central Europe Standard Time", "SI", &[ "Europe/Ljubljana" ]),
+    ("Central Europe Standard Time", "SK", &[ "Europe/Bratislava" ]),
+    ("Central European Standard Time", "001", &[ "Europe/Warsaw" ]),
+    ("Central European Standard Time", "BA", &[ "Europe/Sarajevo" ]),
+    ("Central European Standard Time", "HR", &[ "Europe/Zagreb" ]),
+    ("Central European Standard Time", "MK", &[ "Europe/Skopje" ]),
+    ("Central European Standard Time", "PL", &[ "Europe/Warsaw" ]),
+    ("Central Pacific Standard Time", "001", &[ "Pacific/Guadalcanal" ]),
+    ("Central Pacific Standard Time", "AQ", &[ "Antarctica/Casey" ]),
+    ("Central Pacific Standard Time", "AU", &[ "Antarctica/Macquarie" ]),
+    ("Central Pacific Standard Time", "FM", &[ "Pacific/Kosrae" ]),
+    ("Central Pacific Standard Time", "NC", &[ "Pacific/Noumea" ]),
+    ("Central Pacific Standard Time", "SB", &[ "Pacific/Guadalcanal", "Pacific/Ponape" ]),
+    ("Central Pacific Standard Time", "VU", &[ "Pacific/Efate" ]),
+    ("Central Pacific Standard Time", "ZZ", &[ "Etc/GMT-11" ]),
+    ("Central Standard Time", "001", &[ "America/Chicago" ]),
+    ("Central Standard Time", "CA", &[ "America/Winnipeg", "America/Rankin_Inlet", "America/Resolute" ]),
+    ("Central Standard Time", "MX", &[ "America/Matamoros", "America/Ojinaga" ]),
+    ("Central Standard Time", "US", &[ "America/Chicago", "America/Indiana/Knox", "America/Indiana/Tell_City", "America/Menominee", "America/North_Dakota/Beulah", "America/North_Dakota/Center", "America/North_Dakota/New_Salem" ]),
+    ("Central Standard Time", "ZZ", &[ "CST6CDT" ]),
+    ("Central Standard Time (Mexico)", "001", &[ "America/Mexico_City" ]),
+    ("Central Standard Time (Mexico)", "MX", &[ "America/Mexico_City", "America/Bahia_Banderas", "America/Merida", "America/Monterrey", "America/Chihuahua" ]),
+    ("Chatham Islands Standard Time", "001", &[ "Pacific/Chatham" ]),
+    ("Chatham Islands Standard Time", "NZ", &[ "Pacific/Chatham" ]),
+    ("China Standard Time", "001", &[ "Asia/Shanghai" ]),
+    ("China Standard Time", "CN", &[ "Asia/Shanghai" ]),
+    ("China Standard Time", "HK", &[ "Asia/Hong_Kong" ]),
+    ("China Standard Time", "MO", &[ "Asia/Macau" ]),
+    ("Cuba Standard Time", "001", &[ "America/Havana" ]),
+    ("Cuba Standard Time", "CU", &[ "America/Havana" ]),
+    ("Dateline Standard Time", "001", &[ "Etc/GMT+12" ]),
+    ("Dateline Standard Time", "ZZ", &[ "Etc/GMT+12" ]),
+    ("E. Africa Standard Time", "001", &[ "Africa/Nairobi" ]),
+    ("E. Africa Standard Time", "AQ", &[ "Antarctica/Syowa" ]),
+    ("E. Africa Standard Time", "DJ", &[ "Africa/Djibouti" ]),
+    ("E. Africa Standard Time", "ET", &[ "Africa/Addis_Ababa" ]),
+    ("E. Africa Standard Time", "KE", &[ "Africa/Nairobi", "Africa/Asmera" ]),
+    ("E. Africa Standard Time", "KM", &[ "Indian/Comoro" ]),
+    ("E. Africa Standard Time", "MG", &[ "Indian/Antananarivo" ]),
+    ("E. Africa Standard Time", "SO", &[ "Africa/Mogadishu" ]),
+    ("E. Africa Standard Time", "TZ", &[ "Africa/Dar_es_Salaam" ]),
+    ("E. Africa Standard Time", "UG", &[ "Africa/Kampala" ]),
+    ("E. Africa Standard Time", "YT", &[ "Indian/Mayotte" ]),
+    ("E. Africa Standard Time", "ZZ", &[ "Etc/GMT-3" ]),
+    ("E. Australia Standard Time", "001", &[ "Australia/Brisbane" ]),
+    ("E. Australia Standard Time", "AU", &[ "Australia/Brisbane", "Australia/Lindeman" ]),
+    ("E. Europe Standard Time", "001", &[ "Europe/Chisinau" ]),
+    ("E. Europe Standard Time", "MD", &[ "Europe/Chisinau" ]),
+    ("E. South America Standard Time", "001", &[ "America/Sao_Paulo" ]),
+    ("E. South America Standard Time", "BR", &[ "America/Sao_Paulo" ]),
+    ("Easter Island Standard Time", "001", &[ "Pacific/Easter" ]),
+    ("Easter Island Standard Time", "CL", &[ "Pacific/Easter" ]),
+    ("Eastern Standard Time", "001", &[ "America/New_York" ]),
+    ("Eastern Standard Time", "BS", &[ "America/Nassau" ]),
+    ("Eastern Standard Time", "CA", &[ "America/Toronto", "America/Iqaluit" ]),
+    ("Eastern Standard Time", "US", &[ "America/New_York", "America/Detroit", "America/Indiana/Petersburg", "America/Indiana/Vincennes", "America/Indiana/Winamac", "America/Kentucky/Monticello", "America/Louisville" ]),
+    ("Eastern Standard Time", "ZZ", &[ "EST5EDT" ]),
+    ("Eastern Standard Time (Mexico)", "001", &[ "America/Cancun" ]),
+    ("Eastern Standard Time (Mexico)", "MX", &[ "America/Cancun" ]),
+    ("Egypt Standard Time", "001", &[ "Africa/Cairo" ]),
+    ("Egypt Standard Time", "EG", &[ "Africa/Cairo" ]),
+    ("Ekaterinburg Standard Time", "001", &[ "Asia/Yekaterinburg" ]),
+    ("Ekaterinburg Standard Time", "RU", &[ "Asia/Yekaterinburg" ]),
+    ("FLE Standard Time", "001", &[ "Europe/Kiev" ]),
+    ("FLE Standard Time", "AX", &[ "Europe/Mariehamn" ]),
+    ("FLE Standard Time", "BG", &[ "Europe/Sofia" ]),
+    ("FLE Standard Time", "EE", &[ "Europe/Tallinn" ]),
+    ("FLE Standard Time", "FI", &[ "Europe/Helsinki" ]),
+    ("FLE Standard Time", "LT", &[ "Europe/Vilnius" ]),
+    ("FLE Standard Time", "LV", &[ "Europe/Riga" ]),
+    ("FLE Standard Time", "UA", &[ "Europe/Kiev" ]),
+    ("Fiji Standard Time", "001", &[ "Pacific/Fiji" ]),
+    ("Fiji Standard Time", "FJ", &[ "Pacific/Fiji" ]),
+    ("GMT Standard Time", "001", &[ "Europe/London" ]),
+    ("GMT Standard Time", "ES", &[ "Atlantic/Canary" ]),
+    ("GMT Standard Time", "FO", &[ "Atlantic/Faeroe" ]),
+    ("GMT Standard Time", "GB", &[ "Europe/London" ]),
+    ("GMT Standard Time", "GG", &[ "Europe/Guernsey" ]),
+    ("GMT Standard Time", "IE", &[ "Europe/Dublin" ]),
+    ("GMT Standard Time", "IM", &[ "Europe/Isle_of_Man" ]),
+    ("GMT Standard Time", "JE", &[ "Europe/Jersey" ]),
+    ("GMT Standard Time", "PT", &[ "Europe/Lisbon", "Atlantic/Madeira" ]),
+    ("GTB Standard Time", "001", &[ "Europe/Bucharest" ]),
+    ("GTB Standard Time", "CY", &[ "Asia/Nicosia", "Asia/Famagusta" ]),
+    ("GTB Standard Time", "GR", &[ "Europe/Athens" ]),
+    ("GTB Standard Time", "RO", &[ "Europe/Bucharest" ]),
+    ("Georgian Standard Time", "001", &[ "Asia/Tbilisi" ]),
+    ("Georgian Standard Time", "GE", &[ "Asia/Tbilisi" ]),
+    ("Greenland Standard Time", "001", &[ "America/Godthab" ]),
+    ("Greenland Standard Time", "GL", &[ "America/Godthab" ]),
+    ("Greenwich Standard Time", "001", &[ "Atlantic/Reykjavik" ]),
+    ("Greenwich Standard Time", "BF", &[ "Africa/Ouagadougou" ]),
+    ("Greenwich Standard Time", "CI", &[ "Africa/Abidjan" ]),
+    ("Greenwich Standard Time", "GH", &[ "Africa/Accra" ]),
+    ("Greenwich Standard Time", "GM", &[ "Africa/Banjul" ]),
+    ("Greenwich Standard Time", "GN", &[ "Africa/Conakry" ]),
+    ("Greenwich Standard Time", "GW", &[ "Africa/Bissau" ]),
+    ("Greenwich Standard Time", "IS", &[ "Atlantic/Reykjavik" ]),
+    ("Greenwich Standard Time", "LR", &[ "Africa/Monrovia" ]),
+    ("Greenwich Standard Time", "ML", &[ "Africa/Bamako" ]),
+    ("Greenwich Standard Time", "MR", &[ "Africa/Nouakchott" ]),
+    ("Greenwich Standard Time", "SH", &[ "Atlantic/St_Helena" ]),
+    ("Greenwich Standard Time", "SL", &[ "Africa/Freetown" ]),
+    ("Greenwich Standard Time", "SN", &[ "Africa/Dakar" ]),
+    ("Greenwich Standard Time", "TG", &[ "Africa/Lome" ]),
+    ("Haiti Standard Time", "001", &[ "America/Port-au-Prince" ]),
+    ("Haiti Standard Time", "HT", &[ "America/Port-au-Prince" ]),
+    ("Hawaiian Standard Time", "001", &[ "Pacific/Honolulu" ]),
+    ("Hawaiian Standard Time", "CK", &[ "Pacific/Rarotonga" ]),
+    ("Hawaiian Standard Time", "PF", &[ "Pacific/Tahiti" ]),
+    ("Hawaiian Standard Time", "US", &[ "Pacific/Honolulu" ]),
+    ("Hawaiian Standard Time", "ZZ", &[ "Etc/GMT+10" ]),
+    ("India Standard Time", "001", &[ "Asia/Calcutta" ]),
+    ("India Standard Time", "IN", &[ "Asia/Calcutta" ]),
+    ("Iran Standard Time", "001", &[ "Asia/Tehran" ]),
+    ("Iran Standard Time", "IR", &[ "Asia/Tehran" ]),
+    ("Israel Standard Time", "001", &[ "Asia/Jerusalem" ]),
+    ("Israel Standard Time", "IL", &[ "Asia/Jerusalem" ]),
+    ("Jordan Standard Time", "001", &[ "Asia/Amman" ]),
+    ("Jordan Standard Time", "JO", &[ "Asia/Amman" ]),
+    ("Kaliningrad Standard Time", "001", &[ "Europe/Kaliningrad" ]),
+    ("Kaliningrad Standard Time", "RU", &[ "Europe/Kaliningrad" ]),
+    ("Korea Standard Time", "001", &[ "Asia/Seoul" ]),
+    ("Korea Standard Time", "KR", &[ "Asia/Seoul" ]),
+    ("Libya Standard Time", "001", &[ "Africa/Tripoli" ]),
+    ("Libya Standard Time", "LY", &[ "Africa/Tripoli" ]),
+    ("Line Islands Standard Time", "001", &[ "Pacific/Kiritimati" ]),
+    ("Line Islands Standard Time", "KI", &[ "Pacific/Kiritimati" ]),
+    ("Line Islands Standard Time", "ZZ", &[ "Etc/GMT-14" ]),
+    ("Lord Howe Standard Time", "001", &[ "Australia/Lord_Howe" ]),
+    ("Lord Howe Standard Time", "AU", &[ "Australia/Lord_Howe" ]),
+    ("Magadan Standard Time", "001", &[ "Asia/Magadan" ]),
+    ("Magadan Standard Time", "RU", &[ "Asia/Magadan" ]),
+    ("Magallanes Standard Time", "001", &[ "America/Punta_Arenas" ]),
+    ("Magallanes Standard Time", "CL", &[ "America/Punta_Arenas" ]),
+    ("Marquesas Standard Time", "001", &[ "Pacific/Marquesas" ]),
+    ("Marquesas Standard Time", "PF", &[ "Pacific/Marquesas" ]),
+    ("Mauritius Standard Time", "001", &[ "Indian/Mauritius" ]),
+    ("Mauritius Standard Time", "MU", &[ "Indian/Mauritius" ]),
+    ("Mauritius Standard Time", "RE", &[ "Indian/Reunion" ]),
+    ("Mauritius Standard Time", "SC", &[ "Indian/Mahe" ]),
+    ("Middle East Standard Time", "001", &[ "Asia/Beirut" ]),
+    ("Middle East Standard Time", "LB", &[ "Asia/Beirut" ]),
+    ("Montevideo Standard Time", "001", &[ "America/Montevideo" ]),
+    ("Montevideo Standard Time", "UY", &[ "America/Montevideo" ]),
+    ("Morocco Standard Time", "001", &[ "Africa/Casablanca" ]),
+    ("Morocco Standard Time", "EH", &[ "Africa/El_Aaiun" ]),
+    ("Morocco Standard Time", "MA", &[ "Africa/Casablanca" ]),
+    ("Mountain Standard Time", "001", &[ "America/Denver" ]),
+    ("Mountain Standard Time", "CA", &[ "America/Edmonton", "America/Cambridge_Bay", "America/Inuvik" ]),
+    ("Mountain Standard Time", "MX", &[ "America/Ciudad_Juarez" ]),
+    ("Mountain Standard Time", "US", &[ "America/Denver", "America/Boise" ]),
+    ("Mountain Standard Time", "ZZ", &[ "MST7MDT" ]),
+    ("Mountain Standard Time (Mexico)", "001", &[ "America/Mazatlan" ]),
+    ("Mountain Standard Time (Mexico)", "MX", &[ "America/Mazatlan" ]),
+    ("Myanmar Standard Time", "001", &[ "Asia/Rangoon" ]),
+    ("Myanmar Standard Time", "CC", &[ "Indian/Cocos" ]),
+    ("Myanmar Standard Time", "MM", &[ "Asia/Rangoon" ]),
+    ("N. Central Asia Standard Time", "001", &[ "Asia/Novosibirsk" ]),
+    ("N. Central Asia Standard Time", "RU", &[ "Asia/Novosibirsk" ]),
+    ("Namibia Standard Time", "001", &[ "Africa/Windhoek" ]),
+    ("Namibia Standard Time", "NA", &[ "Africa/Windhoek" ]),
+    ("Nepal Standard Time", "001", &[ "Asia/Katmandu" ]),
+    ("Nepal Standard Time", "NP", &[ "Asia/Katmandu" ]),
+    ("New Zealand Standard Time", "001", &[ "Pacific/Auckland" ]),
+    ("New Zealand Standard Time", "AQ", &[ "Antarctica/McMurdo" ]),
+    ("New Zealand Standard Time", "NZ", &[ "Pacific/Auckland" ]),
+    ("Newfoundland Standard Time", "001", &[ "America/St_Johns" ]),
+    ("Newfoundland Standard Time", "CA", &[ "America/St_Johns" ]),
+    ("Norfolk Standard Time", "001", &[ "Pacific/Norfolk" ]),
+    ("Norfolk Standard Time", "NF", &[ "Pacific/Norfolk" ]),
+    ("North Asia East Standard Time", "001", &[ "Asia/Irkutsk" ]),
+    ("North Asia East Standard Time", "RU", &[ "Asia/Irkutsk" ]),
+    ("North Asia Standard Time", "001", &[ "Asia/Krasnoyarsk" ]),
+    ("North Asia Standard Time", "RU", &[ "Asia/Krasnoyarsk", "Asia/Novokuznetsk" ]),
+    ("North Korea Standard Time", "001", &[ "Asia/Pyongyang" ]),
+    ("North Korea Standard Time", "KP", &[ "Asia/Pyongyang" ]),
+    ("Omsk Standard Time", "001", &[ "Asia/Omsk" ]),
+    ("Omsk Standard Time", "RU", &[ "Asia/Omsk" ]),
+    ("Pacific SA Standard Time", "001", &[ "America/Santiago" ]),
+    ("Pacific SA Standard Time", "CL", &[ "America/Santiago" ]),
+    ("Pacific Standard Time", "001", &[ "America/Los_Angeles" ]),
+    ("Pacific Standard Time", "CA", &[ "America/Vancouver" ]),
+    ("Pacific Standard Time", "US", &[ "America/Los_Angeles" ]),
+    ("Pacific Standard Time", "ZZ", &[ "PST8PDT" ]),
+    ("Pacific Standard Time (Mexico)", "001", &[ "America/Tijuana" ]),
+    ("Pacific Standard Time (Mexico)", "MX", &[ "America/Tijuana", "America/Santa_Isabel" ]),
+    ("Pakistan Standard Time", "001", &[ "Asia/Karachi" ]),
+    ("Pakistan Standard Time", "PK", &[ "Asia/Karachi" ]),
+    ("Paraguay Standard Time", "001", &[ "America/Asuncion" ]),
+    ("Paraguay Standard Time", "PY", &[ "America/Asuncion" ]),
+    ("Qyzylorda Standard Time", "001", &[ "Asia/Qyzylorda" ]),
+    ("Qyzylorda Standard Time", "KZ", &[ "Asia/Qyzylorda" ]),
+    ("Romance Standard Time", "001", &[ "Europe/Paris" ]),
+    ("Romance Standard Time", "BE", &[ "Europe/Brussels" ]),
+    ("Romance Standard Time", "DK", &[ "Europe/Copenhagen" ]),
+    ("Romance Standard Time", "ES", &[ "Europe/Madrid", "Africa/Ceuta" ]),
+    ("Romance Standard Time", "FR", &[ "Europe/Paris" ]),
+    ("Russia Time Zone 10", "001", &[ "Asia/Srednekolymsk" ]),
+    ("Russia Time Zone 10", "RU", &[ "Asia/Srednekolymsk" ]),
+    ("Russia Time Zone 11", "001", &[ "Asia/Kamchatka" ]),
+    ("Russia Time Zone 11", "RU", &[ "Asia/Kamchatka", "Asia/Anadyr" ]),
+    ("Russia Time Zone 3", "001", &[ "Europe/Samara" ]),
+    ("Russia Time Zone 3", "RU", &[ "Europe/Samara" ]),
+    ("Russian Standard Time", "001", &[ "Europe/Moscow" ]),
+    ("Russian Standard Time", "RU", &[ "Europe/Moscow", "Europe/Kirov" ]),
+    ("Russian Standard Time", "UA", &[ "Europe/Simferopol" ]),
+    ("SA Eastern Standard Time", "001", &[ "America/Cayenne" ]),
+    ("SA Eastern Standard Time", "AQ", &[ "Antarctica/Rothera", "Antarctica/Palmer" ]),
+    ("SA Eastern Standard Time", "BR", &[ "America/Fortaleza", "America/Belem", "America/Maceio", "America/Recife", "America/Santarem" ]),
+    ("SA Eastern Standard Time", "FK", &[ "Atlantic/Stanley" ]),
+    ("SA Eastern Standard Time", "GF", &[ "America/Cayenne" ]),
+    ("SA Eastern Standard Time", "SR", &[ "America/Paramaribo" ]),
+    ("SA Eastern Standard Time", "ZZ", &[ "Etc/GMT+3" ]),
+    ("SA Pacific Standard Time", "001", &[ "America/Bogota" ]),
+    ("SA Pacific Standard Time", "BR", &[ "America/Rio_Branco", "America/Eirunepe" ]),
+    ("SA Pacific Standard Time", "CO", &[ "America/Bogota" ]),
+    ("SA Pacific Standard Time", "EC", &[ "America/Guayaquil" ]),
+    ("SA Pacific Standard Time", "JM", &[ "America/Jamaica" ]),
+    ("SA Pacific Standard Time", "KY", &[ "America/Cayman" ]),
+    ("SA Pacific Standard Time", "PA", &[ "America/Coral_Harbour", "America/Panama" ]),
+    ("SA Pacific Standard Time", "PE", &[ "America/Lima" ]),
+    ("SA Pacific Standard Time", "ZZ", &[ "Etc/GMT+5" ]),
+    ("SA Western Standard Time", "001", &[ "America/La_Paz" ]),
+    ("SA Western Standard Time", "AG", &[ "America/Antigua" ]),
+    ("SA Western Standard Time", "AI", &[ "America/Anguilla" ]),
+    ("SA Western Standard Time", "AW", &[ "America/Aruba" ]),
+    ("SA Western Standard Time", "BB", &[ "America/Barbados" ]),
+    ("SA Western Standard Time", "BL", &[ "America/St_Barthelemy" ]),
+    ("SA Western Standard Time", "BO", &[ "America/La_Paz" ]),
+    ("SA Western Standard Time", "BQ", &[ "America/Kralendijk" ]),
+    ("SA Western Standard Time", "BR", &[ "America/Manaus", "America/Boa_Vista", "America/Porto_Velho" ]),
+    ("SA Western Standard Time", "CA", &[ "America/Blanc-Sablon" ]),
+    ("SA Western Standard Time", "CW", &[ "America/Curacao" ]),
+    ("SA Western Standard Time", "DM", &[ "America/Dominica" ]),
+    ("SA Western Standard Time", "DO", &[ "America/Santo_Domingo" ]),
+    ("SA Western Standard Time", "GD", &[ "America/Grenada" ]),
+    ("SA Western Standard Time", "GP", &[ "America/Guadeloupe" ]),
+    ("SA Western Standard Time", "GY", &[ "America/Guyana" ]),
+    ("SA Western Standard Time", "KN", &[ "America/St_Kitts" ]),
+    ("SA Western Standard Time", "LC", &[ "America/St_Lucia" ]),
+    ("SA Western Standard Time", "MF", &[ "America/Marigot" ]),
+    ("SA Western Standard Time", "MQ", &[ "America/Martinique" ]),
+    ("SA Western Standard Time", "MS", &[ "America/Montserrat" ]),
+    ("SA Western Standard Time", "PR", &[ "America/Puerto_Rico" ]),
+    ("SA Western Standard Time", "SX", &[ "America/Lower_Princes" ]),
+    ("SA Western Standard Time", "TT", &[ "America/Port_of_Spain" ]),
+    ("SA Western Standard Time", "VC", &[ "America/St_Vincent" ]),
+    ("SA Western Standard Time", "VG", &[ "America/Tortola" ]),
+    ("SA Western Standard Time", "VI", &[ "America/St_Thomas" ]),
+    ("SA Western Standard Time", "ZZ", &[ "Etc/GMT+4" ]),
+    ("SE Asia Standard Time", "001", &[ "Asia/Bangkok" ]),
+    ("SE Asia Standard Time", "AQ", &[ "Antarctica/Davis" ]),
+    ("SE Asia Standard Time", "CX", &[ "Indian/Christmas" ]),
+    ("SE Asia Standard Time", "ID", &[ "Asia/Jakarta", "Asia/Pontianak" ]),
+    ("SE Asia Standard Time", "KH", &[ "Asia/Phnom_Penh" ]),
+    ("SE Asia Standard Time", "LA", &[ "Asia/Vientiane" ]),
+    ("SE Asia Standard Time", "TH", &[ "Asia/Bangkok" ]),
+    ("SE Asia Standard Time", "VN", &[ "Asia/Saigon" ]),
+    ("SE Asia Standard Time", "ZZ", &[ "Etc/GMT-7" ]),
+    ("Saint Pierre Standard Time", "001", &[ "America/Miquelon" ]),
+    ("Saint Pierre Standard Time", "PM", &[ "America/Miquelon" ]),
+    ("Sakhalin Standard Time", "001", &[ "Asia/Sakhalin" ]),
+    ("Sakhalin Standard Time", "RU", &[ "Asia/Sakhalin" ]),
+    ("Samoa Standard Time", "001", &[ "Pacific/Apia" ]),
+    ("Samoa Standard Time", "WS", &[ "Pacific/Apia" ]),
+    ("Sao Tome Standard Time", "001", &[ "Africa/Sao_Tome" ]),
+    ("Sao Tome Standard Time", "ST", &[ "Africa/Sao_Tome" ]),
+    ("Saratov Standard Time", "001", &[ "Europe/Saratov" ]),
+    ("Saratov Standard Time", "RU", &[ "Europe/Saratov" ]),
+    ("Singapore Standard Time", "001", &[ "Asia/Singapore" ]),
+    ("Singapore Standard Time", "BN", &[ "Asia/Brunei" ]),
+    ("Singapore Standard Time", "ID", &[ "Asia/Makassar" ]),
+    ("Singapore Standard Time", "MY", &[ "Asia/Kuala_Lumpur", "Asia/Kuching" ]),
+    ("Singapore Standard Time", "PH", &[ "Asia/Manila" ]),
+    ("Singapore Standard Time", "SG", &[ "Asia/Singapore" ]),
+    ("Singapore Standard Time", "ZZ", &[ "Etc/GMT-8" ]),
+    ("South Africa Standard Time", "001", &[ "Africa/Johannesburg" ]),
+    ("South Africa Standard Time", "BI", &[ "Africa/Bujumbura" ]),
+    ("South Africa Standard Time", "BW", &[ "Africa/Gaborone" ]),
+    ("South Africa Standard Time", "CD", &[ "Africa/Lubumbashi" ]),
+    ("South Africa Standard Time", "LS", &[ "Africa/Maseru" ]),
+    ("South Africa Standard Time", "MW", &[ "Africa/Blantyre" ]),
+    ("South Africa Standard Time", "MZ", &[ "Africa/Maputo" ]),
+    ("South Africa Standard Time", "RW", &[ "Africa/Kigali" ]),
+    ("South Africa Standard Time", "SZ", &[ "Africa/Mbabane" ]),
+    ("South Africa Standard Time", "ZA", &[ "Africa/Johannesburg" ]),
+    ("South Africa Standard Time", "ZM", &[ "Africa/Lusaka" ]),
+    ("South Africa Standard Time", "ZW", &[ "Africa/Harare" ]),
+    ("South Africa Standard Time", "ZZ", &[ "Etc/GMT-2" ]),
+    ("South Sudan Standard Time", "001", &[ "Africa/Juba" ]),
+    ("South Sudan Standard Time", "SS", &[ "Africa/Juba" ]),
+    ("Sri Lanka Standard Time", "001", &[ "Asia/Colombo" ]),
+    ("Sri Lanka Standard Time", "LK", &[ "Asia/Colombo" ]),
+    ("Sudan Standard Time", "001", &[ "Africa/Khartoum" ]),
+    ("Sudan Standard Time", "SD", &[ "Africa/Khartoum" ]),
+    ("Syria Standard Time", "001", &[ "Asia/Damascus" ]),
+    ("Syria Standard Time", "SY", &[ "Asia/Damascus" ]),
+    ("Taipei Standard Time", "001", &[ "Asia/Taipei" ]),
+    ("Taipei Standard Time", "TW", &[ "Asia/Taipei" ]),
+    ("Tasmania Standard Time", "001", &[ "Australia/Hobart" ]),
+    ("Tasmania Standard Time", "AU", &[ "Australia/Hobart" ]),
+    ("Tocantins Standard Time", "001", &[ "America/Araguaina" ]),
+    ("Tocantins Standard Time", "BR", &[ "America/Araguaina" ]),
+    ("Tokyo Standard Time", "001", &[ "Asia/Tokyo" ]),
+    ("Tokyo Standard Time", "ID", &[ "Asia/Jayapura" ]),
+    ("Tokyo Standard Time", "JP", &[ "Asia/Tokyo" ]),
+    ("Tokyo Standard Time", "PW", &[ "Pacific/Palau" ]),
+    ("Tokyo Standard Time", "TL", &[ "Asia/Dili" ]),
+    ("Tokyo Standard Time", "ZZ", &[ "Etc/GMT-9" ]),
+    ("Tomsk Standard Time", "001", &[ "Asia/Tomsk" ]),
+    ("Tomsk Standard Time", "RU", &[ "Asia/Tomsk" ]),
+    ("Tonga Standard Time", "001", &[ "Pacific/Tongatapu" ]),
+    ("Tonga Standard Time", "TO", &[ "Pacific/Tongatapu" ]),
+    ("Transbaikal Standard Time", "001", &[ "Asia/Chita" ]),
+    ("Transbaikal Standard Time", "RU", &[ "Asia/Chita" ]),
+    ("Turkey Standard Time", "001", &[ "Europe/Istanbul" ]),
+    ("Turkey Standard Time", "TR", &[ "Europe/Istanbul" ]),
+    ("Turks And Caicos Standard Time", "001", &[ "America/Grand_Turk" ]),
+    ("Turks And Caicos Standard Time", "TC", &[ "America/Grand_Turk" ]),
+    ("US Eastern Standard Time", "001", &[ "America/Indianapolis" ]),
+    ("US Eastern Standard Time", "US", &[ "America/Indianapolis", "America/Indiana/Marengo", "America/Indiana/Vevay" ]),
+    ("US Mountain Standard Time", "001", &[ "America/Phoenix" ]),
+    ("US Mountain Standard Time", "CA", &[ "America/Creston", "America/Dawson_Creek", "America/Fort_Nelson" ]),
+    ("US Mountain Standard Time", "MX", &[ "America/Hermosillo" ]),
+    ("US Mountain Standard Time", "US", &[ "America/Phoenix" ]),
+    ("US Mountain Standard Time", "ZZ", &[ "Etc/GMT+7" ]),
+    ("UTC", "001", &[ "Etc/UTC" ]),
+    ("UTC", "GL", &[ "America/Danmarkshavn" ]),
+    ("UTC", "ZZ", &[ "Etc/UTC", "Etc/GMT" ]),
+    ("UTC+12", "001", &[ "Etc/GMT-12" ]),
+    ("UTC+12", "KI", &[ "Pacific/Tarawa" ]),
+    ("UTC+12", "MH", &[ "Pacific/Majuro", "Pacific/Kwajalein" ]),
+    ("UTC+12", "NR", &[ "Pacific/Nauru" ]),
+    ("UTC+12", "TV", &[ "Pacific/Funafuti" ]),
+    ("UTC+12", "UM", &[ "Pacific/Wake" ]),
+    ("UTC+12", "WF", &[ "Pacific/Wallis" ]),
+    ("UTC+12", "ZZ", &[ "Etc/GMT-12" ]),
+    ("UTC+13", "001", &[ "Etc/GMT-13" ]),
+    ("UTC+13", "KI", &[ "Pacific/Enderbury" ]),
+    ("UTC+13", "TK", &[ "Pacific/Fakaofo" ]),
+    ("UTC+13", "ZZ", &[ "Etc/GMT-13" ]),
+    ("UTC-02", "001", &[ "Etc/GMT+2" ]),
+    ("UTC-02", "BR", &[ "America/Noronha" ]),
+    ("UTC-02", "GS", &[ "Atlantic/South_Georgia" ]),
+    ("UTC-02", "ZZ", &[ "Etc/GMT+2" ]),
+    ("UTC-08", "001", &[ "Etc/GMT+8" ]),
+    ("UTC-08", "PN", &[ "Pacific/Pitcairn" ]),
+    ("UTC-08", "ZZ", &[ "Etc/GMT+8" ]),
+    ("UTC-09", "001", &[ "Etc/GMT+9" ]),
+    ("UTC-09", "PF", &[ "Pacific/Gambier" ]),
+    ("UTC-09", "ZZ", &[ "Etc/GMT+9" ]),
+    ("UTC-11", "001", &[ "Etc/GMT+11" ]),
+    ("UTC-11", "AS", &[ "Pacific/Pago_Pago" ]),
+    ("UTC-11", "NU", &[ "Pacific/Niue" ]),
+    ("UTC-11", "UM", &[ "Pacific/Midway" ]),
+    ("UTC-11", "ZZ", &[ "Etc/GMT+11" ]),
+    ("Ulaanbaatar Standard Time", "001", &[ "Asia/Ulaanbaatar" ]),
+    ("Ulaanbaatar Standard Time", "MN", &[ "Asia/Ulaanbaatar" ]),
+    ("Venezuela Standard Time", "001", &[ "America/Caracas" ]),
+    ("Venezuela Standard Time", "VE", &[ "America/Caracas" ]),
+    ("Vladivostok Standard Time", "001", &[ "Asia/Vladivostok" ]),
+    ("Vladivostok Standard Time", "RU", &[ "Asia/Vladivostok", "Asia/Ust-Nera" ]),
+    ("Volgograd Standard Time", "001", &[ "Europe/Volgograd" ]),
+    ("Volgograd Standard Time", "RU", &[ "Europe/Volgograd" ]),
+    ("W. Australia Standard Time", "001", &[ "Australia/Perth" ]),
+    ("W. Australia Standard Time", "AU", &[ "Australia/Perth" ]),
+    ("W. Central Africa Standard Time", "001", &[ "Africa/Lagos" ]),
+    ("W. Central Africa Standard Time", "AO", &[ "Africa/Luanda" ]),
+    ("W. Central Africa Standard Time", "BJ", &[ "Africa/Porto-Novo" ]),
+    ("W. Central Africa Standard Time", "CD", &[ "Africa/Kinshasa" ]),
+    ("W. Central Africa Standard Time", "CF", &[ "Africa/Bangui" ]),
+    ("W. Central Africa Standard Time", "CG", &[ "Africa/Brazzaville" ]),
+    ("W. Central Africa Standard Time", "CM", &[ "Africa/Douala" ]),
+    ("W. Central Africa Standard Time", "DZ", &[ "Africa/Algiers" ]),
+    ("W. Central Africa Standard Time", "GA", &[ "Africa/Libreville" ]),
+    ("W. Central Africa Standard Time", "GQ", &[ "Africa/Malabo" ]),
+    ("W. Central Africa Standard Time", "NE", &[ "Africa/Niamey" ]),
+    ("W. Central Africa Standard Time", "NG", &[ "Africa/Lagos" ]),
+    ("W. Central Africa Standard Time", "TD", &[ "Africa/Ndjamena" ]),
+    ("W. Central Africa Standard Time", "TN", &[ "Africa/Tunis" ]),
+    ("W. Central Africa Standard Time", "ZZ", &[ "Etc/GMT-1" ]),
+    ("W. Europe Standard Time", "001", &[ "Europe/Berlin" ]),
+    ("W. Europe Standard Time", "AD", &[ "Europe/Andorra" ]),
+    ("W. Europe Standard Time", "AT", &[ "Europe/Vienna" ]),
+    ("W. Europe Standard Time", "CH", &[ "Europe/Zurich" ]),
+    ("W. Europe Standard Time", "DE", &[ "Europe/Berlin", "Europe/Busingen" ]),
+    ("W. Europe Standard Time", "GI", &[ "Europe/Gibraltar" ]),
+    ("W. Europe Standard Time", "IT", &[ "Europe/Rome" ]),
+    ("W. Europe Standard Time", "LI", &[ "Europe/Vaduz" ]),
+    ("W. Europe Standard Time", "LU", &[ "Europe/Luxembourg" ]),
+    ("W. Europe Standard Time", "MC", &[ "Europe/Monaco" ]),
+    ("W. Europe Standard Time", "MT", &[ "Europe/Malta" ]),
+    ("W. Europe Standard Time", "NL", &[ "Europe/Amsterdam" ]),
+    ("W. Europe Standard Time", "NO", &[ "Europe/Oslo" ]),
+    ("W. Europe Standard Time", "SE", &[ "Europe/Stockholm" ]),
+    ("W. Europe Standard Time", "SJ", &[ "Arctic/Longyearbyen" ]),
+    ("W. Europe Standard Time", "SM", &[ "Europe/San_Marino" ]),
+    ("W. Europe Standard Time", "VA", &[ "Europe/Vatican" ]),
+    ("W. Mongolia Standard Time", "001", &[ "Asia/Hovd" ]),
+    ("W. Mongolia Standard Time", "MN", &[ "Asia/Hovd" ]),
+    ("West Asia Standard Time", "001", &[ "Asia/Tashkent" ]),
+    ("West Asia Standard Time", "AQ", &[ "Antarctica/Mawson", "Antarctica/Vostok" ]),
+    ("West Asia Standard Time", "KZ", &[ "Asia/Oral", "Asia/Aqtau", "Asia/Aqtobe", "Asia/Atyrau" ]),
+    ("West Asia Standard Time", "MV", &[ "Indian/Maldives" ]),
+    ("West Asia Standard Time", "TF", &[ "Indian/Kerguelen" ]),
+    ("West Asia Standard Time", "TJ", &[ "Asia/Dushanbe" ]),
+    ("West Asia Standard Time", "TM", &[ "Asia/Ashgabat" ]),
+    ("West Asia Standard Time", "UZ", &[ "Asia/Tashkent", "Asia/Samarkand" ]),
+    ("West Asia Standard Time", "ZZ", &[ "Etc/GMT-5" ]),
+    ("West Bank Standard Time", "001", &[ "Asia/Hebron" ]),
+    ("West Bank Standard Time", "PS", &[ "Asia/Hebron", "Asia/Gaza" ]),
+    ("West Pacific Standard Time", "001", &[ "Pacific/Port_Moresby" ]),
+    ("West Pacific Standard Time", "AQ", &[ "Antarctica/DumontDUrville" ]),
+    ("West Pacific Standard Time", "GU", &[ "Pacific/Guam" ]),
+    ("West Pacific Standard Time", "MP", &[ "Pacific/Saipan" ]),
+    ("West Pacific Standard Time", "PG", &[ "Pacific/Port_Moresby", "Pacific/Truk" ]),
+    ("West Pacific Standard Time", "ZZ", &[ "Etc/GMT-10" ]),
+    ("Yakutsk Standard Time", "001", &[ "Asia/Yakutsk" ]),
+    ("Yakutsk Standard Time", "RU", &[ "Asia/Yakutsk", "Asia/Khandyga" ]),
+    ("Yukon Standard Time", "001", &[ "America/Whitehorse" ]),
+    ("Yukon Standard Time", "CA", &[ "America/Whitehorse", "America/Dawson" ]),
+];
+
+/// Every zone name in the mappings, with its Windows zone ID, sorted by
+/// zone name.
+pub static ZONES: &[(&str, &str)] = &[
+    ("Africa/Abidjan", "Greenwich Standard Time"),
+    ("Africa/Accra", "Greenwich Standard Time"),
+    ("Africa/Addis_Ababa", "E. Africa Standard Time"),
+    ("Africa/Algiers", "W. Central Africa Standard Time"),
+    ("Africa/Asmera", "E. Africa Standard Time"),
+    ("Africa/Bamako", "Greenwich Standard Time"),
+    ("Africa/Bangui", "W. Central Africa Standard Time"),
+    ("Africa/Banjul", "Greenwich Standard Time"),
+    ("Africa/Bissau", "Greenwich Standard Time"),
+    ("Africa/Blantyre", "South Africa Standard Time"),
+    ("Africa/Brazzaville", "W. Central Africa Standard Time"),
+    ("Africa/Bujumbura", "South Africa Standard Time"),
+    ("Africa/Cairo", "Egypt Standard Time"),
+    ("Africa/Casablanca", "Morocco Standard Time"),
+    ("Africa/Ceuta", "Romance Standard Time"),
+    ("Africa/Conakry", "Greenwich Standard Time"),
+    ("Africa/Dakar", "Greenwich Standard Time"),
+    ("Africa/Dar_es_Salaam", "E. Africa Standard Time"),
+    ("Africa/Djibouti", "E. Africa Standard Time"),
+    ("Africa/Douala", "W. Central Africa Standard Time"),
+    ("Africa/El_Aaiun", "Morocco Standard Time"),
+    ("Africa/Freetown", "Greenwich Standard Time"),
+    ("Africa/Gaborone", "South Africa Standard Time"),
+    ("Africa/Harare", "South Africa Standard Time"),
+    ("Africa/Johannesburg", "South Africa Standard Time"),
+    ("Africa/Juba", "South Sudan Standard Time"),
+    ("Africa/Kampala", "E. Africa Standard Time"),
+    ("Africa/Khartoum", "Sudan Standard Time"),
+    ("Africa/Kigali", "South Africa Standard Time"),
+    ("Africa/Kinshasa", "W. Central Africa Standard Time"),
+    ("Africa/Lagos", "W. Central Africa Standard Time"),
+    ("Africa/Libreville", "W. Central Africa Standard Time"),
+    ("Africa/Lome", "Greenwich Standard Time"),
+    ("Africa/Luanda", "W. Central Africa Standard Time"),
+    ("Africa/Lubumbashi", "South Africa Standard Time"),
+    ("Africa/Lusaka", "South Africa Standard Time"),
+    ("Africa/Malabo", "W. Central Africa Standard Time"),
+    ("Africa/Maputo", "South Africa Standard Time"),
+    ("Africa/Maseru", "South Africa Standard Time"),
+    ("Africa/Mbabane", "South Africa Standard Time"),
+    ("Africa/Mogadishu", "E. Africa Standard Time"),
+    ("Africa/Monrovia", "Greenwich Standard Time"),
+    ("Africa/Nairobi", "E. Africa Standard Time"),
+    ("Africa/Ndjamena", "W. Central Africa Standard Time"),
+    ("Africa/Niamey", "W. Central Africa Standard Time"),
+    ("Africa/Nouakchott", "Greenwich Standard Time"),
+    ("Africa/Ouagadougou", "Greenwich Standard Time"),
+    ("Africa/Porto-Novo", "W. Central Africa Standard Time"),
+    ("Africa/Sao_Tome", "Sao Tome Standard Time"),
+    ("Africa/Tripoli", "Libya Standard Time"),
+    ("Africa/Tunis", "W. Central Africa Standard Time"),
+    ("Africa/Windhoek", "Namibia Standard Time"),
+    ("America/Adak", "Aleutian Standard Time"),
+    ("America/Anchorage", "Alaskan Standard Time"),
+    ("America/Anguilla", "SA Western Standard Time"),
+    ("America/Antigua", "SA Western Standard Time"),
+    ("America/Araguaina", "Tocantins Standard Time"),
+    ("America/Argentina/La_Rioja", "Argentina Standard Time"),
+    ("America/Argentina/Rio_Gallegos", "Argentina Standard Time"),
+    ("America/Argentina/Salta", "Argentina Standard Time"),
+    ("America/Argentina/San_Juan", "Argentina Standard Time"),
+    ("America/Argentina/San_Luis", "Argentina Standard Time"),
+    ("America/Argentina/Tucuman", "Argentina Standard Time"),
+    ("America/Argentina/Ushuaia", "Argentina Standard Time"),
+    ("America/Aruba", "SA Western Standard Time"),
+    ("America/Asuncion", "Paraguay Standard Time"),
+    ("America/Bahia", "Bahia Standard Time"),
+    ("America/Bahia_Banderas", "Central Standard Time (Mexico)"),
+    ("America/Barbados", "SA Western Standard Time"),
+    ("America/Belem", "SA Eastern Standard Time"),
+    ("America/Belize", "Central America Standard Time"),
+    ("America/Blanc-Sablon", "SA Western Standard Time"),
+    ("America/Boa_Vista", "SA Western Standard Time"),
+    ("America/Bogota", "SA Pacific Standard Time"),
+    ("America/Boise", "Mountain Standard Time"),
+    ("America/Buenos_Aires", "Argentina Standard Time"),
+    ("America/Cambridge_Bay", "Mountain Standard Time"),
+    ("America/Campo_Grande", "Central Brazilian Standard Time"),
+    ("America/Cancun", "Eastern Standard Time (Mexico)"),
+    ("America/Caracas", "Venezuela Standard Time"),
+    ("America/Catamarca", "Argentina Standard Time"),
+    ("America/Cayenne", "SA Eastern Standard Time"),
+    ("America/Cayman", "SA Pacific Standard Time"),
+    ("America/Chicago", "Central Standard Time"),
+    ("America/Chihuahua", "Central Standard Time (Mexico)"),
+    ("America/Ciudad_Juarez", "Mountain Standard Time"),
+    ("America/Coral_Harbour", "SA Pacific Standard Time"),
+    ("America/Cordoba", "Argentina Standard Time"),
+    ("America/Costa_Rica", "Central America Standard Time"),
+    ("America/Creston", "US Mountain Standard Time"),
+    ("America/Cuiaba", "Central Brazilian Standard Time"),
+    ("America/Curacao", "SA Western Standard Time"),
+    ("America/Danmarkshavn", "UTC"),
+    ("America/Dawson", "Yukon Standard Time"),
+    ("America/Dawson_Creek", "US Mountain Standard Time"),
+    ("America/Denver", "Mountain Standard Time"),
+    ("America/Detroit", "Eastern Standard Time"),
+    ("America/Dominica", "SA Western Standard Time"),
+    ("America/Edmonton", "Mountain Standard Time"),
+    ("America/Eirunepe", "SA Pacific Standard Time"),
+    ("America/El_Salvador", "Central America Standard Time"),
+    ("America/Fort_Nelson", "US Mountain Standard Time"),
+    ("America/Fortaleza", "SA Eastern Standard Time"),
+    ("America/Glace_Bay", "Atlantic Standard Time"),
+    ("America/Godthab", "Greenland Standard Time"),
+    ("America/Goose_Bay", "Atlantic Standard Time"),
+    ("America/Grand_Turk", "Turks And Caicos Standard Time"),
+    ("America/Grenada", "SA Western Standard Time"),
+    ("America/Guadeloupe", "SA Western Standard Time"),
+    ("America/Guatemala", "Central America Standard Time"),
+    ("America/Guayaquil", "SA Pacific Standard Time"),
+    ("America/Guyana", "SA Western Standard Time"),
+    ("America/Halifax", "Atlantic Standard Time"),
+    ("America/Havana", "Cuba Standard Time"),
+    ("America/Hermosillo", "US Mountain Standard Time"),
+    ("America/Indiana/Knox", "Central Standard Time"),
+    ("America/Indiana/Marengo", "US Eastern Standard Time"),
+    ("America/Indiana/Petersburg", "Eastern Standard Time"),
+    ("America/Indiana/Tell_City", "Central Standard Time"),
+    ("America/Indiana/Vevay", "US Eastern Standard Time"),
+    ("America/Indiana/Vincennes", "Eastern Standard Time"),
+    ("America/Indiana/Winamac", "Eastern Standard Time"),
+    ("America/Indianapolis", "US Eastern Standard Time"),
+    ("America/Inuvik", "Mountain Standard Time"),
+    ("America/Iqaluit", "Eastern Standard Time"),
+    ("America/Jamaica", "SA Pacific Standard Time"),
+    ("America/Jujuy", "Argentina Standard Time"),
+    ("America/Juneau", "Alaskan Standard Time"),
+    ("America/Kentucky/Monticello", "Eastern Standard Time"),
+    ("America/Kralendijk", "SA Western Standard Time"),
+    ("America/La_Paz", "SA Western Standard Time"),
+    ("America/Lima", "SA Pacific Standard Time"),
+    ("America/Los_Angeles", "Pacific Standard Time"),
+    ("America/Louisville", "Eastern Standard Time"),
+    ("America/Lower_Princes", "SA Western Standard Time"),
+    ("America/Maceio", "SA Eastern Standard Time"),
+    ("America/Managua", "Central America Standard Time"),
+    ("America/Manaus", "SA Western Standard Time"),
+    ("America/Marigot", "SA Western Standard Time"),
+    ("America/Martinique", "SA Western Standard Time"),
+    ("America/Matamoros", "Central Standard Time"),
+    ("America/Mazatlan", "Mountain Standard Time (Mexico)"),
+    ("America/Mendoza", "Argentina Standard Time"),
+    ("America/Menominee", "Central Standard Time"),
+    ("America/Merida", "Central Standard Time (Mexico)"),
+    ("America/Metlakatla", "Alaskan Standard Time"),
+    ("America/Mexico_City", "Central Standard Time (Mexico)"),
+    ("America/Miquelon", "Saint Pierre Standard Time"),
+    ("America/Moncton", "Atlantic Standard Time"),
+    ("America/Monterrey", "Central Standard Time (Mexico)"),
+    ("America/Montevideo", "Montevideo Standard Time"),
+    ("America/Montserrat", "SA Western Standard Time"),
+    ("America/Nassau", "Eastern Standard Time"),
+    ("America/New_York", "Eastern Standard Time"),
+    ("America/Nome", "Alaskan Standard Time"),
+    ("America/Noronha", "UTC-02"),
+    ("America/North_Dakota/Beulah", "Central Standard Time"),
+    ("America/North_Dakota/Center", "Central Standard Time"),
+    ("America/North_Dakota/New_Salem", "Central Standard Time"),
+    ("America/Ojinaga", "Central Standard Time"),
+    ("America/Panama", "SA Pacific Standard Time"),
+    ("America/Paramaribo", "SA Eastern Standard Time"),
+    ("America/Phoenix", "US Mountain Standard Time"),
+    ("America/Port-au-Prince", "Haiti Standard Time"),
+    ("America/Port_of_Spain", "SA Western Standard Time"),
+    ("America/Porto_Velho", "SA Western Standard Time"),
+    ("America/Puerto_Rico", "SA Western Standard Time"),
+    ("America/Punta_Arenas", "Magallanes Standard Time"),
+    ("America/Rankin_Inlet", "Central Standard Time"),
+    ("America/Recife", "SA Eastern Standard Time"),
+    ("America/Regina", "Canada Central Standard Time"),
+    ("America/Resolute", "Central Standard Time"),
+    ("America/Rio_Branco", "SA Pacific Standard Time"),
+    ("America/Santa_Isabel", "Pacific Standard Time (Mexico)"),
+    ("America/Santarem", "SA Eastern Standard Time"),
+    ("America/Santiago", "Pacific SA Standard Time"),
+    ("America/Santo_Domingo", "SA Western Standard Time"),
+    ("America/Sao_Paulo", "E. South America Standard Time"),
+    ("America/Scoresbysund", "Azores Standard Time"),
+    ("America/Sitka", "Alaskan Standard Time"),
+    ("America/St_Barthelemy", "SA Western Standard Time"),
+    ("America/St_Johns", "Newfoundland Standard Time"),
+    ("America/St_Kitts", "SA Western Standard Time"),
+    ("America/St_Lucia", "SA Western Standard Time"),
+    ("America/St_Thomas", "SA Western Standard Time"),
+    ("America/St_Vincent", "SA Western Standard Time"),
+    ("America/Swift_Current", "Canada Central Standard Time"),
+    ("America/Tegucigalpa", "Central America Standard Time"),
+    ("America/Thule", "Atlantic Standard Time"),
+    ("America/Tijuana", "Pacific Standard Time (Mexico)"),
+    ("America/Toronto", "Eastern Standard Time"),
+    ("America/Tortola", "SA Western Standard Time"),
+    ("America/Vancouver", "Pacific Standard Time"),
+    ("America/Whitehorse", "Yukon Standard Time"),
+    ("America/Winnipeg", "Central Standard Time"),
+    ("America/Yakutat", "Alaskan Standard Time"),
+    ("Antarctica/Casey", "Central Pacific Standard Time"),
+    ("Antarctica/Davis", "SE Asia Standard Time"),
+    ("Antarctica/DumontDUrville", "West Pacific Standard Time"),
+    ("Antarctica/Macquarie", "Central Pacific Standard Time"),
+    ("Antarctica/Mawson", "West Asia Standard Time"),
+    ("Antarctica/McMurdo", "New Zealand Standard Time"),
+    ("Antarctica/Palmer", "SA Eastern Standard Time"),
+    ("Antarctica/Rothera", "SA Eastern Standard Time"),
+    ("Antarctica/Syowa", "E. Africa Standard Time"),
+    ("Antarctica/Vostok", "West Asia Standard Time"),
+    ("Arctic/Longyearbyen", "W. Europe Standard Time"),
+    ("Asia/Aden", "Arab Standard Time"),
+    ("Asia/Almaty", "Central Asia Standard Time"),
+    ("Asia/Amman", "Jordan Standard Time"),
+    ("Asia/Anadyr", "Russia Time Zone 11"),
+    ("Asia/Aqtau", "West Asia Standard Time"),
+    ("Asia/Aqtobe", "West Asia Standard Time"),
+    ("Asia/Ashgabat", "West Asia Standard Time"),
+    ("Asia/Atyrau", "West Asia Standard Time"),
+    ("Asia/Baghdad", "Arabic Standard Time"),
+    ("Asia/Bahrain", "Arab Standard Time"),
+    ("Asia/Baku", "Azerbaijan Standard Time"),
+    ("Asia/Bangkok", "SE Asia Standard Time"),
+    ("Asia/Barnaul", "Altai Standard Time"),
+    ("Asia/Beirut", "Middle East Standard Time"),
+    ("Asia/Bishkek", "Central Asia Standard Time"),
+    ("Asia/Brunei", "Singapore Standard Time"),
+    ("Asia/Calcutta", "India Standard Time"),
+    ("Asia/Chita", "Transbaikal Standard Time"),
+    ("Asia/Colombo", "Sri Lanka Standard Time"),
+    ("Asia/Damascus", "Syria Standard Time"),
+    ("Asia/Dhaka", "Bangladesh Standard Time"),
+    ("Asia/Dili", "Tokyo Standard Time"),
+    ("Asia/Dubai", "Arabian Standard Time"),
+    ("Asia/Dushanbe", "West Asia Standard Time"),
+    ("Asia/Famagusta", "GTB Standard Time"),
+    ("Asia/Gaza", "West Bank Standard Time"),
+    ("Asia/Hebron", "West Bank Standard Time"),
+    ("Asia/Hong_Kong", "China Standard Time"),
+    ("Asia/Hovd", "W. Mongolia Standard Time"),
+    ("Asia/Irkutsk", "North Asia East Standard Time"),
+    ("Asia/Jakarta", "SE Asia Standard Time"),
+    ("Asia/Jayapura", "Tokyo Standard Time"),
+    ("Asia/Jerusalem", "Israel Standard Time"),
+    ("Asia/Kabul", "Afghanistan Standard Time"),
+    ("Asia/Kamchatka", "Russia Time Zone 11"),
+    ("Asia/Karachi", "Pakistan Standard Time"),
+    ("Asia/Katmandu", "Nepal Standard Time"),
+    ("Asia/Khandyga", "Yakutsk Standard Time"),
+    ("Asia/Krasnoyarsk", "North Asia Standard Time"),
+    ("Asia/Kuala_Lumpur", "Singapore Standard Time"),
+    ("Asia/Kuching", "Singapore Standard Time"),
+    ("Asia/Kuwait", "Arab Standard Time"),
+    ("Asia/Macau", "China Standard Time"),
+    ("Asia/Magadan", "Magadan Standard Time"),
+    ("Asia/Makassar", "Singapore Standard Time"),
+    ("Asia/Manila", "Singapore Standard Time"),
+    ("Asia/Muscat", "Arabian Standard Time"),
+    ("Asia/Nicosia", "GTB Standard Time"),
+    ("Asia/Novokuznetsk", "North Asia Standard Time"),
+    ("Asia/Novosibirsk", "N. Central Asia Standard Time"),
+    ("Asia/Omsk", "Omsk Standard Time"),
+    ("Asia/Oral", "West Asia Standard Time"),
+    ("Asia/Phnom_Penh", "SE Asia Standard Time"),
+    ("Asia/Pontianak", "SE Asia Standard Time"),
+    ("Asia/Pyongyang", "North Korea Standard Time"),
+    ("Asia/Qatar", "Arab Standard Time"),
+    ("Asia/Qostanay", "Central Asia Standard Time"),
+    ("Asia/Qyzylorda", "Qyzylorda Standard Time"),
+    ("Asia/Rangoon", "Myanmar Standard Time"),
+    ("Asia/Riyadh", "Arab Standard Time"),
+    ("Asia/Saigon", "SE Asia Standard Time"),
+    ("Asia/Sakhalin", "Sakhalin Standard Time"),
+    ("Asia/Samarkand", "West Asia Standard Time"),
+    ("Asia/Seoul", "Korea Standard Time"),
+    ("Asia/Shanghai", "China Standard Time"),
+    ("Asia/Singapore", "Singapore Standard Time"),
+    ("Asia/Srednekolymsk", "Russia Time Zone 10"),
+    ("Asia/Taipei", "Taipei Standard Time"),
+    ("Asia/Tashkent", "West Asia Standard Time"),
+    ("Asia/Tbilisi", "Georgian Standard Time"),
+    ("Asia/Tehran", "Iran Standard Time"),
+    ("Asia/Thimphu", "Bangladesh Standard Time"),
+    ("Asia/Tokyo", "Tokyo Standard Time"),
+    ("Asia/Tomsk", "Tomsk Standard Time"),
+    ("Asia/Ulaanbaatar", "Ulaanbaatar Standard Time"),
+    ("Asia/Urumqi", "Central Asia Standard Time"),
+    ("Asia/Ust-Nera", "Vladivostok Standard Time"),
+    ("Asia/Vientiane", "SE Asia Standard Time"),
+    ("Asia/Vladivostok", "Vladivostok Standard Time"),
+    ("Asia/Yakutsk", "Yakutsk Standard Time"),
+    ("Asia/Yekaterinburg", "Ekaterinburg Standard Time"),
+    ("Asia/Yerevan", "Caucasus Standard Time"),
+    ("Atlantic/Azores", "Azores Standard Time"),
+    ("Atlantic/Bermuda", "Atlantic Standard Time"),
+    ("Atlantic/Canary", "GMT Standard Time"),
+    ("Atlantic/Cape_Verde", "Cape Verde Standard Time"),
+    ("Atlantic/Faeroe", "GMT Standard Time"),
+    ("Atlantic/Madeira", "GMT Standard Time"),
+    ("Atlantic/Reykjavik", "Greenwich Standard Time"),
+    ("Atlantic/South_Georgia", "UTC-02"),
+    ("Atlantic/St_Helena", "Greenwich Standard Time"),
+    ("Atlantic/Stanley", "SA Eastern Standard Time"),
+    ("Australia/Adelaide", "Cen. Australia Standard Time"),
+    ("Australia/Brisbane", "E. Australia Standard Time"),
+    ("Australia/Broken_Hill", "Cen. Australia Standard Time"),
+    ("Australia/Darwin", "AUS Central Standard Time"),
+    ("Australia/Eucla", "Aus Central W. Standard Time"),
+    ("Australia/Hobart", "Tasmania Standard Time"),
+    ("Australia/Lindeman", "E. Australia Standard Time"),
+    ("Australia/Lord_Howe", "Lord Howe Standard Time"),
+    ("Australia/Melbourne", "AUS Eastern Standard Time"),
+    ("Australia/Perth", "W. Australia Standard Time"),
+    ("Australia/Sydney", "AUS Eastern Standard Time"),
+    ("CST6CDT", "Central Standard Time"),
+    ("EST5EDT", "Eastern Standard Time"),
+    ("Etc/GMT", "UTC"),
+    ("Etc/GMT+1", "Cape Verde Standard Time"),
+    ("Etc/GMT+10", "Hawaiian Standard Time"),
+    ("Etc/GMT+11", "UTC-11"),
+    ("Etc/GMT+12", "Dateline Standard Time"),
+    ("Etc/GMT+2", "UTC-02"),
+    ("Etc/GMT+3", "SA Eastern Standard Time"),
+    ("Etc/GMT+4", "SA Western Standard Time"),
+    ("Etc/GMT+5", "SA Pacific Standard Time"),
+    ("Etc/GMT+6", "Central America Standard Time"),
+    ("Etc/GMT+7", "US Mountain Standard Time"),
+    ("Etc/GMT+8", "UTC-08"),
+    ("Etc/GMT+9", "UTC-09"),
+    ("Etc/GMT-1", "W. Central Africa Standard Time"),
+    ("Etc/GMT-10", "West Pacific Standard Time"),
+    ("Etc/GMT-11", "Central Pacific Standard Time"),
+    ("Etc/GMT-12", "UTC+12"),
+    ("Etc/GMT-13", "UTC+13"),
+    ("Etc/GMT-14", "Line Islands Standard Time"),
+    ("Etc/GMT-2", "South Africa Standard Time"),
+    ("Etc/GMT-3", "E. Africa Standard Time"),
+    ("Etc/GMT-4", "Arabian Standard Time"),
+    ("Etc/GMT-5", "West Asia Standard Time"),
+    ("Etc/GMT-6", "Central Asia Standard Time"),
+    ("Etc/GMT-7", "SE Asia Standard Time"),
+    ("Etc/GMT-8", "Singapore Standard Time"),
+    ("Etc/GMT-9", "Tokyo Standard Time"),
+    ("Etc/UTC", "UTC"),
+    ("Europe/Amsterdam", "W. Europe Standard Time"),
+    ("Europe/Andorra", "W. Europe Standard Time"),
+    ("Europe/Astrakhan", "Astrakhan Standard Time"),
+    ("Europe/Athens", "GTB Standard Time"),
+    ("Europe/Belgrade", "Central Europe Standard Time"),
+    ("Europe/Berlin", "W. Europe Standard Time"),
+    ("Europe/Bratislava", "Central Europe Standard Time"),
+    ("Europe/Brussels", "Romance Standard Time"),
+    ("Europe/Bucharest", "GTB Standard Time"),
+    ("Europe/Budapest", "Central Europe Standard Time"),
+    ("Europe/Busingen", "W. Europe Standard Time"),
+    ("Europe/Chisinau", "E. Europe Standard Time"),
+    ("Europe/Copenhagen", "Romance Standard Time"),
+    ("Europe/Dublin", "GMT Standard Time"),
+    ("Europe/Gibraltar", "W. Europe Standard Time"),
+    ("Europe/Guernsey", "GMT Standard Time"),
+    ("Europe/Helsinki", "FLE Standard Time"),
+    ("Europe/Isle_of_Man", "GMT Standard Time"),
+    ("Europe/Istanbul", "Turkey Standard Time"),
+    ("Europe/Jersey", "GMT Standard Time"),
+    ("Europe/Kaliningrad", "Kaliningrad Standard Time"),
+    ("Europe/Kiev", "FLE Standard Time"),
+    ("Europe/Kirov", "Russian Standard Time"),
+    ("Europe/Lisbon", "GMT Standard Time"),
+    ("Europe/Ljubljana", "Central Europe Standard Time"),
+    ("Europe/London", "GMT Standard Time"),
+    ("Europe/Luxembourg", "W. Europe Standard Time"),
+    ("Europe/Madrid", "Romance Standard Time"),
+    ("Europe/Malta", "W. Europe Standard Time"),
+    ("Europe/Mariehamn", "FLE Standard Time"),
+    ("Europe/Minsk", "Belarus Standard Time"),
+    ("Europe/Monaco", "W. Europe Standard Time"),
+    ("Europe/Moscow", "Russian Standard Time"),
+    ("Europe/Oslo", "W. Europe Standard Time"),
+    ("Europe/Paris", "Romance Standard Time"),
+    ("Europe/Podgorica", "Central Europe Standard Time"),
+    ("Europe/Prague", "Central Europe Standard Time"),
+    ("Europe/Riga", "FLE Standard Time"),
+    ("Europe/Rome", "W. Europe Standard Time"),
+    ("Europe/Samara", "Russia Time Zone 3"),
+    ("Europe/San_Marino", "W. Europe Standard Time"),
+    ("Europe/Sarajevo", "Central European Standard Time"),
+    ("Europe/Saratov", "Saratov Standard Time"),
+    ("Europe/Simferopol", "Russian Standard Time"),
+    ("Europe/Skopje", "Central European Standard Time"),
+    ("Europe/Sofia", "FLE Standard Time"),
+    ("Europe/Stockholm", "W. Europe Standard Time"),
+    ("Europe/Tallinn", "FLE Standard Time"),
+    ("Europe/Tirane", "Central Europe Standard Time"),
+    ("Europe/Ulyanovsk", "Astrakhan Standard Time"),
+    ("Europe/Vaduz", "W. Europe Standard Time"),
+    ("Europe/Vatican", "W. Europe Standard Time"),
+    ("Europe/Vienna", "W. Europe Standard Time"),
+    ("Europe/Vilnius", "FLE Standard Time"),
+    ("Europe/Volgograd", "Volgograd Standard Time"),
+    ("Europe/Warsaw", "Central European Standard Time"),
+    ("Europe/Zagreb", "Central European Standard Time"),
+    ("Europe/Zurich", "W. Europe Standard Time"),
+    ("Indian/Antananarivo", "E. Africa Standard Time"),
+    ("Indian/Chagos", "Central Asia Standard Time"),
+    ("Indian/Christmas", "SE Asia Standard Time"),
+    ("Indian/Cocos", "Myanmar Standard Time"),
+    ("Indian/Comoro", "E. Africa Standard Time"),
+    ("Indian/Kerguelen", "West Asia Standard Time"),
+    ("Indian/Mahe", "Mauritius Standard Time"),
+    ("Indian/Maldives", "West Asia Standard Time"),
+    ("Indian/Mauritius", "Mauritius Standard Time"),
+    ("Indian/Mayotte", "E. Africa Standard Time"),
+    ("Indian/Reunion", "Mauritius Standard Time"),
+    ("MST7MDT", "Mountain Standard Time"),
+    ("PST8PDT", "Pacific Standard Time"),
+    ("Pacific/Apia", "Samoa Standard Time"),
+    ("Pacific/Auckland", "New Zealand Standard Time"),
+    ("Pacific/Bougainville", "Bougainville Standard Time"),
+    ("Pacific/Chatham", "Chatham Islands Standard Time"),
+    ("Pacific/Easter", "Easter Island Standard Time"),
+    ("Pacific/Efate", "Central Pacific Standard Time"),
+    ("Pacific/Enderbury", "UTC+13"),
+    ("Pacific/Fakaofo", "UTC+13"),
+    ("Pacific/Fiji", "Fiji Standard Time"),
+    ("Pacific/Funafuti", "UTC+12"),
+    ("Pacific/Galapagos", "Central America Standard Time"),
+    ("Pacific/Gambier", "UTC-09"),
+    ("Pacific/Guadalcanal", "Central Pacific Standard Time"),
+    ("Pacific/Guam", "West Pacific Standard Time"),
+    ("Pacific/Honolulu", "Hawaiian Standard Time"),
+    ("Pacific/Kiritimati", "Line Islands Standard Time"),
+    ("Pacific/Kosrae", "Central Pacific Standard Time"),
+    ("Pacific/Kwajalein", "UTC+12"),
+    ("Pacific/Majuro", "UTC+12"),
+    ("Pacific/Marquesas", "Marquesas Standard Time"),
+    ("Pacific/Midway", "UTC-11"),
+    ("Pacific/Nauru", "UTC+12"),
+    ("Pacific/Niue", "UTC-11"),
+    ("Pacific/Norfolk", "Norfolk Standard Time"),
+    ("Pacific/Noumea", "Central Pacific Standard Time"),
+    ("Pacific/Pago_Pago", "UTC-11"),
+    ("Pacific/Palau", "Tokyo Standard Time"),
+    ("Pacific/Pitcairn", "UTC-08"),
+    ("Pacific/Ponape", "Central Pacific Standard Time"),
+    ("Pacific/Port_Moresby", "West Pacific Standard Time"),
+    ("Pacific/Rarotonga", "Hawaiian Standard Time"),
+    ("Pacific/Saipan", "West Pacific Standard Time"),
+    ("Pacific/Tahiti", "Hawaiian Standard Time"),
+    ("Pacific/Tarawa", "UTC+12"),
+    ("Pacific/Tongatapu", "Tonga Standard Time"),
+    ("Pacific/Truk", "West Pacific Standard Time"),
+    ("Pacific/Wake", "UTC+12"),
+    ("Pacific/Wallis", "UTC+12"),
+];
@@ -0,0 +1,137 @@
+//! Converting between Windows time zone IDs and zoneinfo names.
+//!
+//! Windows has its own set of time zone IDs, such as “W. Europe Standard
+//! Time”, which turn up in data from Outlook, Exchange, and the Windows
+//! registry. The Unicode CLDR project maintains a mapping between these and
+//! the zoneinfo database’s names, which is bundled here.
+//!
+//! One Windows ID covers several zoneinfo zones that share the same rules,
+//! so converting to a zone name uses a territory, such as `DE` or `CH`,
+//! when one is known, and the ID’s main zone otherwise.
+//!
+//! CLDR uses some older names, such as “Asia/Calcutta”, where the zoneinfo
+//! database has since renamed the zone. The functions here return the
+//! current names, which can be passed straight to `TimeZone::named`, and
+//! accept either name.
+
+mod data;
+use self::data::{MAPPINGS, ZONES};
+
+
+/// The territory used in the CLDR data for the zone to use when the
+/// territory isn’t known.
+const WORLD: &str = "001";
+
+/// The older names that CLDR still uses, with the current zoneinfo name
+/// for each, sorted by the older name.
+static RENAMED: &[(&str, &str)] = &[
+    ("Africa/Asmera",         "Africa/Asmara"),
+    ("America/Buenos_Aires",  "America/Argentina/Buenos_Aires"),
+    ("America/Catamarca",     "America/Argentina/Catamarca"),
+    ("America/Coral_Harbour", "America/Atikokan"),
+    ("America/Cordoba",       "America/Argentina/Cordoba"),
+    ("America/Godthab",       "America/Nuuk"),
+    ("America/Indianapolis",  "America/Indiana/Indianapolis"),
+    ("America/Jujuy",         "America/Argentina/Jujuy"),
+    ("America/Louisville",    "America/Kentucky/Louisville"),
+    ("America/Mendoza",       "America/Argentina/Mendoza"),
+    ("Asia/Calcutta",         "Asia/Kolkata"),
+    ("Asia/Katmandu",         "Asia/Kathmandu"),
+    ("Asia/Rangoon",          "Asia/Yangon"),
+    ("Asia/Saigon",           "Asia/Ho_Chi_Minh"),
+    ("Atlantic/Faeroe",       "Atlantic/Faroe"),
+    ("Europe/Kiev",           "Europe/Kyiv"),
+    ("Pacific/Enderbury",     "Pacific/Kanton"),
+    ("Pacific/Ponape",        "Pacific/Pohnpei"),
+    ("Pacific/Truk",          "Pacific/Chuuk"),
+];
+
+
+/// Returns the zoneinfo name for a Windows time zone ID, such as
+/// “Europe/Zurich” for “W. Europe Standard Time” in the territory `CH`.
+///
+/// If the territory isn’t given, or the ID isn’t used there, the ID’s main
+/// zone is returned, such as “Europe/Berlin” for “W. Europe Standard Time”.
+/// Returns `None` if the ID isn’t known.
+pub fn to_zone_name(windows_id: &str, territory: Option<&str>) -> Option<&'static str> {
+    let zones = territory.and_then(|t| mapping(windows_id, t))
+                         .or_else(|| mapping(windows_id, WORLD))?;
+
+    zones.first().map(|&zone| current_name(zone))
+}
+
+/// Returns every zoneinfo name that a Windows time zone ID covers in the
+/// given territory, with the main one first, or an empty list if the ID
+/// isn’t used there. The territory `ZZ` holds zones that aren’t in any
+/// country, such as “Etc/GMT+5”.
+pub fn zone_names(windows_id: &str, territory: &str) -> Vec<&'static str> {
+    mapping(windows_id, territory).unwrap_or(&[])
+                                  .iter()
+                                  .map(|&zone| current_name(zone))
+                                  .collect()
+}
+
+/// Returns the Windows time zone ID for a zoneinfo name, such as
+/// “Tokyo Standard Time” for “Asia/Tokyo”, or `None` if the zone has no
+/// Windows equivalent.
+pub fn from_zone_name(zone_name: &str) -> Option<&'static str> {
+    let name = RENAMED.iter().find(|r| r.1 == zone_name).map_or(zone_name, |r| r.0);
+    ZONES.binary_search_by_key(&name, |z| z.0).ok().map(|index| ZONES[index].1)
+}
+
+/// Returns every Windows time zone ID in the mapping, in alphabetical
+/// order.
+pub fn windows_ids() -> Vec<&'static str> {
+    let mut ids: Vec<&'static str> = MAPPINGS.iter().map(|m| m.0).collect();
+    ids.dedup();
+    ids
+}
+
+fn mapping(windows_id: &str, territory: &str) -> Option<&'static [&'static str]> {
+    MAPPINGS.binary_search_by(|m| (m.0, m.1).cmp(&(windows_id, territory)))
+            .ok()
+            .map(|index| MAPPINGS[index].2)
+}
+
+fn current_name(zone: &'static str) -> &'static str {
+    match RENAMED.binary_search_by_key(&zone, |r| r.0) {
+        Ok(index)  => RENAMED[index].1,
+        Err(_)     => zone,
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn tables_are_sorted() {
+        assert!(MAPPINGS.windows(2).all(|w| (w[0].0, w[0].1) < (w[1].0, w[1].1)));
+        assert!(ZONES.windows(2).all(|w| w[0].0 < w[1].0));
+        assert!(RENAMED.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn every_id_has_a_main_zone() {
+        for id in windows_ids() {
+            assert!(mapping(id, WORLD).map_or(false, |zones| zones.len() == 1), "{}", id);
+        }
+    }
+
+    #[test]
+    fn every_zone_maps_back() {
+        for &(id, _, zones) in MAPPINGS {
+            for &zone in zones {
+                assert_eq!(from_zone_name(zone), Some(id), "{}", zone);
+                assert_eq!(from_zone_name(current_name(zone)), Some(id), "{}", zone);
+            }
+        }
+    }
+
+    #[test]
+    fn renamed() {
+        assert_eq!(current_name("Asia/Calcutta"), "Asia/Kolkata");
+        assert_eq!(current_name("Asia/Tokyo"), "Asia/Tokyo");
+    }
+}
@@ -0,0 +1,363 @@
+//! Writing and reading durations in a compact, unit-suffixed syntax.
+//!
+//! A duration is written as a list of whole numbers of units, largest
+//! first, with the seconds holding any fraction, such as `1h 23m 4.5s`.
+//! Negative durations have a `-` before the first unit, which applies to
+//! all of them.
+//!
+//! When reading, the units can be any of `w`, `d`, `h`, `m`, `s`, `ms`,
+//! `us` (or `µs`), and `ns`, in any order, with or without spaces between
+//! them, and each number can have a fraction: `90s`, `1h30m`, `2d`, and
+//! `1.5h` are all valid. Days are always 86,400 seconds, and weeks seven
+//! of those days.
+
+use std::error::Error as ErrorTrait;
+use std::fmt;
+use std::str::FromStr;
+
+use duration::{Duration, NANOS_PER_SECOND};
+
+
+/// The units a duration can be written in, from largest to smallest.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+pub enum DurationUnit {
+    Weeks,
+    Days,
+    Hours,
+    Minutes,
+    Seconds,
+}
+
+impl DurationUnit {
+    fn nanoseconds(self) -> u128 {
+        let seconds = match self {
+            DurationUnit::Weeks    => 7 * 24 * 60 * 60,
+            DurationUnit::Days     => 24 * 60 * 60,
+            DurationUnit::Hours    => 60 * 60,
+            DurationUnit::Minutes  => 60,
+            DurationUnit::Seconds  => 1,
+        };
+
+        seconds * NANOS_PER_SECOND as u128
+    }
+
+    fn suffix(self) -> &'static str {
+        match self {
+            DurationUnit::Weeks    => "w",
+            DurationUnit::Days     => "d",
+            DurationUnit::Hours    => "h",
+            DurationUnit::Minutes  => "m",
+            DurationUnit::Seconds  => "s",
+        }
+    }
+}
+
+const UNITS: [DurationUnit; 5] = [
+    DurationUnit::Weeks, DurationUnit::Days, DurationUnit::Hours,
+    DurationUnit::Minutes, DurationUnit::Seconds,
+];
+
+
+/// A way of writing durations, such as `1d 2h 3m 4.5s`.
+///
+/// The default, which is what `Duration`’s `Display` implementation uses,
+/// starts from days, writes every unit down to seconds, and gives the
+/// seconds as many fractional digits as they need.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct DurationFormat {
+    largest_unit: DurationUnit,
+    max_components: Option<usize>,
+    fraction_digits: usize,
+}
+
+impl Default for DurationFormat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DurationFormat {
+
+    /// Creates the default format.
+    pub fn new() -> Self {
+        Self { largest_unit: DurationUnit::Days, max_components: None, fraction_digits: 9 }
+    }
+
+    /// Sets the largest unit to write. Anything longer is written as a
+    /// number of this unit, so with minutes as the largest unit, three
+    /// hours are written as `180m`.
+    pub fn largest_unit(self, unit: DurationUnit) -> Self {
+        Self { largest_unit: unit, ..self }
+    }
+
+    /// Limits the output to the given number of units, starting with the
+    /// largest one that isn’t zero, and rounding off the rest. With two
+    /// components, `1d 2h 31m` is written as `1d 3h`. Units that end up as
+    /// zero are left out, but still count towards the limit. A limit of
+    /// zero is treated as one.
+    pub fn max_components(self, count: usize) -> Self {
+        Self { max_components: Some(count.max(1)), ..self }
+    }
+
+    /// Rounds the seconds to the given number of fractional digits, up to
+    /// nine. Trailing zeroes are never written, so zero means whole
+    /// seconds only.
+    pub fn fraction_digits(self, digits: usize) -> Self {
+        Self { fraction_digits: digits.min(9), ..self }
+    }
+
+    /// Writes the given duration to a new string.
+    pub fn format(&self, duration: &Duration) -> String {
+        let mut buf = String::new();
+        let _ = self.write(duration, &mut buf);
+        buf
+    }
+
+    fn write(&self, duration: &Duration, w: &mut dyn fmt::Write) -> fmt::Result {
+        let nanoseconds = duration.total_nanoseconds();
+        let units = &UNITS[self.largest_unit as usize ..];
+
+        let mut length = round(nanoseconds.unsigned_abs(), 10_u128.pow(9 - self.fraction_digits as u32));
+        let mut last = units.len() - 1;
+
+        // Rounding off the smaller units can carry into a larger one, which
+        // moves which units are written, so this repeats until it settles.
+        if let Some(count) = self.max_components {
+            loop {
+                let first = units.iter().position(|u| length >= u.nanoseconds()).unwrap_or(units.len() - 1);
+                last = (first + count - 1).min(units.len() - 1);
+
+                let rounded = round(length, units[last].nanoseconds());
+                if rounded == length { break }
+                length = rounded;
+            }
+        }
+
+        if length == 0 {
+            return w.write_str("0s");
+        }
+
+        if nanoseconds < 0 {
+            w.write_char('-')?;
+        }
+
+        let mut separator = "";
+        for &unit in &units[.. last + 1] {
+            let (amount, rest) = (length / unit.nanoseconds(), length % unit.nanoseconds());
+            length = rest;
+
+            if unit == DurationUnit::Seconds && rest != 0 {
+                let fraction = format!("{:09}", rest);
+                write!(w, "{}{}.{}s", separator, amount, fraction.trim_end_matches('0'))?;
+            }
+            else if amount != 0 {
+                write!(w, "{}{}{}", separator, amount, unit.suffix())?;
+            }
+            else {
+                continue;
+            }
+
+            separator = " ";
+        }
+
+        Ok(())
+    }
+}
+
+/// Rounds a length to the nearest multiple of a unit, with halves rounded
+/// up.
+fn round(length: u128, unit: u128) -> u128 {
+    (length + unit / 2) / unit * unit
+}
+
+impl fmt::Display for Duration {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        DurationFormat::new().write(self, f)
+    }
+}
+
+
+/// An error that occurs when reading a duration, with the byte position
+/// in the input where it went wrong.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum ParseDurationError {
+
+    /// The input had no units in it.
+    Empty,
+
+    /// There was something other than a number where one was expected.
+    ExpectedNumber { pos: usize },
+
+    /// A number had no unit after it.
+    MissingUnit { pos: usize },
+
+    /// A number had a unit after it that isn’t one of the known ones.
+    UnknownUnit { pos: usize },
+
+    /// The duration is too long to fit in a `Duration`. The position is
+    /// that of the number that made it too long.
+    Overflow { pos: usize },
+}
+
+impl ParseDurationError {
+
+    /// Returns the byte position in the input where the error is.
+    pub fn position(&self) -> usize {
+        match *self {
+            ParseDurationError::Empty                       => 0,
+            ParseDurationError::ExpectedNumber { pos }
+            | ParseDurationError::MissingUnit { pos }
+            | ParseDurationError::UnknownUnit { pos }
+            | ParseDurationError::Overflow { pos }          => pos,
+        }
+    }
+}
+
+impl fmt::Display for ParseDurationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParseDurationError::Empty                     => write!(f, "empty duration"),
+            ParseDurationError::ExpectedNumber { pos }    => write!(f, "expected a number at position {}", pos),
+            ParseDurationError::MissingUnit { pos }       => write!(f, "missing unit at position {}", pos),
+            ParseDurationError::UnknownUnit { pos }       => write!(f, "unknown unit at position {}", pos),
+            ParseDurationError::Overflow { pos }          => write!(f, "duration too long at position {}", pos),
+        }
+    }
+}
+
+impl ErrorTrait for ParseDurationError {}
+
+impl FromStr for Duration {
+    type Err = ParseDurationError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser { input, pos: 0 };
+
+        let negative = parser.eat(|c| c == '-');
+        let signed = negative || parser.eat(|c| c == '+');
+
+        parser.skip_whitespace();
+        if parser.is_at_end() {
+            return Err(if signed { ParseDurationError::ExpectedNumber { pos: parser.pos } }
+                            else { ParseDurationError::Empty });
+        }
+
+        let mut total: i128 = 0;
+        while !parser.is_at_end() {
+            let start = parser.pos;
+            let length = parser.component()?;
+
+            total = total.checked_add(length).ok_or(ParseDurationError::Overflow { pos: start })?;
+            let signed = if negative { -total } else { total };
+            if Self::from_nanoseconds(signed).is_none() {
+                return Err(ParseDurationError::Overflow { pos: start });
+            }
+
+            parser.skip_whitespace();
+        }
+
+        Ok(Self::from_nanoseconds(if negative { -total } else { total }).unwrap())
+    }
+}
+
+/// The state of reading a duration: the input, and how far through it the
+/// reading has got, in bytes.
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+
+    /// Reads one number and its unit, returning its length in nanoseconds.
+    fn component(&mut self) -> Result<i128, ParseDurationError> {
+        let start = self.pos;
+        let whole = self.take_while(|c| c.is_ascii_digit());
+        let fraction = if self.eat(|c| c == '.') { self.take_while(|c| c.is_ascii_digit()) }
+                                              else { "" };
+
+        if whole.is_empty() && fraction.is_empty() {
+            return Err(ParseDurationError::ExpectedNumber { pos: start });
+        }
+
+        let unit_pos = self.pos;
+        let unit = match self.take_while(|c| c.is_alphabetic()) {
+            ""          => return Err(ParseDurationError::MissingUnit { pos: unit_pos }),
+            "w"         => DurationUnit::Weeks.nanoseconds(),
+            "d"         => DurationUnit::Days.nanoseconds(),
+            "h"         => DurationUnit::Hours.nanoseconds(),
+            "m"         => DurationUnit::Minutes.nanoseconds(),
+            "s"         => DurationUnit::Seconds.nanoseconds(),
+            "ms"        => 1_000_000,
+            "us" | "µs" => 1_000,
+            "ns"        => 1,
+            _           => return Err(ParseDurationError::UnknownUnit { pos: unit_pos }),
+        } as i128;
+
+        let overflow = ParseDurationError::Overflow { pos: start };
+        let mut length: i128 = 0;
+        for digit in whole.bytes() {
+            length = length.checked_mul(10).and_then(|l| l.checked_add(i128::from(digit - b'0'))).ok_or(overflow)?;
+        }
+        length = length.checked_mul(unit).ok_or(overflow)?;
+
+        // Digits past the eighteenth can’t add a whole nanosecond, even to
+        // a number of weeks, so they’re ignored.
+        let fraction = &fraction[.. fraction.len().min(18)];
+        if !fraction.is_empty() {
+            let digits: i128 = fraction.parse().unwrap();
+            length = length.checked_add(digits * unit / 10_i128.pow(fraction.len() as u32)).ok_or(overflow)?;
+        }
+
+        Ok(length)
+    }
+
+    fn take_while<F: Fn(char) -> bool>(&mut self, predicate: F) -> &'a str {
+        let rest = &self.input[self.pos ..];
+        let end = rest.find(|c| !predicate(c)).unwrap_or(rest.len());
+        self.pos += end;
+        &rest[.. end]
+    }
+
+    fn eat<F: Fn(char) -> bool>(&mut self, predicate: F) -> bool {
+        match self.input[self.pos ..].chars().next() {
+            Some(c) if predicate(c) => { self.pos += c.len_utf8(); true },
+            _                       => false,
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        let _ = self.take_while(char::is_whitespace);
+    }
+
+    fn is_at_end(&self) -> bool {
+        self.pos == self.input.len()
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn unit_order() {
+        assert_eq!(UNITS.iter().map(|u| *u as usize).collect::<Vec<_>>(), vec![ 0, 1, 2, 3, 4 ]);
+    }
+
+    #[test]
+    fn rounding() {
+        assert_eq!(round(1_499, 1_000), 1_000);
+        assert_eq!(round(1_500, 1_000), 2_000);
+    }
+
+    #[test]
+    fn positions() {
+        assert_eq!("".parse::<Duration>(), Err(ParseDurationError::Empty));
+        assert_eq!("-".parse::<Duration>(), Err(ParseDurationError::ExpectedNumber { pos: 1 }));
+        assert_eq!("1h x".parse::<Duration>(), Err(ParseDurationError::ExpectedNumber { pos: 3 }));
+        assert_eq!("1h 30".parse::<Duration>(), Err(ParseDurationError::MissingUnit { pos: 5 }));
+        assert_eq!("1h 30y".parse::<Duration>(), Err(ParseDurationError::UnknownUnit { pos: 5 }));
+        assert_eq!("1h 1.5.s".parse::<Duration>(), Err(ParseDurationError::MissingUnit { pos: 6 }));
+    }
+}
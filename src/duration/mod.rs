@@ -0,0 +1,402 @@
+//! Lengths of time on the timeline.
+
+use std::convert::TryFrom;
+use std::iter::Sum;
+use std::ops::{Add, Sub, Mul, Div, Neg};
+
+mod fmt;
+pub use self::fmt::{DurationFormat, DurationUnit, ParseDurationError};
+
+
+/// The number of nanoseconds in a second.
+const NANOS_PER_SECOND: i64 = 1_000_000_000;
+
+/// The number of nanoseconds in a millisecond.
+const NANOS_PER_MILLISECOND: i64 = 1_000_000;
+
+
+/// A **duration** is a length of time on the timeline, irrespective of
+/// time zone or calendar format, with nanosecond precision.
+///
+/// Durations are ordered by their length, with negative durations coming
+/// before zero.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Copy)]
+pub struct Duration {
+    seconds: i64,
+    nanoseconds: i32,
+}
+
+impl Duration {
+
+    /// The shortest possible duration, which is negative.
+    pub const MIN: Self = Self { seconds: i64::MIN, nanoseconds: 0 };
+
+    /// The longest possible duration.
+    pub const MAX: Self = Self { seconds: i64::MAX, nanoseconds: 999_999_999 };
+
+    /// Create a new zero-length duration.
+    pub fn zero() -> Self {
+        Self { seconds: 0, nanoseconds: 0 }
+    }
+
+    /// Create a new duration that’s the given number of seconds long.
+    pub fn of(seconds: i64) -> Self {
+        Self { seconds, nanoseconds: 0 }
+    }
+
+    /// Create a new duration that’s the given number of seconds and
+    /// milliseconds long. Milliseconds outside the range 0 to 999 are
+    /// carried into the seconds, and a duration that would be out of range
+    /// becomes `MIN` or `MAX`.
+    pub fn of_ms(seconds: i64, milliseconds: i16) -> Self {
+        Self::saturating_from_nanoseconds(i128::from(seconds) * i128::from(NANOS_PER_SECOND) + i128::from(milliseconds) * i128::from(NANOS_PER_MILLISECOND))
+    }
+
+    /// Create a new duration that’s the given number of seconds and
+    /// nanoseconds long. Nanoseconds outside the range 0 to 999,999,999
+    /// are carried into the seconds, and a duration that would be out of
+    /// range becomes `MIN` or `MAX`.
+    pub fn of_ns(seconds: i64, nanoseconds: i32) -> Self {
+        Self::saturating_from_nanoseconds(i128::from(seconds) * i128::from(NANOS_PER_SECOND) + i128::from(nanoseconds))
+    }
+
+    /// Create a new duration that’s the given number of minutes long, or
+    /// `MIN` or `MAX` if it would be out of range.
+    pub fn of_minutes(minutes: i64) -> Self {
+        Self::of_seconds_in_unit(minutes, 60)
+    }
+
+    /// Create a new duration that’s the given number of hours long, or
+    /// `MIN` or `MAX` if it would be out of range.
+    pub fn of_hours(hours: i64) -> Self {
+        Self::of_seconds_in_unit(hours, 60 * 60)
+    }
+
+    /// Create a new duration that’s the given number of days long, or
+    /// `MIN` or `MAX` if it would be out of range. Every day is exactly
+    /// 86,400 seconds long, so adding one to a zoned datetime across a
+    /// daylight-saving transition won’t keep the same wall-clock time.
+    pub fn of_days(days: i64) -> Self {
+        Self::of_seconds_in_unit(days, 24 * 60 * 60)
+    }
+
+    /// Create a new duration that’s the given number of weeks long, or
+    /// `MIN` or `MAX` if it would be out of range. Every week is exactly
+    /// seven 86,400-second days long.
+    pub fn of_weeks(weeks: i64) -> Self {
+        Self::of_seconds_in_unit(weeks, 7 * 24 * 60 * 60)
+    }
+
+    fn of_seconds_in_unit(amount: i64, seconds_in_unit: i64) -> Self {
+        Self::saturating_from_nanoseconds(i128::from(amount) * i128::from(seconds_in_unit) * i128::from(NANOS_PER_SECOND))
+    }
+
+    /// Return the seconds and milliseconds portions of the duration as
+    /// a 2-element tuple. Any nanoseconds that don’t make up a whole
+    /// millisecond are left out.
+    pub fn lengths(&self) -> (i64, i16) {
+        (self.seconds, (self.nanoseconds / 1_000_000) as i16)
+    }
+
+    /// Return the seconds and nanoseconds portions of the duration as
+    /// a 2-element tuple.
+    pub fn lengths_ns(&self) -> (i64, i32) {
+        (self.seconds, self.nanoseconds)
+    }
+
+    // I’ve done it like this instead of having separate seconds() and
+    // milliseconds() functions, because I think there’s a danger that
+    // people will think that milliseconds() returns the *total* length
+    // in milliseconds, rather than just this particular portion. This
+    // way, it’s clear that there are two separate values being returned.
+
+    /// Return the whole length of the duration in milliseconds. Any
+    /// nanoseconds that don’t make up a whole millisecond are left out,
+    /// rounding towards zero. This doesn’t always fit in an `i64`.
+    pub fn total_millis(&self) -> i128 {
+        self.total_nanoseconds() / i128::from(NANOS_PER_MILLISECOND)
+    }
+
+    /// Return the length of the duration in seconds as a floating-point
+    /// number, which loses precision for very long durations.
+    pub fn as_secs_f64(&self) -> f64 {
+        self.seconds as f64 + f64::from(self.nanoseconds) / NANOS_PER_SECOND as f64
+    }
+
+    /// Returns whether this duration is shorter than zero.
+    pub fn is_negative(&self) -> bool {
+        self.seconds < 0
+    }
+
+    /// Returns the length of this duration, making it positive.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this is `MIN`, which has no positive equivalent.
+    pub fn abs(&self) -> Self {
+        if self.is_negative() { -*self }
+                         else { *self }
+    }
+
+    /// Negates this duration, returning `None` if this is `MIN`, which has
+    /// no positive equivalent.
+    pub fn checked_neg(&self) -> Option<Self> {
+        Self::from_nanoseconds(-self.total_nanoseconds())
+    }
+
+    /// Adds two durations, returning `None` if the result would be out of
+    /// range.
+    pub fn checked_add(&self, rhs: Self) -> Option<Self> {
+        Self::from_nanoseconds(self.total_nanoseconds() + rhs.total_nanoseconds())
+    }
+
+    /// Subtracts a duration from this one, returning `None` if the result
+    /// would be out of range.
+    pub fn checked_sub(&self, rhs: Self) -> Option<Self> {
+        Self::from_nanoseconds(self.total_nanoseconds() - rhs.total_nanoseconds())
+    }
+
+    /// Multiplies this duration, returning `None` if the result would be
+    /// out of range.
+    pub fn checked_mul(&self, amount: i64) -> Option<Self> {
+        Self::from_nanoseconds(self.total_nanoseconds().checked_mul(i128::from(amount))?)
+    }
+
+    /// Divides this duration, rounding towards zero, returning `None` if the
+    /// amount is zero or the result would be out of range.
+    pub fn checked_div(&self, amount: i64) -> Option<Self> {
+        Self::from_nanoseconds(self.total_nanoseconds().checked_div(i128::from(amount))?)
+    }
+
+    /// Adds two durations, returning `MIN` or `MAX` if the result would be
+    /// out of range.
+    pub fn saturating_add(&self, rhs: Self) -> Self {
+        Self::saturating_from_nanoseconds(self.total_nanoseconds() + rhs.total_nanoseconds())
+    }
+
+    /// Subtracts a duration from this one, returning `MIN` or `MAX` if the
+    /// result would be out of range.
+    pub fn saturating_sub(&self, rhs: Self) -> Self {
+        Self::saturating_from_nanoseconds(self.total_nanoseconds() - rhs.total_nanoseconds())
+    }
+
+    /// Multiplies this duration, returning `MIN` or `MAX` if the result
+    /// would be out of range.
+    pub fn saturating_mul(&self, amount: i64) -> Self {
+        match self.checked_mul(amount) {
+            Some(duration)                              => duration,
+            None if (self.seconds < 0) == (amount < 0)  => Self::MAX,
+            None                                        => Self::MIN,
+        }
+    }
+
+    /// Rounds this duration to the nearest multiple of the given unit, such
+    /// as `Duration::of_minutes(15)`, with halfway lengths rounded away
+    /// from zero. The sign of the unit is ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the unit is zero, or the result would be out of range.
+    pub fn round(&self, unit: Self) -> Self {
+        let (nanoseconds, unit) = (self.total_nanoseconds(), unit.nonzero_unit());
+        let remainder = nanoseconds % unit;
+        let mut rounded = nanoseconds - remainder;
+        if remainder.abs() * 2 >= unit {
+            rounded += unit * remainder.signum();
+        }
+
+        Self::from_nanoseconds(rounded).expect("overflow when rounding duration")
+    }
+
+    /// Rounds this duration towards zero to a multiple of the given unit.
+    /// The sign of the unit is ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the unit is zero.
+    pub fn truncate(&self, unit: Self) -> Self {
+        let (nanoseconds, unit) = (self.total_nanoseconds(), unit.nonzero_unit());
+        Self::from_nanoseconds(nanoseconds - nanoseconds % unit).unwrap()
+    }
+
+    fn nonzero_unit(&self) -> i128 {
+        match self.total_nanoseconds().abs() {
+            0     => panic!("rounding duration to a zero-length unit"),
+            unit  => unit,
+        }
+    }
+
+    /// Returns the whole length of this duration in nanoseconds, which
+    /// always fits in an `i128`, with plenty of room left for adding.
+    fn total_nanoseconds(&self) -> i128 {
+        i128::from(self.seconds) * i128::from(NANOS_PER_SECOND) + i128::from(self.nanoseconds)
+    }
+
+    fn from_nanoseconds(nanoseconds: i128) -> Option<Self> {
+        let seconds = i64::try_from(nanoseconds.div_euclid(i128::from(NANOS_PER_SECOND))).ok()?;
+        let nanoseconds = nanoseconds.rem_euclid(i128::from(NANOS_PER_SECOND)) as i32;
+        Some(Self { seconds, nanoseconds })
+    }
+
+    fn saturating_from_nanoseconds(nanoseconds: i128) -> Self {
+        match Self::from_nanoseconds(nanoseconds) {
+            Some(duration)           => duration,
+            None if nanoseconds < 0  => Self::MIN,
+            None                     => Self::MAX,
+        }
+    }
+}
+
+/// Splits a number of nanoseconds into whole seconds, rounding down, and
+/// the nanoseconds left over, which are always positive.
+pub(crate) fn split_nanoseconds(nanoseconds: i64) -> (i64, i32) {
+    (nanoseconds.div_euclid(NANOS_PER_SECOND), nanoseconds.rem_euclid(NANOS_PER_SECOND) as i32)
+}
+
+impl Add<Duration> for Duration {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        self.checked_add(rhs).expect("overflow when adding durations")
+    }
+}
+
+impl Sub<Duration> for Duration {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.checked_sub(rhs).expect("overflow when subtracting durations")
+    }
+}
+
+impl Mul<i64> for Duration {
+    type Output = Self;
+
+    fn mul(self, amount: i64) -> Self {
+        self.checked_mul(amount).expect("overflow when multiplying duration")
+    }
+}
+
+/// Dividing a duration by a number rounds towards zero, to the nearest
+/// nanosecond.
+impl Div<i64> for Duration {
+    type Output = Self;
+
+    fn div(self, amount: i64) -> Self {
+        assert!(amount != 0, "dividing duration by zero");
+        self.checked_div(amount).expect("overflow when dividing duration")
+    }
+}
+
+/// Dividing one duration by another gives the ratio between their lengths,
+/// such as 2.5 for 150 seconds divided by one minute. Dividing by a zero
+/// duration gives an infinite or NaN result, as with any other `f64`.
+impl Div<Duration> for Duration {
+    type Output = f64;
+
+    fn div(self, other: Self) -> f64 {
+        self.total_nanoseconds() as f64 / other.total_nanoseconds() as f64
+    }
+}
+
+impl Neg for Duration {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.checked_neg().expect("overflow when negating duration")
+    }
+}
+
+impl Sum for Duration {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), Add::add)
+    }
+}
+
+impl<'a> Sum<&'a Duration> for Duration {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.cloned().sum()
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn carried_milliseconds() {
+        assert_eq!(Duration::of_ms(1, 1500), Duration::of_ms(2, 500));
+        assert_eq!(Duration::of_ms(0, -250), Duration::of_ms(-1, 750));
+    }
+
+    #[test]
+    fn carried_nanoseconds() {
+        assert_eq!(Duration::of_ns(0, -1), Duration::of_ns(-1, 999_999_999));
+    }
+
+    #[test]
+    fn saturated_constructors() {
+        assert_eq!(Duration::of_ms(i64::MAX, 1000), Duration::MAX);
+        assert_eq!(Duration::of_ns(i64::MIN, -1), Duration::MIN);
+    }
+
+    #[test]
+    fn checked() {
+        assert_eq!(Duration::MAX.checked_add(Duration::of_ns(0, 1)), None);
+        assert_eq!(Duration::MIN.checked_sub(Duration::of_ns(0, 1)), None);
+        assert_eq!(Duration::MAX.checked_mul(2), None);
+        assert_eq!(Duration::MIN.checked_mul(-1), None);
+        assert_eq!(Duration::of(3).checked_mul(-2), Some(Duration::of(-6)));
+    }
+
+    #[test]
+    fn saturating() {
+        assert_eq!(Duration::MAX.saturating_add(Duration::of(1)), Duration::MAX);
+        assert_eq!(Duration::MIN.saturating_sub(Duration::of(1)), Duration::MIN);
+        assert_eq!(Duration::MIN.saturating_mul(-1), Duration::MAX);
+        assert_eq!(Duration::MAX.saturating_mul(-2), Duration::MIN);
+        assert_eq!(Duration::of(1).saturating_add(Duration::of(1)), Duration::of(2));
+    }
+
+    #[test]
+    fn units() {
+        assert_eq!(Duration::of_minutes(2), Duration::of(120));
+        assert_eq!(Duration::of_hours(-1), Duration::of(-3600));
+        assert_eq!(Duration::of_days(1), Duration::of(86400));
+        assert_eq!(Duration::of_weeks(2), Duration::of(1_209_600));
+        assert_eq!(Duration::of_weeks(i64::MAX), Duration::MAX);
+        assert_eq!(Duration::of_days(i64::MIN), Duration::MIN);
+    }
+
+    #[test]
+    fn negation() {
+        assert_eq!(Duration::MIN.checked_neg(), None);
+        assert_eq!(-Duration::MAX, Duration::of_ns(i64::MIN, 1));
+        assert_eq!(Duration::of_ns(-1, 999_999_999).abs(), Duration::of_ns(0, 1));
+    }
+
+    #[test]
+    fn division() {
+        assert_eq!(Duration::MIN.checked_div(-1), None);
+        assert_eq!(Duration::of(1).checked_div(0), None);
+        assert_eq!(Duration::of(-7) / 2, Duration::of_ms(-4, 500));
+        assert_eq!(Duration::of(1) / 3, Duration::of_ns(0, 333_333_333));
+    }
+
+    #[test]
+    fn rounding() {
+        let unit = Duration::of(10);
+        assert_eq!(Duration::of(14).round(unit), Duration::of(10));
+        assert_eq!(Duration::of(15).round(unit), Duration::of(20));
+        assert_eq!(Duration::of(-15).round(unit), Duration::of(-20));
+        assert_eq!(Duration::of(-19).truncate(unit), Duration::of(-10));
+        assert_eq!(Duration::MIN.truncate(unit), Duration::of(i64::MIN + 8));
+    }
+
+    #[test]
+    #[should_panic]
+    fn rounding_to_zero() {
+        let _ = Duration::of(1).round(Duration::zero());
+    }
+}
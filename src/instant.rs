@@ -4,42 +4,66 @@ use std::fmt;
 use std::ops::{Add, Sub};
 
 use system::sys_time;
-use duration::Duration;
+use duration::{Duration, split_nanoseconds};
 
 
 /// An **instant** is an exact point on the timeline, irrespective of time
-/// zone or calendar format, with millisecond precision.
+/// zone or calendar format, with nanosecond precision.
 ///
 /// Internally, this is represented by a 64-bit integer of seconds, and a
-/// 16-bit integer of milliseconds. This means that it will overflow (and thus
-/// be unsuitable for) instants past GMT 15:30:08, Sunday 4th December,
-/// 292,277,026,596 (yes, that’s a year)
+/// 32-bit integer of nanoseconds. Arithmetic is limited to the instants
+/// between `MIN` and `MAX`, which cover every datetime from the year
+/// -999,999,999 to the year 999,999,999, so that any instant can be
+/// turned into a `LocalDateTime`.
 #[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
 pub struct Instant {
     seconds: i64,
-    milliseconds: i16,
+    nanoseconds: i32,
 }
 
 impl Instant {
 
+    /// The earliest instant that arithmetic can produce: midnight on the
+    /// 1st of January, -999,999,999.
+    pub const MIN: Self = Self { seconds: -31_557_014_135_596_800, nanoseconds: 0 };
+
+    /// The latest instant that arithmetic can produce: the last nanosecond
+    /// of the 31st of December, 999,999,999.
+    pub const MAX: Self = Self { seconds: 31_556_889_832_780_799, nanoseconds: 999_999_999 };
+
     /// Creates a new Instant set to the number of seconds since the Unix
-    /// epoch, and zero milliseconds.
+    /// epoch, and zero milliseconds. Seconds outside the range from `MIN`
+    /// to `MAX` are clamped to it, as are those of the other constructors.
     pub fn at(seconds: i64) -> Self {
         Self::at_ms(seconds, 0)
     }
 
     /// Creates a new Instant set to the number of seconds since the
     /// Unix epoch, along with the number of milliseconds so far this
-    /// second.
+    /// second. Milliseconds outside the range 0 to 999 are carried into
+    /// the seconds.
     pub fn at_ms(seconds: i64, milliseconds: i16) -> Self {
-        Self { seconds, milliseconds }
+        let milliseconds = i64::from(milliseconds);
+        Self {
+            seconds:      seconds.saturating_add(milliseconds.div_euclid(1000)),
+            nanoseconds:  milliseconds.rem_euclid(1000) as i32 * 1_000_000,
+        }.clamped()
+    }
+
+    /// Creates a new Instant set to the number of seconds since the
+    /// Unix epoch, along with the number of nanoseconds so far this
+    /// second. Nanoseconds outside the range 0 to 999,999,999 are carried
+    /// into the seconds.
+    pub fn at_ns(seconds: i64, nanoseconds: i32) -> Self {
+        let (carry, nanoseconds) = split_nanoseconds(i64::from(nanoseconds));
+        Self { seconds: seconds.saturating_add(carry), nanoseconds }.clamped()
     }
 
     /// Creates a new Instant set to the computer’s current time.
     #[cfg_attr(target_os = "redox", allow(unused_unsafe))]
     pub fn now() -> Self {
-        let (seconds, milliseconds) = unsafe { sys_time() };
-        Self { seconds, milliseconds }
+        let (seconds, nanoseconds) = unsafe { sys_time() };
+        Self { seconds, nanoseconds }
     }
 
     /// Creates a new Instant set to the Unix epoch.
@@ -54,36 +78,168 @@ impl Instant {
 
     /// Returns the number of milliseconds at this instant
     pub fn milliseconds(&self) -> i16 {
-        self.milliseconds
+        (self.nanoseconds / 1_000_000) as i16
+    }
+
+    /// Returns the number of nanoseconds at this instant
+    pub fn nanoseconds(&self) -> i32 {
+        self.nanoseconds
+    }
+
+    /// Returns the length of time that has passed since this instant,
+    /// which is negative if it’s still in the future.
+    pub fn elapsed(&self) -> Duration {
+        Self::now() - *self
+    }
+
+    /// Adds a duration to this instant, returning `None` if the result
+    /// would be outside the range from `MIN` to `MAX`.
+    pub fn checked_add(&self, duration: Duration) -> Option<Self> {
+        let (seconds, nanoseconds) = duration.lengths_ns();
+        let (carry, nanoseconds) = split_nanoseconds(i64::from(self.nanoseconds) + i64::from(nanoseconds));
+        let seconds = self.seconds.checked_add(seconds)?.checked_add(carry)?;
+        Self { seconds, nanoseconds }.bounded()
+    }
+
+    /// Subtracts a duration from this instant, returning `None` if the
+    /// result would be outside the range from `MIN` to `MAX`.
+    pub fn checked_sub(&self, duration: Duration) -> Option<Self> {
+        let (seconds, nanoseconds) = duration.lengths_ns();
+        let (carry, nanoseconds) = split_nanoseconds(i64::from(self.nanoseconds) - i64::from(nanoseconds));
+        let seconds = self.seconds.checked_sub(seconds)?.checked_add(carry)?;
+        Self { seconds, nanoseconds }.bounded()
+    }
+
+    /// Adds a duration to this instant, returning `MIN` or `MAX` if the
+    /// result would be outside the range between them.
+    pub fn saturating_add(&self, duration: Duration) -> Self {
+        match self.checked_add(duration) {
+            Some(instant)                         => instant,
+            None if duration.lengths_ns().0 < 0   => Self::MIN,
+            None                                  => Self::MAX,
+        }
+    }
+
+    /// Subtracts a duration from this instant, returning `MIN` or `MAX` if
+    /// the result would be outside the range between them.
+    pub fn saturating_sub(&self, duration: Duration) -> Self {
+        match self.checked_sub(duration) {
+            Some(instant)                         => instant,
+            None if duration.lengths_ns().0 < 0   => Self::MAX,
+            None                                  => Self::MIN,
+        }
+    }
+
+    fn clamped(self) -> Self {
+        self.max(Self::MIN).min(Self::MAX)
+    }
+
+    fn bounded(self) -> Option<Self> {
+        if self >= Self::MIN && self <= Self::MAX { Some(self) }
+                                              else { None }
     }
 }
 
 impl fmt::Debug for Instant {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "Instant({}s/{}ms)", self.seconds, self.milliseconds)
+        write!(f, "Instant({}s/{}ns)", self.seconds, self.nanoseconds)
     }
 }
 
+/// Adding a duration panics if the result would be past `MIN` or `MAX`;
+/// `checked_add` and `saturating_add` don’t.
 impl Add<Duration> for Instant {
     type Output = Self;
 
     fn add(self, duration: Duration) -> Self {
-        let (seconds, milliseconds) = duration.lengths();
-        Self {
-            seconds: self.seconds + seconds,
-            milliseconds: self.milliseconds + milliseconds,
-        }
+        self.checked_add(duration).expect("overflow when adding duration to instant")
     }
 }
 
+/// Subtracting a duration panics if the result would be past `MIN` or
+/// `MAX`; `checked_sub` and `saturating_sub` don’t.
 impl Sub<Duration> for Instant {
     type Output = Self;
 
     fn sub(self, duration: Duration) -> Self {
-        let (seconds, milliseconds) = duration.lengths();
-        Self {
-            seconds: self.seconds - seconds,
-            milliseconds: self.milliseconds - milliseconds,
-        }
+        self.checked_sub(duration).expect("overflow when subtracting duration from instant")
+    }
+}
+
+/// Subtracting one instant from another gives the length of time between
+/// them, which is negative if the other instant is later. Every instant is
+/// between `MIN` and `MAX`, so this never panics.
+impl Sub<Instant> for Instant {
+    type Output = Duration;
+
+    fn sub(self, other: Self) -> Duration {
+        Duration::of_ns(self.seconds, self.nanoseconds)
+            .checked_sub(Duration::of_ns(other.seconds, other.nanoseconds))
+            .expect("overflow when subtracting instants")
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn add_carries() {
+        let instant = Instant::at_ns(10, 999_999_999) + Duration::of_ns(0, 2);
+        assert_eq!(instant, Instant::at_ns(11, 1));
+    }
+
+    #[test]
+    fn sub_borrows() {
+        let instant = Instant::at_ns(10, 1) - Duration::of_ns(0, 2);
+        assert_eq!(instant, Instant::at_ns(9, 999_999_999));
+    }
+
+    #[test]
+    fn carried_fractions() {
+        assert_eq!(Instant::at_ms(1, 1500), Instant::at_ms(2, 500));
+        assert_eq!(Instant::at_ms(1, -1), Instant::at_ms(0, 999));
+        assert_eq!(Instant::at_ns(1, -1), Instant::at_ns(0, 999_999_999));
+    }
+
+    #[test]
+    fn checked() {
+        assert_eq!(Instant::MAX.checked_add(Duration::of_ns(0, 1)), None);
+        assert_eq!(Instant::MIN.checked_sub(Duration::of_ns(0, 1)), None);
+        assert_eq!(Instant::at(0).checked_add(Duration::MAX), None);
+        assert_eq!(Instant::at(0).checked_sub(Duration::MIN), None);
+        assert_eq!(Instant::at(0).checked_add(Duration::of(5)), Some(Instant::at(5)));
+    }
+
+    #[test]
+    fn saturating() {
+        assert_eq!(Instant::at(0).saturating_add(Duration::MAX), Instant::MAX);
+        assert_eq!(Instant::at(0).saturating_add(Duration::MIN), Instant::MIN);
+        assert_eq!(Instant::at(0).saturating_sub(Duration::MAX), Instant::MIN);
+        assert_eq!(Instant::at(0).saturating_sub(Duration::MIN), Instant::MAX);
+    }
+
+    #[test]
+    fn difference() {
+        assert_eq!(Instant::at_ns(5, 100) - Instant::at_ns(3, 200), Duration::of_ns(1, 999_999_900));
+        assert_eq!(Instant::at(3) - Instant::at(5), Duration::of(-2));
+        assert_eq!(Instant::MAX - Instant::MIN, Duration::of_ns(63_113_903_968_377_599, 999_999_999));
+    }
+
+    #[test]
+    fn clamped_constructors() {
+        assert_eq!(Instant::at(i64::MAX), Instant::MAX);
+        assert_eq!(Instant::at(i64::MIN), Instant::MIN);
+        assert_eq!(Instant::at_ns(i64::MAX, -1), Instant::MAX);
+        assert_eq!(Instant::at_ms(i64::MIN, 1), Instant::MIN);
+        assert_eq!(Instant::at(i64::MAX) + Duration::zero(), Instant::MAX);
+        assert_eq!(Instant::at(i64::MIN) - Duration::zero(), Instant::MIN);
+        assert_eq!(Instant::at(i64::MAX) - Instant::at(i64::MIN), Instant::MAX - Instant::MIN);
+    }
+
+    #[test]
+    fn milliseconds_truncate() {
+        assert_eq!(Instant::at_ns(0, 123_999_999).milliseconds(), 123);
     }
 }
@@ -33,7 +33,7 @@ pub use cal::zone as zone;
 pub use cal::convenience;
 
 mod duration;
-pub use duration::Duration;
+pub use duration::{Duration, DurationFormat, DurationUnit, ParseDurationError};
 
 mod instant;
 pub use instant::Instant;
@@ -1,8 +1,11 @@
 //! System-dependent functions, or anything that this library is unable to
 //! do without help from the OS.
 
+use std::env;
 use std::ffi::OsStr;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+
+use cal::zone::local::{self, LocalSource};
 
 extern crate libc;
 
@@ -21,14 +24,14 @@ use libc::clock_gettime;
 
 
 /// Returns the system’s current time, as a tuple of seconds elapsed since
-/// the Unix epoch, and the millisecond of the second.
+/// the Unix epoch, and the nanosecond of the second.
 #[cfg(any(target_os = "macos", target_os = "ios"))]
-pub unsafe fn sys_time() -> (i64, i16) {
+pub unsafe fn sys_time() -> (i64, i32) {
     use std::ptr::null_mut;
 
     let mut tv = libc::timeval { tv_sec: 0, tv_usec: 0 };
     let _ = gettimeofday(&mut tv, null_mut());
-    (tv.tv_sec, (tv.tv_usec / 1000) as i16)
+    (tv.tv_sec, tv.tv_usec as i32 * 1000)
 }
 
 #[cfg(windows)] use winapi::minwindef::FILETIME;
@@ -36,15 +39,15 @@ pub unsafe fn sys_time() -> (i64, i16) {
 #[cfg(windows)] const HECTONANOSEC_TO_UNIX_EPOCH: i64 = 11_644_473_600 * HECTONANOSECS_IN_SEC;
 
 /// Returns the system’s current time, as a tuple of seconds elapsed since
-/// the Unix epoch, and the millisecond of the second.
+/// the Unix epoch, and the nanosecond of the second.
 #[cfg(any(target_os = "windows"))]
-pub unsafe fn sys_time() -> (i64, i16) {
+pub unsafe fn sys_time() -> (i64, i32) {
     use std::mem;
     use kernel32::GetSystemTimeAsFileTime;
     let mut ft = mem::zeroed();
 
     GetSystemTimeAsFileTime(&mut ft);
-    (file_time_to_unix_seconds(&ft), (file_time_to_nsec(&ft) / 1000000) as i16)
+    (file_time_to_unix_seconds(&ft), file_time_to_nsec(&ft))
 
 }
 
@@ -68,47 +71,55 @@ fn file_time_as_u64(ft: &FILETIME) -> u64 {
 
 
 /// Returns the system’s current time, as a tuple of seconds elapsed since
-/// the Unix epoch, and the millisecond of the second.
+/// the Unix epoch, and the nanosecond of the second.
 #[cfg(not(any(target_os = "macos", target_os = "ios", target_os = "redox", windows)))]
-pub unsafe fn sys_time() -> (i64, i16) {
+pub unsafe fn sys_time() -> (i64, i32) {
     let mut tv = libc::timespec { tv_sec: 0, tv_nsec: 0 };
     let _ = clock_gettime(libc::CLOCK_REALTIME, &mut tv);
-    (tv.tv_sec as i64, (tv.tv_nsec / 1000) as i16)
+    (tv.tv_sec as i64, tv.tv_nsec as i32)
 }
 
 /// Returns the system’s current time, as a tuple of seconds elapsed since
-/// the Unix epoch, and the millisecond of the second.
+/// the Unix epoch, and the nanosecond of the second.
 #[cfg(target_os = "redox")]
-pub fn sys_time() -> (i64, i16) {
+pub fn sys_time() -> (i64, i32) {
    let mut ts = redox_syscall::TimeSpec::default();
    let realtime_clock = redox_syscall::CLOCK_REALTIME;
    let _ = redox_syscall::clock_gettime(realtime_clock, &mut ts);
-   (ts.tv_sec, (ts.tv_nsec / 1000) as i16)
+   (ts.tv_sec, ts.tv_nsec as i32)
 }
 
-/// Attempts to determine the system’s current time zone. There’s no
-/// guaranteed way to do this, so this function returns `None` if no
-/// timezone could be found.
+/// Attempts to determine the name of the system’s current time zone.
+/// There’s no guaranteed way to do this, so this function returns `None`
+/// if no timezone could be found, or if the one that was found has no
+/// name. Use `TimeZone::local` to get the time zone itself.
 pub fn sys_timezone() -> Option<String> {
-    use std::fs::read_link;
+    match local::find() {
+        (_, LocalSource::Default)  => None,
+        (zone, _)                  => zone.zone_name().map(str::to_owned),
+    }
+}
 
-    let link = match read_link("/etc/localtime") {
-        Ok(link) => link,
-        Err(_) => return None,
-    };
+/// Returns the directories that may contain a compiled zoneinfo database,
+/// in the order they should be searched: the one named by the `TZDIR`
+/// environment variable, if any, followed by the usual system locations.
+pub fn zoneinfo_directories() -> Vec<PathBuf> {
+    let mut directories = Vec::new();
 
-    if let Some(tz) = extract_timezone(&*link) {
-        if !tz.is_empty() {
-            return Some(tz);
+    if let Some(tzdir) = env::var_os("TZDIR") {
+        if !tzdir.is_empty() {
+            directories.push(PathBuf::from(tzdir));
         }
     }
 
-    None
+    directories.push(PathBuf::from("/usr/share/zoneinfo"));
+    directories.push(PathBuf::from("/usr/lib/zoneinfo"));
+    directories
 }
 
 /// Given a path, returns whether a valid zoneinfo timezone name can be
 /// detected at the end of that path.
-fn extract_timezone(path: &Path) -> Option<String> {
+pub fn extract_timezone(path: &Path) -> Option<String> {
     let mut bits = Vec::new();
 
     for pathlet in path.iter().rev().take_while(|c| is_tz_component(c)) {
@@ -0,0 +1,80 @@
+extern crate datetime;
+use datetime::zone::TimeZone;
+use datetime::zone::abbreviation::{AbbreviationMap, Error};
+use datetime::zone::tzdata::Database;
+use datetime::{LocalDate, LocalDateTime, LocalTime, Month, ISO};
+
+use std::path::Path;
+
+
+fn map() -> AbbreviationMap {
+    let mut database = Database::new();
+    database.parse("\
+        Z Asia/Kolkata 5:30 - IST\n\
+        Z Asia/Jerusalem 2 - IST\n\
+        Z Europe/Dublin 1 - IST\n\
+        Z America/Los_Angeles -8 - PST\n\
+        Z Asia/Manila 8 - PST\n").unwrap();
+
+    let mut zones: Vec<TimeZone> = database.zone_names().into_iter().map(|name| database.time_zone(name).unwrap()).collect();
+    zones.push(TimeZone::named_in(Path::new("./tests/zoneinfo"), "Europe/London").unwrap());
+    zones.push(TimeZone::named_in(Path::new("./tests/zoneinfo"), "America/New_York").unwrap());
+    AbbreviationMap::from_zones(&zones)
+}
+
+#[test]
+fn unambiguous() {
+    let map = map();
+    assert_eq!(map.resolve("BST", None).unwrap().offset, 3600);
+    assert_eq!(map.resolve("EDT", None).unwrap().zones, vec![ "America/New_York" ]);
+}
+
+#[test]
+fn from_posix_footer() {
+    // New York’s transitions end in 2037, after which its POSIX rule
+    // gives the abbreviations.
+    assert_eq!(map().resolve("EST", None).unwrap().offset, -5 * 3600);
+}
+
+#[test]
+fn ambiguous() {
+    match map().resolve("IST", None) {
+        Err(Error::Ambiguous { abbreviation, mut offsets }) => {
+            offsets.sort();
+            assert_eq!(abbreviation, "IST");
+            assert_eq!(offsets, vec![ 3600, 7200, 19800 ]);
+        },
+        other => panic!("expected ambiguity, got {:?}", other),
+    }
+}
+
+#[test]
+fn region_hint() {
+    let map = map();
+    assert_eq!(map.resolve("IST", Some("Asia/Kolkata")).unwrap().offset, 19800);
+    assert_eq!(map.resolve("IST", Some("Europe")).unwrap().offset, 3600);
+    assert_eq!(map.resolve("PST", Some("America")).unwrap().offset, -8 * 3600);
+}
+
+#[test]
+fn region_hint_still_ambiguous() {
+    // Two of the candidates are in Asia, so that doesn’t narrow it down.
+    assert!(matches!(map().resolve("IST", Some("Asia")), Err(Error::Ambiguous { .. })));
+}
+
+#[test]
+fn region_hint_unneeded() {
+    assert_eq!(map().resolve("BST", Some("Asia")).unwrap().offset, 3600);
+}
+
+#[test]
+fn offset_datetime() {
+    let local = LocalDateTime::new(LocalDate::ymd(2024, Month::March, 1).unwrap(), LocalTime::hms(10, 0, 0).unwrap());
+    let datetime = map().to_offset_datetime(local, "PST", Some("America/Los_Angeles")).unwrap();
+    assert_eq!(datetime.iso().to_string(), "2024-03-01T10:00:00.000-08");
+}
+
+#[test]
+fn unknown() {
+    assert_eq!(map().offset("XYZ", None), Err(Error::Unknown("XYZ".into())));
+}
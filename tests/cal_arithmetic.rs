@@ -13,3 +13,31 @@ fn subtraction() {
     let date = LocalDateTime::at(100000000);
     assert_eq!(LocalDateTime::at(99999999), date - Duration::of(1))
 }
+
+#[test]
+fn datetime_difference() {
+    let then = LocalDateTime::at_ns(1000, 250_000_000);
+    let now = LocalDateTime::at_ns(1600, 100_000_000);
+    assert_eq!(now - then, Duration::of_ns(599, 850_000_000));
+    assert_eq!(then - now, Duration::of_ns(-600, 150_000_000));
+}
+
+#[test]
+fn time_difference() {
+    use datetime::LocalTime;
+
+    let morning = LocalTime::hms(9, 30, 0).unwrap();
+    let evening = LocalTime::hms_ms(17, 45, 10, 500).unwrap();
+    assert_eq!(evening - morning, Duration::of_ms(8 * 3600 + 15 * 60 + 10, 500));
+    assert_eq!(morning - evening, Duration::of_ms(-(8 * 3600 + 15 * 60 + 11), 500));
+}
+
+#[test]
+fn offset_difference() {
+    use datetime::OffsetDateTime;
+
+    let london: OffsetDateTime = "2024-06-01T12:00:00Z".parse().unwrap();
+    let paris: OffsetDateTime = "2024-06-01T14:30:00+02:00".parse().unwrap();
+    assert_eq!(paris - london, Duration::of(30 * 60));
+    assert_eq!(london - paris, Duration::of(-30 * 60));
+}
@@ -0,0 +1,77 @@
+extern crate datetime;
+use datetime::zone::catalogue::Catalogue;
+
+use std::path::Path;
+
+
+fn catalogue() -> Catalogue {
+    Catalogue::load(Path::new("./tests/catalogue")).unwrap()
+}
+
+#[test]
+fn zone_names() {
+    assert_eq!(catalogue().zone_names(), vec![ "America/New_York", "Europe/London" ]);
+}
+
+#[test]
+fn all_names() {
+    assert_eq!(catalogue().all_names(), vec![ "America/New_York", "Europe/London", "GB", "US/Eastern" ]);
+}
+
+#[test]
+fn canonical_names() {
+    let catalogue = catalogue();
+    assert_eq!(catalogue.canonical_name("US/Eastern"), Some("America/New_York"));
+    assert_eq!(catalogue.canonical_name("Europe/London"), Some("Europe/London"));
+    assert_eq!(catalogue.canonical_name("Europe/Atlantis"), None);
+
+    assert!(catalogue.is_canonical("Europe/London"));
+    assert!(!catalogue.is_canonical("GB"));
+    assert!(catalogue.contains("GB"));
+}
+
+#[test]
+fn aliases() {
+    let catalogue = catalogue();
+    assert_eq!(catalogue.aliases("Europe/London"), vec![ "GB" ]);
+    assert_eq!(catalogue.aliases("GB"), Vec::<&str>::new());
+}
+
+#[test]
+fn countries() {
+    let catalogue = catalogue();
+    assert_eq!(catalogue.country_name("GB"), Some("Britain (UK)"));
+    assert_eq!(catalogue.country_name("XX"), None);
+
+    let jersey = catalogue.zones_for_country("JE");
+    assert_eq!(jersey.len(), 1);
+    assert_eq!(jersey[0].zone, "Europe/London");
+    assert_eq!(jersey[0].comment, None);
+
+    let us: Vec<_> = catalogue.zones_for_country("US").into_iter().map(|l| (&*l.zone, l.comment.as_deref())).collect();
+    assert_eq!(us, vec![ ("America/New_York", Some("Eastern (most areas)")),
+                         ("America/Chicago",  Some("Central (most areas)")) ]);
+}
+
+#[test]
+fn coordinates() {
+    let catalogue = catalogue();
+    let new_york = &catalogue.zones_for_country("US")[0];
+    assert!((new_york.latitude - 40.714_167).abs() < 1e-6);
+    assert!((new_york.longitude + 74.006_389).abs() < 1e-6);
+}
+
+#[test]
+fn without_tzdata_source() {
+    // The zoneinfo fixtures have TZif files but no tzdata.zi, so every
+    // file is listed as a zone, and there are no aliases or countries.
+    let catalogue = Catalogue::load(Path::new("./tests/zoneinfo")).unwrap();
+    assert_eq!(catalogue.zone_names(), vec![ "America/New_York", "Europe/London" ]);
+    assert_eq!(catalogue.canonical_name("GB"), None);
+    assert!(catalogue.locations().is_empty());
+}
+
+#[test]
+fn missing_directory() {
+    assert!(Catalogue::load(Path::new("./tests/nowhere")).is_err());
+}
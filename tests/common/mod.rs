@@ -0,0 +1,18 @@
+//! Helpers shared between the integration tests.
+
+use datetime::zone::TimeZone;
+use datetime::{LocalDateTime, Instant};
+
+
+/// Checks that two zones give the same offsets and abbreviations every
+/// twelve hours from 1900 until well past the end of their transition
+/// tables, where both zones fall back to their rules.
+pub fn assert_same_zone(name: &str, zone: &TimeZone, expected: &TimeZone) {
+    let mut time = -2_208_988_800;
+    while time < 4_102_444_800 {
+        let datetime = LocalDateTime::from_instant(Instant::at(time));
+        assert_eq!(zone.offset(datetime), expected.offset(datetime), "{} at {:?}", name, datetime);
+        assert_eq!(zone.name(datetime), expected.name(datetime), "{} at {:?}", name, datetime);
+        time += 12 * 60 * 60;
+    }
+}
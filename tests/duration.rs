@@ -24,6 +24,11 @@ mod addition {
     fn wrapping_exact() {
         assert_eq!(Duration::of(1), Duration::of_ms(0, 500) + Duration::of_ms(0, 500))
     }
+
+    #[test]
+    fn nanoseconds() {
+        assert_eq!(Duration::of_ns(1, 1), Duration::of_ns(0, 999_999_999) + Duration::of_ns(0, 2))
+    }
 }
 
 
@@ -49,6 +54,11 @@ mod subtraction {
     fn wrapping_exact() {
         assert_eq!(Duration::of(1), Duration::of_ms(1, 500) - Duration::of_ms(0, 500))
     }
+
+    #[test]
+    fn nanoseconds() {
+        assert_eq!(Duration::of_ns(0, 999_999_999), Duration::of(1) - Duration::of_ns(0, 1))
+    }
 }
 
 
@@ -64,4 +74,109 @@ mod multiplication {
     fn milliseconds() {
         assert_eq!(Duration::of(1), Duration::of_ms(0, 500) * 2)
     }
+
+    #[test]
+    fn nanoseconds() {
+        assert_eq!(Duration::of_ns(3, 3), Duration::of_ns(1, 1) * 3)
+    }
+}
+
+
+mod lengths {
+    use super::*;
+
+    #[test]
+    fn milliseconds_are_truncated() {
+        assert_eq!(Duration::of_ns(2, 123_999_999).lengths(), (2, 123))
+    }
+
+    #[test]
+    fn nanoseconds() {
+        assert_eq!(Duration::of_ns(2, 123_999_999).lengths_ns(), (2, 123_999_999))
+    }
+}
+
+
+mod division {
+    use super::*;
+
+    #[test]
+    fn simple() {
+        assert_eq!(Duration::of(4), Duration::of(16) / 4)
+    }
+
+    #[test]
+    fn nanoseconds() {
+        assert_eq!(Duration::of_ns(0, 500_000_001), Duration::of_ns(1, 2) / 2)
+    }
+
+    #[test]
+    fn ratio() {
+        assert_eq!(Duration::of(150) / Duration::of_minutes(1), 2.5)
+    }
+
+    #[test]
+    #[should_panic]
+    fn by_zero() {
+        let _ = Duration::of(1) / 0;
+    }
+}
+
+
+mod totals {
+    use super::*;
+
+    #[test]
+    fn milliseconds() {
+        assert_eq!(Duration::of_ns(-2, 999_999).total_millis(), -1999)
+    }
+
+    #[test]
+    fn longest() {
+        assert_eq!(Duration::MAX.total_millis(), i128::from(i64::MAX) * 1000 + 999)
+    }
+
+    #[test]
+    fn floating_point() {
+        assert_eq!(Duration::of_ms(-2, 250).as_secs_f64(), -1.75)
+    }
+}
+
+
+mod ordering {
+    use super::*;
+
+    #[test]
+    fn by_length() {
+        let mut durations = vec![ Duration::of(1), Duration::of_ms(-1, 500), Duration::zero(), Duration::of_ns(0, 1) ];
+        durations.sort();
+        assert_eq!(durations, vec![ Duration::of_ms(-1, 500), Duration::zero(), Duration::of_ns(0, 1), Duration::of(1) ]);
+    }
+
+    #[test]
+    fn bounds() {
+        assert!(Duration::MIN < Duration::zero() && Duration::zero() < Duration::MAX)
+    }
+}
+
+
+mod sum {
+    use super::*;
+
+    #[test]
+    fn owned() {
+        let total: Duration = (1 .. 5).map(Duration::of_hours).sum();
+        assert_eq!(total, Duration::of_hours(10))
+    }
+
+    #[test]
+    fn borrowed() {
+        let laps = [ Duration::of_ms(61, 250), Duration::of_ms(59, 750) ];
+        assert_eq!(laps.iter().sum::<Duration>(), Duration::of(121))
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(Vec::<Duration>::new().into_iter().sum::<Duration>(), Duration::zero())
+    }
 }
@@ -0,0 +1,191 @@
+extern crate datetime;
+use datetime::{Duration, DurationFormat, DurationUnit, ParseDurationError};
+
+
+mod display {
+    use super::*;
+
+    #[test]
+    fn every_unit() {
+        let duration = Duration::of_days(1) + Duration::of_hours(1) + Duration::of_minutes(23) + Duration::of_ms(4, 500);
+        assert_eq!(duration.to_string(), "1d 1h 23m 4.5s")
+    }
+
+    #[test]
+    fn zero_units_left_out() {
+        assert_eq!(Duration::of_hours(2).to_string(), "2h");
+        assert_eq!((Duration::of_hours(1) + Duration::of(5)).to_string(), "1h 5s");
+    }
+
+    #[test]
+    fn zero() {
+        assert_eq!(Duration::zero().to_string(), "0s")
+    }
+
+    #[test]
+    fn fractions() {
+        assert_eq!(Duration::of_ms(0, 250).to_string(), "0.25s");
+        assert_eq!(Duration::of_ns(1, 1).to_string(), "1.000000001s");
+    }
+
+    #[test]
+    fn negative() {
+        assert_eq!(Duration::of_ms(-91, 500).to_string(), "-1m 30.5s")
+    }
+
+    #[test]
+    fn extremes() {
+        assert_eq!(Duration::MIN.to_string(), "-106751991167300d 15h 30m 8s");
+        assert_eq!(Duration::MIN.to_string().parse::<Duration>(), Ok(Duration::MIN));
+        assert_eq!(Duration::MAX.to_string().parse::<Duration>(), Ok(Duration::MAX));
+    }
+}
+
+
+mod format {
+    use super::*;
+
+    #[test]
+    fn largest_unit() {
+        let format = DurationFormat::new().largest_unit(DurationUnit::Minutes);
+        assert_eq!(format.format(&Duration::of_hours(3)), "180m");
+    }
+
+    #[test]
+    fn weeks() {
+        let format = DurationFormat::new().largest_unit(DurationUnit::Weeks);
+        assert_eq!(format.format(&Duration::of_days(15)), "2w 1d");
+    }
+
+    #[test]
+    fn max_components() {
+        let format = DurationFormat::new().max_components(2);
+        let duration = Duration::of_days(1) + Duration::of_hours(2) + Duration::of_minutes(31);
+        assert_eq!(format.format(&duration), "1d 3h");
+    }
+
+    #[test]
+    fn max_components_carry() {
+        let format = DurationFormat::new().max_components(2);
+        let duration = Duration::of_hours(23) + Duration::of_minutes(59) + Duration::of(45);
+        assert_eq!(format.format(&duration), "1d");
+    }
+
+    #[test]
+    fn max_components_count_zeroes() {
+        let format = DurationFormat::new().max_components(2);
+        assert_eq!(format.format(&(Duration::of_hours(1) + Duration::of(5))), "1h");
+    }
+
+    #[test]
+    fn fraction_digits() {
+        let format = DurationFormat::new().fraction_digits(2);
+        assert_eq!(format.format(&Duration::of_ms(4, 567)), "4.57s");
+        assert_eq!(format.format(&Duration::of_ms(4, 996)), "5s");
+    }
+
+    #[test]
+    fn whole_seconds() {
+        let format = DurationFormat::new().fraction_digits(0);
+        assert_eq!(format.format(&Duration::of_ms(59, 500)), "1m");
+        assert_eq!(format.format(&Duration::of_ms(-1, 600)), "0s");
+    }
+}
+
+
+mod parsing {
+    use super::*;
+
+    fn parse(input: &str) -> Result<Duration, ParseDurationError> {
+        input.parse()
+    }
+
+    #[test]
+    fn seconds() {
+        assert_eq!(parse("90s"), Ok(Duration::of(90)))
+    }
+
+    #[test]
+    fn no_spaces() {
+        assert_eq!(parse("1h30m"), Ok(Duration::of_minutes(90)))
+    }
+
+    #[test]
+    fn days() {
+        assert_eq!(parse("2d"), Ok(Duration::of_days(2)))
+    }
+
+    #[test]
+    fn spaces() {
+        assert_eq!(parse("1h 23m 4.5s"), Ok(Duration::of_ms(60 * 60 + 23 * 60 + 4, 500)))
+    }
+
+    #[test]
+    fn fractional_units() {
+        assert_eq!(parse("1.5h"), Ok(Duration::of_minutes(90)));
+        assert_eq!(parse(".25m"), Ok(Duration::of(15)));
+    }
+
+    #[test]
+    fn small_units() {
+        assert_eq!(parse("1ms 2us 3ns"), Ok(Duration::of_ns(0, 1_002_003)));
+        assert_eq!(parse("5µs"), Ok(Duration::of_ns(0, 5_000)));
+    }
+
+    #[test]
+    fn signs() {
+        assert_eq!(parse("-1m 30.5s"), Ok(Duration::of_ms(-91, 500)));
+        assert_eq!(parse("+2w"), Ok(Duration::of_weeks(2)));
+    }
+
+    #[test]
+    fn round_trip() {
+        let duration = Duration::of_ns(-123_456, 789);
+        assert_eq!(parse(&duration.to_string()), Ok(duration));
+    }
+}
+
+
+mod errors {
+    use super::*;
+
+    fn parse(input: &str) -> Result<Duration, ParseDurationError> {
+        input.parse()
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(parse(""), Err(ParseDurationError::Empty));
+        assert_eq!(parse("  "), Err(ParseDurationError::Empty));
+    }
+
+    #[test]
+    fn missing_unit() {
+        assert_eq!(parse("90"), Err(ParseDurationError::MissingUnit { pos: 2 }))
+    }
+
+    #[test]
+    fn unknown_unit() {
+        let error = parse("1h 5x").unwrap_err();
+        assert_eq!(error, ParseDurationError::UnknownUnit { pos: 4 });
+        assert_eq!(error.position(), 4);
+        assert_eq!(error.to_string(), "unknown unit at position 4");
+    }
+
+    #[test]
+    fn expected_number() {
+        assert_eq!(parse("1h -5m"), Err(ParseDurationError::ExpectedNumber { pos: 3 }))
+    }
+
+    #[test]
+    fn overflow() {
+        assert_eq!(parse("1s 15250284452472w"), Err(ParseDurationError::Overflow { pos: 3 }));
+        assert_eq!(parse("99999999999999999999999999999999999999999s"), Err(ParseDurationError::Overflow { pos: 0 }));
+    }
+
+    #[test]
+    fn just_in_range() {
+        assert_eq!(parse("-9223372036854775808s"), Ok(Duration::MIN));
+        assert_eq!(parse("9223372036854775808s"), Err(ParseDurationError::Overflow { pos: 0 }));
+    }
+}
@@ -0,0 +1,43 @@
+#![cfg(feature = "embedded-tzdata")]
+
+extern crate datetime;
+use datetime::zone::{embedded, TimeZone, TimeZoneSource};
+use datetime::{LocalDateTime, Instant};
+
+use std::path::Path;
+
+mod common;
+
+
+fn compare(name: &str) {
+    let embedded = embedded::lookup(name).unwrap();
+    let system = TimeZone::named_in(Path::new("./tests/zoneinfo"), name).unwrap();
+    common::assert_same_zone(name, &embedded, &system);
+}
+
+#[test]
+fn london() {
+    compare("Europe/London");
+}
+
+#[test]
+fn new_york() {
+    compare("America/New_York");
+}
+
+#[test]
+fn every_zone_is_usable() {
+    let summer = LocalDateTime::from_instant(Instant::at(1_467_331_200));
+
+    for name in embedded::zone_names() {
+        let zone = embedded::lookup(name).unwrap();
+        assert_eq!(zone.zone_name(), Some(name));
+        let _ = zone.offset(summer);
+
+        let valid = match zone.0 {
+            TimeZoneSource::Static(tz)        => tz.fixed_timespans.validate(),
+            TimeZoneSource::Runtime(ref arc)  => arc.fixed_timespans.borrow().validate(),
+        };
+        assert_eq!(valid, Ok(()), "{} is malformed", name);
+    }
+}
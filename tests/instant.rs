@@ -12,6 +12,16 @@ fn milliseconds() {
     assert_eq!(Instant::at_ms(3, 333).milliseconds(), 333)
 }
 
+#[test]
+fn milliseconds_as_nanoseconds() {
+    assert_eq!(Instant::at_ms(3, 333), Instant::at_ns(3, 333_000_000))
+}
+
+#[test]
+fn nanoseconds() {
+    assert_eq!(Instant::at_ns(3, 123_456_789).nanoseconds(), 123_456_789)
+}
+
 #[test]
 fn epoch() {
     assert_eq!(Instant::at_epoch().seconds(), 0)
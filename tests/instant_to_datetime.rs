@@ -70,14 +70,15 @@ fn year_2038_problem() {
 
 #[test]
 fn the_end_of_time() {
+    // Seconds past the last datetime get clamped to it.
     let date = LocalDateTime::at(0x7FFF_FFFF_FFFF_FFFF);
 
-    assert_eq!(date.year(),   292_277_026_596);
+    assert_eq!(date.year(),   999_999_999);
     assert_eq!(date.month(),  Month::December);
-    assert_eq!(date.day(),    4);
-    assert_eq!(date.hour(),   15);
-    assert_eq!(date.minute(), 30);
-    assert_eq!(date.second(), 07);
+    assert_eq!(date.day(),    31);
+    assert_eq!(date.hour(),   23);
+    assert_eq!(date.minute(), 59);
+    assert_eq!(date.second(), 59);
 }
 
 
@@ -48,6 +48,60 @@ mod datetimes {
 
         assert_eq!(debugged, "2009-02-13T23:31:30.000");
     }
+
+    #[test]
+    fn microseconds() {
+        let time = LocalTime::hms_ns(12, 0, 0, 123_456_000).unwrap();
+        assert_eq!(time.iso().to_string(), "12:00:00.123456");
+    }
+
+    #[test]
+    fn nanoseconds() {
+        let time = LocalTime::hms_ns(12, 0, 0, 5).unwrap();
+        assert_eq!(time.iso().to_string(), "12:00:00.000000005");
+    }
+
+    #[test]
+    fn round_trip() {
+        for input in &[ "2009-02-13T23:31:30.123", "2009-02-13T23:31:30.123456", "2009-02-13T23:31:30.123456789" ] {
+            let then: LocalDateTime = input.parse().unwrap();
+            assert_eq!(then.iso().to_string(), *input);
+        }
+    }
+
+    #[test]
+    fn parse_fractions() {
+        use datetime::TimePiece;
+
+        let time: LocalTime = "12:00:00,5".parse().unwrap();
+        assert_eq!(time.nanosecond(), 500_000_000);
+
+        let time: LocalTime = "12:00:00.1234567891".parse().unwrap();
+        assert_eq!(time.nanosecond(), 123_456_789);
+    }
+
+    #[test]
+    fn fraction_only_after_seconds() {
+        use datetime::{OffsetDateTime, TimePiece};
+
+        let time: LocalTime = "12:00Z,5".parse().unwrap();
+        assert_eq!(time.nanosecond(), 0);
+
+        let then: OffsetDateTime = "2009-02-13T23:31:30+01:00.5".parse().unwrap();
+        assert_eq!(then.nanosecond(), 0);
+
+        let then: LocalDateTime = "2009-02-13T23:31:30.25Z".parse().unwrap();
+        assert_eq!(then.nanosecond(), 250_000_000);
+    }
+
+    #[test]
+    fn offset_fractions() {
+        use datetime::{OffsetDateTime, TimePiece};
+
+        let then: OffsetDateTime = "2009-02-13T23:31:30.000001+01:00".parse().unwrap();
+        assert_eq!(then.microsecond(), 1);
+        assert_eq!(then.iso().to_string(), "2009-02-13T23:31:30.000001+01");
+    }
 }
 
 mod offsets {
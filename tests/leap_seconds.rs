@@ -0,0 +1,94 @@
+extern crate datetime;
+use datetime::zone::leap::{LeapSecondTable, LeapSecond};
+use datetime::zone::tzif;
+use datetime::{Instant, LocalDate, LocalDateTime, LocalTime, Month};
+
+use std::fs;
+
+
+fn from_list() -> LeapSecondTable {
+    let source = fs::read_to_string("./tests/leap/leap-seconds.list").unwrap();
+    LeapSecondTable::parse_list(&source).unwrap()
+}
+
+fn from_tzif() -> LeapSecondTable {
+    let bytes = fs::read("./tests/leap/right-UTC").unwrap();
+    LeapSecondTable::from_tzif(&tzif::parse(&bytes).unwrap()).unwrap()
+}
+
+fn utc(year: i64, month: Month, day: i8, hour: i8, minute: i8, second: i8) -> Instant {
+    let date = LocalDate::ymd(year, month, day).unwrap();
+    let time = LocalTime::hms(hour, minute, second).unwrap();
+    LocalDateTime::new(date, time).to_instant()
+}
+
+#[test]
+fn sources_agree() {
+    let list = from_list();
+    let tzif = from_tzif();
+    assert_eq!(list.records(), tzif.records());
+    assert_eq!(list.records().len(), 27);
+    assert_eq!(list.records().last(), Some(&LeapSecond { time: utc(2017, Month::January, 1, 0, 0, 0).seconds(), tai_offset: 37 }));
+}
+
+#[test]
+fn expiry() {
+    assert_eq!(from_list().expires(), Some(utc(2026, Month::June, 28, 0, 0, 0)));
+}
+
+#[test]
+fn tai_offsets() {
+    let table = from_list();
+    assert_eq!(table.tai_offset(utc(1971, Month::June, 1, 0, 0, 0)), 10);
+    assert_eq!(table.tai_offset(utc(1999, Month::January, 1, 0, 0, 0)), 32);
+    assert_eq!(table.tai_offset(utc(2016, Month::December, 31, 23, 59, 59)), 36);
+    assert_eq!(table.tai_offset(utc(2024, Month::March, 1, 12, 0, 0)), 37);
+}
+
+#[test]
+fn utc_to_tai_and_back() {
+    let table = from_list();
+    let instant = Instant::at_ms(utc(2024, Month::March, 1, 12, 0, 0).seconds(), 250);
+    let tai = table.to_tai(instant);
+    assert_eq!(tai, Instant::at_ms(instant.seconds() + 37, 250));
+    assert_eq!(table.from_tai(tai), instant);
+}
+
+#[test]
+fn gps_epoch() {
+    let table = from_list();
+    let epoch = utc(1980, Month::January, 6, 0, 0, 0);
+    assert_eq!(table.to_gps(epoch), Instant::at(0));
+    assert_eq!(table.from_gps(Instant::at(0)), epoch);
+}
+
+#[test]
+fn gps_now() {
+    // GPS time has been 18 seconds ahead of UTC since the start of 2017.
+    let table = from_list();
+    let instant = utc(2024, Month::March, 1, 12, 0, 0);
+    let gps = table.to_gps(instant);
+    assert_eq!(gps.seconds(), instant.seconds() - 315_964_800 + 18);
+    assert_eq!(table.from_gps(gps), instant);
+}
+
+#[test]
+fn leap_seconds() {
+    let table = from_list();
+    assert!(table.is_leap_second(utc(2016, Month::December, 31, 23, 59, 59)));
+    assert!(table.is_leap_second(utc(1972, Month::June, 30, 23, 59, 59)));
+    assert!(!table.is_leap_second(utc(2016, Month::December, 31, 23, 59, 58)));
+    assert!(!table.is_leap_second(utc(2017, Month::January, 1, 0, 0, 0)));
+    assert!(!table.is_leap_second(utc(2017, Month::June, 30, 23, 59, 59)));
+}
+
+#[test]
+fn tai_through_leap_second() {
+    let table = from_list();
+    let before = table.to_tai(utc(2016, Month::December, 31, 23, 59, 59));
+    let after = table.to_tai(utc(2017, Month::January, 1, 0, 0, 0));
+    assert_eq!(after.seconds() - before.seconds(), 2);
+
+    let leap = Instant::at(before.seconds() + 1);
+    assert_eq!(table.from_tai(leap), utc(2016, Month::December, 31, 23, 59, 59));
+}
@@ -0,0 +1,56 @@
+extern crate datetime;
+use datetime::zone::{TimeZone, Error};
+use datetime::{LocalDateTime, LocalDate, LocalTime, Month};
+
+use std::path::Path;
+
+
+fn fixtures() -> &'static Path {
+    Path::new("./tests/zoneinfo")
+}
+
+#[test]
+fn found() {
+    let zone = TimeZone::named_in(fixtures(), "Europe/London").unwrap();
+    assert_eq!(zone.zone_name(), Some("Europe/London"));
+
+    let summer = LocalDateTime::new(LocalDate::ymd(2016, Month::July, 1).unwrap(), LocalTime::midnight());
+    assert_eq!(zone.offset(summer), 3600);
+}
+
+#[test]
+fn not_found() {
+    match TimeZone::named_in(fixtures(), "Europe/Atlantis") {
+        Err(Error::NotFound(name))  => assert_eq!(name, "Europe/Atlantis"),
+        other                       => panic!("expected NotFound, got {:?}", other),
+    }
+}
+
+#[test]
+fn directory_is_not_a_zone() {
+    match TimeZone::named_in(fixtures(), "Europe") {
+        Err(Error::NotFound(_))  => {},
+        other                    => panic!("expected NotFound, got {:?}", other),
+    }
+}
+
+#[test]
+fn traversal() {
+    match TimeZone::named_in(fixtures(), "../zoneinfo/Europe/London") {
+        Err(Error::InvalidName(_))  => {},
+        other                       => panic!("expected InvalidName, got {:?}", other),
+    }
+
+    match TimeZone::named("../../../../etc/passwd") {
+        Err(Error::InvalidName(_))  => {},
+        other                       => panic!("expected InvalidName, got {:?}", other),
+    }
+}
+
+#[test]
+fn not_tzif() {
+    match TimeZone::named_in(Path::new("./tests"), "examples.json") {
+        Err(Error::Tzif(_))  => {},
+        other                => panic!("expected Tzif, got {:?}", other),
+    }
+}
@@ -0,0 +1,141 @@
+extern crate datetime;
+use datetime::zone::TimeZone;
+use datetime::{LocalDateTime, LocalDate, LocalTime, Month, Instant, DatePiece, TimePiece};
+
+use std::fs::File;
+use std::io::Read;
+
+
+fn london() -> TimeZone {
+    let mut bytes = Vec::new();
+    let mut file = File::open("./tests/zoneinfo/Europe/London").unwrap();
+    let _ = file.read_to_end(&mut bytes).unwrap();
+    TimeZone::from_tzif(Some("Europe/London".into()), &bytes).unwrap()
+}
+
+fn at(year: i64, month: Month, day: i8, hour: i8, minute: i8) -> LocalDateTime {
+    LocalDateTime::new(
+        LocalDate::ymd(year, month, day).unwrap(),
+        LocalTime::hm(hour, minute).unwrap(),
+    )
+}
+
+
+#[test]
+fn far_future_offsets() {
+    let zone = london();
+
+    for &year in &[ 2040, 2100, 2525 ] {
+        assert_eq!(zone.offset(at(year, Month::January, 15, 12, 0)), 0, "January {}", year);
+        assert_eq!(zone.name(at(year, Month::January, 15, 12, 0)), "GMT");
+        assert_eq!(zone.offset(at(year, Month::July, 15, 12, 0)), 3600, "July {}", year);
+        assert_eq!(zone.name(at(year, Month::July, 15, 12, 0)), "BST");
+    }
+}
+
+#[test]
+fn far_future_transition_instant() {
+    let zone = london();
+
+    // Daylight-saving time starts at 01:00 UTC on the last Sunday in
+    // March, which is the 29th in 2043.
+    assert_eq!(zone.offset(at(2043, Month::March, 29, 0, 59)), 0);
+    assert_eq!(zone.offset(at(2043, Month::March, 29, 1, 1)), 3600);
+}
+
+#[test]
+fn far_future_local_times() {
+    let zone = london();
+
+    assert!(zone.convert_local(at(2043, Month::March, 29, 1, 30)).is_impossible());
+    assert!(zone.convert_local(at(2043, Month::October, 25, 1, 30)).is_ambiguous());
+
+    let zoned = zone.convert_local(at(2043, Month::June, 1, 9, 0)).unwrap_precise();
+    assert_eq!(zoned.hour(), 9);
+    assert_eq!(zoned.to_instant(), at(2043, Month::June, 1, 8, 0).to_instant());
+}
+
+#[test]
+fn from_posix() {
+    let zone = TimeZone::from_posix("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+    assert!(!zone.is_fixed());
+
+    assert_eq!(zone.offset(at(1985, Month::January, 1, 0, 0)), 3600);
+    assert_eq!(zone.offset(at(2027, Month::July, 1, 0, 0)), 7200);
+    assert_eq!(zone.name(at(2027, Month::July, 1, 0, 0)), "CEST");
+
+    assert!(zone.convert_local(at(2027, Month::March, 28, 2, 30)).is_impossible());
+    assert!(zone.convert_local(at(2027, Month::October, 31, 2, 30)).is_ambiguous());
+}
+
+#[test]
+fn from_posix_southern() {
+    let zone = TimeZone::from_posix("AEST-10AEDT,M10.1.0,M4.1.0/3").unwrap();
+    assert_eq!(zone.name(at(2031, Month::January, 1, 0, 0)), "AEDT");
+    assert_eq!(zone.name(at(2031, Month::June, 1, 0, 0)), "AEST");
+    assert_eq!(zone.name(at(2031, Month::December, 31, 23, 0)), "AEDT");
+}
+
+#[test]
+fn from_posix_fixed() {
+    let zone = TimeZone::from_posix("<+0545>-5:45").unwrap();
+    assert!(zone.is_fixed());
+    assert_eq!(zone.offset(at(2030, Month::May, 5, 5, 5)), 5 * 3600 + 45 * 60);
+}
+
+#[test]
+fn from_posix_permanent_daylight() {
+    let zone = TimeZone::from_posix("EST5EDT,0/0,J365/25").unwrap();
+    assert_eq!(zone.name(at(2030, Month::January, 1, 12, 0)), "EDT");
+    assert_eq!(zone.name(at(2030, Month::December, 31, 12, 0)), "EDT");
+    assert!(!zone.convert_local(at(2031, Month::January, 1, 0, 30)).is_ambiguous());
+}
+
+#[test]
+fn from_posix_range_ends() {
+    let zone = TimeZone::from_posix("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+    assert_eq!(zone.offset(LocalDateTime::MAX), 3600);
+    assert_eq!(zone.offset(LocalDateTime::MIN), 3600);
+    assert!(zone.convert_local(LocalDateTime::MAX).unwrap_precise().to_instant() < Instant::MAX);
+
+    let last = zone.previous_transition(Instant::MAX).unwrap();
+    assert_eq!(LocalDateTime::from_instant(last.instant).date(),
+               LocalDate::ymd(LocalDate::MAX.year(), Month::October, 31).unwrap());
+    assert!(zone.next_transition(Instant::MAX).is_none());
+    assert!(zone.next_transition(Instant::at(i64::MAX)).is_none());
+
+    let first = zone.next_transition(Instant::MIN).unwrap();
+    assert_eq!(LocalDateTime::from_instant(first.instant).year(), LocalDate::MIN.year());
+    assert!(zone.previous_transition(Instant::MIN).is_none());
+}
+
+#[test]
+fn from_posix_instant_range_ends() {
+    let zone = TimeZone::from_posix("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+
+    let last = zone.at(Instant::MAX);
+    assert_eq!(last.offset(), 3600);
+    assert_eq!(last.local_datetime(), LocalDateTime::MAX);
+
+    let beyond = zone.at(Instant::at(i64::MAX / 2));
+    assert_eq!(beyond.local_datetime(), LocalDateTime::MAX);
+
+    let first = zone.at(Instant::MIN);
+    assert_eq!(first.offset(), 3600);
+    assert_eq!(first.to_instant(), Instant::MIN);
+}
+
+#[test]
+fn from_posix_fixed_range_ends() {
+    let east = TimeZone::from_posix("<+01>-1").unwrap();
+    assert_eq!(east.at(Instant::MAX).local_datetime(), LocalDateTime::MAX);
+
+    let west = TimeZone::from_posix("<-01>1").unwrap();
+    assert_eq!(west.at(Instant::MIN).local_datetime(), LocalDateTime::MIN);
+    assert_eq!(west.at(Instant::MAX).to_instant(), Instant::MAX);
+}
+
+#[test]
+fn from_posix_invalid() {
+    assert!(TimeZone::from_posix("Europe/London").is_err());
+}
@@ -0,0 +1,121 @@
+extern crate datetime;
+use datetime::{LocalDate, LocalDateTime, LocalTime, Month};
+use datetime::zone::{TimeZoneSource, Error};
+use datetime::zone::registry::TimeZoneRegistry;
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::thread;
+
+
+fn registry() -> TimeZoneRegistry {
+    TimeZoneRegistry::with_directories(vec![ PathBuf::from("./tests/zoneinfo") ])
+}
+
+fn summer() -> LocalDateTime {
+    LocalDateTime::new(LocalDate::ymd(2024, Month::July, 1).unwrap(), LocalTime::midnight())
+}
+
+#[test]
+fn get() {
+    let zone = registry().get("Europe/London").unwrap();
+    assert_eq!(zone.zone_name(), Some("Europe/London"));
+    assert_eq!(zone.offset(summer()), 3600);
+}
+
+#[test]
+fn cached() {
+    let registry = registry();
+    assert!(!registry.contains("Europe/London"));
+
+    let first = registry.get("Europe/London").unwrap();
+    let second = registry.get("Europe/London").unwrap();
+    assert!(registry.contains("Europe/London"));
+
+    match (first.0, second.0) {
+        (TimeZoneSource::Runtime(a), TimeZoneSource::Runtime(b)) => assert!(Arc::ptr_eq(&a, &b)),
+        _ => panic!("expected runtime zones"),
+    }
+}
+
+#[test]
+fn missing() {
+    let registry = registry();
+    match registry.get("Europe/Nowhere") {
+        Err(Error::NotFound(name)) => assert_eq!(name, "Europe/Nowhere"),
+        result => panic!("unexpected {:?}", result),
+    }
+    assert!(registry.cached_names().is_empty());
+}
+
+#[test]
+fn invalid_name() {
+    assert!(matches!(registry().get("../zoneinfo/Europe/London"), Err(Error::InvalidName(_))));
+}
+
+#[test]
+fn cached_names() {
+    let registry = registry();
+    registry.get("Europe/London").unwrap();
+    registry.get("America/New_York").unwrap();
+    assert_eq!(registry.cached_names(), vec![ "America/New_York", "Europe/London" ]);
+
+    registry.clear();
+    assert!(registry.cached_names().is_empty());
+}
+
+#[test]
+fn across_threads() {
+    let registry = Arc::new(registry());
+    let handles: Vec<_> = (0 .. 8).map(|_| {
+        let registry = Arc::clone(&registry);
+        thread::spawn(move || registry.get("America/New_York").unwrap().offset(summer()))
+    }).collect();
+
+    for handle in handles {
+        assert_eq!(handle.join().unwrap(), -4 * 3600);
+    }
+    assert_eq!(registry.cached_names(), vec![ "America/New_York" ]);
+}
+
+#[test]
+fn reload() {
+    let directory = env::temp_dir().join(format!("datetime-registry-{}", std::process::id()));
+    fs::create_dir_all(directory.join("Test")).unwrap();
+    fs::copy("./tests/zoneinfo/Europe/London", directory.join("Test/Zone")).unwrap();
+    fs::copy("./tests/zoneinfo/Europe/London", directory.join("Test/Gone")).unwrap();
+
+    let registry = TimeZoneRegistry::with_directories(vec![ directory.clone() ]);
+    let before = registry.get("Test/Zone").unwrap();
+    registry.get("Test/Gone").unwrap();
+    assert_eq!(before.offset(summer()), 3600);
+
+    fs::copy("./tests/zoneinfo/America/New_York", directory.join("Test/Zone")).unwrap();
+    fs::remove_file(directory.join("Test/Gone")).unwrap();
+    assert_eq!(registry.get("Test/Zone").unwrap().offset(summer()), 3600);
+
+    registry.reload().unwrap();
+    assert_eq!(registry.get("Test/Zone").unwrap().offset(summer()), -4 * 3600);
+    assert_eq!(registry.cached_names(), vec![ "Test/Zone" ]);
+    assert_eq!(before.offset(summer()), 3600);
+
+    fs::remove_dir_all(directory).unwrap();
+}
+
+#[test]
+fn failed_reload_keeps_cache() {
+    let directory = env::temp_dir().join(format!("datetime-registry-failed-{}", std::process::id()));
+    fs::create_dir_all(directory.join("Test")).unwrap();
+    fs::copy("./tests/zoneinfo/Europe/London", directory.join("Test/Zone")).unwrap();
+
+    let registry = TimeZoneRegistry::with_directories(vec![ directory.clone() ]);
+    registry.get("Test/Zone").unwrap();
+
+    fs::write(directory.join("Test/Zone"), b"not a tzif file").unwrap();
+    assert!(matches!(registry.reload(), Err(Error::Tzif(_))));
+    assert_eq!(registry.get("Test/Zone").unwrap().offset(summer()), 3600);
+
+    fs::remove_dir_all(directory).unwrap();
+}
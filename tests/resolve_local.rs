@@ -0,0 +1,138 @@
+extern crate datetime;
+use datetime::zone::{TimeZone, Disambiguation, ResolveError};
+use datetime::zone::tzdata::Database;
+use datetime::{LocalDateTime, LocalDate, LocalTime, Month, Instant, TimePiece};
+
+use std::path::Path;
+
+
+fn london() -> TimeZone {
+    TimeZone::named_in(Path::new("./tests/zoneinfo"), "Europe/London").unwrap()
+}
+
+fn local(month: Month, day: i8, hour: i8, minute: i8) -> LocalDateTime {
+    LocalDateTime::new(LocalDate::ymd(2027, month, day).unwrap(), LocalTime::hm(hour, minute).unwrap())
+}
+
+
+#[test]
+fn precise() {
+    let zone = london();
+    let summer = local(Month::July, 1, 12, 0);
+
+    for &disambiguation in &[ Disambiguation::Earlier, Disambiguation::Later, Disambiguation::Compatible, Disambiguation::Reject ] {
+        let zoned = zone.resolve_local(summer, disambiguation).unwrap();
+        assert_eq!(zoned.hour(), 12);
+        assert_eq!(zoned.to_instant(), Instant::at(1_814_439_600));
+    }
+}
+
+#[test]
+fn skipped_earlier() {
+    let zoned = london().resolve_local(local(Month::March, 28, 1, 30), Disambiguation::Earlier).unwrap();
+    assert_eq!((zoned.hour(), zoned.minute()), (0, 30));
+    assert_eq!(zoned.to_instant(), Instant::at(1_806_193_800));
+}
+
+#[test]
+fn skipped_later() {
+    let zoned = london().resolve_local(local(Month::March, 28, 1, 30), Disambiguation::Later).unwrap();
+    assert_eq!((zoned.hour(), zoned.minute()), (2, 30));
+    assert_eq!(zoned.to_instant(), Instant::at(1_806_197_400));
+}
+
+#[test]
+fn skipped_compatible() {
+    let zoned = london().resolve_local(local(Month::March, 28, 1, 30), Disambiguation::Compatible).unwrap();
+    assert_eq!((zoned.hour(), zoned.minute()), (2, 30));
+}
+
+#[test]
+fn skipped_reject() {
+    let time = local(Month::March, 28, 1, 30);
+    match london().resolve_local(time, Disambiguation::Reject) {
+        Err(ResolveError::Skipped(t))  => assert_eq!(t, time),
+        other                          => panic!("expected Skipped, got {:?}", other),
+    }
+}
+
+#[test]
+fn ambiguous_earlier() {
+    let zoned = london().resolve_local(local(Month::October, 31, 1, 30), Disambiguation::Earlier).unwrap();
+    assert_eq!(zoned.to_instant(), Instant::at(1_824_942_600));
+}
+
+#[test]
+fn ambiguous_later() {
+    let zoned = london().resolve_local(local(Month::October, 31, 1, 30), Disambiguation::Later).unwrap();
+    assert_eq!(zoned.to_instant(), Instant::at(1_824_946_200));
+}
+
+#[test]
+fn ambiguous_compatible() {
+    let zoned = london().resolve_local(local(Month::October, 31, 1, 30), Disambiguation::Compatible).unwrap();
+    assert_eq!(zoned.to_instant(), Instant::at(1_824_942_600));
+}
+
+#[test]
+fn ambiguous_reject() {
+    let time = local(Month::October, 31, 1, 30);
+    match london().resolve_local(time, Disambiguation::Reject) {
+        Err(ResolveError::Ambiguous(t))  => assert_eq!(t, time),
+        other                            => panic!("expected Ambiguous, got {:?}", other),
+    }
+}
+
+
+fn compiled(source: &str, name: &str) -> TimeZone {
+    let mut database = Database::new();
+    database.parse(source).unwrap();
+    database.time_zone(name).unwrap()
+}
+
+/// Two transitions twelve hours apart, the first skipping one hour and
+/// the second skipping two.
+fn close_together() -> TimeZone {
+    compiled("Zone Test/Close 0 - A 2027 Mar 1 0:00\n\
+                        1:00 - B 2027 Mar 1 12:00\n\
+                        3:00 - C\n", "Test/Close")
+}
+
+#[test]
+fn skipped_close_together_first() {
+    let zone = close_together();
+
+    let later = zone.resolve_local(local(Month::March, 1, 0, 30), Disambiguation::Later).unwrap();
+    assert_eq!((later.hour(), later.minute()), (1, 30));
+    assert_eq!(later.to_instant(), Instant::at(1_803_861_000));
+
+    let earlier = zone.resolve_local(local(Month::March, 1, 0, 30), Disambiguation::Earlier).unwrap();
+    assert_eq!((earlier.hour(), earlier.minute()), (23, 30));
+    assert_eq!(earlier.to_instant(), Instant::at(1_803_857_400));
+}
+
+#[test]
+fn skipped_close_together_second() {
+    let zone = close_together();
+
+    let later = zone.resolve_local(local(Month::March, 1, 12, 30), Disambiguation::Later).unwrap();
+    assert_eq!((later.hour(), later.minute()), (14, 30));
+    assert_eq!(later.to_instant(), Instant::at(1_803_900_600));
+
+    let earlier = zone.resolve_local(local(Month::March, 1, 12, 30), Disambiguation::Earlier).unwrap();
+    assert_eq!((earlier.hour(), earlier.minute()), (10, 30));
+    assert_eq!(earlier.to_instant(), Instant::at(1_803_893_400));
+}
+
+#[test]
+fn skipped_out_of_range() {
+    let zone = compiled("Zone Test/End 0 - A 999999999 Dec 31 23:30\n\
+                                      1:00 - B\n", "Test/End");
+
+    let time = LocalDateTime::new(LocalDate::MAX, LocalTime::hm(23, 45).unwrap());
+    assert_eq!(zone.resolve_local(time, Disambiguation::Later).unwrap_err(), ResolveError::OutOfRange);
+    assert_eq!(zone.resolve_local(time, Disambiguation::Compatible).unwrap_err(), ResolveError::OutOfRange);
+
+    let earlier = zone.resolve_local(time, Disambiguation::Earlier).unwrap();
+    assert_eq!((earlier.hour(), earlier.minute()), (22, 45));
+}
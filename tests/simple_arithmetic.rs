@@ -11,3 +11,14 @@ fn addition() {
 fn subtraction() {
     assert_eq!(Instant::at(20), Instant::at(50) - Duration::of(30))
 }
+
+#[test]
+fn difference() {
+    assert_eq!(Instant::at_ms(50, 250) - Instant::at_ms(20, 500), Duration::of_ms(29, 750))
+}
+
+#[test]
+fn elapsed() {
+    let (seconds, _) = Instant::at(1_000_000_000).elapsed().lengths();
+    assert!(seconds > 0)
+}
@@ -43,7 +43,8 @@ const TEST_ZONESET: &'static StaticTimeZone<'static> = &StaticTimeZone {
                 is_dst: false,
                 name: Cow::Borrowed("ZONE_A"),
             }),
-        ]
+        ],
+        extension: None,
     }
 };
 
@@ -0,0 +1,96 @@
+extern crate datetime;
+use datetime::zone::TimeZone;
+use datetime::Instant;
+
+use std::path::Path;
+
+
+fn zone(name: &str) -> TimeZone {
+    TimeZone::named_in(Path::new("./tests/zoneinfo"), name).unwrap()
+}
+
+#[test]
+fn a_year_of_changes() {
+    let london = zone("Europe/London");
+    let changes: Vec<_> = london.transitions(Instant::at(1_798_761_600), Instant::at(1_830_297_600)).collect();
+
+    assert_eq!(changes.len(), 2);
+    assert_eq!(changes[0].instant, Instant::at(1_806_195_600));
+    assert_eq!(changes[0].before.name, "GMT");
+    assert_eq!(changes[0].after.name, "BST");
+    assert_eq!(changes[1].instant, Instant::at(1_824_944_400));
+    assert_eq!(changes[1].before.offset, 3600);
+    assert_eq!(changes[1].after.offset, 0);
+}
+
+#[test]
+fn range_is_half_open() {
+    let london = zone("Europe/London");
+    let start = Instant::at(1_806_195_600);
+    let end = Instant::at(1_824_944_400);
+
+    let changes: Vec<_> = london.transitions(start, end).collect();
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].instant, start);
+}
+
+#[test]
+fn from_the_table() {
+    let new_york = zone("America/New_York");
+    let changes: Vec<_> = new_york.transitions(Instant::at(1_167_609_600), Instant::at(1_199_145_600)).collect();
+
+    let instants: Vec<_> = changes.iter().map(|t| t.instant).collect();
+    assert_eq!(instants, vec![ Instant::at(1_173_596_400), Instant::at(1_194_156_000) ]);
+    assert_eq!(changes[0].after.name, "EDT");
+}
+
+#[test]
+fn next() {
+    let new_york = zone("America/New_York");
+    let next = new_york.next_transition(Instant::at(2_525_000_000)).unwrap();
+    assert_eq!(next.instant, Instant::at(2_530_767_600));
+    assert_eq!(next.after.offset, -4 * 3600);
+
+    let after_that = new_york.next_transition(next.instant).unwrap();
+    assert!(after_that.instant > next.instant);
+    assert_eq!(after_that.after.offset, -5 * 3600);
+}
+
+#[test]
+fn previous() {
+    let new_york = zone("America/New_York");
+    let previous = new_york.previous_transition(Instant::at(2_530_767_601)).unwrap();
+    assert_eq!(previous.instant, Instant::at(2_530_767_600));
+
+    let before_that = new_york.previous_transition(previous.instant).unwrap();
+    assert!(before_that.instant < previous.instant);
+    assert_eq!(before_that.after.offset, -5 * 3600);
+}
+
+#[test]
+fn previous_includes_same_second() {
+    let london = zone("Europe/London");
+    let previous = london.previous_transition(Instant::at_ms(1_806_195_600, 500)).unwrap();
+    assert_eq!(previous.instant, Instant::at(1_806_195_600));
+}
+
+#[test]
+fn nothing_before_the_first() {
+    let london = zone("Europe/London");
+    assert!(london.previous_transition(Instant::at(-5_000_000_000)).is_none());
+}
+
+#[test]
+fn fixed_zone_has_none() {
+    let utc = TimeZone::from_posix("UTC0").unwrap();
+    assert!(utc.next_transition(Instant::at(0)).is_none());
+    assert!(utc.previous_transition(Instant::at(0)).is_none());
+    assert_eq!(utc.transitions(Instant::at(0), Instant::at(2_000_000_000)).count(), 0);
+}
+
+#[test]
+fn posix_zone_goes_on_forever() {
+    let zone = TimeZone::from_posix("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+    let count = zone.transitions(Instant::at(0), Instant::at(31_536_000 * 10)).count();
+    assert_eq!(count, 20);
+}
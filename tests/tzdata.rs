@@ -0,0 +1,57 @@
+extern crate datetime;
+use datetime::zone::TimeZone;
+use datetime::zone::tzdata::{Database, Error};
+use datetime::{LocalDateTime, Instant};
+
+use std::fs;
+use std::path::Path;
+
+mod common;
+
+
+fn database() -> Database {
+    let source = fs::read_to_string("./tests/tzdata/subset.zi").unwrap();
+    let mut database = Database::new();
+    database.parse(&source).unwrap();
+    database
+}
+
+fn compare(name: &str) {
+    let compiled = database().time_zone(name).unwrap();
+    let system = TimeZone::named_in(Path::new("./tests/zoneinfo"), name).unwrap();
+    common::assert_same_zone(name, &compiled, &system);
+}
+
+#[test]
+fn london() {
+    compare("Europe/London");
+}
+
+#[test]
+fn new_york() {
+    compare("America/New_York");
+}
+
+#[test]
+fn names() {
+    let database = database();
+    assert_eq!(database.zone_names(), vec![ "America/New_York", "Europe/London" ]);
+    assert_eq!(database.links(), vec![ ("GB", "Europe/London"), ("US/Eastern", "America/New_York") ]);
+}
+
+#[test]
+fn link() {
+    let zone = database().time_zone("GB").unwrap();
+    assert_eq!(zone.zone_name(), Some("GB"));
+
+    let summer = LocalDateTime::from_instant(Instant::at(1_467_331_200));
+    assert_eq!(zone.offset(summer), 3600);
+}
+
+#[test]
+fn unknown_zone() {
+    match database().time_zone("Europe/Atlantis") {
+        Err(Error::UnknownZone(name))  => assert_eq!(name, "Europe/Atlantis"),
+        other                          => panic!("expected UnknownZone, got {:?}", other),
+    }
+}
@@ -0,0 +1,68 @@
+extern crate datetime;
+use datetime::zone::{TimeZone, tzif};
+use datetime::{LocalDateTime, LocalDate, LocalTime, Month};
+
+use std::fs::File;
+use std::io::Read;
+
+
+fn read_fixture(name: &str) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    let mut file = File::open(format!("./tests/zoneinfo/{}", name)).unwrap();
+    let _ = file.read_to_end(&mut bytes).unwrap();
+    bytes
+}
+
+fn at(year: i64, month: Month, day: i8, hour: i8) -> LocalDateTime {
+    LocalDateTime::new(
+        LocalDate::ymd(year, month, day).unwrap(),
+        LocalTime::hms(hour, 0, 0).unwrap(),
+    )
+}
+
+
+#[test]
+fn london_version() {
+    let tzif = tzif::parse(&read_fixture("Europe/London")).unwrap();
+    assert!(tzif.version >= 2);
+    assert_eq!(tzif.footer, Some("GMT0BST,M3.5.0/1,M10.5.0".to_string()));
+}
+
+#[test]
+fn london_offsets() {
+    let zone = TimeZone::from_tzif(Some("Europe/London".into()), &read_fixture("Europe/London")).unwrap();
+    assert_eq!(zone.zone_name(), Some("Europe/London"));
+
+    assert_eq!(zone.offset(at(2010, Month::January, 1, 12)), 0);
+    assert_eq!(zone.name(at(2010, Month::January, 1, 12)), "GMT");
+
+    assert_eq!(zone.offset(at(2010, Month::June, 9, 12)), 3600);
+    assert_eq!(zone.name(at(2010, Month::June, 9, 12)), "BST");
+}
+
+#[test]
+fn london_local_conversion() {
+    let zone = TimeZone::from_tzif(None, &read_fixture("Europe/London")).unwrap();
+
+    let instant = zone.convert_local(at(2010, Month::June, 9, 15)).unwrap_precise().to_instant();
+    assert_eq!(instant, at(2010, Month::June, 9, 14).to_instant());
+
+    assert!(zone.convert_local(at(2010, Month::March, 28, 1)).is_impossible());
+    assert!(zone.convert_local(at(2010, Month::October, 31, 1)).is_ambiguous());
+}
+
+#[test]
+fn new_york_offsets() {
+    let zone = TimeZone::from_tzif(None, &read_fixture("America/New_York")).unwrap();
+    assert_eq!(zone.offset(at(2015, Month::January, 15, 12)), -5 * 3600);
+    assert_eq!(zone.name(at(2015, Month::January, 15, 12)), "EST");
+    assert_eq!(zone.offset(at(2015, Month::July, 15, 12)), -4 * 3600);
+    assert_eq!(zone.name(at(2015, Month::July, 15, 12)), "EDT");
+}
+
+#[test]
+fn corrupt() {
+    let mut bytes = read_fixture("America/New_York");
+    bytes.truncate(bytes.len() / 2);
+    assert_eq!(TimeZone::from_tzif(None, &bytes).unwrap_err(), tzif::Error::Truncated);
+}
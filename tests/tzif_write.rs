@@ -0,0 +1,114 @@
+extern crate datetime;
+use datetime::zone::{FixedTimespan, TimeZone};
+use datetime::zone::runtime::{OwnedTimeZone, OwnedFixedTimespanSet};
+use datetime::zone::tzif::{self, Tzif};
+use datetime::{Instant, LocalDateTime};
+
+use std::borrow::Cow;
+use std::fs;
+
+
+fn read(bytes: &[u8], name: &str) -> OwnedTimeZone {
+    tzif::parse(bytes).unwrap().to_time_zone(Some(name.to_owned())).unwrap()
+}
+
+fn timespan(offset: i64, is_dst: bool, name: &'static str) -> FixedTimespan<'static> {
+    FixedTimespan { offset, is_dst, name: Cow::Borrowed(name) }
+}
+
+/// A zone with made-up transitions, including ones outside the range of
+/// 32-bit timestamps.
+fn artificial() -> OwnedTimeZone {
+    let set = OwnedFixedTimespanSet {
+        first: timespan(0, false, "AAA"),
+        rest: vec![
+            (-5_000_000_000, timespan(1800, false, "BBB")),
+            (0,              timespan(3600, true,  "CCC")),
+            (1_000_000_000,  timespan(1800, false, "BBB")),
+            (5_000_000_000,  timespan(-9000, false, "-0230")),
+        ],
+        extension: None,
+    };
+
+    OwnedTimeZone::new(Some("Test/Artificial".into()), set).unwrap()
+}
+
+#[test]
+fn artificial_round_trip() {
+    let zone = artificial();
+    let bytes = tzif::write(&zone).unwrap();
+    assert_eq!(&bytes[.. 5], b"TZif2");
+    assert_eq!(read(&bytes, "Test/Artificial"), zone);
+}
+
+#[test]
+fn footer_round_trip() {
+    let mut zone = artificial();
+    zone.fixed_timespans.extension = Some("<-0230>2:30<-0130>,M3.2.0,M11.1.0".parse().unwrap());
+
+    let bytes = tzif::write(&zone).unwrap();
+    assert!(bytes.ends_with(b"\n<-0230>2:30<-0130>,M3.2.0,M11.1.0\n"));
+    assert_eq!(read(&bytes, "Test/Artificial"), zone);
+}
+
+#[test]
+fn without_footer() {
+    let zone = read(&fs::read("./tests/zoneinfo/Europe/London").unwrap(), "Europe/London");
+    assert!(zone.fixed_timespans.extension.is_some());
+
+    let mut tzif = Tzif::from_time_zone(&zone);
+    tzif.footer = None;
+    let bytes = tzif.to_bytes().unwrap();
+    assert!(bytes.ends_with(b"\n\n"));
+    assert_eq!(read(&bytes, "Europe/London").fixed_timespans.extension, None);
+}
+
+#[test]
+fn system_zones_round_trip() {
+    for name in &[ "Europe/London", "America/New_York" ] {
+        let original = fs::read(format!("./tests/zoneinfo/{}", name)).unwrap();
+        let zone = read(&original, name);
+        assert_eq!(read(&tzif::write(&zone).unwrap(), name), zone, "{}", name);
+    }
+}
+
+#[test]
+fn written_file_gives_same_offsets() {
+    let zone = read(&fs::read("./tests/zoneinfo/America/New_York").unwrap(), "America/New_York");
+    let written = TimeZone::from_tzif(Some("America/New_York".into()), &tzif::write(&zone).unwrap()).unwrap();
+    let original = TimeZone::named_in("./tests/zoneinfo".as_ref(), "America/New_York").unwrap();
+
+    let mut time = -2_208_988_800;
+    while time < 4_102_444_800 {
+        let datetime = LocalDateTime::from_instant(Instant::at(time));
+        assert_eq!(written.offset(datetime), original.offset(datetime));
+        assert_eq!(written.name(datetime), original.name(datetime));
+        time += 7 * 24 * 60 * 60;
+    }
+}
+
+#[test]
+fn version_one_block() {
+    // Reading only the 32-bit block should still give the right offset in
+    // 1970, after the early transition that doesn’t fit.
+    let mut bytes = tzif::write(&artificial()).unwrap();
+    bytes[4] = 0;
+    let v1 = tzif::parse(&bytes).unwrap();
+    assert_eq!(v1.transitions.len(), 3);
+    assert_eq!(v1.transitions[0].0, i64::from(i32::MIN));
+    assert_eq!(v1.local_time_types[v1.transitions[0].1].name, "BBB");
+}
+
+#[test]
+fn version_one_out_of_range() {
+    let mut tzif = Tzif::from_time_zone(&artificial());
+    tzif.version = 1;
+    tzif.footer = None;
+    assert_eq!(tzif.to_bytes(), Err(tzif::Error::TimeOutOfRange));
+
+    tzif.transitions.retain(|t| t.0.abs() < 2_000_000_000);
+    assert!(tzif.to_bytes().is_ok());
+
+    tzif.leap_seconds.push((5_000_000_000, 1));
+    assert_eq!(tzif.to_bytes(), Err(tzif::Error::TimeOutOfRange));
+}
@@ -0,0 +1,71 @@
+extern crate datetime;
+use datetime::zone::windows::{to_zone_name, from_zone_name, zone_names, windows_ids};
+
+
+#[test]
+fn main_zone() {
+    assert_eq!(to_zone_name("W. Europe Standard Time", None), Some("Europe/Berlin"));
+}
+
+#[test]
+fn zone_in_territory() {
+    assert_eq!(to_zone_name("W. Europe Standard Time", Some("CH")), Some("Europe/Zurich"));
+}
+
+#[test]
+fn unknown_territory() {
+    assert_eq!(to_zone_name("W. Europe Standard Time", Some("JP")), Some("Europe/Berlin"));
+}
+
+#[test]
+fn unknown_id() {
+    assert_eq!(to_zone_name("Mars Standard Time", None), None);
+    assert_eq!(to_zone_name("Mars Standard Time", Some("US")), None);
+}
+
+#[test]
+fn renamed_zone() {
+    assert_eq!(to_zone_name("India Standard Time", None), Some("Asia/Kolkata"));
+}
+
+#[test]
+fn every_zone_in_territory() {
+    let zones = zone_names("Eastern Standard Time", "US");
+    assert_eq!(zones[0], "America/New_York");
+    assert!(zones.contains(&"America/Kentucky/Louisville"));
+    assert!(zone_names("Eastern Standard Time", "JP").is_empty());
+}
+
+#[test]
+fn back_to_windows() {
+    assert_eq!(from_zone_name("Europe/Zurich"), Some("W. Europe Standard Time"));
+    assert_eq!(from_zone_name("Asia/Tokyo"), Some("Tokyo Standard Time"));
+}
+
+#[test]
+fn back_from_either_name() {
+    assert_eq!(from_zone_name("Asia/Kolkata"), Some("India Standard Time"));
+    assert_eq!(from_zone_name("Asia/Calcutta"), Some("India Standard Time"));
+}
+
+#[test]
+fn no_windows_zone() {
+    assert_eq!(from_zone_name("Nowhere/Special"), None);
+}
+
+#[test]
+fn ids() {
+    let ids = windows_ids();
+    assert!(ids.contains(&"GMT Standard Time"));
+    assert!(ids.windows(2).all(|w| w[0] < w[1]));
+}
+
+#[test]
+fn loads_as_time_zone() {
+    use datetime::zone::TimeZone;
+    use std::path::Path;
+
+    let name = to_zone_name("GMT Standard Time", None).unwrap();
+    let zone = TimeZone::named_in(Path::new("./tests/zoneinfo"), name).unwrap();
+    assert_eq!(zone.zone_name(), Some("Europe/London"));
+}
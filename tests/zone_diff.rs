@@ -0,0 +1,73 @@
+extern crate datetime;
+use datetime::{LocalDate, LocalDateTime, LocalTime, Month, Instant, DatePiece};
+use datetime::zone::TimeZone;
+use datetime::zone::diff::compare;
+
+use std::path::Path;
+
+
+fn utc(year: i64, month: Month, day: i8, hour: i8) -> Instant {
+    LocalDateTime::new(LocalDate::ymd(year, month, day).unwrap(), LocalTime::hm(hour, 0).unwrap()).to_instant()
+}
+
+#[test]
+fn moved_daylight_saving() {
+    // The US rules before and after 2007.
+    let old = TimeZone::from_posix("EST5EDT,M4.1.0,M10.5.0").unwrap();
+    let new = TimeZone::from_posix("EST5EDT,M3.2.0,M11.1.0").unwrap();
+
+    let differences = compare(&old, &new, utc(2030, Month::January, 1, 0), utc(2031, Month::January, 1, 0));
+    assert_eq!(differences.len(), 2);
+
+    assert_eq!(differences[0].from, utc(2030, Month::March, 10, 7));
+    assert_eq!(differences[0].to, utc(2030, Month::April, 7, 7));
+    assert_eq!(differences[0].old.name, "EST");
+    assert_eq!(differences[0].new.name, "EDT");
+
+    assert_eq!(differences[1].from, utc(2030, Month::October, 27, 6));
+    assert_eq!(differences[1].to, utc(2030, Month::November, 3, 6));
+    assert_eq!(differences[1].old.offset, -5 * 3600);
+    assert_eq!(differences[1].new.offset, -4 * 3600);
+}
+
+#[test]
+fn affected_appointments() {
+    let old = TimeZone::from_posix("EST5EDT,M4.1.0,M10.5.0").unwrap();
+    let new = TimeZone::from_posix("EST5EDT,M3.2.0,M11.1.0").unwrap();
+    let differences = compare(&old, &new, utc(2030, Month::January, 1, 0), utc(2031, Month::January, 1, 0));
+
+    let affected = old.at(utc(2030, Month::March, 20, 15));
+    let unaffected = old.at(utc(2030, Month::June, 1, 15));
+    assert!(differences.iter().any(|d| d.contains(affected.to_instant())));
+    assert!(!differences.iter().any(|d| d.contains(unaffected.to_instant())));
+}
+
+#[test]
+fn abolished_daylight_saving() {
+    let old = TimeZone::named_in(Path::new("./tests/zoneinfo"), "Europe/London").unwrap();
+    let new = TimeZone::from_posix("GMT0").unwrap();
+
+    let differences = compare(&old, &new, utc(2030, Month::January, 1, 0), utc(2032, Month::January, 1, 0));
+    assert_eq!(differences.len(), 2);
+    assert!(differences.iter().all(|d| d.old.name == "BST" && d.new.name == "GMT"));
+    assert_eq!(differences[0].from, utc(2030, Month::March, 31, 1));
+    assert_eq!(differences[1].to, utc(2031, Month::October, 26, 1));
+}
+
+#[test]
+fn identical_rules() {
+    let file = TimeZone::named_in(Path::new("./tests/zoneinfo"), "America/New_York").unwrap();
+    let rule = TimeZone::from_posix("EST5EDT,M3.2.0,M11.1.0").unwrap();
+    assert!(compare(&file, &rule, utc(2030, Month::January, 1, 0), utc(2040, Month::January, 1, 0)).is_empty());
+}
+
+#[test]
+fn end_of_range() {
+    let old = TimeZone::from_posix("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+    let new = TimeZone::from_posix("CET-1").unwrap();
+
+    let differences = compare(&old, &new, utc(LocalDate::MAX.year(), Month::January, 1, 0), Instant::MAX);
+    assert_eq!(differences.len(), 1);
+    assert_eq!(differences[0].old.name, "CEST");
+    assert_eq!(differences[0].from, utc(LocalDate::MAX.year(), Month::March, 28, 1));
+}
@@ -0,0 +1,127 @@
+extern crate datetime;
+use datetime::zone::{TimeZone, ZonedDateTime, Disambiguation, ResolveError};
+use datetime::{LocalDateTime, LocalDate, LocalTime, Month, Duration, DatePiece, TimePiece};
+
+use std::path::Path;
+
+
+fn london() -> TimeZone {
+    TimeZone::named_in(Path::new("./tests/zoneinfo"), "Europe/London").unwrap()
+}
+
+fn local(year: i64, month: Month, day: i8, hour: i8, minute: i8) -> LocalDateTime {
+    LocalDateTime::new(LocalDate::ymd(year, month, day).unwrap(), LocalTime::hm(hour, minute).unwrap())
+}
+
+fn zoned(year: i64, month: Month, day: i8, hour: i8, minute: i8) -> ZonedDateTime<'static> {
+    london().resolve_local(local(year, month, day, hour, minute), Disambiguation::Reject).unwrap()
+}
+
+fn wall_clock(zoned: &ZonedDateTime) -> (i64, Month, i8, i8, i8) {
+    (zoned.year(), zoned.month(), zoned.day(), zoned.hour(), zoned.minute())
+}
+
+
+#[test]
+fn add_across_spring_forward() {
+    let later = zoned(2027, Month::March, 28, 0, 30) + Duration::of(3600);
+    assert_eq!(wall_clock(&later), (2027, Month::March, 28, 2, 30));
+}
+
+#[test]
+fn add_across_fall_back() {
+    let later = zoned(2027, Month::October, 31, 0, 30) + Duration::of(2 * 3600);
+    assert_eq!(wall_clock(&later), (2027, Month::October, 31, 1, 30));
+}
+
+#[test]
+fn subtract_across_spring_forward() {
+    let earlier = zoned(2027, Month::March, 28, 2, 30) - Duration::of(3600);
+    assert_eq!(wall_clock(&earlier), (2027, Month::March, 28, 0, 30));
+}
+
+#[test]
+fn add_keeps_elapsed_time() {
+    let start = zoned(2027, Month::March, 27, 12, 0);
+    let end = start.clone() + Duration::of(86400);
+    assert_eq!(end.to_instant().seconds() - start.to_instant().seconds(), 86400);
+    assert_eq!(wall_clock(&end), (2027, Month::March, 28, 13, 0));
+}
+
+#[test]
+fn tomorrow_at_the_same_time() {
+    let start = zoned(2027, Month::March, 27, 12, 0);
+    let tomorrow = start.plus_days(1, Disambiguation::Compatible).unwrap();
+    assert_eq!(wall_clock(&tomorrow), (2027, Month::March, 28, 12, 0));
+    assert_eq!(tomorrow.to_instant().seconds() - start.to_instant().seconds(), 23 * 3600);
+}
+
+#[test]
+fn yesterday_at_the_same_time() {
+    let start = zoned(2027, Month::October, 31, 12, 0);
+    let yesterday = start.plus_days(-1, Disambiguation::Compatible).unwrap();
+    assert_eq!(wall_clock(&yesterday), (2027, Month::October, 30, 12, 0));
+    assert_eq!(start.to_instant().seconds() - yesterday.to_instant().seconds(), 25 * 3600);
+}
+
+#[test]
+fn days_into_a_gap() {
+    let start = zoned(2027, Month::March, 27, 1, 30);
+
+    let compatible = start.plus_days(1, Disambiguation::Compatible).unwrap();
+    assert_eq!(wall_clock(&compatible), (2027, Month::March, 28, 2, 30));
+
+    match start.plus_days(1, Disambiguation::Reject) {
+        Err(ResolveError::Skipped(t))  => assert_eq!(t, local(2027, Month::March, 28, 1, 30)),
+        other                          => panic!("expected Skipped, got {:?}", other),
+    }
+}
+
+#[test]
+fn days_into_an_overlap() {
+    let start = zoned(2027, Month::October, 30, 1, 30);
+    let earlier = start.plus_days(1, Disambiguation::Earlier).unwrap();
+    let later = start.plus_days(1, Disambiguation::Later).unwrap();
+    assert_eq!(later.to_instant().seconds() - earlier.to_instant().seconds(), 3600);
+}
+
+#[test]
+fn next_month() {
+    let start = zoned(2027, Month::March, 15, 9, 0);
+    let next = start.plus_months(1, Disambiguation::Reject).unwrap();
+    assert_eq!(wall_clock(&next), (2027, Month::April, 15, 9, 0));
+}
+
+#[test]
+fn month_end_is_clamped() {
+    let start = zoned(2027, Month::January, 31, 9, 0);
+    let next = start.plus_months(1, Disambiguation::Reject).unwrap();
+    assert_eq!(wall_clock(&next), (2027, Month::February, 28, 9, 0));
+
+    let leap = zoned(2028, Month::January, 31, 9, 0).plus_months(1, Disambiguation::Reject).unwrap();
+    assert_eq!(wall_clock(&leap), (2028, Month::February, 29, 9, 0));
+}
+
+#[test]
+fn months_backwards_across_a_year() {
+    let start = zoned(2027, Month::January, 15, 9, 0);
+    let earlier = start.plus_months(-14, Disambiguation::Reject).unwrap();
+    assert_eq!(wall_clock(&earlier), (2025, Month::November, 15, 9, 0));
+}
+
+#[test]
+fn days_out_of_range() {
+    let start = zoned(2027, Month::January, 15, 9, 0);
+    assert_eq!(start.plus_days(i64::MAX, Disambiguation::Reject).unwrap_err(), ResolveError::OutOfRange);
+    assert_eq!(start.plus_days(i64::MIN, Disambiguation::Reject).unwrap_err(), ResolveError::OutOfRange);
+    assert_eq!(start.plus_days(400_000_000_000, Disambiguation::Reject).unwrap_err(), ResolveError::OutOfRange);
+}
+
+#[test]
+fn months_out_of_range() {
+    let start = zoned(2027, Month::January, 15, 9, 0);
+    assert_eq!(start.plus_months(i64::MAX, Disambiguation::Reject).unwrap_err(), ResolveError::OutOfRange);
+    assert_eq!(start.plus_months(i64::MIN, Disambiguation::Reject).unwrap_err(), ResolveError::OutOfRange);
+    assert_eq!(start.plus_months(24_000_000_000, Disambiguation::Reject).unwrap_err(), ResolveError::OutOfRange);
+    assert_eq!(start.plus_months(-24_000_000_000, Disambiguation::Reject).unwrap_err(), ResolveError::OutOfRange);
+}
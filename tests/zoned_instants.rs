@@ -0,0 +1,76 @@
+extern crate datetime;
+use datetime::zone::TimeZone;
+use datetime::{Instant, Month, DatePiece, TimePiece};
+
+use std::path::Path;
+
+
+fn zone(name: &str) -> TimeZone {
+    TimeZone::named_in(Path::new("./tests/zoneinfo"), name).unwrap()
+}
+
+
+#[test]
+fn at_an_instant() {
+    // 2027-07-01 11:00:00 UTC
+    let zoned = zone("Europe/London").at(Instant::at(1_814_439_600));
+    assert_eq!((zoned.year(), zoned.month(), zoned.day()), (2027, Month::July, 1));
+    assert_eq!((zoned.hour(), zoned.minute()), (12, 0));
+    assert_eq!(zoned.offset(), 3600);
+    assert_eq!(zoned.abbreviation(), "BST");
+    assert!(zoned.is_dst());
+    assert_eq!(zoned.to_instant(), Instant::at(1_814_439_600));
+}
+
+#[test]
+fn in_winter() {
+    // 2027-01-15 12:00:00 UTC
+    let zoned = zone("America/New_York").at(Instant::at(1_800_014_400));
+    assert_eq!((zoned.hour(), zoned.minute()), (7, 0));
+    assert_eq!(zoned.offset(), -5 * 3600);
+    assert_eq!(zoned.abbreviation(), "EST");
+    assert!(!zoned.is_dst());
+}
+
+#[test]
+fn with_zone() {
+    let london = zone("Europe/London").at(Instant::at(1_814_439_600));
+    let new_york = london.with_zone(&zone("America/New_York"));
+
+    assert_eq!((new_york.day(), new_york.hour()), (1, 7));
+    assert_eq!(new_york.abbreviation(), "EDT");
+    assert_eq!(new_york.to_instant(), london.to_instant());
+}
+
+#[test]
+fn equal_by_instant() {
+    let london = zone("Europe/London").at(Instant::at(1_814_439_600));
+    let new_york = zone("America/New_York").at(Instant::at(1_814_439_600));
+    assert_eq!(london, new_york);
+}
+
+#[test]
+fn ordered_by_instant() {
+    let london = zone("Europe/London").at(Instant::at(1_814_439_600));
+    let new_york = zone("America/New_York").at(Instant::at(1_814_439_601));
+
+    // New York’s wall-clock time is earlier, but its instant is later.
+    assert!(london < new_york);
+
+    let mut times = vec![ new_york.clone(), london.clone() ];
+    times.sort();
+    assert_eq!(times, vec![ london, new_york ]);
+}
+
+#[test]
+fn through_an_overlap() {
+    // 2027-10-31 00:30 and 01:30 UTC are both 01:30 on the wall clock.
+    let london = zone("Europe/London");
+    let first = london.at(Instant::at(1_824_942_600));
+    let second = london.at(Instant::at(1_824_946_200));
+
+    assert_eq!((first.hour(), first.minute()), (second.hour(), second.minute()));
+    assert_eq!(first.abbreviation(), "BST");
+    assert_eq!(second.abbreviation(), "GMT");
+    assert!(first < second);
+}
@@ -0,0 +1,176 @@
+extern crate datetime;
+use datetime::zone::{TimeZone, ZonedDateTime, OffsetMismatch};
+use datetime::{Instant, ISO, TimePiece};
+
+use std::env;
+use std::path::Path;
+
+
+/// Zone names in brackets are looked up in the fixtures directory.
+fn fixtures() {
+    env::set_var("TZDIR", "./tests/zoneinfo");
+}
+
+fn london() -> TimeZone {
+    TimeZone::named_in(Path::new("./tests/zoneinfo"), "Europe/London").unwrap()
+}
+
+fn parse(input: &str) -> ZonedDateTime<'static> {
+    fixtures();
+    input.parse().unwrap()
+}
+
+
+#[test]
+fn format_summer() {
+    let zoned = london().at(Instant::at(1_814_439_600));
+    assert_eq!(zoned.iso().to_string(), "2027-07-01T12:00:00.000+01:00[Europe/London]");
+}
+
+#[test]
+fn format_negative_offset() {
+    let zone = TimeZone::named_in(Path::new("./tests/zoneinfo"), "America/New_York").unwrap();
+    let zoned = zone.at(Instant::at(1_800_014_400));
+    assert_eq!(zoned.iso().to_string(), "2027-01-15T07:00:00.000-05:00[America/New_York]");
+}
+
+#[test]
+fn format_seconds_offset() {
+    // London’s local mean time was 1 minute 15 seconds behind GMT, which
+    // gets rounded to the nearest minute.
+    let zoned = london().at(Instant::at(-4_000_000_000));
+    assert_eq!(zoned.iso().to_string(), "1843-03-31T16:52:05.000-00:01[Europe/London]");
+}
+
+#[test]
+fn parse_seconds_offset() {
+    let rounded = parse("1843-03-31T16:52:05-00:01[Europe/London]");
+    let exact = parse("1843-03-31T16:52:05-00:01:15[Europe/London]");
+    assert_eq!(rounded.offset(), -75);
+    assert_eq!(rounded, exact);
+    assert_eq!(rounded.to_instant(), Instant::at(-4_000_000_000));
+}
+
+#[test]
+fn format_unnamed() {
+    let zone = TimeZone::from_posix("EST5").unwrap();
+    let zoned = zone.at(Instant::at(0));
+    assert_eq!(zoned.iso().to_string(), "1969-12-31T19:00:00.000-05:00");
+}
+
+#[test]
+fn round_trip() {
+    for &instant in &[ 1_814_439_600, 1_824_942_600, 1_824_946_200, -4_000_000_000 ] {
+        let zoned = london().at(Instant::at(instant));
+        let parsed = parse(&zoned.iso().to_string());
+        assert_eq!(parsed, zoned);
+        assert_eq!(parsed.offset(), zoned.offset());
+        assert_eq!(parsed.zone_name(), Some("Europe/London"));
+    }
+}
+
+#[test]
+fn parse_without_fraction() {
+    let zoned = parse("2027-07-01T12:00:00+01:00[Europe/London]");
+    assert_eq!(zoned.to_instant(), Instant::at(1_814_439_600));
+}
+
+#[test]
+fn parse_ambiguous_by_offset() {
+    let earlier = parse("2027-10-31T01:30:00+01:00[Europe/London]");
+    let later = parse("2027-10-31T01:30:00+00:00[Europe/London]");
+    assert_eq!(earlier.to_instant(), Instant::at(1_824_942_600));
+    assert_eq!(later.to_instant(), Instant::at(1_824_946_200));
+}
+
+#[test]
+fn parse_utc() {
+    let zoned = parse("2027-07-01T11:00:00Z[Europe/London]");
+    assert_eq!(zoned.hour(), 12);
+    assert_eq!(zoned.offset(), 3600);
+}
+
+#[test]
+fn parse_without_offset() {
+    let zoned = parse("2027-07-01T12:00:00[Europe/London]");
+    assert_eq!(zoned.to_instant(), Instant::at(1_814_439_600));
+}
+
+#[test]
+fn mismatch_rejected() {
+    fixtures();
+    match "2026-03-29T01:30:00+01:00[Europe/London]".parse::<ZonedDateTime>() {
+        Err(datetime_error) => assert!(datetime_error.to_string().contains("not valid")),
+        Ok(zoned)           => panic!("expected a mismatch, got {:?}", zoned),
+    }
+}
+
+#[test]
+fn mismatch_prefer_offset() {
+    fixtures();
+    let zoned = ZonedDateTime::parse_with("2026-03-29T01:30:00+01:00[Europe/London]", OffsetMismatch::PreferOffset).unwrap();
+    assert_eq!(zoned.to_instant(), Instant::at(1_774_744_200));
+    assert_eq!((zoned.hour(), zoned.minute()), (0, 30));
+}
+
+#[test]
+fn mismatch_prefer_zone() {
+    fixtures();
+    let zoned = ZonedDateTime::parse_with("2027-07-01T12:00:00+05:00[Europe/London]", OffsetMismatch::PreferZone).unwrap();
+    assert_eq!(zoned.to_instant(), Instant::at(1_814_439_600));
+}
+
+#[test]
+fn offset_zone() {
+    let zoned = parse("2027-07-01T16:30:00+05:30[+05:30]");
+    assert_eq!(zoned.to_instant(), Instant::at(1_814_439_600));
+    assert_eq!(zoned.iso().to_string(), "2027-07-01T16:30:00.000+05:30[+05:30]");
+}
+
+#[test]
+fn extra_annotations() {
+    let zoned = parse("2027-07-01T12:00:00+01:00[Europe/London][u-ca=gregory]");
+    assert_eq!(zoned.to_instant(), Instant::at(1_814_439_600));
+}
+
+fn error(input: &str) -> String {
+    fixtures();
+    input.parse::<ZonedDateTime>().unwrap_err().to_string()
+}
+
+#[test]
+fn critical_annotation() {
+    assert!(error("2027-07-01T12:00:00+01:00[Europe/London][!u-ca=gregory]").contains("invalid annotation"));
+}
+
+#[test]
+fn missing_zone() {
+    assert!(error("2027-07-01T12:00:00+01:00").contains("missing"));
+}
+
+#[test]
+fn unknown_zone() {
+    assert!(error("2027-07-01T12:00:00+01:00[Europe/Atlantis]").contains("not found"));
+}
+
+#[test]
+fn bad_offset() {
+    assert!(error("2027-07-01T12:00:00+1[Europe/London]").contains("invalid offset"));
+}
+
+#[test]
+fn error_message() {
+    fixtures();
+    let error = ZonedDateTime::parse_with("2027-07-01T12:00:00+01:00", OffsetMismatch::Reject).unwrap_err();
+    assert_eq!(error.to_string(), "parsing resulted in an invalid date: missing bracketed time zone");
+}
+
+#[test]
+fn nanoseconds() {
+    let zoned = parse("2027-07-01T12:00:00.123456789+01:00[Europe/London]");
+    assert_eq!(zoned.millisecond(), 123);
+    assert_eq!(zoned.microsecond(), 123_456);
+    assert_eq!(zoned.nanosecond(), 123_456_789);
+    assert_eq!(zoned.to_instant(), Instant::at_ns(1_814_439_600, 123_456_789));
+    assert_eq!(zoned.iso().to_string(), "2027-07-01T12:00:00.123456789+01:00[Europe/London]");
+}